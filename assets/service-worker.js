@@ -0,0 +1,116 @@
+// KoShelf offline support. Generated from assets/service-worker.js; the
+// placeholders below are filled in with the embedded frontend build.
+//
+// The app shell is precached on install. API responses, exported data and
+// media are fetched from the network first and fall back to the last cached
+// copy when offline. Cache names carry the frontend version, so a new build
+// installs fresh caches and drops the old ones on activation.
+
+const VERSION = '__KOSHELF_VERSION__';
+const PRECACHE_PATHS = __KOSHELF_PRECACHE_PATHS__;
+
+const CACHE_PREFIX = 'koshelf-';
+const SHELL_CACHE = `${CACHE_PREFIX}shell-${VERSION}`;
+const DATA_CACHE = `${CACHE_PREFIX}data-${VERSION}`;
+
+const scopePath = new URL(self.registration.scope).pathname;
+
+function scopedUrl(path) {
+    return new URL(path, self.registration.scope).href;
+}
+
+self.addEventListener('install', (event) => {
+    event.waitUntil(
+        caches
+            .open(SHELL_CACHE)
+            .then((cache) => cache.addAll(PRECACHE_PATHS.map(scopedUrl)))
+            .then(() => self.skipWaiting()),
+    );
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil(
+        caches
+            .keys()
+            .then((names) =>
+                Promise.all(
+                    names
+                        .filter(
+                            (name) =>
+                                name.startsWith(CACHE_PREFIX) &&
+                                name !== SHELL_CACHE &&
+                                name !== DATA_CACHE,
+                        )
+                        .map((name) => caches.delete(name)),
+                ),
+            )
+            .then(() => self.clients.claim()),
+    );
+});
+
+async function networkFirst(request, fallbackPath) {
+    const cache = await caches.open(DATA_CACHE);
+    try {
+        const response = await fetch(request);
+        if (response.status === 200) {
+            // Caching is best effort; a full quota must not fail the request.
+            cache.put(request, response.clone()).catch(() => {});
+        }
+        return response;
+    } catch (error) {
+        const cached =
+            (await cache.match(request)) ??
+            (fallbackPath
+                ? await caches.match(scopedUrl(fallbackPath))
+                : undefined);
+        if (cached) {
+            return cached;
+        }
+        throw error;
+    }
+}
+
+async function cacheFirst(request) {
+    const cached = await caches.match(request, { cacheName: SHELL_CACHE });
+    return cached ?? fetch(request);
+}
+
+self.addEventListener('fetch', (event) => {
+    const { request } = event;
+    if (request.method !== 'GET') {
+        return;
+    }
+
+    const url = new URL(request.url);
+    if (
+        url.origin !== self.location.origin ||
+        !url.pathname.startsWith(scopePath)
+    ) {
+        return;
+    }
+
+    const path = url.pathname.slice(scopePath.length);
+
+    // Live-update stream and session endpoints always go to the network.
+    if (path.startsWith('api/events/') || path.startsWith('api/auth/')) {
+        return;
+    }
+
+    if (request.mode === 'navigate') {
+        event.respondWith(networkFirst(request, 'index.html'));
+        return;
+    }
+
+    if (PRECACHE_PATHS.includes(path)) {
+        event.respondWith(cacheFirst(request));
+        return;
+    }
+
+    if (
+        path.startsWith('api/') ||
+        path.startsWith('data/') ||
+        path.startsWith('assets/')
+    ) {
+        event.respondWith(networkFirst(request));
+    }
+});
@@ -27,6 +27,7 @@ fn main() {
     println!("cargo:rerun-if-changed=Cargo.lock");
     println!("cargo:rerun-if-env-changed=KOSHELF_SKIP_NPM_INSTALL");
     println!("cargo:rerun-if-env-changed=KOSHELF_SKIP_REACT_BUILD");
+    println!("cargo:rerun-if-env-changed=KOSHELF_DEBUG_ASSETS");
     println!("cargo:rerun-if-env-changed=KOSHELF_SKIP_LICENSE_GENERATION");
 
     let skip_npm_install = env_flag("KOSHELF_SKIP_NPM_INSTALL");
@@ -27,6 +27,7 @@ recap = Rückblick
 settings = Einstellungen
 github = GitHub
 reading-companion = Lese-Begleiter
+main-navigation = Hauptnavigation
 # Used in footer/sidebar for update time
 last-updated = Letztes Update
 view-details = Details anzeigen
@@ -130,8 +131,10 @@ comics-finished = { $count ->
     [one] { comic-label } abgeschlossen
    *[other] { comic-label } abgeschlossen
 }
+items-started = { $count } begonnen
 unknown-book = Unbekanntes Buch
 unknown-author = Unbekannter Autor
+file-removed = Datei entfernt
 by = von
 book-overview = Buchübersicht
 comic-overview = Comicübersicht
@@ -140,8 +143,29 @@ comic-overview = Comicübersicht
 #            Book Details
 # -----------------------------------
 description = Beschreibung
+    .read-more = Weiterlesen
+    .read-less = Weniger anzeigen
 publisher = Verlag
+date-added = Hinzugefügt
+editors = { $count ->
+    [one] Herausgeber
+   *[other] Herausgeber
+}
+translators = { $count ->
+    [one] Übersetzer
+   *[other] Übersetzer
+}
 series = Reihe
+series-page =
+    .all = Alle Reihen
+    .empty = Noch keine Reihen
+    .empty-description = Reihen erscheinen hier, sobald genug ihrer Bücher in deiner Bibliothek sind.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Alle Autoren
+    .empty = Noch keine Autoren
+    .empty-description = Autoren erscheinen hier, sobald Bücher mit Autorenangaben in deiner Bibliothek sind.
+    .average-rating = Durchschnittliche Bewertung
 genres = Genres
 language = Sprache
 book-identifiers = Buch-Identifikatoren
@@ -156,9 +180,15 @@ notes-label = { $count ->
     [one] Notiz
    *[other] Notizen
 }
+bookmarks-label = { $count ->
+    [one] Lesezeichen
+   *[other] Lesezeichen
+}
 bookmarks = Lesezeichen
 page-bookmark = Seiten-Lesezeichen
 highlights-quotes = Markierungen & Zitate
+highlight-of-the-day = Markierung des Tages
+up-next = Reihe fortsetzen
 additional-information = Zusätzliche Informationen
 reading-progress = Lesefortschritt
 page-number = Seite { $count }
@@ -181,9 +211,25 @@ weekly-statistics = Wochenstatistiken
 yearly-statistics = Jährliche Statistiken
 total-read-time = Gesamte Lesezeit
 total-pages-read = Gesamte gelesene Seiten
+unique-pages-read = Verschiedene gelesene Seiten
 pages-per-hour = Seiten/Stunde
 # Abbreviation for Pages Per Hour
 pph-abbreviation = S/h
+reading-pace = Lesetempo
+    .median = Median
+weekday-split = Werktage vs. Wochenende
+    .weekdays = Werktage
+    .weekends = Wochenende
+    .average-time = Durchschnitt pro Tag
+    .average-pages = Seiten pro Tag
+    .reading-days = Lesetage
+finished-over-time = Beendet im Zeitverlauf
+    .by-month = Nach Monat
+    .by-year = Nach Jahr
+    .completions = { $count ->
+        [one] { $count } Abschluss
+       *[other] { $count } Abschlüsse
+    }
 reading-sessions-label = { $count ->
     [one] Lese-Sitzung
    *[other] Lese-Sitzungen
@@ -243,15 +289,43 @@ toggle =
     .show = Anzeigen
 less = Weniger
 more = Mehr
+heatmap-summary = { $days ->
+    [0] Keine Lesezeit in { $year } erfasst.
+    [one] An { $days } Tag in { $year } gelesen: insgesamt { $time } und { $pages } Seiten.
+   *[other] An { $days } Tagen in { $year } gelesen: insgesamt { $time } und { $pages } Seiten.
+}
+    .busiest = Am meisten gelesen am { $date }: { $time }.
 period = Zeitraum
 sessions = Sitzungen
 yearly-summary = Jahreszusammenfassung { $count }
+recap-top-items = Highlights des Jahres
+    .highest-rated = Am besten bewertet
+    .longest = Meiste Lesezeit
+    .ratings = Bewertungen
+recap-superlatives = Längste & Kürzeste
+    .longest-pages = Längstes Buch
+    .shortest-pages = Kürzestes Buch
+    .longest-time = Längste Lektüre
+    .shortest-time = Schnellste Lektüre
+recap-compare = Jahre vergleichen
+    .change = Veränderung
+    .need-two-years = Zum Vergleichen werden zwei Jahre benötigt
+    .need-two-years-description = Beende Lektüren in mindestens zwei verschiedenen Jahren, um sie nebeneinander zu vergleichen.
 recap-empty =
     .nothing-here = Hier gibt es noch nichts
     .try-switching = Versuche, den Bereich oder das Jahr oben zu wechseln.
     .finish-reading = Beende ein Buch in KoReader, um deinen Rückblick zu sehen.
     .info-question = Warum wird mein Rückblick nicht angezeigt?
     .info-answer = KoShelf verwendet Lesestatistiken zur Erkennung von abgeschlossenen Büchern und Comics, was das Nachverfolgen von Wiederholungslektüren ermöglicht. Ein Buch einfach als „beendet" zu markieren, ohne Lesedaten zu haben, lässt es hier nicht erscheinen.
+did-not-finish = Abgebrochen
+    .items = Abgebrochene Bücher
+    .time-spent = Investierte Zeit
+    .year-entry = { $count } · { $time }
+    .last-read = Zuletzt gelesen am { $date }
+    .never-read = Nie gelesen
+recap-idle-month =
+    .nothing-finished = In diesem Monat nichts beendet
+    .no-reading = In diesem Monat nicht gelesen
 stats-empty =
     .nothing-here = Hier gibt es noch nichts
     .start-reading = Beginne mit KoReader zu lesen, um deine Statistiken hier zu sehen.
@@ -287,6 +361,14 @@ previous-month =
     .aria-label = Vorheriger Monat
 next-month =
     .aria-label = Nächster Monat
+previous-year =
+    .aria-label = Vorheriges Jahr
+next-year =
+    .aria-label = Nächstes Jahr
+year-view =
+    .aria-label = Ganzes Jahr anzeigen
+month-view =
+    .aria-label = Monatskalender anzeigen
 search =
     .aria-label = Suchen
 close-search =
@@ -466,3 +548,28 @@ page-activity = Seitenaktivität
     .legend-bookmark = Lesezeichen
     .legend-chapter = Kapitel
     .info = Die Seitenzahlen werden durch die aktuellen Formatierungsoptionen des Dokuments beeinflusst. Im Gegensatz zu anderen Bereichen von KoShelf unterstützt diese Ansicht keine synthetische Seitenskalierung.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Tastenkürzel
+    .search = Bibliothek durchsuchen
+    .next-item = Nächstes Buch
+    .previous-item = Vorheriges Buch
+    .go-to = Zu { $page } wechseln
+    .help = Tastenkürzel anzeigen
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Leseverlauf
+    .session = { $pages ->
+        [one] { $pages } Seite
+       *[other] { $pages } Seiten
+    } in { $duration } gelesen
+    .bookmark = Lesezeichen auf Seite { $page }
+    .break = { $count ->
+        [one] { $count } Tag ohne Lesen
+       *[other] { $count } Tage ohne Lesen
+    }
+    .undated = Ohne Datum
@@ -27,6 +27,7 @@ recap = Recap
 settings = Settings
 github = GitHub
 reading-companion = Reading Companion
+main-navigation = Main navigation
 # Used in footer/sidebar for update time
 last-updated = Last updated
 view-details = View Details
@@ -130,8 +131,10 @@ comics-finished = { $count ->
     [one] { comic-label } Finished
    *[other] { comic-label } Finished
 }
+items-started = { $count } started
 unknown-book = Unknown Book
 unknown-author = Unknown Author
+file-removed = File removed
 by = by
 book-overview = Book Overview
 comic-overview = Comic Overview
@@ -140,8 +143,29 @@ comic-overview = Comic Overview
 #            Book Details
 # -----------------------------------
 description = Description
+    .read-more = Read more
+    .read-less = Read less
 publisher = Publisher
+date-added = Added
+editors = { $count ->
+    [one] Editor
+   *[other] Editors
+}
+translators = { $count ->
+    [one] Translator
+   *[other] Translators
+}
 series = Series
+series-page =
+    .all = All Series
+    .empty = No series yet
+    .empty-description = Series show up here once enough of their books are in your library.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = All Authors
+    .empty = No authors yet
+    .empty-description = Authors show up here once books with author metadata are in your library.
+    .average-rating = Average Rating
 genres = Genres
 language = Language
 book-identifiers = Book Identifiers
@@ -156,9 +180,15 @@ notes-label = { $count ->
     [one] Note
    *[other] Notes
 }
+bookmarks-label = { $count ->
+    [one] Bookmark
+   *[other] Bookmarks
+}
 bookmarks = Bookmarks
 page-bookmark = Page Bookmark
 highlights-quotes = Highlights & Quotes
+highlight-of-the-day = Highlight of the Day
+up-next = Continue the Series
 additional-information = Additional Information
 reading-progress = Reading Progress
 page-number = Page { $count }
@@ -181,9 +211,25 @@ weekly-statistics = Weekly Statistics
 yearly-statistics = Yearly Statistics
 total-read-time = Total Read Time
 total-pages-read = Total Pages Read
+unique-pages-read = Unique Pages Read
 pages-per-hour = Pages/Hour
 # Abbreviation for Pages Per Hour
 pph-abbreviation = pph
+reading-pace = Reading Pace
+    .median = Median
+weekday-split = Weekdays vs. Weekends
+    .weekdays = Weekdays
+    .weekends = Weekends
+    .average-time = Average per Day
+    .average-pages = Pages per Day
+    .reading-days = Reading Days
+finished-over-time = Finished Over Time
+    .by-month = By Month
+    .by-year = By Year
+    .completions = { $count ->
+        [one] { $count } completion
+       *[other] { $count } completions
+    }
 reading-sessions-label = { $count ->
     [one] Reading Session
    *[other] Reading Sessions
@@ -243,15 +289,43 @@ toggle =
     .show = Show
 less = Less
 more = More
+heatmap-summary = { $days ->
+    [0] No reading recorded in { $year }.
+    [one] Read on { $days } day in { $year }: { $time } and { $pages } pages in total.
+   *[other] Read on { $days } days in { $year }: { $time } and { $pages } pages in total.
+}
+    .busiest = Most reading on { $date }: { $time }.
 period = Period
 sessions = Sessions
 yearly-summary = Yearly Summary { $count }
+recap-top-items = Top of the Year
+    .highest-rated = Highest Rated
+    .longest = Most Time Spent
+    .ratings = Ratings
+recap-superlatives = Longest & Shortest
+    .longest-pages = Longest Book
+    .shortest-pages = Shortest Book
+    .longest-time = Longest Read
+    .shortest-time = Quickest Read
+recap-compare = Compare Years
+    .change = Change
+    .need-two-years = Need two years to compare
+    .need-two-years-description = Finish reading in at least two different years to compare them side by side.
 recap-empty =
     .nothing-here = Nothing here yet
     .try-switching = Try switching scope or year above.
     .finish-reading = Finish reading in KoReader to see your recap.
     .info-question = Why isn't my recap showing up?
     .info-answer = KoShelf uses reading statistics to detect book and comic completions, which allows tracking re-reads. Simply marking a book as "finished" without reading data will not make it appear here.
+did-not-finish = Did Not Finish
+    .items = Books given up
+    .time-spent = Time invested
+    .year-entry = { $count } · { $time }
+    .last-read = Last read { $date }
+    .never-read = Never read
+recap-idle-month =
+    .nothing-finished = Nothing finished this month
+    .no-reading = No reading this month
 stats-empty =
     .nothing-here = Nothing here yet
     .start-reading = Start reading with KoReader to see your statistics here.
@@ -287,6 +361,14 @@ previous-month =
     .aria-label = Previous month
 next-month =
     .aria-label = Next month
+previous-year =
+    .aria-label = Previous year
+next-year =
+    .aria-label = Next year
+year-view =
+    .aria-label = Show the whole year
+month-view =
+    .aria-label = Show the month calendar
 search =
     .aria-label = Search
 close-search =
@@ -466,3 +548,28 @@ page-activity = Page Activity
     .legend-bookmark = Bookmark
     .legend-chapter = Chapter
     .info = Page numbers are affected by the current formatting options of the document. Unlike other parts of KoShelf, this view does not support synthetic page scaling.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Keyboard shortcuts
+    .search = Search the library
+    .next-item = Next book
+    .previous-item = Previous book
+    .go-to = Go to { $page }
+    .help = Show keyboard shortcuts
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Reading History
+    .session = Read { $pages ->
+        [one] { $pages } page
+       *[other] { $pages } pages
+    } in { $duration }
+    .bookmark = Bookmark on page { $page }
+    .break = { $count ->
+        [one] { $count } day without reading
+       *[other] { $count } days without reading
+    }
+    .undated = Undated
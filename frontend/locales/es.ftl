@@ -23,6 +23,7 @@ recap = Resumen
 settings = Configuración
 github = GitHub
 reading-companion = Compañero de lectura
+main-navigation = Navegación principal
 # Used in footer/sidebar for update time
 last-updated = Última actualización
 view-details = Ver detalles
@@ -126,8 +127,13 @@ comics-finished = { $count ->
     [one] { comic-label } terminado
    *[other] { comic-label } terminados
 }
+items-started = { $count ->
+    [one] { $count } empezado
+   *[other] { $count } empezados
+}
 unknown-book = Libro desconocido
 unknown-author = Autor desconocido
+file-removed = Archivo eliminado
 by = por
 book-overview = Resumen del libro
 comic-overview = Resumen del cómic
@@ -136,8 +142,29 @@ comic-overview = Resumen del cómic
 #            Book Details
 # -----------------------------------
 description = Descripción
+    .read-more = Leer más
+    .read-less = Leer menos
 publisher = Editorial
+date-added = Añadido
+editors = { $count ->
+    [one] Editor
+   *[other] Editores
+}
+translators = { $count ->
+    [one] Traductor
+   *[other] Traductores
+}
 series = Serie
+series-page =
+    .all = Todas las series
+    .empty = Aún no hay series
+    .empty-description = Las series aparecen aquí cuando tienes suficientes de sus libros en tu biblioteca.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Todos los autores
+    .empty = Aún no hay autores
+    .empty-description = Los autores aparecen aquí cuando tienes libros con autor en tu biblioteca.
+    .average-rating = Valoración media
 genres = Géneros
 language = Idioma
 book-identifiers = Identificadores
@@ -152,9 +179,15 @@ notes-label = { $count ->
     [one] Nota
    *[other] Notas
 }
+bookmarks-label = { $count ->
+    [one] Marcador
+   *[other] Marcadores
+}
 bookmarks = Marcadores
 page-bookmark = Marcador de página
 highlights-quotes = Subrayados y Citas
+highlight-of-the-day = Subrayado del día
+up-next = Continúa la serie
 additional-information = Información adicional
 reading-progress = Progreso de lectura
 page-number = Página { $count }
@@ -177,9 +210,25 @@ weekly-statistics = Estadísticas semanales
 yearly-statistics = Estadísticas anuales
 total-read-time = Tiempo total de lectura
 total-pages-read = Total de páginas leídas
+unique-pages-read = Páginas distintas leídas
 pages-per-hour = Páginas/Hora
 # Abbreviation for Pages Per Hour
 pph-abbreviation = pph
+reading-pace = Ritmo de lectura
+    .median = Mediana
+weekday-split = Entre semana vs. fines de semana
+    .weekdays = Entre semana
+    .weekends = Fines de semana
+    .average-time = Promedio por día
+    .average-pages = Páginas por día
+    .reading-days = Días de lectura
+finished-over-time = Terminados a lo largo del tiempo
+    .by-month = Por mes
+    .by-year = Por año
+    .completions = { $count ->
+        [one] { $count } lectura completada
+       *[other] { $count } lecturas completadas
+    }
 reading-sessions-label = { $count ->
     [one] Sesión de lectura
    *[other] Sesiones de lectura
@@ -239,15 +288,43 @@ toggle =
     .show = Mostrar
 less = Menos
 more = Más
+heatmap-summary = { $days ->
+    [0] No hay lectura registrada en { $year }.
+    [one] Lectura en { $days } día de { $year }: { $time } y { $pages } páginas en total.
+   *[other] Lectura en { $days } días de { $year }: { $time } y { $pages } páginas en total.
+}
+    .busiest = Día con más lectura: { $date } ({ $time }).
 period = Periodo
 sessions = Sesiones
 yearly-summary = Resumen anual { $count }
+recap-top-items = Lo mejor del año
+    .highest-rated = Mejor valorados
+    .longest = Más tiempo de lectura
+    .ratings = Valoraciones
+recap-superlatives = Más largos y más cortos
+    .longest-pages = Libro más largo
+    .shortest-pages = Libro más corto
+    .longest-time = Lectura más larga
+    .shortest-time = Lectura más rápida
+recap-compare = Comparar años
+    .change = Cambio
+    .need-two-years = Se necesitan dos años para comparar
+    .need-two-years-description = Termina lecturas en al menos dos años distintos para compararlos lado a lado.
 recap-empty =
     .nothing-here = Aún no hay nada aquí
     .try-switching = Prueba a cambiar el alcance o el año arriba.
     .finish-reading = Termina de leer en KoReader para ver tu resumen.
     .info-question = ¿Por qué no aparece mi resumen?
     .info-answer = KoShelf usa estadísticas de lectura para detectar libros y cómics completados, lo que permite rastrear relecturas. Marcar un libro como "terminado" sin datos de lectura no hará que aparezca aquí.
+did-not-finish = Sin terminar
+    .items = Libros abandonados
+    .time-spent = Tiempo invertido
+    .year-entry = { $count } · { $time }
+    .last-read = Última lectura: { $date }
+    .never-read = Nunca leído
+recap-idle-month =
+    .nothing-finished = Nada terminado este mes
+    .no-reading = Sin lectura este mes
 stats-empty =
     .nothing-here = Aún no hay nada aquí
     .start-reading = Empieza a leer con KoReader para ver tus estadísticas aquí.
@@ -283,6 +360,14 @@ previous-month =
     .aria-label = Mes anterior
 next-month =
     .aria-label = Mes siguiente
+previous-year =
+    .aria-label = Año anterior
+next-year =
+    .aria-label = Año siguiente
+year-view =
+    .aria-label = Mostrar el año completo
+month-view =
+    .aria-label = Mostrar el calendario mensual
 search =
     .aria-label = Buscar
 close-search =
@@ -462,3 +547,28 @@ page-activity = Actividad de páginas
     .legend-bookmark = Marcador
     .legend-chapter = Capítulo
     .info = Los números de página se ven afectados por las opciones de formato actuales del documento. A diferencia de otras partes de KoShelf, esta vista no admite el escalado sintético de páginas.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Atajos de teclado
+    .search = Buscar en la biblioteca
+    .next-item = Libro siguiente
+    .previous-item = Libro anterior
+    .go-to = Ir a { $page }
+    .help = Mostrar atajos de teclado
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Historial de lectura
+    .session = { $pages ->
+        [one] { $pages } página leída
+       *[other] { $pages } páginas leídas
+    } en { $duration }
+    .bookmark = Marcador en la página { $page }
+    .break = { $count ->
+        [one] { $count } día sin leer
+       *[other] { $count } días sin leer
+    }
+    .undated = Sin fecha
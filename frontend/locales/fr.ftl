@@ -27,6 +27,7 @@ recap = Récap
 settings = Paramètres
 github = GitHub
 reading-companion = Compagnon de lecture
+main-navigation = Navigation principale
 # Used in footer/sidebar for update time
 last-updated = Dernière mise à jour
 view-details = Voir les détails
@@ -130,8 +131,13 @@ comics-finished = { $count ->
     [one] { comic-label } terminée
    *[other] { comic-label } terminées
 }
+items-started = { $count ->
+    [one] { $count } commencé
+   *[other] { $count } commencés
+}
 unknown-book = Livre inconnu
 unknown-author = Auteur inconnu
+file-removed = Fichier supprimé
 by = par
 book-overview = Aperçu du livre
 comic-overview = Aperçu de la bande dessinée
@@ -140,8 +146,29 @@ comic-overview = Aperçu de la bande dessinée
 #            Book Details
 # -----------------------------------
 description = Description
+    .read-more = Lire la suite
+    .read-less = Réduire
 publisher = Éditeur
+date-added = Ajouté
+editors = { $count ->
+    [one] Éditeur scientifique
+   *[other] Éditeurs scientifiques
+}
+translators = { $count ->
+    [one] Traducteur
+   *[other] Traducteurs
+}
 series = Série
+series-page =
+    .all = Toutes les séries
+    .empty = Aucune série pour le moment
+    .empty-description = Les séries apparaissent ici dès que suffisamment de leurs livres sont dans votre bibliothèque.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Tous les auteurs
+    .empty = Aucun auteur pour le moment
+    .empty-description = Les auteurs apparaissent ici dès que votre bibliothèque contient des livres avec un auteur.
+    .average-rating = Note moyenne
 genres = Genres
 language = Langue
 book-identifiers = Identifiants du livre
@@ -156,9 +183,15 @@ notes-label = { $count ->
     [one] Note
    *[other] Notes
 }
+bookmarks-label = { $count ->
+    [one] Signet
+   *[other] Signets
+}
 bookmarks = Signets
 page-bookmark = Signet de page
 highlights-quotes = Surlignages & Citations
+highlight-of-the-day = Surlignage du jour
+up-next = Continuer la série
 additional-information = Informations supplémentaires
 reading-progress = Progression de lecture
 page-number = Page { $count }
@@ -181,9 +214,25 @@ weekly-statistics = Statistiques hebdomadaires
 yearly-statistics = Statistiques annuelles
 total-read-time = Temps de lecture total
 total-pages-read = Total des pages lues
+unique-pages-read = Pages distinctes lues
 pages-per-hour = Pages/heure
 # Abbreviation for Pages Per Hour
 pph-abbreviation = p/h
+reading-pace = Rythme de lecture
+    .median = Médiane
+weekday-split = Semaine vs. week-end
+    .weekdays = En semaine
+    .weekends = Le week-end
+    .average-time = Moyenne par jour
+    .average-pages = Pages par jour
+    .reading-days = Jours de lecture
+finished-over-time = Terminés au fil du temps
+    .by-month = Par mois
+    .by-year = Par année
+    .completions = { $count ->
+        [one] { $count } lecture terminée
+       *[other] { $count } lectures terminées
+    }
 reading-sessions-label = { $count ->
     [one] Session de lecture
    *[other] Sessions de lecture
@@ -243,15 +292,43 @@ toggle =
     .show = Afficher
 less = Moins
 more = Plus
+heatmap-summary = { $days ->
+    [0] Aucune lecture enregistrée en { $year }.
+    [one] Lecture sur { $days } jour en { $year } : { $time } et { $pages } pages au total.
+   *[other] Lecture sur { $days } jours en { $year } : { $time } et { $pages } pages au total.
+}
+    .busiest = Jour le plus lu : { $date } ({ $time }).
 period = Période
 sessions = Sessions
 yearly-summary = Résumé annuel { $count }
+recap-top-items = Le meilleur de l'année
+    .highest-rated = Les mieux notés
+    .longest = Plus de temps passé
+    .ratings = Notes
+recap-superlatives = Les plus longs et les plus courts
+    .longest-pages = Livre le plus long
+    .shortest-pages = Livre le plus court
+    .longest-time = Lecture la plus longue
+    .shortest-time = Lecture la plus rapide
+recap-compare = Comparer les années
+    .change = Évolution
+    .need-two-years = Deux années sont nécessaires pour comparer
+    .need-two-years-description = Terminez des lectures sur au moins deux années différentes pour les comparer côte à côte.
 recap-empty =
     .nothing-here = Il n’y a encore rien ici
     .try-switching = Essayez de changer la période ou l'année au dessus.
     .finish-reading = Terminez un livre dans KoReader pour voir votre récapitulatif.
     .info-question = Pourquoi mon récapitulatif ne s’affiche-t-il pas ?
     .info-answer = KoShelf utilise les statistiques de lecture pour détecter les livres et bandes dessinées terminés, ce qui permet de suivre les relectures. Marquer simplement un livre comme "terminé" sans données de lecture ne le fera pas apparaître ici.
+did-not-finish = Non terminés
+    .items = Livres abandonnés
+    .time-spent = Temps investi
+    .year-entry = { $count } · { $time }
+    .last-read = Dernière lecture le { $date }
+    .never-read = Jamais lu
+recap-idle-month =
+    .nothing-finished = Rien terminé ce mois-ci
+    .no-reading = Aucune lecture ce mois-ci
 
 stats-empty =
     .nothing-here = Il n’y a encore rien ici
@@ -288,6 +365,14 @@ previous-month =
     .aria-label = Mois précédent
 next-month =
     .aria-label = Mois suivant
+previous-year =
+    .aria-label = Année précédente
+next-year =
+    .aria-label = Année suivante
+year-view =
+    .aria-label = Afficher l’année entière
+month-view =
+    .aria-label = Afficher le calendrier du mois
 search =
     .aria-label = Rechercher
 close-search =
@@ -467,3 +552,28 @@ page-activity = Activité des pages
     .legend-bookmark = Signet
     .legend-chapter = Chapitre
     .info = Les numéros de page sont influencés par les options de formatage actuelles du document. Contrairement aux autres parties de KoShelf, cette vue ne prend pas en charge la mise à l'échelle synthétique des pages.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Raccourcis clavier
+    .search = Rechercher dans la bibliothèque
+    .next-item = Livre suivant
+    .previous-item = Livre précédent
+    .go-to = Aller à { $page }
+    .help = Afficher les raccourcis clavier
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Historique de lecture
+    .session = { $pages ->
+        [one] { $pages } page lue
+       *[other] { $pages } pages lues
+    } en { $duration }
+    .bookmark = Signet à la page { $page }
+    .break = { $count ->
+        [one] { $count } jour sans lecture
+       *[other] { $count } jours sans lecture
+    }
+    .undated = Sans date
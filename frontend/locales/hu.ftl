@@ -20,6 +20,7 @@ recap = Visszatekintés
 settings = Beállítások
 github = GitHub
 reading-companion = Olvasónapló
+main-navigation = Főmenü
 # Used in footer/sidebar for update time
 last-updated = Utoljára frissítve:
 view-details = Részletek
@@ -118,8 +119,10 @@ books-finished = { $count ->
 comics-finished = { $count ->
    *[other] { comic-label } befejezve
 }
+items-started = { $count } elkezdve
 unknown-book = Ismeretlen könyv
 unknown-author = Ismeretlen szerző
+file-removed = Fájl eltávolítva
 by = Írta:
 book-overview = Könyv áttekintése
 comic-overview = Képregény áttekintése
@@ -128,8 +131,29 @@ comic-overview = Képregény áttekintése
 #            Book Details
 # -----------------------------------
 description = Leírás
+    .read-more = Tovább
+    .read-less = Kevesebb
 publisher = Kiadó
+date-added = Hozzáadva
+editors = { $count ->
+    [one] Szerkesztő
+   *[other] Szerkesztők
+}
+translators = { $count ->
+    [one] Fordító
+   *[other] Fordítók
+}
 series = Sorozat
+series-page =
+    .all = Összes sorozat
+    .empty = Még nincsenek sorozatok
+    .empty-description = A sorozatok akkor jelennek meg itt, ha elég kötetük van a könyvtáradban.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Összes szerző
+    .empty = Még nincsenek szerzők
+    .empty-description = A szerzők akkor jelennek meg itt, ha a könyvtáradban szerzővel ellátott könyvek vannak.
+    .average-rating = Átlagos értékelés
 genres = Műfajok
 language = Nyelv
 book-identifiers = Könyv azonosítók
@@ -142,9 +166,14 @@ highlights-label = { $count ->
 notes-label = { $count ->
    *[other] Jegyzet
 }
+bookmarks-label = { $count ->
+   *[other] Könyvjelző
+}
 bookmarks = Könyvjelzők
 page-bookmark = Oldal könyvjelző
 highlights-quotes = Kiemelések és idézetek
+highlight-of-the-day = A nap kiemelése
+up-next = Folytasd a sorozatot
 additional-information = További információk
 reading-progress = Olvasási folyamat
 page-number = { $count }. oldal
@@ -165,9 +194,25 @@ weekly-statistics = Heti statisztikák
 yearly-statistics = Éves statisztikák
 total-read-time = Teljes olvasási idő
 total-pages-read = Elolvasott oldalak száma
+unique-pages-read = Különböző elolvasott oldalak
 pages-per-hour = Oldal/óra
 # Abbreviation for Pages Per Hour
 pph-abbreviation = old./ó.
+reading-pace = Olvasási tempó
+    .median = Medián
+weekday-split = Hétköznap vs. hétvége
+    .weekdays = Hétköznap
+    .weekends = Hétvége
+    .average-time = Napi átlag
+    .average-pages = Oldal naponta
+    .reading-days = Olvasási napok
+finished-over-time = Befejezések időben
+    .by-month = Havonta
+    .by-year = Évente
+    .completions = { $count ->
+        [one] { $count } befejezés
+       *[other] { $count } befejezés
+    }
 reading-sessions-label = { $count ->
    *[other] Olvasási alkalom
 }
@@ -222,15 +267,42 @@ toggle =
     .show = Mutatás
 less = Kevesebb
 more = Több
+heatmap-summary = { $days ->
+    [0] { $year }: nincs rögzített olvasás.
+   *[other] { $year }: { $days } olvasási nap, összesen { $time } és { $pages } oldal.
+}
+    .busiest = Legtöbb olvasás: { $date } ({ $time }).
 period = Időszak
 sessions = Olvasási alkalom
 yearly-summary = Éves összegzés: { $count }
+recap-top-items = Az év legjobbjai
+    .highest-rated = Legjobbra értékelt
+    .longest = Legtöbb olvasási idő
+    .ratings = Értékelések
+recap-superlatives = Leghosszabb és legrövidebb
+    .longest-pages = Leghosszabb könyv
+    .shortest-pages = Legrövidebb könyv
+    .longest-time = Leghosszabb olvasás
+    .shortest-time = Leggyorsabb olvasás
+recap-compare = Évek összehasonlítása
+    .change = Változás
+    .need-two-years = Az összehasonlításhoz két év szükséges
+    .need-two-years-description = Fejezz be olvasásokat legalább két különböző évben, hogy egymás mellett összehasonlíthasd őket.
 recap-empty =
     .nothing-here = Itt még nincs semmi
     .try-switching = Próbálj meg fentebb hatókört vagy évet váltani.
     .finish-reading = Fejezz be egy könyvet a KoReaderben, hogy lásd az összegzést.
     .info-question = Miért nem jelenik meg az összegzésem?
     .info-answer = A KoShelf az olvasási statisztikákat használja a befejezett könyvek és képregények érzékeléséhez, ami lehetővé teszi az újraolvasások nyomon követését. Ha egyszerűen "befejezett"-re állítasz egy könyvet olvasási adatok nélkül, az nem fog itt megjelenni.
+did-not-finish = Félbehagyott
+    .items = Félbehagyott könyvek
+    .time-spent = Ráfordított idő
+    .year-entry = { $count } · { $time }
+    .last-read = Utoljára olvasva: { $date }
+    .never-read = Sosem olvasott
+recap-idle-month =
+    .nothing-finished = Ebben a hónapban semmit nem fejeztél be
+    .no-reading = Ebben a hónapban nem olvastál
 stats-empty =
     .nothing-here = Itt még nincs semmi
     .start-reading = Kezdj el olvasni a KoReaderben, hogy itt lásd a statisztikáidat.
@@ -266,6 +338,14 @@ previous-month =
     .aria-label = Előző hónap
 next-month =
     .aria-label = Következő hónap
+previous-year =
+    .aria-label = Előző év
+next-year =
+    .aria-label = Következő év
+year-view =
+    .aria-label = Egész év megjelenítése
+month-view =
+    .aria-label = Havi naptár megjelenítése
 search =
     .aria-label = Keresés
 close-search =
@@ -442,3 +522,26 @@ page-activity = Oldalaktivitás
     .legend-bookmark = Könyvjelző
     .legend-chapter = Fejezet
     .info = Az oldalszámokat a dokumentum aktuális formázási beállításai befolyásolják. A KoShelf többi részétől eltérően ez a nézet nem támogatja a szintetikus oldalszámozást.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Billentyűparancsok
+    .search = Keresés a könyvtárban
+    .next-item = Következő könyv
+    .previous-item = Előző könyv
+    .go-to = Ugrás ide: { $page }
+    .help = Billentyűparancsok megjelenítése
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Olvasási előzmények
+    .session = { $pages ->
+       *[other] { $pages } oldal
+    } olvasva, { $duration }
+    .bookmark = Könyvjelző, { $page }. oldal
+    .break = { $count ->
+       *[other] { $count } nap olvasás nélkül
+    }
+    .undated = Dátum nélkül
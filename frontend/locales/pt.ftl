@@ -27,6 +27,7 @@ recap = Retrospectiva
 settings = Configurações
 github = GitHub
 reading-companion = Companheiro de Leitura
+main-navigation = Navegação principal
 # Used in footer/sidebar for update time
 last-updated = Última atualização
 view-details = Ver Detalhes
@@ -130,8 +131,13 @@ comics-finished = { $count ->
     [one] { comic-label } Terminada
    *[other] { comic-label } Terminadas
 }
+items-started = { $count ->
+    [one] { $count } iniciado
+   *[other] { $count } iniciados
+}
 unknown-book = Livro Desconhecido
 unknown-author = Autor Desconhecido
+file-removed = Arquivo removido
 by = por
 book-overview = Visão Geral
 comic-overview = Visão Geral da Banda Desenhada
@@ -140,8 +146,29 @@ comic-overview = Visão Geral da Banda Desenhada
 #            Book Details
 # -----------------------------------
 description = Descrição
+    .read-more = Ler mais
+    .read-less = Ler menos
 publisher = Editora
+date-added = Adicionado
+editors = { $count ->
+    [one] Organizador
+   *[other] Organizadores
+}
+translators = { $count ->
+    [one] Tradutor
+   *[other] Tradutores
+}
 series = Série
+series-page =
+    .all = Todas as séries
+    .empty = Ainda não há séries
+    .empty-description = As séries aparecem aqui quando houver livros suficientes delas na sua biblioteca.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Todos os autores
+    .empty = Ainda não há autores
+    .empty-description = Os autores aparecem aqui quando houver livros com autor na sua biblioteca.
+    .average-rating = Avaliação média
 genres = Gêneros
 language = Idioma
 book-identifiers = Identificadores
@@ -156,9 +183,15 @@ notes-label = { $count ->
     [one] Nota
    *[other] Notas
 }
+bookmarks-label = { $count ->
+    [one] Marcador
+   *[other] Marcadores
+}
 bookmarks = Marcadores
 page-bookmark = Marcador de Página
 highlights-quotes = Destaques & Citações
+highlight-of-the-day = Destaque do Dia
+up-next = Continue a série
 additional-information = Informações Adicionais
 reading-progress = Progresso de Leitura
 page-number = Página { $count }
@@ -181,9 +214,25 @@ weekly-statistics = Estatísticas Semanais
 yearly-statistics = Estatísticas Anuais
 total-read-time = Tempo Total de Leitura
 total-pages-read = Total de Páginas Lidas
+unique-pages-read = Páginas Distintas Lidas
 pages-per-hour = Páginas/Hora
 # Abbreviation for Pages Per Hour
 pph-abbreviation = pph
+reading-pace = Ritmo de leitura
+    .median = Mediana
+weekday-split = Dias úteis vs. fins de semana
+    .weekdays = Dias úteis
+    .weekends = Fins de semana
+    .average-time = Média por dia
+    .average-pages = Páginas por dia
+    .reading-days = Dias de leitura
+finished-over-time = Concluídos ao longo do tempo
+    .by-month = Por mês
+    .by-year = Por ano
+    .completions = { $count ->
+        [one] { $count } leitura concluída
+       *[other] { $count } leituras concluídas
+    }
 reading-sessions-label = { $count ->
     [one] Sessão de Leitura
    *[other] Sessões de Leitura
@@ -243,15 +292,43 @@ toggle =
     .show = Mostrar
 less = Menos
 more = Mais
+heatmap-summary = { $days ->
+    [0] Nenhuma leitura registrada em { $year }.
+    [one] Leitura em { $days } dia de { $year }: { $time } e { $pages } páginas no total.
+   *[other] Leitura em { $days } dias de { $year }: { $time } e { $pages } páginas no total.
+}
+    .busiest = Dia com mais leitura: { $date } ({ $time }).
 period = Período
 sessions = Sessões
 yearly-summary = Resumo Anual { $count }
+recap-top-items = Destaques do Ano
+    .highest-rated = Mais Bem Avaliados
+    .longest = Mais Tempo de Leitura
+    .ratings = Avaliações
+recap-superlatives = Mais longos e mais curtos
+    .longest-pages = Livro mais longo
+    .shortest-pages = Livro mais curto
+    .longest-time = Leitura mais longa
+    .shortest-time = Leitura mais rápida
+recap-compare = Comparar Anos
+    .change = Variação
+    .need-two-years = São necessários dois anos para comparar
+    .need-two-years-description = Conclua leituras em pelo menos dois anos diferentes para compará-los lado a lado.
 recap-empty =
     .nothing-here = Nada aqui ainda
     .try-switching = Tente mudar o escopo ou o ano acima.
     .finish-reading = Termine de ler no KoReader para ver seu resumo.
     .info-question = Por que meu resumo não está aparecendo?
     .info-answer = O KoShelf usa estatísticas de leitura para detectar conclusões, o que permite o rastreamento de releituras. Apenas marcar um livro como "concluído" sem dados de leitura não fará com que ele apareça aqui.
+did-not-finish = Não terminados
+    .items = Livros abandonados
+    .time-spent = Tempo investido
+    .year-entry = { $count } · { $time }
+    .last-read = Última leitura em { $date }
+    .never-read = Nunca lido
+recap-idle-month =
+    .nothing-finished = Nada terminado neste mês
+    .no-reading = Nenhuma leitura neste mês
 stats-empty =
     .nothing-here = Nada aqui ainda
     .start-reading = Comece a ler com o KoReader para ver suas estatísticas aqui.
@@ -287,6 +364,14 @@ previous-month =
     .aria-label = Mês anterior
 next-month =
     .aria-label = Próximo mês
+previous-year =
+    .aria-label = Ano anterior
+next-year =
+    .aria-label = Próximo ano
+year-view =
+    .aria-label = Mostrar o ano inteiro
+month-view =
+    .aria-label = Mostrar o calendário do mês
 search =
     .aria-label = Buscar
 close-search =
@@ -466,3 +551,28 @@ page-activity = Atividade de Páginas
     .legend-bookmark = Marcador
     .legend-chapter = Capítulo
     .info = Os números de página são afetados pelas opções de formatação atuais do documento. Ao contrário de outras partes do KoShelf, esta vista não suporta escalonamento sintético de páginas.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Atalhos de teclado
+    .search = Pesquisar na biblioteca
+    .next-item = Próximo livro
+    .previous-item = Livro anterior
+    .go-to = Ir para { $page }
+    .help = Mostrar atalhos de teclado
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Histórico de leitura
+    .session = { $pages ->
+        [one] { $pages } página lida
+       *[other] { $pages } páginas lidas
+    } em { $duration }
+    .bookmark = Marcador na página { $page }
+    .break = { $count ->
+        [one] { $count } dia sem ler
+       *[other] { $count } dias sem ler
+    }
+    .undated = Sem data
@@ -27,6 +27,7 @@ recap = Итоги
 settings = Настройки
 github = GitHub
 reading-companion = Помощник чтения
+main-navigation = Основная навигация
 # Used in footer/sidebar for update time
 last-updated = Последнее обновление
 view-details = Подробнее
@@ -138,8 +139,10 @@ comics-finished = { $count ->
     [many] { comic-label } прочитано
    *[other] { comic-label } прочитано
 }
+items-started = Начато: { $count }
 unknown-book = Неизвестная книга
 unknown-author = Неизвестный автор
+file-removed = Файл удалён
 by = автор
 book-overview = Обзор книги
 comic-overview = Обзор комикса
@@ -148,8 +151,33 @@ comic-overview = Обзор комикса
 #            Book Details
 # -----------------------------------
 description = Описание
+    .read-more = Читать далее
+    .read-less = Свернуть
 publisher = Издательство
+date-added = Добавлено
+editors = { $count ->
+    [one] Редактор
+    [few] Редактора
+    [many] Редакторов
+   *[other] Редакторов
+}
+translators = { $count ->
+    [one] Переводчик
+    [few] Переводчика
+    [many] Переводчиков
+   *[other] Переводчиков
+}
 series = Серия
+series-page =
+    .all = Все серии
+    .empty = Серий пока нет
+    .empty-description = Серии появятся здесь, когда в вашей библиотеке будет достаточно их книг.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Все авторы
+    .empty = Авторов пока нет
+    .empty-description = Авторы появятся здесь, когда в вашей библиотеке будут книги с указанным автором.
+    .average-rating = Средняя оценка
 genres = Жанры
 language = Язык
 book-identifiers = Идентификаторы книги
@@ -168,9 +196,17 @@ notes-label = { $count ->
     [many] Заметок
    *[other] Заметок
 }
+bookmarks-label = { $count ->
+    [one] Закладка
+    [few] Закладки
+    [many] Закладок
+   *[other] Закладок
+}
 bookmarks = Закладки
 page-bookmark = Закладка страницы
 highlights-quotes = Выделения и цитаты
+highlight-of-the-day = Цитата дня
+up-next = Продолжить серию
 additional-information = Дополнительная информация
 reading-progress = Прогресс чтения
 page-number = Страница { $count }
@@ -197,9 +233,27 @@ weekly-statistics = Недельная статистика
 yearly-statistics = Годовая статистика
 total-read-time = Общее время чтения
 total-pages-read = Всего прочитано страниц
+unique-pages-read = Уникальных страниц прочитано
 pages-per-hour = Страниц/час
 # Abbreviation for Pages Per Hour
 pph-abbreviation = стр/ч
+reading-pace = Темп чтения
+    .median = Медиана
+weekday-split = Будни и выходные
+    .weekdays = Будни
+    .weekends = Выходные
+    .average-time = В среднем за день
+    .average-pages = Страниц в день
+    .reading-days = Дней чтения
+finished-over-time = Прочитано по периодам
+    .by-month = По месяцам
+    .by-year = По годам
+    .completions = { $count ->
+        [one] { $count } прочтение
+        [few] { $count } прочтения
+        [many] { $count } прочтений
+       *[other] { $count } прочтений
+    }
 reading-sessions-label = { $count ->
     [one] Сессия чтения
     [few] Сессии чтения
@@ -269,15 +323,44 @@ toggle =
     .show = Показать
 less = Меньше
 more = Больше
+heatmap-summary = { $days ->
+    [0] { $year }: чтение не записано.
+    [one] { $year }: { $days } день с чтением, всего { $time } и { $pages } стр.
+    [few] { $year }: { $days } дня с чтением, всего { $time } и { $pages } стр.
+   *[other] { $year }: { $days } дней с чтением, всего { $time } и { $pages } стр.
+}
+    .busiest = Больше всего чтения: { $date } ({ $time }).
 period = Период
 sessions = Сессии
 yearly-summary = Итог { $count } года
+recap-top-items = Лучшее за год
+    .highest-rated = С высшей оценкой
+    .longest = Больше всего времени
+    .ratings = Оценки
+recap-superlatives = Самые длинные и короткие
+    .longest-pages = Самая длинная книга
+    .shortest-pages = Самая короткая книга
+    .longest-time = Самое долгое чтение
+    .shortest-time = Самое быстрое чтение
+recap-compare = Сравнение лет
+    .change = Изменение
+    .need-two-years = Для сравнения нужны два года
+    .need-two-years-description = Завершите чтение хотя бы в двух разных годах, чтобы сравнить их рядом.
 recap-empty =
     .nothing-here = Пока ничего нет
     .try-switching = Попробуйте изменить область или год выше.
     .finish-reading = Завершите чтение в KoReader, чтобы увидеть ваши итоги.
     .info-question = Почему мои итоги не отображаются?
     .info-answer = KoShelf использует статистику чтения для определения завершений книг и комиксов, что позволяет отслеживать перечитывания. Простое пометка книги как "завершённой" без данных о чтении не приведёт к её появлению здесь.
+did-not-finish = Брошенные
+    .items = Брошено книг
+    .time-spent = Потрачено времени
+    .year-entry = { $count } · { $time }
+    .last-read = Последнее чтение: { $date }
+    .never-read = Не читалась
+recap-idle-month =
+    .nothing-finished = В этом месяце ничего не завершено
+    .no-reading = В этом месяце не было чтения
 stats-empty =
     .nothing-here = Пока ничего нет
     .start-reading = Начните читать в KoReader, чтобы увидеть вашу статистику здесь.
@@ -313,6 +396,14 @@ previous-month =
     .aria-label = Предыдущий месяц
 next-month =
     .aria-label = Следующий месяц
+previous-year =
+    .aria-label = Предыдущий год
+next-year =
+    .aria-label = Следующий год
+year-view =
+    .aria-label = Показать весь год
+month-view =
+    .aria-label = Показать календарь месяца
 search =
     .aria-label = Поиск
 close-search =
@@ -502,3 +593,32 @@ page-activity = Активность по страницам
     .legend-bookmark = Закладка
     .legend-chapter = Глава
     .info = Номера страниц зависят от текущих параметров форматирования документа. В отличие от других разделов KoShelf, это представление не поддерживает синтетическое масштабирование страниц.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Сочетания клавиш
+    .search = Поиск по библиотеке
+    .next-item = Следующая книга
+    .previous-item = Предыдущая книга
+    .go-to = Перейти: { $page }
+    .help = Показать сочетания клавиш
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = История чтения
+    .session = { $pages ->
+        [one] { $pages } страница
+        [few] { $pages } страницы
+        [many] { $pages } страниц
+       *[other] { $pages } страниц
+    } за { $duration }
+    .bookmark = Закладка на странице { $page }
+    .break = { $count ->
+        [one] { $count } день без чтения
+        [few] { $count } дня без чтения
+        [many] { $count } дней без чтения
+       *[other] { $count } дней без чтения
+    }
+    .undated = Без даты
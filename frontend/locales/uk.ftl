@@ -27,6 +27,7 @@ recap = Підсумки
 settings = Налаштування
 github = GitHub
 reading-companion = Супутник читання
+main-navigation = Основна навігація
 # Used in footer/sidebar for update time
 last-updated = Останнє оновлення
 view-details = Детальніше
@@ -138,8 +139,10 @@ comics-finished = { $count ->
     [many] { comic-label } прочитано
    *[other] { comic-label } прочитано
 }
+items-started = Розпочато: { $count }
 unknown-book = Невідома книга
 unknown-author = Невідомий автор
+file-removed = Файл видалено
 by = автор
 book-overview = Огляд книги
 comic-overview = Огляд коміксу
@@ -148,8 +151,33 @@ comic-overview = Огляд коміксу
 #            Book Details
 # -----------------------------------
 description = Опис
+    .read-more = Читати далі
+    .read-less = Згорнути
 publisher = Видавництво
+date-added = Додано
+editors = { $count ->
+    [one] Редактор
+    [few] Редактори
+    [many] Редакторів
+   *[other] Редакторів
+}
+translators = { $count ->
+    [one] Перекладач
+    [few] Перекладачі
+    [many] Перекладачів
+   *[other] Перекладачів
+}
 series = Серія
+series-page =
+    .all = Усі серії
+    .empty = Серій поки немає
+    .empty-description = Серії з'являться тут, коли у вашій бібліотеці буде достатньо їхніх книг.
+    .progress = { $completed } / { $total }
+author-page =
+    .all = Усі автори
+    .empty = Авторів поки немає
+    .empty-description = Автори з'являться тут, коли у вашій бібліотеці будуть книги із зазначеним автором.
+    .average-rating = Середня оцінка
 genres = Жанри
 language = Мова
 book-identifiers = Ідентифікатори книги
@@ -168,9 +196,17 @@ notes-label = { $count ->
     [many] Нотаток
    *[other] Нотаток
 }
+bookmarks-label = { $count ->
+    [one] Закладка
+    [few] Закладки
+    [many] Закладок
+   *[other] Закладок
+}
 bookmarks = Закладки
 page-bookmark = Закладка сторінки
 highlights-quotes = Виділення та цитати
+highlight-of-the-day = Цитата дня
+up-next = Продовжити серію
 additional-information = Додаткова інформація
 reading-progress = Прогрес читання
 page-number = Сторінка { $count }
@@ -197,9 +233,27 @@ weekly-statistics = Тижнева статистика
 yearly-statistics = Річна статистика
 total-read-time = Загальний час читання
 total-pages-read = Всього прочитано сторінок
+unique-pages-read = Унікальних сторінок прочитано
 pages-per-hour = Сторінок/година
 # Abbreviation for Pages Per Hour
 pph-abbreviation = стор/год
+reading-pace = Темп читання
+    .median = Медіана
+weekday-split = Будні та вихідні
+    .weekdays = Будні
+    .weekends = Вихідні
+    .average-time = У середньому за день
+    .average-pages = Сторінок на день
+    .reading-days = Днів читання
+finished-over-time = Прочитано за періодами
+    .by-month = За місяцями
+    .by-year = За роками
+    .completions = { $count ->
+        [one] { $count } прочитання
+        [few] { $count } прочитання
+        [many] { $count } прочитань
+       *[other] { $count } прочитань
+    }
 reading-sessions-label = { $count ->
     [one] Сесія читання
     [few] Сесії читання
@@ -269,15 +323,44 @@ toggle =
     .show = Показати
 less = Менше
 more = Більше
+heatmap-summary = { $days ->
+    [0] { $year }: читання не записано.
+    [one] { $year }: { $days } день з читанням, загалом { $time } і { $pages } стор.
+    [few] { $year }: { $days } дні з читанням, загалом { $time } і { $pages } стор.
+   *[other] { $year }: { $days } днів з читанням, загалом { $time } і { $pages } стор.
+}
+    .busiest = Найбільше читання: { $date } ({ $time }).
 period = Період
 sessions = Сесії
 yearly-summary = Річний підсумок { $count }
+recap-top-items = Найкраще за рік
+    .highest-rated = З найвищою оцінкою
+    .longest = Найбільше часу
+    .ratings = Оцінки
+recap-superlatives = Найдовші та найкоротші
+    .longest-pages = Найдовша книга
+    .shortest-pages = Найкоротша книга
+    .longest-time = Найдовше читання
+    .shortest-time = Найшвидше читання
+recap-compare = Порівняння років
+    .change = Зміна
+    .need-two-years = Для порівняння потрібні два роки
+    .need-two-years-description = Завершіть читання принаймні у двох різних роках, щоб порівняти їх поруч.
 recap-empty =
     .nothing-here = Поки нічого немає
     .try-switching = Спробуйте змінити область або рік вище.
     .finish-reading = Завершіть читання в KoReader, щоб побачити ваші підсумки.
     .info-question = Чому мої підсумки не відображаються?
     .info-answer = KoShelf використовує статистику читання для визначення завершень книг і коміксів, що дозволяє відстежувати перечитування. Просте позначення книги як "завершеної" без даних про читання не призведе до її появи тут.
+did-not-finish = Покинуті
+    .items = Покинуто книг
+    .time-spent = Витрачено часу
+    .year-entry = { $count } · { $time }
+    .last-read = Останнє читання: { $date }
+    .never-read = Не читалася
+recap-idle-month =
+    .nothing-finished = Цього місяця нічого не завершено
+    .no-reading = Цього місяця не було читання
 stats-empty =
     .nothing-here = Поки нічого немає
     .start-reading = Почніть читати в KoReader, щоб побачити вашу статистику тут.
@@ -313,6 +396,14 @@ previous-month =
     .aria-label = Попередній місяць
 next-month =
     .aria-label = Наступний місяць
+previous-year =
+    .aria-label = Попередній рік
+next-year =
+    .aria-label = Наступний рік
+year-view =
+    .aria-label = Показати весь рік
+month-view =
+    .aria-label = Показати календар місяця
 search =
     .aria-label = Пошук
 close-search =
@@ -502,3 +593,32 @@ page-activity = Активність по сторінках
     .legend-bookmark = Закладка
     .legend-chapter = Розділ
     .info = Номери сторінок залежать від поточних параметрів форматування документа. На відміну від інших розділів KoShelf, це подання не підтримує синтетичне масштабування сторінок.
+
+# -----------------------------------
+#         Keyboard Shortcuts
+# -----------------------------------
+keyboard-shortcuts = Клавіатурні скорочення
+    .search = Пошук у бібліотеці
+    .next-item = Наступна книга
+    .previous-item = Попередня книга
+    .go-to = Перейти: { $page }
+    .help = Показати клавіатурні скорочення
+
+# -----------------------------------
+#          Reading Timeline
+# -----------------------------------
+reading-timeline = Історія читання
+    .session = { $pages ->
+        [one] { $pages } сторінка
+        [few] { $pages } сторінки
+        [many] { $pages } сторінок
+       *[other] { $pages } сторінок
+    } за { $duration }
+    .bookmark = Закладка на сторінці { $page }
+    .break = { $count ->
+        [one] { $count } день без читання
+        [few] { $count } дні без читання
+        [many] { $count } днів без читання
+       *[other] { $count } днів без читання
+    }
+    .undated = Без дати
@@ -103,6 +103,7 @@ export function App() {
             navItems={navItems}
             currentPath={location.pathname}
             siteTitle={siteTitle}
+            logoUrl={site?.logo_url}
             generatedAt={site?.generated_at}
             version={site?.version}
         >
@@ -5,12 +5,19 @@ import { RouteScrollRestoration } from '../../shared/lib/navigation/RouteScrollR
 import { LoadingSpinner } from '../../shared/ui/feedback/LoadingSpinner';
 import { PageContent } from '../../shared/ui/layout/PageContent';
 import {
+    AuthorDetailRoute,
+    AuthorListRoute,
     CalendarRoute,
+    CalendarYearRoute,
     LibraryDetailRoute,
     LibraryListRoute,
     LoginRoute,
     ReaderRoute,
+    RecapCompareRoute,
+    RecapMonthRoute,
     RecapRoute,
+    SeriesDetailRoute,
+    SeriesListRoute,
     SettingsRoute,
     StatisticsRoute,
 } from './lazy-routes';
@@ -86,6 +93,10 @@ export function AppRoutes({
                         path={routePathPattern('calendar')}
                         element={<CalendarRoute />}
                     />
+                    <Route
+                        path={routePathPattern('calendar-year')}
+                        element={<CalendarYearRoute />}
+                    />
                     <Route
                         path={routePathPattern('settings')}
                         element={<SettingsRoute />}
@@ -114,10 +125,34 @@ export function AppRoutes({
                         path={routePathPattern('comics-read')}
                         element={<ReaderRoute collection="comics" />}
                     />
+                    <Route
+                        path={routePathPattern('series-list')}
+                        element={<SeriesListRoute />}
+                    />
+                    <Route
+                        path={routePathPattern('series-detail')}
+                        element={<SeriesDetailRoute />}
+                    />
+                    <Route
+                        path={routePathPattern('authors-list')}
+                        element={<AuthorListRoute />}
+                    />
+                    <Route
+                        path={routePathPattern('author-detail')}
+                        element={<AuthorDetailRoute />}
+                    />
                     <Route
                         path={routePathPattern('recap')}
                         element={<RecapRoute />}
                     />
+                    <Route
+                        path={routePathPattern('recap-compare')}
+                        element={<RecapCompareRoute />}
+                    />
+                    <Route
+                        path={routePathPattern('recap-month')}
+                        element={<RecapMonthRoute />}
+                    />
 
                     <Route
                         path="*"
@@ -53,18 +53,59 @@ const importLibraryDetailRoute = async () => {
     return { default: module.LibraryDetailRoute };
 };
 
+const importSeriesListRoute = async () => {
+    const module = await import('../../features/series/routes/SeriesListRoute');
+    return { default: module.SeriesListRoute };
+};
+
+const importSeriesDetailRoute = async () => {
+    const module =
+        await import('../../features/series/routes/SeriesDetailRoute');
+    return { default: module.SeriesDetailRoute };
+};
+
+const importAuthorListRoute = async () => {
+    const module =
+        await import('../../features/authors/routes/AuthorListRoute');
+    return { default: module.AuthorListRoute };
+};
+
+const importAuthorDetailRoute = async () => {
+    const module =
+        await import('../../features/authors/routes/AuthorDetailRoute');
+    return { default: module.AuthorDetailRoute };
+};
+
 const importReaderRoute = async () => {
     const module = await import('../../features/reader/routes/ReaderRoute');
     return { default: module.ReaderRoute };
 };
 
+const importCalendarYearRoute = async () => {
+    const module =
+        await import('../../features/calendar/routes/CalendarYearRoute');
+    return { default: module.CalendarYearRoute };
+};
+
 const importRecapRoute = async () => {
     const module = await import('../../features/recap/routes/RecapRoute');
     return { default: module.RecapRoute };
 };
 
+const importRecapCompareRoute = async () => {
+    const module =
+        await import('../../features/recap/routes/RecapCompareRoute');
+    return { default: module.RecapCompareRoute };
+};
+
+const importRecapMonthRoute = async () => {
+    const module = await import('../../features/recap/routes/RecapMonthRoute');
+    return { default: module.RecapMonthRoute };
+};
+
 export const StatisticsRoute = lazyWithPreload(importStatisticsRoute);
 export const CalendarRoute = lazyWithPreload(importCalendarRoute);
+export const CalendarYearRoute = lazyWithPreload(importCalendarYearRoute);
 export const SettingsRoute = lazyWithPreload(importSettingsRoute);
 export const LoginRoute = lazyWithPreload(importLoginRoute);
 export const LibraryListRoute = lazyWithPreload<{
@@ -76,7 +117,13 @@ export const LibraryDetailRoute = lazyWithPreload<{
 export const ReaderRoute = lazyWithPreload<{
     collection: LibraryCollectionRoute;
 }>(importReaderRoute);
+export const SeriesListRoute = lazyWithPreload(importSeriesListRoute);
+export const SeriesDetailRoute = lazyWithPreload(importSeriesDetailRoute);
+export const AuthorListRoute = lazyWithPreload(importAuthorListRoute);
+export const AuthorDetailRoute = lazyWithPreload(importAuthorDetailRoute);
 export const RecapRoute = lazyWithPreload(importRecapRoute);
+export const RecapCompareRoute = lazyWithPreload(importRecapCompareRoute);
+export const RecapMonthRoute = lazyWithPreload(importRecapMonthRoute);
 
 const preloadedRoutePromises = new Map<RouteId, Promise<void>>();
 const PRELOADERS_BY_ROUTE: Record<RouteId, Array<() => Promise<void>>> = {
@@ -84,6 +131,7 @@ const PRELOADERS_BY_ROUTE: Record<RouteId, Array<() => Promise<void>>> = {
     login: [LoginRoute.preload],
     statistics: [StatisticsRoute.preload],
     calendar: [CalendarRoute.preload],
+    'calendar-year': [CalendarYearRoute.preload],
     settings: [SettingsRoute.preload],
     'books-list': [LibraryListRoute.preload],
     'books-detail': [LibraryDetailRoute.preload],
@@ -91,7 +139,13 @@ const PRELOADERS_BY_ROUTE: Record<RouteId, Array<() => Promise<void>>> = {
     'comics-list': [LibraryListRoute.preload],
     'comics-detail': [LibraryDetailRoute.preload],
     'comics-read': [ReaderRoute.preload],
+    'series-list': [SeriesListRoute.preload],
+    'series-detail': [SeriesDetailRoute.preload],
+    'authors-list': [AuthorListRoute.preload],
+    'author-detail': [AuthorDetailRoute.preload],
     recap: [RecapRoute.preload],
+    'recap-compare': [RecapCompareRoute.preload],
+    'recap-month': [RecapMonthRoute.preload],
 };
 
 export function preloadRoute(routeId: RouteId): Promise<void> {
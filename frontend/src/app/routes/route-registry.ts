@@ -5,6 +5,7 @@ export type RouteId =
     | 'login'
     | 'statistics'
     | 'calendar'
+    | 'calendar-year'
     | 'settings'
     | 'books-list'
     | 'books-detail'
@@ -12,7 +13,13 @@ export type RouteId =
     | 'comics-list'
     | 'comics-detail'
     | 'comics-read'
-    | 'recap';
+    | 'series-list'
+    | 'series-detail'
+    | 'authors-list'
+    | 'author-detail'
+    | 'recap'
+    | 'recap-compare'
+    | 'recap-month';
 
 export type MainRouteId =
     | 'statistics'
@@ -23,6 +30,8 @@ export type MainRouteId =
     | 'recap';
 export type DetailRouteId = 'books-detail' | 'comics-detail';
 export type ReaderRouteId = 'books-read' | 'comics-read';
+export type SeriesRouteId = 'series-list' | 'series-detail';
+export type AuthorRouteId = 'authors-list' | 'author-detail';
 export type LibraryCollectionRoute = 'books' | 'comics';
 type LibraryContentTypeRoute = 'book' | 'comic';
 
@@ -37,6 +46,11 @@ const ROUTE_DEFINITIONS: readonly RouteDefinition[] = [
     { id: 'login', path: '/login', mainRouteId: null },
     { id: 'statistics', path: '/statistics', mainRouteId: 'statistics' },
     { id: 'calendar', path: '/calendar', mainRouteId: 'calendar' },
+    {
+        id: 'calendar-year',
+        path: '/calendar/:year',
+        mainRouteId: 'calendar',
+    },
     { id: 'settings', path: '/settings', mainRouteId: 'settings' },
     { id: 'books-list', path: '/books', mainRouteId: 'books-list' },
     { id: 'books-detail', path: '/books/:id', mainRouteId: 'books-list' },
@@ -44,7 +58,21 @@ const ROUTE_DEFINITIONS: readonly RouteDefinition[] = [
     { id: 'comics-list', path: '/comics', mainRouteId: 'comics-list' },
     { id: 'comics-detail', path: '/comics/:id', mainRouteId: 'comics-list' },
     { id: 'comics-read', path: '/comics/:id/read', mainRouteId: 'comics-list' },
+    { id: 'series-list', path: '/series', mainRouteId: 'books-list' },
+    { id: 'series-detail', path: '/series/:id', mainRouteId: 'books-list' },
+    { id: 'authors-list', path: '/authors', mainRouteId: 'books-list' },
+    { id: 'author-detail', path: '/authors/:id', mainRouteId: 'books-list' },
     { id: 'recap', path: '/recap', mainRouteId: 'recap' },
+    {
+        id: 'recap-compare',
+        path: '/recap/compare',
+        mainRouteId: 'recap',
+    },
+    {
+        id: 'recap-month',
+        path: '/recap/:year/:month',
+        mainRouteId: 'recap',
+    },
 ] as const;
 
 const ROUTE_DEFINITION_BY_ID: Record<RouteId, RouteDefinition> =
@@ -61,7 +89,11 @@ const MAIN_ROUTE_IDS = [
     'recap',
 ] as const;
 
-export type ScrollableRouteId = MainRouteId | DetailRouteId;
+export type ScrollableRouteId =
+    | MainRouteId
+    | DetailRouteId
+    | SeriesRouteId
+    | AuthorRouteId;
 
 const READER_ROUTE_IDS: ReadonlySet<string> = new Set<string>([
     'books-read',
@@ -72,6 +104,10 @@ const SCROLLABLE_ROUTE_IDS: ReadonlySet<string> = new Set<string>([
     ...MAIN_ROUTE_IDS,
     'books-detail',
     'comics-detail',
+    'series-list',
+    'series-detail',
+    'authors-list',
+    'author-detail',
 ]);
 
 export function isScrollableRouteId(
@@ -95,24 +131,34 @@ function normalizePathname(pathname: string): string {
     return pathname.replace(/\/+$/, '') || '/';
 }
 
-export function buildRoutePath(
-    routeId: RouteId,
-    params?: {
-        id?: string;
-    },
-): string {
+type RouteParams = {
+    id?: string;
+    year?: string;
+    month?: string;
+};
+
+export function buildRoutePath(routeId: RouteId, params?: RouteParams): string {
     const route = ROUTE_DEFINITION_BY_ID[routeId];
-    if (route.path.includes(':id')) {
-        const id = params?.id;
-        if (!id) {
+    const paramNames = Array.from(
+        route.path.matchAll(/:(\w+)/g),
+        (match) => match[1] as keyof RouteParams,
+    );
+    if (paramNames.length === 0) {
+        return route.path;
+    }
+
+    const values: Record<string, string> = {};
+    for (const name of paramNames) {
+        const value = params?.[name];
+        if (!value) {
             throw new Error(
-                `Missing required route param "id" for route ${routeId}`,
+                `Missing required route param "${name}" for route ${routeId}`,
             );
         }
-        return generatePath(route.path, { id });
+        values[name] = value;
     }
 
-    return route.path;
+    return generatePath(route.path, values);
 }
 
 export function routePathPattern(routeId: RouteId): string {
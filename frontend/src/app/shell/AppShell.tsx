@@ -1,5 +1,5 @@
 import { useQueryClient } from '@tanstack/react-query';
-import { useEffect, type ReactNode } from 'react';
+import { useCallback, useEffect, useState, type ReactNode } from 'react';
 
 import {
     libraryDetailCacheKey,
@@ -20,15 +20,18 @@ import { readStoredRecapScope } from '../../features/recap/model/recap-model';
 import { getPrefetchOnIntentPreference } from '../../shared/lib/network/prefetch-preference';
 import { shouldPrefetchOnCurrentConnection } from '../../shared/lib/network/prefetch-guards';
 import { matchRouteByHref, preloadRoute } from '../routes/lazy-routes';
+import { KeyboardShortcutsDialog } from './KeyboardShortcutsDialog';
 import { RouteHeaderProvider } from './route-header';
 import { ShellMobileNav } from './ShellMobileNav';
 import { ShellSidebar } from './ShellSidebar';
 import type { NavItem } from './shell-nav';
+import { useKeyboardShortcuts } from './useKeyboardShortcuts';
 
 type AppShellProps = {
     navItems: NavItem[];
     currentPath: string;
     siteTitle: string;
+    logoUrl?: string;
     generatedAt?: string;
     version?: string;
     children: ReactNode;
@@ -38,11 +41,18 @@ export function AppShell({
     navItems,
     currentPath,
     siteTitle,
+    logoUrl,
     generatedAt,
     version,
     children,
 }: AppShellProps) {
     const queryClient = useQueryClient();
+    const [shortcutsOpen, setShortcutsOpen] = useState(false);
+    const toggleShortcuts = useCallback(
+        () => setShortcutsOpen((open) => !open),
+        [],
+    );
+    useKeyboardShortcuts(navItems, toggleShortcuts);
 
     useEffect(() => {
         const inFlightDetailPrefetches = new Set<string>();
@@ -79,7 +89,11 @@ export function AppShell({
                 return;
             }
 
-            if (routeId === 'recap') {
+            if (
+                routeId === 'recap' ||
+                routeId === 'recap-compare' ||
+                routeId === 'recap-month'
+            ) {
                 const recapScope = readStoredRecapScope();
                 void prefetchRecapIndexQuery(queryClient, recapScope);
                 return;
@@ -209,6 +223,7 @@ export function AppShell({
                 navItems={navItems}
                 currentPath={currentPath}
                 siteTitle={siteTitle}
+                logoUrl={logoUrl}
                 generatedAt={generatedAt}
                 version={version}
             />
@@ -218,8 +233,14 @@ export function AppShell({
                 currentPath={currentPath}
                 siteTitle={siteTitle}
             >
-                <div className="min-h-full lg:ml-64">{children}</div>
+                <div className="min-h-full lg:ml-64 print:ml-0">{children}</div>
             </RouteHeaderProvider>
+
+            <KeyboardShortcutsDialog
+                open={shortcutsOpen}
+                onClose={() => setShortcutsOpen(false)}
+                navItems={navItems}
+            />
         </div>
     );
 }
@@ -0,0 +1,83 @@
+import type { ReactNode } from 'react';
+
+import { translation } from '../../shared/i18n';
+import { ModalShell } from '../../shared/ui/modal/ModalShell';
+import type { NavItem } from './shell-nav';
+
+type KeyboardShortcutsDialogProps = {
+    open: boolean;
+    onClose: () => void;
+    navItems: NavItem[];
+};
+
+function Key({ children }: { children: ReactNode }) {
+    return (
+        <kbd className="min-w-6 px-1.5 py-0.5 rounded-md border border-gray-300 dark:border-dark-600 bg-gray-100 dark:bg-dark-800 text-xs font-mono text-center text-gray-700 dark:text-dark-200">
+            {children}
+        </kbd>
+    );
+}
+
+function ShortcutRow({ keys, label }: { keys: string[]; label: string }) {
+    return (
+        <li className="flex items-center justify-between gap-4 py-1.5">
+            <span className="text-sm text-gray-700 dark:text-dark-200">
+                {label}
+            </span>
+            <span className="flex items-center gap-1 shrink-0">
+                {keys.map((key) => (
+                    <Key key={key}>{key}</Key>
+                ))}
+            </span>
+        </li>
+    );
+}
+
+export function KeyboardShortcutsDialog({
+    open,
+    onClose,
+    navItems,
+}: KeyboardShortcutsDialogProps) {
+    return (
+        <ModalShell
+            open={open}
+            onClose={onClose}
+            cardClassName="max-w-md bg-white/95 dark:bg-dark-900/90 border border-gray-200/70 dark:border-dark-600/50 rounded-2xl shadow-2xl"
+        >
+            <div className="p-6">
+                <h2 className="text-lg font-semibold text-gray-900 dark:text-white mb-4">
+                    {translation.get('keyboard-shortcuts')}
+                </h2>
+                <ul className="divide-y divide-gray-200/70 dark:divide-dark-700/60">
+                    <ShortcutRow
+                        keys={['/']}
+                        label={translation.get('keyboard-shortcuts.search')}
+                    />
+                    <ShortcutRow
+                        keys={['j']}
+                        label={translation.get('keyboard-shortcuts.next-item')}
+                    />
+                    <ShortcutRow
+                        keys={['k']}
+                        label={translation.get(
+                            'keyboard-shortcuts.previous-item',
+                        )}
+                    />
+                    {navItems.map((item) => (
+                        <ShortcutRow
+                            key={item.routeId}
+                            keys={['g', item.shortcut]}
+                            label={translation.get('keyboard-shortcuts.go-to', {
+                                page: item.label,
+                            })}
+                        />
+                    ))}
+                    <ShortcutRow
+                        keys={['?']}
+                        label={translation.get('keyboard-shortcuts.help')}
+                    />
+                </ul>
+            </div>
+        </ModalShell>
+    );
+}
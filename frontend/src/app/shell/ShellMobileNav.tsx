@@ -1,5 +1,6 @@
 import { Link } from 'react-router';
 
+import { translation } from '../../shared/i18n';
 import { isActivePath, type NavItem } from './shell-nav';
 
 type ShellMobileNavProps = {
@@ -15,7 +16,8 @@ export function ShellMobileNav({ navItems, currentPath }: ShellMobileNavProps) {
     return (
         <nav
             data-shell-nav
-            className="lg:hidden fixed bottom-4 left-8 right-8 z-50"
+            className="lg:hidden print:hidden fixed bottom-4 left-8 right-8 z-50"
+            aria-label={translation.get('main-navigation')}
         >
             <div className="bg-white/75 dark:bg-dark-950/75 backdrop-blur-xs border border-gray-200/50 dark:border-dark-700/50 rounded-2xl px-2 py-1.5 shadow-2xl">
                 <div className="flex items-center justify-around overflow-x-auto scrollbar-hide">
@@ -28,6 +30,7 @@ export function ShellMobileNav({ navItems, currentPath }: ShellMobileNavProps) {
                                 key={item.href}
                                 id={item.id}
                                 to={item.href}
+                                aria-current={active ? 'page' : undefined}
                                 className={`nav-item flex flex-col items-center py-1.5 px-2 rounded-xl min-w-fit ${active ? 'nav-item-active' : ''}`}
                             >
                                 <ItemIcon
@@ -8,6 +8,7 @@ type ShellSidebarProps = {
     navItems: NavItem[];
     currentPath: string;
     siteTitle: string;
+    logoUrl?: string;
     generatedAt?: string;
     version?: string;
 };
@@ -16,6 +17,7 @@ export function ShellSidebar({
     navItems,
     currentPath,
     siteTitle,
+    logoUrl,
     generatedAt,
     version,
 }: ShellSidebarProps) {
@@ -30,27 +32,45 @@ export function ShellSidebar({
     const settingsActive = isActivePath(currentPath, 'settings');
 
     return (
-        <aside className="hidden lg:flex fixed left-0 top-0 bottom-0 w-64 bg-white/90 dark:bg-dark-950/75 backdrop-blur-xs border-r border-gray-200/50 dark:border-dark-700/50 flex-col z-30">
+        <aside className="hidden lg:flex print:hidden fixed left-0 top-0 bottom-0 w-64 bg-white/90 dark:bg-dark-950/75 backdrop-blur-xs border-r border-gray-200/50 dark:border-dark-700/50 flex-col z-30">
             <div className="py-4 px-6 border-b border-gray-200/50 dark:border-dark-700/50">
-                <div className="flex items-center space-x-3">
-                    <div className="w-10 h-10 bg-linear-to-br from-primary-400 to-primary-600 rounded-xl flex items-center justify-center shadow-lg">
-                        <BrandIcon
-                            className="w-6 h-6 text-white"
-                            aria-hidden="true"
-                        />
-                    </div>
-                    <div>
-                        <h1 className="text-xl font-bold text-gray-900 dark:text-white">
-                            {siteTitle}
+                <Link
+                    to="/"
+                    className="flex items-center space-x-3 rounded-xl focus-visible:outline-hidden focus-visible:ring-2 focus-visible:ring-primary-500/50"
+                >
+                    {logoUrl ? (
+                        <h1 className="min-w-0">
+                            <img
+                                src={logoUrl}
+                                alt={siteTitle}
+                                className="block h-10 w-auto max-w-full object-contain"
+                            />
                         </h1>
-                        <p className="text-xs font-medium text-gray-500 dark:text-dark-400 mt-0.5">
-                            {translation.get('reading-companion')}
-                        </p>
-                    </div>
-                </div>
+                    ) : (
+                        <>
+                            <div className="w-10 h-10 bg-linear-to-br from-primary-400 to-primary-600 rounded-xl flex items-center justify-center shadow-lg">
+                                <BrandIcon
+                                    className="w-6 h-6 text-white"
+                                    aria-hidden="true"
+                                />
+                            </div>
+                            <div>
+                                <h1 className="text-xl font-bold text-gray-900 dark:text-white">
+                                    {siteTitle}
+                                </h1>
+                                <p className="text-xs font-medium text-gray-500 dark:text-dark-400 mt-0.5">
+                                    {translation.get('reading-companion')}
+                                </p>
+                            </div>
+                        </>
+                    )}
+                </Link>
             </div>
 
-            <nav className="flex-1 px-4 py-6 space-y-3">
+            <nav
+                className="flex-1 px-4 py-6 space-y-3"
+                aria-label={translation.get('main-navigation')}
+            >
                 {primaryNavItems.map((item) => {
                     const active = isActivePath(currentPath, item.routeId);
                     const ItemIcon = item.icon;
@@ -60,6 +80,7 @@ export function ShellSidebar({
                             key={item.href}
                             id={item.id}
                             to={item.href}
+                            aria-current={active ? 'page' : undefined}
                             className={`sidebar-item-modern group ${active ? 'sidebar-item-modern-active' : ''}`}
                         >
                             <div
@@ -95,7 +116,8 @@ export function ShellSidebar({
             <div className="mt-auto px-4 py-4 border-t border-gray-200/50 dark:border-dark-700/50 space-y-3">
                 <Link
                     to="/settings"
-                    className={`block rounded-lg p-3 transition-all duration-200 border ${
+                    aria-current={settingsActive ? 'page' : undefined}
+                    className={`block rounded-lg p-3 transition-all duration-200 border focus-visible:outline-hidden focus-visible:ring-2 focus-visible:ring-primary-500/50 ${
                         settingsActive
                             ? 'bg-primary-50/50 dark:bg-primary-900/20 border-primary-200/50 dark:border-primary-700/50'
                             : 'bg-gray-100/50 dark:bg-dark-900/50 border-gray-200/50 dark:border-dark-700/50 hover:bg-gray-200/50 dark:hover:bg-dark-800/50'
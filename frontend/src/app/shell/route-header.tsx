@@ -14,6 +14,7 @@ function resolveFallbackTitle(pathname: string, siteTitle: string): string {
         case 'statistics':
             return translation.get('reading-statistics');
         case 'calendar':
+        case 'calendar-year':
             return translation.get('calendar');
         case 'settings':
             return translation.get('settings');
@@ -26,6 +27,8 @@ function resolveFallbackTitle(pathname: string, siteTitle: string): string {
         case 'comics-read':
             return translation.get('comics');
         case 'recap':
+        case 'recap-compare':
+        case 'recap-month':
             return translation.get('recap');
         default:
             return siteTitle;
@@ -91,7 +94,7 @@ export function RouteHeaderProvider({
 
     return (
         <RouteHeaderContext.Provider value={contextValue}>
-            <header className="fixed top-0 left-0 right-0 lg:left-64 bg-white/90 dark:bg-dark-950/75 backdrop-blur-xs border-b border-gray-200/50 dark:border-dark-700/50 px-4 md:px-6 h-[70px] md:h-[80px] z-40">
+            <header className="fixed print:static top-0 left-0 right-0 lg:left-64 bg-white/90 dark:bg-dark-950/75 backdrop-blur-xs border-b border-gray-200/50 dark:border-dark-700/50 px-4 md:px-6 h-[70px] md:h-[80px] z-40">
                 <div className="flex items-center justify-between h-full">
                     <div className="lg:hidden flex items-center min-w-0 flex-1">
                         {activeHeader.mobileContent}
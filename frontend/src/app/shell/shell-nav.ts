@@ -20,6 +20,8 @@ export type NavItem = {
     label: string;
     href: string;
     icon: IconType;
+    /** Key that follows `g` to jump here, e.g. `g s` for statistics. */
+    shortcut: string;
 };
 
 const ICONS = {
@@ -44,6 +46,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
             routeId: 'books-list',
             label: translation.get('books'),
             href: '/books',
+            shortcut: 'b',
             icon: ICONS.books,
         });
     }
@@ -53,6 +56,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
             routeId: 'comics-list',
             label: translation.get('comics'),
             href: '/comics',
+            shortcut: 'm',
             icon: ICONS.comics,
         });
     }
@@ -63,6 +67,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
             routeId: 'statistics',
             label: translation.get('statistics'),
             href: '/statistics',
+            shortcut: 's',
             icon: ICONS.statistics,
         });
 
@@ -70,6 +75,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
             routeId: 'calendar',
             label: translation.get('calendar'),
             href: '/calendar',
+            shortcut: 'c',
             icon: ICONS.calendar,
         });
 
@@ -78,6 +84,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
             routeId: 'recap',
             label: translation.get('recap'),
             href: '/recap',
+            shortcut: 'r',
             icon: ICONS.recap,
         });
     }
@@ -87,6 +94,7 @@ export function buildNavItems(site: SiteData | undefined): NavItem[] {
         routeId: 'settings',
         label: translation.get('settings'),
         href: '/settings',
+        shortcut: ',',
         icon: ICONS.settings,
     });
 
@@ -0,0 +1,137 @@
+import { useEffect, useRef } from 'react';
+import { useNavigate } from 'react-router';
+
+import { isTypingTarget } from '../../shared/lib/dom/is-typing-target';
+import type { NavItem } from './shell-nav';
+
+/** How long after `g` the second key of a jump is accepted. */
+const GO_PREFIX_TIMEOUT_MS = 1500;
+
+const CARD_SELECTOR = '.book-card';
+
+function isRightToLeft(): boolean {
+    return getComputedStyle(document.documentElement).direction === 'rtl';
+}
+
+/**
+ * Move focus to the next (`step = 1`) or previous (`step = -1`) library card.
+ * Without a focused card, `j` starts at the first card and `k` at the last.
+ * Returns whether a card was focused.
+ */
+function focusAdjacentCard(
+    step: 1 | -1,
+    requireFocusedCard: boolean,
+): boolean {
+    const cards = Array.from(
+        document.querySelectorAll<HTMLElement>(CARD_SELECTOR),
+    ).filter((card) => card.offsetParent !== null);
+    if (cards.length === 0) {
+        return false;
+    }
+
+    const active = document.activeElement;
+    const currentCard =
+        active instanceof Element ? active.closest(CARD_SELECTOR) : null;
+    const currentIndex = currentCard
+        ? cards.indexOf(currentCard as HTMLElement)
+        : -1;
+    if (currentIndex === -1 && requireFocusedCard) {
+        return false;
+    }
+
+    const nextIndex =
+        currentIndex === -1
+            ? step === 1
+                ? 0
+                : cards.length - 1
+            : Math.min(Math.max(currentIndex + step, 0), cards.length - 1);
+    const link = cards[nextIndex]?.querySelector<HTMLElement>('a[href]');
+    if (!link) {
+        return false;
+    }
+
+    link.focus();
+    link.scrollIntoView({ block: 'nearest' });
+    return true;
+}
+
+/**
+ * Site-wide keyboard shortcuts: `j`/`k` (and the arrow keys once a card has
+ * focus) move between library cards, `g` followed by a nav item's shortcut
+ * jumps to that page, and `?` toggles the help dialog. `/` to search is
+ * handled by the library list itself. Nothing fires while typing in a field.
+ */
+export function useKeyboardShortcuts(
+    navItems: NavItem[],
+    onToggleHelp: () => void,
+): void {
+    const navigate = useNavigate();
+    // Kept across re-subscriptions, which happen whenever the nav items do.
+    const goPrefixAtRef = useRef(0);
+
+    useEffect(() => {
+        const handleKeyDown = (event: KeyboardEvent): void => {
+            if (
+                event.defaultPrevented ||
+                event.ctrlKey ||
+                event.metaKey ||
+                event.altKey ||
+                isTypingTarget(event.target)
+            ) {
+                return;
+            }
+
+            const goPrefixAt = goPrefixAtRef.current;
+            if (goPrefixAt > 0) {
+                const withinTimeout =
+                    event.timeStamp - goPrefixAt <= GO_PREFIX_TIMEOUT_MS;
+                goPrefixAtRef.current = 0;
+                const target = withinTimeout
+                    ? navItems.find((item) => item.shortcut === event.key)
+                    : undefined;
+                if (target) {
+                    event.preventDefault();
+                    navigate(target.href);
+                    return;
+                }
+            }
+
+            switch (event.key) {
+                case 'g':
+                    goPrefixAtRef.current = event.timeStamp;
+                    break;
+                case '?':
+                    event.preventDefault();
+                    onToggleHelp();
+                    break;
+                case 'j':
+                    if (focusAdjacentCard(1, false)) {
+                        event.preventDefault();
+                    }
+                    break;
+                case 'k':
+                    if (focusAdjacentCard(-1, false)) {
+                        event.preventDefault();
+                    }
+                    break;
+                case 'ArrowRight':
+                case 'ArrowLeft': {
+                    // "Forward" is to the left in right-to-left layouts.
+                    const forward =
+                        (event.key === 'ArrowRight') !== isRightToLeft();
+                    if (focusAdjacentCard(forward ? 1 : -1, true)) {
+                        event.preventDefault();
+                    }
+                    break;
+                }
+                default:
+                    break;
+            }
+        };
+
+        document.addEventListener('keydown', handleKeyDown);
+        return () => {
+            document.removeEventListener('keydown', handleKeyDown);
+        };
+    }, [navItems, navigate, onToggleHelp]);
+}
@@ -0,0 +1,19 @@
+import { keepPreviousData, useQuery } from '@tanstack/react-query';
+
+import { api } from '../../../shared/api';
+
+export function useAuthorListQuery() {
+    return useQuery({
+        queryKey: ['author-list'] as const,
+        queryFn: () => api.getAuthors(),
+    });
+}
+
+export function useAuthorDetailQuery(slug: string | undefined) {
+    return useQuery({
+        queryKey: ['author-detail', slug] as const,
+        queryFn: () => api.getAuthor(slug ?? ''),
+        enabled: Boolean(slug),
+        placeholderData: keepPreviousData,
+    });
+}
@@ -0,0 +1,130 @@
+import { Link, useParams } from 'react-router';
+import { LuCheck, LuClock, LuLibrary, LuStar } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { useBookCardTiltEffect } from '../../../shared/lib/dom/useTiltEffect';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { buttonVariants } from '../../../shared/ui/button/button-variants';
+import { MetricCard } from '../../../shared/ui/cards/MetricCard';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { LibraryCard } from '../../library/components/LibraryCard';
+import { sectionFromLibraryItem } from '../../library/model/library-model';
+import { useAuthorDetailQuery } from '../hooks/useAuthorQueries';
+
+export function AuthorDetailRoute() {
+    const params = useParams();
+    const slug = params.id;
+    const { siteQuery } = useSiteQuery();
+    const detailQuery = useAuthorDetailQuery(slug);
+    const detail = detailQuery.data;
+    const author = detail?.author;
+    const title = author?.name ?? translation.get('author-page.all');
+    const averageRating =
+        typeof author?.average_rating === 'number'
+            ? formatNumber(author.average_rating, { maximumFractionDigits: 1 })
+            : '–';
+    useDocumentTitle(title, siteQuery.data?.title);
+    useBookCardTiltEffect(`author:${slug}:${detail?.items.length ?? 0}`);
+
+    return (
+        <>
+            <PageHeader
+                title={title}
+                controls={
+                    <Link
+                        to={buildRoutePath('authors-list')}
+                        className={buttonVariants({ variant: 'neutral' })}
+                    >
+                        {translation.get('author-page.all')}
+                    </Link>
+                }
+            />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={detailQuery.isError}
+                    error={detailQuery.error}
+                    onRetry={() => void detailQuery.refetch()}
+                    showBlockingSpinner={detailQuery.isLoading}
+                    showOverlaySpinner={detailQuery.isPlaceholderData}
+                    hasData={Boolean(detail)}
+                    srLabel="Loading author"
+                    renderContent={() =>
+                        author && detail ? (
+                            <>
+                                <div className="flex flex-wrap gap-3 sm:gap-4">
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuLibrary}
+                                        iconContainerClassName="bg-cyan-500/20 dark:bg-linear-to-br dark:from-cyan-500 dark:to-cyan-600"
+                                        iconClassName="text-cyan-600 dark:text-white"
+                                        value={formatNumber(author.item_count)}
+                                        label={translation.get(
+                                            'book-label',
+                                            author.item_count,
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuCheck}
+                                        iconContainerClassName="bg-green-500/20 dark:bg-linear-to-br dark:from-green-500 dark:to-green-600"
+                                        iconClassName="text-green-600 dark:text-white"
+                                        value={formatNumber(
+                                            author.completed_count,
+                                        )}
+                                        label={translation.get(
+                                            'status.completed',
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuStar}
+                                        iconContainerClassName="bg-yellow-500/20 dark:bg-linear-to-br dark:from-yellow-500 dark:to-yellow-600"
+                                        iconClassName="text-yellow-600 dark:text-white"
+                                        value={averageRating}
+                                        label={translation.get(
+                                            'author-page.average-rating',
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuClock}
+                                        iconContainerClassName="bg-indigo-500/20 dark:bg-linear-to-br dark:from-indigo-500 dark:to-indigo-600"
+                                        iconClassName="text-indigo-600 dark:text-white"
+                                        value={formatDuration(
+                                            author.total_reading_time_sec,
+                                        )}
+                                        label={translation.get('reading-time')}
+                                    />
+                                </div>
+
+                                <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-5 xl:grid-cols-6 2xl:grid-cols-8 gap-4 md:gap-6">
+                                    {detail.items.map((item) => (
+                                        <LibraryCard
+                                            key={item.id}
+                                            item={item}
+                                            collection={
+                                                item.content_type === 'comic'
+                                                    ? 'comics'
+                                                    : 'books'
+                                            }
+                                            sectionKey={sectionFromLibraryItem(
+                                                item,
+                                            )}
+                                        />
+                                    ))}
+                                </div>
+                            </>
+                        ) : null
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
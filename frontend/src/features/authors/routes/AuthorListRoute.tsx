@@ -0,0 +1,107 @@
+import { Link } from 'react-router';
+import { FaStar } from 'react-icons/fa';
+import { LuUsers } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import type { LibraryAuthorSummary } from '../../../shared/contracts';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { PageStateLayout } from '../../../shared/ui/feedback/PageStateLayout';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { useAuthorListQuery } from '../hooks/useAuthorQueries';
+
+function AuthorCard({ author }: { author: LibraryAuthorSummary }) {
+    return (
+        <Link
+            to={buildRoutePath('author-detail', { id: author.slug })}
+            className="block bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg p-4 hover:border-primary-500 dark:hover:border-primary-500 transition-colors"
+        >
+            <h3 className="text-base font-semibold text-gray-900 dark:text-white truncate">
+                {author.name}
+            </h3>
+            <p className="mt-1 flex items-center gap-2 text-sm font-medium text-gray-500 dark:text-dark-400">
+                <span>
+                    {formatNumber(author.item_count)}{' '}
+                    {translation.get('book-label', author.item_count)}
+                </span>
+                {typeof author.average_rating === 'number' && (
+                    <span className="inline-flex items-center gap-1">
+                        <FaStar
+                            className="w-3 h-3 text-yellow-500"
+                            aria-hidden="true"
+                        />
+                        {formatNumber(author.average_rating, {
+                            maximumFractionDigits: 1,
+                        })}
+                    </span>
+                )}
+                {author.total_reading_time_sec > 0 && (
+                    <span>
+                        {formatDuration(author.total_reading_time_sec)}
+                    </span>
+                )}
+            </p>
+        </Link>
+    );
+}
+
+export function AuthorListRoute() {
+    const { siteQuery } = useSiteQuery();
+    const listQuery = useAuthorListQuery();
+    const title = translation.get('author-page.all');
+    useDocumentTitle(title, siteQuery.data?.title);
+
+    const authors = listQuery.data?.authors ?? [];
+
+    return (
+        <>
+            <PageHeader title={title} />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={listQuery.isError}
+                    error={listQuery.error}
+                    onRetry={() => void listQuery.refetch()}
+                    showBlockingSpinner={listQuery.isLoading}
+                    showOverlaySpinner={false}
+                    hasData={Boolean(listQuery.data)}
+                    srLabel="Loading authors"
+                    renderContent={() =>
+                        authors.length > 0 ? (
+                            <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-3 md:gap-4">
+                                {authors.map((author) => (
+                                    <AuthorCard
+                                        key={author.slug}
+                                        author={author}
+                                    />
+                                ))}
+                            </div>
+                        ) : (
+                            <PageStateLayout
+                                icon={
+                                    <LuUsers
+                                        className="w-12 h-12 text-white"
+                                        aria-hidden="true"
+                                    />
+                                }
+                                gradientFrom="from-cyan-500"
+                                gradientTo="to-cyan-600"
+                                glowFrom="from-cyan-500/20"
+                                glowTo="to-cyan-600/20"
+                                title={translation.get('author-page.empty')}
+                                description={translation.get(
+                                    'author-page.empty-description',
+                                )}
+                            />
+                        )
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
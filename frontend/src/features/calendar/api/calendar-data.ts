@@ -1,5 +1,6 @@
 import { api } from '../../../shared/api';
 import type {
+    CalendarDayEntry,
     CalendarItemRef,
     CalendarScopeStats,
     ReadingCalendarEvent,
@@ -9,6 +10,8 @@ export type CalendarEventResponse = ReadingCalendarEvent;
 
 export type CalendarItemResponse = CalendarItemRef;
 
+export type CalendarDayEntryResponse = CalendarDayEntry;
+
 export type CalendarMonthlyStats = CalendarScopeStats;
 
 export interface CalendarScopedMonthlyStats {
@@ -20,6 +23,7 @@ export interface CalendarScopedMonthlyStats {
 export interface CalendarMonthResponse {
     events: CalendarEventResponse[];
     items: Record<string, CalendarItemResponse>;
+    days: Record<string, CalendarDayEntryResponse[]>;
     stats: CalendarScopedMonthlyStats;
 }
 
@@ -42,6 +46,7 @@ export async function loadCalendarMonth(
     return {
         events: data.events,
         items: data.items,
+        days: data.days,
         stats: {
             all: data.stats_by_scope.all,
             books: data.stats_by_scope.books,
@@ -0,0 +1,98 @@
+import { LuCalendarDays } from 'react-icons/lu';
+
+import { translation } from '../../../shared/i18n';
+import { formatPlainDate } from '../../../shared/lib/intl/formatDate';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { CloseButton } from '../../../shared/ui/button/CloseButton';
+import { ModalShell } from '../../../shared/ui/modal/ModalShell';
+import type {
+    CalendarDayEntryResponse,
+    CalendarItemResponse,
+} from '../api/calendar-data';
+
+type CalendarDayModalProps = {
+    open: boolean;
+    dayKey: string | null;
+    entries: CalendarDayEntryResponse[];
+    items: Record<string, CalendarItemResponse>;
+    onClose: () => void;
+};
+
+export function CalendarDayModal({
+    open,
+    dayKey,
+    entries,
+    items,
+    onClose,
+}: CalendarDayModalProps) {
+    if (!dayKey) {
+        return null;
+    }
+
+    const totalTimeSec = entries.reduce(
+        (sum, entry) => sum + entry.reading_time_sec,
+        0,
+    );
+
+    return (
+        <ModalShell
+            open={open}
+            onClose={onClose}
+            cardClassName="max-w-md w-full max-h-[85vh] overflow-y-auto bg-white/95 dark:bg-dark-900/70 backdrop-blur-xl border border-gray-200/70 dark:border-dark-600/50 rounded-2xl shadow-2xl"
+            showCloseButton={false}
+        >
+            <div className="flex items-center justify-between p-4 border-b border-gray-200/70 dark:border-dark-700/50">
+                <div className="flex items-center gap-3 min-w-0 flex-1 pr-3">
+                    <div className="w-8 h-8 bg-primary-500/20 dark:bg-linear-to-br dark:from-primary-500 dark:to-primary-600 rounded-lg flex items-center justify-center shrink-0">
+                        <LuCalendarDays
+                            className="w-4 h-4 text-primary-600 dark:text-white"
+                            aria-hidden="true"
+                        />
+                    </div>
+                    <div className="min-w-0">
+                        <h3 className="text-base font-bold text-gray-900 dark:text-white truncate leading-tight">
+                            {formatPlainDate(dayKey, { monthStyle: 'long' })}
+                        </h3>
+                        <p className="text-xs font-medium text-gray-500 dark:text-dark-300 truncate">
+                            {formatDuration(totalTimeSec)}
+                        </p>
+                    </div>
+                </div>
+                <CloseButton
+                    onClick={onClose}
+                    className="w-8 h-8 rounded-lg shrink-0"
+                />
+            </div>
+
+            <ul className="p-4 space-y-2">
+                {entries.map((entry) => {
+                    const item = items[entry.item_ref];
+
+                    return (
+                        <li
+                            key={entry.item_ref}
+                            className="flex items-center gap-3 p-3 rounded-xl bg-gray-50 dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70"
+                        >
+                            <span
+                                className="w-2.5 h-2.5 rounded-full shrink-0"
+                                style={{
+                                    backgroundColor: `var(--calendar-event-color-${item?.color_index ?? 0})`,
+                                }}
+                                aria-hidden="true"
+                            />
+                            <span className="flex-1 min-w-0 text-sm font-medium text-gray-900 dark:text-white truncate">
+                                {item?.title ??
+                                    translation.get('unknown-book')}
+                            </span>
+                            <span className="text-xs text-gray-500 dark:text-dark-300 text-right shrink-0">
+                                {formatDuration(entry.reading_time_sec)}
+                                <br />
+                                {translation.get('pages', entry.pages_read)}
+                            </span>
+                        </li>
+                    );
+                })}
+            </ul>
+        </ModalShell>
+    );
+}
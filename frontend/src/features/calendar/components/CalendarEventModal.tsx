@@ -114,6 +114,11 @@ export function CalendarEventModal({
                                 {authors}
                             </span>
                         </p>
+                        {item?.removed && (
+                            <span className="inline-block mt-1 text-[11px] font-medium px-2 py-0.5 rounded-full border border-gray-200 dark:border-dark-600 text-gray-500 dark:text-dark-300 bg-gray-100/80 dark:bg-dark-700/60">
+                                {translation.get('file-removed')}
+                            </span>
+                        )}
                     </div>
                 </div>
                 <CloseButton
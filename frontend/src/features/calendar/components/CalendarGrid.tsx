@@ -1,61 +1,66 @@
-import { createCalendar, DayGrid, destroyCalendar } from '@event-calendar/core';
+import {
+    createCalendar,
+    DayGrid,
+    destroyCalendar,
+    Interaction,
+} from '@event-calendar/core';
 import type { Calendar } from '@event-calendar/core';
 import { useCallback, useEffect, useMemo, useRef } from 'react';
 
+import type { WeekStart } from '../../../shared/contracts';
 import { translation } from '../../../shared/i18n';
 import type {
     CalendarEventResponse,
     CalendarItemResponse,
 } from '../api/calendar-data';
+import { dayKey } from '../model/calendar-model';
 
 type CalendarGridProps = {
     locale: string;
+    weekStart: WeekStart;
     displayedMonth: Date;
     events: CalendarEventResponse[];
     items: Record<string, CalendarItemResponse>;
     onDisplayedMonthChange: (date: Date) => void;
     onEventSelect: (event: CalendarEventResponse) => void;
+    onDaySelect: (dayKey: string) => void;
 };
 
 type EventExtendedProps = {
     rawEvent: CalendarEventResponse;
 };
 
-const EVENT_COLOR_PAIR_COUNT = 10;
-
-function colorforevent(event: CalendarEventResponse): string {
-    let hash = 0;
-    const token = event.item_ref;
-
-    for (let index = 0; index < token.length; index += 1) {
-        hash = (hash << 5) - hash + token.charCodeAt(index);
-        hash |= 0;
-    }
-
-    const colorIndex = Math.abs(hash) % EVENT_COLOR_PAIR_COUNT;
-    return `var(--calendar-event-color-${colorIndex})`;
+function colorForItem(item: CalendarItemResponse | undefined): string {
+    return `var(--calendar-event-color-${item?.color_index ?? 0})`;
 }
 
 function normalizeToMonth(date: Date): Date {
     return new Date(date.getFullYear(), date.getMonth(), 1, 12, 0, 0, 0);
 }
 
+function firstDayIndex(weekStart: WeekStart): number {
+    return weekStart === 'sunday' ? 0 : 1;
+}
+
 export function CalendarGrid({
     locale,
+    weekStart,
     displayedMonth,
     events,
     items,
     onDisplayedMonthChange,
     onEventSelect,
+    onDaySelect,
 }: CalendarGridProps) {
     const containerRef = useRef<HTMLElement | null>(null);
     const calendarRef = useRef<Calendar | null>(null);
     const scrollTimeoutRef = useRef<number | null>(null);
     const optionRefs = useRef<{
         locale: string;
+        weekStart: WeekStart;
         displayedMonth: Date;
         mappedEvents: Calendar.EventInput[];
-    }>({ locale, displayedMonth, mappedEvents: [] });
+    }>({ locale, weekStart, displayedMonth, mappedEvents: [] });
 
     const scrollCurrentDayIntoView = useCallback(() => {
         const calendarContainer = containerRef.current;
@@ -106,7 +111,7 @@ export function CalendarGrid({
                     start: event.start,
                     end: event.end || event.start,
                     allDay: true,
-                    backgroundColor: colorforevent(event),
+                    backgroundColor: colorForItem(item),
                     textColor: 'var(--calendar-event-text-color)',
                     extendedProps: {
                         rawEvent: event,
@@ -117,8 +122,13 @@ export function CalendarGrid({
     );
 
     useEffect(() => {
-        optionRefs.current = { locale, displayedMonth, mappedEvents };
-    }, [locale, displayedMonth, mappedEvents]);
+        optionRefs.current = {
+            locale,
+            weekStart,
+            displayedMonth,
+            mappedEvents,
+        };
+    }, [locale, weekStart, displayedMonth, mappedEvents]);
 
     const handleDatesSet = useCallback(
         (info: Calendar.DatesSetInfo) => {
@@ -154,26 +164,38 @@ export function CalendarGrid({
         [onEventSelect],
     );
 
+    const handleDateClick = useCallback(
+        (info: Calendar.DateClickInfo) => {
+            onDaySelect(dayKey(info.date));
+        },
+        [onDaySelect],
+    );
+
     useEffect(() => {
         if (!containerRef.current) {
             return;
         }
 
         const opts = optionRefs.current;
-        const instance = createCalendar(containerRef.current, [DayGrid], {
-            view: 'dayGridMonth',
-            height: 'auto',
-            locale: opts.locale,
-            date: opts.displayedMonth,
-            firstDay: 1,
-            displayEventEnd: false,
-            editable: false,
-            eventStartEditable: false,
-            eventDurationEditable: false,
-            events: opts.mappedEvents,
-            eventClick: handleEventClick,
-            datesSet: handleDatesSet,
-        });
+        const instance = createCalendar(
+            containerRef.current,
+            [DayGrid, Interaction],
+            {
+                view: 'dayGridMonth',
+                height: 'auto',
+                locale: opts.locale,
+                date: opts.displayedMonth,
+                firstDay: firstDayIndex(opts.weekStart),
+                displayEventEnd: false,
+                editable: false,
+                eventStartEditable: false,
+                eventDurationEditable: false,
+                events: opts.mappedEvents,
+                eventClick: handleEventClick,
+                dateClick: handleDateClick,
+                datesSet: handleDatesSet,
+            },
+        );
 
         calendarRef.current = instance;
 
@@ -181,7 +203,7 @@ export function CalendarGrid({
             void destroyCalendar(instance);
             calendarRef.current = null;
         };
-    }, [handleDatesSet, handleEventClick]);
+    }, [handleDateClick, handleDatesSet, handleEventClick]);
 
     useEffect(() => {
         calendarRef.current?.setOption('events', mappedEvents);
@@ -195,9 +217,14 @@ export function CalendarGrid({
         calendarRef.current?.setOption('locale', locale);
     }, [locale]);
 
+    useEffect(() => {
+        calendarRef.current?.setOption('firstDay', firstDayIndex(weekStart));
+    }, [weekStart]);
+
     return (
         <section
             ref={containerRef}
+            aria-label={translation.get('calendar')}
             className="calendar-container bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg shadow-xs"
         />
     );
@@ -1,5 +1,5 @@
 import { useMemo } from 'react';
-import { LuChevronLeft, LuChevronRight } from 'react-icons/lu';
+import { LuCalendarRange, LuChevronLeft, LuChevronRight } from 'react-icons/lu';
 
 import { useRouteHeader } from '../../../app/shell/use-route-header';
 import type { ScopeValue } from '../../../shared/api';
@@ -17,6 +17,7 @@ type CalendarHeaderProps = {
     onToday: () => void;
     onOpenMonthPicker: () => void;
     onOpenYearPicker: () => void;
+    onOpenYearView: () => void;
     todayDisabled: boolean;
 };
 
@@ -30,6 +31,7 @@ export function CalendarHeader({
     onToday,
     onOpenMonthPicker,
     onOpenYearPicker,
+    onOpenYearView,
     todayDisabled,
 }: CalendarHeaderProps) {
     const header = useMemo(() => {
@@ -104,6 +106,13 @@ export function CalendarHeader({
                         {translation.get('today')}
                     </Button>
 
+                    <Button
+                        variant="neutral"
+                        icon={LuCalendarRange}
+                        aria-label={translation.get('year-view.aria-label')}
+                        onClick={onOpenYearView}
+                    />
+
                     <ContentScopeFilter
                         visible={showTypeFilter}
                         value={scope}
@@ -117,6 +126,7 @@ export function CalendarHeader({
         onNextMonth,
         onOpenMonthPicker,
         onOpenYearPicker,
+        onOpenYearView,
         onPreviousMonth,
         onScopeChange,
         onToday,
@@ -0,0 +1,56 @@
+import { describe, expect, it } from 'vitest';
+
+import { buildMonthWeeks, parseCalendarYearParam } from './calendar-model';
+
+describe('buildMonthWeeks', () => {
+    it('includes February 29th in leap years only', () => {
+        expect(buildMonthWeeks(2024, 1, 'monday').flat()).toContain(
+            '2024-02-29',
+        );
+        expect(buildMonthWeeks(2026, 1, 'monday').flat()).not.toContain(
+            '2026-02-29',
+        );
+        expect(
+            buildMonthWeeks(2100, 1, 'monday')
+                .flat()
+                .filter((day) => day !== null),
+        ).toHaveLength(28);
+    });
+
+    it('aligns the first day with its weekday column', () => {
+        // 2026-03-01 is a Sunday.
+        const mondayWeeks = buildMonthWeeks(2026, 2, 'monday');
+        expect(mondayWeeks[0]).toEqual([
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            '2026-03-01',
+        ]);
+
+        const sundayWeeks = buildMonthWeeks(2026, 2, 'sunday');
+        expect(sundayWeeks[0][0]).toBe('2026-03-01');
+        expect(sundayWeeks[0][6]).toBe('2026-03-07');
+    });
+
+    it('pads every week to seven days without gaps', () => {
+        const weeks = buildMonthWeeks(2026, 2, 'monday');
+        expect(weeks.every((week) => week.length === 7)).toBe(true);
+
+        const days = weeks.flat().filter((day) => day !== null);
+        expect(days).toHaveLength(31);
+        expect(days[0]).toBe('2026-03-01');
+        expect(days[30]).toBe('2026-03-31');
+    });
+});
+
+describe('parseCalendarYearParam', () => {
+    it('accepts four-digit years only', () => {
+        expect(parseCalendarYearParam('2024')).toBe(2024);
+        expect(parseCalendarYearParam('24')).toBeNull();
+        expect(parseCalendarYearParam('2024-03')).toBeNull();
+        expect(parseCalendarYearParam(undefined)).toBeNull();
+    });
+});
@@ -1,9 +1,12 @@
+import { buildRoutePath } from '../../../app/routes/route-registry';
 import type { ScopeValue } from '../../../shared/api';
+import type { WeekStart } from '../../../shared/contracts';
 import {
     patchRouteState,
     readRouteState,
 } from '../../../shared/lib/state/route-state-storage';
 import type {
+    CalendarDayEntryResponse,
     CalendarEventResponse,
     CalendarItemResponse,
     CalendarMonthResponse,
@@ -13,6 +16,7 @@ import type {
 type AggregatedCalendarData = {
     events: CalendarEventResponse[];
     items: Record<string, CalendarItemResponse>;
+    days: Record<string, CalendarDayEntryResponse[]>;
 };
 
 function safeDate(year: number, monthIndex: number, date: number): Date {
@@ -23,6 +27,10 @@ export function monthKey(date: Date): string {
     return `${date.getFullYear()}-${String(date.getMonth() + 1).padStart(2, '0')}`;
 }
 
+export function dayKey(date: Date): string {
+    return `${monthKey(date)}-${String(date.getDate()).padStart(2, '0')}`;
+}
+
 export function parseMonthKey(targetMonthKey: string): Date {
     const [yearRaw, monthRaw] = targetMonthKey.split('-');
     const year = Number(yearRaw);
@@ -65,10 +73,13 @@ export function aggregateCalendarData(
 ): AggregatedCalendarData {
     const events: CalendarEventResponse[] = [];
     const items: Record<string, CalendarItemResponse> = {};
+    const days: Record<string, CalendarDayEntryResponse[]> = {};
     const seen = new Set<string>();
 
     for (const monthData of months) {
         Object.assign(items, monthData.items);
+        // Each month only lists its own days, so keys never collide.
+        Object.assign(days, monthData.days);
 
         for (const event of monthData.events) {
             const dedupeKey = `${event.item_ref}|${event.start}|${event.end ?? ''}`;
@@ -81,11 +92,11 @@ export function aggregateCalendarData(
         }
     }
 
-    return { events, items };
+    return { events, items, days };
 }
 
 export function eventMatchesScope(
-    event: CalendarEventResponse,
+    event: Pick<CalendarEventResponse, 'item_ref'>,
     items: Record<string, CalendarItemResponse>,
     scope: ScopeValue,
 ): boolean {
@@ -174,3 +185,50 @@ export function persistCalendarViewState(state: CalendarViewState): void {
         monthKey: normalizeCalendarMonthKey(state.monthKey),
     });
 }
+
+/**
+ * Day keys of one month split into weeks, padded with `null` before the 1st
+ * and after the last day so every column is the same weekday.
+ */
+export function buildMonthWeeks(
+    year: number,
+    monthIndex: number,
+    weekStart: WeekStart,
+): (string | null)[][] {
+    const firstDayIndex = weekStart === 'sunday' ? 0 : 1;
+    const leading =
+        (safeDate(year, monthIndex, 1).getDay() - firstDayIndex + 7) % 7;
+    // Day 0 of the next month is the last day of this one.
+    const daysInMonth = safeDate(year, monthIndex + 1, 0).getDate();
+
+    const cells: (string | null)[] = Array.from(
+        { length: leading },
+        () => null,
+    );
+    for (let date = 1; date <= daysInMonth; date += 1) {
+        cells.push(dayKey(safeDate(year, monthIndex, date)));
+    }
+    while (cells.length % 7 !== 0) {
+        cells.push(null);
+    }
+
+    const weeks: (string | null)[][] = [];
+    for (let index = 0; index < cells.length; index += 7) {
+        weeks.push(cells.slice(index, index + 7));
+    }
+    return weeks;
+}
+
+export function parseCalendarYearParam(
+    yearParam: string | undefined,
+): number | null {
+    if (!yearParam || !/^\d{4}$/.test(yearParam)) {
+        return null;
+    }
+
+    return Number(yearParam);
+}
+
+export function buildCalendarYearPath(year: number): string {
+    return buildRoutePath('calendar-year', { year: String(year) });
+}
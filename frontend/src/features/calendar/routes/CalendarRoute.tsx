@@ -1,4 +1,5 @@
 import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useNavigate } from 'react-router';
 
 import '../../../styles/calendar.css';
 import type { ScopeValue } from '../../../shared/api';
@@ -10,6 +11,7 @@ import { useQueryTransitionState } from '../../../shared/lib/state/useQueryTrans
 import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
 import { OVERLAY_TRANSITION_DURATION_MS } from '../../../shared/lib/dom/useOverlayAnimation';
 import type { CalendarEventResponse } from '../api/calendar-data';
+import { CalendarDayModal } from '../components/CalendarDayModal';
 import { CalendarEventModal } from '../components/CalendarEventModal';
 import { CalendarGrid } from '../components/CalendarGrid';
 import { CalendarHeader } from '../components/CalendarHeader';
@@ -21,6 +23,7 @@ import {
 } from '../hooks/useCalendarQueries';
 import {
     aggregateCalendarData,
+    buildCalendarYearPath,
     eventMatchesScope,
     isCurrentMonth,
     loadInitialCalendarViewState,
@@ -57,8 +60,11 @@ export function CalendarRoute() {
     const [selectedEvent, setSelectedEvent] =
         useState<CalendarEventResponse | null>(null);
     const [isEventModalOpen, setIsEventModalOpen] = useState(false);
+    const [selectedDayKey, setSelectedDayKey] = useState<string | null>(null);
+    const [isDayModalOpen, setIsDayModalOpen] = useState(false);
 
     const { siteQuery, showTypeFilter } = useSiteQuery();
+    const navigate = useNavigate();
 
     const displayedMonthKey = monthKey(displayedMonth);
     const previousMonthKey = shiftMonthKey(displayedMonthKey, -1);
@@ -167,6 +173,22 @@ export function CalendarRoute() {
         [mergedCalendarData.events, mergedCalendarData.items, scope],
     );
 
+    const selectedDayEntries = useMemo(() => {
+        if (!selectedDayKey) {
+            return [];
+        }
+
+        return (mergedCalendarData.days[selectedDayKey] ?? []).filter(
+            (entry) =>
+                eventMatchesScope(entry, mergedCalendarData.items, scope),
+        );
+    }, [
+        mergedCalendarData.days,
+        mergedCalendarData.items,
+        scope,
+        selectedDayKey,
+    ]);
+
     const selectedItem = selectedEvent
         ? (mergedCalendarData.items[selectedEvent.item_ref] ?? null)
         : null;
@@ -192,6 +214,22 @@ export function CalendarRoute() {
         setIsEventModalOpen(true);
     }, []);
 
+    const handleDaySelect = useCallback(
+        (dayKey: string) => {
+            const hasReading = (mergedCalendarData.days[dayKey] ?? []).some(
+                (entry) =>
+                    eventMatchesScope(entry, mergedCalendarData.items, scope),
+            );
+            if (!hasReading) {
+                return;
+            }
+
+            setSelectedDayKey(dayKey);
+            setIsDayModalOpen(true);
+        },
+        [mergedCalendarData.days, mergedCalendarData.items, scope],
+    );
+
     const handlePreviousMonth = useCallback(() => {
         setPersistMonthSelection(true);
         setDisplayedMonth((currentDate) => shiftMonth(currentDate, -1));
@@ -221,6 +259,20 @@ export function CalendarRoute() {
         };
     }, [isEventModalOpen, selectedEvent]);
 
+    useEffect(() => {
+        if (isDayModalOpen || !selectedDayKey) {
+            return;
+        }
+
+        const timerId = window.setTimeout(() => {
+            setSelectedDayKey(null);
+        }, OVERLAY_TRANSITION_DURATION_MS);
+
+        return () => {
+            window.clearTimeout(timerId);
+        };
+    }, [isDayModalOpen, selectedDayKey]);
+
     const initialLoading =
         !canStartMonthQueries ||
         (currentMonthEnabled && currentMonthTransition.showBlockingSpinner);
@@ -243,6 +295,11 @@ export function CalendarRoute() {
                         );
                         setYearPickerOpen(true);
                     }}
+                    onOpenYearView={() =>
+                        navigate(
+                            buildCalendarYearPath(displayedMonth.getFullYear()),
+                        )
+                    }
                     todayDisabled={isCurrentMonth(displayedMonth)}
                 />
 
@@ -271,6 +328,9 @@ export function CalendarRoute() {
 
                                 <CalendarGrid
                                     locale={locale}
+                                    weekStart={
+                                        siteQuery.data?.week_start ?? 'monday'
+                                    }
                                     displayedMonth={displayedMonth}
                                     events={filteredEvents}
                                     items={mergedCalendarData.items}
@@ -278,6 +338,7 @@ export function CalendarRoute() {
                                         handleDisplayedMonthChange
                                     }
                                     onEventSelect={handleEventSelect}
+                                    onDaySelect={handleDaySelect}
                                 />
                             </>
                         )}
@@ -342,6 +403,14 @@ export function CalendarRoute() {
                 item={selectedItem}
                 onClose={() => setIsEventModalOpen(false)}
             />
+
+            <CalendarDayModal
+                open={isDayModalOpen}
+                dayKey={selectedDayKey}
+                entries={selectedDayEntries}
+                items={mergedCalendarData.items}
+                onClose={() => setIsDayModalOpen(false)}
+            />
         </>
     );
 }
@@ -0,0 +1,259 @@
+import { useMemo, useState } from 'react';
+import { Navigate, useNavigate, useParams } from 'react-router';
+import { LuCalendar, LuChevronLeft, LuChevronRight } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import type { WeekStart } from '../../../shared/contracts';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import {
+    formatMonthKey,
+    formatPlainDate,
+} from '../../../shared/lib/intl/formatDate';
+import { Button } from '../../../shared/ui/button/Button';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { ContentScopeFilter } from '../../../shared/ui/selectors/ContentScopeFilter';
+import { TooltipManager } from '../../../shared/overlay/tooltip-manager';
+import { useStatisticsYearQuery } from '../../statistics/hooks/useStatisticsQueries';
+import { DataFormatter } from '../../statistics/lib/formatters';
+import {
+    HEATMAP_COLOR_CLASSES,
+    normalizeHeatmapLevel,
+} from '../../statistics/model/heatmap';
+import {
+    buildCalendarYearPath,
+    buildMonthWeeks,
+    loadInitialCalendarViewState,
+    parseCalendarYearParam,
+    persistCalendarViewState,
+} from '../model/calendar-model';
+
+const WEEKDAY_KEYS: Record<WeekStart, readonly string[]> = {
+    monday: ['mon', 'tue', 'wed', 'thu', 'fri', 'sat', 'sun'],
+    sunday: ['sun', 'mon', 'tue', 'wed', 'thu', 'fri', 'sat'],
+};
+
+type YearActivity = {
+    byDay: Map<string, { read: number; pages: number }>;
+    maxActivity: number;
+};
+
+type YearMonthCardProps = {
+    monthKey: string;
+    weeks: (string | null)[][];
+    weekStart: WeekStart;
+    activity: YearActivity;
+    onOpenMonth: (monthKey: string) => void;
+};
+
+function dayTooltip(day: string, read: number, pages: number): string {
+    const date = formatPlainDate(day, { monthStyle: 'long' });
+    const time = DataFormatter.formatReadTime(read);
+    const pageCount = `${DataFormatter.formatCount(pages)} ${translation.get('pages-label', pages)}`;
+    return `${date}: ${time}, ${pageCount}`;
+}
+
+function YearMonthCard({
+    monthKey,
+    weeks,
+    weekStart,
+    activity,
+    onOpenMonth,
+}: YearMonthCardProps) {
+    return (
+        <section className="bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg shadow-xs p-4">
+            <button
+                type="button"
+                className="mb-3 text-sm font-semibold text-gray-900 dark:text-white hover:text-primary-600 dark:hover:text-primary-400 transition-colors cursor-pointer"
+                onClick={() => onOpenMonth(monthKey)}
+            >
+                {formatMonthKey(monthKey)}
+            </button>
+            <div className="grid grid-cols-7 gap-1 text-center">
+                {WEEKDAY_KEYS[weekStart].map((weekday) => (
+                    <span
+                        key={weekday}
+                        className="text-[10px] font-medium text-gray-500 dark:text-dark-400"
+                    >
+                        {translation.get(`weekday.${weekday}`)}
+                    </span>
+                ))}
+                {weeks.flat().map((day, index) => {
+                    if (!day) {
+                        return <span key={`pad-${index}`} aria-hidden="true" />;
+                    }
+
+                    const read = activity.byDay.get(day)?.read ?? 0;
+                    const pages = activity.byDay.get(day)?.pages ?? 0;
+                    const level = normalizeHeatmapLevel(
+                        read,
+                        activity.maxActivity,
+                    );
+                    const tooltip = dayTooltip(day, read, pages);
+
+                    return (
+                        <button
+                            key={day}
+                            type="button"
+                            className={`aspect-square rounded-xs text-[10px] leading-none text-gray-700 dark:text-dark-200 hover:ring-1 hover:ring-inset hover:ring-gray-900 dark:hover:ring-white cursor-pointer ${HEATMAP_COLOR_CLASSES[level].join(' ')}`}
+                            aria-label={tooltip}
+                            onClick={() => onOpenMonth(monthKey)}
+                            ref={(element) => {
+                                if (element) {
+                                    TooltipManager.attach(element, tooltip);
+                                }
+                            }}
+                        >
+                            {Number(day.slice(8))}
+                        </button>
+                    );
+                })}
+            </div>
+        </section>
+    );
+}
+
+export function CalendarYearRoute() {
+    const params = useParams();
+    const year = parseCalendarYearParam(params.year);
+
+    if (year === null) {
+        return <Navigate to={buildRoutePath('calendar')} replace />;
+    }
+
+    return <CalendarYearView year={year} />;
+}
+
+function CalendarYearView({ year }: { year: number }) {
+    const navigate = useNavigate();
+    const [scope, setScope] = useState(
+        () => loadInitialCalendarViewState().scope,
+    );
+    const { siteQuery, showTypeFilter } = useSiteQuery();
+    const weekStart = siteQuery.data?.week_start ?? 'monday';
+
+    useDocumentTitle(
+        `${translation.get('calendar')} ${year}`,
+        siteQuery.data?.title,
+    );
+
+    const yearQuery = useStatisticsYearQuery(scope, year);
+
+    const activity = useMemo<YearActivity>(() => {
+        const byDay = new Map<string, { read: number; pages: number }>();
+        let maxActivity = 0;
+
+        yearQuery.data?.daily_activity.forEach((entry) => {
+            maxActivity = Math.max(maxActivity, entry.reading_time_sec);
+            byDay.set(entry.date, {
+                read: entry.reading_time_sec,
+                pages: entry.pages_read,
+            });
+        });
+
+        const configuredMax = yearQuery.data?.heatmap_config.max_scale_sec;
+        if (configuredMax !== null && configuredMax !== undefined) {
+            maxActivity = configuredMax;
+        }
+
+        return { byDay, maxActivity };
+    }, [yearQuery.data]);
+
+    const months = useMemo(
+        () =>
+            Array.from({ length: 12 }, (_, monthIndex) => ({
+                key: `${year}-${String(monthIndex + 1).padStart(2, '0')}`,
+                weeks: buildMonthWeeks(year, monthIndex, weekStart),
+            })),
+        [weekStart, year],
+    );
+
+    const handleScopeChange = (nextScope: typeof scope) => {
+        setScope(nextScope);
+        persistCalendarViewState({
+            scope: nextScope,
+            monthKey: loadInitialCalendarViewState().monthKey,
+        });
+    };
+
+    const openMonth = (monthKey: string) => {
+        persistCalendarViewState({ scope, monthKey });
+        navigate(buildRoutePath('calendar'));
+    };
+
+    const goToYear = (offset: number) => {
+        navigate(buildCalendarYearPath(year + offset));
+    };
+
+    return (
+        <>
+            <PageHeader
+                title={String(year)}
+                controls={
+                    <div className="flex items-center space-x-2 md:space-x-4">
+                        <div className="flex items-center space-x-1">
+                            <Button
+                                variant="neutral"
+                                icon={LuChevronLeft}
+                                aria-label={translation.get(
+                                    'previous-year.aria-label',
+                                )}
+                                onClick={() => goToYear(-1)}
+                            />
+                            <Button
+                                variant="neutral"
+                                icon={LuChevronRight}
+                                aria-label={translation.get(
+                                    'next-year.aria-label',
+                                )}
+                                onClick={() => goToYear(1)}
+                            />
+                        </div>
+                        <Button
+                            variant="neutral"
+                            icon={LuCalendar}
+                            aria-label={translation.get(
+                                'month-view.aria-label',
+                            )}
+                            onClick={() => navigate(buildRoutePath('calendar'))}
+                        />
+                        <ContentScopeFilter
+                            visible={showTypeFilter}
+                            value={scope}
+                            onChange={handleScopeChange}
+                        />
+                    </div>
+                }
+            />
+
+            <PageContent>
+                <QueryStateLayout
+                    isError={yearQuery.isError}
+                    error={yearQuery.error}
+                    onRetry={() => yearQuery.refetch()}
+                    showBlockingSpinner={yearQuery.isLoading}
+                    showOverlaySpinner={yearQuery.isPlaceholderData}
+                    hasData={!yearQuery.isLoading}
+                    srLabel="Loading calendar year"
+                    renderContent={() => (
+                        <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-4">
+                            {months.map((month) => (
+                                <YearMonthCard
+                                    key={month.key}
+                                    monthKey={month.key}
+                                    weeks={month.weeks}
+                                    weekStart={weekStart}
+                                    activity={activity}
+                                    onOpenMonth={openMonth}
+                                />
+                            ))}
+                        </div>
+                    )}
+                />
+            </PageContent>
+        </>
+    );
+}
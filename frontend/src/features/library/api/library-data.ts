@@ -2,6 +2,7 @@
 export type {
     LibraryStatus,
     LibrarySeries,
+    LibraryContributors,
     LibraryListItem,
     LibraryDetailItem,
     LibraryReaderPresentation,
@@ -11,5 +12,7 @@ export type {
     LibrarySessionStats,
     LibraryListData,
     LibraryDetailData,
+    LibraryTimeline,
+    LibraryTimelineEntry,
     ExternalIdentifier,
 } from '../../../shared/contracts';
@@ -52,6 +52,9 @@ export function useUpdateItem(itemId: string, collection: LibraryCollection) {
             void queryClient.invalidateQueries({
                 queryKey: libraryListQueryKey(collection),
             });
+            void queryClient.invalidateQueries({
+                queryKey: ['statistics-abandoned'],
+            });
         },
     });
 }
@@ -0,0 +1,50 @@
+import { Link } from 'react-router';
+import { LuQuote } from 'react-icons/lu';
+
+import {
+    buildRoutePath,
+    detailRouteIdForContentType,
+} from '../../../app/routes/route-registry';
+import type { HighlightQuote } from '../../../shared/contracts';
+import { translation } from '../../../shared/i18n';
+
+type HighlightOfTheDayCardProps = {
+    highlight: HighlightQuote;
+};
+
+export function HighlightOfTheDayCard({
+    highlight,
+}: HighlightOfTheDayCardProps) {
+    const detailPath = buildRoutePath(
+        detailRouteIdForContentType(highlight.content_type),
+        { id: highlight.item_id },
+    );
+
+    return (
+        <section className="bg-white dark:bg-dark-800/60 border border-gray-200/70 dark:border-dark-700/50 rounded-xl shadow-xs p-4 md:p-5">
+            <div className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide mb-2">
+                {translation.get('highlight-of-the-day')}
+            </div>
+            <div className="flex items-start">
+                <LuQuote
+                    className="w-5 h-5 text-primary-400 mt-0.5 mr-2.5 shrink-0"
+                    aria-hidden
+                />
+                <div className="min-w-0">
+                    <p className="text-sm md:text-base font-medium text-gray-800 dark:text-gray-200 leading-relaxed whitespace-pre-line">
+                        {highlight.text}
+                    </p>
+                    <div className="mt-2 text-sm text-gray-500 dark:text-dark-300 truncate">
+                        <Link
+                            to={detailPath}
+                            className="font-semibold text-primary-600 dark:text-primary-400 hover:underline"
+                        >
+                            {highlight.item_title}
+                        </Link>
+                        {highlight.chapter && <span> · {highlight.chapter}</span>}
+                    </div>
+                </div>
+            </div>
+        </section>
+    );
+}
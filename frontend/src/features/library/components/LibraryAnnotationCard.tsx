@@ -7,6 +7,7 @@ import {
 } from 'react';
 import {
     LuBookOpen,
+    LuBookmark,
     LuCheck,
     LuClock3,
     LuFileText,
@@ -88,6 +89,18 @@ function CardHeader({
                         );
                     })()}
 
+                {isBookmark && !chapter && (
+                    <span className="inline-flex items-center min-w-0">
+                        <LuBookmark
+                            className="w-4 h-4 mr-1.5 text-primary-400 shrink-0"
+                            aria-hidden="true"
+                        />
+                        <span className="truncate">
+                            {translation.get('page-bookmark')}
+                        </span>
+                    </span>
+                )}
+
                 {chapter && (
                     <span className="inline-flex items-center min-w-0">
                         <LuFileText
@@ -136,7 +149,7 @@ function CardHeader({
                         to={readerHref}
                         title={translation.get('open-at-annotation')}
                         aria-label={translation.get('open-at-annotation')}
-                        className="inline-flex items-center justify-center gap-1.5 w-8 h-8 sm:w-auto sm:h-auto px-0 sm:px-2.5 py-1 rounded-md text-xs font-medium text-primary-600 dark:text-primary-300 bg-primary-500/10 hover:bg-primary-500/20 border border-primary-500/20 hover:border-primary-500/30 transition-colors"
+                        className="inline-flex print:hidden items-center justify-center gap-1.5 w-8 h-8 sm:w-auto sm:h-auto px-0 sm:px-2.5 py-1 rounded-md text-xs font-medium text-primary-600 dark:text-primary-300 bg-primary-500/10 hover:bg-primary-500/20 border border-primary-500/20 hover:border-primary-500/30 transition-colors"
                     >
                         <LuBookOpen
                             className="w-3.5 h-3.5"
@@ -437,7 +450,7 @@ export function LibraryAnnotationCard({
 
     return (
         <article
-            className={`bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg overflow-hidden shadow-xs${isBookmark ? ' border-l-3 border-l-orange-400 dark:border-l-orange-500' : ''}`}
+            className={`bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg overflow-hidden shadow-xs print:shadow-none print:break-inside-avoid${isBookmark ? ' border-l-3 border-l-orange-400 dark:border-l-orange-500' : ''}`}
         >
             <CardHeader
                 variant={variant}
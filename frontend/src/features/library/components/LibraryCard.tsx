@@ -12,6 +12,7 @@ import { translation } from '../../../shared/i18n';
 import { useLazyImageSource } from '../../../shared/lib/dom/useLazyImageSource';
 import { createDetailReturnState } from '../../../shared/lib/navigation/detail-return-state';
 import type { LibraryListItem } from '../api/library-data';
+import { COVER_HEIGHT, COVER_WIDTH } from '../lib/cover-size';
 import {
     formatSeriesDisplay,
     toProgressPercentage,
@@ -44,7 +45,7 @@ export function LibraryCard({
 }: LibraryCardProps) {
     const location = useLocation();
     const detailPath = buildRoutePath(detailRouteIdForCollection(collection), {
-        id: item.id,
+        id: item.slug ?? item.id,
     });
     const primaryAuthor = item.authors[0];
     const annotationCount = item.annotation_count ?? 0;
@@ -100,7 +101,10 @@ export function LibraryCard({
                                     ? 'transition-opacity duration-500 ease-out '
                                     : ''
                             }${isLoaded ? 'opacity-100' : 'opacity-0'}`}
+                            width={COVER_WIDTH}
+                            height={COVER_HEIGHT}
                             loading="lazy"
+                            decoding="async"
                             onLoad={onLoad}
                             onError={onError}
                         />
@@ -1,4 +1,4 @@
-import { useMemo, useRef, useState } from 'react';
+import { Fragment, useMemo, useRef, useState } from 'react';
 import { LuArrowLeft, LuBookOpen, LuDownload } from 'react-icons/lu';
 import { Link } from 'react-router';
 
@@ -16,18 +16,52 @@ import { DropdownPortal } from '../../../shared/ui/dropdown/DropdownPortal';
 import { isReaderFormatSupported } from '../../reader/lib/reader-format-support';
 import type { LibraryCollection } from '../model/library-model';
 
+/** An author name, linked to their author page when `href` is set. */
+export type LibraryAuthorLink = {
+    name: string;
+    href: string | null;
+};
+
 type LibraryDetailHeaderProps = {
     title: string;
     primaryAuthor?: string;
+    authors?: ReadonlyArray<LibraryAuthorLink>;
     collection: LibraryCollection;
     itemId: string;
     backHref: string;
     format?: string | null;
 };
 
+function AuthorNames({
+    authors,
+}: {
+    authors: ReadonlyArray<LibraryAuthorLink>;
+}) {
+    return (
+        <>
+            {authors.map((author, index) => (
+                <Fragment key={author.name}>
+                    {index > 0 && ', '}
+                    {author.href ? (
+                        <Link
+                            to={author.href}
+                            className="hover:text-primary-600 dark:hover:text-primary-300 hover:underline"
+                        >
+                            {author.name}
+                        </Link>
+                    ) : (
+                        author.name
+                    )}
+                </Fragment>
+            ))}
+        </>
+    );
+}
+
 export function LibraryDetailHeader({
     title,
     primaryAuthor,
+    authors,
     collection,
     itemId,
     backHref,
@@ -50,6 +84,14 @@ export function LibraryDetailHeader({
               })
             : null;
 
+    const authorLinks = useMemo<ReadonlyArray<LibraryAuthorLink>>(() => {
+        if (authors && authors.length > 0) {
+            return authors;
+        }
+
+        return primaryAuthor ? [{ name: primaryAuthor, href: null }] : [];
+    }, [authors, primaryAuthor]);
+
     const header = useMemo(
         () => ({
             mobileContent: (
@@ -73,9 +115,10 @@ export function LibraryDetailHeader({
                             {title}
                         </h1>
 
-                        {primaryAuthor && (
+                        {authorLinks.length > 0 && (
                             <p className="text-xs text-gray-500 dark:text-dark-300 truncate">
-                                {translation.get('by')} {primaryAuthor}
+                                {translation.get('by')}{' '}
+                                <AuthorNames authors={authorLinks} />
                             </p>
                         )}
                     </div>
@@ -87,9 +130,10 @@ export function LibraryDetailHeader({
                         {title}
                     </h2>
 
-                    {primaryAuthor && (
+                    {authorLinks.length > 0 && (
                         <p className="text-sm font-medium text-gray-500 dark:text-dark-300 truncate">
-                            {translation.get('by')} {primaryAuthor}
+                            {translation.get('by')}{' '}
+                            <AuthorNames authors={authorLinks} />
                         </p>
                     )}
                 </div>
@@ -154,13 +198,13 @@ export function LibraryDetailHeader({
             ),
         }),
         [
+            authorLinks,
             backHref,
             fileDownloadName,
             fileHref,
             fileLabel,
             jsonDownloadName,
             jsonHref,
-            primaryAuthor,
             readerHref,
             shareOpen,
             title,
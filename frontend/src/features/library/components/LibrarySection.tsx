@@ -9,7 +9,9 @@ import { LibraryCard } from './LibraryCard';
 type LibrarySectionProps = {
     sectionKey: LibrarySectionKey;
     title: string;
+    /** Cards rendered so far; may be fewer than `totalCount`. */
     items: LibraryListItem[];
+    totalCount: number;
     collection: LibraryCollection;
     visible: boolean;
     onToggle: () => void;
@@ -48,6 +50,7 @@ export function LibrarySection({
     sectionKey,
     title,
     items,
+    totalCount,
     collection,
     visible,
     onToggle,
@@ -64,7 +67,7 @@ export function LibrarySection({
                 <span
                     className={`bg-linear-to-r ${style.badgeClass} text-white text-sm px-3 py-1 rounded-full shadow-md font-medium`}
                 >
-                    {items.length}
+                    {totalCount}
                 </span>
             }
             visible={visible}
@@ -0,0 +1,61 @@
+import { Link } from 'react-router';
+import { LuArrowRight } from 'react-icons/lu';
+
+import {
+    buildRoutePath,
+    detailRouteIdForContentType,
+} from '../../../app/routes/route-registry';
+import type { LibraryUpNextEntry } from '../../../shared/contracts';
+import { translation } from '../../../shared/i18n';
+import { formatSeriesDisplay } from '../lib/library-detail-formatters';
+
+type UpNextCardProps = {
+    entries: LibraryUpNextEntry[];
+};
+
+export function UpNextCard({ entries }: UpNextCardProps) {
+    return (
+        <section className="bg-white dark:bg-dark-800/60 border border-gray-200/70 dark:border-dark-700/50 rounded-xl shadow-xs p-4 md:p-5">
+            <div className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide mb-3">
+                {translation.get('up-next')}
+            </div>
+            <ul className="grid grid-cols-1 sm:grid-cols-2 xl:grid-cols-3 gap-3">
+                {entries.map(({ item, ...entry }) => (
+                    <li key={entry.series_slug}>
+                        <Link
+                            to={buildRoutePath(
+                                detailRouteIdForContentType(item.content_type),
+                                { id: item.slug ?? item.id },
+                            )}
+                            className="group flex items-center gap-3 rounded-lg p-1.5 -m-1.5 hover:bg-gray-50 dark:hover:bg-dark-700/50 transition-colors"
+                        >
+                            <img
+                                src={item.cover_url}
+                                alt=""
+                                className="w-10 h-15 object-cover rounded-sm shadow-sm bg-gray-200 dark:bg-dark-700 shrink-0"
+                                loading="lazy"
+                                decoding="async"
+                            />
+                            <div className="min-w-0 flex-1">
+                                <div className="text-sm font-semibold text-gray-900 dark:text-white truncate">
+                                    {item.title}
+                                </div>
+                                <div className="text-xs text-gray-500 dark:text-dark-300 truncate">
+                                    {formatSeriesDisplay(item.series)} ·{' '}
+                                    {translation.get('series-page.progress', {
+                                        completed: entry.completed_count,
+                                        total: entry.item_count,
+                                    })}
+                                </div>
+                            </div>
+                            <LuArrowRight
+                                className="w-4 h-4 text-gray-400 group-hover:text-primary-500 shrink-0"
+                                aria-hidden="true"
+                            />
+                        </Link>
+                    </li>
+                ))}
+            </ul>
+        </section>
+    );
+}
@@ -0,0 +1,13 @@
+import { useQuery } from '@tanstack/react-query';
+
+import { api } from '../../../shared/api';
+import type { HighlightsData } from '../../../shared/contracts';
+
+export function useHighlightsQuery(enabled: boolean) {
+    return useQuery<HighlightsData>({
+        queryKey: ['highlights'],
+        queryFn: () => api.getHighlights(),
+        enabled,
+        staleTime: 5 * 60 * 1000,
+    });
+}
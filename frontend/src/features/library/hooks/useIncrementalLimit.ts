@@ -0,0 +1,72 @@
+import { useEffect, useRef, useState } from 'react';
+import type { RefObject } from 'react';
+
+type UseIncrementalLimitResult = {
+    /** How many items to render; `Infinity` when everything is shown. */
+    limit: number;
+    hasMore: boolean;
+    sentinelRef: RefObject<HTMLDivElement | null>;
+};
+
+type LimitState = {
+    resetKey: string;
+    limit: number;
+};
+
+/**
+ * Grow a render limit by `pageSize` whenever the sentinel element scrolls
+ * near the viewport. The limit starts over when `resetKey` changes (a new
+ * search or filter), but not when the items are merely refreshed, so a live
+ * update does not collapse a list the user has scrolled through.
+ *
+ * A `pageSize` of 0 renders everything at once.
+ */
+export function useIncrementalLimit(
+    total: number,
+    pageSize: number,
+    resetKey: string,
+): UseIncrementalLimitResult {
+    const sentinelRef = useRef<HTMLDivElement>(null);
+    const [state, setState] = useState<LimitState>(() => ({
+        resetKey,
+        limit: pageSize,
+    }));
+    const supportsIntersectionObserver =
+        typeof window !== 'undefined' && 'IntersectionObserver' in window;
+    const showAll = pageSize <= 0 || !supportsIntersectionObserver;
+    const limit = showAll
+        ? Infinity
+        : state.resetKey === resetKey
+          ? Math.max(state.limit, pageSize)
+          : pageSize;
+    const hasMore = limit < total;
+
+    useEffect(() => {
+        if (!hasMore) {
+            return;
+        }
+
+        const sentinel = sentinelRef.current;
+        if (!sentinel) {
+            return;
+        }
+
+        const observer = new IntersectionObserver(
+            (entries) => {
+                if (entries.some((entry) => entry.isIntersecting)) {
+                    observer.disconnect();
+                    setState({ resetKey, limit: limit + pageSize });
+                }
+            },
+            { rootMargin: '800px 0px' },
+        );
+
+        observer.observe(sentinel);
+
+        return () => {
+            observer.disconnect();
+        };
+    }, [hasMore, limit, pageSize, resetKey]);
+
+    return { limit, hasMore, sentinelRef };
+}
@@ -0,0 +1,12 @@
+import { useQuery } from '@tanstack/react-query';
+
+import { api } from '../../../shared/api';
+import type { LibraryUpNextData } from '../../../shared/contracts';
+
+export function useUpNextQuery(enabled: boolean) {
+    return useQuery<LibraryUpNextData>({
+        queryKey: ['up-next'],
+        queryFn: () => api.getUpNext(),
+        enabled,
+    });
+}
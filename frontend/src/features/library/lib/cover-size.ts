@@ -0,0 +1,6 @@
+// Covers are scaled to this height when they are generated (COVER_MAX_HEIGHT
+// in src/pipeline/media.rs). Cards give their images these dimensions, in the
+// same 2:3 ratio as the `aspect-book` box, so a cover streaming in never
+// changes the size of its card.
+export const COVER_HEIGHT = 600;
+export const COVER_WIDTH = (COVER_HEIGHT * 2) / 3;
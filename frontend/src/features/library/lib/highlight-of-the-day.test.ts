@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest';
+
+import type { HighlightQuote } from '../../../shared/contracts';
+import { localDateKey, pickHighlightOfTheDay } from './highlight-of-the-day';
+
+function makeQuote(index: number): HighlightQuote {
+    return {
+        item_id: `item-${index}`,
+        item_title: `Book ${index}`,
+        content_type: 'book',
+        text: `Quote ${index}`,
+    };
+}
+
+describe('pickHighlightOfTheDay', () => {
+    const quotes = Array.from({ length: 20 }, (_, index) => makeQuote(index));
+
+    it('returns null when there are no highlights', () => {
+        expect(pickHighlightOfTheDay([], '2026-03-01')).toBeNull();
+    });
+
+    it('is stable for the same date', () => {
+        expect(pickHighlightOfTheDay(quotes, '2026-03-01')).toBe(
+            pickHighlightOfTheDay(quotes, '2026-03-01'),
+        );
+    });
+
+    it('rotates across days', () => {
+        const picks = new Set(
+            Array.from({ length: 14 }, (_, day) =>
+                pickHighlightOfTheDay(
+                    quotes,
+                    `2026-03-${String(day + 1).padStart(2, '0')}`,
+                ),
+            ),
+        );
+        expect(picks.size).toBeGreaterThan(1);
+    });
+});
+
+describe('localDateKey', () => {
+    it('formats local dates with zero padding', () => {
+        expect(localDateKey(new Date(2026, 0, 5, 23, 30))).toBe('2026-01-05');
+    });
+});
@@ -0,0 +1,33 @@
+import type { HighlightQuote } from '../../../shared/contracts';
+
+/** Local calendar date as `YYYY-MM-DD`; the widget rotates on this key. */
+export function localDateKey(date: Date): string {
+    const month = String(date.getMonth() + 1).padStart(2, '0');
+    const day = String(date.getDate()).padStart(2, '0');
+    return `${date.getFullYear()}-${month}-${day}`;
+}
+
+/** FNV-1a hash — small, stable across browsers, good enough for seeding. */
+function hashSeed(seed: string): number {
+    let hash = 0x811c9dc5;
+    for (let index = 0; index < seed.length; index += 1) {
+        hash ^= seed.charCodeAt(index);
+        hash = Math.imul(hash, 0x01000193);
+    }
+    return hash >>> 0;
+}
+
+/**
+ * Pick one highlight deterministically for the given date key, so every
+ * visit on the same day shows the same quote.
+ */
+export function pickHighlightOfTheDay(
+    highlights: HighlightQuote[],
+    dateKey: string,
+): HighlightQuote | null {
+    if (highlights.length === 0) {
+        return null;
+    }
+
+    return highlights[hashSeed(dateKey) % highlights.length] ?? null;
+}
@@ -75,6 +75,16 @@ export function formatAnnotationDatetime(
     });
 }
 
+export function formatAddedDate(
+    value: string | null | undefined,
+): string | null {
+    if (!value) {
+        return null;
+    }
+
+    return formatInstant(value, { dateStyle: 'long' });
+}
+
 export function formatLanguageDisplayName(
     value: string | null | undefined,
 ): string {
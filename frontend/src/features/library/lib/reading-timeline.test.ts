@@ -0,0 +1,69 @@
+import { describe, expect, it } from 'vitest';
+
+import type { LibraryTimelineEntry } from '../api/library-data';
+import { groupTimelineByDay } from './reading-timeline';
+
+function session(
+    date: string,
+    durationSec: number,
+    pagesRead: number,
+): LibraryTimelineEntry {
+    return {
+        kind: 'session',
+        date,
+        time: `${date}T10:00:00+00:00`,
+        duration_sec: durationSec,
+        pages_read: pagesRead,
+    };
+}
+
+function highlight(date: string): LibraryTimelineEntry {
+    return {
+        kind: 'highlight',
+        date,
+        time: `${date}T10:05:00+00:00`,
+        annotation: { id: `highlight-${date}`, text: 'Quote' },
+    };
+}
+
+describe('groupTimelineByDay', () => {
+    it('returns no days for an empty timeline', () => {
+        expect(groupTimelineByDay([])).toEqual([]);
+    });
+
+    it('sums sessions per day and keeps annotations in order', () => {
+        const days = groupTimelineByDay([
+            session('2026-03-01', 600, 12),
+            highlight('2026-03-01'),
+            session('2026-03-01', 300, 8),
+        ]);
+
+        expect(days).toHaveLength(1);
+        expect(days[0].readingTimeSec).toBe(900);
+        expect(days[0].pagesRead).toBe(20);
+        expect(days[0].entries.map((entry) => entry.kind)).toEqual([
+            'session',
+            'highlight',
+            'session',
+        ]);
+    });
+
+    it('counts the idle days between reading days', () => {
+        const days = groupTimelineByDay([
+            session('2026-03-01', 600, 12),
+            session('2026-03-02', 600, 12),
+            highlight('2026-03-08'),
+        ]);
+
+        expect(days.map((day) => day.idleDaysBefore)).toEqual([0, 0, 5]);
+    });
+
+    it('skips entries without a date', () => {
+        const days = groupTimelineByDay([
+            { kind: 'bookmark', annotation: { id: 'undated' } },
+            session('2026-03-01', 600, 12),
+        ]);
+
+        expect(days.map((day) => day.date)).toEqual(['2026-03-01']);
+    });
+});
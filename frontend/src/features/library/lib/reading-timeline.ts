@@ -0,0 +1,65 @@
+import { parsePlainDate } from '../../../shared/lib/intl/formatDate';
+import type { LibraryTimelineEntry } from '../api/library-data';
+
+/** Idle days between two reading days before the gap is shown as a break. */
+export const TIMELINE_BREAK_MIN_DAYS = 2;
+
+const MILLISECONDS_PER_DAY = 24 * 60 * 60 * 1000;
+
+export type TimelineDay = {
+    date: string;
+    entries: LibraryTimelineEntry[];
+    readingTimeSec: number;
+    pagesRead: number;
+    /** Days without any activity since the previous day on the timeline. */
+    idleDaysBefore: number;
+};
+
+function idleDaysBetween(previous: string, next: string): number {
+    const start = parsePlainDate(previous);
+    const end = parsePlainDate(next);
+    if (!start || !end) {
+        return 0;
+    }
+
+    const delta = Math.round(
+        (end.getTime() - start.getTime()) / MILLISECONDS_PER_DAY,
+    );
+    return Math.max(0, delta - 1);
+}
+
+/**
+ * Group dated timeline entries (already oldest first) by reading day.
+ * Entries without a date are skipped; the API lists those separately.
+ */
+export function groupTimelineByDay(
+    entries: LibraryTimelineEntry[],
+): TimelineDay[] {
+    const days: TimelineDay[] = [];
+
+    for (const entry of entries) {
+        if (!entry.date) {
+            continue;
+        }
+
+        let day = days[days.length - 1];
+        if (!day || day.date !== entry.date) {
+            day = {
+                date: entry.date,
+                entries: [],
+                readingTimeSec: 0,
+                pagesRead: 0,
+                idleDaysBefore: day ? idleDaysBetween(day.date, entry.date) : 0,
+            };
+            days.push(day);
+        }
+
+        day.entries.push(entry);
+        if (entry.kind === 'session') {
+            day.readingTimeSec += entry.duration_sec ?? 0;
+            day.pagesRead += entry.pages_read ?? 0;
+        }
+    }
+
+    return days;
+}
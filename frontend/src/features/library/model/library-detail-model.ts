@@ -2,6 +2,7 @@ export const LIBRARY_DETAIL_SECTION_KEYS = [
     'book-overview',
     'reading-stats',
     'page-activity',
+    'timeline',
     'review',
     'highlights',
     'bookmarks',
@@ -20,6 +21,7 @@ const DEFAULT_DETAIL_SECTION_STATE: LibraryDetailSectionVisibilityState = {
     'book-overview': true,
     'reading-stats': false,
     'page-activity': false,
+    timeline: false,
     review: true,
     highlights: true,
     bookmarks: true,
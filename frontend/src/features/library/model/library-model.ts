@@ -65,7 +65,9 @@ export function normalizeLibraryFilterValue(
     return value as LibraryFilterValue;
 }
 
-function sectionFromLibraryItem(item: LibraryListItem): LibrarySectionKey {
+export function sectionFromLibraryItem(
+    item: LibraryListItem,
+): LibrarySectionKey {
     if (item.status === 'reading') {
         return 'reading';
     }
@@ -98,7 +100,9 @@ export function bucketLibraryItems(
     LIBRARY_SECTION_KEYS.forEach((sectionKey) => {
         buckets[sectionKey].sort(
             (left, right) =>
-                left.title.localeCompare(right.title) ||
+                (left.title_sort ?? left.title).localeCompare(
+                    right.title_sort ?? right.title,
+                ) ||
                 left.id.localeCompare(right.id),
         );
     });
@@ -16,7 +16,10 @@ import { useQueryTransitionState } from '../../../shared/lib/state/useQueryTrans
 import { buttonVariants } from '../../../shared/ui/button/button-variants';
 import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
 import { PageContent } from '../../../shared/ui/layout/PageContent';
-import { LibraryDetailHeader } from '../components/LibraryDetailHeader';
+import {
+    LibraryDetailHeader,
+    type LibraryAuthorLink,
+} from '../components/LibraryDetailHeader';
 import { EditWarningModal } from '../components/EditWarningModal';
 import { useLibraryDetailQuery } from '../hooks/useLibraryQueries';
 import { useEditWarning } from '../hooks/useEditWarning';
@@ -26,7 +29,9 @@ import {
     useUpdateAnnotation,
     useDeleteAnnotation,
 } from '../api/library-mutations';
+import { useAuthorListQuery } from '../../authors/hooks/useAuthorQueries';
 import { isReaderFormatSupported } from '../../reader/lib/reader-format-support';
+import { useSeriesListQuery } from '../../series/hooks/useSeriesQueries';
 import {
     LIBRARY_DETAIL_SECTION_KEYS,
     defaultLibraryDetailSectionState,
@@ -40,6 +45,7 @@ import { LibraryOverviewSection } from '../sections/LibraryOverviewSection';
 import { LibraryReadingStatsSection } from '../sections/LibraryReadingStatsSection';
 import { LibraryPageActivitySection } from '../sections/LibraryPageActivitySection';
 import { LibraryReviewSection } from '../sections/LibraryReviewSection';
+import { LibraryTimelineSection } from '../sections/LibraryTimelineSection';
 
 type LibraryDetailRouteProps = {
     collection: LibraryCollection;
@@ -67,18 +73,57 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
     const detail = detailTransition.displayData;
     const item = detail?.item;
 
+    const seriesListQuery = useSeriesListQuery();
+    const seriesName = item?.series?.name.trim().toLowerCase();
+    const seriesSlug = useMemo(() => {
+        if (!seriesName) {
+            return null;
+        }
+
+        return (
+            seriesListQuery.data?.series.find(
+                (series) => series.name.toLowerCase() === seriesName,
+            )?.slug ?? null
+        );
+    }, [seriesListQuery.data?.series, seriesName]);
+
+    const authorListQuery = useAuthorListQuery();
+    const authorLinks = useMemo<LibraryAuthorLink[]>(() => {
+        const slugByName = new Map(
+            (authorListQuery.data?.authors ?? []).map((author) => [
+                author.name.toLowerCase(),
+                author.slug,
+            ]),
+        );
+
+        return (item?.authors ?? []).map((name) => {
+            const slug = slugByName.get(name.trim().toLowerCase());
+            return {
+                name,
+                href: slug
+                    ? buildRoutePath('author-detail', { id: slug })
+                    : null,
+            };
+        });
+    }, [authorListQuery.data?.authors, item?.authors]);
+
     const canWrite =
         siteQuery.data?.capabilities.has_writeback === true &&
         item?.has_metadata === true;
     const itemStats = detail?.statistics?.item_stats ?? null;
     const sessionStats = detail?.statistics?.session_stats ?? null;
     const completions = detail?.completions ?? null;
+    const timeline = detail?.timeline ?? null;
+    const hasTimeline =
+        (timeline?.entries.length ?? 0) > 0 ||
+        (timeline?.undated.length ?? 0) > 0;
 
     const highlightAnnotations = detail?.highlights ?? [];
     const bookmarkAnnotations = detail?.bookmarks ?? [];
 
     const noteCount = itemStats?.notes ?? 0;
     const highlightCount = itemStats?.highlights ?? 0;
+    const bookmarkCount = itemStats?.bookmarks ?? 0;
     const reviewNote = item?.review_note ?? '';
     const hasReviewNote =
         item?.review_note !== null && item?.review_note !== undefined;
@@ -86,6 +131,10 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
     const hasReview = hasReviewNote || hasRating;
     const hasPublisher =
         item?.publisher !== null && item?.publisher !== undefined;
+    const contributors = item?.contributors ?? null;
+    const hasContributors =
+        (contributors?.editors?.length ?? 0) > 0 ||
+        (contributors?.translators?.length ?? 0) > 0;
 
     // ── Write infrastructure ────────────────────────────────────────────
     const { guardedAction, warningOpen, acknowledge, cancel } =
@@ -198,6 +247,23 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
         );
     }
 
+    // Links using a retired slug move to the item's current URL.
+    const loadedItem = detailQuery.isPlaceholderData
+        ? undefined
+        : detailQuery.data?.item;
+    const canonicalId = loadedItem ? (loadedItem.slug ?? loadedItem.id) : null;
+    if (canonicalId && id !== canonicalId && id !== loadedItem?.id) {
+        return (
+            <Navigate
+                to={buildRoutePath(detailRouteIdForCollection(collection), {
+                    id: canonicalId,
+                })}
+                state={location.state}
+                replace
+            />
+        );
+    }
+
     const headerTitle = item?.title ?? collectionTitle(collection);
     const primaryAuthor = item?.authors[0];
     const returnTo = resolveDetailReturnPath(location.state);
@@ -217,8 +283,9 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
             <LibraryDetailHeader
                 title={headerTitle}
                 primaryAuthor={primaryAuthor}
+                authors={authorLinks}
                 collection={collection}
-                itemId={id}
+                itemId={item?.id ?? id}
                 backHref={backHref}
                 format={resolvedFormat}
             />
@@ -250,6 +317,8 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
                                 completions={completions}
                                 highlightCount={highlightCount}
                                 noteCount={noteCount}
+                                bookmarkCount={bookmarkCount}
+                                seriesSlug={seriesSlug}
                                 visible={sectionState['book-overview']}
                                 onToggle={() => toggle('book-overview')}
                                 canWrite={canWrite}
@@ -277,6 +346,14 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
                                 />
                             )}
 
+                            {timeline && hasTimeline && (
+                                <LibraryTimelineSection
+                                    timeline={timeline}
+                                    visible={sectionState.timeline}
+                                    onToggle={() => toggle('timeline')}
+                                />
+                            )}
+
                             {(hasReview || canWrite) && (
                                 <LibraryReviewSection
                                     note={reviewNote}
@@ -329,9 +406,14 @@ export function LibraryDetailRoute({ collection }: LibraryDetailRouteProps) {
                                     />
                                 )}
 
-                            {(hasPublisher || item!.identifiers.length > 0) && (
+                            {(hasPublisher ||
+                                hasContributors ||
+                                Boolean(item!.added_at) ||
+                                item!.identifiers.length > 0) && (
                                 <LibraryAdditionalInfoSection
                                     publisher={item!.publisher ?? null}
+                                    contributors={contributors}
+                                    addedAt={item!.added_at ?? null}
                                     identifiers={item!.identifiers}
                                     visible={sectionState['additional-info']}
                                     onToggle={() => toggle('additional-info')}
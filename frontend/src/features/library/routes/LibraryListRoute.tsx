@@ -5,6 +5,7 @@ import { listRouteIdForCollection } from '../../../app/routes/route-registry';
 import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
 import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
 import { translation } from '../../../shared/i18n';
+import { isTypingTarget } from '../../../shared/lib/dom/is-typing-target';
 import { useBookCardTiltEffect } from '../../../shared/lib/dom/useTiltEffect';
 import {
     patchRouteState,
@@ -14,11 +15,20 @@ import { useSectionVisibilityState } from '../../../shared/lib/state/useSectionV
 import { useQueryTransitionState } from '../../../shared/lib/state/useQueryTransitionState';
 import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
 import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { HighlightOfTheDayCard } from '../components/HighlightOfTheDayCard';
 import { LibraryEmptyState } from '../components/LibraryEmptyState';
 import { LibraryHeader } from '../components/LibraryHeader';
 import { LibrarySection } from '../components/LibrarySection';
+import { UpNextCard } from '../components/UpNextCard';
+import { useHighlightsQuery } from '../hooks/useHighlightsQuery';
+import { useIncrementalLimit } from '../hooks/useIncrementalLimit';
 import { useLibraryHoverPreviewEffect } from '../hooks/useLibraryHoverPreviewEffect';
 import { useLibraryListQuery } from '../hooks/useLibraryQueries';
+import { useUpNextQuery } from '../hooks/useUpNextQuery';
+import {
+    localDateKey,
+    pickHighlightOfTheDay,
+} from '../lib/highlight-of-the-day';
 import {
     LIBRARY_FILTER_VALUES,
     LIBRARY_SECTION_KEYS,
@@ -45,20 +55,6 @@ const SECTION_TITLE_KEYS: Record<LibrarySectionKey, string> = {
     unread: 'status.unread',
 };
 
-function isTypingTarget(target: EventTarget | null): boolean {
-    if (!(target instanceof HTMLElement)) {
-        return false;
-    }
-
-    const tagName = target.tagName;
-    return (
-        target.isContentEditable ||
-        tagName === 'INPUT' ||
-        tagName === 'TEXTAREA' ||
-        tagName === 'SELECT'
-    );
-}
-
 export function LibraryListRoute({ collection }: LibraryListRouteProps) {
     const location = useLocation();
     const navigate = useNavigate();
@@ -307,26 +303,72 @@ export function LibraryListRoute({ collection }: LibraryListRouteProps) {
         [effectiveFilterValue, normalizedSearch, sectionBuckets],
     );
 
+    const visibleItemCount = useMemo(
+        () =>
+            sectionRows.reduce((sum, section) => sum + section.items.length, 0),
+        [sectionRows],
+    );
+
+    // Large libraries render a page of cards at a time. Search and filters
+    // run on all items above, so only the rendering is split up. Collapsed
+    // sections render no cards until they are opened.
+    const isSectionExpanded = useCallback(
+        (sectionKey: LibrarySectionKey) =>
+            normalizedSearch ? true : sectionState[sectionKey],
+        [normalizedSearch, sectionState],
+    );
+    const expandedItemCount = sectionRows
+        .filter((section) => isSectionExpanded(section.sectionKey))
+        .reduce((sum, section) => sum + section.items.length, 0);
+    const { limit, hasMore, sentinelRef } = useIncrementalLimit(
+        expandedItemCount,
+        siteQuery.data?.page_size ?? 0,
+        `${collection}|${effectiveFilterValue}|${normalizedSearch}`,
+    );
+    const renderedRows = useMemo(() => {
+        let remaining = limit;
+        return sectionRows.map((section) => {
+            const totalCount = section.items.length;
+            if (!isSectionExpanded(section.sectionKey)) {
+                return { ...section, totalCount, items: [] };
+            }
+            const items = section.items.slice(0, Math.max(remaining, 0));
+            remaining -= items.length;
+            return { ...section, totalCount, items };
+        });
+    }, [isSectionExpanded, limit, sectionRows]);
+
     const visibleCardKey = useMemo(
         () =>
-            sectionRows
+            renderedRows
                 .flatMap((section) =>
                     section.items.map(
                         (item) => `${section.sectionKey}:${item.id}`,
                     ),
                 )
                 .join('|'),
-        [sectionRows],
+        [renderedRows],
     );
 
     useBookCardTiltEffect(`${collection}:${visibleCardKey}`);
     useLibraryHoverPreviewEffect(`${collection}:${visibleCardKey}`);
 
-    const visibleItemCount = useMemo(
-        () =>
-            sectionRows.reduce((sum, section) => sum + section.items.length, 0),
-        [sectionRows],
-    );
+    const highlightsQuery = useHighlightsQuery(!normalizedSearch);
+    const highlightOfTheDay = useMemo(() => {
+        const contentType = collection === 'comics' ? 'comic' : 'book';
+        const candidates = (highlightsQuery.data?.highlights ?? []).filter(
+            (highlight) => highlight.content_type === contentType,
+        );
+        return pickHighlightOfTheDay(candidates, localDateKey(new Date()));
+    }, [collection, highlightsQuery.data?.highlights]);
+
+    const upNextQuery = useUpNextQuery(!normalizedSearch);
+    const upNextEntries = useMemo(() => {
+        const contentType = collection === 'comics' ? 'comic' : 'book';
+        return (upNextQuery.data?.entries ?? []).filter(
+            (entry) => entry.item.content_type === contentType,
+        );
+    }, [collection, upNextQuery.data?.entries]);
 
     const pageTitle = translation.get(libraryTitleTranslationKey(collection));
 
@@ -365,14 +407,23 @@ export function LibraryListRoute({ collection }: LibraryListRouteProps) {
                             <LibraryEmptyState />
                         ) : (
                             <>
-                                {sectionRows.map((section) => {
-                                    if (section.items.length === 0) {
+                                {!normalizedSearch && highlightOfTheDay && (
+                                    <HighlightOfTheDayCard
+                                        highlight={highlightOfTheDay}
+                                    />
+                                )}
+                                {!normalizedSearch &&
+                                    upNextEntries.length > 0 && (
+                                        <UpNextCard entries={upNextEntries} />
+                                    )}
+                                {renderedRows.map((section) => {
+                                    if (section.totalCount === 0) {
                                         return null;
                                     }
 
-                                    const visible = normalizedSearch
-                                        ? true
-                                        : sectionState[section.sectionKey];
+                                    const visible = isSectionExpanded(
+                                        section.sectionKey,
+                                    );
 
                                     return (
                                         <LibrarySection
@@ -384,6 +435,7 @@ export function LibraryListRoute({ collection }: LibraryListRouteProps) {
                                                 ],
                                             )}
                                             items={section.items}
+                                            totalCount={section.totalCount}
                                             collection={collection}
                                             visible={visible}
                                             onToggle={() =>
@@ -394,6 +446,9 @@ export function LibraryListRoute({ collection }: LibraryListRouteProps) {
                                         />
                                     );
                                 })}
+                                {hasMore && (
+                                    <div ref={sentinelRef} aria-hidden="true" />
+                                )}
                             </>
                         )
                     }
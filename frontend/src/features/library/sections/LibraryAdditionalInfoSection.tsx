@@ -1,12 +1,25 @@
-import { LuArrowUpRight, LuBuilding2, LuHash } from 'react-icons/lu';
+import {
+    LuArrowUpRight,
+    LuBuilding2,
+    LuCalendarDays,
+    LuHash,
+    LuLanguages,
+    LuPencil,
+} from 'react-icons/lu';
 
 import { translation } from '../../../shared/i18n';
 import { MetricCard } from '../../../shared/ui/cards/MetricCard';
 import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
-import type { ExternalIdentifier } from '../api/library-data';
+import { formatAddedDate } from '../lib/library-detail-formatters';
+import type {
+    ExternalIdentifier,
+    LibraryContributors,
+} from '../api/library-data';
 
 type LibraryAdditionalInfoSectionProps = {
     publisher: string | null;
+    contributors: LibraryContributors | null;
+    addedAt: string | null;
     identifiers: ExternalIdentifier[];
     visible: boolean;
     onToggle: () => void;
@@ -14,10 +27,16 @@ type LibraryAdditionalInfoSectionProps = {
 
 export function LibraryAdditionalInfoSection({
     publisher,
+    contributors,
+    addedAt,
     identifiers,
     visible,
     onToggle,
 }: LibraryAdditionalInfoSectionProps) {
+    const editors = contributors?.editors ?? [];
+    const translators = contributors?.translators ?? [];
+    const addedDate = formatAddedDate(addedAt);
+
     return (
         <CollapsibleSection
             sectionKey="additional-info"
@@ -41,6 +60,46 @@ export function LibraryAdditionalInfoSection({
                     />
                 )}
 
+                {editors.length > 0 && (
+                    <MetricCard
+                        variant="inline"
+                        size="sm"
+                        icon={LuPencil}
+                        iconContainerClassName="bg-amber-500/20 dark:bg-linear-to-br dark:from-amber-500 dark:to-amber-600"
+                        iconClassName="text-amber-600 dark:text-white"
+                        value={editors.join(', ')}
+                        label={translation.get('editors', {
+                            count: editors.length,
+                        })}
+                    />
+                )}
+
+                {translators.length > 0 && (
+                    <MetricCard
+                        variant="inline"
+                        size="sm"
+                        icon={LuLanguages}
+                        iconContainerClassName="bg-teal-500/20 dark:bg-linear-to-br dark:from-teal-500 dark:to-teal-600"
+                        iconClassName="text-teal-600 dark:text-white"
+                        value={translators.join(', ')}
+                        label={translation.get('translators', {
+                            count: translators.length,
+                        })}
+                    />
+                )}
+
+                {addedDate !== null && (
+                    <MetricCard
+                        variant="inline"
+                        size="sm"
+                        icon={LuCalendarDays}
+                        iconContainerClassName="bg-sky-500/20 dark:bg-linear-to-br dark:from-sky-500 dark:to-sky-600"
+                        iconClassName="text-sky-600 dark:text-white"
+                        value={addedDate}
+                        label={translation.get('date-added')}
+                    />
+                )}
+
                 {identifiers.length > 0 && (
                     <div className="bg-white dark:bg-dark-850/50 border border-gray-200/70 dark:border-dark-700/70 rounded-lg p-6">
                         <h4 className="text-lg font-semibold text-gray-900 dark:text-white mb-4 flex items-center">
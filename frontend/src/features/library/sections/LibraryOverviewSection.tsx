@@ -5,6 +5,7 @@ import { HiOutlineBookOpen } from 'react-icons/hi2';
 import {
     LuArrowUpRight,
     LuBan,
+    LuBookmark,
     LuCheck,
     LuFileText,
     LuLanguages,
@@ -14,6 +15,7 @@ import {
 } from 'react-icons/lu';
 import type { IconType } from 'react-icons';
 
+import { buildRoutePath } from '../../../app/routes/route-registry';
 import { translation } from '../../../shared/i18n';
 import { formatNumber } from '../../../shared/lib/intl/formatNumber';
 import { Button } from '../../../shared/ui/button/Button';
@@ -76,6 +78,9 @@ type LibraryOverviewSectionProps = {
     completions: LibraryCompletions | null;
     highlightCount: number;
     noteCount: number;
+    bookmarkCount: number;
+    /** Slug of the item's series page, when its series has one. */
+    seriesSlug?: string | null;
     visible: boolean;
     onToggle: () => void;
     canWrite?: boolean;
@@ -142,6 +147,8 @@ export function LibraryOverviewSection({
     completions,
     highlightCount,
     noteCount,
+    bookmarkCount,
+    seriesSlug = null,
     visible,
     onToggle,
     canWrite = false,
@@ -150,6 +157,7 @@ export function LibraryOverviewSection({
 }: LibraryOverviewSectionProps) {
     const [coverFailed, setCoverFailed] = useState(false);
     const [statusModalOpen, setStatusModalOpen] = useState(false);
+    const [descriptionExpanded, setDescriptionExpanded] = useState(false);
 
     const handleOpenStatusModal = () => {
         if (guardedAction) {
@@ -176,10 +184,18 @@ export function LibraryOverviewSection({
     const pagesCount = item.pages ?? itemStats?.pages;
     const seriesSearchBasePath = item.search_base_path?.trim() || '/';
     const seriesSearchTerm = (item.series?.name ?? '').trim();
+    const seriesPath = seriesSlug
+        ? buildRoutePath('series-detail', { id: seriesSlug })
+        : `${seriesSearchBasePath}?search=${encodeURIComponent(seriesSearchTerm)}`;
     const sanitizedDescription = useMemo(
         () => sanitizeRichTextHtml(item.description ?? ''),
         [item.description],
     );
+    const sanitizedExcerpt = useMemo(
+        () => sanitizeRichTextHtml(item.description_excerpt ?? ''),
+        [item.description_excerpt],
+    );
+    const showExcerpt = Boolean(sanitizedExcerpt) && !descriptionExpanded;
 
     return (
         <>
@@ -318,6 +334,20 @@ export function LibraryOverviewSection({
                                 />
                             )}
 
+                            {isBook && (
+                                <MetricCard
+                                    className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                    icon={LuBookmark}
+                                    iconContainerClassName="bg-orange-500/20 dark:bg-linear-to-br dark:from-orange-500 dark:to-orange-600"
+                                    iconClassName="text-orange-600 dark:text-white"
+                                    value={formatNumber(bookmarkCount)}
+                                    label={translation.get(
+                                        'bookmarks-label',
+                                        bookmarkCount,
+                                    )}
+                                />
+                            )}
+
                             <MetricCard
                                 className="flex-1 min-w-[120px] sm:min-w-[140px]"
                                 size="sm"
@@ -341,11 +371,41 @@ export function LibraryOverviewSection({
                                     {translation.get('description')}
                                 </h3>
                                 <div
-                                    className="leading-relaxed prose max-w-none book-description"
+                                    className={`leading-relaxed prose max-w-none book-description${showExcerpt ? ' print:hidden' : ''}`}
                                     dangerouslySetInnerHTML={{
-                                        __html: sanitizedDescription,
+                                        __html: showExcerpt
+                                            ? sanitizedExcerpt
+                                            : sanitizedDescription,
                                     }}
                                 />
+                                {showExcerpt && (
+                                    <div
+                                        className="hidden print:block leading-relaxed prose max-w-none book-description"
+                                        dangerouslySetInnerHTML={{
+                                            __html: sanitizedDescription,
+                                        }}
+                                    />
+                                )}
+                                {sanitizedExcerpt && (
+                                    <button
+                                        type="button"
+                                        className="mt-3 print:hidden text-sm font-medium text-primary-600 dark:text-primary-400 hover:underline"
+                                        aria-expanded={descriptionExpanded}
+                                        onClick={() =>
+                                            setDescriptionExpanded(
+                                                (expanded) => !expanded,
+                                            )
+                                        }
+                                    >
+                                        {descriptionExpanded
+                                            ? translation.get(
+                                                  'description.read-less',
+                                              )
+                                            : translation.get(
+                                                  'description.read-more',
+                                              )}
+                                    </button>
+                                )}
                             </div>
                         )}
 
@@ -361,16 +421,26 @@ export function LibraryOverviewSection({
                                     {translation.get('series')}
                                 </h4>
 
-                                <Link
-                                    to={`${seriesSearchBasePath}?search=${encodeURIComponent(seriesSearchTerm)}`}
-                                    className="inline-flex items-center px-4 py-2 rounded-lg text-sm font-medium bg-gray-100 dark:bg-dark-700 text-primary-600 dark:text-primary-300 border border-gray-300 dark:border-dark-600 hover:bg-primary-50 dark:hover:bg-dark-600 hover:border-primary-500 hover:text-primary-700 dark:hover:text-primary-200 transition-colors"
-                                >
-                                    {seriesDisplay}
-                                    <LuArrowUpRight
-                                        className="w-4 h-4 ml-2"
-                                        aria-hidden="true"
-                                    />
-                                </Link>
+                                <div className="flex flex-wrap items-center gap-3">
+                                    <Link
+                                        to={seriesPath}
+                                        className="inline-flex items-center px-4 py-2 rounded-lg text-sm font-medium bg-gray-100 dark:bg-dark-700 text-primary-600 dark:text-primary-300 border border-gray-300 dark:border-dark-600 hover:bg-primary-50 dark:hover:bg-dark-600 hover:border-primary-500 hover:text-primary-700 dark:hover:text-primary-200 transition-colors"
+                                    >
+                                        {seriesDisplay}
+                                        <LuArrowUpRight
+                                            className="w-4 h-4 ml-2"
+                                            aria-hidden="true"
+                                        />
+                                    </Link>
+                                    {seriesSlug && (
+                                        <Link
+                                            to={buildRoutePath('series-list')}
+                                            className="text-sm font-medium text-primary-600 dark:text-primary-400 hover:underline"
+                                        >
+                                            {translation.get('series-page.all')}
+                                        </Link>
+                                    )}
+                                </div>
                             </div>
                         )}
 
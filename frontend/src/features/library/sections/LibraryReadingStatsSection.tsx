@@ -1,5 +1,11 @@
 import { HiOutlineBookOpen } from 'react-icons/hi2';
-import { LuCalendarDays, LuClock3, LuInfo, LuZap } from 'react-icons/lu';
+import {
+    LuCalendarDays,
+    LuClock3,
+    LuFileText,
+    LuInfo,
+    LuZap,
+} from 'react-icons/lu';
 
 import { translation } from '../../../shared/i18n';
 import {
@@ -38,6 +44,11 @@ export function LibraryReadingStatsSection({
     visible,
     onToggle,
 }: LibraryReadingStatsSectionProps) {
+    const totalReadPages = itemStats?.total_read_pages ?? 0;
+    // KOReader counts each page once, so after a re-read the lifetime total
+    // is not the sum of the per-completion page counts.
+    const hasRereads = (completions?.total_completions ?? 0) > 1;
+
     return (
         <CollapsibleSection
             sectionKey="reading-stats"
@@ -110,6 +121,30 @@ export function LibraryReadingStatsSection({
                     label={translation.get('pages-per-hour')}
                 />
 
+                {totalReadPages > 0 && (
+                    <MetricCard
+                        icon={LuFileText}
+                        iconContainerClassName="bg-cyan-500/20 dark:bg-linear-to-br dark:from-cyan-500 dark:to-cyan-600"
+                        iconClassName="text-cyan-600 dark:text-white"
+                        value={formatNumber(totalReadPages)}
+                        label={translation.get(
+                            hasRereads
+                                ? 'unique-pages-read'
+                                : 'total-pages-read',
+                        )}
+                    />
+                )}
+
+                {typeof sessionStats.pages_per_day === 'number' && (
+                    <MetricCard
+                        icon={LuFileText}
+                        iconContainerClassName="bg-teal-500/20 dark:bg-linear-to-br dark:from-teal-500 dark:to-teal-600"
+                        iconClassName="text-teal-600 dark:text-white"
+                        value={formatReadingSpeed(sessionStats.pages_per_day)}
+                        label={translation.get('average-pages-day')}
+                    />
+                )}
+
                 <MetricCard
                     icon={LuCalendarDays}
                     iconContainerClassName="bg-purple-500/20 dark:bg-linear-to-br dark:from-purple-500 dark:to-purple-600"
@@ -187,6 +222,19 @@ export function LibraryReadingStatsSection({
                                                     )}
                                                 </span>
 
+                                                {entry.pages_read > 0 && (
+                                                    <span className="flex items-center whitespace-nowrap">
+                                                        <LuFileText
+                                                            className="w-3.5 h-3.5 mr-1"
+                                                            aria-hidden="true"
+                                                        />
+                                                        {translation.get(
+                                                            'pages',
+                                                            entry.pages_read,
+                                                        )}
+                                                    </span>
+                                                )}
+
                                                 {averageSessionDuration !==
                                                     null && (
                                                     <span className="flex items-center whitespace-nowrap">
@@ -0,0 +1,180 @@
+import { useMemo } from 'react';
+import type { IconType } from 'react-icons';
+import { HiOutlineBookOpen } from 'react-icons/hi2';
+import {
+    LuBookmark,
+    LuHighlighter,
+    LuNotebookPen,
+    LuSofa,
+} from 'react-icons/lu';
+
+import { translation } from '../../../shared/i18n';
+import { formatDateObject } from '../../../shared/lib/intl/formatDate';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+import type {
+    LibraryTimeline,
+    LibraryTimelineEntry,
+} from '../api/library-data';
+import { formatIsoDate } from '../lib/library-detail-formatters';
+import {
+    TIMELINE_BREAK_MIN_DAYS,
+    groupTimelineByDay,
+} from '../lib/reading-timeline';
+
+type LibraryTimelineSectionProps = {
+    timeline: LibraryTimeline;
+    visible: boolean;
+    onToggle: () => void;
+};
+
+const ENTRY_ICONS: Record<LibraryTimelineEntry['kind'], IconType> = {
+    session: HiOutlineBookOpen,
+    highlight: LuHighlighter,
+    note: LuNotebookPen,
+    bookmark: LuBookmark,
+};
+
+function formatEntryTime(time: string | null | undefined): string | null {
+    if (!time) {
+        return null;
+    }
+
+    return formatDateObject(
+        new Date(time),
+        { hour: 'numeric', minute: '2-digit' },
+        '',
+    );
+}
+
+function entryLabel(entry: LibraryTimelineEntry): string {
+    if (entry.kind === 'session') {
+        return translation.get('reading-timeline.session', {
+            pages: entry.pages_read ?? 0,
+            duration: formatDuration(entry.duration_sec ?? 0),
+        });
+    }
+
+    const annotation = entry.annotation;
+    const excerpt =
+        entry.kind === 'note'
+            ? annotation?.note
+            : entry.kind === 'highlight'
+              ? annotation?.text
+              : null;
+    if (excerpt?.trim()) {
+        return excerpt.trim();
+    }
+
+    return translation.get('reading-timeline.bookmark', {
+        page: annotation?.pageno ?? '--',
+    });
+}
+
+function TimelineEntryRow({ entry }: { entry: LibraryTimelineEntry }) {
+    const Icon = ENTRY_ICONS[entry.kind];
+    const time = formatEntryTime(entry.time);
+    const chapter = entry.annotation?.chapter;
+
+    return (
+        <li className="-ml-3.5 flex items-start gap-3 py-1.5">
+            <span className="w-7 h-7 rounded-full bg-gray-100 dark:bg-dark-800 flex items-center justify-center shrink-0 ring-4 ring-white dark:ring-dark-900">
+                <Icon
+                    className="w-3.5 h-3.5 text-primary-600 dark:text-primary-400"
+                    aria-hidden="true"
+                />
+            </span>
+            <div className="flex-1 min-w-0 pt-1">
+                <p
+                    className={`text-sm text-gray-800 dark:text-dark-100 ${entry.kind === 'session' ? 'font-medium' : 'line-clamp-3'}`}
+                >
+                    {entryLabel(entry)}
+                </p>
+                {(time || chapter) && (
+                    <p className="text-xs text-gray-500 dark:text-dark-400 mt-0.5 truncate">
+                        {[time, chapter].filter(Boolean).join(' · ')}
+                    </p>
+                )}
+            </div>
+        </li>
+    );
+}
+
+export function LibraryTimelineSection({
+    timeline,
+    visible,
+    onToggle,
+}: LibraryTimelineSectionProps) {
+    const days = useMemo(
+        () => groupTimelineByDay(timeline.entries),
+        [timeline.entries],
+    );
+
+    return (
+        <CollapsibleSection
+            sectionKey="timeline"
+            defaultVisible={false}
+            accentClass="bg-linear-to-b from-teal-400 to-teal-600"
+            title={translation.get('reading-timeline')}
+            visible={visible}
+            onToggle={onToggle}
+            contentClassName="mb-8"
+        >
+            <ol className="space-y-6">
+                {days.map((day) => (
+                    <li key={day.date}>
+                        {day.idleDaysBefore >= TIMELINE_BREAK_MIN_DAYS && (
+                            <div className="flex items-center gap-2 mb-6 text-xs font-medium text-gray-500 dark:text-dark-400">
+                                <LuSofa
+                                    className="w-4 h-4"
+                                    aria-hidden="true"
+                                />
+                                {translation.get(
+                                    'reading-timeline.break',
+                                    day.idleDaysBefore,
+                                )}
+                            </div>
+                        )}
+                        <h3 className="flex flex-wrap items-baseline gap-x-3 mb-2">
+                            <span className="text-base font-semibold text-gray-900 dark:text-white">
+                                {formatIsoDate(day.date)}
+                            </span>
+                            {day.readingTimeSec > 0 && (
+                                <span className="text-sm text-gray-500 dark:text-dark-400">
+                                    {formatDuration(day.readingTimeSec)}
+                                </span>
+                            )}
+                        </h3>
+                        <ul className="ml-3.5 border-l border-gray-200 dark:border-dark-700">
+                            {day.entries.map((entry, index) => (
+                                <TimelineEntryRow
+                                    key={
+                                        entry.annotation?.id ??
+                                        `${entry.time}-${index}`
+                                    }
+                                    entry={entry}
+                                />
+                            ))}
+                        </ul>
+                    </li>
+                ))}
+
+                {timeline.undated.length > 0 && (
+                    <li>
+                        <h3 className="text-base font-semibold text-gray-900 dark:text-white mb-2">
+                            {translation.get('reading-timeline.undated')}
+                        </h3>
+                        <ul className="ml-3.5 border-l border-gray-200 dark:border-dark-700">
+                            {timeline.undated.map((entry, index) => (
+                                <TimelineEntryRow
+                                    key={entry.annotation?.id ?? index}
+                                    entry={entry}
+                                />
+                            ))}
+                        </ul>
+                    </li>
+                )}
+            </ol>
+        </CollapsibleSection>
+    );
+}
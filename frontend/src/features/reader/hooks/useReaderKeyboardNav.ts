@@ -1,17 +1,6 @@
 import { useEffect } from 'react';
 
-function shouldIgnoreKeyboardNavigation(target: EventTarget | null): boolean {
-    if (!(target instanceof HTMLElement)) {
-        return false;
-    }
-
-    const tagName = target.tagName.toLowerCase();
-    if (tagName === 'input' || tagName === 'textarea' || tagName === 'select') {
-        return true;
-    }
-
-    return target.isContentEditable;
-}
+import { isTypingTarget } from '../../../shared/lib/dom/is-typing-target';
 
 export function useReaderKeyboardNav(
     onPrev: () => void,
@@ -19,10 +8,7 @@ export function useReaderKeyboardNav(
 ): void {
     useEffect(() => {
         const handleKeyDown = (e: KeyboardEvent) => {
-            if (
-                e.defaultPrevented ||
-                shouldIgnoreKeyboardNavigation(e.target)
-            ) {
+            if (e.defaultPrevented || isTypingTarget(e.target)) {
                 return;
             }
 
@@ -143,7 +143,7 @@ export function useReaderView(
         ? normalizeLibraryFormat(item?.format)
         : null;
     const fileHref = supportsReader
-        ? api.getItemFileHref(id ?? '', item?.format)
+        ? api.getItemFileHref(item?.id ?? id ?? '', item?.format)
         : null;
     const backHref = id
         ? buildRoutePath(detailRouteIdForCollection(collection), { id })
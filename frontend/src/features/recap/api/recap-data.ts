@@ -4,6 +4,9 @@ import type {
     CompletionItem,
     CompletionsShareAssets,
     CompletionsSummary,
+    CompletionsTopItems,
+    CompletionSuperlatives,
+    RatingBucket,
 } from '../../../shared/contracts';
 
 export type RecapScope = ScopeValue;
@@ -13,6 +16,9 @@ export type {
     CompletionItem,
     CompletionsShareAssets,
     CompletionsSummary,
+    CompletionsTopItems,
+    CompletionSuperlatives,
+    RatingBucket,
 };
 
 interface RecapIndexResponse {
@@ -26,6 +32,7 @@ interface RecapYearResponse {
     months: CompletionGroup[];
     items: CompletionItem[];
     share_assets: CompletionsShareAssets | null;
+    top_items: CompletionsTopItems | null;
 }
 
 export async function loadRecapIndex(
@@ -48,7 +55,7 @@ export async function loadRecapYear(
     const data = await api.getReadingCompletions(scope, {
         year,
         groupBy: 'month',
-        include: 'summary,share_assets',
+        include: 'summary,share_assets,top_items',
     });
 
     return {
@@ -57,5 +64,6 @@ export async function loadRecapYear(
         months: data.groups ?? [],
         items: data.items ?? [],
         share_assets: data.share_assets ?? null,
+        top_items: data.top_items ?? null,
     };
 }
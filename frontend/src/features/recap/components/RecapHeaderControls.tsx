@@ -1,4 +1,4 @@
-import { LuDownload } from 'react-icons/lu';
+import { LuArrowLeftRight, LuDownload } from 'react-icons/lu';
 
 import { translation } from '../../../shared/i18n';
 import { Button } from '../../../shared/ui/button/Button';
@@ -21,6 +21,8 @@ type RecapHeaderControlsProps = {
     onToggleSort: () => void;
     shareEnabled: boolean;
     onShareClick: () => void;
+    compareEnabled: boolean;
+    onCompareClick: () => void;
 };
 
 export function RecapHeaderControls({
@@ -34,11 +36,14 @@ export function RecapHeaderControls({
     onToggleSort,
     shareEnabled,
     onShareClick,
+    compareEnabled,
+    onCompareClick,
 }: RecapHeaderControlsProps) {
     const sortLabel = sortNewestFirst
         ? translation.get('sort-order.newest-first')
         : translation.get('sort-order.oldest-first');
     const shareLabel = translation.get('download.recap-label');
+    const compareLabel = translation.get('recap-compare');
 
     return (
         <div className="flex items-center space-x-2 md:space-x-4">
@@ -61,6 +66,14 @@ export function RecapHeaderControls({
                 disabled={!shareEnabled}
             />
 
+            <Button
+                variant="neutral"
+                icon={LuArrowLeftRight}
+                aria-label={compareLabel}
+                onClick={onCompareClick}
+                disabled={!compareEnabled}
+            />
+
             <Button
                 variant="neutral"
                 icon={sortNewestFirst ? SortNewestIcon : SortOldestIcon}
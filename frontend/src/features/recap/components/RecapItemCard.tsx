@@ -9,10 +9,6 @@ import {
     LuStar,
 } from 'react-icons/lu';
 
-import {
-    buildRoutePath,
-    detailRouteIdForContentType,
-} from '../../../app/routes/route-registry';
 import { translation } from '../../../shared/i18n';
 import { useLazyImageSource } from '../../../shared/lib/dom/useLazyImageSource';
 import { formatDurationParts } from '../../../shared/lib/intl/formatDuration';
@@ -23,6 +19,7 @@ import type { CompletionItem } from '../api/recap-data';
 import {
     buildStarDisplay,
     formatRecapDateRange,
+    resolveRecapDetailPath,
     resolveRecapSearchBasePath,
 } from '../lib/recap-formatters';
 
@@ -32,20 +29,7 @@ type RecapItemCardProps = {
 
 export function RecapItemCard({ item }: RecapItemCardProps) {
     const location = useLocation();
-    const detailPath = useMemo(() => {
-        const itemId = item.item_id?.trim() ?? '';
-        if (!itemId) {
-            return null;
-        }
-
-        if (item.content_type !== 'book' && item.content_type !== 'comic') {
-            return null;
-        }
-
-        return buildRoutePath(detailRouteIdForContentType(item.content_type), {
-            id: itemId,
-        });
-    }, [item.content_type, item.item_id]);
+    const detailPath = useMemo(() => resolveRecapDetailPath(item), [item]);
     const coverUrl = item.item_cover?.trim() || null;
     const {
         imageRef,
@@ -131,6 +115,11 @@ export function RecapItemCard({ item }: RecapItemCardProps) {
                         <div className="flex items-start justify-between gap-4">
                             <div className="flex-1">
                                 {titleNode}
+                                {item.removed && (
+                                    <span className="inline-block mt-1 text-xs font-medium px-2 py-0.5 rounded-full border border-gray-200 dark:border-dark-600 text-gray-500 dark:text-dark-300 bg-gray-100/80 dark:bg-dark-700/60">
+                                        {translation.get('file-removed')}
+                                    </span>
+                                )}
                                 {item.series && (
                                     <div className="text-sm font-medium text-gray-500 dark:text-dark-300 mt-1">
                                         {item.series}
@@ -0,0 +1,89 @@
+import { useMemo } from 'react';
+import { Link, useLocation } from 'react-router';
+import { HiOutlineBookOpen } from 'react-icons/hi2';
+
+import { useLazyImageSource } from '../../../shared/lib/dom/useLazyImageSource';
+import { createDetailReturnState } from '../../../shared/lib/navigation/detail-return-state';
+import type { CompletionItem } from '../api/recap-data';
+import { resolveRecapDetailPath } from '../lib/recap-formatters';
+
+type RecapSuperlativeCardProps = {
+    label: string;
+    item: CompletionItem;
+    value: string;
+};
+
+export function RecapSuperlativeCard({
+    label,
+    item,
+    value,
+}: RecapSuperlativeCardProps) {
+    const location = useLocation();
+    const detailPath = useMemo(() => resolveRecapDetailPath(item), [item]);
+    const coverUrl = item.item_cover?.trim() || null;
+    const {
+        imageRef,
+        resolvedSrc: resolvedCoverSrc,
+        hasError: coverFailed,
+        onError: onCoverError,
+    } = useLazyImageSource({
+        src: coverUrl ?? '',
+    });
+    const detailReturnState = createDetailReturnState(
+        location.pathname,
+        location.search,
+    );
+
+    const coverVisual =
+        coverUrl && !coverFailed ? (
+            <img
+                ref={imageRef}
+                className="w-12 h-16 object-cover rounded-sm shadow-xs"
+                src={resolvedCoverSrc}
+                alt={`Cover of ${item.title}`}
+                loading="lazy"
+                onError={onCoverError}
+            />
+        ) : (
+            <div className="w-12 h-16 flex items-center justify-center rounded-sm border border-gray-200 dark:border-dark-600 bg-gray-50 dark:bg-dark-900/70 text-gray-400 dark:text-dark-400">
+                <HiOutlineBookOpen className="w-6 h-6" aria-hidden />
+            </div>
+        );
+    const titleClassName =
+        'block text-sm font-semibold text-gray-900 dark:text-white truncate';
+
+    return (
+        <div className="flex items-center gap-3 min-w-0">
+            {detailPath ? (
+                <Link
+                    to={detailPath}
+                    state={detailReturnState}
+                    className="shrink-0"
+                >
+                    {coverVisual}
+                </Link>
+            ) : (
+                <div className="shrink-0">{coverVisual}</div>
+            )}
+            <div className="min-w-0">
+                <div className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide">
+                    {label}
+                </div>
+                {detailPath ? (
+                    <Link
+                        to={detailPath}
+                        state={detailReturnState}
+                        className={`${titleClassName} hover:text-primary-600 dark:hover:text-primary-400 transition-colors`}
+                    >
+                        {item.title}
+                    </Link>
+                ) : (
+                    <span className={titleClassName}>{item.title}</span>
+                )}
+                <div className="text-xs font-medium text-gray-600 dark:text-dark-300">
+                    {value}
+                </div>
+            </div>
+        </div>
+    );
+}
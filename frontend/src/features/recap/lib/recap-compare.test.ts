@@ -0,0 +1,66 @@
+import { describe, expect, it } from 'vitest';
+
+import type { CompletionsSummary } from '../api/recap-data';
+import {
+    buildRecapCompareRows,
+    resolveRecapCompareYears,
+} from './recap-compare';
+
+function makeSummary(
+    overrides: Partial<CompletionsSummary> = {},
+): CompletionsSummary {
+    return {
+        total_items: 0,
+        total_started: 0,
+        total_reading_time_sec: 0,
+        longest_session_duration_sec: 0,
+        average_session_duration_sec: 0,
+        active_days: 0,
+        active_days_percentage: 0,
+        longest_streak_days: 0,
+        best_month: null,
+        ...overrides,
+    };
+}
+
+describe('buildRecapCompareRows', () => {
+    it('computes deltas as b minus a', () => {
+        const rows = buildRecapCompareRows(
+            makeSummary({ total_items: 12, total_reading_time_sec: 7200 }),
+            makeSummary({ total_items: 9, total_reading_time_sec: 10800 }),
+        );
+
+        const items = rows.find((row) => row.metric === 'items');
+        const time = rows.find((row) => row.metric === 'reading-time');
+        expect(items?.delta).toBe(-3);
+        expect(time?.delta).toBe(3600);
+        expect(time?.kind).toBe('duration');
+    });
+});
+
+describe('resolveRecapCompareYears', () => {
+    it('returns null with fewer than two years', () => {
+        expect(resolveRecapCompareYears([], null, null)).toBeNull();
+        expect(resolveRecapCompareYears([2025], 2024, 2025)).toBeNull();
+    });
+
+    it('defaults to the latest two years', () => {
+        expect(resolveRecapCompareYears([2023, 2025, 2024], null, null)).toEqual(
+            { a: 2024, b: 2025 },
+        );
+    });
+
+    it('ignores unavailable or duplicate requested years', () => {
+        expect(resolveRecapCompareYears([2023, 2025], 2019, 2025)).toEqual({
+            a: 2023,
+            b: 2025,
+        });
+        expect(resolveRecapCompareYears([2023, 2025], 2025, 2025)).toEqual({
+            a: 2023,
+            b: 2025,
+        });
+        expect(resolveRecapCompareYears([2023, 2024, 2025], 2023, 2024)).toEqual(
+            { a: 2023, b: 2024 },
+        );
+    });
+});
@@ -0,0 +1,92 @@
+import type { CompletionsSummary } from '../api/recap-data';
+
+export type RecapCompareMetric =
+    | 'items'
+    | 'reading-time'
+    | 'active-days'
+    | 'longest-streak'
+    | 'average-session'
+    | 'longest-session';
+
+export type RecapCompareRow = {
+    metric: RecapCompareMetric;
+    kind: 'count' | 'duration';
+    a: number;
+    b: number;
+    delta: number;
+};
+
+export type RecapCompareYears = {
+    a: number;
+    b: number;
+};
+
+const METRICS: ReadonlyArray<{
+    metric: RecapCompareMetric;
+    kind: RecapCompareRow['kind'];
+    value: (summary: CompletionsSummary) => number;
+}> = [
+    { metric: 'items', kind: 'count', value: (s) => s.total_items },
+    {
+        metric: 'reading-time',
+        kind: 'duration',
+        value: (s) => s.total_reading_time_sec,
+    },
+    { metric: 'active-days', kind: 'count', value: (s) => s.active_days },
+    {
+        metric: 'longest-streak',
+        kind: 'count',
+        value: (s) => s.longest_streak_days,
+    },
+    {
+        metric: 'average-session',
+        kind: 'duration',
+        value: (s) => s.average_session_duration_sec,
+    },
+    {
+        metric: 'longest-session',
+        kind: 'duration',
+        value: (s) => s.longest_session_duration_sec,
+    },
+];
+
+/** Side-by-side rows for two yearly summaries; `delta` is `b - a`. */
+export function buildRecapCompareRows(
+    a: CompletionsSummary,
+    b: CompletionsSummary,
+): RecapCompareRow[] {
+    return METRICS.map(({ metric, kind, value }) => {
+        const valueA = value(a);
+        const valueB = value(b);
+        return { metric, kind, a: valueA, b: valueB, delta: valueB - valueA };
+    });
+}
+
+/**
+ * Resolve the pair of years to compare. Requested years that have no data
+ * fall back to the latest year (`b`) and the most recent other year (`a`).
+ * Returns `null` when fewer than two years are available.
+ */
+export function resolveRecapCompareYears(
+    availableYears: number[],
+    requestedA: number | null,
+    requestedB: number | null,
+): RecapCompareYears | null {
+    const years = [...new Set(availableYears)].sort(
+        (left, right) => right - left,
+    );
+    if (years.length < 2) {
+        return null;
+    }
+
+    const b =
+        requestedB !== null && years.includes(requestedB)
+            ? requestedB
+            : years[0];
+    const a =
+        requestedA !== null && requestedA !== b && years.includes(requestedA)
+            ? requestedA
+            : years.find((year) => year !== b);
+
+    return a === undefined ? null : { a, b };
+}
@@ -1,3 +1,7 @@
+import {
+    buildRoutePath,
+    detailRouteIdForContentType,
+} from '../../../app/routes/route-registry';
 import {
     formatMonthKey,
     formatPlainDateRange,
@@ -54,3 +58,18 @@ export function resolveRecapSearchBasePath(
 
     return '/books';
 }
+
+export function resolveRecapDetailPath(item: CompletionItem): string | null {
+    const itemId = item.item_id?.trim() ?? '';
+    if (!itemId) {
+        return null;
+    }
+
+    if (item.content_type !== 'book' && item.content_type !== 'comic') {
+        return null;
+    }
+
+    return buildRoutePath(detailRouteIdForContentType(item.content_type), {
+        id: itemId,
+    });
+}
@@ -0,0 +1,50 @@
+import { describe, expect, it } from 'vitest';
+
+import {
+    buildRecapMonthPath,
+    parseRecapMonthParams,
+    recapMonthKey,
+    shiftRecapMonth,
+} from './recap-model';
+
+describe('parseRecapMonthParams', () => {
+    it('accepts zero-padded and plain month segments', () => {
+        expect(parseRecapMonthParams('2025', '03')).toEqual({
+            year: 2025,
+            month: 3,
+        });
+        expect(parseRecapMonthParams('2025', '11')).toEqual({
+            year: 2025,
+            month: 11,
+        });
+    });
+
+    it('rejects malformed or out-of-range values', () => {
+        expect(parseRecapMonthParams('2025', '13')).toBeNull();
+        expect(parseRecapMonthParams('2025', '0')).toBeNull();
+        expect(parseRecapMonthParams('25', '01')).toBeNull();
+        expect(parseRecapMonthParams('2025', 'jan')).toBeNull();
+        expect(parseRecapMonthParams(undefined, '01')).toBeNull();
+    });
+});
+
+describe('shiftRecapMonth', () => {
+    it('wraps across year boundaries', () => {
+        expect(shiftRecapMonth({ year: 2025, month: 1 }, -1)).toEqual({
+            year: 2024,
+            month: 12,
+        });
+        expect(shiftRecapMonth({ year: 2025, month: 12 }, 1)).toEqual({
+            year: 2026,
+            month: 1,
+        });
+    });
+});
+
+describe('recap month paths', () => {
+    it('builds padded keys and route paths', () => {
+        const target = { year: 2025, month: 4 };
+        expect(recapMonthKey(target)).toBe('2025-04');
+        expect(buildRecapMonthPath(target)).toBe('/recap/2025/04');
+    });
+});
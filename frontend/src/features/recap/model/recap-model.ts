@@ -1,3 +1,4 @@
+import { buildRoutePath } from '../../../app/routes/route-registry';
 import {
     patchRouteState,
     readRouteState,
@@ -9,6 +10,11 @@ type RecapViewState = {
     year: number | null;
 };
 
+export type RecapMonth = {
+    year: number;
+    month: number;
+};
+
 function normalizeRecapYear(value: unknown): number | null {
     if (typeof value !== 'number' || !Number.isFinite(value)) {
         return null;
@@ -95,3 +101,47 @@ export function orderRecapMonths(
         items: [...month.items].reverse(),
     }));
 }
+
+export function parseRecapMonthParams(
+    yearParam: string | undefined,
+    monthParam: string | undefined,
+): RecapMonth | null {
+    if (!yearParam || !monthParam) {
+        return null;
+    }
+    if (!/^\d{4}$/.test(yearParam) || !/^\d{1,2}$/.test(monthParam)) {
+        return null;
+    }
+
+    const year = normalizeRecapYear(Number(yearParam));
+    const month = Number(monthParam);
+    if (year === null || month < 1 || month > 12) {
+        return null;
+    }
+
+    return { year, month };
+}
+
+export function shiftRecapMonth(target: RecapMonth, offset: number): RecapMonth {
+    const index = target.year * 12 + (target.month - 1) + offset;
+    return {
+        year: Math.floor(index / 12),
+        month: (index % 12) + 1,
+    };
+}
+
+export function recapMonthKey(target: RecapMonth): string {
+    return `${target.year}-${String(target.month).padStart(2, '0')}`;
+}
+
+export function buildRecapMonthPath(target: RecapMonth): string {
+    return buildRoutePath('recap-month', {
+        year: String(target.year),
+        month: String(target.month).padStart(2, '0'),
+    });
+}
+
+export function monthFromRecapKey(monthKey: string): RecapMonth | null {
+    const [yearPart, monthPart] = monthKey.split('-');
+    return parseRecapMonthParams(yearPart, monthPart);
+}
@@ -0,0 +1,286 @@
+import { useMemo, useState } from 'react';
+import { useSearchParams } from 'react-router';
+import { LuArrowLeftRight } from 'react-icons/lu';
+
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { PageStateLayout } from '../../../shared/ui/feedback/PageStateLayout';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { ContentScopeFilter } from '../../../shared/ui/selectors/ContentScopeFilter';
+import { YearSelector } from '../../../shared/ui/selectors/YearSelector';
+import type { CompletionsSummary } from '../api/recap-data';
+import {
+    useRecapIndexQuery,
+    useRecapYearQuery,
+} from '../hooks/useRecapQueries';
+import {
+    buildRecapCompareRows,
+    resolveRecapCompareYears,
+    type RecapCompareMetric,
+    type RecapCompareRow,
+} from '../lib/recap-compare';
+import { formatRecapMonth } from '../lib/recap-formatters';
+import { readStoredRecapScope } from '../model/recap-model';
+
+function parseYearParam(value: string | null): number | null {
+    if (!value || !/^\d{4}$/.test(value)) {
+        return null;
+    }
+
+    return Number(value);
+}
+
+function metricLabel(metric: RecapCompareMetric): string {
+    switch (metric) {
+        case 'items':
+            return translation.get('status.completed');
+        case 'reading-time':
+            return translation.get('total-read-time');
+        case 'active-days':
+            return translation.get('active-days', 2);
+        case 'longest-streak':
+            return translation.get('streak.longest');
+        case 'average-session':
+            return translation.get('session.average');
+        case 'longest-session':
+            return translation.get('session.longest');
+    }
+}
+
+function formatMetricValue(row: RecapCompareRow, value: number): string {
+    return row.kind === 'duration'
+        ? formatDuration(value, { includeDays: true })
+        : formatNumber(value);
+}
+
+function formatDelta(row: RecapCompareRow): string {
+    if (row.delta === 0) {
+        return '±0';
+    }
+
+    const sign = row.delta > 0 ? '+' : '−';
+    return `${sign}${formatMetricValue(row, Math.abs(row.delta))}`;
+}
+
+function deltaClassName(delta: number): string {
+    if (delta > 0) {
+        return 'text-green-600 dark:text-green-400';
+    }
+    if (delta < 0) {
+        return 'text-red-600 dark:text-red-400';
+    }
+    return 'text-gray-500 dark:text-dark-400';
+}
+
+function RecapCompareTable({
+    yearA,
+    yearB,
+    summaryA,
+    summaryB,
+}: {
+    yearA: number;
+    yearB: number;
+    summaryA: CompletionsSummary;
+    summaryB: CompletionsSummary;
+}) {
+    const rows = buildRecapCompareRows(summaryA, summaryB);
+    const headerCellClass =
+        'px-4 py-3 text-right text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide';
+    const valueCellClass =
+        'px-4 py-3 text-right text-sm font-semibold text-gray-900 dark:text-white whitespace-nowrap';
+
+    return (
+        <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl shadow-xs overflow-x-auto">
+            <table className="w-full">
+                <thead className="border-b border-gray-200/70 dark:border-dark-700/50">
+                    <tr>
+                        <th className="px-4 py-3" />
+                        <th className={headerCellClass}>{yearA}</th>
+                        <th className={headerCellClass}>{yearB}</th>
+                        <th className={headerCellClass}>
+                            {translation.get('recap-compare.change')}
+                        </th>
+                    </tr>
+                </thead>
+                <tbody className="divide-y divide-gray-200/70 dark:divide-dark-700/50">
+                    {rows.map((row) => (
+                        <tr key={row.metric}>
+                            <td className="px-4 py-3 text-sm font-medium text-gray-600 dark:text-dark-300">
+                                {metricLabel(row.metric)}
+                            </td>
+                            <td className={valueCellClass}>
+                                {formatMetricValue(row, row.a)}
+                            </td>
+                            <td className={valueCellClass}>
+                                {formatMetricValue(row, row.b)}
+                            </td>
+                            <td
+                                className={`${valueCellClass} ${deltaClassName(row.delta)}`}
+                            >
+                                {formatDelta(row)}
+                            </td>
+                        </tr>
+                    ))}
+                    <tr>
+                        <td className="px-4 py-3 text-sm font-medium text-gray-600 dark:text-dark-300">
+                            {translation.get('best-month')}
+                        </td>
+                        <td className={valueCellClass}>
+                            {summaryA.best_month
+                                ? formatRecapMonth(summaryA.best_month)
+                                : '--'}
+                        </td>
+                        <td className={valueCellClass}>
+                            {summaryB.best_month
+                                ? formatRecapMonth(summaryB.best_month)
+                                : '--'}
+                        </td>
+                        <td className={valueCellClass} />
+                    </tr>
+                </tbody>
+            </table>
+        </div>
+    );
+}
+
+export function RecapCompareRoute() {
+    const [searchParams, setSearchParams] = useSearchParams();
+    const [scope, setScope] = useState(() => readStoredRecapScope());
+    const { siteQuery, showTypeFilter } = useSiteQuery();
+    useDocumentTitle(translation.get('recap-compare'), siteQuery.data?.title);
+
+    const recapIndexQuery = useRecapIndexQuery(scope);
+    const availableYears = useMemo(
+        () => [...(recapIndexQuery.data?.available_years ?? [])].reverse(),
+        [recapIndexQuery.data?.available_years],
+    );
+    const years = useMemo(
+        () =>
+            resolveRecapCompareYears(
+                availableYears,
+                parseYearParam(searchParams.get('a')),
+                parseYearParam(searchParams.get('b')),
+            ),
+        [availableYears, searchParams],
+    );
+
+    const yearAQuery = useRecapYearQuery(scope, years?.a ?? null);
+    const yearBQuery = useRecapYearQuery(scope, years?.b ?? null);
+    const summaryA = yearAQuery.data?.summary ?? null;
+    const summaryB = yearBQuery.data?.summary ?? null;
+
+    const selectYear = (key: 'a' | 'b', year: number) => {
+        if (!years) {
+            return;
+        }
+        const next = { ...years, [key]: year };
+        setSearchParams(
+            { a: String(next.a), b: String(next.b) },
+            { replace: true },
+        );
+    };
+
+    const isError =
+        recapIndexQuery.isError || yearAQuery.isError || yearBQuery.isError;
+    const showBlockingSpinner =
+        recapIndexQuery.isLoading ||
+        (years !== null && (yearAQuery.isLoading || yearBQuery.isLoading));
+
+    return (
+        <>
+            <PageHeader
+                title={translation.get('recap-compare')}
+                controls={
+                    <div className="flex items-center space-x-2 md:space-x-4">
+                        {years && (
+                            <>
+                                <YearSelector
+                                    years={availableYears.filter(
+                                        (year) => year !== years.b,
+                                    )}
+                                    selectedYear={years.a}
+                                    onSelect={(year) => selectYear('a', year)}
+                                    iconColorClass="text-gray-600 dark:text-gray-300 sm:text-green-400 sm:dark:text-green-400"
+                                    optionActiveClass="bg-green-50/50 dark:bg-dark-700/50 text-green-900 dark:text-white"
+                                    mobileFallback={String(years.a)}
+                                />
+                                <YearSelector
+                                    years={availableYears.filter(
+                                        (year) => year !== years.a,
+                                    )}
+                                    selectedYear={years.b}
+                                    onSelect={(year) => selectYear('b', year)}
+                                    iconColorClass="text-gray-600 dark:text-gray-300 sm:text-green-400 sm:dark:text-green-400"
+                                    optionActiveClass="bg-green-50/50 dark:bg-dark-700/50 text-green-900 dark:text-white"
+                                    mobileFallback={String(years.b)}
+                                />
+                            </>
+                        )}
+                        <ContentScopeFilter
+                            visible={showTypeFilter}
+                            value={scope}
+                            onChange={setScope}
+                        />
+                    </div>
+                }
+            />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={isError}
+                    error={
+                        recapIndexQuery.error ??
+                        yearAQuery.error ??
+                        yearBQuery.error
+                    }
+                    onRetry={() => {
+                        void recapIndexQuery.refetch();
+                        void yearAQuery.refetch();
+                        void yearBQuery.refetch();
+                    }}
+                    showBlockingSpinner={showBlockingSpinner}
+                    showOverlaySpinner={
+                        yearAQuery.isPlaceholderData ||
+                        yearBQuery.isPlaceholderData
+                    }
+                    hasData={!showBlockingSpinner}
+                    srLabel="Loading recap comparison"
+                    renderContent={() =>
+                        years && summaryA && summaryB ? (
+                            <RecapCompareTable
+                                yearA={years.a}
+                                yearB={years.b}
+                                summaryA={summaryA}
+                                summaryB={summaryB}
+                            />
+                        ) : (
+                            <PageStateLayout
+                                icon={
+                                    <LuArrowLeftRight
+                                        className="w-12 h-12 text-white"
+                                        aria-hidden
+                                    />
+                                }
+                                gradientFrom="from-purple-500"
+                                gradientTo="to-pink-500"
+                                glowFrom="from-purple-500/20"
+                                glowTo="to-pink-500/20"
+                                title={translation.get(
+                                    'recap-compare.need-two-years',
+                                )}
+                                description={translation.get(
+                                    'recap-compare.need-two-years-description',
+                                )}
+                            />
+                        )
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
@@ -0,0 +1,170 @@
+import { useEffect, useMemo, useState } from 'react';
+import { Navigate, useNavigate, useParams } from 'react-router';
+import { LuChevronLeft, LuChevronRight } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { useRecapCoverTiltEffect } from '../../../shared/lib/dom/useTiltEffect';
+import { formatMonthKey } from '../../../shared/lib/intl/formatDate';
+import { Button } from '../../../shared/ui/button/Button';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { ContentScopeFilter } from '../../../shared/ui/selectors/ContentScopeFilter';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import {
+    useRecapIndexQuery,
+    useRecapYearQuery,
+} from '../hooks/useRecapQueries';
+import {
+    buildRecapMonthPath,
+    orderRecapMonths,
+    parseRecapMonthParams,
+    persistRecapViewState,
+    readRecapSortNewest,
+    readStoredRecapScope,
+    recapMonthKey,
+    shiftRecapMonth,
+    type RecapMonth,
+} from '../model/recap-model';
+import { RecapEmptyState } from '../sections/RecapEmptyState';
+import { RecapTimelineSection } from '../sections/RecapTimelineSection';
+
+export function RecapMonthRoute() {
+    const params = useParams();
+    const target = useMemo(
+        () => parseRecapMonthParams(params.year, params.month),
+        [params.month, params.year],
+    );
+
+    if (!target) {
+        return <Navigate to={buildRoutePath('recap')} replace />;
+    }
+
+    return <RecapMonthView target={target} />;
+}
+
+function RecapMonthView({ target }: { target: RecapMonth }) {
+    const navigate = useNavigate();
+    const [scope, setScope] = useState(() => readStoredRecapScope());
+    const { siteQuery, showTypeFilter } = useSiteQuery();
+
+    const monthKey = recapMonthKey(target);
+    const monthTitle = formatMonthKey(monthKey, {
+        monthStyle: 'long',
+        includeYear: true,
+    });
+    useDocumentTitle(
+        `${translation.get('recap')} ${monthTitle}`,
+        siteQuery.data?.title,
+    );
+
+    const recapIndexQuery = useRecapIndexQuery(scope);
+    const hasYearData =
+        recapIndexQuery.data?.available_years.includes(target.year) ?? false;
+
+    // Years without completions have no yearly data to fetch; the month
+    // still renders, just with the empty state.
+    const recapYearQuery = useRecapYearQuery(
+        scope,
+        hasYearData ? target.year : null,
+    );
+    const month = useMemo(() => {
+        const group = recapYearQuery.data?.months.find(
+            (candidate) => candidate.key === monthKey,
+        );
+        if (!group) {
+            return null;
+        }
+
+        return orderRecapMonths([group], readRecapSortNewest())[0] ?? null;
+    }, [monthKey, recapYearQuery.data?.months]);
+
+    useRecapCoverTiltEffect(`${scope}:${monthKey}:${month?.items.length ?? 0}`);
+
+    useEffect(() => {
+        if (!recapIndexQuery.isSuccess) {
+            return;
+        }
+
+        persistRecapViewState({ scope, year: target.year });
+    }, [recapIndexQuery.isSuccess, scope, target.year]);
+
+    const goToMonth = (offset: number) => {
+        navigate(buildRecapMonthPath(shiftRecapMonth(target, offset)));
+        window.scrollTo({ top: 0, left: 0, behavior: 'auto' });
+    };
+
+    const isError = recapIndexQuery.isError || recapYearQuery.isError;
+    const showBlockingSpinner =
+        recapIndexQuery.isLoading ||
+        (hasYearData && recapYearQuery.isLoading);
+    const showOverlaySpinner =
+        recapIndexQuery.isPlaceholderData || recapYearQuery.isPlaceholderData;
+
+    return (
+        <>
+            <PageHeader
+                title={monthTitle}
+                controls={
+                    <div className="flex items-center space-x-2 md:space-x-4">
+                        <Button
+                            variant="neutral"
+                            icon={LuChevronLeft}
+                            aria-label={translation.get(
+                                'previous-month.aria-label',
+                            )}
+                            onClick={() => goToMonth(-1)}
+                        />
+                        <Button
+                            variant="neutral"
+                            icon={LuChevronRight}
+                            aria-label={translation.get(
+                                'next-month.aria-label',
+                            )}
+                            onClick={() => goToMonth(1)}
+                        />
+                        <ContentScopeFilter
+                            visible={showTypeFilter}
+                            value={scope}
+                            onChange={setScope}
+                        />
+                    </div>
+                }
+            />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={isError}
+                    error={recapIndexQuery.error ?? recapYearQuery.error}
+                    onRetry={() => {
+                        void recapIndexQuery.refetch();
+                        if (hasYearData) {
+                            void recapYearQuery.refetch();
+                        }
+                    }}
+                    showBlockingSpinner={showBlockingSpinner}
+                    showOverlaySpinner={showOverlaySpinner}
+                    hasData={!showBlockingSpinner}
+                    srLabel="Loading recap month"
+                    renderContent={() =>
+                        month ? (
+                            <div
+                                className="recap-timeline space-y-6"
+                                id="recapTimeline"
+                            >
+                                <RecapTimelineSection
+                                    months={[month]}
+                                    scope={scope}
+                                />
+                            </div>
+                        ) : (
+                            <RecapEmptyState hasYearContext={true} />
+                        )
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
@@ -1,5 +1,7 @@
 import { useEffect, useMemo, useState } from 'react';
+import { useNavigate } from 'react-router';
 
+import { buildRoutePath } from '../../../app/routes/route-registry';
 import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
 import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
 import { translation } from '../../../shared/i18n';
@@ -25,11 +27,15 @@ import {
     readRecapSortNewest,
     resolveLatestYear,
 } from '../model/recap-model';
+import { useStatisticsAbandonedQuery } from '../../statistics/hooks/useStatisticsQueries';
+import { RecapDidNotFinishSection } from '../sections/RecapDidNotFinishSection';
 import { RecapEmptyState } from '../sections/RecapEmptyState';
 import { RecapSummarySection } from '../sections/RecapSummarySection';
 import { RecapTimelineSection } from '../sections/RecapTimelineSection';
+import { RecapTopItemsSection } from '../sections/RecapTopItemsSection';
 
 export function RecapRoute() {
+    const navigate = useNavigate();
     const [scope, setScope] = useState(() => readStoredRecapScope());
     const [selectedYear, setSelectedYear] = useState<number | null>(() =>
         readStoredRecapYear(),
@@ -44,6 +50,7 @@ export function RecapRoute() {
     const { siteQuery, showTypeFilter } = useSiteQuery();
 
     const recapIndexQuery = useRecapIndexQuery(scope);
+    const abandonedQuery = useStatisticsAbandonedQuery(scope);
     const recapIndexTransition = useQueryTransitionState({
         data: recapIndexQuery.data,
         isLoading: recapIndexQuery.isLoading,
@@ -169,6 +176,10 @@ export function RecapRoute() {
                         }}
                         shareEnabled={Boolean(shareAssets)}
                         onShareClick={() => setShareModalOpenKey(shareResetKey)}
+                        compareEnabled={availableYears.length >= 2}
+                        onCompareClick={() =>
+                            navigate(buildRoutePath('recap-compare'))
+                        }
                     />
                 }
             />
@@ -233,9 +244,21 @@ export function RecapRoute() {
                                                 summary={recapYear.summary}
                                             />
                                         )}
+                                        {recapYear.top_items && (
+                                            <RecapTopItemsSection
+                                                topItems={recapYear.top_items}
+                                            />
+                                        )}
+                                        {abandonedQuery.data && (
+                                            <RecapDidNotFinishSection
+                                                year={recapYear.year}
+                                                items={abandonedQuery.data.items}
+                                            />
+                                        )}
                                         <RecapTimelineSection
                                             months={orderedMonths}
                                             scope={scope}
+                                            linkMonths
                                         />
                                     </div>
                                 </div>
@@ -0,0 +1,53 @@
+import { LuClock3 } from 'react-icons/lu';
+
+import type { AbandonedItem } from '../../../shared/contracts';
+import { translation } from '../../../shared/i18n';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { AbandonedItemRow } from '../../statistics/components/AbandonedItemRow';
+
+type RecapDidNotFinishSectionProps = {
+    year: number;
+    items: AbandonedItem[];
+};
+
+export function RecapDidNotFinishSection({
+    year,
+    items,
+}: RecapDidNotFinishSectionProps) {
+    const yearItems = items.filter((item) =>
+        item.last_read_date?.startsWith(`${year}-`),
+    );
+    if (yearItems.length === 0) {
+        return null;
+    }
+
+    const totalTimeSec = yearItems.reduce(
+        (sum, item) => sum + item.reading_time_sec,
+        0,
+    );
+
+    return (
+        <section className="relative pl-10 recap-event">
+            <span className="recap-dot bg-gray-400 dark:bg-dark-400"></span>
+            <div className="flex flex-col space-y-3">
+                <div className="flex flex-col md:flex-row md:items-center md:justify-between gap-2">
+                    <h3 className="text-xl md:text-2xl font-bold text-gray-900 dark:text-white">
+                        {translation.get('did-not-finish')}
+                    </h3>
+                    <div className="inline-flex items-center self-start gap-1.5 px-3 py-1.5 rounded-lg bg-linear-to-br from-rose-500/10 to-rose-400/5 dark:from-rose-500/20 dark:to-rose-400/10 border border-rose-200/50 dark:border-rose-700/30 text-rose-700 dark:text-rose-300 text-sm font-medium">
+                        <LuClock3 className="w-4 h-4" aria-hidden />
+                        {translation.get('did-not-finish.year-entry', {
+                            count: yearItems.length,
+                            time: formatDuration(totalTimeSec),
+                        })}
+                    </div>
+                </div>
+                <ul className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 shadow-xs space-y-3">
+                    {yearItems.map((item) => (
+                        <AbandonedItemRow key={item.id} item={item} />
+                    ))}
+                </ul>
+            </div>
+        </section>
+    );
+}
@@ -86,6 +86,14 @@ export function RecapSummarySection({
                         value={
                             <span className="text-2xl/none md:text-3xl font-black text-gray-900 dark:text-white">
                                 {formatNumber(summary.total_items)}
+                                {summary.total_started > 0 && (
+                                    <span className="ml-2 text-xs font-medium text-gray-500 dark:text-gray-400">
+                                        {translation.get(
+                                            'items-started',
+                                            summary.total_started,
+                                        )}
+                                    </span>
+                                )}
                             </span>
                         }
                         label={completionLabel(scope, summary.total_items)}
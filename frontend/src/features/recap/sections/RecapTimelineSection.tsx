@@ -1,15 +1,18 @@
+import { Link } from 'react-router';
 import { HiOutlineBookOpen } from 'react-icons/hi2';
-import { LuClock3 } from 'react-icons/lu';
+import { LuBookPlus, LuClock3 } from 'react-icons/lu';
 
 import { translation } from '../../../shared/i18n';
 import { formatDuration } from '../../../shared/lib/intl/formatDuration';
 import type { CompletionGroup, RecapScope } from '../api/recap-data';
 import { formatRecapMonth } from '../lib/recap-formatters';
 import { RecapItemCard } from '../components/RecapItemCard';
+import { buildRecapMonthPath, monthFromRecapKey } from '../model/recap-model';
 
 type RecapTimelineSectionProps = {
     months: CompletionGroup[];
     scope: RecapScope;
+    linkMonths?: boolean;
 };
 
 function completionLabel(scope: RecapScope, count: number): string {
@@ -22,9 +25,36 @@ function completionLabel(scope: RecapScope, count: number): string {
     return translation.get('status.completed');
 }
 
+function MonthHeading({
+    monthKey,
+    linked,
+}: {
+    monthKey: string;
+    linked: boolean;
+}) {
+    const target = linked ? monthFromRecapKey(monthKey) : null;
+    const label = formatRecapMonth(monthKey);
+
+    return (
+        <h3 className="text-xl md:text-2xl font-bold text-gray-900 dark:text-white">
+            {target ? (
+                <Link
+                    to={buildRecapMonthPath(target)}
+                    className="hover:text-primary-600 dark:hover:text-primary-400 transition-colors"
+                >
+                    {label}
+                </Link>
+            ) : (
+                label
+            )}
+        </h3>
+    );
+}
+
 export function RecapTimelineSection({
     months,
     scope,
+    linkMonths = false,
 }: RecapTimelineSectionProps) {
     return (
         <>
@@ -37,9 +67,10 @@ export function RecapTimelineSection({
                     <div className="relative pl-10 recap-event">
                         <span className="recap-dot bg-gray-400 dark:bg-dark-400"></span>
                         <div className="flex flex-col md:flex-row md:items-center md:justify-between gap-2">
-                            <h3 className="text-xl md:text-2xl font-bold text-gray-900 dark:text-white">
-                                {formatRecapMonth(month.key)}
-                            </h3>
+                            <MonthHeading
+                                monthKey={month.key}
+                                linked={linkMonths}
+                            />
 
                             <div className="flex items-center gap-2">
                                 <div className="inline-flex items-center gap-1.5 px-3 py-1.5 rounded-lg bg-linear-to-br from-blue-500/10 to-blue-400/5 dark:from-blue-500/20 dark:to-blue-400/10 border border-blue-200/50 dark:border-blue-700/30 text-blue-700 dark:text-blue-300 text-sm font-medium">
@@ -56,6 +87,21 @@ export function RecapTimelineSection({
                                     )}
                                 </div>
 
+                                {month.items_started > 0 && (
+                                    <div className="inline-flex items-center gap-1.5 px-3 py-1.5 rounded-lg bg-linear-to-br from-green-500/10 to-green-400/5 dark:from-green-500/20 dark:to-green-400/10 border border-green-200/50 dark:border-green-700/30 text-green-700 dark:text-green-300 text-sm font-medium">
+                                        <LuBookPlus
+                                            className="w-4 h-4"
+                                            aria-hidden
+                                        />
+                                        <span className="month-items-started">
+                                            {translation.get(
+                                                'items-started',
+                                                month.items_started,
+                                            )}
+                                        </span>
+                                    </div>
+                                )}
+
                                 <div className="inline-flex items-center gap-1.5 px-3 py-1.5 rounded-lg bg-linear-to-br from-purple-500/10 to-purple-400/5 dark:from-purple-500/20 dark:to-purple-400/10 border border-purple-200/50 dark:border-purple-700/30 text-purple-700 dark:text-purple-300 text-sm font-medium">
                                     <LuClock3 className="w-4 h-4" aria-hidden />
                                     <span className="month-hours-display font-semibold">
@@ -69,6 +115,16 @@ export function RecapTimelineSection({
                         </div>
                     </div>
 
+                    {month.items.length === 0 && (
+                        <p className="pl-10 text-sm text-gray-500 dark:text-dark-400">
+                            {translation.get(
+                                month.reading_time_sec > 0
+                                    ? 'recap-idle-month.nothing-finished'
+                                    : 'recap-idle-month.no-reading',
+                            )}
+                        </p>
+                    )}
+
                     {month.items.map((item, index) => (
                         <RecapItemCard
                             key={`${month.key}:${item.title}:${item.end_date}:${index}`}
@@ -0,0 +1,214 @@
+import type { ReactNode } from 'react';
+import { LuStar } from 'react-icons/lu';
+
+import { translation } from '../../../shared/i18n';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import type {
+    CompletionItem,
+    CompletionSuperlatives,
+    CompletionsTopItems,
+    RatingBucket,
+} from '../api/recap-data';
+import { RecapSuperlativeCard } from '../components/RecapSuperlativeCard';
+import { buildStarDisplay } from '../lib/recap-formatters';
+
+type RecapTopItemsSectionProps = {
+    topItems: CompletionsTopItems;
+};
+
+const cardClassName =
+    'bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 shadow-xs';
+
+function StarRow({ rating }: { rating: number | null | undefined }) {
+    return (
+        <span className="flex items-center gap-0.5 shrink-0">
+            {buildStarDisplay(rating).map((filled, index) => (
+                <LuStar
+                    key={index}
+                    className={`w-3.5 h-3.5 ${
+                        filled
+                            ? 'text-yellow-400 fill-yellow-400'
+                            : 'text-gray-300 dark:text-dark-500'
+                    }`}
+                    aria-hidden
+                />
+            ))}
+        </span>
+    );
+}
+
+function TopItemList({
+    title,
+    items,
+    renderValue,
+}: {
+    title: string;
+    items: CompletionItem[];
+    renderValue: (item: CompletionItem) => ReactNode;
+}) {
+    return (
+        <div className={cardClassName}>
+            <h4 className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide mb-3">
+                {title}
+            </h4>
+            <ol className="space-y-2">
+                {items.map((item, index) => (
+                    <li
+                        key={`${item.item_id ?? item.title}:${item.end_date}`}
+                        className="flex items-center justify-between gap-3"
+                    >
+                        <span className="flex items-center gap-2 min-w-0">
+                            <span className="w-4 text-sm font-bold text-gray-400 dark:text-dark-400 shrink-0">
+                                {formatNumber(index + 1)}
+                            </span>
+                            <span className="text-sm font-medium text-gray-900 dark:text-white truncate">
+                                {item.title}
+                            </span>
+                        </span>
+                        {renderValue(item)}
+                    </li>
+                ))}
+            </ol>
+        </div>
+    );
+}
+
+function RatingDistribution({ buckets }: { buckets: RatingBucket[] }) {
+    const maxCount = Math.max(1, ...buckets.map((bucket) => bucket.count));
+
+    return (
+        <div className={cardClassName}>
+            <h4 className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide mb-3">
+                {translation.get('recap-top-items.ratings')}
+            </h4>
+            <div className="space-y-1.5">
+                {[...buckets].reverse().map((bucket) => (
+                    <div
+                        key={bucket.rating}
+                        className="flex items-center gap-2"
+                    >
+                        <StarRow rating={bucket.rating} />
+                        <div className="flex-1 h-2 rounded-full bg-gray-100 dark:bg-dark-700 overflow-hidden">
+                            <div
+                                className="h-full rounded-full bg-yellow-400"
+                                style={{
+                                    width: `${(bucket.count / maxCount) * 100}%`,
+                                }}
+                            />
+                        </div>
+                        <span className="w-6 text-right text-xs font-medium text-gray-600 dark:text-dark-300">
+                            {formatNumber(bucket.count)}
+                        </span>
+                    </div>
+                ))}
+            </div>
+        </div>
+    );
+}
+
+function Superlatives({
+    superlatives,
+}: {
+    superlatives: CompletionSuperlatives;
+}) {
+    const formatPages = (item: CompletionItem) =>
+        translation.get('pages', item.total_pages ?? 0);
+    const formatTime = (item: CompletionItem) =>
+        formatDuration(item.reading_time_sec, { includeDays: true });
+    const entries = [
+        {
+            key: 'longest-pages',
+            item: superlatives.longest_by_pages,
+            format: formatPages,
+        },
+        {
+            key: 'shortest-pages',
+            item: superlatives.shortest_by_pages,
+            format: formatPages,
+        },
+        {
+            key: 'longest-time',
+            item: superlatives.longest_by_time,
+            format: formatTime,
+        },
+        {
+            key: 'shortest-time',
+            item: superlatives.shortest_by_time,
+            format: formatTime,
+        },
+    ].flatMap(({ key, item, format }) =>
+        item ? [{ key, item, value: format(item) }] : [],
+    );
+
+    if (entries.length === 0) {
+        return null;
+    }
+
+    return (
+        <div className={cardClassName}>
+            <h4 className="text-[11px] font-medium text-gray-500 dark:text-gray-400 uppercase tracking-wide mb-3">
+                {translation.get('recap-superlatives')}
+            </h4>
+            <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-3">
+                {entries.map(({ key, item, value }) => (
+                    <RecapSuperlativeCard
+                        key={key}
+                        label={translation.get(`recap-superlatives.${key}`)}
+                        item={item}
+                        value={value}
+                    />
+                ))}
+            </div>
+        </div>
+    );
+}
+
+export function RecapTopItemsSection({ topItems }: RecapTopItemsSectionProps) {
+    const distribution = topItems.rating_distribution ?? null;
+
+    return (
+        <section className="relative pl-10 recap-event">
+            <span className="recap-dot bg-gray-400 dark:bg-dark-400"></span>
+            <div className="flex flex-col space-y-3">
+                <h3 className="text-xl md:text-2xl font-bold text-gray-900 dark:text-white">
+                    {translation.get('recap-top-items')}
+                </h3>
+
+                <div className="grid grid-cols-1 lg:grid-cols-3 gap-2 md:gap-3">
+                    {topItems.highest_rated.length > 0 && (
+                        <TopItemList
+                            title={translation.get(
+                                'recap-top-items.highest-rated',
+                            )}
+                            items={topItems.highest_rated}
+                            renderValue={(item) => (
+                                <StarRow rating={item.rating} />
+                            )}
+                        />
+                    )}
+                    {topItems.longest.length > 0 && (
+                        <TopItemList
+                            title={translation.get('recap-top-items.longest')}
+                            items={topItems.longest}
+                            renderValue={(item) => (
+                                <span className="text-xs font-medium text-gray-600 dark:text-dark-300 shrink-0">
+                                    {formatDuration(item.reading_time_sec, {
+                                        includeDays: true,
+                                    })}
+                                </span>
+                            )}
+                        />
+                    )}
+                    {distribution && (
+                        <RatingDistribution buckets={distribution} />
+                    )}
+                </div>
+
+                {topItems.superlatives && (
+                    <Superlatives superlatives={topItems.superlatives} />
+                )}
+            </div>
+        </section>
+    );
+}
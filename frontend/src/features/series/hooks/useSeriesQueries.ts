@@ -0,0 +1,19 @@
+import { keepPreviousData, useQuery } from '@tanstack/react-query';
+
+import { api } from '../../../shared/api';
+
+export function useSeriesListQuery() {
+    return useQuery({
+        queryKey: ['series-list'] as const,
+        queryFn: () => api.getSeriesList(),
+    });
+}
+
+export function useSeriesDetailQuery(slug: string | undefined) {
+    return useQuery({
+        queryKey: ['series-detail', slug] as const,
+        queryFn: () => api.getSeries(slug ?? ''),
+        enabled: Boolean(slug),
+        placeholderData: keepPreviousData,
+    });
+}
@@ -0,0 +1,134 @@
+import { Link, useParams } from 'react-router';
+import { HiOutlineBookOpen } from 'react-icons/hi2';
+import { LuCheck, LuClock, LuLibrary } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { useBookCardTiltEffect } from '../../../shared/lib/dom/useTiltEffect';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { buttonVariants } from '../../../shared/ui/button/button-variants';
+import { MetricCard } from '../../../shared/ui/cards/MetricCard';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { LibraryCard } from '../../library/components/LibraryCard';
+import { sectionFromLibraryItem } from '../../library/model/library-model';
+import { useSeriesDetailQuery } from '../hooks/useSeriesQueries';
+
+export function SeriesDetailRoute() {
+    const params = useParams();
+    const slug = params.id;
+    const { siteQuery } = useSiteQuery();
+    const detailQuery = useSeriesDetailQuery(slug);
+    const detail = detailQuery.data;
+    const series = detail?.series;
+    const title = series?.name ?? translation.get('series');
+    useDocumentTitle(title, siteQuery.data?.title);
+    useBookCardTiltEffect(`series:${slug}:${detail?.items.length ?? 0}`);
+
+    return (
+        <>
+            <PageHeader
+                title={title}
+                controls={
+                    <Link
+                        to={buildRoutePath('series-list')}
+                        className={buttonVariants({ variant: 'neutral' })}
+                    >
+                        {translation.get('series-page.all')}
+                    </Link>
+                }
+            />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={detailQuery.isError}
+                    error={detailQuery.error}
+                    onRetry={() => void detailQuery.refetch()}
+                    showBlockingSpinner={detailQuery.isLoading}
+                    showOverlaySpinner={detailQuery.isPlaceholderData}
+                    hasData={Boolean(detail)}
+                    srLabel="Loading series"
+                    renderContent={() =>
+                        series && detail ? (
+                            <>
+                                <div className="flex flex-wrap gap-3 sm:gap-4">
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuLibrary}
+                                        iconContainerClassName="bg-pink-500/20 dark:bg-linear-to-br dark:from-pink-500 dark:to-pink-600"
+                                        iconClassName="text-pink-600 dark:text-white"
+                                        value={formatNumber(series.item_count)}
+                                        label={translation.get(
+                                            'book-label',
+                                            series.item_count,
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuCheck}
+                                        iconContainerClassName="bg-green-500/20 dark:bg-linear-to-br dark:from-green-500 dark:to-green-600"
+                                        iconClassName="text-green-600 dark:text-white"
+                                        value={translation.get(
+                                            'series-page.progress',
+                                            {
+                                                completed:
+                                                    series.completed_count,
+                                                total: series.item_count,
+                                            },
+                                        )}
+                                        label={translation.get(
+                                            'status.completed',
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={HiOutlineBookOpen}
+                                        iconContainerClassName="bg-primary-500/20 dark:bg-linear-to-br dark:from-primary-500 dark:to-primary-600"
+                                        iconClassName="text-primary-600 dark:text-white"
+                                        value={formatNumber(
+                                            series.reading_count,
+                                        )}
+                                        label={translation.get(
+                                            'status.reading-short',
+                                        )}
+                                    />
+                                    <MetricCard
+                                        className="flex-1 min-w-[120px] sm:min-w-[140px]"
+                                        icon={LuClock}
+                                        iconContainerClassName="bg-indigo-500/20 dark:bg-linear-to-br dark:from-indigo-500 dark:to-indigo-600"
+                                        iconClassName="text-indigo-600 dark:text-white"
+                                        value={formatDuration(
+                                            series.total_reading_time_sec,
+                                        )}
+                                        label={translation.get('reading-time')}
+                                    />
+                                </div>
+
+                                <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-5 xl:grid-cols-6 2xl:grid-cols-8 gap-4 md:gap-6">
+                                    {detail.items.map((item) => (
+                                        <LibraryCard
+                                            key={item.id}
+                                            item={item}
+                                            collection={
+                                                item.content_type === 'comic'
+                                                    ? 'comics'
+                                                    : 'books'
+                                            }
+                                            sectionKey={sectionFromLibraryItem(
+                                                item,
+                                            )}
+                                        />
+                                    ))}
+                                </div>
+                            </>
+                        ) : null
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
@@ -0,0 +1,132 @@
+import { useState } from 'react';
+import { Link } from 'react-router';
+import { HiOutlineBookOpen } from 'react-icons/hi2';
+import { LuLibrary } from 'react-icons/lu';
+
+import { buildRoutePath } from '../../../app/routes/route-registry';
+import type { LibrarySeriesSummary } from '../../../shared/contracts';
+import { useDocumentTitle } from '../../../shared/hooks/useDocumentTitle';
+import { useSiteQuery } from '../../../shared/hooks/useSiteQuery';
+import { translation } from '../../../shared/i18n';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { PageStateLayout } from '../../../shared/ui/feedback/PageStateLayout';
+import { QueryStateLayout } from '../../../shared/ui/feedback/QueryStateLayout';
+import { PageContent } from '../../../shared/ui/layout/PageContent';
+import { PageHeader } from '../../../shared/ui/layout/PageHeader';
+import { useSeriesListQuery } from '../hooks/useSeriesQueries';
+
+function SeriesCard({ series }: { series: LibrarySeriesSummary }) {
+    const [coverFailed, setCoverFailed] = useState(false);
+    const completedPercentage =
+        series.item_count > 0
+            ? Math.round((series.completed_count / series.item_count) * 100)
+            : 0;
+
+    return (
+        <article className="book-card group shadow-lg dark:shadow-none">
+            <Link
+                to={buildRoutePath('series-detail', { id: series.slug })}
+                className="block"
+                aria-label={series.name}
+            >
+                <div className="aspect-book bg-gray-200 dark:bg-dark-700 relative overflow-hidden">
+                    {!coverFailed ? (
+                        <img
+                            src={series.cover_url}
+                            alt={series.name}
+                            className="w-full h-full object-cover"
+                            loading="lazy"
+                            decoding="async"
+                            onError={() => setCoverFailed(true)}
+                        />
+                    ) : (
+                        <div className="absolute inset-0 flex items-center justify-center text-gray-400 dark:text-dark-500 bg-gray-300 dark:bg-gray-600">
+                            <HiOutlineBookOpen
+                                className="w-10 h-10"
+                                aria-hidden="true"
+                            />
+                        </div>
+                    )}
+
+                    <div
+                        className="book-progress-bar progress-reading"
+                        style={{ width: `${completedPercentage}%` }}
+                    />
+                </div>
+                <div className="p-3 space-y-0.5">
+                    <h3 className="text-sm font-semibold text-gray-900 dark:text-white truncate">
+                        {series.name}
+                    </h3>
+                    <p className="text-xs font-medium text-gray-500 dark:text-dark-400 truncate">
+                        {translation.get('series-page.progress', {
+                            completed: series.completed_count,
+                            total: series.item_count,
+                        })}
+                        {series.total_reading_time_sec > 0 &&
+                            ` · ${formatDuration(series.total_reading_time_sec)}`}
+                    </p>
+                </div>
+            </Link>
+        </article>
+    );
+}
+
+function SeriesEmptyState() {
+    return (
+        <PageStateLayout
+            icon={
+                <LuLibrary
+                    className="w-12 h-12 text-white"
+                    aria-hidden="true"
+                />
+            }
+            gradientFrom="from-pink-500"
+            gradientTo="to-pink-600"
+            glowFrom="from-pink-500/20"
+            glowTo="to-pink-600/20"
+            title={translation.get('series-page.empty')}
+            description={translation.get('series-page.empty-description')}
+        />
+    );
+}
+
+export function SeriesListRoute() {
+    const { siteQuery } = useSiteQuery();
+    const listQuery = useSeriesListQuery();
+    const title = translation.get('series-page.all');
+    useDocumentTitle(title, siteQuery.data?.title);
+
+    const series = listQuery.data?.series ?? [];
+
+    return (
+        <>
+            <PageHeader title={title} />
+
+            <PageContent className="space-y-6 md:space-y-8">
+                <QueryStateLayout
+                    isError={listQuery.isError}
+                    error={listQuery.error}
+                    onRetry={() => void listQuery.refetch()}
+                    showBlockingSpinner={listQuery.isLoading}
+                    showOverlaySpinner={false}
+                    hasData={Boolean(listQuery.data)}
+                    srLabel="Loading series"
+                    renderContent={() =>
+                        series.length > 0 ? (
+                            <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-5 xl:grid-cols-6 2xl:grid-cols-8 gap-4 md:gap-6">
+                                {series.map((entry) => (
+                                    <SeriesCard
+                                        key={entry.slug}
+                                        series={entry}
+                                    />
+                                ))}
+                            </div>
+                        ) : (
+                            <SeriesEmptyState />
+                        )
+                    }
+                />
+            </PageContent>
+        </>
+    );
+}
@@ -1,9 +1,14 @@
 import { api, type ScopeValue } from '../../../shared/api';
 import type {
+    GenreCount,
     HeatmapConfig,
     MetricPoint,
+    ReadingAbandonedData,
+    ReadingFinishedData,
     ReadingOverview,
+    ReadingPaceData,
     ReadingStreaks,
+    WeekdaySplit,
 } from '../../../shared/contracts';
 import {
     summarizeYearlyStats,
@@ -13,7 +18,16 @@ import {
 
 export type StatisticsScope = ScopeValue;
 
-export type { ReadingOverview, ReadingStreaks, HeatmapConfig };
+export type {
+    GenreCount,
+    ReadingAbandonedData,
+    ReadingFinishedData,
+    ReadingOverview,
+    ReadingPaceData,
+    ReadingStreaks,
+    HeatmapConfig,
+    WeekdaySplit,
+};
 
 export interface DailyActivityEntry {
     date: string;
@@ -43,6 +57,7 @@ interface StatisticsIndexResponse {
     available_weeks: StatisticsIndexWeek[];
     overview: ReadingOverview;
     streaks: ReadingStreaks;
+    weekday_split: WeekdaySplit;
     heatmap_config: HeatmapConfig;
 }
 
@@ -79,6 +94,7 @@ export async function loadStatisticsIndex(
         })),
         overview: summary.overview,
         streaks: summary.streaks,
+        weekday_split: summary.weekday_split,
         heatmap_config: summary.heatmap_config,
     };
 }
@@ -186,3 +202,28 @@ export async function loadStatisticsYearlySection(
         yearlySummary: summarizeYearlyStats(monthlyStats, totalCompletions),
     };
 }
+
+export async function loadStatisticsGenres(
+    scope: StatisticsScope,
+): Promise<GenreCount[]> {
+    const data = await api.getReadingGenres(scope);
+    return data.genres;
+}
+
+export async function loadStatisticsPace(
+    scope: StatisticsScope,
+): Promise<ReadingPaceData> {
+    return api.getReadingPace(scope);
+}
+
+export async function loadStatisticsFinished(
+    scope: StatisticsScope,
+): Promise<ReadingFinishedData> {
+    return api.getReadingFinished(scope);
+}
+
+export async function loadStatisticsAbandoned(
+    scope: StatisticsScope,
+): Promise<ReadingAbandonedData> {
+    return api.getReadingAbandoned(scope);
+}
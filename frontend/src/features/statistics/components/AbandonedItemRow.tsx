@@ -0,0 +1,78 @@
+import { Link, useLocation } from 'react-router';
+import { HiOutlineBookOpen } from 'react-icons/hi2';
+
+import {
+    buildRoutePath,
+    detailRouteIdForContentType,
+} from '../../../app/routes/route-registry';
+import type { AbandonedItem } from '../../../shared/contracts';
+import { translation } from '../../../shared/i18n';
+import { useLazyImageSource } from '../../../shared/lib/dom/useLazyImageSource';
+import { formatPlainDate } from '../../../shared/lib/intl/formatDate';
+import { formatDuration } from '../../../shared/lib/intl/formatDuration';
+import { createDetailReturnState } from '../../../shared/lib/navigation/detail-return-state';
+
+type AbandonedItemRowProps = {
+    item: AbandonedItem;
+};
+
+export function AbandonedItemRow({ item }: AbandonedItemRowProps) {
+    const location = useLocation();
+    const detailPath = buildRoutePath(
+        detailRouteIdForContentType(item.content_type),
+        { id: item.id },
+    );
+    const {
+        imageRef,
+        resolvedSrc: resolvedCoverSrc,
+        hasError: coverFailed,
+        onError: onCoverError,
+    } = useLazyImageSource({
+        src: item.cover_url,
+    });
+
+    return (
+        <li className="flex items-center gap-3 min-w-0">
+            <Link
+                to={detailPath}
+                state={createDetailReturnState(
+                    location.pathname,
+                    location.search,
+                )}
+                className="flex items-center gap-3 min-w-0 flex-1 group"
+            >
+                {!coverFailed ? (
+                    <img
+                        ref={imageRef}
+                        className="w-10 h-14 object-cover rounded-sm shadow-xs shrink-0"
+                        src={resolvedCoverSrc}
+                        alt={`Cover of ${item.title}`}
+                        loading="lazy"
+                        onError={onCoverError}
+                    />
+                ) : (
+                    <div className="w-10 h-14 flex items-center justify-center rounded-sm border border-gray-200 dark:border-dark-600 bg-gray-50 dark:bg-dark-900/70 text-gray-400 dark:text-dark-400 shrink-0">
+                        <HiOutlineBookOpen className="w-5 h-5" aria-hidden />
+                    </div>
+                )}
+                <div className="min-w-0">
+                    <div className="text-sm font-semibold text-gray-900 dark:text-white truncate group-hover:text-primary-600 dark:group-hover:text-primary-400 transition-colors">
+                        {item.title}
+                    </div>
+                    <div className="text-xs text-gray-500 dark:text-dark-400 truncate">
+                        {item.last_read_date
+                            ? translation.get('did-not-finish.last-read', {
+                                  date: formatPlainDate(item.last_read_date, {
+                                      monthStyle: 'long',
+                                  }),
+                              })
+                            : translation.get('did-not-finish.never-read')}
+                    </div>
+                </div>
+            </Link>
+            <span className="text-xs font-medium text-gray-600 dark:text-dark-300 shrink-0">
+                {formatDuration(item.reading_time_sec)}
+            </span>
+        </li>
+    );
+}
@@ -5,7 +5,11 @@ import {
 } from '@tanstack/react-query';
 
 import {
+    loadStatisticsAbandoned,
+    loadStatisticsFinished,
+    loadStatisticsGenres,
     loadStatisticsIndex,
+    loadStatisticsPace,
     loadStatisticsWeek,
     loadStatisticsYear,
     loadStatisticsYearlySection,
@@ -98,3 +102,51 @@ export function useStatisticsYearlySectionQuery(
         placeholderData: keepPreviousData,
     });
 }
+
+function statisticsGenresQueryKey(scope: StatisticsScope) {
+    return ['statistics-genres', scope] as const;
+}
+
+export function useStatisticsGenresQuery(scope: StatisticsScope) {
+    return useQuery({
+        queryKey: statisticsGenresQueryKey(scope),
+        queryFn: () => loadStatisticsGenres(scope),
+        placeholderData: keepPreviousData,
+    });
+}
+
+function statisticsPaceQueryKey(scope: StatisticsScope) {
+    return ['statistics-pace', scope] as const;
+}
+
+export function useStatisticsPaceQuery(scope: StatisticsScope) {
+    return useQuery({
+        queryKey: statisticsPaceQueryKey(scope),
+        queryFn: () => loadStatisticsPace(scope),
+        placeholderData: keepPreviousData,
+    });
+}
+
+function statisticsFinishedQueryKey(scope: StatisticsScope) {
+    return ['statistics-finished', scope] as const;
+}
+
+export function useStatisticsFinishedQuery(scope: StatisticsScope) {
+    return useQuery({
+        queryKey: statisticsFinishedQueryKey(scope),
+        queryFn: () => loadStatisticsFinished(scope),
+        placeholderData: keepPreviousData,
+    });
+}
+
+function statisticsAbandonedQueryKey(scope: StatisticsScope) {
+    return ['statistics-abandoned', scope] as const;
+}
+
+export function useStatisticsAbandonedQuery(scope: StatisticsScope) {
+    return useQuery({
+        queryKey: statisticsAbandonedQueryKey(scope),
+        queryFn: () => loadStatisticsAbandoned(scope),
+        placeholderData: keepPreviousData,
+    });
+}
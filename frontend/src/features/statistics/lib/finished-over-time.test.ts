@@ -0,0 +1,46 @@
+import { describe, expect, it } from 'vitest';
+
+import {
+    FINISHED_MONTH_WINDOW,
+    hasFinishedData,
+    visibleFinishedCounts,
+} from './finished-over-time';
+
+function months(count: number) {
+    return Array.from({ length: count }, (_, index) => ({
+        period: `2024-${String((index % 12) + 1).padStart(2, '0')}`,
+        completions: index,
+    }));
+}
+
+describe('visibleFinishedCounts', () => {
+    it('limits the monthly view to the most recent months', () => {
+        const data = { months: months(18), years: [] };
+        const visible = visibleFinishedCounts(data, 'month');
+
+        expect(visible).toHaveLength(FINISHED_MONTH_WINDOW);
+        expect(visible[visible.length - 1].completions).toBe(17);
+    });
+
+    it('shows every year in the yearly view', () => {
+        const years = [
+            { period: '2019', completions: 4 },
+            { period: '2020', completions: 0 },
+        ];
+        expect(visibleFinishedCounts({ months: [], years }, 'year')).toBe(
+            years,
+        );
+    });
+});
+
+describe('hasFinishedData', () => {
+    it('requires at least one completion', () => {
+        expect(hasFinishedData([])).toBe(false);
+        expect(hasFinishedData([{ period: '2024', completions: 0 }])).toBe(
+            false,
+        );
+        expect(hasFinishedData([{ period: '2024', completions: 2 }])).toBe(
+            true,
+        );
+    });
+});
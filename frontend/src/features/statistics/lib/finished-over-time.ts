@@ -0,0 +1,26 @@
+import type { FinishedCount } from '../../../shared/contracts';
+
+export type FinishedGranularity = 'month' | 'year';
+
+/** Months shown in the monthly view, ending with the latest completion. */
+export const FINISHED_MONTH_WINDOW = 12;
+
+/**
+ * Entries to chart for a granularity: every year, or the most recent
+ * {@link FINISHED_MONTH_WINDOW} months.
+ */
+export function visibleFinishedCounts(
+    data: { months: FinishedCount[]; years: FinishedCount[] },
+    granularity: FinishedGranularity,
+): FinishedCount[] {
+    if (granularity === 'year') {
+        return data.years;
+    }
+
+    return data.months.slice(-FINISHED_MONTH_WINDOW);
+}
+
+/** Whether any period has at least one completion. */
+export function hasFinishedData(entries: FinishedCount[]): boolean {
+    return entries.some((entry) => entry.completions > 0);
+}
@@ -1,5 +1,6 @@
 import { translation } from '../../../shared/i18n';
 import { formatPlainDateRange } from '../../../shared/lib/intl/formatDate';
+import { formatDurationParts } from '../../../shared/lib/intl/formatDuration';
 import { formatNumber } from '../../../shared/lib/intl/formatNumber';
 import {
     joinUnitValueParts,
@@ -40,15 +41,7 @@ export class DataFormatter {
             return [{ amount: '--' }];
         }
 
-        const normalizedSeconds = Math.max(0, Math.floor(seconds));
-        const hours = Math.floor(normalizedSeconds / 3600);
-        const minutes = Math.floor((normalizedSeconds % 3600) / 60);
-
-        if (hours > 0) {
-            return [withUnit(hours, 'units.h'), withUnit(minutes, 'units.m')];
-        }
-
-        return [withUnit(minutes, 'units.m')];
+        return formatDurationParts(seconds);
     }
 
     static formatReadTime(seconds: number | null | undefined): string {
@@ -62,26 +55,10 @@ export class DataFormatter {
             return [{ amount: '--' }];
         }
 
-        const normalizedSeconds = Math.max(0, Math.floor(seconds));
-        const totalMinutes = Math.floor(normalizedSeconds / 60);
-        const totalHours = Math.floor(totalMinutes / 60);
-        const days = Math.floor(totalHours / 24);
-        const hours = totalHours % 24;
-        const minutes = totalMinutes % 60;
-
-        if (days > 0) {
-            return [
-                withUnit(days, 'units.d'),
-                withUnit(hours, 'units.h'),
-                withUnit(minutes, 'units.m'),
-            ];
-        }
-
-        if (hours > 0) {
-            return [withUnit(hours, 'units.h'), withUnit(minutes, 'units.m')];
-        }
-
-        return [withUnit(minutes, 'units.m')];
+        return formatDurationParts(seconds, {
+            includeDays: true,
+            keepZeroUnits: true,
+        });
     }
 
     static formatMinutesParts(
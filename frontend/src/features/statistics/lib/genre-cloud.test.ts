@@ -0,0 +1,44 @@
+import { describe, expect, it } from 'vitest';
+
+import { buildGenreCloud } from './genre-cloud';
+
+describe('buildGenreCloud', () => {
+    it('returns an empty cloud when there are no genres', () => {
+        expect(buildGenreCloud([])).toEqual([]);
+    });
+
+    it('keeps the most frequent genres and sorts them by name', () => {
+        const cloud = buildGenreCloud(
+            [
+                { name: 'Horror', count: 1 },
+                { name: 'Fantasy', count: 8 },
+                { name: 'Science Fiction', count: 4 },
+            ],
+            2,
+        );
+
+        expect(cloud.map((tag) => tag.name)).toEqual([
+            'Fantasy',
+            'Science Fiction',
+        ]);
+    });
+
+    it('scales weights between the least and most frequent genre', () => {
+        const cloud = buildGenreCloud([
+            { name: 'A', count: 1 },
+            { name: 'B', count: 10 },
+            { name: 'C', count: 100 },
+        ]);
+
+        expect(cloud.map((tag) => tag.weight)).toEqual([0, 0.5, 1]);
+    });
+
+    it('gives every tag full weight when counts are equal', () => {
+        const cloud = buildGenreCloud([
+            { name: 'A', count: 3 },
+            { name: 'B', count: 3 },
+        ]);
+
+        expect(cloud.every((tag) => tag.weight === 1)).toBe(true);
+    });
+});
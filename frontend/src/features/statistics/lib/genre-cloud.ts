@@ -0,0 +1,40 @@
+import type { GenreCount } from '../../../shared/contracts';
+
+export const GENRE_CLOUD_LIMIT = 40;
+
+export type GenreCloudTag = {
+    name: string;
+    count: number;
+    /** Relative size between 0 (least frequent) and 1 (most frequent). */
+    weight: number;
+};
+
+/**
+ * Keeps the most frequent genres and assigns each a log-scaled weight so a
+ * single dominant genre does not shrink everything else to the minimum size.
+ * The result is sorted alphabetically for display.
+ */
+export function buildGenreCloud(
+    genres: GenreCount[],
+    limit: number = GENRE_CLOUD_LIMIT,
+): GenreCloudTag[] {
+    const top = [...genres]
+        .filter((genre) => genre.count > 0)
+        .sort((a, b) => b.count - a.count || a.name.localeCompare(b.name))
+        .slice(0, limit);
+    if (top.length === 0) {
+        return [];
+    }
+
+    const counts = top.map((genre) => Math.log(genre.count));
+    const min = Math.min(...counts);
+    const span = Math.max(...counts) - min;
+
+    return top
+        .map((genre) => ({
+            name: genre.name,
+            count: genre.count,
+            weight: span > 0 ? (Math.log(genre.count) - min) / span : 1,
+        }))
+        .sort((a, b) => a.name.localeCompare(b.name));
+}
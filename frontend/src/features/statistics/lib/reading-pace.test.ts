@@ -0,0 +1,40 @@
+import { describe, expect, it } from 'vitest';
+
+import { hasPaceData, paceBucketLabel } from './reading-pace';
+
+describe('paceBucketLabel', () => {
+    it('labels closed buckets with their range', () => {
+        expect(
+            paceBucketLabel({
+                min_pages_per_hour: 30,
+                max_pages_per_hour: 35,
+                sessions: 4,
+            }),
+        ).toBe('30–35');
+    });
+
+    it('labels the open-ended outlier bucket with a plus', () => {
+        expect(
+            paceBucketLabel({
+                min_pages_per_hour: 60,
+                max_pages_per_hour: null,
+                sessions: 1,
+            }),
+        ).toBe('60+');
+    });
+});
+
+describe('hasPaceData', () => {
+    it('requires at least one session', () => {
+        expect(hasPaceData([])).toBe(false);
+        expect(
+            hasPaceData([
+                {
+                    min_pages_per_hour: 10,
+                    max_pages_per_hour: 20,
+                    sessions: 0,
+                },
+            ]),
+        ).toBe(false);
+    });
+});
@@ -0,0 +1,18 @@
+import type { PaceBucket } from '../../../shared/contracts';
+
+/**
+ * Axis label for a pace bucket, e.g. `30–35`, or `60+` for the open-ended
+ * bucket that collects the fastest outliers.
+ */
+export function paceBucketLabel(bucket: PaceBucket): string {
+    if (bucket.max_pages_per_hour === null) {
+        return `${bucket.min_pages_per_hour}+`;
+    }
+
+    return `${bucket.min_pages_per_hour}–${bucket.max_pages_per_hour}`;
+}
+
+/** Whether the histogram has enough sessions to be worth showing. */
+export function hasPaceData(buckets: PaceBucket[]): boolean {
+    return buckets.some((bucket) => bucket.sessions > 0);
+}
@@ -0,0 +1,76 @@
+import { describe, expect, it } from 'vitest';
+
+import {
+    calculateCellDate,
+    formatISODate,
+    heatmapWeekIndex,
+    summarizeHeatmapActivity,
+} from './heatmap';
+
+describe('calculateCellDate', () => {
+    it('starts the first column on the Monday before January 1st', () => {
+        // 2026-01-01 is a Thursday.
+        expect(formatISODate(calculateCellDate(2026, 0, 0))).toBe(
+            '2025-12-29',
+        );
+        expect(formatISODate(calculateCellDate(2026, 0, 6))).toBe(
+            '2026-01-04',
+        );
+    });
+
+    it('starts the first column on the Sunday before January 1st', () => {
+        expect(formatISODate(calculateCellDate(2026, 0, 0, 'sunday'))).toBe(
+            '2025-12-28',
+        );
+        expect(formatISODate(calculateCellDate(2026, 1, 0, 'sunday'))).toBe(
+            '2026-01-04',
+        );
+    });
+
+    it('keeps January 1st in the first column when it is the week start', () => {
+        // 2023-01-01 is a Sunday.
+        expect(formatISODate(calculateCellDate(2023, 0, 0, 'sunday'))).toBe(
+            '2023-01-01',
+        );
+        expect(formatISODate(calculateCellDate(2023, 0, 0))).toBe(
+            '2022-12-26',
+        );
+    });
+});
+
+describe('heatmapWeekIndex', () => {
+    it('counts calendar days across a DST change', () => {
+        // 2024-01-01 is a Monday; 2024-03-11 is 70 days later, after the
+        // March DST change in most northern-hemisphere zones.
+        expect(heatmapWeekIndex(new Date(2024, 2, 11, 0, 30))).toBe(10);
+        expect(heatmapWeekIndex(new Date(2024, 2, 10, 23, 30))).toBe(9);
+    });
+
+    it('follows the configured week start', () => {
+        // The Sunday-start heatmap begins on 2023-12-31.
+        expect(heatmapWeekIndex(new Date(2024, 2, 10, 0, 30), 'sunday')).toBe(
+            10,
+        );
+        expect(heatmapWeekIndex(new Date(2024, 0, 1), 'sunday')).toBe(0);
+    });
+});
+
+describe('summarizeHeatmapActivity', () => {
+    it('counts only days with reading time', () => {
+        const summary = summarizeHeatmapActivity([
+            { date: '2026-03-02', reading_time_sec: 600, pages_read: 12 },
+            { date: '2026-03-01', reading_time_sec: 1800, pages_read: 30 },
+            { date: '2026-03-03', reading_time_sec: 0, pages_read: 0 },
+            { date: '2026-02-27', reading_time_sec: 1800, pages_read: 25 },
+        ]);
+
+        expect(summary.activeDays).toBe(3);
+        expect(summary.totalReadSec).toBe(4200);
+        expect(summary.totalPages).toBe(67);
+        expect(summary.busiestDay?.date).toBe('2026-02-27');
+    });
+
+    it('has no busiest day without reading', () => {
+        expect(summarizeHeatmapActivity([]).busiestDay).toBeNull();
+    });
+});
@@ -1,3 +1,6 @@
+import type { WeekStart } from '../../../shared/contracts';
+import type { DailyActivityEntry } from '../api/statistics-data';
+
 export const HEATMAP_COLOR_CLASSES = [
     ['bg-gray-100', 'dark:bg-dark-800'],
     ['bg-green-100', 'dark:bg-green-900'],
@@ -6,22 +9,54 @@ export const HEATMAP_COLOR_CLASSES = [
     ['bg-green-600', 'dark:bg-green-300'],
 ] as const;
 
+/** Weekday labels shown next to the first, middle and last heatmap rows. */
+export const HEATMAP_DAY_LABELS: Record<WeekStart, readonly string[]> = {
+    monday: ['weekday.mon', 'weekday.thu', 'weekday.sun'],
+    sunday: ['weekday.sun', 'weekday.wed', 'weekday.sat'],
+};
+
+/** Start of the week containing January 1st (the heatmap's first column). */
+export function firstHeatmapWeekStart(
+    year: number,
+    weekStart: WeekStart = 'monday',
+): Date {
+    const janFirst = new Date(year, 0, 1);
+    const firstDayIndex = weekStart === 'sunday' ? 0 : 1;
+    const shift = -((janFirst.getDay() - firstDayIndex + 7) % 7);
+    const start = new Date(janFirst);
+    start.setDate(janFirst.getDate() + shift);
+    return start;
+}
+
 export function calculateCellDate(
     year: number,
     weekIndex: number,
     dayIndex: number,
+    weekStart: WeekStart = 'monday',
 ): Date {
-    const janFirst = new Date(year, 0, 1);
-    const janDayOfWeek = janFirst.getDay();
-    const shiftToMonday = janDayOfWeek === 0 ? -6 : 1 - janDayOfWeek;
-    const firstMonday = new Date(janFirst);
-    firstMonday.setDate(janFirst.getDate() + shiftToMonday);
-
-    const cellDate = new Date(firstMonday);
+    const cellDate = firstHeatmapWeekStart(year, weekStart);
     cellDate.setDate(cellDate.getDate() + weekIndex * 7 + dayIndex);
     return cellDate;
 }
 
+/**
+ * Column index of `date` in its year's heatmap. Days are counted on the
+ * calendar rather than in elapsed milliseconds, so DST changes between
+ * January and `date` cannot shift the result by a day.
+ */
+export function heatmapWeekIndex(
+    date: Date,
+    weekStart: WeekStart = 'monday',
+): number {
+    const start = firstHeatmapWeekStart(date.getFullYear(), weekStart);
+    const days = Math.round(
+        (Date.UTC(date.getFullYear(), date.getMonth(), date.getDate()) -
+            Date.UTC(start.getFullYear(), start.getMonth(), start.getDate())) /
+            (1000 * 60 * 60 * 24),
+    );
+    return Math.floor(days / 7);
+}
+
 export function formatISODate(date: Date): string {
     return `${date.getFullYear()}-${String(date.getMonth() + 1).padStart(2, '0')}-${String(
         date.getDate(),
@@ -47,3 +82,45 @@ export function normalizeHeatmapLevel(
     const scaled = Math.ceil((activity / maxActivity) * maxLevel);
     return Math.min(maxLevel, Math.max(1, scaled));
 }
+
+export type HeatmapActivitySummary = {
+    activeDays: number;
+    totalReadSec: number;
+    totalPages: number;
+    /** Day with the most reading time; the earlier one on ties. */
+    busiestDay: DailyActivityEntry | null;
+};
+
+/** What the heatmap shows, as numbers for its screen reader summary. */
+export function summarizeHeatmapActivity(
+    entries: readonly DailyActivityEntry[],
+): HeatmapActivitySummary {
+    const summary: HeatmapActivitySummary = {
+        activeDays: 0,
+        totalReadSec: 0,
+        totalPages: 0,
+        busiestDay: null,
+    };
+
+    for (const entry of entries) {
+        // Matches the cells the heatmap colors in.
+        if (entry.reading_time_sec <= 0) {
+            continue;
+        }
+        summary.activeDays += 1;
+        summary.totalReadSec += entry.reading_time_sec;
+        summary.totalPages += entry.pages_read;
+
+        const busiest = summary.busiestDay;
+        if (
+            !busiest ||
+            entry.reading_time_sec > busiest.reading_time_sec ||
+            (entry.reading_time_sec === busiest.reading_time_sec &&
+                entry.date < busiest.date)
+        ) {
+            summary.busiestDay = entry;
+        }
+    }
+
+    return summary;
+}
@@ -17,8 +17,13 @@ import {
 export const SECTION_NAMES = [
     'overall-stats',
     'reading-streak',
+    'weekday-split',
     'yearly-stats',
     'weekly-stats',
+    'genre-cloud',
+    'reading-pace',
+    'finished-over-time',
+    'did-not-finish',
 ] as const;
 
 export type SectionName = (typeof SECTION_NAMES)[number];
@@ -98,8 +103,13 @@ export function defaultSectionState(): SectionVisibilityState {
     return {
         'overall-stats': true,
         'reading-streak': true,
+        'weekday-split': true,
         'yearly-stats': true,
         'weekly-stats': true,
+        'genre-cloud': true,
+        'reading-pace': true,
+        'finished-over-time': true,
+        'did-not-finish': true,
     };
 }
 
@@ -12,7 +12,11 @@ import { PageHeader } from '../../../shared/ui/layout/PageHeader';
 import type { StatisticsWeekResponse } from '../api/statistics-data';
 import { ContentScopeFilter } from '../../../shared/ui/selectors/ContentScopeFilter';
 import {
+    useStatisticsAbandonedQuery,
+    useStatisticsFinishedQuery,
+    useStatisticsGenresQuery,
     useStatisticsIndexQuery,
+    useStatisticsPaceQuery,
     useStatisticsWeekQuery,
     useStatisticsYearQuery,
     useStatisticsYearlySectionQuery,
@@ -27,9 +31,14 @@ import {
     type YearlySummaryStats,
     type SectionName,
 } from '../model/statistics-model';
+import { DidNotFinishSection } from '../sections/DidNotFinishSection';
+import { FinishedOverTimeSection } from '../sections/FinishedOverTimeSection';
+import { GenreCloudSection } from '../sections/GenreCloudSection';
 import { OverallStatsSection } from '../sections/OverallStatsSection';
+import { ReadingPaceSection } from '../sections/ReadingPaceSection';
 import { ReadingStreakSection } from '../sections/ReadingStreakSection';
 import { StatisticsEmptyState } from '../sections/StatisticsEmptyState';
+import { WeekdaySplitSection } from '../sections/WeekdaySplitSection';
 import { WeeklyStatsSection } from '../sections/WeeklyStatsSection';
 import { YearlyStatsSection } from '../sections/YearlyStatsSection';
 
@@ -167,6 +176,10 @@ export function StatisticsRoute() {
         statsIndexTransition.hasFreshData,
     ]);
 
+    const genresQuery = useStatisticsGenresQuery(scope);
+    const paceQuery = useStatisticsPaceQuery(scope);
+    const finishedQuery = useStatisticsFinishedQuery(scope);
+    const abandonedQuery = useStatisticsAbandonedQuery(scope);
     const weekQuery = useStatisticsWeekQuery(scope, effectiveSelectedWeek);
     const weekTransition = useQueryTransitionState({
         data: weekQuery.data,
@@ -291,10 +304,19 @@ export function StatisticsRoute() {
                                     }
                                     loading={heatmapLoading}
                                     animationSeed={location.key}
+                                    weekStart={
+                                        siteQuery.data?.week_start ?? 'monday'
+                                    }
                                     currentStreak={validatedCurrentStreak}
                                     longestStreak={statsIndex!.streaks.longest}
                                 />
 
+                                <WeekdaySplitSection
+                                    visible={sectionState['weekday-split']}
+                                    onToggle={toggleSection}
+                                    split={statsIndex!.weekday_split}
+                                />
+
                                 <YearlyStatsSection
                                     visible={sectionState['yearly-stats']}
                                     onToggle={toggleSection}
@@ -315,6 +337,31 @@ export function StatisticsRoute() {
                                     weeklyStats={weeklyStats}
                                     loading={weeklyLoading}
                                 />
+
+                                <GenreCloudSection
+                                    visible={sectionState['genre-cloud']}
+                                    onToggle={toggleSection}
+                                    scope={scope}
+                                    genres={genresQuery.data ?? []}
+                                />
+
+                                <ReadingPaceSection
+                                    visible={sectionState['reading-pace']}
+                                    onToggle={toggleSection}
+                                    pace={paceQuery.data ?? null}
+                                />
+
+                                <FinishedOverTimeSection
+                                    visible={sectionState['finished-over-time']}
+                                    onToggle={toggleSection}
+                                    finished={finishedQuery.data ?? null}
+                                />
+
+                                <DidNotFinishSection
+                                    visible={sectionState['did-not-finish']}
+                                    onToggle={toggleSection}
+                                    abandoned={abandonedQuery.data ?? null}
+                                />
                             </>
                         )
                     }
@@ -0,0 +1,93 @@
+import type { ReadingAbandonedData } from '../api/statistics-data';
+import { AbandonedItemRow } from '../components/AbandonedItemRow';
+import { DataFormatter } from '../lib/formatters';
+import { translation } from '../../../shared/i18n';
+import type { SectionName } from '../model/statistics-model';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+
+type DidNotFinishSectionProps = {
+    visible: boolean;
+    onToggle: (sectionName: SectionName) => void;
+    abandoned: ReadingAbandonedData | null;
+};
+
+export function DidNotFinishSection({
+    visible,
+    onToggle,
+    abandoned,
+}: DidNotFinishSectionProps) {
+    if (!abandoned || abandoned.items.length === 0) {
+        return null;
+    }
+
+    const totalTimeSec = abandoned.items.reduce(
+        (sum, item) => sum + item.reading_time_sec,
+        0,
+    );
+    const years = [...abandoned.years].reverse();
+
+    return (
+        <CollapsibleSection
+            sectionKey="did-not-finish"
+            accentClass="bg-linear-to-b from-rose-400 to-rose-600"
+            title={translation.get('did-not-finish')}
+            visible={visible}
+            onToggle={() => onToggle('did-not-finish')}
+        >
+            <div className="grid grid-cols-1 lg:grid-cols-3 gap-3 sm:gap-4 mb-8">
+                <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs">
+                    <dl className="grid grid-cols-2 gap-4 mb-4">
+                        <div>
+                            <dt className="text-xs text-gray-500 dark:text-dark-400">
+                                {translation.get('did-not-finish.items')}
+                            </dt>
+                            <dd className="text-lg font-bold text-gray-900 dark:text-white">
+                                {DataFormatter.formatCount(
+                                    abandoned.items.length,
+                                )}
+                            </dd>
+                        </div>
+                        <div>
+                            <dt className="text-xs text-gray-500 dark:text-dark-400">
+                                {translation.get('did-not-finish.time-spent')}
+                            </dt>
+                            <dd className="text-lg font-bold text-gray-900 dark:text-white">
+                                {DataFormatter.formatReadTime(totalTimeSec)}
+                            </dd>
+                        </div>
+                    </dl>
+                    {years.length > 0 && (
+                        <ul className="space-y-1.5 border-t border-gray-200/70 dark:border-dark-700/50 pt-4">
+                            {years.map((entry) => (
+                                <li
+                                    key={entry.year}
+                                    className="flex items-center justify-between text-sm"
+                                >
+                                    <span className="font-semibold text-gray-900 dark:text-white">
+                                        {entry.year}
+                                    </span>
+                                    <span className="text-gray-600 dark:text-dark-300">
+                                        {translation.get(
+                                            'did-not-finish.year-entry',
+                                            {
+                                                count: entry.items,
+                                                time: DataFormatter.formatReadTime(
+                                                    entry.reading_time_sec,
+                                                ),
+                                            },
+                                        )}
+                                    </span>
+                                </li>
+                            ))}
+                        </ul>
+                    )}
+                </div>
+                <ul className="lg:col-span-2 bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs space-y-3 max-h-96 overflow-y-auto">
+                    {abandoned.items.map((item) => (
+                        <AbandonedItemRow key={item.id} item={item} />
+                    ))}
+                </ul>
+            </div>
+        </CollapsibleSection>
+    );
+}
@@ -0,0 +1,90 @@
+import { useState } from 'react';
+
+import type { ReadingFinishedData } from '../api/statistics-data';
+import {
+    DistributionBarChart,
+    type DistributionBarItem,
+} from '../components/DistributionBarChart';
+import { translation } from '../../../shared/i18n';
+import { formatMonthKey } from '../../../shared/lib/intl/formatDate';
+import {
+    hasFinishedData,
+    visibleFinishedCounts,
+    type FinishedGranularity,
+} from '../lib/finished-over-time';
+import type { SectionName } from '../model/statistics-model';
+import { Button } from '../../../shared/ui/button/Button';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+
+type FinishedOverTimeSectionProps = {
+    visible: boolean;
+    onToggle: (sectionName: SectionName) => void;
+    finished: ReadingFinishedData | null;
+};
+
+export function FinishedOverTimeSection({
+    visible,
+    onToggle,
+    finished,
+}: FinishedOverTimeSectionProps) {
+    const [granularity, setGranularity] =
+        useState<FinishedGranularity>('month');
+
+    if (!finished || !hasFinishedData(finished.years)) {
+        return null;
+    }
+
+    const items: DistributionBarItem[] = visibleFinishedCounts(
+        finished,
+        granularity,
+    ).map((entry) => {
+        const label =
+            granularity === 'year'
+                ? entry.period
+                : formatMonthKey(entry.period, { monthStyle: 'short' });
+        const period =
+            granularity === 'year'
+                ? entry.period
+                : formatMonthKey(entry.period, { includeYear: true });
+        return {
+            readTime: entry.completions,
+            label,
+            tooltip: `${period}: ${translation.get('finished-over-time.completions', entry.completions)}`,
+        };
+    });
+
+    return (
+        <CollapsibleSection
+            sectionKey="finished-over-time"
+            accentClass="bg-linear-to-b from-amber-400 to-amber-600"
+            title={translation.get('finished-over-time')}
+            visible={visible}
+            onToggle={() => onToggle('finished-over-time')}
+            controls={
+                <>
+                    <Button
+                        variant="neutral"
+                        label={translation.get('finished-over-time.by-month')}
+                        active={granularity === 'month'}
+                        onClick={() => setGranularity('month')}
+                    />
+                    <Button
+                        variant="neutral"
+                        label={translation.get('finished-over-time.by-year')}
+                        active={granularity === 'year'}
+                        onClick={() => setGranularity('year')}
+                    />
+                </>
+            }
+        >
+            <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs mb-8">
+                <DistributionBarChart
+                    items={items}
+                    columns={items.length}
+                    heightClassName="h-44 sm:h-52"
+                    barClassName="from-amber-600 to-yellow-400 shadow-[0_-2px_16px_rgba(251,191,36,0.3)]"
+                />
+            </div>
+        </CollapsibleSection>
+    );
+}
@@ -0,0 +1,64 @@
+import type { GenreCount, StatisticsScope } from '../api/statistics-data';
+import { translation } from '../../../shared/i18n';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { buildGenreCloud } from '../lib/genre-cloud';
+import type { SectionName } from '../model/statistics-model';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+
+type GenreCloudSectionProps = {
+    visible: boolean;
+    onToggle: (sectionName: SectionName) => void;
+    scope: StatisticsScope;
+    genres: GenreCount[];
+};
+
+const MIN_FONT_REM = 0.8;
+const MAX_FONT_REM = 2;
+
+function tagTitle(scope: StatisticsScope, count: number): string {
+    const label =
+        scope === 'comics'
+            ? translation.get('comic-label', count)
+            : translation.get('book-label', count);
+    return `${formatNumber(count)} ${label}`;
+}
+
+export function GenreCloudSection({
+    visible,
+    onToggle,
+    scope,
+    genres,
+}: GenreCloudSectionProps) {
+    const tags = buildGenreCloud(genres);
+    if (tags.length === 0) {
+        return null;
+    }
+
+    return (
+        <CollapsibleSection
+            sectionKey="genre-cloud"
+            accentClass="bg-linear-to-b from-pink-400 to-pink-600"
+            title={translation.get('genres')}
+            visible={visible}
+            onToggle={() => onToggle('genre-cloud')}
+        >
+            <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs mb-8">
+                <ul className="flex flex-wrap items-baseline justify-center gap-x-4 gap-y-2">
+                    {tags.map((tag) => (
+                        <li
+                            key={tag.name}
+                            title={tagTitle(scope, tag.count)}
+                            className="font-semibold leading-tight text-gray-700 dark:text-dark-200"
+                            style={{
+                                fontSize: `${MIN_FONT_REM + tag.weight * (MAX_FONT_REM - MIN_FONT_REM)}rem`,
+                                opacity: 0.55 + tag.weight * 0.45,
+                            }}
+                        >
+                            {tag.name}
+                        </li>
+                    ))}
+                </ul>
+            </div>
+        </CollapsibleSection>
+    );
+}
@@ -13,11 +13,15 @@ import { DataFormatter } from '../lib/formatters';
 import type { StatisticsYearResponse } from '../api/statistics-data';
 import { translation } from '../../../shared/i18n';
 import { TooltipManager } from '../../../shared/overlay/tooltip-manager';
+import type { WeekStart } from '../../../shared/contracts';
 import {
     HEATMAP_COLOR_CLASSES,
+    HEATMAP_DAY_LABELS,
     calculateCellDate,
     formatISODate,
+    heatmapWeekIndex,
     normalizeHeatmapLevel,
+    summarizeHeatmapActivity,
 } from '../model/heatmap';
 
 const HEATMAP_ALL_COLOR_CLASSES = HEATMAP_COLOR_CLASSES.flat();
@@ -27,6 +31,7 @@ type HeatmapSectionProps = {
     yearData: StatisticsYearResponse | undefined;
     loading: boolean;
     animationSeed: string;
+    weekStart: WeekStart;
 };
 
 function prefersReducedMotion(): boolean {
@@ -42,6 +47,7 @@ export function HeatmapSection({
     yearData,
     loading,
     animationSeed,
+    weekStart,
 }: HeatmapSectionProps) {
     const scrollContainerRef = useRef<HTMLDivElement>(null);
     const heatmapContainerRef = useRef<HTMLDivElement>(null);
@@ -71,6 +77,31 @@ export function HeatmapSection({
 
     const effectiveYear = yearData?.year ?? selectedYear;
 
+    const summaryText = useMemo(() => {
+        if (!yearData) {
+            return null;
+        }
+        const summary = summarizeHeatmapActivity(yearData.daily_activity);
+        const text = translation.get('heatmap-summary', {
+            days: summary.activeDays,
+            year: String(yearData.year),
+            time: DataFormatter.formatReadTime(summary.totalReadSec),
+            pages: DataFormatter.formatCount(summary.totalPages),
+        });
+        if (!summary.busiestDay) {
+            return text;
+        }
+        const busiest = translation.get('heatmap-summary.busiest', {
+            date: formatPlainDate(summary.busiestDay.date, {
+                monthStyle: 'long',
+            }),
+            time: DataFormatter.formatReadTime(
+                summary.busiestDay.reading_time_sec,
+            ),
+        });
+        return `${text} ${busiest}`;
+    }, [yearData]);
+
     useEffect(() => {
         const scrollContainer = scrollContainerRef.current;
         const heatmapContainer = heatmapContainerRef.current;
@@ -80,20 +111,7 @@ export function HeatmapSection({
 
         if (effectiveYear === new Date().getFullYear()) {
             const weekWidth = heatmapContainer.scrollWidth / 53;
-            const currentWeek = (() => {
-                const today = new Date();
-                const janFirst = new Date(today.getFullYear(), 0, 1);
-                const janDayOfWeek = janFirst.getDay();
-                const shiftToMonday =
-                    janDayOfWeek === 0 ? -6 : 1 - janDayOfWeek;
-                const firstMonday = new Date(janFirst);
-                firstMonday.setDate(janFirst.getDate() + shiftToMonday);
-                const daysDiff = Math.floor(
-                    (today.getTime() - firstMonday.getTime()) /
-                        (1000 * 60 * 60 * 24),
-                );
-                return Math.floor(daysDiff / 7);
-            })();
+            const currentWeek = heatmapWeekIndex(new Date(), weekStart);
 
             const targetPosition = currentWeek * weekWidth;
             scrollToHorizontalPosition(
@@ -106,7 +124,7 @@ export function HeatmapSection({
         }
 
         scrollToHorizontalOverflowRatio(scrollContainer, heatmapContainer, 0.8);
-    }, [effectiveYear, yearData]);
+    }, [effectiveYear, weekStart, yearData]);
 
     useEffect(() => {
         const dayLabels = dayLabelsRef.current;
@@ -151,7 +169,12 @@ export function HeatmapSection({
 
         for (let week = 0; week < 53; week += 1) {
             for (let day = 0; day < 7; day += 1) {
-                const date = calculateCellDate(effectiveYear, week, day);
+                const date = calculateCellDate(
+                    effectiveYear,
+                    week,
+                    day,
+                    weekStart,
+                );
                 const dateIso = formatISODate(date);
                 const activity = activityMap.map.get(dateIso) ?? {
                     pages: 0,
@@ -177,7 +200,7 @@ export function HeatmapSection({
         }
 
         return cellMap;
-    }, [activityMap, effectiveYear]);
+    }, [activityMap, effectiveYear, weekStart]);
 
     useLayoutEffect(() => {
         const grid = heatmapGridRef.current;
@@ -262,7 +285,10 @@ export function HeatmapSection({
                 </div>
             )}
 
-            <div className="flex">
+            {summaryText && <p className="sr-only">{summaryText}</p>}
+
+            {/* Screen readers get the summary above instead of the grid. */}
+            <div className="flex" aria-hidden="true">
                 <div className="text-xs text-gray-500 dark:text-dark-400 font-medium w-8 sm:w-12 shrink-0 pr-2 sm:pr-4">
                     <div className="h-6 mb-3"></div>
                     <div
@@ -270,9 +296,9 @@ export function HeatmapSection({
                         id="dayLabels"
                         ref={dayLabelsRef}
                     >
-                        <span>{translation.get('weekday.mon')}</span>
-                        <span>{translation.get('weekday.thu')}</span>
-                        <span>{translation.get('weekday.sun')}</span>
+                        {HEATMAP_DAY_LABELS[weekStart].map((key) => (
+                            <span key={key}>{translation.get(key)}</span>
+                        ))}
                     </div>
                 </div>
 
@@ -343,7 +369,10 @@ export function HeatmapSection({
                 </div>
             </div>
 
-            <div className="flex items-center justify-end mt-4 space-x-2 text-xs font-medium">
+            <div
+                className="flex items-center justify-end mt-4 space-x-2 text-xs font-medium"
+                aria-hidden="true"
+            >
                 <span className="text-gray-500 dark:text-dark-400">
                     {translation.get('less')}
                 </span>
@@ -0,0 +1,66 @@
+import type { ReadingPaceData } from '../api/statistics-data';
+import {
+    DistributionBarChart,
+    type DistributionBarItem,
+} from '../components/DistributionBarChart';
+import { translation } from '../../../shared/i18n';
+import { formatNumber } from '../../../shared/lib/intl/formatNumber';
+import { hasPaceData, paceBucketLabel } from '../lib/reading-pace';
+import type { SectionName } from '../model/statistics-model';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+
+type ReadingPaceSectionProps = {
+    visible: boolean;
+    onToggle: (sectionName: SectionName) => void;
+    pace: ReadingPaceData | null;
+};
+
+export function ReadingPaceSection({
+    visible,
+    onToggle,
+    pace,
+}: ReadingPaceSectionProps) {
+    if (!pace || !hasPaceData(pace.buckets)) {
+        return null;
+    }
+
+    const pph = translation.get('pph-abbreviation');
+    const items: DistributionBarItem[] = pace.buckets.map((bucket) => {
+        const label = paceBucketLabel(bucket);
+        return {
+            readTime: bucket.sessions,
+            label,
+            tooltip: `${label} ${pph}: ${formatNumber(bucket.sessions)} ${translation.get('reading-sessions-label', bucket.sessions)}`,
+        };
+    });
+
+    return (
+        <CollapsibleSection
+            sectionKey="reading-pace"
+            accentClass="bg-linear-to-b from-teal-400 to-teal-600"
+            title={translation.get('reading-pace')}
+            visible={visible}
+            onToggle={() => onToggle('reading-pace')}
+        >
+            <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs mb-8">
+                {pace.median_pages_per_hour !== null && (
+                    <p className="mb-4 text-sm text-gray-500 dark:text-dark-400">
+                        {translation.get('reading-pace.median')}:{' '}
+                        <span className="font-semibold text-gray-900 dark:text-white">
+                            {formatNumber(pace.median_pages_per_hour, {
+                                maximumFractionDigits: 1,
+                            })}{' '}
+                            {pph}
+                        </span>
+                    </p>
+                )}
+                <DistributionBarChart
+                    items={items}
+                    columns={items.length}
+                    heightClassName="h-44 sm:h-52"
+                    barClassName="from-teal-600 to-emerald-400 shadow-[0_-2px_16px_rgba(52,211,153,0.3)]"
+                />
+            </div>
+        </CollapsibleSection>
+    );
+}
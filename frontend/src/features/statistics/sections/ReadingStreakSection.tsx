@@ -2,6 +2,7 @@ import type {
     StatisticsYearResponse,
     ReadingStreaks,
 } from '../api/statistics-data';
+import type { WeekStart } from '../../../shared/contracts';
 import { translation } from '../../../shared/i18n';
 import {
     formatStreakDateRange,
@@ -20,6 +21,7 @@ type ReadingStreakSectionProps = {
     yearData: StatisticsYearResponse | undefined;
     loading: boolean;
     animationSeed: string;
+    weekStart: WeekStart;
     currentStreak: ReadingStreaks['current'];
     longestStreak: ReadingStreaks['longest'];
 };
@@ -33,6 +35,7 @@ export function ReadingStreakSection({
     yearData,
     loading,
     animationSeed,
+    weekStart,
     currentStreak,
     longestStreak,
 }: ReadingStreakSectionProps) {
@@ -60,6 +63,7 @@ export function ReadingStreakSection({
                     yearData={yearData}
                     loading={loading}
                     animationSeed={animationSeed}
+                    weekStart={weekStart}
                 />
 
                 <div className="w-full mt-3 sm:mt-3 md:mt-4">
@@ -0,0 +1,118 @@
+import { LuBriefcase, LuSofa } from 'react-icons/lu';
+import type { IconType } from 'react-icons';
+
+import type { WeekdaySplit } from '../api/statistics-data';
+import type { DayKindStats } from '../../../shared/contracts';
+import { DataFormatter } from '../lib/formatters';
+import { translation } from '../../../shared/i18n';
+import type { SectionName } from '../model/statistics-model';
+import { MetricCardUnitValue } from '../../../shared/ui/cards/MetricCardUnitValue';
+import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
+
+type WeekdaySplitSectionProps = {
+    visible: boolean;
+    onToggle: (sectionName: SectionName) => void;
+    split: WeekdaySplit;
+};
+
+type DayKindCardProps = {
+    icon: IconType;
+    iconContainerClassName: string;
+    iconClassName: string;
+    label: string;
+    stats: DayKindStats;
+};
+
+function DayKindCard({
+    icon: Icon,
+    iconContainerClassName,
+    iconClassName,
+    label,
+    stats,
+}: DayKindCardProps) {
+    return (
+        <div className="bg-white dark:bg-dark-800/80 border border-gray-200/70 dark:border-dark-700/50 rounded-xl p-4 md:p-6 shadow-xs">
+            <div className="flex items-center gap-3 mb-4">
+                <div
+                    className={`w-9 h-9 rounded-lg flex items-center justify-center ${iconContainerClassName}`}
+                >
+                    <Icon className={`w-5 h-5 ${iconClassName}`} />
+                </div>
+                <h3 className="text-base font-semibold text-gray-900 dark:text-white">
+                    {label}
+                </h3>
+            </div>
+            <dl className="grid grid-cols-2 gap-4">
+                <div>
+                    <dt className="text-xs text-gray-500 dark:text-dark-400">
+                        {translation.get('weekday-split.average-time')}
+                    </dt>
+                    <dd className="text-lg font-bold text-gray-900 dark:text-white">
+                        <MetricCardUnitValue
+                            value={DataFormatter.formatReadTimeParts(
+                                stats.average_reading_time_sec,
+                            )}
+                        />
+                    </dd>
+                </div>
+                <div>
+                    <dt className="text-xs text-gray-500 dark:text-dark-400">
+                        {translation.get('weekday-split.average-pages')}
+                    </dt>
+                    <dd className="text-lg font-bold text-gray-900 dark:text-white">
+                        {DataFormatter.formatCount(stats.average_pages_read)}
+                    </dd>
+                </div>
+                <div>
+                    <dt className="text-xs text-gray-500 dark:text-dark-400">
+                        {translation.get('total-read-time')}
+                    </dt>
+                    <dd className="text-sm font-semibold text-gray-700 dark:text-dark-200">
+                        {DataFormatter.formatReadTime(stats.reading_time_sec)}
+                    </dd>
+                </div>
+                <div>
+                    <dt className="text-xs text-gray-500 dark:text-dark-400">
+                        {translation.get('weekday-split.reading-days')}
+                    </dt>
+                    <dd className="text-sm font-semibold text-gray-700 dark:text-dark-200">
+                        {DataFormatter.formatCount(stats.active_days)}
+                    </dd>
+                </div>
+            </dl>
+        </div>
+    );
+}
+
+export function WeekdaySplitSection({
+    visible,
+    onToggle,
+    split,
+}: WeekdaySplitSectionProps) {
+    return (
+        <CollapsibleSection
+            sectionKey="weekday-split"
+            accentClass="bg-linear-to-b from-sky-400 to-sky-600"
+            title={translation.get('weekday-split')}
+            visible={visible}
+            onToggle={() => onToggle('weekday-split')}
+        >
+            <div className="grid grid-cols-1 sm:grid-cols-2 gap-3 sm:gap-4 mb-8">
+                <DayKindCard
+                    icon={LuBriefcase}
+                    iconContainerClassName="bg-sky-500/20 dark:bg-linear-to-br dark:from-sky-500 dark:to-sky-600"
+                    iconClassName="text-sky-600 dark:text-white"
+                    label={translation.get('weekday-split.weekdays')}
+                    stats={split.weekdays}
+                />
+                <DayKindCard
+                    icon={LuSofa}
+                    iconContainerClassName="bg-orange-500/20 dark:bg-linear-to-br dark:from-orange-500 dark:to-orange-600"
+                    iconClassName="text-orange-600 dark:text-white"
+                    label={translation.get('weekday-split.weekends')}
+                    stats={split.weekends}
+                />
+            </div>
+        </CollapsibleSection>
+    );
+}
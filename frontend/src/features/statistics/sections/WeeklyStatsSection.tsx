@@ -23,14 +23,15 @@ import { MetricCard } from '../../../shared/ui/cards/MetricCard';
 import { MetricCardUnitValue } from '../../../shared/ui/cards/MetricCardUnitValue';
 import { CollapsibleSection } from '../../../shared/ui/sections/CollapsibleSection';
 
+// Indexed by `Date.getUTCDay()` (Sunday = 0).
 const WEEKDAY_TRANSLATION_KEYS = [
+    'weekday.sun',
     'weekday.mon',
     'weekday.tue',
     'weekday.wed',
     'weekday.thu',
     'weekday.fri',
     'weekday.sat',
-    'weekday.sun',
 ] as const;
 
 const MILLIS_PER_DAY = 1000 * 60 * 60 * 24;
@@ -39,22 +40,19 @@ function buildWeekdayBarItems(
     dailyActivity: DailyActivityEntry[],
     startDate: string,
 ): DistributionBarItem[] {
-    const days = WEEKDAY_TRANSLATION_KEYS.map((key) => ({
+    const start = startDate ? parsePlainDate(startDate) : null;
+    // Label bars from the week's actual first day so Sunday-start weeks
+    // line up; fall back to Monday when the week is unknown.
+    const firstWeekday = start ? start.getUTCDay() : 1;
+    const days = Array.from({ length: 7 }, (_, index) => ({
         reading_time_sec: 0,
         pages_read: 0,
-        label: translation.get(key),
+        label: translation.get(
+            WEEKDAY_TRANSLATION_KEYS[(firstWeekday + index) % 7],
+        ),
     }));
 
-    if (startDate) {
-        const start = parsePlainDate(startDate);
-        if (!start) {
-            return days.map((day) => ({
-                readTime: day.reading_time_sec,
-                tooltip: `${day.label}: ${DataFormatter.formatReadTime(day.reading_time_sec)}, ${translation.get('pages', day.pages_read)}`,
-                label: day.label,
-            }));
-        }
-
+    if (start) {
         for (const entry of dailyActivity) {
             const current = parsePlainDate(entry.date);
             if (!current) {
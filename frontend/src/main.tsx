@@ -13,7 +13,11 @@ import { api } from './shared/api';
 import { isLoginHashRoute, redirectToLogin } from './shared/api-fetch';
 import type { SiteData } from './shared/contracts';
 import { translation } from './shared/i18n';
+import { keepCustomCssLast } from './shared/lib/dom/keep-custom-css-last';
+import { setDateFormat } from './shared/lib/intl/formatDate';
+import { setDurationFormat } from './shared/lib/intl/formatDuration';
 import { RuntimeUpdatesBridge } from './shared/runtime-updates';
+import { registerServiceWorker } from './shared/service-worker';
 import { initThemePreference } from './shared/theme';
 
 const SERVER_MODE_STORAGE_KEY = 'koshelf_server_mode';
@@ -32,6 +36,8 @@ if (window.__KOSHELF_SERVER_MODE !== 'external') {
 }
 
 initThemePreference();
+keepCustomCssLast();
+registerServiceWorker();
 
 const queryClient = new QueryClient({
     defaultOptions: {
@@ -57,6 +63,11 @@ async function bootstrap(): Promise<void> {
     }
 
     await translation.init(initialSite?.language);
+    setDateFormat(initialSite?.date_format);
+    setDurationFormat(
+        initialSite?.duration_granularity,
+        initialSite?.duration_max_units,
+    );
 
     ReactDOM.createRoot(document.getElementById('root')!).render(
         <React.StrictMode>
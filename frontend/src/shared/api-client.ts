@@ -1,11 +1,21 @@
 import type {
+    HighlightsData,
+    LibraryAuthorDetailData,
+    LibraryAuthorListData,
     PageActivityData,
     LibraryDetailData,
     LibraryListData,
+    LibrarySeriesDetailData,
+    LibrarySeriesListData,
+    LibraryUpNextData,
+    ReadingAbandonedData,
     ReadingAvailablePeriodsData,
     ReadingCalendarData,
     ReadingCompletionsData,
+    ReadingFinishedData,
+    ReadingGenresData,
     ReadingMetricsData,
+    ReadingPaceData,
     ReadingSummaryData,
     SessionInfo,
     SiteData,
@@ -42,6 +52,12 @@ export interface ApiClient {
     logout(): Promise<void>;
     getItems(scope?: ScopeValue): Promise<LibraryListData>;
     getItem(id: string): Promise<LibraryDetailData>;
+    getHighlights(): Promise<HighlightsData>;
+    getSeriesList(): Promise<LibrarySeriesListData>;
+    getSeries(slug: string): Promise<LibrarySeriesDetailData>;
+    getUpNext(): Promise<LibraryUpNextData>;
+    getAuthors(): Promise<LibraryAuthorListData>;
+    getAuthor(slug: string): Promise<LibraryAuthorDetailData>;
     getReadingSummary(
         scope: ScopeValue,
         from?: string,
@@ -67,6 +83,10 @@ export interface ApiClient {
         scope: ScopeValue,
         params: CompletionsParams,
     ): Promise<ReadingCompletionsData>;
+    getReadingGenres(scope: ScopeValue): Promise<ReadingGenresData>;
+    getReadingPace(scope: ScopeValue): Promise<ReadingPaceData>;
+    getReadingFinished(scope: ScopeValue): Promise<ReadingFinishedData>;
+    getReadingAbandoned(scope: ScopeValue): Promise<ReadingAbandonedData>;
     getItemPageActivity(
         id: string,
         completion?: string,
@@ -16,9 +16,19 @@ export interface SiteAuth {
     password_policy: PasswordPolicy;
 }
 
+export type WeekStart = 'monday' | 'sunday';
+export type DateFormat = 'locale' | 'iso' | 'us' | 'eu' | 'long';
+export type DurationGranularity = 'auto' | 'minutes' | 'seconds';
+
 export interface SiteData {
     title: string;
+    logo_url?: string;
     language: string;
+    week_start?: WeekStart;
+    page_size?: number;
+    date_format?: DateFormat;
+    duration_granularity?: DurationGranularity;
+    duration_max_units?: number;
     capabilities: SiteCapabilities;
     auth?: SiteAuth;
     version?: string;
@@ -74,6 +84,19 @@ export interface ReadingStreaks {
     longest: StreakData;
 }
 
+export interface DayKindStats {
+    reading_time_sec: number;
+    pages_read: number;
+    active_days: number;
+    average_reading_time_sec: number;
+    average_pages_read: number;
+}
+
+export interface WeekdaySplit {
+    weekdays: DayKindStats;
+    weekends: DayKindStats;
+}
+
 export interface HeatmapConfig {
     max_scale_sec?: number | null;
 }
@@ -82,6 +105,7 @@ export interface ReadingSummaryData {
     range: ResolvedRange;
     overview: ReadingOverview;
     streaks: ReadingStreaks;
+    weekday_split: WeekdaySplit;
     heatmap_config: HeatmapConfig;
 }
 
@@ -126,7 +150,15 @@ export interface CalendarItemRef {
     authors: string[];
     content_type: 'book' | 'comic';
     item_id?: string | null;
+    color_index: number;
     item_cover?: string | null;
+    removed?: boolean;
+}
+
+export interface CalendarDayEntry {
+    item_ref: string;
+    reading_time_sec: number;
+    pages_read: number;
 }
 
 export interface CalendarScopeStats {
@@ -140,6 +172,7 @@ export interface ReadingCalendarData {
     month: string;
     events: ReadingCalendarEvent[];
     items: Record<string, CalendarItemRef>;
+    days: Record<string, CalendarDayEntry[]>;
     stats_by_scope: {
         all: CalendarScopeStats;
         books: CalendarScopeStats;
@@ -155,6 +188,7 @@ export interface CompletionItem {
     reading_time_sec: number;
     session_count: number;
     pages_read: number;
+    total_pages?: number | null;
     calendar_length_days?: number | null;
     average_speed?: number | null;
     average_session_duration_sec?: number | null;
@@ -164,17 +198,20 @@ export interface CompletionItem {
     item_id?: string | null;
     item_cover?: string | null;
     content_type?: 'book' | 'comic' | null;
+    removed?: boolean;
 }
 
 export interface CompletionGroup {
     key: string;
     items_finished: number;
+    items_started: number;
     reading_time_sec: number;
     items: CompletionItem[];
 }
 
 export interface CompletionsSummary {
     total_items: number;
+    total_started: number;
     total_reading_time_sec: number;
     longest_session_duration_sec: number;
     average_session_duration_sec: number;
@@ -190,11 +227,83 @@ export interface CompletionsShareAssets {
     banner_url: string;
 }
 
+export interface RatingBucket {
+    rating: number;
+    count: number;
+}
+
+export interface CompletionsTopItems {
+    highest_rated: CompletionItem[];
+    longest: CompletionItem[];
+    rating_distribution?: RatingBucket[] | null;
+    superlatives: CompletionSuperlatives;
+}
+
+export interface CompletionSuperlatives {
+    longest_by_pages?: CompletionItem | null;
+    shortest_by_pages?: CompletionItem | null;
+    longest_by_time?: CompletionItem | null;
+    shortest_by_time?: CompletionItem | null;
+}
+
 export interface ReadingCompletionsData {
     groups?: CompletionGroup[] | null;
     items?: CompletionItem[] | null;
     summary?: CompletionsSummary | null;
     share_assets?: CompletionsShareAssets | null;
+    top_items?: CompletionsTopItems | null;
+}
+
+export interface GenreCount {
+    name: string;
+    count: number;
+}
+
+export interface ReadingGenresData {
+    genres: GenreCount[];
+}
+
+export interface PaceBucket {
+    min_pages_per_hour: number;
+    max_pages_per_hour: number | null;
+    sessions: number;
+}
+
+export interface ReadingPaceData {
+    sessions: number;
+    median_pages_per_hour: number | null;
+    buckets: PaceBucket[];
+}
+
+export interface FinishedCount {
+    period: string;
+    completions: number;
+}
+
+export interface ReadingFinishedData {
+    months: FinishedCount[];
+    years: FinishedCount[];
+}
+
+export interface AbandonedYear {
+    year: number;
+    items: number;
+    reading_time_sec: number;
+}
+
+export interface AbandonedItem {
+    id: string;
+    title: string;
+    authors: string[];
+    cover_url: string;
+    content_type: LibraryContentType;
+    reading_time_sec: number;
+    last_read_date?: string | null;
+}
+
+export interface ReadingAbandonedData {
+    years: AbandonedYear[];
+    items: AbandonedItem[];
 }
 
 // ── Library types (shared) ───────────────────────────────────────────────
@@ -209,15 +318,25 @@ export interface LibrarySeries {
 
 export interface LibraryListItem {
     id: string;
+    slug?: string | null;
     title: string;
+    title_sort?: string | null;
     authors: string[];
     series?: LibrarySeries | null;
     status: LibraryStatus;
     progress_percentage?: number | null;
     rating?: number | null;
     annotation_count?: number;
+    highlight_count?: number;
+    bookmark_count?: number;
     cover_url: string;
     content_type: LibraryContentType;
+    added_at?: string | null;
+}
+
+export interface LibraryContributors {
+    editors?: string[];
+    translators?: string[];
 }
 
 export interface ExternalIdentifier {
@@ -229,6 +348,7 @@ export interface ExternalIdentifier {
 
 export interface LibraryDetailItem {
     id: string;
+    slug?: string | null;
     title: string;
     authors: string[];
     series?: LibrarySeries | null;
@@ -238,9 +358,13 @@ export interface LibraryDetailItem {
     cover_url: string;
     content_type: LibraryContentType;
     format: string;
+    contributors?: LibraryContributors | null;
+    added_at?: string | null;
+    is_private?: boolean;
     language?: string | null;
     publisher?: string | null;
     description?: string | null;
+    description_excerpt?: string | null;
     review_note?: string | null;
     pages?: number | null;
     search_base_path: string;
@@ -275,6 +399,71 @@ export interface LibraryAnnotation {
     drawer?: string | null;
 }
 
+export interface HighlightQuote {
+    item_id: string;
+    item_title: string;
+    content_type: LibraryContentType;
+    text: string;
+    chapter?: string | null;
+}
+
+export interface HighlightsData {
+    highlights: HighlightQuote[];
+}
+
+export interface LibrarySeriesSummary {
+    slug: string;
+    name: string;
+    item_count: number;
+    completed_count: number;
+    reading_count: number;
+    abandoned_count: number;
+    total_reading_time_sec: number;
+    cover_url: string;
+}
+
+export interface LibrarySeriesListData {
+    series: LibrarySeriesSummary[];
+}
+
+export interface LibrarySeriesDetailData {
+    series: LibrarySeriesSummary;
+    items: LibraryListItem[];
+}
+
+export interface LibraryUpNextEntry {
+    series_slug: string;
+    series_name: string;
+    completed_count: number;
+    item_count: number;
+    item: LibraryListItem;
+}
+
+export interface LibraryUpNextData {
+    entries: LibraryUpNextEntry[];
+}
+
+export interface LibraryAuthorSummary {
+    slug: string;
+    name: string;
+    item_count: number;
+    completed_count: number;
+    reading_count: number;
+    rated_count: number;
+    average_rating?: number;
+    total_reading_time_sec: number;
+    cover_url: string;
+}
+
+export interface LibraryAuthorListData {
+    authors: LibraryAuthorSummary[];
+}
+
+export interface LibraryAuthorDetailData {
+    author: LibraryAuthorSummary;
+    items: LibraryListItem[];
+}
+
 export interface LibraryCompletionEntry {
     start_date: string;
     end_date: string;
@@ -296,6 +485,7 @@ export interface LibraryItemStats {
     bookmarks?: number | null;
     pages?: number | null;
     total_reading_time_sec?: number | null;
+    total_read_pages?: number | null;
 }
 
 export interface LibrarySessionStats {
@@ -304,6 +494,7 @@ export interface LibrarySessionStats {
     longest_session_duration_sec?: number | null;
     last_read_date?: string | null;
     reading_speed?: number | null;
+    pages_per_day?: number | null;
 }
 
 export interface LibraryDetailStatistics {
@@ -323,6 +514,27 @@ export interface LibraryDetailData {
     completions?: LibraryCompletions | null;
     reader_presentation?: LibraryReaderPresentation | null;
     chapters?: ChapterEntry[] | null;
+    timeline?: LibraryTimeline | null;
+}
+
+export type LibraryTimelineEntryKind =
+    | 'session'
+    | 'highlight'
+    | 'note'
+    | 'bookmark';
+
+export interface LibraryTimelineEntry {
+    kind: LibraryTimelineEntryKind;
+    time?: string | null;
+    date?: string | null;
+    duration_sec?: number | null;
+    pages_read?: number | null;
+    annotation?: LibraryAnnotation | null;
+}
+
+export interface LibraryTimeline {
+    entries: LibraryTimelineEntry[];
+    undated: LibraryTimelineEntry[];
 }
 
 export interface ChapterEntry {
@@ -353,9 +565,14 @@ export interface PageActivityData {
 
 export interface ExportSite {
     name: string;
+    logo_url?: string;
     version: string;
     generated_at: string;
     default_language: string;
+    page_size?: number;
+    date_format?: DateFormat;
+    duration_granularity?: DurationGranularity;
+    duration_max_units?: number;
     capabilities: SiteCapabilities;
 }
 
@@ -9,13 +9,23 @@ import { normalizeScope } from './api-client';
 import { fetchJson } from './api-fetch';
 import type {
     ApiResponse,
+    HighlightsData,
+    LibraryAuthorDetailData,
+    LibraryAuthorListData,
     PageActivityData,
     LibraryDetailData,
     LibraryListData,
+    LibrarySeriesDetailData,
+    LibrarySeriesListData,
+    LibraryUpNextData,
+    ReadingAbandonedData,
     ReadingAvailablePeriodsData,
     ReadingCalendarData,
     ReadingCompletionsData,
+    ReadingFinishedData,
+    ReadingGenresData,
     ReadingMetricsData,
+    ReadingPaceData,
     ReadingSummaryData,
     SessionInfo,
     SiteData,
@@ -108,6 +118,48 @@ export class HttpApiClient implements ApiClient {
         return response.data;
     }
 
+    async getHighlights(): Promise<HighlightsData> {
+        const response = (await fetchJson(
+            '/api/highlights',
+        )) as ApiResponse<HighlightsData>;
+        return response.data;
+    }
+
+    async getSeriesList(): Promise<LibrarySeriesListData> {
+        const response = (await fetchJson(
+            '/api/series',
+        )) as ApiResponse<LibrarySeriesListData>;
+        return response.data;
+    }
+
+    async getSeries(slug: string): Promise<LibrarySeriesDetailData> {
+        const response = (await fetchJson(
+            `/api/series/${encodeURIComponent(slug)}`,
+        )) as ApiResponse<LibrarySeriesDetailData>;
+        return response.data;
+    }
+
+    async getUpNext(): Promise<LibraryUpNextData> {
+        const response = (await fetchJson(
+            '/api/up-next',
+        )) as ApiResponse<LibraryUpNextData>;
+        return response.data;
+    }
+
+    async getAuthors(): Promise<LibraryAuthorListData> {
+        const response = (await fetchJson(
+            '/api/authors',
+        )) as ApiResponse<LibraryAuthorListData>;
+        return response.data;
+    }
+
+    async getAuthor(slug: string): Promise<LibraryAuthorDetailData> {
+        const response = (await fetchJson(
+            `/api/authors/${encodeURIComponent(slug)}`,
+        )) as ApiResponse<LibraryAuthorDetailData>;
+        return response.data;
+    }
+
     async getReadingSummary(
         scope: ScopeValue,
         from?: string,
@@ -197,6 +249,52 @@ export class HttpApiClient implements ApiClient {
         return response.data;
     }
 
+    async getReadingGenres(scope: ScopeValue): Promise<ReadingGenresData> {
+        const selectedScope = normalizeScope(scope);
+        const url = appendParams('/api/reading/genres', {
+            scope: selectedScope,
+        });
+        const response = (await fetchJson(
+            url,
+        )) as ApiResponse<ReadingGenresData>;
+        return response.data;
+    }
+
+    async getReadingPace(scope: ScopeValue): Promise<ReadingPaceData> {
+        const selectedScope = normalizeScope(scope);
+        const url = appendParams('/api/reading/pace', {
+            scope: selectedScope,
+        });
+        const response = (await fetchJson(
+            url,
+        )) as ApiResponse<ReadingPaceData>;
+        return response.data;
+    }
+
+    async getReadingFinished(scope: ScopeValue): Promise<ReadingFinishedData> {
+        const selectedScope = normalizeScope(scope);
+        const url = appendParams('/api/reading/finished', {
+            scope: selectedScope,
+        });
+        const response = (await fetchJson(
+            url,
+        )) as ApiResponse<ReadingFinishedData>;
+        return response.data;
+    }
+
+    async getReadingAbandoned(
+        scope: ScopeValue,
+    ): Promise<ReadingAbandonedData> {
+        const selectedScope = normalizeScope(scope);
+        const url = appendParams('/api/reading/abandoned', {
+            scope: selectedScope,
+        });
+        const response = (await fetchJson(
+            url,
+        )) as ApiResponse<ReadingAbandonedData>;
+        return response.data;
+    }
+
     async getItemPageActivity(
         id: string,
         completion?: string,
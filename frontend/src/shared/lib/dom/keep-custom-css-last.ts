@@ -0,0 +1,20 @@
+const CUSTOM_CSS_LINK_ID = 'koshelf-custom-css';
+
+/**
+ * Keep the `--custom-css` stylesheet the last element of `<head>`. Lazily
+ * loaded routes append their own stylesheets, which would otherwise come
+ * after it and win ties against the custom rules.
+ */
+export function keepCustomCssLast(): void {
+    const link = document.getElementById(CUSTOM_CSS_LINK_ID);
+    if (!link) {
+        return;
+    }
+
+    const observer = new MutationObserver(() => {
+        if (document.head.lastElementChild !== link) {
+            document.head.appendChild(link);
+        }
+    });
+    observer.observe(document.head, { childList: true });
+}
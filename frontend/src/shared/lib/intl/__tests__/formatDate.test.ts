@@ -1,4 +1,4 @@
-import { beforeEach, describe, expect, it, vi } from 'vitest';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
 
 const { getLanguageMock } = vi.hoisted(() => ({
     getLanguageMock: vi.fn(() => 'en-US'),
@@ -13,8 +13,10 @@ vi.mock('../../../i18n', () => ({
 import {
     formatDateObject,
     formatDateObjectToParts,
+    formatMonthKey,
     formatPlainDate,
     formatPlainDateRange,
+    setDateFormat,
 } from '../formatDate';
 import { getRegionPatternLocale } from '../locale-options';
 
@@ -172,3 +174,48 @@ describe('mixed locale date formatting', () => {
         ]);
     });
 });
+
+describe('fixed date formats', () => {
+    beforeEach(() => {
+        getLanguageMock.mockReturnValue('de-DE');
+    });
+
+    afterEach(() => {
+        setDateFormat('locale');
+    });
+
+    it('writes plain dates in the configured format', () => {
+        setDateFormat('iso');
+        expect(formatPlainDate('2026-03-05')).toBe('2026-03-05');
+
+        setDateFormat('us');
+        expect(formatPlainDate('2026-03-05')).toBe('03/05/2026');
+
+        setDateFormat('eu');
+        expect(formatPlainDate('2026-03-05')).toBe('05.03.2026');
+    });
+
+    it('keeps month names in the UI language', () => {
+        setDateFormat('long');
+        expect(formatPlainDate('2026-03-05')).toBe('5. März 2026');
+
+        setDateFormat('iso');
+        expect(formatMonthKey('2026-03', { includeYear: true })).toBe(
+            'März 2026',
+        );
+    });
+
+    it('formats ranges as two full dates', () => {
+        setDateFormat('iso');
+        expect(formatPlainDateRange('2026-03-05', '2026-03-07')).toBe(
+            '2026-03-05 – 2026-03-07',
+        );
+    });
+
+    it('falls back to the locale format', () => {
+        setDateFormat(undefined);
+        expect(formatPlainDate('2026-03-05', { yearDisplay: 'always' })).toBe(
+            '5. März 2026',
+        );
+    });
+});
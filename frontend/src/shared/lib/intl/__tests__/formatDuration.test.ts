@@ -0,0 +1,49 @@
+import { afterEach, describe, expect, it, vi } from 'vitest';
+
+vi.mock('../../../i18n', () => ({
+    translation: {
+        getLanguage: () => 'en-US',
+        get: (key: string) => key.replace('units.', ''),
+    },
+}));
+
+import { formatDuration, setDurationFormat } from '../formatDuration';
+
+describe('formatDuration', () => {
+    afterEach(() => {
+        setDurationFormat(undefined, undefined);
+    });
+
+    it('shows minutes, and seconds only under a minute by default', () => {
+        expect(formatDuration(0)).toBe('0m');
+        expect(formatDuration(40)).toBe('40s');
+        expect(formatDuration(90)).toBe('1m');
+        expect(formatDuration(3600)).toBe('1h 0m');
+        expect(formatDuration(90061, { includeDays: true })).toBe('1d 1h 1m');
+        expect(formatDuration(86400, { includeDays: true })).toBe('1d');
+    });
+
+    it('keeps page read durations short', () => {
+        expect(formatDuration(0, { includeSeconds: true })).toBe('0s');
+        expect(formatDuration(120, { includeSeconds: true })).toBe('2m');
+        expect(formatDuration(3725, { includeSeconds: true })).toBe('1h 2m');
+    });
+
+    it('follows the configured granularity', () => {
+        setDurationFormat('minutes', 0);
+        expect(formatDuration(40)).toBe('0m');
+
+        setDurationFormat('seconds', 0);
+        expect(formatDuration(0)).toBe('0s');
+        expect(formatDuration(3725)).toBe('1h 2m 5s');
+    });
+
+    it('cuts units off at the limit without rounding up', () => {
+        setDurationFormat('auto', 2);
+        const long = 2 * 86400 - 1;
+
+        expect(formatDuration(long, { includeDays: true })).toBe('1d 23h');
+        expect(formatDuration(2 * 86400, { includeDays: true })).toBe('2d');
+        expect(formatDuration(3599)).toBe('59m');
+    });
+});
@@ -1,3 +1,4 @@
+import type { DateFormat } from '../../contracts';
 import { translation } from '../../i18n';
 import { resolveLocalePatternContext } from './locale-options';
 
@@ -43,6 +44,16 @@ type InstantFormatOptions = {
     fallback?: string;
 };
 
+let dateFormat: DateFormat = 'locale';
+
+/**
+ * Use a fixed date format instead of the one the locale implies. Applies to
+ * full dates only; month and weekday names keep following the language.
+ */
+export function setDateFormat(format: DateFormat | undefined): void {
+    dateFormat = format ?? 'locale';
+}
+
 function currentLocale(): string {
     return translation.getLanguage() || FALLBACK_LOCALE;
 }
@@ -273,6 +284,46 @@ function parseDateParts(
     return { year, month, day };
 }
 
+function pad2(value: number): string {
+    return String(value).padStart(2, '0');
+}
+
+/** The date in the configured fixed format, or `null` to follow the locale. */
+function formatFixedDate(
+    year: number,
+    month: number,
+    day: number,
+): string | null {
+    switch (dateFormat) {
+        case 'iso':
+            return `${year}-${pad2(month)}-${pad2(day)}`;
+        case 'us':
+            return `${pad2(month)}/${pad2(day)}/${year}`;
+        case 'eu':
+            return `${pad2(day)}.${pad2(month)}.${year}`;
+        case 'long':
+            return formatDateObjectToString(
+                new Date(Date.UTC(year, month - 1, day, 12, 0, 0, 0)),
+                {
+                    day: 'numeric',
+                    month: 'long',
+                    year: 'numeric',
+                    timeZone: 'UTC',
+                },
+            );
+        default:
+            return null;
+    }
+}
+
+function formatFixedPlainDate(value: Date): string | null {
+    return formatFixedDate(
+        value.getUTCFullYear(),
+        value.getUTCMonth() + 1,
+        value.getUTCDate(),
+    );
+}
+
 function plainDateFormatOptions(
     startDate: Date,
     yearDisplay: YearDisplay,
@@ -336,6 +387,11 @@ export function formatPlainDate(
         return value;
     }
 
+    const fixed = formatFixedPlainDate(parsed);
+    if (fixed !== null) {
+        return fixed;
+    }
+
     return formatDateObjectToString(
         parsed,
         plainDateFormatOptions(
@@ -362,6 +418,10 @@ export function formatPlainDateRange(
         return `${startValue} – ${endValue}`;
     }
 
+    if (dateFormat !== 'locale') {
+        return `${formatPlainDate(startValue, options)} – ${formatPlainDate(endValue, options)}`;
+    }
+
     const locale = currentLocale();
     const formatOptions = plainDateFormatOptions(
         start,
@@ -427,6 +487,18 @@ export function formatInstant(
         return value;
     }
 
+    const fixedDate = formatFixedDate(
+        parsed.getFullYear(),
+        parsed.getMonth() + 1,
+        parsed.getDate(),
+    );
+    if (fixedDate !== null) {
+        const time = formatDateObjectToString(parsed, {
+            timeStyle: options.timeStyle ?? 'short',
+        });
+        return `${fixedDate} ${time}`;
+    }
+
     return formatDateObjectToString(parsed, {
         dateStyle: options.dateStyle ?? 'medium',
         timeStyle: options.timeStyle ?? 'short',
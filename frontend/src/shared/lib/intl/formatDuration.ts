@@ -1,3 +1,4 @@
+import type { DurationGranularity } from '../../contracts';
 import { translation } from '../../i18n';
 import { formatNumber } from './formatNumber';
 import { joinUnitValueParts, type UnitValuePart } from './unit-value';
@@ -5,114 +6,100 @@ import { joinUnitValueParts, type UnitValuePart } from './unit-value';
 type FormatDurationOptions = {
     includeDays?: boolean;
     includeSeconds?: boolean;
+    /**
+     * Whether zero units after the first are shown, e.g. `1h 0m` rather than
+     * `1h`. Defaults to showing them unless days or seconds are included.
+     */
+    keepZeroUnits?: boolean;
 };
 
-export function formatDurationParts(
-    seconds: number | null | undefined,
-    options?: FormatDurationOptions,
-): UnitValuePart[] {
-    if (
-        typeof seconds !== 'number' ||
-        !Number.isFinite(seconds) ||
-        seconds <= 0
-    ) {
-        return [{ amount: '0', unit: translation.get('units.s') }];
-    }
+type DurationUnit = {
+    key: 'units.d' | 'units.h' | 'units.m' | 'units.s';
+    seconds: number;
+};
 
-    const normalizedSeconds = Math.max(0, Math.floor(seconds));
-    const totalMinutes = Math.floor(normalizedSeconds / 60);
+const DAY: DurationUnit = { key: 'units.d', seconds: 86400 };
+const HOUR: DurationUnit = { key: 'units.h', seconds: 3600 };
+const MINUTE: DurationUnit = { key: 'units.m', seconds: 60 };
+const SECOND: DurationUnit = { key: 'units.s', seconds: 1 };
 
-    if (options?.includeSeconds) {
-        if (normalizedSeconds < 60) {
-            return [
-                {
-                    amount: formatNumber(normalizedSeconds),
-                    unit: translation.get('units.s'),
-                },
-            ];
-        }
+/** Units shown with `includeSeconds`, e.g. `1h 12m` rather than `1h 12m 5s`. */
+const PRECISE_MAX_UNITS = 2;
 
-        if (normalizedSeconds < 3600) {
-            const minutes = Math.floor(normalizedSeconds / 60);
-            const secs = normalizedSeconds % 60;
-            const parts: UnitValuePart[] = [
-                {
-                    amount: formatNumber(minutes),
-                    unit: translation.get('units.m'),
-                },
-            ];
-            if (secs > 0) {
-                parts.push({
-                    amount: formatNumber(secs),
-                    unit: translation.get('units.s'),
-                });
-            }
-            return parts;
-        }
+let granularity: DurationGranularity = 'auto';
+let maxUnits = 0;
 
-        const hours = Math.floor(normalizedSeconds / 3600);
-        const minutes = Math.floor((normalizedSeconds % 3600) / 60);
-        const parts: UnitValuePart[] = [
-            {
-                amount: formatNumber(hours),
-                unit: translation.get('units.h'),
-            },
-        ];
-        if (minutes > 0) {
-            parts.push({
-                amount: formatNumber(minutes),
-                unit: translation.get('units.m'),
-            });
-        }
-        return parts;
+/**
+ * Apply the site's duration settings: the smallest unit shown and how many
+ * units a duration shows at most (0 = no limit).
+ */
+export function setDurationFormat(
+    nextGranularity: DurationGranularity | undefined,
+    nextMaxUnits: number | undefined,
+): void {
+    granularity = nextGranularity ?? 'auto';
+    maxUnits =
+        typeof nextMaxUnits === 'number' && nextMaxUnits > 0
+            ? Math.floor(nextMaxUnits)
+            : 0;
+}
+
+function showsSeconds(totalSeconds: number, includeSeconds: boolean): boolean {
+    if (includeSeconds || granularity === 'seconds') {
+        return true;
     }
+    // "0m" for a 40 second read would read as nothing at all.
+    return granularity === 'auto' && totalSeconds > 0 && totalSeconds < 60;
+}
 
-    if (options?.includeDays) {
-        const days = Math.floor(totalMinutes / (24 * 60));
-        const hours = Math.floor((totalMinutes % (24 * 60)) / 60);
-        const minutes = totalMinutes % 60;
+/**
+ * Splits `seconds` into units from largest to smallest. Every unit is
+ * truncated rather than rounded, so a longer duration never shows as shorter
+ * than a shorter one, also when trailing units are cut by the unit limit.
+ */
+export function formatDurationParts(
+    seconds: number | null | undefined,
+    options?: FormatDurationOptions,
+): UnitValuePart[] {
+    const totalSeconds =
+        typeof seconds === 'number' && Number.isFinite(seconds)
+            ? Math.max(0, Math.floor(seconds))
+            : 0;
+    const includeSeconds = Boolean(options?.includeSeconds);
 
-        const parts: UnitValuePart[] = [];
-        if (days > 0) {
-            parts.push({
-                amount: formatNumber(days),
-                unit: translation.get('units.d'),
-            });
-        }
-        if (hours > 0) {
-            parts.push({
-                amount: formatNumber(hours),
-                unit: translation.get('units.h'),
-            });
-        }
-        if (minutes > 0 || parts.length === 0) {
-            parts.push({
-                amount: formatNumber(minutes),
-                unit: translation.get('units.m'),
-            });
-        }
-        return parts;
+    const units = [options?.includeDays ? DAY : null, HOUR, MINUTE].filter(
+        (unit): unit is DurationUnit => unit !== null,
+    );
+    if (showsSeconds(totalSeconds, includeSeconds)) {
+        units.push(SECOND);
     }
+    const smallest = units[units.length - 1];
 
-    const hours = Math.floor(totalMinutes / 60);
-    const minutes = totalMinutes % 60;
+    const values: Array<{ unit: DurationUnit; value: number }> = [];
+    let remaining = totalSeconds;
+    for (const unit of units) {
+        const value = Math.floor(remaining / unit.seconds);
+        remaining -= value * unit.seconds;
+        // Skip leading zero units; the smallest one is kept for zero durations.
+        if (values.length > 0 || value > 0 || unit === smallest) {
+            values.push({ unit, value });
+        }
+    }
 
-    if (hours > 0) {
-        return [
-            { amount: formatNumber(hours), unit: translation.get('units.h') },
-            {
-                amount: formatNumber(minutes),
-                unit: translation.get('units.m'),
-            },
-        ];
+    let kept = includeSeconds ? values.slice(0, PRECISE_MAX_UNITS) : values;
+    const keepZeroUnits =
+        options?.keepZeroUnits ?? !(includeSeconds || options?.includeDays);
+    if (!keepZeroUnits) {
+        kept = kept.filter(({ value }, index) => index === 0 || value > 0);
+    }
+    if (maxUnits > 0) {
+        kept = kept.slice(0, maxUnits);
     }
 
-    return [
-        {
-            amount: formatNumber(totalMinutes),
-            unit: translation.get('units.m'),
-        },
-    ];
+    return kept.map(({ unit, value }) => ({
+        amount: formatNumber(value),
+        unit: translation.get(unit.key),
+    }));
 }
 
 export function formatDuration(
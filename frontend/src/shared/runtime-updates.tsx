@@ -21,10 +21,16 @@ const QUERY_PREFIXES_TO_INVALIDATE: ReadonlyArray<readonly string[]> = [
     ['site'],
     ['library-list'],
     ['library-detail'],
+    ['series-list'],
+    ['series-detail'],
+    ['up-next'],
+    ['author-list'],
+    ['author-detail'],
     ['page-activity'],
     ['statistics-index'],
     ['statistics-week'],
     ['statistics-year'],
+    ['statistics-abandoned'],
     ['calendar-months'],
     ['calendar-month'],
     ['recap-index'],
@@ -0,0 +1,39 @@
+/**
+ * Register the service worker that keeps the app usable offline.
+ *
+ * A new KoShelf build ships a worker with new cache names. It activates
+ * immediately, so the page reloads once to pick up the matching assets.
+ */
+export function registerServiceWorker(): void {
+    if (!('serviceWorker' in navigator)) {
+        return;
+    }
+
+    const hadController = Boolean(navigator.serviceWorker.controller);
+    let reloading = false;
+    navigator.serviceWorker.addEventListener('controllerchange', () => {
+        if (!hadController || reloading) {
+            return;
+        }
+        reloading = true;
+        window.location.reload();
+    });
+
+    window.addEventListener('load', () => {
+        navigator.serviceWorker
+            .register('./service-worker.js')
+            .then((registration) => {
+                // The app rarely navigates, so check for a new build whenever
+                // it comes back to the foreground.
+                document.addEventListener('visibilitychange', () => {
+                    if (document.visibilityState === 'visible') {
+                        void registration.update().catch(() => {});
+                    }
+                });
+            })
+            .catch(() => {
+                // Offline support is optional (e.g. the Vite dev server has
+                // no worker, and browsers refuse it on insecure origins).
+            });
+    });
+}
@@ -12,14 +12,24 @@ import type {
     ExportPageActivityData,
     ExportReadingPeriods,
     ExportSite,
+    HighlightsData,
+    LibraryAuthorDetailData,
+    LibraryAuthorListData,
     LibraryDetailData,
     LibraryListData,
     LibraryListItem,
+    LibrarySeriesDetailData,
+    LibrarySeriesListData,
+    LibraryUpNextData,
     MetricPoint,
+    ReadingAbandonedData,
     ReadingAvailablePeriodsData,
     ReadingCalendarData,
     ReadingCompletionsData,
+    ReadingFinishedData,
+    ReadingGenresData,
     ReadingMetricsData,
+    ReadingPaceData,
     ReadingSummaryData,
     SessionInfo,
     SiteData,
@@ -61,6 +71,8 @@ function monthKeysForRange(from?: string, to?: string): string[] {
     return keys;
 }
 
+const ITEM_ID_PATTERN = /^[0-9a-f]{32}$/i;
+
 // ── StaticApiClient ─────────────────────────────────────────────────────
 
 export class StaticApiClient implements ApiClient {
@@ -80,7 +92,12 @@ export class StaticApiClient implements ApiClient {
         const exported = (await fetchJson('/data/site.json')) as ExportSite;
         return {
             title: exported.name,
+            logo_url: exported.logo_url,
             language: exported.default_language,
+            page_size: exported.page_size,
+            date_format: exported.date_format,
+            duration_granularity: exported.duration_granularity,
+            duration_max_units: exported.duration_max_units,
             capabilities: exported.capabilities,
             version: exported.version,
             generated_at: exported.generated_at,
@@ -126,8 +143,70 @@ export class StaticApiClient implements ApiClient {
         return { items };
     }
 
+    /** Map a URL slug (`--id-scheme slug`), current or retired, to the item
+     * ID the data files use. */
+    private async resolveItemId(key: string): Promise<string> {
+        if (ITEM_ID_PATTERN.test(key)) {
+            return key;
+        }
+        const slugs = await this.fetchOptionalMap('/data/items/slugs.json');
+        if (slugs[key]) {
+            return slugs[key];
+        }
+        const redirects = await this.fetchOptionalMap(
+            '/data/items/redirects.json',
+        );
+        const target = redirects[key];
+        return target ? (slugs[target] ?? target) : key;
+    }
+
+    private async fetchOptionalMap(
+        path: string,
+    ): Promise<Record<string, string>> {
+        try {
+            return await this.fetchCached<Record<string, string>>(path);
+        } catch {
+            return {};
+        }
+    }
+
     async getItem(id: string): Promise<LibraryDetailData> {
-        return (await fetchJson(`/data/items/${id}.json`)) as LibraryDetailData;
+        const itemId = await this.resolveItemId(id);
+        return (await fetchJson(
+            `/data/items/${itemId}.json`,
+        )) as LibraryDetailData;
+    }
+
+    async getHighlights(): Promise<HighlightsData> {
+        return this.fetchCached<HighlightsData>('/data/highlights.json');
+    }
+
+    async getSeriesList(): Promise<LibrarySeriesListData> {
+        return this.fetchCached<LibrarySeriesListData>(
+            '/data/series/index.json',
+        );
+    }
+
+    async getSeries(slug: string): Promise<LibrarySeriesDetailData> {
+        return (await fetchJson(
+            `/data/series/${encodeURIComponent(slug)}.json`,
+        )) as LibrarySeriesDetailData;
+    }
+
+    async getUpNext(): Promise<LibraryUpNextData> {
+        return this.fetchCached<LibraryUpNextData>('/data/up-next.json');
+    }
+
+    async getAuthors(): Promise<LibraryAuthorListData> {
+        return this.fetchCached<LibraryAuthorListData>(
+            '/data/authors/index.json',
+        );
+    }
+
+    async getAuthor(slug: string): Promise<LibraryAuthorDetailData> {
+        return (await fetchJson(
+            `/data/authors/${encodeURIComponent(slug)}.json`,
+        )) as LibraryAuthorDetailData;
     }
 
     async getReadingSummary(
@@ -155,7 +234,7 @@ export class StaticApiClient implements ApiClient {
             );
         }
 
-        // Week-aligned range: key is the Monday date (from).
+        // Week-aligned range: key is the first day of the week (from).
         return this.fetchCached<ReadingSummaryData>(
             `/data/reading/summary/week/${from}/${selectedScope}.json`,
         );
@@ -258,14 +337,45 @@ export class StaticApiClient implements ApiClient {
         )) as ReadingCompletionsData;
     }
 
+    async getReadingGenres(scope: ScopeValue): Promise<ReadingGenresData> {
+        const selectedScope = normalizeScope(scope);
+        return this.fetchCached<ReadingGenresData>(
+            `/data/reading/genres/${selectedScope}.json`,
+        );
+    }
+
+    async getReadingPace(scope: ScopeValue): Promise<ReadingPaceData> {
+        const selectedScope = normalizeScope(scope);
+        return this.fetchCached<ReadingPaceData>(
+            `/data/reading/pace/${selectedScope}.json`,
+        );
+    }
+
+    async getReadingFinished(scope: ScopeValue): Promise<ReadingFinishedData> {
+        const selectedScope = normalizeScope(scope);
+        return this.fetchCached<ReadingFinishedData>(
+            `/data/reading/finished/${selectedScope}.json`,
+        );
+    }
+
+    async getReadingAbandoned(
+        scope: ScopeValue,
+    ): Promise<ReadingAbandonedData> {
+        const selectedScope = normalizeScope(scope);
+        return this.fetchCached<ReadingAbandonedData>(
+            `/data/reading/abandoned/${selectedScope}.json`,
+        );
+    }
+
     async getItemPageActivity(
         id: string,
         completion?: string,
     ): Promise<PageActivityData> {
         let exported: ExportPageActivityData;
         try {
+            const itemId = await this.resolveItemId(id);
             exported = (await fetchJson(
-                `/data/items/page-activity/${id}.json`,
+                `/data/items/page-activity/${itemId}.json`,
             )) as ExportPageActivityData;
         } catch {
             return {
@@ -91,8 +91,9 @@ const SIZE_CLASSES = {
     xs: 'h-auto px-2 py-1.5 text-sm gap-1.5 rounded-md',
 } as const;
 
+// Buttons do nothing on paper, so they are left out of printouts.
 const BASE_CLASS =
-    'inline-flex items-center justify-center font-medium transition-colors focus-visible:outline-hidden focus-visible:ring-2 focus-visible:ring-primary-500/50 disabled:opacity-60';
+    'inline-flex items-center justify-center font-medium transition-colors focus-visible:outline-hidden focus-visible:ring-2 focus-visible:ring-primary-500/50 disabled:opacity-60 print:hidden';
 
 export type ButtonVariantsOptions = {
     variant?: ButtonVariant | null;
@@ -6,7 +6,7 @@ type PageContentProps = {
 };
 
 const PAGE_CONTENT_BASE_CLASSNAME =
-    'pt-[88px] md:pt-24 pb-28 lg:pb-6 px-4 md:px-6';
+    'pt-[88px] md:pt-24 pb-28 lg:pb-6 px-4 md:px-6 print:pt-6 print:pb-0';
 
 export function PageContent({ children, className = '' }: PageContentProps) {
     const resolvedClassName = className
@@ -21,7 +21,9 @@ export function PageHeader({ title, controls }: PageHeaderProps) {
                     {title}
                 </h2>
             ),
-            controls: <div className="flex items-center">{controls}</div>,
+            controls: (
+                <div className="flex items-center print:hidden">{controls}</div>
+            ),
         }),
         [controls, title],
     );
@@ -54,7 +54,11 @@ export function CollapsibleSection({
     contentClassName,
     children,
 }: CollapsibleSectionProps) {
-    const containerClassName = [contentClassName, visible ? '' : 'hidden']
+    // Collapsed sections still print.
+    const containerClassName = [
+        contentClassName,
+        visible ? '' : 'hidden print:block',
+    ]
         .filter(Boolean)
         .join(' ');
 
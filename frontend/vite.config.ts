@@ -5,6 +5,10 @@ export default defineConfig(({ mode }) => {
     const env = loadEnv(mode, process.cwd(), '');
     const backendTarget =
         env.KOSHELF_DEV_BACKEND_URL || 'http://localhost:3000';
+    // Readable bundles with source maps, for inspecting the embedded build.
+    const debugAssets = ['1', 'true', 'yes', 'on'].includes(
+        (env.KOSHELF_DEBUG_ASSETS ?? '').toLowerCase(),
+    );
 
     return {
         plugins: [react()],
@@ -22,11 +26,17 @@ export default defineConfig(({ mode }) => {
                     target: backendTarget,
                     changeOrigin: true,
                 },
+                '/manifest.json': {
+                    target: backendTarget,
+                    changeOrigin: true,
+                },
             },
         },
         build: {
             outDir: 'dist',
-            sourcemap: false,
+            sourcemap: debugAssets,
+            minify: !debugAssets,
+            cssMinify: !debugAssets,
             emptyOutDir: true,
             rollupOptions: {
                 output: {
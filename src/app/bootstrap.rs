@@ -1,9 +1,24 @@
-use crate::app::config::{CommonArgs, SiteConfig, parse_time_to_seconds};
-use crate::pipeline::ingest::{load_reading_data, sync_library};
+use crate::app::config::{
+    CommonArgs, SiteAssetSources, SiteConfig, parse_stats_date, parse_time_to_seconds,
+};
+use crate::pipeline::build_report::BuildReport;
+use crate::pipeline::custom_css;
+use crate::pipeline::html_includes::HtmlIncludes;
+use crate::pipeline::icons;
+use crate::pipeline::ingest::{
+    DEFAULT_SCAN_JOBS, StatisticsParseCache, load_reading_data, sync_library, sync_slugs,
+};
+use crate::pipeline::logo;
 use crate::pipeline::media::{self, resolve_media_dirs};
 use crate::pipeline::recap::regenerate_share_images;
 use crate::server::api::responses::site::{PasswordPolicy, SiteAuth, SiteCapabilities, SiteData};
+use crate::shelf::library::authors::AuthorAliases;
+use crate::shelf::statistics::StatisticsConfig;
+use crate::shelf::statistics::calendar::CalendarColorBy;
+use crate::shelf::statistics::compute::completion_detection::CompletionConfig;
+use crate::shelf::statistics::compute::completion_overrides::CompletionOverrides;
 use crate::shelf::time_config::TimeConfig;
+use crate::source::remote::{self, RemoteSourceKind, SftpSource};
 use crate::source::scanner::MetadataLocation;
 use crate::store::lifecycle::{
     RuntimeDataPathOptions, RuntimeDataPolicy, resolve_runtime_data_policy,
@@ -12,9 +27,11 @@ use crate::store::memory::ReadingData;
 use crate::store::sqlite::repo::LibraryRepository;
 use crate::store::sqlite::{open_library_pool, run_library_migrations};
 use anyhow::{Context, Result};
-use log::info;
+use log::{info, warn};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 fn metadata_location(common: &CommonArgs) -> MetadataLocation {
     if let Some(ref docsettings_path) = common.docsettings_path {
@@ -26,13 +43,13 @@ fn metadata_location(common: &CommonArgs) -> MetadataLocation {
     }
 }
 
-fn resolve_data_policy(common: &CommonArgs) -> RuntimeDataPolicy {
+pub(super) fn resolve_data_policy(common: &CommonArgs) -> RuntimeDataPolicy {
     resolve_runtime_data_policy(&RuntimeDataPathOptions {
         data_path: common.data_path.clone(),
     })
 }
 
-fn build_site_config(
+pub(super) fn build_site_config(
     common: &CommonArgs,
     output_dir: PathBuf,
     is_internal_server: bool,
@@ -56,38 +73,286 @@ fn build_site_config(
         None
     };
 
+    let author_aliases = match common.author_aliases {
+        Some(ref path) => {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read author aliases {:?}", path))?;
+            let aliases = AuthorAliases::from_toml_str(&contents)
+                .with_context(|| format!("Failed to load author aliases {:?}", path))?;
+            if aliases.is_empty() {
+                warn!("Author aliases file {:?} lists no authors", path);
+            } else {
+                info!("Loaded aliases for {} authors", aliases.len());
+            }
+            aliases
+        }
+        None => AuthorAliases::default(),
+    };
+
+    let completion_overrides = match common.completion_overrides {
+        Some(ref path) => {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read completion overrides {:?}", path))?;
+            let overrides = CompletionOverrides::from_toml_str(&contents)
+                .with_context(|| format!("Failed to load completion overrides {:?}", path))?;
+            if overrides.is_empty() {
+                warn!("Completion overrides file {:?} lists no books", path);
+            } else {
+                info!("Loaded completion overrides for {} books", overrides.len());
+            }
+            overrides
+        }
+        None => CompletionOverrides::default(),
+    };
+
+    let watch_paths = resolve_watch_paths(
+        &common.watch_path,
+        &output_dir,
+        runtime_data_policy.persistent_data_dir(),
+    )?;
+
     Ok(SiteConfig {
         output_dir,
         site_title: common.title.clone(),
+        html_includes: Arc::new(RwLock::new(HtmlIncludes::load(
+            common.head_include.as_deref(),
+            common.footer_include.as_deref(),
+        )?)),
         include_unread: common.include_unread,
+        scan_jobs: common.scan_jobs.unwrap_or(DEFAULT_SCAN_JOBS),
+        normalize_authors: common.normalize_authors,
+        author_aliases,
+        id_scheme: common.id_scheme,
+        description_format: common.description_format,
+        description_max_chars: common.description_max_chars,
+        cover_fit: common.cover_fit,
+        series_min_items: common.series_min_items,
+        demo: common.demo,
+        page_size: common.page_size,
+        date_format: common.date_format,
+        duration_granularity: common.duration_granularity,
+        duration_max_units: common.duration_max_units,
+        on_rebuild: common.on_rebuild.clone(),
+        watch_paths,
+        asset_sources: SiteAssetSources {
+            logo: common.logo.clone(),
+            custom_css: common.custom_css.clone(),
+            head_include: common.head_include.clone(),
+            footer_include: common.footer_include.clone(),
+        },
         library_paths: common.library_path.clone(),
         metadata_location: metadata_location(common),
         statistics_db_paths: common.statistics_db.clone(),
         kobo_db_path: common.kobo_db.clone(),
         heatmap_scale_max,
-        time_config: TimeConfig::from_cli(&common.timezone, &common.day_start_time)?,
+        time_config: TimeConfig::from_cli(
+            &common.timezone,
+            &common.day_start_time,
+            &common.week_start,
+        )?
+        .with_fixed_now(common.source_date),
+        stats_config: StatisticsConfig::default()
+            .with_session_gap_minutes(common.session_gap)
+            .with_streak_grace_days(common.streak_grace_days),
+        calendar_color_by: common
+            .calendar_color_by
+            .as_deref()
+            .map(CalendarColorBy::parse)
+            .transpose()?
+            .unwrap_or_default(),
+        recap_show_empty_months: common.recap_show_empty_months,
         min_pages_per_day: common.min_pages_per_day,
         min_time_per_day,
+        session_min: common.session_min,
+        session_max: common.session_max,
+        session_max_mode: common.session_max_mode,
+        stats_from: parse_stats_date(common.stats_from.as_deref(), "--stats-from")?,
+        stats_to: parse_stats_date(common.stats_to.as_deref(), "--stats-to")?,
+        completion_config: CompletionConfig::from_cli(
+            common.completion_min_pages,
+            common.completion_min_early,
+            common.completion_min_late,
+            common.completion_restart_window,
+        ),
+        completion_overrides,
         include_all_stats: common.include_all_stats,
+        keep_ghost_stats: common.keep_ghost_stats,
+        match_stats_by_title: common.match_stats_by_title,
+        stats_exclude: common.stats_exclude.clone(),
         is_internal_server,
         language: common.language.clone(),
         use_stable_page_metadata: !common.ignore_stable_page_metadata,
         auth_enabled,
         writeback_enabled,
         include_files,
+        export_csv: false,
+        export_all_annotations: false,
         runtime_data_policy,
     })
 }
 
+/// Make `--watch-path` entries absolute and reject those overlapping a
+/// directory KoShelf writes to, whose changes would trigger endless rebuilds.
+fn resolve_watch_paths(
+    watch_paths: &[PathBuf],
+    output_dir: &Path,
+    data_dir: Option<&Path>,
+) -> Result<Vec<PathBuf>> {
+    if watch_paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let written_dirs = [Some(output_dir), data_dir]
+        .into_iter()
+        .flatten()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(|dir| std::fs::canonicalize(dir).or_else(|_| std::path::absolute(dir)))
+        .collect::<std::io::Result<Vec<_>>>()?;
+
+    watch_paths
+        .iter()
+        .map(|path| {
+            let resolved = std::fs::canonicalize(path)
+                .with_context(|| format!("Failed to resolve watch path {:?}", path))?;
+            if let Some(dir) = written_dirs
+                .iter()
+                .find(|dir| resolved.starts_with(dir) || dir.starts_with(&resolved))
+            {
+                anyhow::bail!(
+                    "Watch path {:?} overlaps {:?}, which KoShelf writes to; \
+                     watching it would rebuild endlessly",
+                    path,
+                    dir
+                );
+            }
+            Ok(resolved)
+        })
+        .collect()
+}
+
+/// Directory that keeps mirrored remote sources between runs, so an offline
+/// device can still fall back to the previous copy. Ephemeral runs use the
+/// user's cache directory, never a shared temp path another user could plant
+/// files in; without one, the mirror lives in a temp directory for this run.
+fn remote_cache_root(
+    runtime_data_policy: &RuntimeDataPolicy,
+) -> Result<(PathBuf, Option<tempfile::TempDir>)> {
+    if let Some(dir) = runtime_data_policy.persistent_data_dir() {
+        return Ok((dir.join("remote-sources"), None));
+    }
+    if let Some(cache_dir) = dirs::cache_dir() {
+        let root = cache_dir.join("koshelf").join("remote-sources");
+        create_private_dir(&root)?;
+        return Ok((root, None));
+    }
+    let temp_dir = tempfile::Builder::new()
+        .prefix("koshelf-remote-sources-")
+        .tempdir()
+        .context("Failed to create a temporary directory for remote sources")?;
+    Ok((temp_dir.path().to_path_buf(), Some(temp_dir)))
+}
+
+/// Create `path` readable by the current user only.
+fn create_private_dir(path: &Path) -> Result<()> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder
+        .create(path)
+        .with_context(|| format!("Failed to create remote cache directory {:?}", path))
+}
+
+/// Mirror every `--source` into the local cache and add the cached copies to
+/// the library paths and statistics databases.
+async fn resolve_remote_sources(
+    common: &CommonArgs,
+    cache_root: &Path,
+) -> Result<(CommonArgs, Vec<RemoteMirror>)> {
+    let mut resolved = common.clone();
+    let mut mirrors = Vec::new();
+    for url in &common.source {
+        let source = SftpSource::parse(url)?;
+        let mirror = RemoteMirror {
+            cache_path: source.cache_path(cache_root),
+            source,
+        };
+        let kind = mirror.source.kind();
+        let local_path = mirror.sync().await?;
+
+        match kind {
+            RemoteSourceKind::Library => resolved.library_path.push(local_path),
+            RemoteSourceKind::StatisticsDb => resolved.statistics_db.push(local_path),
+        }
+        mirrors.push(mirror);
+    }
+    Ok((resolved, mirrors))
+}
+
+/// A `--source` and the local copy the pipeline reads.
+#[derive(Clone)]
+pub(crate) struct RemoteMirror {
+    source: SftpSource,
+    cache_path: PathBuf,
+}
+
+impl RemoteMirror {
+    async fn sync(&self) -> Result<PathBuf> {
+        let mirror = self.clone();
+        tokio::task::spawn_blocking(move || {
+            remote::sync_to_cache(&mirror.source, &mirror.cache_path)
+        })
+        .await
+        .context("Remote source sync task failed")?
+    }
+}
+
+/// Syncs the remote sources again every `--source-interval` while `serve` or
+/// `export --watch` runs. Changed files land in the mirrors, where the file
+/// watcher picks them up like any other library change.
+pub(crate) struct RemoteResync {
+    mirrors: Vec<RemoteMirror>,
+    interval: Duration,
+}
+
+impl RemoteResync {
+    pub(crate) fn spawn(self) {
+        info!(
+            "Syncing {} remote source(s) every {} s",
+            self.mirrors.len(),
+            self.interval.as_secs()
+        );
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick fires at once; startup has just synced.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                for mirror in &self.mirrors {
+                    if let Err(e) = mirror.sync().await {
+                        warn!("{:#}", e);
+                    }
+                }
+            }
+        });
+    }
+}
+
 /// State produced by the shared pipeline initialization.
 pub(crate) struct PipelineState {
     pub config: SiteConfig,
     pub repo: LibraryRepository,
     pub reading_data: Option<ReadingData>,
+    /// Statistics parse of the initial build, for the watcher's reloads
+    pub stats_cache: StatisticsParseCache,
     pub has_reading_data: bool,
     pub site_data: SiteData,
     pub generated_at: String,
+    pub build_report: BuildReport,
+    /// Periodic re-sync of `--source` mirrors, for the long-running modes
+    pub remote_resync: Option<RemoteResync>,
     pub _runtime_temp_dir: Option<tempfile::TempDir>,
+    pub _remote_temp_dir: Option<tempfile::TempDir>,
 }
 
 /// Run the shared pipeline: DB setup, library update, statistics, recap images, site metadata.
@@ -112,6 +377,22 @@ pub(crate) async fn initialize_pipeline(
         ),
     }
 
+    let mut remote_temp_dir = None;
+    let (common, remote_mirrors) = if common.source.is_empty() {
+        (common.clone(), Vec::new())
+    } else {
+        let (cache_root, temp_dir) = remote_cache_root(&runtime_data_policy)?;
+        remote_temp_dir = temp_dir;
+        resolve_remote_sources(common, &cache_root).await?
+    };
+    let common = &common;
+    let remote_resync = parse_time_to_seconds(&common.source_interval)?
+        .filter(|_| !remote_mirrors.is_empty())
+        .map(|secs| RemoteResync {
+            mirrors: remote_mirrors,
+            interval: Duration::from_secs(secs.into()),
+        });
+
     // In ephemeral mode, use a separate temp directory for runtime data.
     let runtime_temp_dir = if !runtime_data_policy.is_persistent() {
         let tmp =
@@ -146,7 +427,10 @@ pub(crate) async fn initialize_pipeline(
         info!("Runtime library DB path: {:?}", db_path);
     }
 
+    let mut build_report = BuildReport::default();
+
     // ── 1. Create DB ─────────────────────────────────────────────────
+    let started = Instant::now();
     let db_path = config
         .runtime_data_policy
         .library_db_path()
@@ -162,10 +446,16 @@ pub(crate) async fn initialize_pipeline(
     // ── 2. Create media directories ──────────────────────────────────
     let media_dirs = resolve_media_dirs(&config.output_dir, is_internal_server);
     media::create_media_directories(&media_dirs)?;
+    icons::sync_custom_icons(common.icon.as_deref(), &media_dirs.icons_dir)?;
+    logo::sync_logo(common.logo.as_deref(), &media_dirs.logo_dir)?;
+    custom_css::sync_custom_css(common.custom_css.as_deref(), &media_dirs.assets_dir)?;
+    build_report.record("setup", started);
 
     // ── 3. Update library ────────────────────────────────────────────
+    let started = Instant::now();
     if !config.library_paths.is_empty() {
         sync_library(&config, &repo, &media_dirs).await?;
+        sync_slugs(&config, &repo).await?;
 
         match repo.load_all_item_ids().await {
             Ok(ids) => {
@@ -179,13 +469,19 @@ pub(crate) async fn initialize_pipeline(
         }
     }
 
+    build_report.record("library scan", started);
+
     // ── 4. Load statistics ───────────────────────────────────────────
-    let reading_data = load_reading_data(&config, &repo).await?;
+    let started = Instant::now();
+    let mut stats_cache = StatisticsParseCache::default();
+    let reading_data = load_reading_data(&config, &repo, &mut stats_cache).await?;
+    build_report.record("statistics", started);
     let has_reading_data = reading_data
         .as_ref()
         .is_some_and(|rd| !rd.stats_data.page_stats.is_empty());
 
     // ── 5. Generate recap images ─────────────────────────────────────
+    let started = Instant::now();
     if let Some(ref rd) = reading_data {
         regenerate_share_images(
             &rd.stats_data,
@@ -193,10 +489,12 @@ pub(crate) async fn initialize_pipeline(
             &rd.page_scaling,
             &media_dirs.recap_dir,
             &config.time_config,
+            &config.stats_config,
             true,
         )
         .await?;
     }
+    build_report.record("share images", started);
 
     // ── 6. Build site metadata ───────────────────────────────────────
     let generated_at = config.time_config.now_rfc3339();
@@ -214,7 +512,13 @@ pub(crate) async fn initialize_pipeline(
 
     let site_data = SiteData {
         title: config.site_title.clone(),
+        logo_url: logo::logo_url(&media_dirs.logo_dir),
         language: config.language.clone(),
+        week_start: config.time_config.week_start,
+        page_size: config.page_size,
+        date_format: config.date_format,
+        duration_granularity: config.duration_granularity,
+        duration_max_units: config.duration_max_units,
         capabilities: SiteCapabilities {
             has_books,
             has_comics,
@@ -229,9 +533,13 @@ pub(crate) async fn initialize_pipeline(
         config,
         repo,
         reading_data,
+        stats_cache,
         has_reading_data,
         site_data,
         generated_at,
+        build_report,
+        remote_resync,
         _runtime_temp_dir: runtime_temp_dir,
+        _remote_temp_dir: remote_temp_dir,
     })
 }
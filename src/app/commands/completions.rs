@@ -0,0 +1,45 @@
+//! Shell completion scripts generated from the clap [`Cli`] definition.
+
+use crate::app::config::Cli;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::io;
+
+const BIN_NAME: &str = "koshelf";
+
+pub(crate) fn print_completions(shell: Shell) {
+    clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, &mut io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn completion_script(shell: Shell) -> String {
+        let mut script = Vec::new();
+        clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, &mut script);
+        String::from_utf8(script).expect("utf-8 completion script")
+    }
+
+    #[test]
+    fn scripts_cover_subcommands_flags_and_choices() {
+        let bash = completion_script(Shell::Bash);
+        assert!(bash.contains("koshelf,serve)"));
+        assert!(bash.contains("--library-path"));
+        assert!(bash.contains("text json"));
+        assert!(bash.contains("bash elvish fish powershell zsh"));
+
+        let zsh = completion_script(Shell::Zsh);
+        assert!(zsh.starts_with("#compdef koshelf"));
+        assert!(zsh.contains("--library-path"));
+        assert!(zsh.contains("(text json)"));
+
+        let fish = completion_script(Shell::Fish);
+        assert!(fish.contains("complete -c koshelf"));
+        assert!(fish.contains("-l tls-cert"));
+
+        let powershell = completion_script(Shell::PowerShell);
+        assert!(powershell.contains("Register-ArgumentCompleter -Native -CommandName 'koshelf'"));
+        assert!(powershell.contains("'koshelf;serve'"));
+    }
+}
@@ -1,14 +1,24 @@
 use crate::app::bootstrap::initialize_pipeline;
 use crate::app::config::ExportArgs;
+use crate::app::preflight;
+use crate::app::shutdown::Shutdown;
+use crate::pipeline::dry_run;
 use crate::pipeline::export::{ExportConfig, export_data_files};
 use crate::pipeline::frontend;
+use crate::pipeline::staging::StagedOutput;
 use crate::pipeline::watcher::FileWatcher;
 use crate::store::memory::{ReadingDataStore, SiteStore};
 use anyhow::{Context, Result};
 use log::info;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Instant;
 
 pub(crate) async fn export(args: ExportArgs) -> Result<()> {
+    if args.common.validate_only {
+        return preflight::run(&args.common, args.validate()).await;
+    }
+
     if let Err(e) = args.validate() {
         super::exit_validation_error("export", e);
     }
@@ -18,34 +28,76 @@ pub(crate) async fn export(args: ExportArgs) -> Result<()> {
         .clone()
         .context("Output directory is required for export")?;
 
-    let state = initialize_pipeline(
+    if args.dry_run {
+        return export_dry_run(&args, &output_dir).await;
+    }
+
+    // Everything below writes to the staging directory until `commit`.
+    let staged = StagedOutput::prepare(&output_dir)?;
+
+    let mut state = initialize_pipeline(
         &args.common,
-        output_dir.clone(),
+        staged.path().to_path_buf(),
         false,
         false,
         false,
         args.include_files,
     )
     .await?;
+    state.config.export_csv = args.export_csv;
+    state.config.export_all_annotations = args.export_all_annotations;
 
     // ── Sync static frontend ─────────────────────────────────────────
-    frontend::sync_static_frontend(&state.config.output_dir, state.has_reading_data)?;
+    let started = Instant::now();
+    frontend::sync_static_frontend(
+        staged.path(),
+        state.has_reading_data,
+        &state.config.site_title,
+        &state
+            .config
+            .html_includes
+            .read()
+            .expect("html includes lock poisoned while reading"),
+    )?;
+    state.build_report.record("frontend", started);
 
     // ── Export data files ────────────────────────────────────────────
     let export_config = ExportConfig {
         site_title: state.config.site_title.clone(),
+        logo_url: state.site_data.logo_url.clone(),
+        generated_at: state.generated_at.clone(),
         language: state.config.language.clone(),
+        page_size: state.config.page_size,
+        date_format: state.config.date_format,
+        duration_granularity: state.config.duration_granularity,
+        duration_max_units: state.config.duration_max_units,
+        description_max_chars: state.config.description_max_chars,
+        series_min_items: state.config.series_min_items,
         include_files: state.config.include_files,
+        export_csv: state.config.export_csv,
+        export_all_annotations: state.config.export_all_annotations,
     };
     export_data_files(
-        &output_dir.join("data"),
-        &output_dir,
+        &staged.path().join("data"),
+        staged.path(),
         &state.repo,
         state.reading_data.as_ref(),
         &export_config,
+        &mut state.build_report,
     )
     .await?;
 
+    let started = Instant::now();
+    staged.commit()?;
+    state.build_report.record("commit", started);
+    state.config.output_dir = output_dir;
+
+    state.build_report.log_summary();
+    if let Some(ref path) = args.build_report {
+        state.build_report.write(path)?;
+        info!("Build report written to {:?}", path);
+    }
+
     if args.watch {
         info!("Watching library changes to refresh static shell/assets and /data export.");
 
@@ -57,6 +109,10 @@ pub(crate) async fn export(args: ExportArgs) -> Result<()> {
             reading_data_store.replace(rd);
         }
 
+        if let Some(remote_resync) = state.remote_resync {
+            remote_resync.spawn();
+        }
+
         let file_watcher = FileWatcher::new(
             state.config,
             Some(site_store),
@@ -64,8 +120,9 @@ pub(crate) async fn export(args: ExportArgs) -> Result<()> {
             None,
             Some(state.repo),
             None,
+            state.stats_cache,
         );
-        if let Err(e) = file_watcher.run().await {
+        if let Err(e) = file_watcher.run(Shutdown::on_signals()).await {
             log::error!("File watcher error: {}", e);
         }
     } else {
@@ -74,3 +131,61 @@ pub(crate) async fn export(args: ExportArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Run the export into a temporary staging directory and report how it
+/// differs from `output_dir`.
+///
+/// The persistent library DB is bypassed as well, so nothing outside the
+/// staging directory is written.
+async fn export_dry_run(args: &ExportArgs, output_dir: &Path) -> Result<()> {
+    let staging_dir = tempfile::tempdir().context("Failed to create dry-run staging directory")?;
+    let mut common = args.common.clone();
+    common.data_path = None;
+
+    let mut state = initialize_pipeline(
+        &common,
+        staging_dir.path().to_path_buf(),
+        false,
+        false,
+        false,
+        false,
+    )
+    .await?;
+
+    let export_config = ExportConfig {
+        site_title: state.config.site_title.clone(),
+        logo_url: state.site_data.logo_url.clone(),
+        generated_at: state.generated_at.clone(),
+        language: state.config.language.clone(),
+        page_size: state.config.page_size,
+        date_format: state.config.date_format,
+        duration_granularity: state.config.duration_granularity,
+        duration_max_units: state.config.duration_max_units,
+        description_max_chars: state.config.description_max_chars,
+        series_min_items: state.config.series_min_items,
+        include_files: false,
+        export_csv: args.export_csv,
+        export_all_annotations: args.export_all_annotations,
+    };
+    export_data_files(
+        &staging_dir.path().join("data"),
+        staging_dir.path(),
+        &state.repo,
+        state.reading_data.as_ref(),
+        &export_config,
+        &mut state.build_report,
+    )
+    .await?;
+
+    let plan = dry_run::plan_export(
+        staging_dir.path(),
+        output_dir,
+        &state.repo,
+        args.include_files,
+        state.has_reading_data,
+    )
+    .await?;
+    plan.log_summary(output_dir);
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::shelf::models::{BookInfo, LibraryItemFormat};
+use crate::source::parsers::{ComicParser, EpubParser, Fb2Parser, MobiParser, sniff_format};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::io::Cursor;
+use std::path::Path;
+
+#[derive(Serialize)]
+struct Inspection {
+    path: String,
+    /// Format implied by the file extension.
+    format: LibraryItemFormat,
+    /// Format of the parser that was used, after sniffing the contents.
+    parsed_as: LibraryItemFormat,
+    /// Parsed metadata, without the raw cover bytes.
+    book_info: serde_json::Value,
+    cover: Option<CoverReport>,
+}
+
+#[derive(Serialize)]
+struct CoverReport {
+    mime_type: Option<String>,
+    size_bytes: usize,
+    width: Option<u32>,
+    height: Option<u32>,
+    /// Archive entry the cover was taken from (comics only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archive_entry: Option<String>,
+}
+
+/// Parse a single book or comic and print what KoShelf extracted from it.
+pub(crate) async fn inspect(path: &Path) -> Result<()> {
+    let inspection = inspect_file(path).await?;
+    println!("{}", serde_json::to_string_pretty(&inspection)?);
+    Ok(())
+}
+
+async fn inspect_file(path: &Path) -> Result<Inspection> {
+    let format = LibraryItemFormat::from_path(path).with_context(|| {
+        format!(
+            "{:?} is not a supported book or comic file (EPUB, FB2, MOBI, CBZ, CBR)",
+            path
+        )
+    })?;
+    let parsed_as = sniff_format(path, format)?;
+
+    let mut book_info = match parsed_as {
+        LibraryItemFormat::Epub => EpubParser::new().parse(path).await,
+        LibraryItemFormat::Fb2 => Fb2Parser::new().parse(path).await,
+        LibraryItemFormat::Cbz | LibraryItemFormat::Cbr => ComicParser::new().parse(path).await,
+        LibraryItemFormat::Mobi => MobiParser::new().parse(path).await,
+    }
+    .with_context(|| format!("Failed to parse {:?} as {:?}", path, parsed_as))?;
+
+    let archive_entry = match parsed_as {
+        LibraryItemFormat::Cbz | LibraryItemFormat::Cbr => ComicParser::cover_entry(path)?,
+        _ => None,
+    };
+    let cover = cover_report(&mut book_info, archive_entry);
+
+    let mut book_info = serde_json::to_value(&book_info)?;
+    if let Some(fields) = book_info.as_object_mut() {
+        // Reported under `cover` instead.
+        fields.remove("cover_data");
+        fields.remove("cover_mime_type");
+    }
+
+    Ok(Inspection {
+        path: path.display().to_string(),
+        format,
+        parsed_as,
+        book_info,
+        cover,
+    })
+}
+
+/// Take the cover out of `book_info` and describe it instead.
+fn cover_report(book_info: &mut BookInfo, archive_entry: Option<String>) -> Option<CoverReport> {
+    let data = book_info.cover_data.take()?;
+    let dimensions = image::ImageReader::new(Cursor::new(&data))
+        .with_guessed_format()
+        .ok()
+        .and_then(|reader| reader.into_dimensions().ok());
+
+    Some(CoverReport {
+        mime_type: book_info.cover_mime_type.take(),
+        size_bytes: data.len(),
+        width: dimensions.map(|(width, _)| width),
+        height: dimensions.map(|(_, height)| height),
+        archive_entry,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbImage::new(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[tokio::test]
+    async fn comic_reports_cover_entry_and_pages() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("issue.cbz");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        for (name, data) in [("002.png", png(2, 2)), ("001.png", png(3, 4))] {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(&data).unwrap();
+        }
+        zip.start_file("ComicInfo.xml", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"<ComicInfo><Title>Issue One</Title></ComicInfo>")
+            .unwrap();
+        zip.finish().unwrap();
+
+        let inspection = inspect_file(&path).await.unwrap();
+        assert_eq!(inspection.parsed_as, LibraryItemFormat::Cbz);
+        assert_eq!(inspection.book_info["title"], "Issue One");
+        assert_eq!(inspection.book_info["pages"], 2);
+        assert!(inspection.book_info.get("cover_data").is_none());
+
+        let cover = inspection.cover.unwrap();
+        assert_eq!(cover.archive_entry.as_deref(), Some("001.png"));
+        assert_eq!(cover.mime_type.as_deref(), Some("image/png"));
+        assert_eq!((cover.width, cover.height), (Some(3), Some(4)));
+    }
+
+    #[tokio::test]
+    async fn unsupported_extension_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, b"hello").unwrap();
+        assert!(inspect_file(&path).await.is_err());
+    }
+}
@@ -1,7 +1,10 @@
+mod completions;
 mod export;
+mod inspect;
 mod licenses;
 mod serve;
 mod set_password;
+mod stats_dump;
 
 use crate::app::config::{Cli, CliCommand};
 use anyhow::Result;
@@ -28,6 +31,7 @@ pub async fn dispatch(command: CliCommand) -> Result<()> {
     match command {
         CliCommand::Serve(args) => serve::serve(args).await,
         CliCommand::Export(args) => export::export(args).await,
+        CliCommand::StatsDump(args) => stats_dump::stats_dump(args).await,
         CliCommand::SetPassword {
             data_path,
             password,
@@ -54,5 +58,10 @@ pub async fn dispatch(command: CliCommand) -> Result<()> {
             println!("https://github.com/paviro/KOShelf");
             Ok(())
         }
+        CliCommand::Completions { shell } => {
+            completions::print_completions(shell);
+            Ok(())
+        }
+        CliCommand::Inspect { file } => inspect::inspect(&file).await,
     }
 }
@@ -1,14 +1,18 @@
 use crate::app::bootstrap::initialize_pipeline;
-use crate::app::config::{ServeArgs, parse_trusted_proxy_nets};
+use crate::app::config::{ServeArgs, parse_custom_headers, parse_trusted_proxy_nets};
+use crate::app::preflight;
+use crate::app::shutdown::Shutdown;
 use crate::pipeline::watcher::FileWatcher;
 use crate::server::auth::AuthState;
+use crate::server::auth::access::AccessGate;
 use crate::server::auth::client_addr::ClientAddrResolver;
 use crate::server::auth::password::{
     generate_random_password, generate_token_key, get_stored_auth, hash_password, set_stored_auth,
 };
 use crate::server::auth::rate_limit::login_rate_limiter;
 use crate::server::auth::session::{cleanup_expired, paseto_key_from_bytes};
-use crate::server::{WebServer, WebServerOptions, WriteCoordinator};
+use crate::server::kosync::KosyncState;
+use crate::server::{TlsPaths, WebServer, WebServerOptions, WriteCoordinator};
 use crate::store::memory::{ReadingDataStore, SiteStore, UpdateNotifier};
 use crate::store::sqlite::{open_koshelf_pool, run_koshelf_migrations};
 use anyhow::{Context, Result};
@@ -17,6 +21,10 @@ use std::sync::Arc;
 use std::time::Duration;
 
 pub(crate) async fn serve(args: ServeArgs) -> Result<()> {
+    if args.common.validate_only {
+        return preflight::run(&args.common, args.validate()).await;
+    }
+
     if let Err(e) = args.validate() {
         super::exit_validation_error("serve", e);
     }
@@ -121,6 +129,21 @@ pub(crate) async fn serve(args: ServeArgs) -> Result<()> {
         None
     };
 
+    // ── KOReader progress sync ───────────────────────────────────────
+    let kosync_state = match (&args.kosync_user, &args.kosync_password) {
+        (Some(username), Some(password)) => {
+            Some(KosyncState::new(koshelf_pool.clone(), username, password))
+        }
+        _ => None,
+    };
+
+    let access_gate = AccessGate::new(
+        args.auth_user.as_deref().zip(args.auth_password.as_deref()),
+        args.auth_token.as_deref(),
+    );
+
+    let custom_headers = parse_custom_headers(&args.headers)?;
+
     // ── Start server ─────────────────────────────────────────────────
     let revision_epoch = format!("serve_{}", &state.generated_at);
     let initial_generated_at = state.generated_at;
@@ -142,6 +165,16 @@ pub(crate) async fn serve(args: ServeArgs) -> Result<()> {
     };
 
     let timezone = state.config.time_config.timezone;
+    let description_max_chars = state.config.description_max_chars;
+    let series_min_items = state.config.series_min_items;
+    let html_includes = state.config.html_includes.clone();
+
+    // Installed only now so a signal during startup still terminates at once.
+    let shutdown = Shutdown::on_signals();
+
+    if let Some(remote_resync) = state.remote_resync {
+        remote_resync.spawn();
+    }
 
     let file_watcher = FileWatcher::new(
         state.config,
@@ -150,31 +183,56 @@ pub(crate) async fn serve(args: ServeArgs) -> Result<()> {
         Some(update_notifier.clone()),
         Some(state.repo.clone()),
         write_coordinator.as_ref().map(|wc| wc.recent_writes()),
+        state.stats_cache,
     );
 
     let web_server = WebServer::new(WebServerOptions {
         media_cache_dir: output_dir,
+        bind: args.bind,
         port: args.port,
+        tls: args
+            .tls_cert
+            .zip(args.tls_key)
+            .map(|(cert_path, key_path)| TlsPaths {
+                cert_path,
+                key_path,
+            }),
+        access_gate,
+        custom_headers,
         site_store,
         reading_data_store,
         update_notifier,
         library_repo: state.repo,
         auth_state,
         write_coordinator,
+        kosync_state,
         timezone,
+        html_includes,
+        description_max_chars,
+        series_min_items,
+        enable_metrics: args.enable_metrics,
+        open_browser: args.open,
+        shutdown: shutdown.clone(),
     });
 
-    tokio::select! {
-        result = file_watcher.run() => {
-            if let Err(e) = result {
-                log::error!("File watcher error: {}", e);
-            }
-        }
-        result = web_server.run() => {
-            if let Err(e) = result {
-                log::error!("Web server error: {}", e);
-            }
-        }
+    // Either task stopping on its own (e.g. the port is taken) stops the other.
+    let (watcher_result, server_result) = tokio::join!(
+        async {
+            let result = file_watcher.run(shutdown.clone()).await;
+            shutdown.request();
+            result
+        },
+        async {
+            let result = web_server.run().await;
+            shutdown.request();
+            result
+        },
+    );
+    if let Err(e) = watcher_result {
+        log::error!("File watcher error: {}", e);
+    }
+    if let Err(e) = server_result {
+        log::error!("Web server error: {}", e);
     }
 
     Ok(())
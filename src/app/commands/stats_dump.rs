@@ -0,0 +1,156 @@
+use crate::app::bootstrap::initialize_pipeline;
+use crate::app::config::{StatsDumpArgs, parse_since_date};
+use crate::app::preflight;
+use crate::shelf::statistics::{BookStatistics, StatisticsCalculator, StatisticsConfig};
+use crate::shelf::time_config::TimeConfig;
+use crate::source::koreader::types::{
+    BookCompletions, BookSessionStats, ReadCompletion, ReadingStats, StatBook, StatisticsData,
+};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct StatsDump<'a> {
+    reading_stats: ReadingStats,
+    books: Vec<BookSummary<'a>>,
+}
+
+#[derive(Serialize)]
+struct BookSummary<'a> {
+    md5: &'a str,
+    title: &'a str,
+    authors: &'a str,
+    pages: Option<i64>,
+    total_read_time: Option<i64>,
+    total_read_pages: Option<i64>,
+    completions: Option<&'a BookCompletions>,
+    #[serde(flatten)]
+    sessions: BookSessionStats,
+}
+
+/// Output of `--completed-since`.
+#[derive(Serialize)]
+struct CompletedSince<'a> {
+    since: String,
+    completions: Vec<CompletedBook<'a>>,
+}
+
+#[derive(Serialize)]
+struct CompletedBook<'a> {
+    md5: &'a str,
+    title: &'a str,
+    authors: &'a str,
+    #[serde(flatten)]
+    completion: &'a ReadCompletion,
+}
+
+/// Run the pipeline up to statistics loading and print the result as JSON.
+///
+/// Like `export --dry-run`, everything is built in a temporary directory, so
+/// the library (when given) is scanned and the same filters apply as on the
+/// site, but nothing is written to the data path.
+pub(crate) async fn stats_dump(args: StatsDumpArgs) -> Result<()> {
+    if args.common.validate_only {
+        return preflight::run(&args.common, args.validate()).await;
+    }
+
+    if let Err(e) = args.validate() {
+        super::exit_validation_error("stats-dump", e);
+    }
+
+    let staging_dir = tempfile::tempdir().context("Failed to create stats staging directory")?;
+    let mut common = args.common.clone();
+    common.data_path = None;
+
+    let state = initialize_pipeline(
+        &common,
+        staging_dir.path().to_path_buf(),
+        false,
+        false,
+        false,
+        false,
+    )
+    .await?;
+    let reading_data = state
+        .reading_data
+        .context("None of the statistics databases could be found")?;
+
+    let stats_data = &reading_data.stats_data;
+    let time_config = &reading_data.time_config;
+    let json = match &args.completed_since {
+        // Relative dates count back from today's logical day, so they follow
+        // --timezone and --day-start-time like the completion dates do.
+        Some(since) => {
+            let since = parse_since_date(since, time_config.today_date())?;
+            serde_json::to_string_pretty(&build_completed_since(stats_data, since))?
+        }
+        None => serde_json::to_string_pretty(&build_dump(
+            stats_data,
+            time_config,
+            &reading_data.stats_config,
+        ))?,
+    };
+    println!("{}", json);
+    Ok(())
+}
+
+fn build_completed_since(stats_data: &StatisticsData, since: NaiveDate) -> CompletedSince<'_> {
+    let since = since.format("%Y-%m-%d").to_string();
+    let mut completions: Vec<CompletedBook<'_>> = stats_data
+        .books
+        .iter()
+        .flat_map(|book| {
+            book.completions
+                .iter()
+                .flat_map(|completions| &completions.entries)
+                .map(move |completion| CompletedBook {
+                    md5: &book.md5,
+                    title: &book.title,
+                    authors: &book.authors,
+                    completion,
+                })
+        })
+        // ISO dates compare correctly as strings.
+        .filter(|book| book.completion.end_date >= since)
+        .collect();
+    completions
+        .sort_by(|a, b| (&a.completion.end_date, a.title).cmp(&(&b.completion.end_date, b.title)));
+
+    CompletedSince { since, completions }
+}
+
+fn build_dump<'a>(
+    stats_data: &'a StatisticsData,
+    time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
+) -> StatsDump<'a> {
+    let books = stats_data
+        .books
+        .iter()
+        .map(|book| book_summary(book, stats_data, time_config, stats_config))
+        .collect();
+
+    StatsDump {
+        reading_stats: StatisticsCalculator::calculate_stats(stats_data, time_config, stats_config),
+        books,
+    }
+}
+
+fn book_summary<'a>(
+    book: &'a StatBook,
+    stats_data: &StatisticsData,
+    time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
+) -> BookSummary<'a> {
+    BookSummary {
+        md5: &book.md5,
+        title: &book.title,
+        authors: &book.authors,
+        pages: book.pages,
+        total_read_time: book.total_read_time,
+        total_read_pages: book.total_read_pages,
+        completions: book.completions.as_ref(),
+        sessions: book.calculate_session_stats(&stats_data.page_stats, time_config, stats_config),
+    }
+}
@@ -1,9 +1,14 @@
 use anyhow::{Context, Result};
+use axum::http::{HeaderName, HeaderValue};
+use chrono::NaiveDate;
 use clap::Parser;
 use ipnet::IpNet;
 use regex::Regex;
+use std::net::IpAddr;
 use std::path::PathBuf;
 
+use crate::source::remote::{RemoteSourceKind, SftpSource};
+
 /// KoShelf — a reading companion powered by KOReader metadata.
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
@@ -12,10 +17,126 @@ pub struct Cli {
     #[arg(short = 'c', long, env = "KOSHELF_CONFIG", global = true)]
     pub config: Option<PathBuf>,
 
+    /// Log output format: human-readable text, or one JSON object per line
+    /// for log aggregators.
+    #[arg(
+        long,
+        env = "KOSHELF_LOG_FORMAT",
+        global = true,
+        value_enum,
+        default_value_t = LogFormat::Text
+    )]
+    pub log_format: LogFormat,
+
     #[command(subcommand)]
     pub command: CliCommand,
 }
 
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// How books are addressed in site URLs.
+#[derive(clap::ValueEnum, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdScheme {
+    /// The item ID (partial MD5), e.g. `/books/4f2c…`.
+    #[default]
+    Hash,
+    /// A readable title-author slug, e.g. `/books/the-hobbit-tolkien`.
+    Slug,
+}
+
+/// Markup of book descriptions.
+#[derive(clap::ValueEnum, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DescriptionFormat {
+    /// Descriptions are HTML (or plain text) as the book has them.
+    #[default]
+    Html,
+    /// Descriptions are Markdown and are rendered to HTML.
+    Markdown,
+    /// HTML when the description contains tags, Markdown otherwise.
+    Auto,
+}
+
+/// How covers that are not in the 2:3 book shape are fitted to it.
+#[derive(clap::ValueEnum, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverFit {
+    /// Keep the cover's own aspect ratio.
+    #[default]
+    Original,
+    /// Pad the cover to 2:3 with a color taken from its edges.
+    Contain,
+    /// Crop the cover to 2:3 around its center.
+    Cover,
+}
+
+/// How dates are written on the site, independent of the UI language.
+#[derive(
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DateFormat {
+    /// Follow the language and region settings, e.g. `Mar 5`.
+    #[default]
+    Locale,
+    /// `2025-03-05`
+    Iso,
+    /// `03/05/2025`
+    Us,
+    /// `05.03.2025`
+    Eu,
+    /// `5 March 2025`, with the month name in the UI language.
+    Long,
+}
+
+/// Smallest unit durations such as reading times are shown in.
+#[derive(
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DurationGranularity {
+    /// Minutes, or seconds for durations under a minute.
+    #[default]
+    Auto,
+    /// Minutes only, e.g. `0m` for a 40 second page read.
+    Minutes,
+    /// Always down to the second, e.g. `1h 2m 5s`.
+    Seconds,
+}
+
+/// What happens to page reads longer than `--session-max`.
+#[derive(clap::ValueEnum, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionMaxMode {
+    /// Count them up to the cap.
+    #[default]
+    Clamp,
+    /// Leave them out like page reads under `--session-min`.
+    Drop,
+}
+
 #[derive(clap::Subcommand, Debug, Clone)]
 pub enum CliCommand {
     /// Start the web server (API + live data refresh).
@@ -24,6 +145,11 @@ pub enum CliCommand {
     /// Generate a static site.
     Export(ExportArgs),
 
+    /// Print the computed reading statistics as JSON and exit.
+    /// Uses the same statistics options as `serve` and `export`, so the numbers
+    /// match what the site shows.
+    StatsDump(StatsDumpArgs),
+
     /// Set the authentication password.
     #[command(long_about = "Set the authentication password.\n\n\
         No-ops if a password is already set (use --overwrite to replace it).\n\
@@ -62,6 +188,23 @@ pub enum CliCommand {
 
     /// Print the GitHub repository URL and exit.
     Github,
+
+    /// Print a shell completion script and exit.
+    #[command(long_about = "Print a shell completion script and exit.\n\n\
+        For example, for bash: koshelf completions bash > ~/.local/share/bash-completion/completions/koshelf")]
+    Completions {
+        /// Shell to generate the completion script for.
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+
+    /// Print the metadata parsed from a book or comic as JSON and exit.
+    /// Useful to debug a wrong title, author or cover without running a full scan.
+    Inspect {
+        /// Book or comic file (EPUB, FB2, MOBI, CBZ or CBR).
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        file: PathBuf,
+    },
 }
 
 /// Flags shared by `serve` and `export` subcommands.
@@ -73,6 +216,17 @@ pub struct CommonArgs {
     #[arg(short = 'i', visible_short_alias = 'b', long, env = "KOSHELF_LIBRARY_PATH", alias = "books-path", action = clap::ArgAction::Append)]
     pub library_path: Vec<PathBuf>,
 
+    /// Remote library directory or statistics database to mirror before each run,
+    /// as sftp://[user@]host[:port]/path. Paths ending in .sqlite3 are statistics databases.
+    /// Can be specified multiple times.
+    #[arg(long, env = "KOSHELF_SOURCE", value_name = "URL", action = clap::ArgAction::Append)]
+    pub source: Vec<String>,
+
+    /// How often `serve` and `export --watch` sync --source again, e.g. "5m" or "1h30m",
+    /// or "off" to sync only at startup.
+    #[arg(long, env = "KOSHELF_SOURCE_INTERVAL", default_value = "5m")]
+    pub source_interval: String,
+
     /// Path to KOReader's docsettings folder (for users who store metadata separately). Requires --library-path. Mutually exclusive with --hashdocsettings-path.
     #[arg(long, env = "KOSHELF_DOCSETTINGS_PATH")]
     pub docsettings_path: Option<PathBuf>,
@@ -94,6 +248,55 @@ pub struct CommonArgs {
     #[arg(long, env = "KOSHELF_INCLUDE_UNREAD", default_value = "false")]
     pub include_unread: bool,
 
+    /// Number of library files parsed concurrently. Lower it for slow network
+    /// storage. Defaults to 8.
+    #[arg(long, env = "KOSHELF_SCAN_JOBS", value_name = "N")]
+    pub scan_jobs: Option<usize>,
+
+    /// Reorder "Last, First" author names to "First Last" while scanning
+    #[arg(long, env = "KOSHELF_NORMALIZE_AUTHORS", default_value = "false")]
+    pub normalize_authors: bool,
+
+    /// TOML file mapping canonical author names to their spelling variants,
+    /// applied while scanning (optional)
+    #[arg(long, env = "KOSHELF_AUTHOR_ALIASES", value_name = "FILE")]
+    pub author_aliases: Option<PathBuf>,
+
+    /// How books are addressed in URLs: `hash` (item ID) or `slug`
+    /// (title and author, e.g. /books/the-hobbit-tolkien). Item ID links keep working
+    #[arg(long, env = "KOSHELF_ID_SCHEME", value_enum, default_value_t = IdScheme::Hash)]
+    pub id_scheme: IdScheme,
+
+    /// Markup of book descriptions: `html`, `markdown`, or `auto` (Markdown
+    /// unless the description contains HTML tags)
+    #[arg(long, env = "KOSHELF_DESCRIPTION_FORMAT", value_enum, default_value_t = DescriptionFormat::Html)]
+    pub description_format: DescriptionFormat,
+
+    /// How covers with other shapes are fitted to the 2:3 grid: `original`
+    /// (unchanged), `contain` (padded with an edge color), or `cover` (cropped)
+    #[arg(long, env = "KOSHELF_COVER_FIT", value_enum, default_value_t = CoverFit::Original)]
+    pub cover_fit: CoverFit,
+
+    /// Characters of a book description shown before a "read more" toggle.
+    /// 0 always shows the whole description
+    #[arg(
+        long,
+        env = "KOSHELF_DESCRIPTION_MAX_CHARS",
+        value_name = "N",
+        default_value_t = 800
+    )]
+    pub description_max_chars: usize,
+
+    /// Books a series needs before it gets a series page. 1 gives every
+    /// series a page
+    #[arg(
+        long,
+        env = "KOSHELF_SERIES_MIN_ITEMS",
+        value_name = "N",
+        default_value_t = 2
+    )]
+    pub series_min_items: usize,
+
     // ── Data ────────────────────────────────────────────────────
     /// Persistent runtime data directory for cache files (for example library.sqlite).
     #[arg(long, env = "KOSHELF_DATA_PATH", alias = "data-dir")]
@@ -104,16 +307,72 @@ pub struct CommonArgs {
     #[arg(short, long, env = "KOSHELF_TITLE", default_value = "KoShelf")]
     pub title: String,
 
+    /// Image to generate the favicon and app icons from (PNG, JPEG, WebP or GIF).
+    /// Non-square images are padded to a square. Defaults to the KoShelf icon.
+    #[arg(long, env = "KOSHELF_ICON", value_name = "PATH")]
+    pub icon: Option<PathBuf>,
+
+    /// Image shown in the navigation sidebar instead of the site title (SVG,
+    /// PNG, WebP, AVIF, GIF or JPEG). The title stays its alternative text.
+    #[arg(long, env = "KOSHELF_LOGO", value_name = "PATH")]
+    pub logo: Option<PathBuf>,
+
+    /// Stylesheet loaded after the built-in styles, e.g. to change the accent
+    /// colors or fonts through the theme's CSS custom properties.
+    #[arg(long, env = "KOSHELF_CUSTOM_CSS", value_name = "PATH")]
+    pub custom_css: Option<PathBuf>,
+
+    /// HTML inserted verbatim at the end of every page's <head>, e.g. web font
+    /// links or an analytics tag. Trusted input: it is not sanitized.
+    #[arg(long, env = "KOSHELF_HEAD_INCLUDE", value_name = "PATH")]
+    pub head_include: Option<PathBuf>,
+
+    /// HTML inserted verbatim before every page's </body>. Trusted input: it
+    /// is not sanitized.
+    #[arg(long, env = "KOSHELF_FOOTER_INCLUDE", value_name = "PATH")]
+    pub footer_include: Option<PathBuf>,
+
     /// Default server language for UI translations.
     /// Frontend language/region settings can override this per browser.
     /// Use full locale (e.g., en_US, de_DE) for correct date formatting. Use `list-languages` to see available options.
     #[arg(long, short = 'l', env = "KOSHELF_LANGUAGE", default_value = "en_US")]
     pub language: String,
 
+    /// Date format overriding the one derived from the language: `locale`,
+    /// `iso` (2025-03-05), `us` (03/05/2025), `eu` (05.03.2025) or `long`
+    /// (5 March 2025). Month and weekday names stay in the UI language
+    #[arg(long, env = "KOSHELF_DATE_FORMAT", value_enum, default_value_t = DateFormat::Locale)]
+    pub date_format: DateFormat,
+
+    /// Smallest unit of reading times and other durations: `auto` (minutes,
+    /// seconds under a minute), `minutes` or `seconds`
+    #[arg(long, env = "KOSHELF_DURATION_GRANULARITY", value_enum, default_value_t = DurationGranularity::Auto)]
+    pub duration_granularity: DurationGranularity,
+
+    /// Show at most this many units per duration, e.g. 2 for `1d 3h` instead
+    /// of `1d 3h 12m` (0 = no limit). Dropped units are cut off, never rounded up
+    #[arg(long, env = "KOSHELF_DURATION_MAX_UNITS", default_value_t = 0)]
+    pub duration_max_units: usize,
+
     /// Timezone to interpret timestamps (IANA name, e.g., "Australia/Sydney"). Defaults to system local timezone.
     #[arg(long, env = "KOSHELF_TIMEZONE")]
     pub timezone: Option<String>,
 
+    /// Replace titles, authors, covers and annotation text with placeholders
+    /// for screenshots. Reading statistics are kept as they are.
+    #[arg(long, env = "KOSHELF_DEMO", default_value = "false")]
+    pub demo: bool,
+
+    /// Cards shown per library section before more are loaded on scroll.
+    /// 0 shows every item at once.
+    #[arg(
+        long,
+        env = "KOSHELF_PAGE_SIZE",
+        value_name = "N",
+        default_value_t = 100
+    )]
+    pub page_size: usize,
+
     // ── Statistics tuning ───────────────────────────────────────
     /// Maximum value for heatmap color intensity scaling (e.g., "auto", "1h", "1h30m", "45min"). Values above this will still be shown but use the highest color intensity. Default is "2h".
     #[arg(long, env = "KOSHELF_HEATMAP_SCALE_MAX", default_value = "2h")]
@@ -123,6 +382,19 @@ pub struct CommonArgs {
     #[arg(long, env = "KOSHELF_DAY_START_TIME", value_name = "HH:MM")]
     pub day_start_time: Option<String>,
 
+    /// First day of the week for weekly statistics, the calendar, and the heatmap (monday or sunday). Defaults to monday.
+    #[arg(long, env = "KOSHELF_WEEK_START", value_name = "monday|sunday")]
+    pub week_start: Option<String>,
+
+    /// What decides the color of calendar events: book (each book its own
+    /// color), content-type, rating or series. Defaults to book.
+    #[arg(
+        long,
+        env = "KOSHELF_CALENDAR_COLOR_BY",
+        value_name = "book|content-type|rating|series"
+    )]
+    pub calendar_color_by: Option<String>,
+
     /// Minimum pages read per day to be counted in statistics (optional)
     #[arg(long, env = "KOSHELF_MIN_PAGES_PER_DAY")]
     pub min_pages_per_day: Option<u32>,
@@ -132,12 +404,100 @@ pub struct CommonArgs {
     #[arg(long, env = "KOSHELF_MIN_TIME_PER_DAY", default_value = "30s")]
     pub min_time_per_day: Option<String>,
 
+    /// Drop individual page reads shorter than this many seconds (optional).
+    /// Applied before the per-day minimums.
+    #[arg(long, env = "KOSHELF_SESSION_MIN", value_name = "SECONDS")]
+    pub session_min: Option<u32>,
+
+    /// Clamp individual page reads longer than this many seconds to the cap
+    /// (optional). Applied before the per-day minimums.
+    #[arg(long, env = "KOSHELF_SESSION_MAX", value_name = "SECONDS")]
+    pub session_max: Option<u32>,
+
+    /// What to do with page reads longer than `--session-max`: `clamp` them
+    /// to the cap or `drop` them
+    #[arg(long, env = "KOSHELF_SESSION_MAX_MODE", value_enum, default_value_t = SessionMaxMode::Clamp)]
+    pub session_max_mode: SessionMaxMode,
+
+    /// Fixed Unix timestamp to use as the current time, so identical inputs
+    /// produce identical output (e.g. "generated at" and today's streak).
+    #[arg(long, env = "SOURCE_DATE_EPOCH", value_name = "UNIX_SECONDS")]
+    pub source_date: Option<i64>,
+
+    /// Longest pause between page reads (in minutes) that still counts as the
+    /// same reading session. Defaults to 5.
+    #[arg(long, env = "KOSHELF_SESSION_GAP", value_name = "MINUTES")]
+    pub session_gap: Option<u32>,
+
+    /// Number of days in a row that may be skipped without breaking a reading
+    /// streak. Defaults to 0.
+    #[arg(long, env = "KOSHELF_STREAK_GRACE_DAYS", value_name = "DAYS")]
+    pub streak_grace_days: Option<u16>,
+
+    /// Only include reading from this logical day onwards (YYYY-MM-DD).
+    #[arg(long, env = "KOSHELF_STATS_FROM", value_name = "YYYY-MM-DD")]
+    pub stats_from: Option<String>,
+
+    /// Only include reading up to and including this logical day (YYYY-MM-DD).
+    #[arg(long, env = "KOSHELF_STATS_TO", value_name = "YYYY-MM-DD")]
+    pub stats_to: Option<String>,
+
+    /// Percentage of a book's pages that must be visited for a read to count as
+    /// completed (1-100). Defaults to 78.
+    #[arg(long, env = "KOSHELF_COMPLETION_MIN_PAGES", value_name = "PERCENT")]
+    pub completion_min_pages: Option<u8>,
+
+    /// Percentage of a book's start that a read must include, and that reading
+    /// must have moved past before jumping back counts as a reread (1-100).
+    /// Defaults to 20.
+    #[arg(long, env = "KOSHELF_COMPLETION_MIN_EARLY", value_name = "PERCENT")]
+    pub completion_min_early: Option<u8>,
+
+    /// Percentage of a book's end that a read must include (0-100). Defaults to 2.
+    #[arg(long, env = "KOSHELF_COMPLETION_MIN_LATE", value_name = "PERCENT")]
+    pub completion_min_late: Option<u8>,
+
+    /// Jumping back to within this percentage of a book's start after reading
+    /// past its beginning starts a new read (0-100). Defaults to 5.
+    #[arg(
+        long,
+        env = "KOSHELF_COMPLETION_RESTART_WINDOW",
+        value_name = "PERCENT"
+    )]
+    pub completion_restart_window: Option<u8>,
+
+    /// TOML file with manual completion date ranges per book MD5, used instead
+    /// of automatic completion detection for the listed books (optional)
+    #[arg(long, env = "KOSHELF_COMPLETION_OVERRIDES", value_name = "FILE")]
+    pub completion_overrides: Option<PathBuf>,
+
     /// Include statistics for all books in the database, not just those in --library-path.
     /// By default, when --library-path is provided, statistics are filtered to only include
     /// books present in that directory. Use this flag to include all statistics.
     #[arg(long, env = "KOSHELF_INCLUDE_ALL_STATS", default_value = "false")]
     pub include_all_stats: bool,
 
+    /// Keep statistics for books whose files were removed from --library-path.
+    /// They still count towards totals and recaps, marked as no longer in the library.
+    #[arg(long, env = "KOSHELF_KEEP_GHOST_STATS", default_value = "false")]
+    pub keep_ghost_stats: bool,
+
+    /// Link statistics recorded under an MD5 that matches no library book to
+    /// the book with the same title and author, e.g. after files were
+    /// recompressed. Each link is logged
+    #[arg(long, env = "KOSHELF_MATCH_STATS_BY_TITLE", default_value = "false")]
+    pub match_stats_by_title: bool,
+
+    /// List every month of the recap timeline, including months in which
+    /// nothing was finished, together with the time read in them.
+    #[arg(long, env = "KOSHELF_RECAP_SHOW_EMPTY_MONTHS", default_value = "false")]
+    pub recap_show_empty_months: bool,
+
+    /// Leave a book out of statistics, by KOReader MD5 or a case-insensitive
+    /// title substring. Can be specified multiple times.
+    #[arg(long, env = "KOSHELF_STATS_EXCLUDE", value_name = "MD5|TITLE", action = clap::ArgAction::Append)]
+    pub stats_exclude: Vec<String>,
+
     /// Ignore KOReader stable page metadata for page totals and page-based stats scaling.
     /// By default, stable page metadata is used when available.
     #[arg(
@@ -146,6 +506,25 @@ pub struct CommonArgs {
         default_value = "false"
     )]
     pub ignore_stable_page_metadata: bool,
+
+    /// Shell command run after each watch-mode rebuild that changed data, e.g.
+    /// to purge a CDN cache. It gets a JSON summary on stdin and the counts in
+    /// `KOSHELF_REBUILD_*` environment variables. Failures are only logged.
+    #[arg(long, env = "KOSHELF_ON_REBUILD", value_name = "COMMAND")]
+    pub on_rebuild: Option<String>,
+
+    /// Additional file or directory to watch for changes, e.g. the
+    /// `--custom-css` or `--head-include` file. A change re-applies the logo,
+    /// custom CSS and HTML includes. Can be specified multiple times; must not
+    /// overlap the output or data directory.
+    #[arg(long, env = "KOSHELF_WATCH_PATH", value_name = "PATH", action = clap::ArgAction::Append)]
+    pub watch_path: Vec<PathBuf>,
+
+    /// Check the options, input paths, statistics and Kobo databases, language
+    /// and time settings, print a summary and exit without generating anything.
+    /// Exits non-zero when a check fails.
+    #[arg(long = "validate", default_value = "false")]
+    pub validate_only: bool,
 }
 
 /// Arguments for the `serve` subcommand.
@@ -154,15 +533,46 @@ pub struct ServeArgs {
     #[command(flatten)]
     pub common: CommonArgs,
 
+    /// Address for the web server to listen on (default: all interfaces).
+    /// Use 127.0.0.1 to accept local connections only.
+    #[arg(
+        long,
+        env = "KOSHELF_BIND",
+        default_value = "0.0.0.0",
+        value_name = "ADDRESS"
+    )]
+    pub bind: IpAddr,
+
     /// Port for web server (default: 3000)
     #[arg(short, long, env = "KOSHELF_PORT", default_value = "3000")]
     pub port: u16,
 
+    /// PEM certificate chain for serving HTTPS directly. Requires --tls-key.
+    #[arg(long, env = "KOSHELF_TLS_CERT", value_name = "PATH")]
+    pub tls_cert: Option<PathBuf>,
+
+    /// PEM private key for --tls-cert.
+    #[arg(long, env = "KOSHELF_TLS_KEY", value_name = "PATH")]
+    pub tls_key: Option<PathBuf>,
+
     /// Enable password authentication.
     /// On first run, generates a random password and prints it to stderr.
     #[arg(long, env = "KOSHELF_ENABLE_AUTH", default_value = "false")]
     pub enable_auth: bool,
 
+    /// Username for HTTP Basic authentication on every route.
+    /// Requires --auth-password. Independent of --enable-auth.
+    #[arg(long, env = "KOSHELF_AUTH_USER", value_name = "USERNAME")]
+    pub auth_user: Option<String>,
+
+    /// Password for HTTP Basic authentication.
+    #[arg(long, env = "KOSHELF_AUTH_PASSWORD", value_name = "PASSWORD")]
+    pub auth_password: Option<String>,
+
+    /// Bearer token accepted on every route (`Authorization: Bearer <token>`).
+    #[arg(long, env = "KOSHELF_AUTH_TOKEN", value_name = "TOKEN")]
+    pub auth_token: Option<String>,
+
     /// Enable metadata writeback (allows editing KoReader metadata via API).
     #[arg(long, env = "KOSHELF_ENABLE_WRITEBACK", default_value = "false")]
     pub enable_writeback: bool,
@@ -171,6 +581,31 @@ pub struct ServeArgs {
     /// Repeat the flag or pass comma-separated values.
     #[arg(long, env = "KOSHELF_TRUSTED_PROXIES", value_delimiter = ',', action = clap::ArgAction::Append)]
     pub trusted_proxies: Vec<String>,
+
+    /// Extra response header as "Name: Value", added to everything the server
+    /// serves and overriding the defaults (including the Content-Security-Policy).
+    /// An empty value removes the header. Repeatable; separate entries in the
+    /// environment variable with newlines.
+    #[arg(long = "header", env = "KOSHELF_HEADERS", value_name = "NAME: VALUE", value_delimiter = '\n', action = clap::ArgAction::Append)]
+    pub headers: Vec<String>,
+
+    /// Username for the KOReader progress sync (kosync) endpoints.
+    /// Requires --kosync-password; both enable the endpoints.
+    #[arg(long, env = "KOSHELF_KOSYNC_USER", value_name = "USERNAME")]
+    pub kosync_user: Option<String>,
+
+    /// Password for the KOReader progress sync (kosync) endpoints.
+    #[arg(long, env = "KOSHELF_KOSYNC_PASSWORD", value_name = "PASSWORD")]
+    pub kosync_password: Option<String>,
+
+    /// Expose library and reading statistics for Prometheus at /metrics.
+    /// The endpoint is not covered by --enable-auth.
+    #[arg(long, env = "KOSHELF_ENABLE_METRICS", default_value = "false")]
+    pub enable_metrics: bool,
+
+    /// Open the web app in the default browser once the server is listening.
+    #[arg(long, env = "KOSHELF_OPEN", default_value = "false")]
+    pub open: bool,
 }
 
 /// Arguments for the `export` subcommand.
@@ -184,12 +619,35 @@ pub struct ExportArgs {
     pub output: Option<PathBuf>,
 
     /// Include item files (epub, cbz, etc.) in the exported output.
-    #[arg(long, env = "KOSHELF_INCLUDE_FILES", default_value = "false")]
+    #[arg(
+        long,
+        visible_alias = "include-originals",
+        env = "KOSHELF_INCLUDE_FILES",
+        default_value = "false"
+    )]
     pub include_files: bool,
 
+    /// Write raw reading statistics as CSV files to `export/` in the output.
+    #[arg(long, env = "KOSHELF_EXPORT_CSV", default_value = "false")]
+    pub export_csv: bool,
+
+    /// Write every highlight and note in the library to
+    /// `export/all-highlights.json` and `export/all-highlights.md`.
+    #[arg(long, env = "KOSHELF_EXPORT_ALL_ANNOTATIONS", default_value = "false")]
+    pub export_all_annotations: bool,
+
     /// Re-export on library changes.
     #[arg(short, long, env = "KOSHELF_WATCH", default_value = "false")]
     pub watch: bool,
+
+    /// Report what the export would write, change, and leave stale without
+    /// touching the output directory.
+    #[arg(long, default_value = "false")]
+    pub dry_run: bool,
+
+    /// Write per-phase build timings as JSON to this path.
+    #[arg(long, env = "KOSHELF_BUILD_REPORT")]
+    pub build_report: Option<PathBuf>,
 }
 
 /// Parse time format strings like "1h", "1h30m", "45min", "30s" into seconds.
@@ -227,6 +685,40 @@ pub fn parse_time_to_seconds(time_str: &str) -> Result<Option<u32>> {
     Ok(Some(total_seconds))
 }
 
+/// Parse an optional `YYYY-MM-DD` date argument, naming the flag on error.
+pub fn parse_stats_date(value: Option<&str>, flag: &str) -> Result<Option<NaiveDate>> {
+    value
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .with_context(|| format!("Invalid {} date '{}' (expected YYYY-MM-DD)", flag, value))
+        })
+        .transpose()
+}
+
+/// Resolve a `--completed-since` value against `today`, the current logical
+/// day: an absolute `YYYY-MM-DD` date, `today`, `yesterday` or `<N>d` days ago.
+pub fn parse_since_date(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let normalized = value.trim().to_ascii_lowercase();
+    let days_ago = match normalized.as_str() {
+        "today" => Some(0),
+        "yesterday" => Some(1),
+        other => other
+            .strip_suffix('d')
+            .and_then(|days| days.parse::<u64>().ok()),
+    };
+    match days_ago {
+        Some(days) => today
+            .checked_sub_days(chrono::Days::new(days))
+            .with_context(|| format!("--completed-since '{}' is too far back", value)),
+        None => NaiveDate::parse_from_str(&normalized, "%Y-%m-%d").with_context(|| {
+            format!(
+                "Invalid --completed-since date '{}' (expected YYYY-MM-DD, today, yesterday or days ago like 7d)",
+                value
+            )
+        }),
+    }
+}
+
 pub fn parse_trusted_proxy_nets(entries: &[String]) -> Result<Vec<IpNet>> {
     entries
         .iter()
@@ -241,10 +733,41 @@ pub fn parse_trusted_proxy_nets(entries: &[String]) -> Result<Vec<IpNet>> {
         .collect()
 }
 
+/// Parse `Name: Value` entries from `--header` into response headers.
+pub fn parse_custom_headers(entries: &[String]) -> Result<Vec<(HeaderName, HeaderValue)>> {
+    entries
+        .iter()
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (name, value) = entry.split_once(':').with_context(|| {
+                format!(
+                    "Invalid header '{}'. Expected \"Name: Value\", for example \"Permissions-Policy: camera=()\"",
+                    entry
+                )
+            })?;
+            let name = HeaderName::from_bytes(name.trim().as_bytes())
+                .with_context(|| format!("Invalid header name in '{}'", entry))?;
+            let value = HeaderValue::from_str(value.trim())
+                .with_context(|| format!("Invalid header value in '{}'", entry))?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
 impl CommonArgs {
     pub fn validate(&self) -> Result<()> {
-        if self.library_path.is_empty() && self.statistics_db.is_empty() {
-            anyhow::bail!("Either --library-path or --statistics-db (or both) must be provided");
+        let remote_sources = self
+            .source
+            .iter()
+            .map(|url| SftpSource::parse(url))
+            .collect::<Result<Vec<_>>>()?;
+        let has_library = !self.library_path.is_empty()
+            || remote_sources
+                .iter()
+                .any(|source| source.kind() == RemoteSourceKind::Library);
+
+        if !has_library && self.statistics_db.is_empty() && remote_sources.is_empty() {
+            anyhow::bail!("Either --library-path, --statistics-db, or --source must be provided");
         }
 
         for library_path in &self.library_path {
@@ -256,25 +779,29 @@ impl CommonArgs {
             }
         }
 
-        if self.include_unread && self.library_path.is_empty() {
+        if self.include_unread && !has_library {
             anyhow::bail!("--include-unread can only be used when --library-path is provided");
         }
 
+        if self.scan_jobs == Some(0) {
+            anyhow::bail!("--scan-jobs must be at least 1");
+        }
+
         if self.docsettings_path.is_some() && self.hashdocsettings_path.is_some() {
             anyhow::bail!(
                 "--docsettings-path and --hashdocsettings-path are mutually exclusive. Please use only one."
             );
         }
 
-        if self.docsettings_path.is_some() && self.library_path.is_empty() {
+        if self.docsettings_path.is_some() && !has_library {
             anyhow::bail!("--docsettings-path requires --library-path to be provided");
         }
 
-        if self.hashdocsettings_path.is_some() && self.library_path.is_empty() {
+        if self.hashdocsettings_path.is_some() && !has_library {
             anyhow::bail!("--hashdocsettings-path requires --library-path to be provided");
         }
 
-        if self.kobo_db.is_some() && self.library_path.is_empty() {
+        if self.kobo_db.is_some() && !has_library {
             anyhow::bail!("--kobo-db requires --library-path to be provided");
         }
 
@@ -331,6 +858,9 @@ impl CommonArgs {
             anyhow::bail!("Data directory path is not a directory: {:?}", data_path);
         }
 
+        parse_time_to_seconds(&self.source_interval)
+            .with_context(|| format!("Invalid source-interval format: {}", self.source_interval))?;
+
         parse_time_to_seconds(&self.heatmap_scale_max).with_context(|| {
             format!(
                 "Invalid heatmap-scale-max format: {}",
@@ -343,6 +873,103 @@ impl CommonArgs {
                 .with_context(|| format!("Invalid min-time-per-day format: {}", min_time_str))?;
         }
 
+        if let Some(percent) = self.completion_min_pages
+            && !(1..=100).contains(&percent)
+        {
+            anyhow::bail!("--completion-min-pages must be between 1 and 100");
+        }
+
+        if let Some(percent) = self.completion_min_early
+            && !(1..=100).contains(&percent)
+        {
+            anyhow::bail!("--completion-min-early must be between 1 and 100");
+        }
+
+        if let Some(percent) = self.completion_min_late
+            && percent > 100
+        {
+            anyhow::bail!("--completion-min-late must be between 0 and 100");
+        }
+
+        if let Some(percent) = self.completion_restart_window
+            && percent > 100
+        {
+            anyhow::bail!("--completion-restart-window must be between 0 and 100");
+        }
+
+        if let Some(ref aliases_path) = self.author_aliases
+            && !aliases_path.is_file()
+        {
+            anyhow::bail!("Author aliases file does not exist: {:?}", aliases_path);
+        }
+
+        if let Some(ref icon_path) = self.icon
+            && !icon_path.is_file()
+        {
+            anyhow::bail!("Icon file does not exist: {:?}", icon_path);
+        }
+
+        if let Some(ref logo_path) = self.logo
+            && !logo_path.is_file()
+        {
+            anyhow::bail!("Logo file does not exist: {:?}", logo_path);
+        }
+
+        if let Some(ref css_path) = self.custom_css
+            && !css_path.is_file()
+        {
+            anyhow::bail!("Custom CSS file does not exist: {:?}", css_path);
+        }
+
+        if let Some(ref head_path) = self.head_include
+            && !head_path.is_file()
+        {
+            anyhow::bail!("Head include file does not exist: {:?}", head_path);
+        }
+
+        if let Some(ref footer_path) = self.footer_include
+            && !footer_path.is_file()
+        {
+            anyhow::bail!("Footer include file does not exist: {:?}", footer_path);
+        }
+
+        for watch_path in &self.watch_path {
+            if !watch_path.exists() {
+                anyhow::bail!("Watch path does not exist: {:?}", watch_path);
+            }
+        }
+
+        if let Some(ref overrides_path) = self.completion_overrides
+            && !overrides_path.is_file()
+        {
+            anyhow::bail!(
+                "Completion overrides file does not exist: {:?}",
+                overrides_path
+            );
+        }
+
+        if let (Some(min), Some(max)) = (self.session_min, self.session_max)
+            && min > max
+        {
+            anyhow::bail!(
+                "--session-min ({}) must not be greater than --session-max ({})",
+                min,
+                max
+            );
+        }
+
+        let stats_from = parse_stats_date(self.stats_from.as_deref(), "--stats-from")?;
+        let stats_to = parse_stats_date(self.stats_to.as_deref(), "--stats-to")?;
+        if let (Some(from), Some(to)) = (stats_from, stats_to)
+            && from > to
+        {
+            anyhow::bail!(
+                "--stats-from ({}) must not be after --stats-to ({})",
+                from,
+                to
+            );
+        }
+
         Ok(())
     }
 }
@@ -359,6 +986,85 @@ impl ServeArgs {
         }
 
         parse_trusted_proxy_nets(&self.trusted_proxies)?;
+        parse_custom_headers(&self.headers)?;
+
+        match (&self.kosync_user, &self.kosync_password) {
+            (Some(user), Some(password)) => {
+                if user.trim().is_empty() || password.is_empty() {
+                    anyhow::bail!("--kosync-user and --kosync-password must not be empty");
+                }
+            }
+            (None, None) => {}
+            _ => anyhow::bail!("--kosync-user and --kosync-password must be set together"),
+        }
+
+        match (&self.auth_user, &self.auth_password) {
+            (Some(user), Some(password)) => {
+                if user.is_empty() || password.is_empty() {
+                    anyhow::bail!("--auth-user and --auth-password must not be empty");
+                }
+                if user.contains(':') {
+                    anyhow::bail!("--auth-user must not contain ':'");
+                }
+            }
+            (None, None) => {}
+            _ => anyhow::bail!("--auth-user and --auth-password must be set together"),
+        }
+
+        if self
+            .auth_token
+            .as_deref()
+            .is_some_and(|token| token.trim().is_empty())
+        {
+            anyhow::bail!("--auth-token must not be empty");
+        }
+
+        match (&self.tls_cert, &self.tls_key) {
+            (Some(cert), Some(key)) => {
+                if !cert.is_file() {
+                    anyhow::bail!("TLS certificate does not exist: {:?}", cert);
+                }
+                if !key.is_file() {
+                    anyhow::bail!("TLS key does not exist: {:?}", key);
+                }
+            }
+            (None, None) => {}
+            (Some(_), None) => anyhow::bail!("--tls-cert requires --tls-key"),
+            (None, Some(_)) => anyhow::bail!("--tls-key requires --tls-cert"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments for the `stats-dump` subcommand.
+#[derive(clap::Args, Debug, Clone)]
+pub struct StatsDumpArgs {
+    #[command(flatten)]
+    pub common: CommonArgs,
+
+    /// Only print the books completed on or after this logical day, e.g. for
+    /// a digest: YYYY-MM-DD, `today`, `yesterday` or days ago like `7d`.
+    #[arg(long, env = "KOSHELF_COMPLETED_SINCE", value_name = "DATE")]
+    pub completed_since: Option<String>,
+}
+
+impl StatsDumpArgs {
+    pub fn validate(&self) -> Result<()> {
+        self.common.validate()?;
+
+        let has_statistics_source = self
+            .common
+            .source
+            .iter()
+            .filter_map(|url| SftpSource::parse(url).ok())
+            .any(|source| source.kind() == RemoteSourceKind::StatisticsDb);
+        if self.common.statistics_db.is_empty() && !has_statistics_source {
+            anyhow::bail!("stats-dump requires --statistics-db or a statistics --source");
+        }
+        if let Some(since) = &self.completed_since {
+            parse_since_date(since, chrono::Utc::now().date_naive())?;
+        }
 
         Ok(())
     }
@@ -375,13 +1081,21 @@ impl ExportArgs {
             );
         }
 
+        if self.dry_run && self.watch {
+            anyhow::bail!("--dry-run cannot be combined with --watch");
+        }
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, CliCommand, parse_time_to_seconds};
+    use super::{
+        Cli, CliCommand, DateFormat, LogFormat, parse_custom_headers, parse_since_date,
+        parse_time_to_seconds,
+    };
+    use chrono::NaiveDate;
     use clap::{CommandFactory, FromArgMatches};
     use std::path::PathBuf;
 
@@ -390,6 +1104,60 @@ mod tests {
         assert_eq!(parse_time_to_seconds("off").unwrap(), None);
     }
 
+    #[test]
+    fn custom_headers_parse_name_and_value() {
+        let headers = parse_custom_headers(&[
+            "Permissions-Policy: camera=(), microphone=()".to_string(),
+            "Content-Security-Policy:".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(headers[0].0, "permissions-policy");
+        assert_eq!(headers[0].1, "camera=(), microphone=()");
+        assert_eq!(headers[1].0, "content-security-policy");
+        assert!(headers[1].1.is_empty());
+
+        assert!(parse_custom_headers(&["X-Missing-Colon".to_string()]).is_err());
+        assert!(parse_custom_headers(&["Bad Name: value".to_string()]).is_err());
+    }
+
+    #[test]
+    fn log_format_is_global_and_defaults_to_text() {
+        let parse = |args: &[&str]| {
+            let matches = Cli::command().try_get_matches_from(args)?;
+            Ok::<_, clap::Error>(Cli::from_arg_matches(&matches)?.log_format)
+        };
+
+        assert_eq!(parse(&["koshelf", "serve"]).unwrap(), LogFormat::Text);
+        assert_eq!(
+            parse(&["koshelf", "serve", "--log-format", "json"]).unwrap(),
+            LogFormat::Json
+        );
+        assert_eq!(
+            parse(&["koshelf", "--log-format", "json", "export"]).unwrap(),
+            LogFormat::Json
+        );
+        assert!(parse(&["koshelf", "--log-format", "xml", "serve"]).is_err());
+    }
+
+    #[test]
+    fn date_format_accepts_presets_only() {
+        let parse = |args: &[&str]| {
+            let matches = Cli::command().try_get_matches_from(args)?;
+            let CliCommand::Export(args) = Cli::from_arg_matches(&matches)?.command else {
+                panic!("expected export command");
+            };
+            Ok::<_, clap::Error>(args.common.date_format)
+        };
+
+        assert_eq!(parse(&["koshelf", "export"]).unwrap(), DateFormat::Locale);
+        assert_eq!(
+            parse(&["koshelf", "export", "--date-format", "iso"]).unwrap(),
+            DateFormat::Iso
+        );
+        assert!(parse(&["koshelf", "export", "--date-format", "%d/%m/%Y"]).is_err());
+    }
+
     #[test]
     fn statistics_db_flag_is_repeatable() {
         let matches = Cli::command()
@@ -441,6 +1209,85 @@ mod tests {
         assert!(err.to_string().contains("supplied twice"), "{err}");
     }
 
+    #[test]
+    fn validate_rejects_session_min_above_session_max() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let db_path = dir.path().join("statistics.sqlite3");
+        std::fs::write(&db_path, b"db").expect("db file");
+
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "serve",
+                "-s",
+                db_path.to_str().unwrap(),
+                "--session-min",
+                "600",
+                "--session-max",
+                "60",
+            ])
+            .expect("CLI args should parse");
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Serve(args) = cli.command else {
+            panic!("expected serve command");
+        };
+
+        let err = args
+            .common
+            .validate()
+            .expect_err("inverted limits should fail");
+        assert!(err.to_string().contains("--session-min"), "{err}");
+    }
+
+    #[test]
+    fn stats_dump_requires_a_statistics_db() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let library = dir.path().to_str().unwrap();
+        let db_path = dir.path().join("statistics.sqlite3");
+        std::fs::write(&db_path, b"db").expect("db file");
+
+        let parse = |args: &[&str]| {
+            let matches = Cli::command()
+                .try_get_matches_from(args)
+                .expect("CLI args should parse");
+            match Cli::from_arg_matches(&matches).expect("CLI should convert from matches") {
+                Cli {
+                    command: CliCommand::StatsDump(args),
+                    ..
+                } => args,
+                _ => panic!("expected stats-dump command"),
+            }
+        };
+
+        let err = parse(&["koshelf", "stats-dump", "-i", library])
+            .validate()
+            .expect_err("library alone should fail");
+        assert!(err.to_string().contains("--statistics-db"), "{err}");
+
+        parse(&["koshelf", "stats-dump", "-s", db_path.to_str().unwrap()])
+            .validate()
+            .expect("statistics db should be enough");
+    }
+
+    #[test]
+    fn since_dates_resolve_against_the_logical_day() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(parse_since_date("today", today).unwrap(), today);
+        assert_eq!(
+            parse_since_date("Yesterday", today).unwrap(),
+            date(2024, 2, 29)
+        );
+        assert_eq!(parse_since_date("7d", today).unwrap(), date(2024, 2, 23));
+        assert_eq!(
+            parse_since_date("2023-12-24", today).unwrap(),
+            date(2023, 12, 24)
+        );
+        let err = parse_since_date("last week", today).unwrap_err();
+        assert!(err.to_string().contains("--completed-since"), "{err}");
+    }
+
     #[test]
     fn validate_rejects_missing_statistics_db_among_several() {
         let dir = tempfile::tempdir().expect("temp dir");
@@ -546,6 +1393,93 @@ mod tests {
         assert_eq!(args.port, 8080);
     }
 
+    #[test]
+    fn serve_defaults_to_plain_http_on_all_interfaces() {
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "serve",
+                "--library-path",
+                "/lib",
+                "--data-path",
+                "/data",
+            ])
+            .expect("CLI args should parse");
+
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Serve(args) = cli.command else {
+            panic!("expected serve command")
+        };
+
+        assert_eq!(args.bind, "0.0.0.0".parse::<std::net::IpAddr>().unwrap());
+        assert!(args.tls_cert.is_none());
+        assert!(args.tls_key.is_none());
+    }
+
+    #[test]
+    fn validate_rejects_tls_cert_without_key() {
+        let library = tempfile::tempdir().expect("library temp dir");
+        let cert = tempfile::NamedTempFile::new().expect("cert temp file");
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "serve",
+                "--library-path",
+                library.path().to_str().unwrap(),
+                "--data-path",
+                "/data",
+                "--bind",
+                "127.0.0.1",
+                "--tls-cert",
+                cert.path().to_str().unwrap(),
+            ])
+            .expect("CLI args should parse");
+
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Serve(args) = cli.command else {
+            panic!("expected serve command")
+        };
+
+        assert_eq!(args.bind, "127.0.0.1".parse::<std::net::IpAddr>().unwrap());
+        let error = args.validate().expect_err("cert without key should fail");
+        assert!(
+            error.to_string().contains("--tls-cert requires --tls-key"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_auth_user_without_password() {
+        let library = tempfile::tempdir().expect("library temp dir");
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "serve",
+                "--library-path",
+                library.path().to_str().unwrap(),
+                "--data-path",
+                "/data",
+                "--auth-user",
+                "reader",
+            ])
+            .expect("CLI args should parse");
+
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Serve(args) = cli.command else {
+            panic!("expected serve command")
+        };
+
+        let error = args
+            .validate()
+            .expect_err("user without password should fail");
+        assert!(
+            error
+                .to_string()
+                .contains("--auth-user and --auth-password must be set together"),
+            "unexpected error: {error}"
+        );
+    }
+
     #[test]
     fn serve_parses_kobo_db() {
         let matches = Cli::command()
@@ -632,6 +1566,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_rejects_zero_scan_jobs() {
+        let library = tempfile::tempdir().expect("library temp dir");
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "export",
+                "--library-path",
+                library.path().to_str().unwrap(),
+                "--scan-jobs",
+                "0",
+                "/out",
+            ])
+            .expect("CLI args should parse");
+
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Export(args) = cli.command else {
+            panic!("expected export command")
+        };
+
+        let error = args.validate().expect_err("zero scan jobs should fail");
+        assert!(
+            error.to_string().contains("--scan-jobs must be at least 1"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn export_dry_run_rejects_watch() {
+        let library = tempfile::tempdir().expect("library temp dir");
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "koshelf",
+                "export",
+                "--library-path",
+                library.path().to_str().unwrap(),
+                "--dry-run",
+                "--watch",
+                "/out",
+            ])
+            .expect("CLI args should parse");
+
+        let cli = Cli::from_arg_matches(&matches).expect("CLI should convert from matches");
+        let CliCommand::Export(args) = cli.command else {
+            panic!("expected export command")
+        };
+
+        let error = args.validate().expect_err("dry run with watch should fail");
+        assert!(
+            error.to_string().contains("--dry-run cannot be combined"),
+            "unexpected error: {error}"
+        );
+    }
+
     #[test]
     fn export_parses_positional_output() {
         let matches = Cli::command()
@@ -1,7 +1,11 @@
 //! TOML configuration file support for KoShelf.
 
+use crate::app::config::{
+    CoverFit, DateFormat, DescriptionFormat, DurationGranularity, IdScheme, SessionMaxMode,
+};
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 
 #[derive(Deserialize, Default, Debug)]
@@ -18,30 +22,62 @@ pub struct FileConfig {
 #[serde(deny_unknown_fields)]
 pub struct LibrarySection {
     pub paths: Option<Vec<PathBuf>>,
+    pub sources: Option<Vec<String>>,
+    pub source_interval: Option<String>,
     pub docsettings_path: Option<PathBuf>,
     pub hashdocsettings_path: Option<PathBuf>,
     #[serde(default, deserialize_with = "one_or_many_pathbuf")]
     pub statistics_db: Option<Vec<PathBuf>>,
     pub kobo_db: Option<PathBuf>,
     pub include_unread: Option<bool>,
+    pub scan_jobs: Option<usize>,
+    pub normalize_authors: Option<bool>,
+    pub author_aliases: Option<PathBuf>,
+    pub id_scheme: Option<IdScheme>,
+    pub description_format: Option<DescriptionFormat>,
+    pub description_max_chars: Option<usize>,
+    pub cover_fit: Option<CoverFit>,
+    pub series_min_items: Option<usize>,
 }
 
 #[derive(Deserialize, Default, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct KoshelfSection {
     pub title: Option<String>,
+    pub icon: Option<PathBuf>,
+    pub logo: Option<PathBuf>,
+    pub custom_css: Option<PathBuf>,
+    pub head_include: Option<PathBuf>,
+    pub footer_include: Option<PathBuf>,
     pub language: Option<String>,
+    pub date_format: Option<DateFormat>,
+    pub duration_granularity: Option<DurationGranularity>,
+    pub duration_max_units: Option<usize>,
     pub timezone: Option<String>,
     pub data_path: Option<PathBuf>,
+    pub demo: Option<bool>,
+    pub page_size: Option<usize>,
+    pub on_rebuild: Option<String>,
+    pub watch_paths: Option<Vec<PathBuf>>,
 }
 
 #[derive(Deserialize, Default, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct ServerSection {
+    pub bind: Option<IpAddr>,
     pub port: Option<u16>,
+    pub tls_cert: Option<PathBuf>,
+    pub tls_key: Option<PathBuf>,
     pub enable_auth: Option<bool>,
+    pub auth_user: Option<String>,
+    pub auth_password: Option<String>,
+    pub auth_token: Option<String>,
     pub enable_writeback: Option<bool>,
     pub trusted_proxies: Option<Vec<String>>,
+    pub headers: Option<Vec<String>>,
+    pub kosync_user: Option<String>,
+    pub kosync_password: Option<String>,
+    pub enable_metrics: Option<bool>,
 }
 
 #[derive(Deserialize, Default, Debug)]
@@ -49,6 +85,8 @@ pub struct ServerSection {
 pub struct OutputSection {
     pub path: Option<PathBuf>,
     pub include_files: Option<bool>,
+    pub export_csv: Option<bool>,
+    pub export_all_annotations: Option<bool>,
     pub watch: Option<bool>,
 }
 
@@ -57,9 +95,27 @@ pub struct OutputSection {
 pub struct StatisticsSection {
     pub heatmap_scale_max: Option<String>,
     pub day_start_time: Option<String>,
+    pub week_start: Option<String>,
+    pub calendar_color_by: Option<String>,
     pub min_pages_per_day: Option<u32>,
     pub min_time_per_day: Option<String>,
+    pub session_min: Option<u32>,
+    pub session_max: Option<u32>,
+    pub session_max_mode: Option<SessionMaxMode>,
+    pub session_gap: Option<u32>,
+    pub streak_grace_days: Option<u16>,
+    pub stats_from: Option<String>,
+    pub stats_to: Option<String>,
+    pub completion_min_pages: Option<u8>,
+    pub completion_min_early: Option<u8>,
+    pub completion_min_late: Option<u8>,
+    pub completion_restart_window: Option<u8>,
+    pub completion_overrides: Option<PathBuf>,
     pub include_all_stats: Option<bool>,
+    pub keep_ghost_stats: Option<bool>,
+    pub match_stats_by_title: Option<bool>,
+    pub recap_show_empty_months: Option<bool>,
+    pub stats_exclude: Option<Vec<String>>,
     pub ignore_stable_page_metadata: Option<bool>,
 }
 
@@ -3,11 +3,13 @@ pub mod file;
 pub mod site;
 
 pub use cli::{
-    Cli, CliCommand, CommonArgs, ExportArgs, ServeArgs, parse_time_to_seconds,
+    Cli, CliCommand, CommonArgs, CoverFit, DateFormat, DescriptionFormat, DurationGranularity,
+    ExportArgs, IdScheme, LogFormat, ServeArgs, SessionMaxMode, StatsDumpArgs,
+    parse_custom_headers, parse_since_date, parse_stats_date, parse_time_to_seconds,
     parse_trusted_proxy_nets,
 };
 pub use file::FileConfig;
-pub use site::SiteConfig;
+pub use site::{SiteAssetSources, SiteConfig};
 
 use clap::parser::ValueSource;
 use std::path::PathBuf;
@@ -50,6 +52,17 @@ fn merge_common_with_file_config(
         {
             common.library_path = paths.clone();
         }
+        if let Some(ref sources) = lib.sources
+            && not_explicit(matches, "source")
+            && !sources.is_empty()
+        {
+            common.source = sources.clone();
+        }
+        if let Some(ref interval) = lib.source_interval
+            && not_explicit(matches, "source_interval")
+        {
+            common.source_interval = interval.clone();
+        }
         if let Some(ref p) = lib.docsettings_path
             && not_explicit(matches, "docsettings_path")
         {
@@ -75,6 +88,46 @@ fn merge_common_with_file_config(
         {
             common.include_unread = v;
         }
+        if let Some(v) = lib.scan_jobs
+            && not_explicit(matches, "scan_jobs")
+        {
+            common.scan_jobs = Some(v);
+        }
+        if let Some(v) = lib.normalize_authors
+            && not_explicit(matches, "normalize_authors")
+        {
+            common.normalize_authors = v;
+        }
+        if let Some(ref p) = lib.author_aliases
+            && not_explicit(matches, "author_aliases")
+        {
+            common.author_aliases = Some(p.clone());
+        }
+        if let Some(v) = lib.id_scheme
+            && not_explicit(matches, "id_scheme")
+        {
+            common.id_scheme = v;
+        }
+        if let Some(v) = lib.description_format
+            && not_explicit(matches, "description_format")
+        {
+            common.description_format = v;
+        }
+        if let Some(v) = lib.description_max_chars
+            && not_explicit(matches, "description_max_chars")
+        {
+            common.description_max_chars = v;
+        }
+        if let Some(v) = lib.cover_fit
+            && not_explicit(matches, "cover_fit")
+        {
+            common.cover_fit = v;
+        }
+        if let Some(v) = lib.series_min_items
+            && not_explicit(matches, "series_min_items")
+        {
+            common.series_min_items = v;
+        }
     }
 
     // ── koshelf section ──────────────────────────────────────────
@@ -84,11 +137,51 @@ fn merge_common_with_file_config(
         {
             common.title = v.clone();
         }
+        if let Some(ref p) = ks.icon
+            && not_explicit(matches, "icon")
+        {
+            common.icon = Some(p.clone());
+        }
+        if let Some(ref p) = ks.logo
+            && not_explicit(matches, "logo")
+        {
+            common.logo = Some(p.clone());
+        }
+        if let Some(ref p) = ks.custom_css
+            && not_explicit(matches, "custom_css")
+        {
+            common.custom_css = Some(p.clone());
+        }
+        if let Some(ref p) = ks.head_include
+            && not_explicit(matches, "head_include")
+        {
+            common.head_include = Some(p.clone());
+        }
+        if let Some(ref p) = ks.footer_include
+            && not_explicit(matches, "footer_include")
+        {
+            common.footer_include = Some(p.clone());
+        }
         if let Some(ref v) = ks.language
             && not_explicit(matches, "language")
         {
             common.language = v.clone();
         }
+        if let Some(v) = ks.date_format
+            && not_explicit(matches, "date_format")
+        {
+            common.date_format = v;
+        }
+        if let Some(v) = ks.duration_granularity
+            && not_explicit(matches, "duration_granularity")
+        {
+            common.duration_granularity = v;
+        }
+        if let Some(v) = ks.duration_max_units
+            && not_explicit(matches, "duration_max_units")
+        {
+            common.duration_max_units = v;
+        }
         if let Some(ref v) = ks.timezone
             && not_explicit(matches, "timezone")
         {
@@ -99,6 +192,27 @@ fn merge_common_with_file_config(
         {
             common.data_path = Some(p.clone());
         }
+        if let Some(v) = ks.demo
+            && not_explicit(matches, "demo")
+        {
+            common.demo = v;
+        }
+        if let Some(v) = ks.page_size
+            && not_explicit(matches, "page_size")
+        {
+            common.page_size = v;
+        }
+        if let Some(ref v) = ks.on_rebuild
+            && not_explicit(matches, "on_rebuild")
+        {
+            common.on_rebuild = Some(v.clone());
+        }
+        if let Some(ref paths) = ks.watch_paths
+            && not_explicit(matches, "watch_path")
+            && !paths.is_empty()
+        {
+            common.watch_path = paths.clone();
+        }
     }
 
     // ── statistics section ───────────────────────────────────────
@@ -113,6 +227,16 @@ fn merge_common_with_file_config(
         {
             common.day_start_time = Some(v.clone());
         }
+        if let Some(ref v) = stats.week_start
+            && not_explicit(matches, "week_start")
+        {
+            common.week_start = Some(v.clone());
+        }
+        if let Some(ref v) = stats.calendar_color_by
+            && not_explicit(matches, "calendar_color_by")
+        {
+            common.calendar_color_by = Some(v.clone());
+        }
         if let Some(v) = stats.min_pages_per_day
             && not_explicit(matches, "min_pages_per_day")
         {
@@ -123,11 +247,91 @@ fn merge_common_with_file_config(
         {
             common.min_time_per_day = Some(v.clone());
         }
+        if let Some(v) = stats.session_min
+            && not_explicit(matches, "session_min")
+        {
+            common.session_min = Some(v);
+        }
+        if let Some(v) = stats.session_max
+            && not_explicit(matches, "session_max")
+        {
+            common.session_max = Some(v);
+        }
+        if let Some(v) = stats.session_max_mode
+            && not_explicit(matches, "session_max_mode")
+        {
+            common.session_max_mode = v;
+        }
+        if let Some(v) = stats.session_gap
+            && not_explicit(matches, "session_gap")
+        {
+            common.session_gap = Some(v);
+        }
+        if let Some(v) = stats.streak_grace_days
+            && not_explicit(matches, "streak_grace_days")
+        {
+            common.streak_grace_days = Some(v);
+        }
+        if let Some(ref v) = stats.stats_from
+            && not_explicit(matches, "stats_from")
+        {
+            common.stats_from = Some(v.clone());
+        }
+        if let Some(ref v) = stats.stats_to
+            && not_explicit(matches, "stats_to")
+        {
+            common.stats_to = Some(v.clone());
+        }
+        if let Some(v) = stats.completion_min_pages
+            && not_explicit(matches, "completion_min_pages")
+        {
+            common.completion_min_pages = Some(v);
+        }
+        if let Some(v) = stats.completion_min_early
+            && not_explicit(matches, "completion_min_early")
+        {
+            common.completion_min_early = Some(v);
+        }
+        if let Some(v) = stats.completion_min_late
+            && not_explicit(matches, "completion_min_late")
+        {
+            common.completion_min_late = Some(v);
+        }
+        if let Some(v) = stats.completion_restart_window
+            && not_explicit(matches, "completion_restart_window")
+        {
+            common.completion_restart_window = Some(v);
+        }
+        if let Some(ref p) = stats.completion_overrides
+            && not_explicit(matches, "completion_overrides")
+        {
+            common.completion_overrides = Some(p.clone());
+        }
         if let Some(v) = stats.include_all_stats
             && not_explicit(matches, "include_all_stats")
         {
             common.include_all_stats = v;
         }
+        if let Some(v) = stats.keep_ghost_stats
+            && not_explicit(matches, "keep_ghost_stats")
+        {
+            common.keep_ghost_stats = v;
+        }
+        if let Some(v) = stats.match_stats_by_title
+            && not_explicit(matches, "match_stats_by_title")
+        {
+            common.match_stats_by_title = v;
+        }
+        if let Some(v) = stats.recap_show_empty_months
+            && not_explicit(matches, "recap_show_empty_months")
+        {
+            common.recap_show_empty_months = v;
+        }
+        if let Some(ref v) = stats.stats_exclude
+            && not_explicit(matches, "stats_exclude")
+        {
+            common.stats_exclude = v.clone();
+        }
         if let Some(v) = stats.ignore_stable_page_metadata
             && not_explicit(matches, "ignore_stable_page_metadata")
         {
@@ -145,16 +349,46 @@ pub fn merge_serve_with_file_config(
     merge_common_with_file_config(&mut args.common, config, matches);
 
     if let Some(ref srv) = config.server {
+        if let Some(v) = srv.bind
+            && not_explicit(matches, "bind")
+        {
+            args.bind = v;
+        }
         if let Some(v) = srv.port
             && not_explicit(matches, "port")
         {
             args.port = v;
         }
+        if let Some(ref v) = srv.tls_cert
+            && not_explicit(matches, "tls_cert")
+        {
+            args.tls_cert = Some(v.clone());
+        }
+        if let Some(ref v) = srv.tls_key
+            && not_explicit(matches, "tls_key")
+        {
+            args.tls_key = Some(v.clone());
+        }
         if let Some(v) = srv.enable_auth
             && not_explicit(matches, "enable_auth")
         {
             args.enable_auth = v;
         }
+        if let Some(ref v) = srv.auth_user
+            && not_explicit(matches, "auth_user")
+        {
+            args.auth_user = Some(v.clone());
+        }
+        if let Some(ref v) = srv.auth_password
+            && not_explicit(matches, "auth_password")
+        {
+            args.auth_password = Some(v.clone());
+        }
+        if let Some(ref v) = srv.auth_token
+            && not_explicit(matches, "auth_token")
+        {
+            args.auth_token = Some(v.clone());
+        }
         if let Some(v) = srv.enable_writeback
             && not_explicit(matches, "enable_writeback")
         {
@@ -166,9 +400,39 @@ pub fn merge_serve_with_file_config(
         {
             args.trusted_proxies = values.clone();
         }
+        if let Some(ref values) = srv.headers
+            && not_explicit(matches, "headers")
+            && !values.is_empty()
+        {
+            args.headers = values.clone();
+        }
+        if let Some(ref v) = srv.kosync_user
+            && not_explicit(matches, "kosync_user")
+        {
+            args.kosync_user = Some(v.clone());
+        }
+        if let Some(ref v) = srv.kosync_password
+            && not_explicit(matches, "kosync_password")
+        {
+            args.kosync_password = Some(v.clone());
+        }
+        if let Some(v) = srv.enable_metrics
+            && not_explicit(matches, "enable_metrics")
+        {
+            args.enable_metrics = v;
+        }
     }
 }
 
+/// Merge TOML config into StatsDumpArgs (common sections only).
+pub fn merge_stats_dump_with_file_config(
+    args: &mut StatsDumpArgs,
+    config: &FileConfig,
+    matches: &clap::ArgMatches,
+) {
+    merge_common_with_file_config(&mut args.common, config, matches);
+}
+
 /// Merge TOML config into ExportArgs (common + output sections).
 pub fn merge_export_with_file_config(
     args: &mut ExportArgs,
@@ -188,6 +452,16 @@ pub fn merge_export_with_file_config(
         {
             args.include_files = v;
         }
+        if let Some(v) = out.export_csv
+            && not_explicit(matches, "export_csv")
+        {
+            args.export_csv = v;
+        }
+        if let Some(v) = out.export_all_annotations
+            && not_explicit(matches, "export_all_annotations")
+        {
+            args.export_all_annotations = v;
+        }
         if let Some(v) = out.watch
             && not_explicit(matches, "watch")
         {
@@ -292,6 +566,8 @@ mod tests {
             output: Some(crate::app::config::file::OutputSection {
                 path: Some(PathBuf::from("/output/from-config")),
                 include_files: None,
+                export_csv: None,
+                export_all_annotations: None,
                 watch: None,
             }),
             ..FileConfig::default()
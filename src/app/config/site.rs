@@ -1,8 +1,18 @@
 //! Site configuration module - bundles generator/watcher configuration.
 
+use crate::app::config::{
+    CoverFit, DateFormat, DescriptionFormat, DurationGranularity, IdScheme, SessionMaxMode,
+};
+use crate::pipeline::html_includes::SharedHtmlIncludes;
+use crate::shelf::library::authors::AuthorAliases;
+use crate::shelf::statistics::StatisticsConfig;
+use crate::shelf::statistics::calendar::CalendarColorBy;
+use crate::shelf::statistics::compute::completion_detection::CompletionConfig;
+use crate::shelf::statistics::compute::completion_overrides::CompletionOverrides;
 use crate::shelf::time_config::TimeConfig;
 use crate::source::scanner::MetadataLocation;
 use crate::store::lifecycle::RuntimeDataPolicy;
+use chrono::NaiveDate;
 use std::path::PathBuf;
 
 /// Configuration for site generation and file watching.
@@ -12,8 +22,42 @@ pub struct SiteConfig {
     pub output_dir: PathBuf,
     /// Title for the generated site
     pub site_title: String,
+    /// Custom HTML for the end of every page's head and body
+    pub html_includes: SharedHtmlIncludes,
     /// Whether to include unread books
     pub include_unread: bool,
+    /// Number of library files parsed concurrently during ingest
+    pub scan_jobs: usize,
+    /// Whether "Last, First" author names are reordered to "First Last"
+    pub normalize_authors: bool,
+    /// Canonical author names for spelling variants
+    pub author_aliases: AuthorAliases,
+    /// Whether items get readable URL slugs in addition to their IDs
+    pub id_scheme: IdScheme,
+    /// How book descriptions are turned into HTML
+    pub description_format: DescriptionFormat,
+    /// Description characters shown before "read more" (0 = no limit)
+    pub description_max_chars: usize,
+    /// How covers are fitted to the 2:3 grid when they are generated
+    pub cover_fit: CoverFit,
+    /// Items a series needs before it gets a series page
+    pub series_min_items: usize,
+    /// Whether identifying metadata is replaced with placeholders
+    pub demo: bool,
+    /// Library cards rendered per section before loading more (0 = all)
+    pub page_size: usize,
+    /// Date format the site uses instead of the language's own
+    pub date_format: DateFormat,
+    /// Smallest unit durations are shown in
+    pub duration_granularity: DurationGranularity,
+    /// Most units shown per duration (0 = no limit)
+    pub duration_max_units: usize,
+    /// Shell command run after rebuilds that changed data (optional)
+    pub on_rebuild: Option<String>,
+    /// Extra files and directories the watcher reacts to, as absolute paths
+    pub watch_paths: Vec<PathBuf>,
+    /// Source files of the logo, custom CSS and HTML includes
+    pub asset_sources: SiteAssetSources,
     /// Paths to library directories (books and/or comics)
     pub library_paths: Vec<PathBuf>,
     /// Where to look for KoReader metadata
@@ -26,12 +70,38 @@ pub struct SiteConfig {
     pub heatmap_scale_max: Option<u32>,
     /// Time zone configuration
     pub time_config: TimeConfig,
+    /// Session gap and streak grace used when deriving statistics
+    pub stats_config: StatisticsConfig,
+    /// What decides the color of calendar events
+    pub calendar_color_by: CalendarColorBy,
+    /// Whether the recap lists months in which nothing was finished
+    pub recap_show_empty_months: bool,
     /// Minimum pages per day for statistics filtering (optional)
     pub min_pages_per_day: Option<u32>,
     /// Minimum time per day in seconds for statistics filtering (optional)
     pub min_time_per_day: Option<u32>,
+    /// Page reads shorter than this many seconds are dropped (optional)
+    pub session_min: Option<u32>,
+    /// Page reads longer than this many seconds are capped (optional)
+    pub session_max: Option<u32>,
+    /// Whether capped page reads are clamped to `session_max` or dropped
+    pub session_max_mode: SessionMaxMode,
+    /// First logical day of reading included in statistics (optional)
+    pub stats_from: Option<NaiveDate>,
+    /// Last logical day of reading included in statistics (optional)
+    pub stats_to: Option<NaiveDate>,
+    /// Thresholds for automatic completion detection
+    pub completion_config: CompletionConfig,
+    /// Manual completion ranges that replace detection for the listed books
+    pub completion_overrides: CompletionOverrides,
     /// Whether to include all stats or filter to library books only
     pub include_all_stats: bool,
+    /// Keep statistics for books removed from the library, marked as removed
+    pub keep_ghost_stats: bool,
+    /// Link orphaned statistics to library books by title and author
+    pub match_stats_by_title: bool,
+    /// Books left out of statistics, by MD5 or title substring
+    pub stats_exclude: Vec<String>,
     /// Whether running with internal web server (enables runtime update events)
     pub is_internal_server: bool,
     /// Language for UI translations (e.g., "en_US", "de_DE")
@@ -44,6 +114,20 @@ pub struct SiteConfig {
     pub writeback_enabled: bool,
     /// Whether to include item files in static export output
     pub include_files: bool,
+    /// Whether to write raw statistics CSV files in static export output
+    pub export_csv: bool,
+    /// Whether to write every annotation in the library to one export file
+    pub export_all_annotations: bool,
     /// Resolved runtime lifecycle policy for shared runtime data storage
     pub runtime_data_policy: RuntimeDataPolicy,
 }
+
+/// Files the site's look is copied or loaded from, applied again when a
+/// watched path changes.
+#[derive(Clone, Debug, Default)]
+pub struct SiteAssetSources {
+    pub logo: Option<PathBuf>,
+    pub custom_css: Option<PathBuf>,
+    pub head_include: Option<PathBuf>,
+    pub footer_include: Option<PathBuf>,
+}
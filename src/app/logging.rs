@@ -0,0 +1,129 @@
+//! Logger setup for the `--log-format` choices, coordinated with progress bars.
+
+use crate::app::config::LogFormat;
+use crate::pipeline::progress;
+use chrono::{SecondsFormat, Utc};
+use env_logger::fmt::Formatter;
+use log::kv::{self, Key, Value, VisitSource};
+use log::{Log, Metadata, Record};
+use serde_json::{Map, Value as JsonValue};
+use std::io::{self, Write};
+
+/// Initialize the global logger and progress bars. Defaults to `info`,
+/// overridable via `RUST_LOG`.
+pub fn init(format: LogFormat) {
+    let mut builder = env_logger::builder();
+    builder
+        .filter_level(log::LevelFilter::Info)
+        .parse_default_env();
+    if format == LogFormat::Json {
+        builder.format(write_json_line);
+    }
+    progress::configure(format == LogFormat::Json);
+
+    let logger = builder.build();
+    log::set_max_level(logger.filter());
+    log::set_boxed_logger(Box::new(ProgressAwareLogger(logger)))
+        .expect("logger is initialized once");
+}
+
+/// Clears active progress bars while a line is written so the two do not
+/// interleave on the terminal.
+struct ProgressAwareLogger(env_logger::Logger);
+
+impl Log for ProgressAwareLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.0.matches(record) {
+            progress::suspend(|| self.0.log(record));
+        }
+    }
+
+    fn flush(&self) {
+        self.0.flush();
+    }
+}
+
+/// One JSON object per record: `timestamp`, `level`, `target`, `message`,
+/// plus any structured fields attached to the log call (e.g. `phase`, `paths`).
+fn write_json_line(buf: &mut Formatter, record: &Record<'_>) -> io::Result<()> {
+    writeln!(
+        buf,
+        "{}",
+        json_line(
+            record,
+            Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+        )
+    )
+}
+
+fn json_line(record: &Record<'_>, timestamp: String) -> JsonValue {
+    let mut fields = FieldCollector(Map::new());
+    // Visiting an in-memory source only fails if the visitor does.
+    let _ = record.key_values().visit(&mut fields);
+
+    let mut line = fields.0;
+    line.insert("timestamp".into(), timestamp.into());
+    line.insert("level".into(), record.level().as_str().into());
+    line.insert("target".into(), record.target().into());
+    line.insert("message".into(), record.args().to_string().into());
+    JsonValue::Object(line)
+}
+
+struct FieldCollector(Map<String, JsonValue>);
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        let value = if let Some(b) = value.to_bool() {
+            b.into()
+        } else if let Some(n) = value.to_i64() {
+            n.into()
+        } else if let Some(n) = value.to_u64() {
+            n.into()
+        } else if let Some(n) = value.to_f64() {
+            n.into()
+        } else {
+            value.to_string().into()
+        };
+        self.0.insert(key.as_str().to_string(), value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_line_includes_structured_fields() {
+        let fields: [(&str, Value); 3] = [
+            ("phase", Value::from("rebuild_started")),
+            ("paths", Value::from(3u64)),
+            ("path", Value::from_display(&"/books/a.epub")),
+        ];
+        let record = Record::builder()
+            .args(format_args!("Starting targeted rebuild"))
+            .level(log::Level::Info)
+            .target("koshelf::pipeline::rebuild")
+            .key_values(&fields)
+            .build();
+
+        let line = json_line(&record, "2026-01-01T00:00:00.000Z".to_string());
+
+        assert_eq!(
+            line,
+            serde_json::json!({
+                "timestamp": "2026-01-01T00:00:00.000Z",
+                "level": "INFO",
+                "target": "koshelf::pipeline::rebuild",
+                "message": "Starting targeted rebuild",
+                "phase": "rebuild_started",
+                "paths": 3,
+                "path": "/books/a.epub",
+            })
+        );
+    }
+}
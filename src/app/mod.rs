@@ -1,5 +1,8 @@
 mod bootstrap;
 mod commands;
 pub mod config;
+pub mod logging;
+mod preflight;
+pub mod shutdown;
 
 pub use commands::dispatch;
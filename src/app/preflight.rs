@@ -0,0 +1,170 @@
+//! `--validate`: check every input a run would read, print a summary and exit
+//! without generating anything.
+//!
+//! The checks reuse the code paths generation goes through (option
+//! validation, site config construction, the statistics and Kobo parsers), so
+//! a passing preflight means the run gets past loading its inputs.
+
+use crate::app::bootstrap::{build_site_config, resolve_data_policy};
+use crate::app::config::CommonArgs;
+use crate::source::kobo::KoboDbParser;
+use crate::source::koreader::database::StatisticsParser;
+use crate::source::remote::SftpSource;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// Outcome of one preflight check.
+struct Check {
+    name: &'static str,
+    result: Result<()>,
+}
+
+/// Run every check for `common`, with `options` the result of the mode's own
+/// argument validation, and print the summary. Fails when any check fails.
+pub(crate) async fn run(common: &CommonArgs, options: Result<()>) -> Result<()> {
+    let checks = collect_checks(common, options).await;
+    let failed = checks.iter().filter(|check| check.result.is_err()).count();
+
+    println!("Validating KoShelf configuration:");
+    for check in &checks {
+        match &check.result {
+            Ok(()) => println!("  [ok]   {}", check.name),
+            Err(e) => println!("  [fail] {}: {:#}", check.name, e),
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} checks failed", failed, checks.len());
+    }
+    println!("All {} checks passed", checks.len());
+    Ok(())
+}
+
+async fn collect_checks(common: &CommonArgs, options: Result<()>) -> Vec<Check> {
+    let mut checks = vec![
+        Check {
+            name: "options",
+            result: options,
+        },
+        Check {
+            name: "settings",
+            result: check_settings(common),
+        },
+        Check {
+            name: "language",
+            result: check_language(&common.language),
+        },
+    ];
+
+    for path in &common.library_path {
+        checks.push(Check {
+            name: "library path",
+            result: std::fs::read_dir(path)
+                .map(drop)
+                .with_context(|| format!("Cannot read library path {:?}", path)),
+        });
+    }
+
+    for url in &common.source {
+        // Remote sources are only parsed; syncing them is left to the real run.
+        checks.push(Check {
+            name: "remote source",
+            result: SftpSource::parse(url).map(drop),
+        });
+    }
+
+    if !common.statistics_db.is_empty() {
+        checks.push(Check {
+            name: "statistics database",
+            result: StatisticsParser::parse_merged(&common.statistics_db)
+                .await
+                .map(drop),
+        });
+    }
+
+    if let Some(ref kobo_db) = common.kobo_db {
+        checks.push(Check {
+            name: "Kobo database",
+            result: KoboDbParser::parse(kobo_db).await.map(drop),
+        });
+    }
+
+    checks
+}
+
+/// Timezone, day start, week start, heatmap and stats options, plus the
+/// author aliases and completion overrides files, as parsed for a real run.
+fn check_settings(common: &CommonArgs) -> Result<()> {
+    build_site_config(
+        common,
+        PathBuf::new(),
+        false,
+        false,
+        false,
+        false,
+        resolve_data_policy(common),
+    )
+    .map(drop)
+}
+
+fn check_language(language: &str) -> Result<()> {
+    if !crate::i18n::is_supported_language(language) {
+        anyhow::bail!(
+            "Unsupported language {:?}; run `koshelf list-languages` to see the available ones",
+            language
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::config::{Cli, CliCommand};
+    use clap::Parser;
+
+    fn common_args(args: &[&str]) -> CommonArgs {
+        let cli = Cli::try_parse_from(["koshelf", "export", "out"].iter().chain(args)).unwrap();
+        match cli.command {
+            CliCommand::Export(args) => args.common,
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_every_failing_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing_db = dir.path().join("statistics.sqlite3");
+        let missing_db = missing_db.to_str().unwrap();
+        let common = common_args(&[
+            "--statistics-db",
+            missing_db,
+            "--language",
+            "xx_XX",
+            "--timezone",
+            "Not/AZone",
+        ]);
+
+        let checks = collect_checks(&common, Ok(())).await;
+        let failed: Vec<_> = checks
+            .iter()
+            .filter(|check| check.result.is_err())
+            .map(|check| check.name)
+            .collect();
+        assert_eq!(failed, ["settings", "language", "statistics database"]);
+        assert!(run(&common, Ok(())).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn passes_for_a_readable_library() {
+        let dir = tempfile::tempdir().unwrap();
+        let common = common_args(&[
+            "--library-path",
+            dir.path().to_str().unwrap(),
+            "--language",
+            "de_DE",
+        ]);
+
+        assert!(run(&common, Ok(())).await.is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+//! Graceful shutdown on SIGINT/SIGTERM.
+//!
+//! The first signal asks the long-running tasks to stop: the web server stops
+//! accepting connections and drains in-flight requests, and the file watcher
+//! lets a rebuild already in progress finish but starts no new one. A second
+//! signal exits immediately, so a stuck shutdown can always be interrupted.
+
+use log::{info, warn};
+use tokio::sync::watch;
+
+/// Exit status for the forced exit on a second signal (128 + SIGINT).
+const FORCED_EXIT_CODE: i32 = 130;
+
+/// Shared shutdown flag, cloned into every task that needs to stop cleanly.
+#[derive(Clone)]
+pub struct Shutdown {
+    tx: watch::Sender<bool>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        let (tx, _rx) = watch::channel(false);
+        Self { tx }
+    }
+
+    /// Create a flag that is set by the first SIGINT/SIGTERM; the second one
+    /// exits the process.
+    ///
+    /// Installing the handlers replaces the default "terminate" action, so
+    /// call this only once the caller is ready to react to the flag.
+    pub fn on_signals() -> Self {
+        let shutdown = Self::new();
+        let trigger = shutdown.clone();
+
+        tokio::spawn(async move {
+            match wait_for_signal().await {
+                Ok(signal) => {
+                    info!(
+                        "Received {}, shutting down (send it again to exit immediately)",
+                        signal
+                    );
+                    trigger.request();
+                }
+                Err(error) => {
+                    warn!("Failed to listen for shutdown signals: {}", error);
+                    return;
+                }
+            }
+
+            if let Ok(signal) = wait_for_signal().await {
+                warn!("Received {} again, exiting immediately", signal);
+                std::process::exit(FORCED_EXIT_CODE);
+            }
+        });
+
+        shutdown
+    }
+
+    /// Ask every holder of this flag to stop.
+    pub fn request(&self) {
+        self.tx.send_replace(true);
+    }
+
+    pub fn is_requested(&self) -> bool {
+        *self.tx.borrow()
+    }
+
+    /// Resolve once shutdown has been requested.
+    pub async fn requested(&self) {
+        let mut rx = self.tx.subscribe();
+        // The sender lives in `self`, so the channel cannot close while waiting.
+        let _ = rx.wait_for(|requested| *requested).await;
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> std::io::Result<&'static str> {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut terminate = signal(SignalKind::terminate())?;
+    tokio::select! {
+        result = tokio::signal::ctrl_c() => result.map(|()| "SIGINT"),
+        _ = terminate.recv() => Ok("SIGTERM"),
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() -> std::io::Result<&'static str> {
+    tokio::signal::ctrl_c().await.map(|()| "Ctrl+C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn request_wakes_every_clone() {
+        let shutdown = Shutdown::new();
+        let waiter = shutdown.clone();
+        let task = tokio::spawn(async move { waiter.requested().await });
+
+        assert!(!shutdown.is_requested());
+        shutdown.request();
+
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .expect("waiter woke up")
+            .unwrap();
+        assert!(shutdown.is_requested());
+        // Waiting after the fact resolves immediately.
+        shutdown.requested().await;
+    }
+}
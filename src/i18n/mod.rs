@@ -2,4 +2,4 @@
 
 pub mod translations;
 
-pub use translations::list_supported_languages;
+pub use translations::{is_supported_language, list_supported_languages};
@@ -2,6 +2,20 @@
 
 include!(concat!(env!("OUT_DIR"), "/locale_manifest.rs"));
 
+/// Whether the web app has translations for `code`, either exactly or for its
+/// base language (`de_AT` uses the `de` translations).
+pub fn is_supported_language(code: &str) -> bool {
+    let base = |code: &str| {
+        code.split(['_', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    SUPPORTED_LANGUAGES
+        .iter()
+        .any(|(supported, _)| base(supported) == base(code))
+}
+
 pub fn list_supported_languages() -> String {
     let mut output = String::new();
     output.push_str("Supported Languages:\n\n");
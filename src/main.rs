@@ -15,7 +15,7 @@ mod tests;
 use anyhow::{Context, Result};
 use app::config::{
     Cli, CliCommand, FileConfig, merge_export_with_file_config, merge_serve_with_file_config,
-    merge_set_password_data_path,
+    merge_set_password_data_path, merge_stats_dump_with_file_config,
 };
 use app::dispatch;
 use clap::{CommandFactory, FromArgMatches};
@@ -23,14 +23,11 @@ use std::path::Path;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    env_logger::builder()
-        .filter_level(log::LevelFilter::Info)
-        .parse_default_env()
-        .init();
-
     let matches = Cli::command().get_matches();
     let mut cli = Cli::from_arg_matches(&matches)?;
 
+    app::logging::init(cli.log_format);
+
     // ── Load config file ─────────────────────────────────────────────
     let config_path_explicit = cli.config.clone();
     let default_config_path = Path::new("koshelf.toml");
@@ -57,6 +54,9 @@ async fn main() -> Result<()> {
             CliCommand::Export(ref mut args) => {
                 merge_export_with_file_config(args, fc, sub_matches);
             }
+            CliCommand::StatsDump(ref mut args) => {
+                merge_stats_dump_with_file_config(args, fc, sub_matches);
+            }
             CliCommand::SetPassword {
                 ref mut data_path, ..
             } => {
@@ -0,0 +1,183 @@
+//! Library-wide annotation export for backups and analysis.
+//!
+//! Writes `export/all-highlights.json`, keyed by item ID, and
+//! `export/all-highlights.md` with one section per book. Only highlights
+//! (with their notes) are included; bookmarks, items without highlights and
+//! private items are left out.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use log::info;
+use serde::Serialize;
+
+use crate::server::api::responses::library::{LibraryAnnotation, LibraryListItem};
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// One book's entry in `all-highlights.json`.
+#[derive(Debug, Serialize)]
+struct AnnotatedItem {
+    title: String,
+    authors: Vec<String>,
+    highlights: Vec<LibraryAnnotation>,
+}
+
+/// Write `all-highlights.json` and `all-highlights.md` into `export_dir`.
+pub async fn export_all_annotations(
+    export_dir: &Path,
+    library_repo: &LibraryRepository,
+    items: &[LibraryListItem],
+) -> Result<()> {
+    fs::create_dir_all(export_dir)?;
+
+    let mut annotated: BTreeMap<&str, AnnotatedItem> = BTreeMap::new();
+    for item in items.iter().filter(|item| item.highlight_count > 0) {
+        let is_private = library_repo
+            .get_item(&item.id)
+            .await?
+            .is_some_and(|detail| detail.is_private);
+        if is_private {
+            continue;
+        }
+
+        let highlights = library_repo
+            .get_annotations(&item.id, Some("highlight"))
+            .await?;
+        if highlights.is_empty() {
+            continue;
+        }
+        annotated.insert(
+            &item.id,
+            AnnotatedItem {
+                title: item.title.clone(),
+                authors: item.authors.0.clone(),
+                highlights,
+            },
+        );
+    }
+
+    let json = serde_json::to_string_pretty(&annotated)?;
+    let json_path = export_dir.join("all-highlights.json");
+    fs::write(&json_path, json).with_context(|| format!("failed to write {:?}", json_path))?;
+
+    let md_path = export_dir.join("all-highlights.md");
+    fs::write(&md_path, render_markdown(&annotated))
+        .with_context(|| format!("failed to write {:?}", md_path))?;
+
+    info!(
+        "Exported {} highlights from {} items",
+        annotated
+            .values()
+            .map(|item| item.highlights.len())
+            .sum::<usize>(),
+        annotated.len()
+    );
+    Ok(())
+}
+
+/// Markdown with a `##` section per book, ordered by title.
+fn render_markdown(annotated: &BTreeMap<&str, AnnotatedItem>) -> String {
+    let mut books: Vec<&AnnotatedItem> = annotated.values().collect();
+    books.sort_by_key(|item| item.title.to_lowercase());
+
+    let mut out = String::from("# Highlights\n");
+    for book in books {
+        out.push_str(&format!("\n## {}\n", book.title.trim()));
+        if !book.authors.is_empty() {
+            out.push_str(&format!("\n*{}*\n", book.authors.join(", ")));
+        }
+
+        for highlight in &book.highlights {
+            out.push('\n');
+            if let Some(text) = highlight.text.as_deref().map(str::trim)
+                && !text.is_empty()
+            {
+                for line in text.lines().map(str::trim_end) {
+                    if line.is_empty() {
+                        out.push_str(">\n");
+                    } else {
+                        out.push_str(&format!("> {line}\n"));
+                    }
+                }
+            }
+            if let Some(note) = highlight.note.as_deref().map(str::trim)
+                && !note.is_empty()
+            {
+                out.push_str(&format!("\n**Note:** {note}\n"));
+            }
+
+            let location: Vec<String> = [
+                highlight.chapter.clone().filter(|c| !c.trim().is_empty()),
+                highlight.pageno.map(|page| format!("p. {page}")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            if !location.is_empty() {
+                out.push_str(&format!("\n— {}\n", location.join(", ")));
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn highlight(text: &str, note: Option<&str>, chapter: Option<&str>) -> LibraryAnnotation {
+        LibraryAnnotation {
+            id: "a".to_string(),
+            chapter: chapter.map(str::to_string),
+            datetime: None,
+            datetime_updated: None,
+            pageno: Some(12),
+            text: Some(text.to_string()),
+            note: note.map(str::to_string),
+            pos0: None,
+            pos1: None,
+            color: None,
+            drawer: None,
+        }
+    }
+
+    #[test]
+    fn markdown_has_a_section_per_book_sorted_by_title() {
+        let mut annotated = BTreeMap::new();
+        annotated.insert(
+            "id-z",
+            AnnotatedItem {
+                title: "Zen".to_string(),
+                authors: vec![],
+                highlights: vec![highlight("Breathe.", None, None)],
+            },
+        );
+        annotated.insert(
+            "id-b",
+            AnnotatedItem {
+                title: "atlas".to_string(),
+                authors: vec!["Ann Author".to_string()],
+                highlights: vec![highlight(
+                    "First line\n\nSecond line",
+                    Some("Worth rereading"),
+                    Some("Chapter 1"),
+                )],
+            },
+        );
+
+        assert_eq!(
+            render_markdown(&annotated),
+            "# Highlights\n\
+             \n## atlas\n\
+             \n*Ann Author*\n\
+             \n> First line\n>\n> Second line\n\
+             \n**Note:** Worth rereading\n\
+             \n— Chapter 1, p. 12\n\
+             \n## Zen\n\
+             \n> Breathe.\n\
+             \n— p. 12\n"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Wall-clock timings of the pipeline phases of a single build.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use log::info;
+use serde::Serialize;
+
+use crate::pipeline::export::write_json;
+
+/// Timing of a single pipeline phase.
+#[derive(Debug, Serialize)]
+pub struct PhaseTiming {
+    pub name: &'static str,
+    pub duration_ms: u64,
+}
+
+/// Phase timings collected while a build runs, in execution order.
+#[derive(Debug, Default, Serialize)]
+pub struct BuildReport {
+    pub phases: Vec<PhaseTiming>,
+}
+
+impl BuildReport {
+    /// Record `name` as having run from `started` until now.
+    pub fn record(&mut self, name: &'static str, started: Instant) {
+        self.push(name, started.elapsed());
+    }
+
+    fn push(&mut self, name: &'static str, elapsed: Duration) {
+        self.phases.push(PhaseTiming {
+            name,
+            duration_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+        });
+    }
+
+    pub fn total_ms(&self) -> u64 {
+        self.phases.iter().map(|p| p.duration_ms).sum()
+    }
+
+    /// Summary table, one line per phase plus a total.
+    fn summary_lines(&self) -> Vec<String> {
+        let width = self
+            .phases
+            .iter()
+            .map(|p| p.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("total".len());
+        let total = self.total_ms();
+
+        let mut lines: Vec<String> = self
+            .phases
+            .iter()
+            .map(|p| {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    p.duration_ms as f64 * 100.0 / total as f64
+                };
+                format!(
+                    "{:<width$}  {:>8} ms  {:>5.1}%",
+                    p.name, p.duration_ms, share
+                )
+            })
+            .collect();
+        lines.push(format!("{:<width$}  {:>8} ms", "total", total));
+        lines
+    }
+
+    /// Log the summary table.
+    pub fn log_summary(&self) {
+        info!("Build timings:");
+        for line in self.summary_lines() {
+            info!("  {}", line);
+        }
+    }
+
+    /// Write the report as JSON to `path`.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        #[derive(Serialize)]
+        struct Report<'a> {
+            total_ms: u64,
+            phases: &'a [PhaseTiming],
+        }
+
+        write_json(
+            path,
+            &Report {
+                total_ms: self.total_ms(),
+                phases: &self.phases,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_lists_phases_in_order_with_total() {
+        let mut report = BuildReport::default();
+        report.push("statistics", Duration::from_millis(300));
+        report.push("library scan", Duration::from_millis(100));
+
+        let lines = report.summary_lines();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("statistics  "));
+        assert!(lines[0].ends_with("300 ms   75.0%"));
+        assert!(lines[1].starts_with("library scan"));
+        assert!(lines[2].starts_with("total "));
+        assert!(lines[2].ends_with("400 ms"));
+    }
+
+    #[test]
+    fn write_includes_total() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut report = BuildReport::default();
+        report.push("frontend", Duration::from_millis(12));
+        report.write(&dir.path().join("build-report.json")).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(dir.path().join("build-report.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["total_ms"], 12);
+        assert_eq!(json["phases"][0]["name"], "frontend");
+        assert_eq!(json["phases"][0]["duration_ms"], 12);
+    }
+}
@@ -0,0 +1,246 @@
+//! CSV export of raw reading statistics for spreadsheet analysis.
+//!
+//! Writes `export/sessions.csv` (one row per reading session) and
+//! `export/daily.csv` (one row per logical reading day). Days are bucketed
+//! with the configured [`TimeConfig`](crate::shelf::time_config::TimeConfig)
+//! and page counts use the same scaling as the statistics endpoints.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use log::info;
+
+use crate::shelf::statistics::compute::scaling::round_pages;
+use crate::shelf::statistics::compute::sessions;
+use crate::source::koreader::types::PageStat;
+use crate::store::memory::ReadingData;
+
+const SESSIONS_HEADER: [&str; 5] = ["date", "start", "title", "duration_sec", "pages"];
+const DAILY_HEADER: [&str; 4] = ["date", "duration_sec", "pages", "sessions"];
+
+/// Write `sessions.csv` and `daily.csv` into `export_dir`.
+///
+/// `library_titles` maps lowercase item MD5 to the library title, which
+/// takes precedence over the title recorded in the statistics database.
+pub fn export_csv_files(
+    export_dir: &Path,
+    reading_data: &ReadingData,
+    library_titles: &HashMap<String, String>,
+) -> Result<()> {
+    fs::create_dir_all(export_dir)?;
+
+    let sessions = session_rows(reading_data, library_titles);
+    write_csv(
+        &export_dir.join("sessions.csv"),
+        &SESSIONS_HEADER,
+        &sessions,
+    )?;
+
+    let daily = daily_rows(reading_data);
+    write_csv(&export_dir.join("daily.csv"), &DAILY_HEADER, &daily)?;
+
+    info!(
+        "Exported {} reading sessions and {} reading days to CSV",
+        sessions.len(),
+        daily.len()
+    );
+    Ok(())
+}
+
+/// One row per reading session, oldest first.
+fn session_rows(
+    reading_data: &ReadingData,
+    library_titles: &HashMap<String, String>,
+) -> Vec<Vec<String>> {
+    let time_config = &reading_data.time_config;
+    let mut rows: Vec<(i64, Vec<String>)> = Vec::new();
+
+    for (book_id, book_stats) in stats_by_book(&reading_data.stats_data.page_stats) {
+        let book = reading_data
+            .stats_data
+            .books
+            .iter()
+            .find(|book| book.id == book_id);
+        let title = book
+            .and_then(|book| library_titles.get(&book.md5.to_lowercase()))
+            .cloned()
+            .or_else(|| book.map(|book| book.title.clone()))
+            .unwrap_or_default();
+        let factor = reading_data.page_scaling.factor_for_book_id(book_id);
+
+        for session in
+            sessions::split_sessions(&book_stats, reading_data.stats_config.session_gap_sec)
+        {
+            let Some(first) = session.first() else {
+                continue;
+            };
+            let duration: i64 = session.iter().map(|stat| stat.duration).sum();
+            let pages = round_pages(session.len() as f64 * factor);
+            rows.push((
+                first.start_time,
+                vec![
+                    time_config.format_date(first.start_time),
+                    time_config.format_timestamp_rfc3339(first.start_time),
+                    title.clone(),
+                    duration.to_string(),
+                    pages.to_string(),
+                ],
+            ));
+        }
+    }
+
+    rows.sort_by_key(|(start_time, _)| *start_time);
+    rows.into_iter().map(|(_, row)| row).collect()
+}
+
+/// One row per logical reading day, oldest first.
+fn daily_rows(reading_data: &ReadingData) -> Vec<Vec<String>> {
+    let time_config = &reading_data.time_config;
+    // date -> (duration, scaled pages, sessions)
+    let mut days: BTreeMap<String, (i64, f64, usize)> = BTreeMap::new();
+
+    for (book_id, book_stats) in stats_by_book(&reading_data.stats_data.page_stats) {
+        let factor = reading_data.page_scaling.factor_for_book_id(book_id);
+        for stat in &book_stats {
+            let day = days
+                .entry(time_config.format_date(stat.start_time))
+                .or_default();
+            day.0 += stat.duration;
+            day.1 += factor;
+        }
+        for session in
+            sessions::split_sessions(&book_stats, reading_data.stats_config.session_gap_sec)
+        {
+            if let Some(first) = session.first() {
+                days.entry(time_config.format_date(first.start_time))
+                    .or_default()
+                    .2 += 1;
+            }
+        }
+    }
+
+    days.into_iter()
+        .map(|(date, (duration, pages, sessions))| {
+            vec![
+                date,
+                duration.to_string(),
+                round_pages(pages).to_string(),
+                sessions.to_string(),
+            ]
+        })
+        .collect()
+}
+
+fn stats_by_book(page_stats: &[PageStat]) -> BTreeMap<i64, Vec<PageStat>> {
+    let mut by_book: BTreeMap<i64, Vec<PageStat>> = BTreeMap::new();
+    for stat in page_stats.iter().filter(|stat| stat.duration > 0) {
+        by_book.entry(stat.id_book).or_default().push(stat.clone());
+    }
+    by_book
+}
+
+fn write_csv(path: &Path, header: &[&str], rows: &[Vec<String>]) -> Result<()> {
+    let mut out = String::new();
+    push_record(&mut out, header.iter().copied());
+    for row in rows {
+        push_record(&mut out, row.iter().map(String::as_str));
+    }
+    fs::write(path, out).with_context(|| format!("failed to write {:?}", path))
+}
+
+fn push_record<'a>(out: &mut String, fields: impl Iterator<Item = &'a str>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&escape_field(field));
+    }
+    out.push_str("\r\n");
+}
+
+/// Quote a field per RFC 4180 when it contains a delimiter, quote, or line
+/// break; embedded quotes are doubled.
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::models::ContentType;
+    use crate::shelf::statistics::PageScaling;
+    use crate::shelf::time_config::TimeConfig;
+    use crate::tests::fixtures;
+
+    fn reading_data(page_stats: Vec<PageStat>, day_start_minutes: u16) -> ReadingData {
+        let mut book = fixtures::stat_book(1, "ABC", 300, ContentType::Book);
+        book.title = "Stats \"Title\"".to_string();
+        ReadingData {
+            stats_data: fixtures::statistics_data(vec![book], page_stats),
+            time_config: TimeConfig::new(Some(chrono_tz::UTC), day_start_minutes),
+            stats_config: Default::default(),
+            heatmap_scale_max: None,
+            page_scaling: PageScaling::disabled(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
+        }
+    }
+
+    #[test]
+    fn escapes_commas_quotes_and_line_breaks() {
+        assert_eq!(escape_field("Plain"), "Plain");
+        assert_eq!(escape_field("Dune, Part 1"), "\"Dune, Part 1\"");
+        assert_eq!(
+            escape_field("The \"Best\" Book"),
+            "\"The \"\"Best\"\" Book\""
+        );
+        assert_eq!(escape_field("Two\nLines"), "\"Two\nLines\"");
+    }
+
+    #[test]
+    fn session_title_prefers_library_metadata() {
+        // 2024-01-01 10:00:00 UTC
+        let stats = vec![
+            fixtures::page_stat(1, 1, 1_704_103_200, 60),
+            fixtures::page_stat(1, 2, 1_704_103_260, 60),
+        ];
+        let data = reading_data(stats, 0);
+
+        let rows = session_rows(&data, &HashMap::new());
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0][2], "Stats \"Title\"");
+
+        let titles = HashMap::from([("abc".to_string(), "Library, Title".to_string())]);
+        let rows = session_rows(&data, &titles);
+        assert_eq!(
+            rows[0],
+            vec![
+                "2024-01-01",
+                "2024-01-01T10:00:00+00:00",
+                "Library, Title",
+                "120",
+                "2"
+            ]
+        );
+    }
+
+    #[test]
+    fn daily_rows_use_logical_day_boundaries() {
+        // 2024-01-02 02:00 UTC belongs to 2024-01-01 with a 04:00 day start.
+        let stats = vec![
+            fixtures::page_stat(1, 1, 1_704_103_200, 60),
+            fixtures::page_stat(1, 2, 1_704_160_800, 90),
+            fixtures::page_stat(1, 3, 1_704_160_890, 30),
+        ];
+        let data = reading_data(stats, 4 * 60);
+
+        let rows = daily_rows(&data);
+        assert_eq!(rows, vec![vec!["2024-01-01", "180", "3", "2"]]);
+    }
+}
@@ -0,0 +1,69 @@
+//! User stylesheet (`--custom-css`) loaded after the built-in styles.
+
+use anyhow::{Context, Result};
+use log::info;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::pipeline::media;
+
+/// File name of the copied stylesheet, in the assets directory.
+pub const CUSTOM_CSS_FILE: &str = "custom.css";
+
+/// URL the app shell loads the stylesheet from.
+pub const CUSTOM_CSS_URL: &str = "/assets/custom.css";
+
+/// Copy `source` to the assets directory, or remove a previously copied
+/// stylesheet when none is configured.
+pub fn sync_custom_css(source: Option<&Path>, assets_dir: &Path) -> Result<()> {
+    let target = assets_dir.join(CUSTOM_CSS_FILE);
+    let Some(source) = source else {
+        if let Err(error) = fs::remove_file(&target)
+            && error.kind() != ErrorKind::NotFound
+        {
+            return Err(error.into());
+        }
+        return Ok(());
+    };
+
+    let css = fs::read_to_string(source)
+        .with_context(|| format!("Failed to read custom CSS {:?}", source))?;
+    fs::create_dir_all(assets_dir)?;
+    media::write_replacing(&target, css)?;
+
+    info!("Using custom CSS from {:?}", source);
+    Ok(())
+}
+
+/// Short content hash of the copied stylesheet, used to bust browser caches
+/// when it changes. `None` without a custom stylesheet.
+pub fn custom_css_version(assets_dir: &Path) -> Option<String> {
+    let css = fs::read(assets_dir.join(CUSTOM_CSS_FILE)).ok()?;
+    let digest = format!("{:x}", md5::compute(css));
+    Some(digest[..8].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_copies_and_removes_the_stylesheet() {
+        let temp = tempfile::tempdir().unwrap();
+        let source = temp.path().join("theme.css");
+        fs::write(&source, ":root { --color-primary-500: #e11d48; }").unwrap();
+        let assets_dir = temp.path().join("assets");
+
+        sync_custom_css(Some(&source), &assets_dir).unwrap();
+        let first = custom_css_version(&assets_dir).unwrap();
+        assert_eq!(first.len(), 8);
+
+        fs::write(&source, ":root { --color-primary-500: #16a34a; }").unwrap();
+        sync_custom_css(Some(&source), &assets_dir).unwrap();
+        assert_ne!(custom_css_version(&assets_dir).unwrap(), first);
+
+        sync_custom_css(None, &assets_dir).unwrap();
+        assert!(custom_css_version(&assets_dir).is_none());
+    }
+}
@@ -0,0 +1,248 @@
+//! Demo mode: scrub identifying metadata so the site can be shown in
+//! screenshots.
+//!
+//! Titles, authors and annotation text are replaced with generated
+//! placeholders and covers with plain gradients. Everything numeric (pages,
+//! reading time, dates, ratings, progress) is left alone, so statistics,
+//! calendar and recaps keep their real shape. Placeholders are derived from
+//! the book's MD5, so a book gets the same fake title in the library and in
+//! its statistics, and on every run.
+
+use std::io::Cursor;
+
+use anyhow::Result;
+use image::{ImageFormat, Rgb, RgbImage};
+
+use crate::shelf::models::LibraryItem;
+use crate::source::koreader::types::StatisticsData;
+
+const TITLE_ADJECTIVES: [&str; 16] = [
+    "Silent",
+    "Hidden",
+    "Last",
+    "Crimson",
+    "Distant",
+    "Broken",
+    "Golden",
+    "Quiet",
+    "Winter",
+    "Northern",
+    "Lost",
+    "Burning",
+    "Glass",
+    "Endless",
+    "Forgotten",
+    "Paper",
+];
+const TITLE_NOUNS: [&str; 16] = [
+    "Harbor",
+    "Garden",
+    "Kingdom",
+    "River",
+    "Letters",
+    "Orchard",
+    "Lighthouse",
+    "Archive",
+    "Voyage",
+    "Meridian",
+    "Forest",
+    "Empire",
+    "Tide",
+    "Mountain",
+    "Library",
+    "Station",
+];
+const FIRST_NAMES: [&str; 16] = [
+    "Ada", "Jonas", "Mira", "Elias", "Nora", "Felix", "Iris", "Theo", "Lena", "Oskar", "Clara",
+    "Emil", "Hanna", "Leo", "Maren", "Victor",
+];
+const LAST_NAMES: [&str; 16] = [
+    "Hartley",
+    "Lindqvist",
+    "Moreau",
+    "Calloway",
+    "Brandt",
+    "Okafor",
+    "Sorensen",
+    "Vance",
+    "Albright",
+    "Kestrel",
+    "Navarro",
+    "Whitlock",
+    "Reyes",
+    "Holm",
+    "Ashby",
+    "Fontaine",
+];
+const FILLER_WORDS: [&str; 12] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+];
+
+/// Replace everything identifying in a library item with placeholders.
+pub(crate) fn scrub_library_item(item: &mut LibraryItem) {
+    let seed = seed(&item.id);
+    let info = &mut item.book_info;
+    info.title = placeholder_title(seed);
+    info.title_sort = None;
+    info.authors = vec![placeholder_author(seed)];
+    info.author_sort = None;
+    info.editors.clear();
+    info.translators.clear();
+    info.description = None;
+    info.publisher = None;
+    info.identifiers.clear();
+    info.series = None;
+    info.series_number = None;
+    for (index, chapter) in info.chapters.iter_mut().enumerate() {
+        chapter.title = format!("Chapter {}", index + 1);
+    }
+
+    if let Some(metadata) = item.koreader_metadata.as_mut() {
+        metadata.doc_props = None;
+        metadata.doc_path = None;
+        if let Some(stats) = metadata.stats.as_mut() {
+            stats.title = None;
+            stats.authors = None;
+            stats.series = None;
+        }
+        if let Some(summary) = metadata.summary.as_mut() {
+            summary.note = summary.note.as_deref().map(filler_text);
+        }
+        for annotation in &mut metadata.annotations {
+            annotation.text = annotation.text.as_deref().map(filler_text);
+            annotation.note = annotation.note.as_deref().map(filler_text);
+            annotation.chapter = annotation.chapter.as_ref().map(|_| "Chapter".to_string());
+        }
+    }
+}
+
+/// Replace titles and authors in the statistics database with the same
+/// placeholders the library uses.
+pub(crate) fn scrub_statistics(data: &mut StatisticsData) {
+    for book in data.books.iter_mut().chain(data.stats_by_md5.values_mut()) {
+        let seed = seed(&book.md5);
+        book.title = placeholder_title(seed);
+        book.authors = placeholder_author(seed);
+    }
+}
+
+/// A 400×600 PNG with a vertical gradient whose colors depend on `id`.
+pub(crate) fn placeholder_cover(id: &str) -> Result<Vec<u8>> {
+    let seed = seed(id);
+    let hue = (seed % 360) as f32;
+    let top = hsl_to_rgb(hue, 0.45, 0.55);
+    let bottom = hsl_to_rgb((hue + 40.0) % 360.0, 0.5, 0.25);
+
+    let (width, height) = (400, 600);
+    let image = RgbImage::from_fn(width, height, |_, y| {
+        let t = y as f32 / (height - 1) as f32;
+        Rgb(std::array::from_fn(|c| {
+            (top[c] as f32 * (1.0 - t) + bottom[c] as f32 * t).round() as u8
+        }))
+    });
+
+    let mut png = Cursor::new(Vec::new());
+    image.write_to(&mut png, ImageFormat::Png)?;
+    Ok(png.into_inner())
+}
+
+fn placeholder_title(seed: u64) -> String {
+    format!(
+        "The {} {}",
+        TITLE_ADJECTIVES[(seed % 16) as usize],
+        TITLE_NOUNS[((seed >> 4) % 16) as usize]
+    )
+}
+
+fn placeholder_author(seed: u64) -> String {
+    format!(
+        "{} {}",
+        FIRST_NAMES[((seed >> 8) % 16) as usize],
+        LAST_NAMES[((seed >> 12) % 16) as usize]
+    )
+}
+
+/// Filler words of roughly the same length as `text`, so highlight cards keep
+/// their size.
+fn filler_text(text: &str) -> String {
+    let target = text.chars().count().max(1);
+    let mut out = String::with_capacity(target + 12);
+    for word in FILLER_WORDS.iter().cycle() {
+        if out.len() >= target {
+            break;
+        }
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+    out
+}
+
+/// FNV-1a over the case-folded MD5, stable across runs and releases.
+fn seed(md5: &str) -> u64 {
+    md5.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte.to_ascii_lowercase())).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    [r, g, b].map(|v| ((v + m) * 255.0).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixtures;
+
+    #[test]
+    fn placeholders_depend_only_on_the_md5() {
+        let mut item = fixtures::library_item("0123456789ABCDEF0123456789ABCDEF", None);
+        item.book_info.title = "My Private Diary".to_string();
+        item.book_info.publisher = Some("Real Press".to_string());
+        scrub_library_item(&mut item);
+
+        assert_ne!(item.book_info.title, "My Private Diary");
+        assert_eq!(item.book_info.publisher, None);
+        assert_eq!(
+            item.book_info.title,
+            placeholder_title(seed("0123456789abcdef0123456789abcdef"))
+        );
+    }
+
+    #[test]
+    fn filler_text_roughly_keeps_the_length() {
+        let filler = filler_text("A short sentence that someone highlighted.");
+        assert!(filler.len() >= 43 && filler.len() < 60, "{filler}");
+        assert!(!filler.contains("highlighted"));
+    }
+
+    #[test]
+    fn placeholder_cover_is_a_decodable_image() {
+        let png = placeholder_cover("abc").unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+        assert_eq!((image.width(), image.height()), (400, 600));
+    }
+}
@@ -0,0 +1,180 @@
+//! Static export dry runs.
+//!
+//! The export is generated into a throwaway staging directory and compared
+//! with the real output directory, so an existing output is never modified.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use log::info;
+use walkdir::WalkDir;
+
+use crate::pipeline::{frontend, media};
+use crate::server::api::responses::library::LibraryContentType;
+use crate::shelf::library::{self, LibraryListQuery};
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// Output subdirectories whose contents are regenerated by every export.
+const GENERATED_DIRS: [&str; 2] = ["data", "export"];
+
+/// What an export would change in an output directory.
+#[derive(Debug, Default)]
+pub struct ExportPlan {
+    pub books: usize,
+    pub comics: usize,
+    pub has_reading_data: bool,
+    pub frontend_outdated: bool,
+    pub new_files: usize,
+    pub changed_files: usize,
+    pub unchanged_files: usize,
+    pub covers_to_generate: usize,
+    pub item_files: usize,
+    /// Output files the export no longer produces, relative to the output directory.
+    pub stale_files: Vec<PathBuf>,
+}
+
+/// Compare an export staged in `staging_dir` with `output_dir`.
+pub async fn plan_export(
+    staging_dir: &Path,
+    output_dir: &Path,
+    repo: &LibraryRepository,
+    include_files: bool,
+    has_reading_data: bool,
+) -> Result<ExportPlan> {
+    let items = library::list(repo, LibraryListQuery::default())
+        .await?
+        .items;
+    let mut plan = ExportPlan {
+        books: items
+            .iter()
+            .filter(|i| i.content_type == LibraryContentType::Book)
+            .count(),
+        comics: items
+            .iter()
+            .filter(|i| i.content_type == LibraryContentType::Comic)
+            .count(),
+        has_reading_data,
+        frontend_outdated: frontend::static_frontend_needs_sync(output_dir),
+        ..ExportPlan::default()
+    };
+
+    for dir in GENERATED_DIRS {
+        let staged = relative_files(&staging_dir.join(dir));
+        for relative in &staged {
+            match fs::read(output_dir.join(dir).join(relative)) {
+                Err(_) => plan.new_files += 1,
+                Ok(existing) if existing != fs::read(staging_dir.join(dir).join(relative))? => {
+                    plan.changed_files += 1
+                }
+                Ok(_) => plan.unchanged_files += 1,
+            }
+        }
+        plan.stale_files.extend(
+            relative_files(&output_dir.join(dir))
+                .difference(&staged)
+                .map(|relative| Path::new(dir).join(relative)),
+        );
+    }
+
+    let covers_dir = output_dir.join("assets").join("covers");
+    let staged_covers = relative_files(&staging_dir.join("assets").join("covers"));
+    let file_infos = repo.load_all_item_file_info().await?;
+    for (id, file_path, _) in &file_infos {
+        let cover_name = PathBuf::from(format!("{id}.webp"));
+        if staged_covers.contains(&cover_name)
+            && media::cover_needs_generation(Path::new(file_path), &covers_dir.join(&cover_name))
+        {
+            plan.covers_to_generate += 1;
+        }
+    }
+    let ids: HashSet<&str> = file_infos.iter().map(|(id, _, _)| id.as_str()).collect();
+    plan.stale_files.extend(
+        relative_files(&covers_dir)
+            .into_iter()
+            .filter(|relative| {
+                relative
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_none_or(|stem| !ids.contains(stem))
+            })
+            .map(|relative| Path::new("assets/covers").join(relative)),
+    );
+
+    if include_files {
+        plan.item_files = file_infos.len();
+    } else {
+        plan.stale_files.extend(
+            relative_files(&output_dir.join("assets").join("files"))
+                .into_iter()
+                .map(|relative| Path::new("assets/files").join(relative)),
+        );
+    }
+
+    plan.stale_files.sort();
+    Ok(plan)
+}
+
+impl ExportPlan {
+    /// Log the plan as a human-readable summary.
+    pub fn log_summary(&self, output_dir: &Path) {
+        info!("Dry run: nothing was written to {:?}", output_dir);
+        info!(
+            "Library: {} books, {} comics; reading statistics {}",
+            self.books,
+            self.comics,
+            if self.has_reading_data {
+                "present"
+            } else {
+                "absent"
+            }
+        );
+        if self.frontend_outdated {
+            info!("Static frontend would be (re)installed");
+        }
+        info!(
+            "Data files: {} new, {} changed, {} unchanged",
+            self.new_files, self.changed_files, self.unchanged_files
+        );
+        info!("Covers to generate: {}", self.covers_to_generate);
+        if self.item_files > 0 {
+            info!("Item files to copy: {}", self.item_files);
+        }
+        info!(
+            "Stale files no longer produced by the export: {}",
+            self.stale_files.len()
+        );
+        for path in &self.stale_files {
+            info!("  {}", path.display());
+        }
+    }
+}
+
+/// Files below `dir`, relative to it. Missing directories yield an empty set.
+fn relative_files(dir: &Path) -> HashSet<PathBuf> {
+    WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.path().strip_prefix(dir).ok().map(Path::to_path_buf))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_files_lists_nested_files_and_tolerates_missing_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("items")).unwrap();
+        fs::write(dir.path().join("site.json"), "{}").unwrap();
+        fs::write(dir.path().join("items").join("a.json"), "{}").unwrap();
+
+        let files = relative_files(dir.path());
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(Path::new("items/a.json")));
+        assert!(relative_files(&dir.path().join("missing")).is_empty());
+    }
+}
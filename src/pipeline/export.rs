@@ -5,17 +5,22 @@
 //!
 //! See `rewamp/04_static_export_shim.md` for the target file layout.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use log::info;
 use serde::Serialize;
 
-use crate::pipeline::media;
+use crate::app::config::{DateFormat, DurationGranularity};
+use crate::pipeline::build_report::BuildReport;
+use crate::pipeline::{annotation_export, csv_export, media, progress};
 use crate::server::api::responses::common::ContentTypeFilter;
-use crate::server::api::responses::library::LibraryContentType;
+use crate::server::api::responses::library::{
+    LibraryAuthorListData, LibraryContentType, LibrarySeriesListData,
+};
 use crate::server::api::responses::reading::{ReadingAvailablePeriodsData, ReadingMetricsData};
 use crate::server::api::responses::site::SiteCapabilities;
 use crate::shelf::library::queries::IncludeSet;
@@ -35,9 +40,15 @@ use crate::store::sqlite::repo::LibraryRepository;
 #[derive(Serialize)]
 struct ExportSite {
     name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logo_url: Option<String>,
     version: String,
     generated_at: String,
     default_language: String,
+    page_size: usize,
+    date_format: DateFormat,
+    duration_granularity: DurationGranularity,
+    duration_max_units: usize,
     capabilities: SiteCapabilities,
 }
 
@@ -74,8 +85,22 @@ const SCOPES: [ContentTypeFilter; 3] = [
 /// Minimal configuration needed by the data exporter.
 pub struct ExportConfig {
     pub site_title: String,
+    /// URL of the `--logo` image written to `site.json`.
+    pub logo_url: Option<String>,
+    /// RFC 3339 timestamp written to `site.json`.
+    pub generated_at: String,
     pub language: String,
+    pub page_size: usize,
+    pub date_format: DateFormat,
+    pub duration_granularity: DurationGranularity,
+    pub duration_max_units: usize,
+    /// Description characters shown before "read more" (0 = no limit).
+    pub description_max_chars: usize,
+    /// Items a series needs before it gets a page under `series/`.
+    pub series_min_items: usize,
     pub include_files: bool,
+    pub export_csv: bool,
+    pub export_all_annotations: bool,
 }
 
 // ── Public entry point ──────────────────────────────────────────────────
@@ -90,11 +115,13 @@ pub async fn export_data_files(
     library_repo: &LibraryRepository,
     reading_data: Option<&ReadingData>,
     config: &ExportConfig,
+    report: &mut BuildReport,
 ) -> Result<()> {
     info!("Exporting static data files to {:?}", data_dir);
     fs::create_dir_all(data_dir)?;
 
     // ── Library domain ──────────────────────────────────────────────────
+    let started = Instant::now();
     let items_data = library::list(library_repo, LibraryListQuery::default()).await?;
     let items = &items_data.items;
 
@@ -113,9 +140,14 @@ pub async fn export_data_files(
         &data_dir.join("site.json"),
         &ExportSite {
             name: config.site_title.clone(),
+            logo_url: config.logo_url.clone(),
             version: env!("CARGO_PKG_VERSION").to_string(),
-            generated_at: chrono::Utc::now().to_rfc3339(),
+            generated_at: config.generated_at.clone(),
             default_language: config.language.clone(),
+            page_size: config.page_size,
+            date_format: config.date_format,
+            duration_granularity: config.duration_granularity,
+            duration_max_units: config.duration_max_units,
             capabilities: SiteCapabilities {
                 has_books,
                 has_comics,
@@ -144,10 +176,72 @@ pub async fn export_data_files(
     write_json(&items_dir.join("comics.json"), &comics)?;
 
     // items/{id}.json — per-item with all includes expanded
-    export_item_details(data_dir, library_repo, reading_data, items).await?;
+    export_item_details(
+        data_dir,
+        library_repo,
+        reading_data,
+        items,
+        config.description_max_chars,
+    )
+    .await?;
+
+    // items/slugs.json — slug -> item ID, for `--id-scheme slug` URLs
+    let slugs: BTreeMap<&str, &str> = items
+        .iter()
+        .filter_map(|item| Some((item.slug.as_deref()?, item.id.as_str())))
+        .collect();
+    write_json_unless_empty(&items_dir.join("slugs.json"), &slugs)?;
+
+    // items/redirects.json — retired slug or ID -> the key the item has now
+    let current_keys: HashMap<&str, &str> = items
+        .iter()
+        .map(|item| (item.id.as_str(), item.slug.as_deref().unwrap_or(&item.id)))
+        .collect();
+    let retired = library_repo.load_retired_slugs().await?;
+    let redirects: BTreeMap<&str, &str> = retired
+        .iter()
+        .filter_map(|(slug, id)| Some((slug.as_str(), *current_keys.get(id.as_str())?)))
+        .collect();
+    write_json_unless_empty(&items_dir.join("redirects.json"), &redirects)?;
+
+    report.record("item data", started);
+
+    // export/all-highlights.{json,md} — every highlight, for backups
+    if config.export_all_annotations {
+        let started = Instant::now();
+        annotation_export::export_all_annotations(&output_dir.join("export"), library_repo, items)
+            .await?;
+        report.record("annotation export", started);
+    }
 
     // items/page-activity/{id}.json — per-item page-level reading heatmap data
+    let started = Instant::now();
     export_page_activity(data_dir, library_repo, reading_data, items).await?;
+    report.record("page activity", started);
+
+    // highlights.json — library-wide highlight quotes
+    let highlights = library::highlights(library_repo).await?;
+    write_json(&data_dir.join("highlights.json"), &highlights)?;
+
+    // series/index.json + series/{slug}.json — series pages
+    let started = Instant::now();
+    export_series(
+        data_dir,
+        library_repo,
+        reading_data,
+        config.series_min_items,
+    )
+    .await?;
+    report.record("series", started);
+
+    // up-next.json — next unread item of started series
+    let up_next = library::up_next(library_repo, config.series_min_items).await?;
+    write_json(&data_dir.join("up-next.json"), &up_next)?;
+
+    // authors/index.json + authors/{slug}.json — author pages
+    let started = Instant::now();
+    export_authors(data_dir, library_repo, reading_data).await?;
+    report.record("authors", started);
 
     info!(
         "Exported {} library items ({} detail files)",
@@ -156,6 +250,7 @@ pub async fn export_data_files(
     );
 
     // ── Item files ─────────────────────────────────────────────────────
+    let started = Instant::now();
     if config.include_files {
         export_item_files(output_dir, library_repo).await?;
     } else {
@@ -163,16 +258,42 @@ pub async fn export_data_files(
         let keep: HashSet<String> = HashSet::new();
         cleanup_stale_item_files(&files_dir, &keep)?;
     }
+    report.record("item files", started);
 
     // ── Reading domain ──────────────────────────────────────────────────
     if let Some(rd) = reading_data
         && has_reading_data
     {
+        let started = Instant::now();
         export_reading_summary(data_dir, rd)?;
         export_reading_periods(data_dir, rd)?;
         export_reading_metrics(data_dir, rd)?;
+        report.record("reading statistics", started);
+
+        let started = Instant::now();
         export_reading_calendar(data_dir, rd, library_repo).await?;
+        report.record("calendar", started);
+
+        let started = Instant::now();
         export_reading_completions(data_dir, rd, library_repo).await?;
+        report.record("recap", started);
+
+        let started = Instant::now();
+        export_reading_genres(data_dir, library_repo).await?;
+        export_reading_pace(data_dir, rd)?;
+        export_reading_finished(data_dir, rd)?;
+        export_reading_abandoned(data_dir, rd, library_repo).await?;
+        report.record("genres, pace, finished, abandoned", started);
+
+        if config.export_csv {
+            let started = Instant::now();
+            let library_titles: HashMap<String, String> = items
+                .iter()
+                .map(|item| (item.id.to_lowercase(), item.title.clone()))
+                .collect();
+            csv_export::export_csv_files(&output_dir.join("export"), rd, &library_titles)?;
+            report.record("csv", started);
+        }
     }
 
     info!("Static data export complete");
@@ -186,12 +307,15 @@ async fn export_item_details(
     library_repo: &LibraryRepository,
     reading_data: Option<&ReadingData>,
     items: &[crate::server::api::responses::library::LibraryListItem],
+    description_max_chars: usize,
 ) -> Result<()> {
     let items_dir = data_dir.join("items");
 
     let mut exported_ids = HashSet::new();
+    let pb = progress::bar(items.len() as u64, "Writing item pages:");
 
     for item in items {
+        pb.inc(1);
         if !media::is_canonical_item_id(&item.id) {
             log::warn!(
                 "Skipping detail export for non-canonical item id: {}",
@@ -200,15 +324,80 @@ async fn export_item_details(
             continue;
         }
 
-        let query = LibraryDetailQuery::new(&item.id, IncludeSet::all());
+        let query = LibraryDetailQuery::new(&item.id, IncludeSet::all())
+            .with_description_max_chars(description_max_chars);
         if let Some(detail) = library::detail(library_repo, &query, reading_data).await? {
             write_json(&items_dir.join(format!("{}.json", item.id)), &detail)?;
             exported_ids.insert(item.id.clone());
         }
     }
+    pb.finish_and_clear();
+
+    cleanup_stale_json(
+        &items_dir,
+        &exported_ids,
+        &["index", "books", "comics", "slugs", "redirects"],
+    )?;
+
+    Ok(())
+}
+
+// ── Series export ───────────────────────────────────────────────────────
+
+async fn export_series(
+    data_dir: &Path,
+    library_repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+    min_items: usize,
+) -> Result<()> {
+    let series_dir = data_dir.join("series");
+    let details = library::all_series_details(library_repo, reading_data, min_items).await?;
+
+    let index = LibrarySeriesListData {
+        series: details.iter().map(|detail| detail.series.clone()).collect(),
+    };
+    write_json(&series_dir.join("index.json"), &index)?;
+
+    let mut exported_slugs = HashSet::new();
+    for detail in &details {
+        write_json(
+            &series_dir.join(format!("{}.json", detail.series.slug)),
+            detail,
+        )?;
+        exported_slugs.insert(detail.series.slug.clone());
+    }
+    cleanup_stale_json(&series_dir, &exported_slugs, &["index"])?;
 
-    cleanup_stale_json(&items_dir, &exported_ids, &["index", "books", "comics"])?;
+    info!("Exported {} series", details.len());
+    Ok(())
+}
+
+// ── Author export ───────────────────────────────────────────────────────
+
+async fn export_authors(
+    data_dir: &Path,
+    library_repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+) -> Result<()> {
+    let authors_dir = data_dir.join("authors");
+    let details = library::all_author_details(library_repo, reading_data).await?;
+
+    let index = LibraryAuthorListData {
+        authors: details.iter().map(|detail| detail.author.clone()).collect(),
+    };
+    write_json(&authors_dir.join("index.json"), &index)?;
+
+    let mut exported_slugs = HashSet::new();
+    for detail in &details {
+        write_json(
+            &authors_dir.join(format!("{}.json", detail.author.slug)),
+            detail,
+        )?;
+        exported_slugs.insert(detail.author.slug.clone());
+    }
+    cleanup_stale_json(&authors_dir, &exported_slugs, &["index"])?;
 
+    info!("Exported {} authors", details.len());
     Ok(())
 }
 
@@ -232,8 +421,10 @@ async fn export_page_activity(
 ) -> Result<()> {
     let page_activity_dir = data_dir.join("items").join("page-activity");
     let mut exported_ids = HashSet::new();
+    let pb = progress::bar(items.len() as u64, "Writing page activity:");
 
     for item in items {
+        pb.inc(1);
         if !media::is_canonical_item_id(&item.id) {
             continue;
         }
@@ -266,6 +457,7 @@ async fn export_page_activity(
             exported_ids.insert(item.id.clone());
         }
     }
+    pb.finish_and_clear();
 
     cleanup_stale_json(&page_activity_dir, &exported_ids, &[])?;
 
@@ -600,7 +792,7 @@ async fn export_reading_completions(
                     scope,
                     selector: CompletionsSelector::Year(year),
                     group_by: CompletionsGroupBy::Month,
-                    includes: CompletionsIncludeSet::parse(Some("summary,share_assets"))
+                    includes: CompletionsIncludeSet::parse(Some("summary,share_assets,top_items"))
                         .expect("known-valid include tokens"),
                     tz: None,
                 },
@@ -622,9 +814,72 @@ async fn export_reading_completions(
     Ok(())
 }
 
+// ── Reading genres export ───────────────────────────────────────────────
+
+async fn export_reading_genres(data_dir: &Path, repo: &LibraryRepository) -> Result<()> {
+    let genres_dir = data_dir.join("reading").join("genres");
+
+    for scope in SCOPES {
+        let data = statistics::genres(repo, scope).await?;
+        write_json(&genres_dir.join(format!("{}.json", scope.as_str())), &data)?;
+    }
+
+    Ok(())
+}
+
+// ── Reading pace export ─────────────────────────────────────────────────
+
+fn export_reading_pace(data_dir: &Path, reading_data: &ReadingData) -> Result<()> {
+    let pace_dir = data_dir.join("reading").join("pace");
+
+    for scope in SCOPES {
+        let data = statistics::pace(reading_data, scope);
+        write_json(&pace_dir.join(format!("{}.json", scope.as_str())), &data)?;
+    }
+
+    Ok(())
+}
+
+// ── Reading finished export ─────────────────────────────────────────────
+
+fn export_reading_finished(data_dir: &Path, reading_data: &ReadingData) -> Result<()> {
+    let finished_dir = data_dir.join("reading").join("finished");
+
+    for scope in SCOPES {
+        let data = statistics::finished(reading_data, scope);
+        write_json(
+            &finished_dir.join(format!("{}.json", scope.as_str())),
+            &data,
+        )?;
+    }
+
+    Ok(())
+}
+
+// ── Reading abandoned export ────────────────────────────────────────────
+
+async fn export_reading_abandoned(
+    data_dir: &Path,
+    reading_data: &ReadingData,
+    repo: &LibraryRepository,
+) -> Result<()> {
+    let abandoned_dir = data_dir.join("reading").join("abandoned");
+
+    for scope in SCOPES {
+        let data = statistics::abandoned(reading_data, repo, scope).await?;
+        write_json(
+            &abandoned_dir.join(format!("{}.json", scope.as_str())),
+            &data,
+        )?;
+    }
+
+    Ok(())
+}
+
 // ── Item file export ─────────────────────────────────────────────────
 
-/// Copy item files to `output_dir/assets/files/{id}.{ext}` for static hosting.
+/// Link or copy item files to `output_dir/assets/files/{id}.{ext}` for static
+/// hosting. Files whose source is unchanged since the last export are kept.
 async fn export_item_files(output_dir: &Path, library_repo: &LibraryRepository) -> Result<()> {
     let files_dir = output_dir.join("assets").join("files");
     fs::create_dir_all(&files_dir)?;
@@ -633,6 +888,7 @@ async fn export_item_files(output_dir: &Path, library_repo: &LibraryRepository)
     let file_infos = library_repo.load_all_item_file_info().await?;
     let mut expected_file_names = HashSet::new();
     let mut copied_count = 0usize;
+    let mut unchanged_count = 0usize;
 
     for (id, file_path, format) in &file_infos {
         let Some(file_name) = media::item_file_basename(id, format) else {
@@ -651,6 +907,10 @@ async fn export_item_files(output_dir: &Path, library_repo: &LibraryRepository)
             continue;
         }
         let dest = files_dir.join(file_name);
+        if media::file_copy_is_current(source, &dest) {
+            unchanged_count += 1;
+            continue;
+        }
 
         if dest.exists() {
             if !dest.is_file() && !dest.is_symlink() {
@@ -663,7 +923,7 @@ async fn export_item_files(output_dir: &Path, library_repo: &LibraryRepository)
             }
         }
 
-        if let Err(e) = fs::copy(source, &dest) {
+        if let Err(e) = media::link_or_copy_file(source, &dest) {
             log::warn!("Failed to copy item file {:?} → {:?}: {}", source, dest, e);
         } else {
             copied_count += 1;
@@ -673,7 +933,11 @@ async fn export_item_files(output_dir: &Path, library_repo: &LibraryRepository)
     // Clean up stale files
     cleanup_stale_item_files(&files_dir, &expected_file_names)?;
 
-    info!("Exported {} item files", copied_count);
+    info!(
+        "Exported {} item files ({} unchanged)",
+        copied_count + unchanged_count,
+        unchanged_count
+    );
     Ok(())
 }
 
@@ -702,7 +966,7 @@ fn cleanup_stale_item_files(files_dir: &Path, valid_file_names: &HashSet<String>
 
 // ── Helpers ─────────────────────────────────────────────────────────────
 
-fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+pub(crate) fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
@@ -710,6 +974,18 @@ fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
     fs::write(path, json).with_context(|| format!("failed to write {:?}", path))
 }
 
+/// Write `map` to `path`, or remove a file left by an earlier export when
+/// there is nothing to write.
+fn write_json_unless_empty<T: Serialize>(path: &Path, map: &BTreeMap<&str, T>) -> Result<()> {
+    if !map.is_empty() {
+        write_json(path, map)
+    } else if path.exists() {
+        fs::remove_file(path).with_context(|| format!("failed to remove {:?}", path))
+    } else {
+        Ok(())
+    }
+}
+
 /// Remove `.json` files from `directory` whose stem is not in `valid_stems`
 /// and not in `protected` (e.g. "index" which is managed separately).
 fn cleanup_stale_json(
@@ -777,11 +1053,21 @@ mod tests {
         "/api/items",
         "/api/items/{id}",
         "/api/items/{id}/page-activity",
+        "/api/highlights",
+        "/api/series",
+        "/api/series/{slug}",
+        "/api/up-next",
+        "/api/authors",
+        "/api/authors/{slug}",
         "/api/reading/summary",
         "/api/reading/metrics",
         "/api/reading/available-periods",
         "/api/reading/calendar",
         "/api/reading/completions",
+        "/api/reading/genres",
+        "/api/reading/pace",
+        "/api/reading/finished",
+        "/api/reading/abandoned",
     ];
 
     /// API routes that intentionally have no static export equivalent.
@@ -10,7 +10,10 @@ use std::io::ErrorKind;
 use std::path::Path;
 use std::sync::LazyLock;
 
+use crate::pipeline::custom_css::{CUSTOM_CSS_URL, custom_css_version};
 use crate::pipeline::embed::{gz_decompress, is_precompressed};
+use crate::pipeline::html_includes::HtmlIncludes;
+use crate::pipeline::{icons, media};
 
 static FRONTEND_DIST: Dir = include_dir!("$OUT_DIR/frontend_dist");
 
@@ -20,6 +23,157 @@ static FRONTEND_VERSION: LazyLock<String> = LazyLock::new(|| {
     format!("{}-{:x}", env!("CARGO_PKG_VERSION"), hasher.finish())
 });
 
+/// File name of the generated web app manifest, at the site root.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Home screen labels longer than this get cut off, so longer site titles
+/// keep the default short name.
+const MAX_SHORT_NAME_CHARS: usize = 12;
+
+/// URL prefix of the app icons: generated from `--icon`, or the built-in ones.
+fn icons_base(custom_icons: bool) -> &'static str {
+    if custom_icons {
+        "/assets/icons"
+    } else {
+        "/core/icons"
+    }
+}
+
+/// Add favicon and touch icon links to the app shell's `<head>`.
+pub fn inject_icon_links(index_html: &str, custom_icons: bool) -> String {
+    let base = icons_base(custom_icons);
+    let mut links = String::new();
+    if custom_icons {
+        links.push_str(&format!(
+            "<link rel=\"icon\" href=\"{base}/favicon.ico\" sizes=\"48x48\" />\n        "
+        ));
+    }
+    links.push_str(&format!(
+        "<link rel=\"icon\" type=\"image/png\" href=\"{base}/icon-192.png\" />\n        "
+    ));
+    let touch_icon = if custom_icons {
+        "apple-touch-icon.png"
+    } else {
+        "icon-192.png"
+    };
+    links.push_str(&format!(
+        "<link rel=\"apple-touch-icon\" href=\"{base}/{touch_icon}\" />\n    "
+    ));
+
+    match index_html.find("</head>") {
+        Some(position) => {
+            let mut html = index_html.to_string();
+            html.insert_str(position, &links);
+            html
+        }
+        None => index_html.to_string(),
+    }
+}
+
+/// Link the `--custom-css` stylesheet as the last element of the app shell's
+/// `<head>`, after the built-in stylesheets, so its rules win ties.
+/// `version` busts browser caches when the stylesheet changes.
+pub fn inject_custom_css_link(index_html: &str, version: Option<&str>) -> String {
+    let Some(version) = version else {
+        return index_html.to_string();
+    };
+    let link = format!(
+        "<link rel=\"stylesheet\" id=\"koshelf-custom-css\" href=\"{CUSTOM_CSS_URL}?v={version}\" />\n    "
+    );
+    match index_html.find("</head>") {
+        Some(position) => {
+            let mut html = index_html.to_string();
+            html.insert_str(position, &link);
+            html
+        }
+        None => index_html.to_string(),
+    }
+}
+
+/// Web app manifest for installing KoShelf as a PWA, named after the site title.
+pub fn web_app_manifest(site_title: &str, custom_icons: bool) -> String {
+    let name = if site_title.trim().is_empty() {
+        "KoShelf"
+    } else {
+        site_title.trim()
+    };
+    let short_name = if name.chars().count() <= MAX_SHORT_NAME_CHARS {
+        name
+    } else {
+        "KoShelf"
+    };
+    let base = icons_base(custom_icons);
+    let icon = |size: u32, purpose: &str| {
+        serde_json::json!({
+            "src": format!("{base}/icon-{size}.png"),
+            "sizes": format!("{size}x{size}"),
+            "type": "image/png",
+            "purpose": purpose,
+        })
+    };
+
+    let manifest = serde_json::json!({
+        "name": name,
+        "short_name": short_name,
+        "description": "Your personal reading companion",
+        "start_url": "/",
+        "id": "/",
+        "display": "standalone",
+        "background_color": "#111827",
+        "theme_color": "#6366f1",
+        "icons": [
+            icon(192, "any"),
+            icon(192, "maskable"),
+            icon(512, "any"),
+            icon(512, "maskable"),
+        ],
+    });
+    serde_json::to_string_pretty(&manifest).unwrap_or_default()
+}
+
+/// File name of the generated service worker, at the site root.
+pub const SERVICE_WORKER_FILE: &str = "service-worker.js";
+
+const SERVICE_WORKER_TEMPLATE: &str = include_str!("../../assets/service-worker.js");
+
+static SERVICE_WORKER: LazyLock<String> = LazyLock::new(|| {
+    let mut paths = vec![
+        "./".to_string(),
+        "index.html".to_string(),
+        MANIFEST_FILE.to_string(),
+    ];
+    collect_precache_paths(&FRONTEND_DIST, &mut paths);
+    render_service_worker(&FRONTEND_VERSION, &paths)
+});
+
+/// Service worker that precaches the embedded frontend for offline use.
+/// Its cache names carry the frontend version, so a new build replaces the
+/// previously installed worker and purges its caches.
+pub fn service_worker_script() -> &'static str {
+    &SERVICE_WORKER
+}
+
+fn render_service_worker(version: &str, precache_paths: &[String]) -> String {
+    let paths = serde_json::to_string(precache_paths).unwrap_or_else(|_| "[]".to_string());
+    SERVICE_WORKER_TEMPLATE
+        .replace("__KOSHELF_VERSION__", version)
+        .replace("__KOSHELF_PRECACHE_PATHS__", &paths)
+}
+
+/// Every embedded file except `index.html` (listed separately) and source
+/// maps, which only developer tools request.
+fn collect_precache_paths(dir: &Dir<'_>, paths: &mut Vec<String>) {
+    for file in dir.files() {
+        let path = file.path().to_string_lossy().replace('\\', "/");
+        if !path.is_empty() && path != "index.html" && !path.ends_with(".map") {
+            paths.push(path);
+        }
+    }
+    for child in dir.dirs() {
+        collect_precache_paths(child, paths);
+    }
+}
+
 fn hash_embedded_dir(dir: &Dir<'_>, hasher: &mut DefaultHasher) {
     for file in dir.files() {
         file.path().hash(hasher);
@@ -32,21 +186,38 @@ fn hash_embedded_dir(dir: &Dir<'_>, hasher: &mut DefaultHasher) {
 
 /// In static-export mode, copy the embedded React frontend to the output
 /// directory and clean up legacy output artifacts.
-pub fn sync_static_frontend(output_dir: &Path, has_reading_data: bool) -> Result<()> {
-    let version_file = output_dir.join(".version");
-    let current_version = &*FRONTEND_VERSION;
-
-    let version_matches = fs::read_to_string(&version_file)
-        .map(|v| v.trim() == current_version)
-        .unwrap_or(false);
-
-    if !version_matches || !embedded_files_exist(output_dir, &FRONTEND_DIST) {
+pub fn sync_static_frontend(
+    output_dir: &Path,
+    has_reading_data: bool,
+    site_title: &str,
+    html_includes: &HtmlIncludes,
+) -> Result<()> {
+    if static_frontend_needs_sync(output_dir) {
         cleanup_removed_legacy_outputs(output_dir)?;
         copy_embedded_frontend_dir(output_dir, &FRONTEND_DIST)?;
-        fs::write(&version_file, current_version)?;
-        info!("Static frontend updated (version {})", current_version);
+        media::write_replacing(
+            &output_dir.join(SERVICE_WORKER_FILE),
+            service_worker_script(),
+        )?;
+        media::write_replacing(&output_dir.join(".version"), &*FRONTEND_VERSION)?;
+        info!("Static frontend updated (version {})", *FRONTEND_VERSION);
     }
 
+    // The shell and manifest follow the site title, icons and includes, which
+    // can change without a new build.
+    let custom_icons = icons::has_custom_icons(&output_dir.join("assets").join("icons"));
+    let custom_css = custom_css_version(&output_dir.join("assets"));
+    write_index_html(
+        output_dir,
+        custom_icons,
+        custom_css.as_deref(),
+        html_includes,
+    )?;
+    media::write_replacing(
+        &output_dir.join(MANIFEST_FILE),
+        web_app_manifest(site_title, custom_icons),
+    )?;
+
     if !has_reading_data {
         let recap_assets_dir = output_dir.join("assets").join("recap");
         if let Err(error) = fs::remove_dir_all(&recap_assets_dir)
@@ -59,6 +230,17 @@ pub fn sync_static_frontend(output_dir: &Path, has_reading_data: bool) -> Result
     Ok(())
 }
 
+/// Whether the output directory is missing the current embedded frontend build.
+pub fn static_frontend_needs_sync(output_dir: &Path) -> bool {
+    let version_matches = fs::read_to_string(output_dir.join(".version"))
+        .map(|v| v.trim() == *FRONTEND_VERSION)
+        .unwrap_or(false);
+
+    !version_matches
+        || !embedded_files_exist(output_dir, &FRONTEND_DIST)
+        || !output_dir.join(SERVICE_WORKER_FILE).exists()
+}
+
 fn cleanup_removed_legacy_outputs(output_dir: &Path) -> Result<()> {
     for relative_dir in [
         "books",
@@ -79,12 +261,7 @@ fn cleanup_removed_legacy_outputs(output_dir: &Path) -> Result<()> {
         }
     }
 
-    for relative_file in [
-        "404.html",
-        "service-worker.js",
-        "version.txt",
-        "cache-manifest.json",
-    ] {
+    for relative_file in ["404.html", "version.txt", "cache-manifest.json"] {
         let file = output_dir.join(relative_file);
         if let Err(error) = fs::remove_file(&file)
             && error.kind() != ErrorKind::NotFound
@@ -119,7 +296,8 @@ fn inject_server_mode_script(index_html: &str, server_mode: &str) -> String {
 
 fn write_embedded_frontend_file(output_dir: &Path, file: &File<'_>) -> Result<()> {
     let relative_path = file.path().to_string_lossy().replace('\\', "/");
-    if relative_path.is_empty() {
+    // index.html is written by `write_index_html` on every export.
+    if relative_path.is_empty() || relative_path == "index.html" {
         return Ok(());
     }
 
@@ -128,21 +306,33 @@ fn write_embedded_frontend_file(output_dir: &Path, file: &File<'_>) -> Result<()
         fs::create_dir_all(parent)?;
     }
 
-    if relative_path == "index.html" {
-        let raw = gz_decompress(file.contents())?;
-        let source = std::str::from_utf8(&raw).context("Embedded React index.html is not UTF-8")?;
-        let injected = inject_server_mode_script(source, "external");
-        fs::write(&output_path, injected)?;
-    } else if is_precompressed(&relative_path) {
+    if is_precompressed(&relative_path) {
         let raw = gz_decompress(file.contents())?;
-        fs::write(&output_path, raw)?;
+        media::write_replacing(&output_path, raw)?;
     } else {
-        fs::write(&output_path, file.contents())?;
+        media::write_replacing(&output_path, file.contents())?;
     }
 
     Ok(())
 }
 
+fn write_index_html(
+    output_dir: &Path,
+    custom_icons: bool,
+    custom_css: Option<&str>,
+    html_includes: &HtmlIncludes,
+) -> Result<()> {
+    let Some(file) = FRONTEND_DIST.get_file("index.html") else {
+        return Ok(());
+    };
+    let raw = gz_decompress(file.contents())?;
+    let source = std::str::from_utf8(&raw).context("Embedded React index.html is not UTF-8")?;
+    let html = inject_icon_links(&inject_server_mode_script(source, "external"), custom_icons);
+    let html = inject_custom_css_link(&html_includes.apply(&html), custom_css);
+    media::write_replacing(&output_dir.join("index.html"), html)?;
+    Ok(())
+}
+
 fn embedded_files_exist(output_dir: &Path, dir: &Dir<'_>) -> bool {
     dir.files().all(|f| {
         let path = f.path().to_string_lossy().replace('\\', "/");
@@ -161,3 +351,69 @@ fn copy_embedded_frontend_dir(output_dir: &Path, dir: &Dir<'_>) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_is_named_after_the_site_title() {
+        let short: serde_json::Value =
+            serde_json::from_str(&web_app_manifest("Shelf", false)).unwrap();
+        assert_eq!(short["name"], "Shelf");
+        assert_eq!(short["short_name"], "Shelf");
+        assert_eq!(short["icons"].as_array().unwrap().len(), 4);
+        assert_eq!(short["icons"][0]["src"], "/core/icons/icon-192.png");
+
+        let long: serde_json::Value =
+            serde_json::from_str(&web_app_manifest("Paul's Reading Journey", true)).unwrap();
+        assert_eq!(long["name"], "Paul's Reading Journey");
+        assert_eq!(long["short_name"], "KoShelf");
+        assert_eq!(long["icons"][3]["src"], "/assets/icons/icon-512.png");
+
+        let empty: serde_json::Value = serde_json::from_str(&web_app_manifest(" ", false)).unwrap();
+        assert_eq!(empty["name"], "KoShelf");
+    }
+
+    #[test]
+    fn icon_links_point_at_generated_or_builtin_icons() {
+        let html = "<html><head><title>KoShelf</title></head><body></body></html>";
+
+        let builtin = inject_icon_links(html, false);
+        assert!(
+            builtin.contains(r#"<link rel="apple-touch-icon" href="/core/icons/icon-192.png" />"#)
+        );
+        assert!(!builtin.contains("favicon.ico"));
+        assert!(builtin.find("rel=\"icon\"").unwrap() < builtin.find("</head>").unwrap());
+
+        let custom = inject_icon_links(html, true);
+        assert!(custom.contains(r#"href="/assets/icons/favicon.ico""#));
+        assert!(custom.contains(r#"href="/assets/icons/apple-touch-icon.png""#));
+    }
+
+    #[test]
+    fn custom_css_is_linked_after_the_built_in_styles() {
+        let html = r#"<html><head><link rel="stylesheet" href="/core/css/app.css"></head></html>"#;
+
+        assert_eq!(inject_custom_css_link(html, None), html);
+
+        let linked = inject_custom_css_link(html, Some("0123abcd"));
+        let custom = linked
+            .find(r#"href="/assets/custom.css?v=0123abcd""#)
+            .unwrap();
+        assert!(linked.find("/core/css/app.css").unwrap() < custom);
+        assert!(custom < linked.find("</head>").unwrap());
+    }
+
+    #[test]
+    fn service_worker_embeds_version_and_precache_list() {
+        let script = render_service_worker(
+            "2026.7.1-abc",
+            &["./".to_string(), "core/js/index-1234.js".to_string()],
+        );
+
+        assert!(script.contains("const VERSION = '2026.7.1-abc';"));
+        assert!(script.contains(r#"const PRECACHE_PATHS = ["./","core/js/index-1234.js"];"#));
+        assert!(!script.contains("__KOSHELF_"));
+    }
+}
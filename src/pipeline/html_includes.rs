@@ -0,0 +1,86 @@
+//! HTML snippets from `--head-include` and `--footer-include`, inserted
+//! verbatim into the app shell for analytics tags, web font links and the like.
+//!
+//! The snippets are trusted configuration, so they are not sanitized. They
+//! still run under the site's Content-Security-Policy.
+
+use anyhow::{Context, Result};
+use log::info;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// Includes shared by the server and the watcher, which reloads them when a
+/// `--watch-path` changes.
+pub type SharedHtmlIncludes = Arc<RwLock<HtmlIncludes>>;
+
+/// Snippets for the end of `<head>` and the end of `<body>`.
+#[derive(Clone, Debug, Default)]
+pub struct HtmlIncludes {
+    pub head: Option<String>,
+    pub footer: Option<String>,
+}
+
+impl HtmlIncludes {
+    /// Read the configured snippet files.
+    pub fn load(head: Option<&Path>, footer: Option<&Path>) -> Result<Self> {
+        Ok(Self {
+            head: head.map(|path| read_snippet(path, "head")).transpose()?,
+            footer: footer
+                .map(|path| read_snippet(path, "footer"))
+                .transpose()?,
+        })
+    }
+
+    /// Insert the snippets into the app shell: the head snippet at the end of
+    /// `<head>`, the footer snippet before `</body>`. Apply before linking
+    /// `--custom-css`, which stays last.
+    pub fn apply(&self, index_html: &str) -> String {
+        let mut html = index_html.to_string();
+        if let Some(head) = &self.head {
+            insert_before(&mut html, "</head>", head);
+        }
+        if let Some(footer) = &self.footer {
+            insert_before(&mut html, "</body>", footer);
+        }
+        html
+    }
+}
+
+fn read_snippet(path: &Path, kind: &str) -> Result<String> {
+    let snippet = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {} include {:?}", kind, path))?;
+    info!("Including {} HTML from {:?}", kind, path);
+    Ok(snippet)
+}
+
+fn insert_before(html: &mut String, tag: &str, snippet: &str) {
+    if let Some(position) = html.rfind(tag) {
+        html.insert_str(position, &format!("{}\n    ", snippet.trim()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snippets_are_inserted_verbatim_at_the_end_of_head_and_body() {
+        let html =
+            "<html><head><title>KoShelf</title></head><body><div id=\"root\"></div></body></html>";
+        assert_eq!(HtmlIncludes::default().apply(html), html);
+
+        let includes = HtmlIncludes {
+            head: Some("<link rel=\"preconnect\" href=\"https://fonts.example\">\n".to_string()),
+            footer: Some("<script src=\"/stats.js\" data-site=\"a&b\"></script>".to_string()),
+        };
+        let applied = includes.apply(html);
+
+        let head = applied.find("<link rel=\"preconnect\"").unwrap();
+        assert!(applied.find("<title>").unwrap() < head);
+        assert!(head < applied.find("</head>").unwrap());
+        let footer = applied.find("data-site=\"a&b\"").unwrap();
+        assert!(applied.find("id=\"root\"").unwrap() < footer);
+        assert!(footer < applied.find("</body>").unwrap());
+    }
+}
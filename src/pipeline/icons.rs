@@ -0,0 +1,149 @@
+//! Favicon and app icons generated from a user-supplied image (`--icon`).
+
+use anyhow::{Context, Result};
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, ExtendedColorType, ImageFormat, Rgba, RgbaImage};
+use log::info;
+use std::fs;
+use std::io::{Cursor, ErrorKind};
+use std::path::Path;
+
+use crate::pipeline::media;
+
+/// PNG app icons referenced by the web app manifest, by edge length.
+const APP_ICON_SIZES: [u32; 2] = [192, 512];
+const APPLE_TOUCH_ICON_SIZE: u32 = 180;
+const FAVICON_SIZES: [u32; 3] = [16, 32, 48];
+
+/// iOS fills transparent pixels with black, so the touch icon is flattened
+/// onto the manifest background color instead.
+const APPLE_TOUCH_BACKGROUND: Rgba<u8> = Rgba([0x11, 0x18, 0x27, 0xff]);
+
+/// Generate the icon set from `source` into `icons_dir`, or remove a
+/// previously generated set when no source is configured so the built-in
+/// icons are used again.
+pub fn sync_custom_icons(source: Option<&Path>, icons_dir: &Path) -> Result<()> {
+    let Some(source) = source else {
+        if let Err(error) = fs::remove_dir_all(icons_dir)
+            && error.kind() != ErrorKind::NotFound
+        {
+            return Err(error.into());
+        }
+        return Ok(());
+    };
+
+    let image =
+        image::open(source).with_context(|| format!("Failed to load icon image {:?}", source))?;
+    let square = pad_to_square(&image);
+
+    fs::create_dir_all(icons_dir)?;
+
+    for size in APP_ICON_SIZES {
+        write_png(
+            &resize(&square, size),
+            &icons_dir.join(format!("icon-{size}.png")),
+        )?;
+    }
+
+    let mut touch_icon = RgbaImage::from_pixel(
+        APPLE_TOUCH_ICON_SIZE,
+        APPLE_TOUCH_ICON_SIZE,
+        APPLE_TOUCH_BACKGROUND,
+    );
+    imageops::overlay(
+        &mut touch_icon,
+        &resize(&square, APPLE_TOUCH_ICON_SIZE),
+        0,
+        0,
+    );
+    write_png(&touch_icon, &icons_dir.join("apple-touch-icon.png"))?;
+
+    let frames = FAVICON_SIZES
+        .iter()
+        .map(|&size| {
+            let frame = resize(&square, size);
+            IcoFrame::as_png(frame.as_raw(), size, size, ExtendedColorType::Rgba8)
+        })
+        .collect::<image::ImageResult<Vec<_>>>()?;
+    let mut favicon = Vec::new();
+    IcoEncoder::new(&mut favicon).encode_images(&frames)?;
+    media::write_replacing(&icons_dir.join("favicon.ico"), favicon)?;
+
+    info!("Generated app icons from {:?}", source);
+    Ok(())
+}
+
+/// Whether `icons_dir` holds a generated icon set.
+pub fn has_custom_icons(icons_dir: &Path) -> bool {
+    icons_dir.join("icon-192.png").is_file()
+}
+
+/// Center the image on a transparent square canvas so nothing is cropped.
+fn pad_to_square(image: &DynamicImage) -> RgbaImage {
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    if width == height {
+        return rgba;
+    }
+
+    let side = width.max(height);
+    let mut canvas = RgbaImage::from_pixel(side, side, Rgba([0, 0, 0, 0]));
+    imageops::overlay(
+        &mut canvas,
+        &rgba,
+        i64::from((side - width) / 2),
+        i64::from((side - height) / 2),
+    );
+    canvas
+}
+
+fn resize(square: &RgbaImage, size: u32) -> RgbaImage {
+    imageops::resize(square, size, size, FilterType::Lanczos3)
+}
+
+fn write_png(image: &RgbaImage, path: &Path) -> Result<()> {
+    let mut buffer = Cursor::new(Vec::new());
+    image.write_to(&mut buffer, ImageFormat::Png)?;
+    media::write_replacing(path, buffer.into_inner())
+        .with_context(|| format!("Failed to write icon {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_square_sources_are_padded_and_all_sizes_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("logo.png");
+        RgbaImage::from_pixel(300, 100, Rgba([255, 0, 0, 255]))
+            .save(&source)
+            .unwrap();
+        let icons_dir = dir.path().join("icons");
+
+        sync_custom_icons(Some(&source), &icons_dir).unwrap();
+
+        let icon = image::open(icons_dir.join("icon-192.png"))
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(icon.dimensions(), (192, 192));
+        // Padding above the wide logo stays transparent, the middle is the logo.
+        assert_eq!(icon.get_pixel(96, 5)[3], 0);
+        assert_eq!(icon.get_pixel(96, 96), &Rgba([255, 0, 0, 255]));
+
+        let touch = image::open(icons_dir.join("apple-touch-icon.png"))
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(touch.dimensions(), (180, 180));
+        assert_eq!(touch.get_pixel(90, 2), &APPLE_TOUCH_BACKGROUND);
+
+        assert!(icons_dir.join("icon-512.png").is_file());
+        assert!(icons_dir.join("favicon.ico").is_file());
+        assert!(has_custom_icons(&icons_dir));
+
+        sync_custom_icons(None, &icons_dir).unwrap();
+        assert!(!icons_dir.exists());
+        assert!(!has_custom_icons(&icons_dir));
+    }
+}
@@ -1,5 +1,4 @@
 use anyhow::Result;
-use indicatif::{ProgressBar, ProgressStyle};
 use log::{info, warn};
 use std::sync::Arc;
 use std::time::Instant;
@@ -8,6 +7,7 @@ use crate::app::config::SiteConfig;
 use crate::pipeline::ingest::metadata::MetadataIndices;
 use crate::pipeline::ingest::processor::{ItemProcessor, process_single_item};
 use crate::pipeline::media::MediaDirs;
+use crate::pipeline::progress;
 use crate::source::scanner::CollectedItem;
 use crate::store::sqlite::repo::LibraryRepository;
 
@@ -33,7 +33,8 @@ impl IngestStats {
     }
 }
 
-const INGEST_CONCURRENCY: usize = 8;
+/// Default number of items parsed and ingested concurrently.
+pub(crate) const DEFAULT_SCAN_JOBS: usize = 8;
 
 pub(crate) async fn ingest_items(
     items: &[CollectedItem],
@@ -63,20 +64,16 @@ pub(super) async fn ingest_items_with_metadata_indices(
     info!("Ingesting {} items...", items.len());
     let start = Instant::now();
 
-    let pb = ProgressBar::new(items.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{msg} {bar:30.cyan/blue} {pos}/{len}")
-            .unwrap()
-            .progress_chars("━╸─"),
-    );
-    pb.set_message("Ingesting library:");
+    let pb = progress::bar(items.len() as u64, "Ingesting library:");
 
-    let (tx, rx) = tokio::sync::mpsc::channel::<CollectedItem>(INGEST_CONCURRENCY * 2);
+    // Each worker holds at most one parsed item (and its embedded cover) at a
+    // time, so this bounds both open files and cover buffers.
+    let jobs = config.scan_jobs.max(1);
+    let (tx, rx) = tokio::sync::mpsc::channel::<CollectedItem>(jobs * 2);
     let rx = Arc::new(tokio::sync::Mutex::new(rx));
 
     let mut workers = tokio::task::JoinSet::new();
-    for _ in 0..INGEST_CONCURRENCY {
+    for _ in 0..jobs {
         let rx = rx.clone();
         let repo = repo.clone();
         let config = config.clone();
@@ -165,22 +162,54 @@ mod tests {
         SiteConfig {
             output_dir: output_dir.to_path_buf(),
             site_title: "KoShelf".to_string(),
+            html_includes: Default::default(),
             include_unread: true,
+            scan_jobs: 2,
+            normalize_authors: false,
+            author_aliases: Default::default(),
+            id_scheme: Default::default(),
+            description_format: Default::default(),
+            description_max_chars: 0,
+            cover_fit: Default::default(),
+            series_min_items: 2,
+            demo: false,
+            page_size: 0,
+            date_format: Default::default(),
+            duration_granularity: Default::default(),
+            duration_max_units: 0,
+            on_rebuild: None,
+            watch_paths: Vec::new(),
+            asset_sources: Default::default(),
             library_paths: vec![library_path.to_path_buf()],
             metadata_location: MetadataLocation::InBookFolder,
             statistics_db_paths: vec![],
             kobo_db_path: None,
             heatmap_scale_max: None,
-            time_config: TimeConfig::from_cli(&None, &None).expect("time config"),
+            time_config: TimeConfig::from_cli(&None, &None, &None).expect("time config"),
+            stats_config: Default::default(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
             min_pages_per_day: None,
             min_time_per_day: None,
+            session_min: None,
+            session_max: None,
+            session_max_mode: Default::default(),
+            stats_from: None,
+            stats_to: None,
+            completion_config: Default::default(),
+            completion_overrides: Default::default(),
             include_all_stats: false,
+            keep_ghost_stats: false,
+            match_stats_by_title: false,
+            stats_exclude: Vec::new(),
             is_internal_server: false,
             language: "en_US".to_string(),
             use_stable_page_metadata: true,
             auth_enabled: false,
             writeback_enabled: false,
             include_files: false,
+            export_csv: false,
+            export_all_annotations: false,
             runtime_data_policy,
         }
     }
@@ -407,4 +436,66 @@ mod tests {
         assert_eq!(items.len(), 1);
         assert_eq!(items[0].title, "Compressed FB2");
     }
+
+    #[tokio::test]
+    async fn redirects_old_links_when_a_book_is_read_under_a_new_id() {
+        let library_dir = tempfile::tempdir().expect("library dir");
+        let output_dir = tempfile::tempdir().expect("output dir");
+
+        let book_path = library_dir.path().join("Book.epub");
+        write_minimal_epub(&book_path);
+
+        let repo = test_repo().await;
+        let config = test_config(library_dir.path(), output_dir.path());
+        let media_dirs = resolve_media_dirs(output_dir.path(), config.is_internal_server);
+        std::fs::create_dir_all(&media_dirs.covers_dir).expect("covers dir");
+        let items = [CollectedItem {
+            path: book_path,
+            format: LibraryItemFormat::Epub,
+            kobo_hints: None,
+        }];
+
+        ingest_items(&items, &config, &repo, &media_dirs)
+            .await
+            .expect("first ingest");
+        let old_id = repo
+            .list_items(&LibraryListQuery::default())
+            .await
+            .expect("list items")[0]
+            .id
+            .clone();
+
+        // KOReader's checksum takes over from the one derived from the file.
+        let new_id = "0123456789abcdef0123456789abcdef";
+        let metadata_path = library_dir
+            .path()
+            .join("Book.sdr")
+            .join("metadata.epub.lua");
+        std::fs::create_dir_all(metadata_path.parent().expect("sdr")).expect("sdr dir");
+        std::fs::write(
+            &metadata_path,
+            format!("return {{ partial_md5_checksum = \"{new_id}\" }}\n"),
+        )
+        .expect("metadata file");
+
+        let stats = ingest_items(&items, &config, &repo, &media_dirs)
+            .await
+            .expect("second ingest");
+        assert_eq!(stats.upserted, 1);
+        assert_eq!(stats.errors, 0);
+
+        let items = repo
+            .list_items(&LibraryListQuery::default())
+            .await
+            .expect("list items");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, new_id);
+        assert_eq!(
+            repo.retired_slug_target(&old_id)
+                .await
+                .expect("retired target")
+                .as_deref(),
+            Some(new_id)
+        );
+    }
 }
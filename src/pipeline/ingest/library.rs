@@ -1,17 +1,24 @@
 use anyhow::Result;
 use log::{info, warn};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
-use crate::app::config::SiteConfig;
+use crate::app::config::{CoverFit, DescriptionFormat, IdScheme, SiteConfig};
 use crate::pipeline::ingest::batch::{IngestStats, ingest_items_with_metadata_indices};
 use crate::pipeline::ingest::cleanup::delete_item_and_media;
 use crate::pipeline::ingest::metadata::MetadataIndices;
 use crate::pipeline::ingest::reconcile::build_library_sync_plan;
 use crate::pipeline::media::{self, MediaDirs};
-use crate::source::scanner::{CollectionOptions, collect_paths};
+use crate::shelf::library::slugs::{SlugCandidate, assign_slugs};
+use crate::source::scanner::{CollectedItem, CollectionOptions, collect_paths};
 use crate::store::sqlite::repo::LibraryRepository;
 
+const AUTHOR_SETTINGS: &str = "authors";
+const DEMO_SETTING: &str = "demo";
+const DESCRIPTION_SETTING: &str = "description_format";
+const COVER_FIT_SETTING: &str = "cover_fit";
+
 /// Summary of a library sync: what changed since the last run.
 #[derive(Debug, Default)]
 pub(crate) struct LibrarySyncResult {
@@ -39,6 +46,7 @@ pub(crate) async fn sync_library(
         },
     )
     .await;
+    reingest_on_settings_change(config, repo, media_dirs).await?;
     let stored_fingerprints = repo.load_all_fingerprints().await?;
     let metadata_indices = Arc::new(MetadataIndices::new(&config.metadata_location)?);
 
@@ -72,6 +80,8 @@ pub(crate) async fn sync_library(
         .await;
     }
 
+    prune_parsed_book_info(repo, &fs_items).await;
+
     let ingest_stats = if plan.items_to_ingest.is_empty() {
         None
     } else {
@@ -103,3 +113,115 @@ pub(crate) async fn sync_library(
         ingest_stats,
     })
 }
+
+/// Bring item slugs in line with the ID scheme: assign them (keeping existing
+/// ones where possible) under `slug`, clear them under `hash`. Only writes
+/// when something changed.
+pub(crate) async fn sync_slugs(config: &SiteConfig, repo: &LibraryRepository) -> Result<()> {
+    let rows = repo.load_slug_inputs().await?;
+    let current: HashMap<String, String> = rows
+        .iter()
+        .filter_map(|(id, _, _, slug)| Some((id.clone(), slug.clone()?)))
+        .collect();
+
+    let slugs = match config.id_scheme {
+        IdScheme::Hash => HashMap::new(),
+        IdScheme::Slug => {
+            let candidates: Vec<SlugCandidate> = rows
+                .into_iter()
+                .map(|(id, title, authors, slug)| SlugCandidate {
+                    id,
+                    title,
+                    authors: authors.0,
+                    current: slug,
+                })
+                .collect();
+            assign_slugs(&candidates, &repo.load_retired_slugs().await?)
+        }
+    };
+
+    if slugs != current {
+        info!("Updating URL slugs ({} items)", slugs.len());
+        repo.replace_item_slugs(&slugs).await?;
+    }
+    Ok(())
+}
+
+/// Drop cached parse results for files that are no longer in the library.
+/// Ingest rewrites items according to these settings, so items stored under
+/// other values are ingested again.
+async fn reingest_on_settings_change(
+    config: &SiteConfig,
+    repo: &LibraryRepository,
+    media_dirs: &MediaDirs,
+) -> Result<()> {
+    let settings = [
+        (
+            AUTHOR_SETTINGS,
+            format!(
+                "normalize={}\n{}",
+                config.normalize_authors,
+                config.author_aliases.fingerprint()
+            ),
+        ),
+        (DEMO_SETTING, config.demo.to_string()),
+        (
+            DESCRIPTION_SETTING,
+            format!("{:?}", config.description_format),
+        ),
+        (COVER_FIT_SETTING, format!("{:?}", config.cover_fit)),
+    ];
+
+    let mut changed = Vec::new();
+    for (name, value) in &settings {
+        let stored = repo.load_ingest_setting(name).await?;
+        // Libraries read before these settings existed used their defaults.
+        let stored = match stored {
+            None if *name == DESCRIPTION_SETTING => {
+                Some(format!("{:?}", DescriptionFormat::default()))
+            }
+            None if *name == COVER_FIT_SETTING => Some(format!("{:?}", CoverFit::default())),
+            stored => stored,
+        };
+        if stored.as_deref() != Some(value.as_str()) {
+            changed.push((*name, value, stored.is_some()));
+        }
+    }
+
+    if changed.iter().any(|(_, _, had_value)| *had_value) {
+        info!("Ingest settings changed, re-reading the library");
+        repo.delete_all_fingerprints().await?;
+    }
+    if changed.iter().any(|(name, _, had_value)| {
+        (*name == DEMO_SETTING || *name == COVER_FIT_SETTING) && *had_value
+    }) {
+        // Covers are only regenerated when older than their book, so drop the
+        // covers made in the previous mode or with the previous fit.
+        std::fs::remove_dir_all(&media_dirs.covers_dir)?;
+        std::fs::create_dir_all(&media_dirs.covers_dir)?;
+    }
+    for (name, value, _) in changed {
+        repo.upsert_ingest_setting(name, value).await?;
+    }
+    Ok(())
+}
+
+async fn prune_parsed_book_info(repo: &LibraryRepository, fs_items: &[CollectedItem]) {
+    let cached_paths = match repo.load_parsed_book_info_paths().await {
+        Ok(paths) => paths,
+        Err(e) => {
+            warn!("Failed to load parsed book info cache: {}", e);
+            return;
+        }
+    };
+    let fs_paths: HashSet<String> = fs_items
+        .iter()
+        .map(|item| item.path.to_string_lossy().into_owned())
+        .collect();
+
+    for path in cached_paths.iter().filter(|p| !fs_paths.contains(*p)) {
+        if let Err(e) = repo.delete_parsed_book_info(path).await {
+            warn!("Failed to drop parsed book info for {}: {}", path, e);
+        }
+    }
+}
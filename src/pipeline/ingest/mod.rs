@@ -8,7 +8,7 @@ mod processor;
 mod reconcile;
 mod statistics;
 
-pub(crate) use batch::ingest_items;
+pub(crate) use batch::{DEFAULT_SCAN_JOBS, ingest_items};
 pub(crate) use cleanup::delete_item_for_book_path;
-pub(crate) use library::sync_library;
-pub(crate) use statistics::load_reading_data;
+pub(crate) use library::{sync_library, sync_slugs};
+pub(crate) use statistics::{StatisticsParseCache, load_reading_data};
@@ -3,16 +3,21 @@ use log::{debug, warn};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::app::config::SiteConfig;
+use crate::app::config::{DescriptionFormat, SiteConfig};
+use crate::pipeline::demo;
 use crate::pipeline::ingest::batch::IngestStats;
+use crate::pipeline::ingest::cleanup::delete_item_and_media;
 use crate::pipeline::ingest::metadata::{MetadataIndices, locate_metadata_path};
 use crate::pipeline::media::{self, MediaDirs};
-use crate::shelf::library::upsert_single_item;
+use crate::shelf::library::{normalize_author_names, upsert_single_item};
 use crate::shelf::models::{BookInfo, KoReaderMetadata, LibraryItem, LibraryItemFormat};
+use crate::shelf::utils::{contains_html_tags, render_markdown_description};
+use crate::source::FileFingerprint;
+use crate::source::fingerprints::file_added_unix;
 use crate::source::kobo::KoboFileHints;
 use crate::source::koreader::merge::{normalize_partial_md5, resolve_canonical_partial_md5};
 use crate::source::koreader::{LuaParser, calculate_partial_md5};
-use crate::source::parsers::{ComicParser, EpubParser, Fb2Parser, MobiParser};
+use crate::source::parsers::{ComicParser, EpubParser, Fb2Parser, MobiParser, sniff_format};
 use crate::source::scanner::CollectedItem;
 use crate::store::sqlite::repo::LibraryRepository;
 
@@ -39,8 +44,17 @@ impl ItemProcessor {
         }
     }
 
+    /// Parse with the parser for the file's actual contents; the extension
+    /// only decides when the contents are ambiguous.
     async fn parse_book_info(&self, format: LibraryItemFormat, path: &Path) -> Result<BookInfo> {
-        match format {
+        let content_format = sniff_format(path, format)?;
+        if content_format != format {
+            debug!(
+                "{:?} contains {:?} data, parsing it as such",
+                path, content_format
+            );
+        }
+        match content_format {
             LibraryItemFormat::Epub => self.epub_parser.parse(path).await,
             LibraryItemFormat::Fb2 => self.fb2_parser.parse(path).await,
             LibraryItemFormat::Cbz | LibraryItemFormat::Cbr => self.comic_parser.parse(path).await,
@@ -102,6 +116,17 @@ impl ItemProcessor {
     }
 }
 
+/// Apply `--description-format` to a description the parser already sanitized.
+fn render_description(description: String, format: DescriptionFormat) -> String {
+    match format {
+        DescriptionFormat::Html => description,
+        DescriptionFormat::Auto if contains_html_tags(&description) => description,
+        DescriptionFormat::Markdown | DescriptionFormat::Auto => {
+            render_markdown_description(&description)
+        }
+    }
+}
+
 pub(super) async fn process_single_item(
     item: &CollectedItem,
     processor: &ItemProcessor,
@@ -117,14 +142,51 @@ pub(super) async fn process_single_item(
     let path = item.path.as_path();
     let format = item.format;
 
-    let mut book_info = match processor.parse_book_info(format, path).await {
-        Ok(info) => info,
-        Err(e) => {
-            warn_parse_failure(format, path, &e, item.kobo_hints.as_ref());
-            stats.errors = 1;
-            return stats;
-        }
+    // Reuse the previous parse when the book file itself is unchanged, e.g.
+    // when only its KOReader metadata was updated.
+    let book_fp = FileFingerprint::capture(path).ok();
+    let cached_book_info = match book_fp {
+        Some(ref fp) => repo.load_parsed_book_info(fp).await.unwrap_or_else(|e| {
+            debug!("Ignoring cached book info for {:?}: {}", path, e);
+            None
+        }),
+        None => None,
     };
+    let from_cache = cached_book_info.is_some();
+
+    let mut book_info = match cached_book_info {
+        Some(info) => info,
+        None => match processor.parse_book_info(format, path).await {
+            Ok(info) => info,
+            Err(e) => {
+                warn_parse_failure(format, path, &e, item.kobo_hints.as_ref());
+                stats.errors = 1;
+                return stats;
+            }
+        },
+    };
+    let cover_data = book_info.cover_data.take();
+    if !from_cache
+        && let Some(ref fp) = book_fp
+        && let Err(e) = repo.upsert_parsed_book_info(fp, &book_info).await
+    {
+        warn!("Failed to cache parsed book info for {:?}: {}", path, e);
+    }
+
+    // Applied after caching so the cache keeps names as the file has them.
+    let first_author = book_info.authors.first().cloned();
+    normalize_author_names(
+        &mut book_info.authors,
+        config.normalize_authors,
+        &config.author_aliases,
+    );
+    // The file's sort name describes the author as written there.
+    if book_info.authors.first() != first_author.as_ref() {
+        book_info.author_sort = None;
+    }
+    if let Some(description) = book_info.description.take() {
+        book_info.description = Some(render_description(description, config.description_format));
+    }
 
     let metadata_path = processor.locate_metadata_path(path, format);
     let koreader_metadata = processor.parse_koreader_metadata(metadata_path.clone());
@@ -159,14 +221,59 @@ pub(super) async fn process_single_item(
         _ => {}
     }
 
-    let cover_data = book_info.cover_data.take();
-    let item = LibraryItem {
+    // The file was indexed under another ID before (e.g. it changed under the
+    // hash scheme): replace that item and keep its links pointing here.
+    let predecessor_keys = match repo.find_fingerprint_by_book_path(&path_str).await {
+        Ok(Some(fp)) if fp.item_id != item_id => {
+            let keys = repo.load_item_keys(&fp.item_id).await.unwrap_or_else(|e| {
+                warn!("Failed to load keys of replaced item {}: {}", fp.item_id, e);
+                Vec::new()
+            });
+            delete_item_and_media(
+                repo,
+                &fp.item_id,
+                media_dirs,
+                config.is_internal_server,
+                &format!("now indexed as {item_id}"),
+            )
+            .await;
+            keys
+        }
+        Ok(_) => Vec::new(),
+        Err(e) => {
+            warn!("Failed to look up previous item for {:?}: {}", path, e);
+            Vec::new()
+        }
+    };
+
+    let cover_path = media_dirs.covers_dir.join(format!("{}.webp", item_id));
+    let cover_data = if config.demo {
+        demo::placeholder_cover(&item_id).ok()
+    } else if !media::cover_needs_generation(path, &cover_path) {
+        // Drop an up-to-date cover right away instead of holding it until the
+        // upsert finished.
+        None
+    } else if from_cache {
+        // Cached parse results carry no cover, so read it from the file.
+        processor
+            .parse_book_info(format, path)
+            .await
+            .ok()
+            .and_then(|info| info.cover_data)
+    } else {
+        cover_data
+    };
+    let mut item = LibraryItem {
         id: item_id.clone(),
         book_info,
         koreader_metadata,
         file_path: path.to_path_buf(),
         format,
+        added_at: file_added_unix(path),
     };
+    if config.demo {
+        demo::scrub_library_item(&mut item);
+    }
 
     let stats_fields_changed = needs_stats_reload(&item, repo).await;
 
@@ -177,6 +284,11 @@ pub(super) async fn process_single_item(
         stats.errors = 1;
         return stats;
     }
+    if !predecessor_keys.is_empty()
+        && let Err(e) = repo.retire_item_keys(&predecessor_keys, &item_id).await
+    {
+        warn!("Failed to redirect old links for {:?}: {}", path, e);
+    }
 
     stats.upserted = 1;
     if stats_fields_changed {
@@ -184,18 +296,15 @@ pub(super) async fn process_single_item(
     }
 
     if let Some(cover_data) = cover_data {
-        let cover_path = media_dirs.covers_dir.join(format!("{}.webp", item_id));
-
-        if media::cover_needs_generation(path, &cover_path) {
-            match tokio::task::spawn_blocking(move || {
-                media::encode_cover_to_disk(&cover_data, &cover_path)
-            })
-            .await
-            {
-                Ok(Ok(())) => {}
-                Ok(Err(e)) => warn!("Cover encode failed for {:?}: {}", path, e),
-                Err(e) => warn!("Cover encode task panicked for {:?}: {}", path, e),
-            }
+        let fit = config.cover_fit;
+        match tokio::task::spawn_blocking(move || {
+            media::encode_cover_to_disk(&cover_data, &cover_path, fit)
+        })
+        .await
+        {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => warn!("Cover encode failed for {:?}: {}", path, e),
+            Err(e) => warn!("Cover encode task panicked for {:?}: {}", path, e),
         }
     }
 
@@ -243,7 +352,10 @@ fn warn_parse_failure(
         return;
     }
 
-    warn!("Failed to parse {:?} {:?}: {}", format, path, error);
+    warn!(
+        "Failed to parse {:?} {:?}, skipping it: {}",
+        format, path, error
+    );
 }
 
 async fn needs_stats_reload(item: &LibraryItem, repo: &LibraryRepository) -> bool {
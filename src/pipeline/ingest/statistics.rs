@@ -2,23 +2,79 @@
 //!
 //! Uses DB queries instead of in-memory item collections for content-type
 //! tagging and library-item filtering.
+//!
+//! The raw parse result is cached by file fingerprint (size and mtime of each
+//! database and its WAL/SHM companions), so rebuilds triggered by library
+//! changes skip re-querying statistics databases that have not changed.
+//! Filters and thresholds are applied to a copy of the cached data on every
+//! load, so config changes never see stale filtered results.
 
-use crate::app::config::SiteConfig;
-use crate::shelf::statistics::{PageScaling, StatisticsCalculator};
+use crate::app::config::{SessionMaxMode, SiteConfig};
+use crate::pipeline::demo;
+use crate::shelf::statistics::{DurationCap, PageScaling, StatisticsCalculator};
+use crate::source::FileFingerprint;
 use crate::source::koreader::StatisticsParser;
+use crate::source::koreader::types::StatisticsData;
+use crate::source::sqlite_snapshot::sqlite_snapshot_paths;
 use crate::store::memory::ReadingData;
 use crate::store::sqlite::repo::LibraryRepository;
 use anyhow::Result;
-use log::{info, warn};
+use log::{debug, info, warn};
 use std::collections::HashSet;
 use std::path::PathBuf;
 
+/// Last raw parse result and the fingerprints of the files it was read from.
+struct ParsedStatistics {
+    fingerprints: Vec<Option<FileFingerprint>>,
+    data: StatisticsData,
+}
+
+/// Raw statistics parse kept between loads, owned by whoever loads reading
+/// data repeatedly (the initial build hands it on to the watcher).
+#[derive(Default)]
+pub struct StatisticsParseCache {
+    parsed: Option<ParsedStatistics>,
+}
+
+/// Fingerprint every database in `paths` together with its companions.
+/// Returns `None` if any file cannot be inspected, which disables caching.
+fn capture_fingerprints(paths: &[PathBuf]) -> Option<Vec<Option<FileFingerprint>>> {
+    paths
+        .iter()
+        .flat_map(|path| sqlite_snapshot_paths(path))
+        .map(|path| FileFingerprint::capture_optional(path).ok())
+        .collect()
+}
+
+/// Parse `paths`, reusing the previous result when no file has changed.
+async fn parse_statistics(
+    paths: &[PathBuf],
+    cache: &mut StatisticsParseCache,
+) -> Result<StatisticsData> {
+    let fingerprints = capture_fingerprints(paths);
+    if let Some(fingerprints) = &fingerprints
+        && let Some(cached) = &cache.parsed
+        && &cached.fingerprints == fingerprints
+    {
+        debug!("Statistics databases unchanged, reusing previous parse");
+        return Ok(cached.data.clone());
+    }
+
+    let data = StatisticsParser::parse_merged(paths).await?;
+    cache.parsed = fingerprints.map(|fingerprints| ParsedStatistics {
+        fingerprints,
+        data: data.clone(),
+    });
+    Ok(data)
+}
+
 /// Load and process reading statistics using DB queries for filtering and tagging.
 ///
 /// Called after library ingest at startup, and during rebuild when stats DB changes.
 pub async fn load_reading_data(
     config: &SiteConfig,
     repo: &LibraryRepository,
+    stats_cache: &mut StatisticsParseCache,
 ) -> Result<Option<ReadingData>> {
     let existing_dbs: Vec<PathBuf> = config
         .statistics_db_paths
@@ -39,9 +95,17 @@ pub async fn load_reading_data(
         return Ok(None);
     }
 
-    let mut data = StatisticsParser::parse_merged(&existing_dbs).await?;
+    let mut data = parse_statistics(&existing_dbs, stats_cache).await?;
     let total_books = data.books.len();
 
+    if config.session_min.is_some() || config.session_max.is_some() {
+        let max_duration = config.session_max.map(|max| match config.session_max_mode {
+            SessionMaxMode::Clamp => DurationCap::Clamp(max),
+            SessionMaxMode::Drop => DurationCap::Drop(max),
+        });
+        StatisticsCalculator::apply_duration_limits(&mut data, config.session_min, max_duration);
+    }
+
     if config.min_pages_per_day.is_some() || config.min_time_per_day.is_some() {
         StatisticsCalculator::filter_stats(
             &mut data,
@@ -51,18 +115,50 @@ pub async fn load_reading_data(
         );
     }
 
+    if config.match_stats_by_title {
+        let library: Vec<(String, String, Vec<String>)> = repo
+            .load_titles_and_authors()
+            .await?
+            .into_iter()
+            .map(|(id, title, authors)| (id, title, authors.0))
+            .collect();
+        StatisticsCalculator::link_by_title_and_author(&mut data, &library);
+    }
+
     if !config.include_all_stats {
         let item_ids = repo.load_all_item_ids().await?;
         if !item_ids.is_empty() {
             let md5s: HashSet<String> = item_ids.into_iter().collect();
-            StatisticsCalculator::filter_to_library(&mut data, &md5s);
+            if config.keep_ghost_stats {
+                StatisticsCalculator::mark_removed_books(&mut data, &md5s);
+            } else {
+                StatisticsCalculator::filter_to_library(&mut data, &md5s);
+            }
         }
     }
 
+    StatisticsCalculator::exclude_books(&mut data, &config.stats_exclude);
+    if config.demo {
+        demo::scrub_statistics(&mut data);
+    }
+
     let hidden_flow_pages = repo.load_hidden_flow_pages().await?;
     data.apply_hidden_flow_adjustments(&hidden_flow_pages);
 
-    StatisticsCalculator::populate_completions(&mut data, &config.time_config);
+    StatisticsCalculator::populate_completions(
+        &mut data,
+        &config.time_config,
+        &config.stats_config,
+        &config.completion_config,
+        &config.completion_overrides,
+    );
+
+    StatisticsCalculator::filter_date_range(
+        &mut data,
+        &config.time_config,
+        config.stats_from,
+        config.stats_to,
+    );
 
     let content_type_map = repo.load_content_types_by_id().await?;
     data.tag_content_types(&content_type_map);
@@ -88,7 +184,31 @@ pub async fn load_reading_data(
     Ok(Some(ReadingData {
         stats_data: data,
         time_config: config.time_config.clone(),
+        stats_config: config.stats_config.clone(),
         heatmap_scale_max: config.heatmap_scale_max,
         page_scaling,
+        calendar_color_by: config.calendar_color_by,
+        recap_show_empty_months: config.recap_show_empty_months,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::capture_fingerprints;
+    use std::fs;
+
+    #[test]
+    fn fingerprints_change_when_wal_is_written() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let db = dir.path().join("statistics.sqlite3");
+        fs::write(&db, b"main").expect("write db");
+        let paths = vec![db.clone()];
+
+        let before = capture_fingerprints(&paths).expect("fingerprints");
+        assert_eq!(capture_fingerprints(&paths), Some(before.clone()));
+
+        // Unflushed writes only touch the WAL, never the main file.
+        fs::write(dir.path().join("statistics.sqlite3-wal"), b"wal").expect("write wal");
+        assert_ne!(capture_fingerprints(&paths), Some(before));
+    }
+}
@@ -0,0 +1,102 @@
+//! Navbar logo (`--logo`) shown in place of the built-in brand mark and title.
+
+use anyhow::{Context, Result};
+use log::info;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::pipeline::media;
+
+/// URL prefix the logo directory is served under.
+pub const LOGO_URL_PREFIX: &str = "/assets/logo";
+
+/// Image types browsers can show in an `<img>`, by file extension.
+const LOGO_EXTENSIONS: [&str; 7] = ["svg", "png", "webp", "avif", "gif", "jpg", "jpeg"];
+
+/// Copy `source` to `logo_dir` as `logo.<ext>`, or remove a previously copied
+/// logo when none is configured.
+pub fn sync_logo(source: Option<&Path>, logo_dir: &Path) -> Result<()> {
+    let Some(source) = source else {
+        return remove_logo(logo_dir);
+    };
+
+    let extension = source
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .filter(|extension| LOGO_EXTENSIONS.contains(&extension.as_str()))
+        .with_context(|| {
+            format!(
+                "Unsupported logo image {:?} (expected one of: {})",
+                source,
+                LOGO_EXTENSIONS.join(", ")
+            )
+        })?;
+    let image =
+        fs::read(source).with_context(|| format!("Failed to read logo image {:?}", source))?;
+    // Clear out a logo of another format first.
+    remove_logo(logo_dir)?;
+    fs::create_dir_all(logo_dir)?;
+    media::write_replacing(&logo_dir.join(format!("logo.{extension}")), image)?;
+
+    info!("Using logo from {:?}", source);
+    Ok(())
+}
+
+fn remove_logo(logo_dir: &Path) -> Result<()> {
+    if let Err(error) = fs::remove_dir_all(logo_dir)
+        && error.kind() != ErrorKind::NotFound
+    {
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// URL of the copied logo with a content hash that busts browser caches when
+/// it changes. `None` without a logo.
+pub fn logo_url(logo_dir: &Path) -> Option<String> {
+    let path = fs::read_dir(logo_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| path.file_stem().is_some_and(|stem| stem == "logo"))?;
+    let digest = format!("{:x}", md5::compute(fs::read(&path).ok()?));
+    let file_name = path.file_name()?.to_str()?;
+    Some(format!("{LOGO_URL_PREFIX}/{file_name}?v={}", &digest[..8]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_replaces_and_removes_the_logo() {
+        let temp = tempfile::tempdir().unwrap();
+        let logo_dir = temp.path().join("assets").join("logo");
+        let svg = temp.path().join("Brand.SVG");
+        fs::write(&svg, "<svg xmlns=\"http://www.w3.org/2000/svg\"/>").unwrap();
+        let png = temp.path().join("brand.png");
+        fs::write(&png, [0x89, b'P', b'N', b'G']).unwrap();
+
+        sync_logo(Some(&svg), &logo_dir).unwrap();
+        let url = logo_url(&logo_dir).unwrap();
+        assert!(url.starts_with("/assets/logo/logo.svg?v="));
+
+        // Switching formats must not leave the old file behind.
+        sync_logo(Some(&png), &logo_dir).unwrap();
+        assert!(
+            logo_url(&logo_dir)
+                .unwrap()
+                .starts_with("/assets/logo/logo.png?v=")
+        );
+        assert_eq!(fs::read_dir(&logo_dir).unwrap().count(), 1);
+
+        sync_logo(None, &logo_dir).unwrap();
+        assert!(logo_url(&logo_dir).is_none());
+
+        let text = temp.path().join("logo.txt");
+        fs::write(&text, "not an image").unwrap();
+        assert!(sync_logo(Some(&text), &logo_dir).is_err());
+    }
+}
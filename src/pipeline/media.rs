@@ -1,6 +1,8 @@
 //! Media asset management: directory creation, cover generation/cleanup.
 
+use crate::app::config::CoverFit;
 use anyhow::{Context, Result, bail};
+use image::{Rgb, RgbImage, Rgba, RgbaImage, imageops};
 use log::info;
 use std::collections::HashSet;
 use std::fs;
@@ -17,6 +19,8 @@ pub struct MediaDirs {
     pub covers_dir: PathBuf,
     pub files_dir: PathBuf,
     pub recap_dir: PathBuf,
+    pub icons_dir: PathBuf,
+    pub logo_dir: PathBuf,
 }
 
 /// Compute media directories based on run mode.
@@ -32,12 +36,16 @@ pub fn resolve_media_dirs(output_dir: &Path, is_internal_server: bool) -> MediaD
     let covers_dir = assets_dir.join("covers");
     let files_dir = assets_dir.join("files");
     let recap_dir = assets_dir.join("recap");
+    let icons_dir = assets_dir.join("icons");
+    let logo_dir = assets_dir.join("logo");
     MediaDirs {
         output_dir: output_dir.to_path_buf(),
         assets_dir,
         covers_dir,
         files_dir,
         recap_dir,
+        icons_dir,
+        logo_dir,
     }
 }
 
@@ -52,29 +60,20 @@ pub fn create_media_directories(dirs: &MediaDirs) -> Result<()> {
 
 // ── Cover generation ────────────────────────────────────────────────────
 
+/// Height covers are scaled down to. The frontend sizes its cover placeholders
+/// from this (`COVER_HEIGHT` in `frontend/src/features/library/lib/cover-size.ts`).
+pub const COVER_MAX_HEIGHT: u32 = 600;
+
 /// Encode raw cover bytes to WebP and write to disk.
 ///
-/// Loads the image, resizes to 600px max height, encodes as WebP at quality 50,
-/// and writes the result to `cover_path`.
-pub fn encode_cover_to_disk(cover_data: &[u8], cover_path: &Path) -> Result<()> {
+/// Loads the image, fits it to the 2:3 book shape according to `fit`,
+/// resizes to [`COVER_MAX_HEIGHT`], encodes as WebP at quality 50, and writes
+/// the result to `cover_path`. Transparent areas are flattened onto the
+/// cover's background color, since WebP is encoded without alpha.
+pub fn encode_cover_to_disk(cover_data: &[u8], cover_path: &Path, fit: CoverFit) -> Result<()> {
     let img = image::load_from_memory(cover_data).context("Failed to load cover image")?;
+    let rgb_img = fit_cover(&img.to_rgba8(), fit);
 
-    let resized = {
-        let (original_width, original_height) = (img.width(), img.height());
-        let target_height = 600;
-        if original_height > target_height {
-            let target_width = (original_width * target_height) / original_height;
-            img.resize(
-                target_width,
-                target_height,
-                image::imageops::FilterType::CatmullRom,
-            )
-        } else {
-            img
-        }
-    };
-
-    let rgb_img = resized.to_rgb8();
     let encoder = webp::Encoder::from_rgb(&rgb_img, rgb_img.width(), rgb_img.height());
     let mut config =
         webp::WebPConfig::new().map_err(|_| anyhow::anyhow!("Failed to create WebP config"))?;
@@ -87,12 +86,103 @@ pub fn encode_cover_to_disk(cover_data: &[u8], cover_path: &Path) -> Result<()>
         .encode_advanced(&config)
         .map_err(|e| anyhow::anyhow!("Failed to encode WebP: {:?}", e))?;
 
-    fs::write(cover_path, &*webp_data)
+    write_replacing(cover_path, &*webp_data)
         .with_context(|| format!("Failed to save cover: {:?}", cover_path))?;
 
     Ok(())
 }
 
+/// Flatten, fit and scale a decoded cover to the RGB image that gets encoded.
+fn fit_cover(img: &RgbaImage, fit: CoverFit) -> RgbImage {
+    let background = edge_color(img);
+    let mut rgb = RgbImage::from_fn(img.width(), img.height(), |x, y| {
+        let Rgba([r, g, b, a]) = *img.get_pixel(x, y);
+        let blend =
+            |c: u8, bg: u8| ((c as u32 * a as u32 + bg as u32 * (255 - a as u32)) / 255) as u8;
+        Rgb([
+            blend(r, background[0]),
+            blend(g, background[1]),
+            blend(b, background[2]),
+        ])
+    });
+
+    let (width, height) = rgb.dimensions();
+    // Width and height of the 2:3 box that contains (`contain`) or fits inside
+    // (`cover`) the image.
+    match fit {
+        CoverFit::Original => {}
+        CoverFit::Contain => {
+            let box_width = width.max(height * 2 / 3).max(1);
+            let box_height = height.max(width * 3 / 2).max(1);
+            if (box_width, box_height) != (width, height) {
+                let mut padded = RgbImage::from_pixel(box_width, box_height, background);
+                let x = (box_width - width) / 2;
+                let y = (box_height - height) / 2;
+                imageops::replace(&mut padded, &rgb, x as i64, y as i64);
+                rgb = padded;
+            }
+        }
+        CoverFit::Cover => {
+            let box_width = width.min(height * 2 / 3).max(1);
+            let box_height = height.min(width * 3 / 2).max(1);
+            if (box_width, box_height) != (width, height) {
+                let x = (width - box_width) / 2;
+                let y = (height - box_height) / 2;
+                rgb = imageops::crop_imm(&rgb, x, y, box_width, box_height).to_image();
+            }
+        }
+    }
+
+    let (width, height) = rgb.dimensions();
+    if height > COVER_MAX_HEIGHT {
+        let target_width = ((width * COVER_MAX_HEIGHT) / height).max(1);
+        rgb = imageops::resize(
+            &rgb,
+            target_width,
+            COVER_MAX_HEIGHT,
+            imageops::FilterType::CatmullRom,
+        );
+    }
+    rgb
+}
+
+/// Average color of the image's outermost pixels, weighted by their opacity.
+///
+/// Used to pad covers and to fill their transparent areas; white when the
+/// edges are fully transparent.
+fn edge_color(img: &RgbaImage) -> Rgb<u8> {
+    let (width, height) = img.dimensions();
+    let mut sums = [0u64; 3];
+    let mut total_alpha = 0u64;
+    for (x, y, pixel) in img.enumerate_pixels() {
+        if x != 0 && y != 0 && x + 1 != width && y + 1 != height {
+            continue;
+        }
+        let Rgba([r, g, b, a]) = *pixel;
+        let a = a as u64;
+        sums[0] += r as u64 * a;
+        sums[1] += g as u64 * a;
+        sums[2] += b as u64 * a;
+        total_alpha += a;
+    }
+    if total_alpha == 0 {
+        return Rgb([255, 255, 255]);
+    }
+    Rgb(sums.map(|sum| (sum / total_alpha) as u8))
+}
+
+/// Write `contents` to a temporary sibling of `path` and rename it into place.
+///
+/// Unlike `fs::write` this never truncates an existing file, so a hard link to
+/// it from a previous export keeps its old contents.
+pub fn write_replacing(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)
+}
+
 /// Check whether a cover file needs (re)generation based on file modification times.
 pub fn cover_needs_generation(source_path: &Path, cover_path: &Path) -> bool {
     match (fs::metadata(source_path), fs::metadata(cover_path)) {
@@ -154,6 +244,36 @@ pub fn item_file_basename(item_id: &str, format: &str) -> Option<String> {
     Some(format!("{}.{}", item_id, normalized_format))
 }
 
+/// Hard-link `source` to `target`, or copy it when linking fails (e.g. across
+/// filesystems). Copies keep the source's modification time, so
+/// [`file_copy_is_current`] recognizes them on the next run. `fs::copy`
+/// streams the data, so large files are never held in memory.
+pub fn link_or_copy_file(source: &Path, target: &Path) -> std::io::Result<()> {
+    if fs::hard_link(source, target).is_ok() {
+        return Ok(());
+    }
+    fs::copy(source, target)?;
+    let modified = fs::metadata(source)?.modified()?;
+    fs::File::options()
+        .write(true)
+        .open(target)?
+        .set_modified(modified)
+}
+
+/// Whether `target` is a regular file with the size and modification time of
+/// `source`, i.e. a link or copy made by [`link_or_copy_file`] that is still
+/// up to date.
+pub fn file_copy_is_current(source: &Path, target: &Path) -> bool {
+    match (fs::metadata(source), fs::symlink_metadata(target)) {
+        (Ok(src_meta), Ok(target_meta)) => {
+            target_meta.is_file()
+                && src_meta.len() == target_meta.len()
+                && src_meta.modified().ok() == target_meta.modified().ok()
+        }
+        _ => false,
+    }
+}
+
 /// Refuse to operate on symlinked media directories to avoid accidental
 /// writes/deletes outside the configured output path.
 pub fn ensure_plain_directory(path: &Path) -> Result<()> {
@@ -345,6 +465,73 @@ mod tests {
 
     const CANONICAL_ID: &str = "0123456789abcdef0123456789abcdef";
 
+    #[test]
+    fn contain_pads_wide_covers_with_their_edge_color() {
+        let img = RgbaImage::from_fn(300, 100, |x, y| {
+            if (10..290).contains(&x) && (10..90).contains(&y) {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([200, 40, 40, 255])
+            }
+        });
+        let fitted = fit_cover(&img, CoverFit::Contain);
+        assert_eq!(fitted.dimensions(), (300, 450));
+        assert_eq!(*fitted.get_pixel(150, 0), Rgb([200, 40, 40]));
+        assert_eq!(*fitted.get_pixel(150, 225), Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn cover_crops_to_the_center_and_scales_down() {
+        let img = RgbaImage::from_fn(1200, 1200, |x, _| {
+            if (200..1000).contains(&x) {
+                Rgba([0, 0, 255, 255])
+            } else {
+                Rgba([255, 0, 0, 255])
+            }
+        });
+        let fitted = fit_cover(&img, CoverFit::Cover);
+        assert_eq!(fitted.dimensions(), (400, COVER_MAX_HEIGHT));
+        assert!(fitted.pixels().all(|p| p[2] > p[0]));
+
+        let original = fit_cover(&img, CoverFit::Original);
+        assert_eq!(original.dimensions(), (COVER_MAX_HEIGHT, COVER_MAX_HEIGHT));
+    }
+
+    #[test]
+    fn transparent_areas_are_flattened_onto_the_background() {
+        let img = RgbaImage::from_fn(40, 60, |x, y| {
+            if (10..30).contains(&x) && (10..50).contains(&y) {
+                Rgba([0, 0, 0, 0])
+            } else {
+                Rgba([240, 230, 220, 255])
+            }
+        });
+        let fitted = fit_cover(&img, CoverFit::Original);
+        assert_eq!(*fitted.get_pixel(20, 30), Rgb([240, 230, 220]));
+
+        let transparent = RgbaImage::from_pixel(20, 30, Rgba([0, 0, 0, 0]));
+        let fitted = fit_cover(&transparent, CoverFit::Contain);
+        assert!(fitted.pixels().all(|p| *p == Rgb([255, 255, 255])));
+    }
+
+    #[test]
+    fn linked_or_copied_files_stay_current_until_the_source_changes() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let source = dir.path().join("book.epub");
+        let target = dir.path().join("copy.epub");
+        fs::write(&source, b"book").expect("source write");
+
+        assert!(!file_copy_is_current(&source, &target));
+        link_or_copy_file(&source, &target).expect("link or copy");
+        assert!(file_copy_is_current(&source, &target));
+        assert_eq!(fs::read(&target).expect("target read"), b"book");
+
+        // Replace the source the way sync tools do: a new file, new mtime.
+        fs::remove_file(&source).expect("source remove");
+        fs::write(&source, b"book, revised").expect("source rewrite");
+        assert!(!file_copy_is_current(&source, &target));
+    }
+
     #[cfg(unix)]
     #[test]
     fn sync_item_file_symlink_resolves_relative_source_to_absolute() {
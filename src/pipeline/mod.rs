@@ -1,11 +1,23 @@
-//! Pipeline orchestration: ingest, rebuild, export, media assets, and file watching.
+//! Pipeline orchestration: ingest, rebuild, export, dry runs, build reports, CSV and annotation export, demo mode, media assets, icons and custom CSS, progress bars, staged output, file watching and the post-rebuild hook.
 
+pub mod annotation_export;
+pub mod build_report;
+pub mod csv_export;
+pub mod custom_css;
+pub mod demo;
+pub mod dry_run;
 pub mod embed;
 pub mod export;
 pub mod frontend;
+pub mod html_includes;
+pub mod icons;
 pub mod ingest;
+pub mod logo;
 pub mod media;
+pub mod progress;
 pub mod rebuild;
+pub mod rebuild_hook;
 pub mod recap;
 pub mod share;
+pub mod staging;
 pub mod watcher;
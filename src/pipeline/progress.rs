@@ -0,0 +1,39 @@
+//! Terminal progress bars for long-running generation steps.
+//!
+//! All bars are drawn through one shared [`MultiProgress`] so the logger can
+//! suspend them while writing a line (see `app::logging`); otherwise log output
+//! would tear through a bar that is being redrawn.
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::io::IsTerminal;
+use std::sync::LazyLock;
+
+static PROGRESS: LazyLock<MultiProgress> = LazyLock::new(MultiProgress::new);
+
+/// Show progress bars only on an interactive terminal and not under CI, and
+/// never alongside machine-readable (JSON) logs.
+pub fn configure(machine_readable_logs: bool) {
+    let interactive = std::io::stderr().is_terminal() && std::env::var_os("CI").is_none();
+    if machine_readable_logs || !interactive {
+        PROGRESS.set_draw_target(ProgressDrawTarget::hidden());
+    }
+}
+
+/// A progress bar over `len` steps, labelled with `message`. Hidden when
+/// progress output is disabled; call `finish_and_clear` when done.
+pub fn bar(len: u64, message: &'static str) -> ProgressBar {
+    let pb = PROGRESS.add(ProgressBar::new(len));
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{msg} {bar:30.cyan/blue} {pos}/{len}")
+            .unwrap()
+            .progress_chars("━╸─"),
+    );
+    pb.set_message(message);
+    pb
+}
+
+/// Run `f` with all progress bars temporarily cleared from the terminal.
+pub fn suspend<R>(f: impl FnOnce() -> R) -> R {
+    PROGRESS.suspend(f)
+}
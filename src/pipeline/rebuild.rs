@@ -4,11 +4,17 @@
 //! the watcher module is limited to event setup, debouncing, and dispatch.
 
 use crate::app::config::SiteConfig;
+use crate::pipeline::build_report::BuildReport;
+use crate::pipeline::custom_css;
 use crate::pipeline::export::{ExportConfig, export_data_files};
+use crate::pipeline::frontend::sync_static_frontend;
+use crate::pipeline::html_includes::HtmlIncludes;
 use crate::pipeline::ingest::{
-    delete_item_for_book_path, ingest_items, load_reading_data, sync_library,
+    StatisticsParseCache, delete_item_for_book_path, ingest_items, load_reading_data, sync_library,
+    sync_slugs,
 };
-use crate::pipeline::media::{self, resolve_media_dirs};
+use crate::pipeline::logo;
+use crate::pipeline::media::{self, MediaDirs, resolve_media_dirs};
 use crate::pipeline::recap::regenerate_share_images;
 use crate::server::api::responses::site::{SiteCapabilities, SiteData};
 use crate::shelf::models::LibraryItemFormat;
@@ -19,8 +25,49 @@ use crate::store::memory::{SharedReadingDataStore, SharedSiteStore, UpdateNotifi
 use crate::store::sqlite::repo::LibraryRepository;
 use anyhow::Result;
 use log::{debug, info, warn};
+use serde::Serialize;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const STATS_RELOAD_ATTEMPTS: u32 = 3;
+const STATS_RELOAD_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// What a targeted rebuild did, handed to the `--on-rebuild` command.
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildSummary {
+    /// Paths whose changes triggered the rebuild
+    pub changed_paths: Vec<PathBuf>,
+    /// Items added or updated in the library
+    pub items_updated: u64,
+    /// Items removed from the library
+    pub items_removed: u64,
+    /// Whether reading statistics were reloaded
+    pub stats_reloaded: bool,
+    /// Whether any served or exported data changed
+    pub data_changed: bool,
+    /// Revision of the published data change (server mode only)
+    pub revision: Option<u64>,
+    pub duration_ms: u64,
+}
+
+impl RebuildSummary {
+    /// Fold a later rebuild into this one, e.g. when both are reported to the
+    /// `--on-rebuild` command in a single run.
+    pub fn merge(&mut self, later: RebuildSummary) {
+        for path in later.changed_paths {
+            if !self.changed_paths.contains(&path) {
+                self.changed_paths.push(path);
+            }
+        }
+        self.items_updated += later.items_updated;
+        self.items_removed += later.items_removed;
+        self.stats_reloaded |= later.stats_reloaded;
+        self.data_changed |= later.data_changed;
+        self.revision = later.revision.or(self.revision);
+        self.duration_ms += later.duration_ms;
+    }
+}
 
 /// Targeted rebuild: process only changed paths using the library DB.
 pub async fn rebuild(
@@ -30,10 +77,14 @@ pub async fn rebuild(
     site_store: Option<&SharedSiteStore>,
     reading_data_store: Option<&SharedReadingDataStore>,
     update_notifier: Option<&UpdateNotifier>,
-) -> Result<()> {
+    stats_cache: &mut StatisticsParseCache,
+) -> Result<RebuildSummary> {
+    let started = Instant::now();
+    let changed_paths = accumulated_paths.len();
     info!(
+        phase = "rebuild_started", paths = changed_paths;
         "Starting targeted rebuild for {} changed paths",
-        accumulated_paths.len()
+        changed_paths
     );
 
     let stats_changed = config.statistics_db_paths.iter().any(|db_path| {
@@ -42,6 +93,12 @@ pub async fn rebuild(
             .any(|path| is_sqlite_db_or_companion(path, db_path))
     });
     let full_library_sync_required = requires_full_library_sync(&accumulated_paths, config);
+    let assets_changed = accumulated_paths.iter().any(|path| {
+        config
+            .watch_paths
+            .iter()
+            .any(|watch_path| path.starts_with(watch_path))
+    });
 
     let media_dirs = resolve_media_dirs(&config.output_dir, config.is_internal_server);
     if let Err(e) = media::create_media_directories(&media_dirs) {
@@ -126,6 +183,9 @@ pub async fn rebuild(
 
     // ── 3. Ingest changed/new paths ──────────────────────────────────
     let ingest_stats = ingest_items(&parse_items, config, repo, &media_dirs).await?;
+    if let Err(e) = sync_slugs(config, repo).await {
+        warn!("Failed to update URL slugs: {}", e);
+    }
 
     // ── 4. Stats reload if affected ──────────────────────────────────
     let mut stats_reloaded = false;
@@ -141,15 +201,26 @@ pub async fn rebuild(
             .is_some_and(|update| update.removed > 0);
 
     if needs_stats_reload {
-        match load_reading_data(config, repo).await {
-            Ok(Some(rd)) => {
-                if let Some(store) = reading_data_store {
-                    store.replace(rd);
+        // A stats change usually means KOReader is still writing; a read
+        // that catches it mid-transaction is retried instead of waiting for
+        // the next change to trigger another rebuild.
+        for attempt in 1..=STATS_RELOAD_ATTEMPTS {
+            match load_reading_data(config, repo, stats_cache).await {
+                Ok(Some(rd)) => {
+                    if let Some(store) = reading_data_store {
+                        store.replace(rd);
+                    }
+                    stats_reloaded = true;
+                }
+                Ok(None) => {}
+                Err(e) if attempt < STATS_RELOAD_ATTEMPTS => {
+                    debug!("Statistics reload failed, retrying: {}", e);
+                    tokio::time::sleep(STATS_RELOAD_RETRY_DELAY).await;
+                    continue;
                 }
-                stats_reloaded = true;
+                Err(e) => warn!("Failed to reload statistics: {}", e),
             }
-            Ok(None) => {}
-            Err(e) => warn!("Failed to reload statistics: {}", e),
+            break;
         }
     }
 
@@ -162,6 +233,7 @@ pub async fn rebuild(
             &rd.page_scaling,
             &media_dirs.recap_dir,
             &config.time_config,
+            &config.stats_config,
             false,
         )
         .await
@@ -169,18 +241,29 @@ pub async fn rebuild(
         warn!("Failed to regenerate share images: {}", e);
     }
 
+    let has_reading_data = reading_data_store
+        .and_then(|s| s.get())
+        .is_some_and(|rd| !rd.stats_data.page_stats.is_empty());
+
+    // ── 4c. Re-apply site assets if a watched path changed ──────────
+    if assets_changed && let Err(e) = refresh_site_assets(config, &media_dirs, has_reading_data) {
+        warn!("Failed to re-apply site assets: {}", e);
+    }
+
     // ── 5. Refresh SiteStore from DB ─────────────────────────────────
     let generated_at = config.time_config.now_rfc3339();
 
     match repo.query_content_type_flags().await {
         Ok((has_books, has_comics)) => {
-            let has_reading_data = reading_data_store
-                .and_then(|s| s.get())
-                .is_some_and(|rd| !rd.stats_data.page_stats.is_empty());
-
             let site_data = SiteData {
                 title: config.site_title.clone(),
+                logo_url: logo::logo_url(&media_dirs.logo_dir),
                 language: config.language.clone(),
+                week_start: config.time_config.week_start,
+                page_size: config.page_size,
+                date_format: config.date_format,
+                duration_granularity: config.duration_granularity,
+                duration_max_units: config.duration_max_units,
                 capabilities: SiteCapabilities {
                     has_books,
                     has_comics,
@@ -200,7 +283,8 @@ pub async fn rebuild(
     }
 
     // ── 6. SSE broadcast (only when something actually changed) ────
-    let data_changed = ingest_stats.upserted > 0
+    let data_changed = assets_changed
+        || ingest_stats.upserted > 0
         || deleted_count > 0
         || stats_reloaded
         || library_update.as_ref().is_some_and(|update| {
@@ -209,9 +293,15 @@ pub async fn rebuild(
                     .ingest_stats
                     .is_some_and(|stats| stats.upserted > 0 || stats.stats_invalidated > 0)
         });
+    let mut revision = None;
     if data_changed && let Some(notifier) = update_notifier {
         let update = notifier.publish(generated_at.clone());
-        info!("Published data_changed event, revision {}", update.revision);
+        info!(
+            phase = "rebuild_published", revision = update.revision;
+            "Published data_changed event, revision {}",
+            update.revision
+        );
+        revision = Some(update.revision);
     }
 
     // ── 7. Static data re-export ────────────────────────────────────
@@ -221,8 +311,18 @@ pub async fn rebuild(
 
         let export_config = ExportConfig {
             site_title: config.site_title.clone(),
+            logo_url: logo::logo_url(&media_dirs.logo_dir),
+            generated_at: generated_at.clone(),
             language: config.language.clone(),
+            page_size: config.page_size,
+            date_format: config.date_format,
+            duration_granularity: config.duration_granularity,
+            duration_max_units: config.duration_max_units,
+            description_max_chars: config.description_max_chars,
+            series_min_items: config.series_min_items,
             include_files: config.include_files,
+            export_csv: config.export_csv,
+            export_all_annotations: config.export_all_annotations,
         };
         if let Err(e) = export_data_files(
             &config.output_dir.join("data"),
@@ -230,6 +330,7 @@ pub async fn rebuild(
             repo,
             rd_ref,
             &export_config,
+            &mut BuildReport::default(),
         )
         .await
         {
@@ -237,8 +338,60 @@ pub async fn rebuild(
         }
     }
 
-    info!("Targeted rebuild completed successfully");
+    let duration_ms = started.elapsed().as_millis() as u64;
+    info!(
+        phase = "rebuild_completed",
+        paths = changed_paths,
+        duration_ms = duration_ms;
+        "Targeted rebuild completed successfully"
+    );
+
+    let mut paths: Vec<PathBuf> = accumulated_paths.into_iter().collect();
+    paths.sort();
+    let library_ingest = library_update
+        .as_ref()
+        .and_then(|update| update.ingest_stats);
+    Ok(RebuildSummary {
+        changed_paths: paths,
+        items_updated: ingest_stats.upserted + library_ingest.map_or(0, |stats| stats.upserted),
+        items_removed: deleted_count + library_update.as_ref().map_or(0, |update| update.removed),
+        stats_reloaded,
+        data_changed,
+        revision,
+        duration_ms,
+    })
+}
+
+/// Copy the logo and custom CSS again and reload the HTML includes, for
+/// `--watch-path` changes. In static export mode the app shell is rewritten
+/// as well; the server applies the reloaded includes on the next request.
+fn refresh_site_assets(
+    config: &SiteConfig,
+    media_dirs: &MediaDirs,
+    has_reading_data: bool,
+) -> Result<()> {
+    let sources = &config.asset_sources;
+    logo::sync_logo(sources.logo.as_deref(), &media_dirs.logo_dir)?;
+    custom_css::sync_custom_css(sources.custom_css.as_deref(), &media_dirs.assets_dir)?;
+    let includes = HtmlIncludes::load(
+        sources.head_include.as_deref(),
+        sources.footer_include.as_deref(),
+    )?;
+
+    if !config.is_internal_server {
+        sync_static_frontend(
+            &config.output_dir,
+            has_reading_data,
+            &config.site_title,
+            &includes,
+        )?;
+    }
+    *config
+        .html_includes
+        .write()
+        .expect("html includes lock poisoned while writing") = includes;
 
+    info!("Re-applied logo, custom CSS and HTML includes");
     Ok(())
 }
 
@@ -366,8 +519,10 @@ fn derive_book_path_from_sdr_path(sdr_path: &Path) -> Option<PathBuf> {
 
 #[cfg(test)]
 mod tests {
-    use super::{derive_book_path_from_sdr_path, requires_full_library_sync};
-    use crate::app::config::SiteConfig;
+    use super::{derive_book_path_from_sdr_path, refresh_site_assets, requires_full_library_sync};
+    use crate::app::config::{SiteAssetSources, SiteConfig};
+    use crate::pipeline::custom_css::custom_css_version;
+    use crate::pipeline::media::resolve_media_dirs;
     use crate::shelf::models::LibraryItemFormat;
     use crate::shelf::time_config::TimeConfig;
     use crate::source::scanner::MetadataLocation;
@@ -383,22 +538,54 @@ mod tests {
         SiteConfig {
             output_dir: output_dir.to_path_buf(),
             site_title: "KoShelf".to_string(),
+            html_includes: Default::default(),
             include_unread: true,
+            scan_jobs: 2,
+            normalize_authors: false,
+            author_aliases: Default::default(),
+            id_scheme: Default::default(),
+            description_format: Default::default(),
+            description_max_chars: 0,
+            cover_fit: Default::default(),
+            series_min_items: 2,
+            demo: false,
+            page_size: 0,
+            date_format: Default::default(),
+            duration_granularity: Default::default(),
+            duration_max_units: 0,
+            on_rebuild: None,
+            watch_paths: Vec::new(),
+            asset_sources: Default::default(),
             library_paths: vec![output_dir.join("library")],
             metadata_location: MetadataLocation::InBookFolder,
             statistics_db_paths: vec![],
             kobo_db_path,
             heatmap_scale_max: None,
-            time_config: TimeConfig::from_cli(&None, &None).expect("time config"),
+            time_config: TimeConfig::from_cli(&None, &None, &None).expect("time config"),
+            stats_config: Default::default(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
             min_pages_per_day: None,
             min_time_per_day: None,
+            session_min: None,
+            session_max: None,
+            session_max_mode: Default::default(),
+            stats_from: None,
+            stats_to: None,
+            completion_config: Default::default(),
+            completion_overrides: Default::default(),
             include_all_stats: false,
+            keep_ghost_stats: false,
+            match_stats_by_title: false,
+            stats_exclude: Vec::new(),
             is_internal_server: false,
             language: "en_US".to_string(),
             use_stable_page_metadata: true,
             auth_enabled: false,
             writeback_enabled: false,
             include_files: false,
+            export_csv: false,
+            export_all_annotations: false,
             runtime_data_policy,
         }
     }
@@ -422,6 +609,35 @@ mod tests {
         assert!(!requires_full_library_sync(&regular_book_changed, &config));
     }
 
+    #[test]
+    fn refresh_site_assets_picks_up_edited_sources() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let css = dir.path().join("theme.css");
+        std::fs::write(&css, ":root { --color-primary-500: #e11d48; }").expect("css");
+        let head = dir.path().join("head.html");
+        std::fs::write(&head, "<meta name=\"a\">").expect("head");
+
+        let mut config = test_config(&dir.path().join("data"), None);
+        config.is_internal_server = true;
+        config.asset_sources = SiteAssetSources {
+            custom_css: Some(css.clone()),
+            head_include: Some(head.clone()),
+            ..Default::default()
+        };
+        let media_dirs = resolve_media_dirs(&config.output_dir, true);
+
+        refresh_site_assets(&config, &media_dirs, false).expect("refresh");
+        let first = custom_css_version(&media_dirs.assets_dir).expect("css copied");
+
+        std::fs::write(&css, ":root { --color-primary-500: #16a34a; }").expect("css");
+        std::fs::write(&head, "<meta name=\"b\">").expect("head");
+        refresh_site_assets(&config, &media_dirs, false).expect("refresh");
+
+        assert_ne!(custom_css_version(&media_dirs.assets_dir), Some(first));
+        let includes = config.html_includes.read().unwrap();
+        assert_eq!(includes.head.as_deref(), Some("<meta name=\"b\">"));
+    }
+
     #[test]
     fn sidecar_derives_book_filename_by_metadata_filename() {
         assert_eq!(
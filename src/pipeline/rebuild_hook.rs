@@ -0,0 +1,233 @@
+//! `--on-rebuild`: a shell command run after watch-mode rebuilds that changed
+//! data, e.g. to purge a CDN cache.
+//!
+//! The command gets the [`RebuildSummary`] as JSON on stdin and its counts in
+//! `KOSHELF_REBUILD_*` environment variables. It runs on a background thread,
+//! one invocation at a time: rebuilds that finish while the command is still
+//! running are merged into a single follow-up run. A failure is logged and
+//! never stops the watcher.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use log::{debug, info, warn};
+
+use crate::pipeline::rebuild::RebuildSummary;
+
+/// Runs the `--on-rebuild` command for the watcher, never more than once at a
+/// time.
+pub struct RebuildHook {
+    sender: Sender<RebuildSummary>,
+}
+
+impl RebuildHook {
+    /// Start the thread that runs `command`; it exits when the hook is dropped.
+    pub fn new(command: String) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || run_queued(&command, receiver));
+        Self { sender }
+    }
+
+    /// Queue a run for `summary`, unless the rebuild changed nothing.
+    pub fn notify(&self, summary: RebuildSummary) {
+        if !summary.data_changed {
+            debug!("Skipping --on-rebuild command: nothing changed");
+            return;
+        }
+        let _ = self.sender.send(summary);
+    }
+}
+
+/// Run the command for each queued summary, merging summaries that queued up
+/// while the previous run was going.
+fn run_queued(command: &str, receiver: Receiver<RebuildSummary>) {
+    while let Ok(mut summary) = receiver.recv() {
+        while let Ok(next) = receiver.try_recv() {
+            summary.merge(next);
+        }
+        run_hook(command, &summary);
+    }
+}
+
+/// Run the command for `summary` and wait for it to finish.
+///
+/// Returns whether the command ran and exited successfully.
+fn run_hook(command: &str, summary: &RebuildSummary) -> bool {
+    let payload = match serde_json::to_vec(summary) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Failed to serialize rebuild summary: {}", e);
+            return false;
+        }
+    };
+
+    let mut child = match shell_command(command)
+        .envs(summary_env(summary))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            warn!("Failed to start --on-rebuild command: {}", e);
+            return false;
+        }
+    };
+
+    // Feed stdin from its own thread so a command that writes a lot to stderr
+    // before reading its input cannot block on a full pipe.
+    let writer = child.stdin.take().map(|mut stdin| {
+        std::thread::spawn(move || {
+            // A command that ignores stdin may exit before reading it.
+            let _ = stdin.write_all(&payload);
+        })
+    });
+    let output = child.wait_with_output();
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+
+    match output {
+        Ok(output) if output.status.success() => {
+            info!("--on-rebuild command finished: {}", command);
+            true
+        }
+        Ok(output) => {
+            warn!(
+                "--on-rebuild command exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            false
+        }
+        Err(e) => {
+            warn!("Failed to wait for --on-rebuild command: {}", e);
+            false
+        }
+    }
+}
+
+fn shell_command(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+fn summary_env(summary: &RebuildSummary) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        (
+            "KOSHELF_REBUILD_CHANGED_PATHS",
+            summary.changed_paths.len().to_string(),
+        ),
+        (
+            "KOSHELF_REBUILD_ITEMS_UPDATED",
+            summary.items_updated.to_string(),
+        ),
+        (
+            "KOSHELF_REBUILD_ITEMS_REMOVED",
+            summary.items_removed.to_string(),
+        ),
+        (
+            "KOSHELF_REBUILD_STATS_RELOADED",
+            summary.stats_reloaded.to_string(),
+        ),
+        (
+            "KOSHELF_REBUILD_DURATION_MS",
+            summary.duration_ms.to_string(),
+        ),
+    ];
+    if let Some(revision) = summary.revision {
+        env.push(("KOSHELF_REBUILD_REVISION", revision.to_string()));
+    }
+    env
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn summary(data_changed: bool) -> RebuildSummary {
+        RebuildSummary {
+            changed_paths: vec![PathBuf::from("/books/dune.epub")],
+            items_updated: 1,
+            items_removed: 0,
+            stats_reloaded: false,
+            data_changed,
+            revision: Some(7),
+            duration_ms: 42,
+        }
+    }
+
+    #[test]
+    fn hook_gets_the_summary_on_stdin_and_in_the_environment() {
+        let temp = tempfile::tempdir().unwrap();
+        let payload = temp.path().join("payload.json");
+        let env = temp.path().join("env");
+        let command = format!(
+            "cat > '{}'; echo \"$KOSHELF_REBUILD_ITEMS_UPDATED $KOSHELF_REBUILD_REVISION\" > '{}'",
+            payload.display(),
+            env.display()
+        );
+
+        assert!(run_hook(&command, &summary(true)));
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&payload).unwrap()).unwrap();
+        assert_eq!(json["changed_paths"][0], "/books/dune.epub");
+        assert_eq!(json["items_updated"], 1);
+        assert_eq!(fs::read_to_string(&env).unwrap().trim(), "1 7");
+
+        // A failing command is only logged.
+        assert!(!run_hook("exit 3", &summary(true)));
+    }
+
+    #[test]
+    fn hook_writing_stderr_before_reading_stdin_does_not_block() {
+        let temp = tempfile::tempdir().unwrap();
+        let payload = temp.path().join("payload.json");
+        let mut large = summary(true);
+        large.changed_paths = (0..20_000)
+            .map(|i| PathBuf::from(format!("/books/book-{i}.epub")))
+            .collect();
+        let command = format!(
+            "head -c 1000000 /dev/zero >&2; cat > '{}'",
+            payload.display()
+        );
+
+        assert!(run_hook(&command, &large));
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&payload).unwrap()).unwrap();
+        assert_eq!(json["changed_paths"].as_array().unwrap().len(), 20_000);
+    }
+
+    #[test]
+    fn queued_summaries_run_once_merged_and_no_op_rebuilds_are_skipped() {
+        let temp = tempfile::tempdir().unwrap();
+        let log = temp.path().join("runs");
+        let command = format!(
+            "echo \"$KOSHELF_REBUILD_ITEMS_UPDATED $KOSHELF_REBUILD_REVISION\" >> '{}'",
+            log.display()
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let hook = RebuildHook { sender };
+        hook.notify(summary(false));
+        hook.notify(summary(true));
+        let mut later = summary(true);
+        later.revision = Some(8);
+        hook.notify(later);
+        drop(hook);
+        run_queued(&command, receiver);
+
+        assert_eq!(fs::read_to_string(&log).unwrap(), "2 8\n");
+    }
+}
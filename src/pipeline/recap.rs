@@ -4,18 +4,18 @@
 //! The reading domain service handles completion data on demand — this module
 //! only generates the visual share assets.
 
+use crate::pipeline::progress;
 use crate::pipeline::share::{ShareFormat, ShareImageData, generate_share_image};
 use crate::server::api::responses::library::LibraryContentType;
 use crate::shelf::models::ContentType;
-use crate::shelf::statistics::StatisticsCalculator;
 use crate::shelf::statistics::compute::scaling::PageScaling;
 use crate::shelf::statistics::types::{MonthRecap, RecapItem, YearlySummary};
+use crate::shelf::statistics::{StatisticsCalculator, StatisticsConfig};
 use crate::shelf::time_config::TimeConfig;
 use crate::source::koreader::types::{DailyStats, PageStat, ReadingStats, StatisticsData};
 use crate::store::sqlite::repo::LibraryRepository;
 use anyhow::Result;
 use chrono::Datelike;
-use indicatif::{ProgressBar, ProgressStyle};
 use log::{info, warn};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
@@ -207,6 +207,7 @@ fn compute_yearly_summary(
     month_hours: &HashMap<String, i64>,
     reading_stats: &ReadingStats,
     page_stats: &[PageStat],
+    session_gap_sec: i64,
 ) -> YearlySummary {
     let year_str = format!("{}", year);
     let year_prefix = format!("{}-", year);
@@ -230,8 +231,10 @@ fn compute_yearly_summary(
         .cloned()
         .collect();
 
-    let all_sessions =
-        crate::shelf::statistics::compute::sessions::aggregate_session_durations(&year_page_stats);
+    let all_sessions = crate::shelf::statistics::compute::sessions::aggregate_session_durations(
+        &year_page_stats,
+        session_gap_sec,
+    );
     let session_count = all_sessions.len() as i64;
     let longest_session_duration = all_sessions.iter().max().copied().unwrap_or(0);
     let average_session_duration = if session_count > 0 {
@@ -283,7 +286,7 @@ fn compute_yearly_summary(
     let best_month: Option<(String, i64)> = month_hours
         .iter()
         .filter(|(month_key, _)| month_key.starts_with(&year_prefix))
-        .max_by_key(|(_, seconds)| *seconds)
+        .max_by(|(a_key, a_secs), (b_key, b_secs)| a_secs.cmp(b_secs).then(b_key.cmp(a_key)))
         .map(|(month_key, seconds)| (month_key.clone(), *seconds));
 
     let best_month = best_month.and_then(|(month_key, seconds)| (seconds > 0).then_some(month_key));
@@ -395,8 +398,10 @@ async fn compute_share_data_per_year(
     repo: &LibraryRepository,
     page_scaling: &PageScaling,
     time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
 ) -> HashMap<i32, ShareImageData> {
-    let reading_stats_all = StatisticsCalculator::calculate_stats(stats_data, time_config);
+    let reading_stats_all =
+        StatisticsCalculator::calculate_stats(stats_data, time_config, stats_config);
 
     let (year_month_items, years) =
         group_completions_by_year_month(stats_data, repo, page_scaling).await;
@@ -417,6 +422,7 @@ async fn compute_share_data_per_year(
             &month_hours_all,
             &reading_stats_all,
             &stats_data.page_stats,
+            stats_config.session_gap_sec,
         );
 
         result.insert(
@@ -458,10 +464,12 @@ pub async fn regenerate_share_images(
     page_scaling: &PageScaling,
     recap_dir: &Path,
     time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
     show_progress: bool,
 ) -> Result<()> {
     let share_data_by_year =
-        compute_share_data_per_year(stats_data, repo, page_scaling, time_config).await;
+        compute_share_data_per_year(stats_data, repo, page_scaling, time_config, stats_config)
+            .await;
 
     let valid_years: Vec<i32> = share_data_by_year.keys().copied().collect();
 
@@ -509,14 +517,7 @@ pub async fn regenerate_share_images(
 
         if show_progress {
             info!("Rendering share images...");
-            let pb = ProgressBar::new(total_tasks as u64);
-            pb.set_style(
-                ProgressStyle::default_bar()
-                    .template("{msg} {bar:30.cyan/blue} {pos}/{len}")
-                    .unwrap()
-                    .progress_chars("━╸─"),
-            );
-            pb.set_message("Rendering share images:");
+            let pb = progress::bar(total_tasks as u64, "Rendering share images:");
 
             drop(progress_tx);
 
@@ -580,6 +581,7 @@ pub async fn regenerate_share_images(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shelf::statistics::compute::sessions::DEFAULT_SESSION_GAP_SECONDS;
     use crate::source::koreader::types::StreakInfo;
 
     // ── Recap summary tests ─────────────────────────────────────────────
@@ -616,8 +618,14 @@ mod tests {
             ("2024-12".to_string(), 99_999),
         ]);
 
-        let summary =
-            compute_yearly_summary(2025, &monthly, &month_hours, &empty_reading_stats(), &[]);
+        let summary = compute_yearly_summary(
+            2025,
+            &monthly,
+            &month_hours,
+            &empty_reading_stats(),
+            &[],
+            DEFAULT_SESSION_GAP_SECONDS,
+        );
 
         assert_eq!(summary.total_books, 2);
         assert_eq!(summary.total_time_seconds, 388_800);
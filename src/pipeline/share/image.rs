@@ -1,10 +1,10 @@
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
-use std::fs;
 use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
 use crate::pipeline::embed::gz_decompress;
+use crate::pipeline::media;
 
 const WEBP_QUALITY: f32 = 85.0;
 const WEBP_METHOD: i32 = 1;
@@ -144,12 +144,12 @@ pub fn generate_share_image(
         .encode_advanced(&config)
         .map_err(|e| anyhow::anyhow!("Failed to encode WebP: {:?}", e))?;
 
-    fs::write(output_path, &*webp_data).context("Failed to write WebP file")?;
+    media::write_replacing(output_path, &*webp_data).context("Failed to write WebP file")?;
 
     // Reuse the parsed tree for the SVG output instead of re-parsing.
     let svg_path = output_path.with_extension("svg");
     let svg_output = tree.to_string(&resvg::usvg::WriteOptions::default());
-    fs::write(svg_path, svg_output).context("Failed to write SVG file")?;
+    media::write_replacing(&svg_path, svg_output).context("Failed to write SVG file")?;
 
     log::debug!("Finished creating share image: {:?}", output_path);
 
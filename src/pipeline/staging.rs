@@ -0,0 +1,304 @@
+//! Staged static export output.
+//!
+//! A static export is generated into a temporary sibling of the output
+//! directory and swapped into place once it completed, so a failed run never
+//! leaves a half-updated site behind. The staging directory starts as a
+//! hard-linked copy of the previous output (minus the regenerated `data/` and
+//! `export/` trees), which keeps covers and share images cached across runs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+use crate::pipeline::media;
+
+/// Top-level output entries rebuilt from scratch by every export.
+const REGENERATED_ENTRIES: [&str; 2] = ["data", "export"];
+
+/// Where an export writes before it is committed.
+pub struct StagedOutput {
+    output_dir: PathBuf,
+    staging: Option<TempDir>,
+}
+
+impl StagedOutput {
+    /// Create a staging directory next to `output_dir`.
+    ///
+    /// Falls back to writing in place when the output is a symlink (swapping
+    /// it would replace the link instead of updating its target), contains
+    /// the working directory, is on a different device than its parent (a
+    /// mount point cannot be renamed), or when no sibling directory can be
+    /// created.
+    pub fn prepare(output_dir: &Path) -> Result<Self> {
+        let in_place = Self {
+            output_dir: output_dir.to_path_buf(),
+            staging: None,
+        };
+
+        if fs::symlink_metadata(output_dir).is_ok_and(|meta| meta.file_type().is_symlink()) {
+            info!("Output {:?} is a symlink, writing in place", output_dir);
+            return Ok(in_place);
+        }
+
+        // Resolve `.`, `..` and trailing separators so the output has a real
+        // parent and name to stage next to.
+        let output_dir = if output_dir.exists() {
+            fs::canonicalize(output_dir)
+        } else {
+            std::path::absolute(output_dir)
+        }
+        .with_context(|| format!("Failed to resolve output directory {:?}", output_dir))?;
+
+        if std::env::current_dir().is_ok_and(|cwd| cwd.starts_with(&output_dir)) {
+            info!(
+                "Output {:?} contains the working directory, writing in place",
+                output_dir
+            );
+            return Ok(in_place);
+        }
+
+        let (Some(parent), Some(name)) = (output_dir.parent(), output_dir.file_name()) else {
+            return Ok(in_place);
+        };
+        let parent = parent.to_path_buf();
+        let name = name.to_string_lossy().into_owned();
+        let output_dir = output_dir.as_path();
+
+        if output_dir.exists() && !same_device(output_dir, &parent) {
+            info!(
+                "Output {:?} is on a different device than its parent, writing in place",
+                output_dir
+            );
+            return Ok(in_place);
+        }
+
+        let staging = match tempfile::Builder::new()
+            .prefix(&format!(".{name}.staging-"))
+            .tempdir_in(&parent)
+        {
+            Ok(staging) => staging,
+            Err(e) => {
+                warn!(
+                    "Could not create a staging directory next to {:?} ({}), writing in place",
+                    output_dir, e
+                );
+                return Ok(in_place);
+            }
+        };
+
+        if output_dir.is_dir() {
+            seed_from_previous(output_dir, staging.path())?;
+        }
+
+        Ok(Self {
+            output_dir: output_dir.to_path_buf(),
+            staging: Some(staging),
+        })
+    }
+
+    /// Directory the export should write to.
+    pub fn path(&self) -> &Path {
+        self.staging
+            .as_ref()
+            .map_or(self.output_dir.as_path(), |staging| staging.path())
+    }
+
+    /// Swap the staged export into place.
+    pub fn commit(mut self) -> Result<()> {
+        let Some(staging) = self.staging.take() else {
+            return Ok(());
+        };
+        let staging_path = staging.keep();
+
+        if !self.output_dir.exists() {
+            return fs::rename(&staging_path, &self.output_dir).with_context(|| {
+                format!(
+                    "Failed to move staged export {:?} to {:?}",
+                    staging_path, self.output_dir
+                )
+            });
+        }
+
+        let mut previous = staging_path.clone().into_os_string();
+        previous.push("-previous");
+        let previous = PathBuf::from(previous);
+
+        fs::rename(&self.output_dir, &previous).with_context(|| {
+            format!(
+                "Failed to move previous output {:?} aside, staged export left at {:?}",
+                self.output_dir, staging_path
+            )
+        })?;
+        if let Err(e) = fs::rename(&staging_path, &self.output_dir) {
+            let _ = fs::rename(&previous, &self.output_dir);
+            return Err(e).with_context(|| {
+                format!(
+                    "Failed to move staged export {:?} to {:?}",
+                    staging_path, self.output_dir
+                )
+            });
+        }
+
+        if let Err(e) = fs::remove_dir_all(&previous) {
+            warn!("Failed to remove previous output {:?}: {}", previous, e);
+        }
+        Ok(())
+    }
+}
+
+/// Hard-link the previous output into `staging`, skipping regenerated trees.
+///
+/// Files are copied with their modification time when hard links are not
+/// supported, so cover staleness checks behave the same either way.
+fn seed_from_previous(output_dir: &Path, staging: &Path) -> Result<()> {
+    let entries = WalkDir::new(output_dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() > 1
+                || !REGENERATED_ENTRIES
+                    .iter()
+                    .any(|name| entry.file_name() == *name)
+        });
+
+    for entry in entries {
+        let entry = entry.context("Failed to read previous output")?;
+        let relative = entry
+            .path()
+            .strip_prefix(output_dir)
+            .context("Output entry outside the output directory")?;
+        let target = staging.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_symlink() {
+            let link = fs::read_link(entry.path())?;
+            copy_symlink(&link, &target)?;
+        } else {
+            media::link_or_copy_file(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(link: &Path, target: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(link, target)
+}
+
+#[cfg(not(unix))]
+fn copy_symlink(link: &Path, target: &Path) -> std::io::Result<()> {
+    fs::copy(link, target).map(|_| ())
+}
+
+#[cfg(unix)]
+fn same_device(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn same_device(_a: &Path, _b: &Path) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_swaps_in_staged_output_and_keeps_cached_assets() {
+        let root = tempfile::tempdir().unwrap();
+        let output = root.path().join("site");
+        fs::create_dir_all(output.join("assets/covers")).unwrap();
+        fs::create_dir_all(output.join("data")).unwrap();
+        fs::write(output.join("assets/covers/abc.webp"), b"cover").unwrap();
+        fs::write(output.join("data/site.json"), b"old").unwrap();
+
+        let staged = StagedOutput::prepare(&output).unwrap();
+        let staging = staged.path().to_path_buf();
+        assert_ne!(staging, output);
+        assert!(staging.join("assets/covers/abc.webp").is_file());
+        assert!(!staging.join("data").exists());
+
+        fs::create_dir_all(staging.join("data")).unwrap();
+        fs::write(staging.join("data/site.json"), b"new").unwrap();
+        staged.commit().unwrap();
+
+        assert_eq!(fs::read(output.join("data/site.json")).unwrap(), b"new");
+        assert_eq!(
+            fs::read(output.join("assets/covers/abc.webp")).unwrap(),
+            b"cover"
+        );
+        assert!(!staging.exists());
+        assert_eq!(fs::read_dir(root.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn trailing_dot_components_stage_next_to_the_resolved_output() {
+        let root = tempfile::tempdir().unwrap();
+        let output = root.path().join("site");
+        fs::create_dir_all(output.join("data")).unwrap();
+        fs::write(output.join("data/site.json"), b"old").unwrap();
+
+        let staged = StagedOutput::prepare(&output.join(".")).unwrap();
+        let staging = staged.path().to_path_buf();
+        assert_eq!(
+            staging.parent().map(fs::canonicalize).transpose().unwrap(),
+            Some(fs::canonicalize(root.path()).unwrap())
+        );
+
+        fs::create_dir_all(staging.join("data")).unwrap();
+        fs::write(staging.join("data/site.json"), b"new").unwrap();
+        staged.commit().unwrap();
+
+        assert_eq!(fs::read(output.join("data/site.json")).unwrap(), b"new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_output_is_written_in_place() {
+        let root = tempfile::tempdir().unwrap();
+        let target = root.path().join("real-site");
+        let link = root.path().join("site");
+        fs::create_dir_all(&target).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let staged = StagedOutput::prepare(&link).unwrap();
+        assert_eq!(staged.path(), link);
+        fs::write(staged.path().join("index.html"), b"new").unwrap();
+        staged.commit().unwrap();
+
+        assert!(
+            fs::symlink_metadata(&link)
+                .unwrap()
+                .file_type()
+                .is_symlink()
+        );
+        assert_eq!(fs::read(target.join("index.html")).unwrap(), b"new");
+    }
+
+    #[test]
+    fn dropping_uncommitted_output_leaves_previous_output_alone() {
+        let root = tempfile::tempdir().unwrap();
+        let output = root.path().join("site");
+        fs::create_dir_all(output.join("data")).unwrap();
+        fs::write(output.join("data/site.json"), b"old").unwrap();
+
+        let staged = StagedOutput::prepare(&output).unwrap();
+        fs::create_dir_all(staged.path().join("data")).unwrap();
+        fs::write(staged.path().join("data/site.json"), b"half").unwrap();
+        drop(staged);
+
+        assert_eq!(fs::read(output.join("data/site.json")).unwrap(), b"old");
+        assert_eq!(fs::read_dir(root.path()).unwrap().count(), 1);
+    }
+}
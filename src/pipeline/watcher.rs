@@ -1,5 +1,8 @@
 use crate::app::config::SiteConfig;
+use crate::app::shutdown::Shutdown;
+use crate::pipeline::ingest::StatisticsParseCache;
 use crate::pipeline::rebuild::rebuild;
+use crate::pipeline::rebuild_hook::RebuildHook;
 use crate::server::RecentWrites;
 use crate::shelf::models::LibraryItemFormat;
 use crate::source::scanner::MetadataLocation;
@@ -30,6 +33,10 @@ pub struct FileWatcher {
     update_notifier: Option<UpdateNotifier>,
     library_repo: Option<LibraryRepository>,
     recent_writes: Option<RecentWrites>,
+    stats_cache: StatisticsParseCache,
+    /// Directories KoShelf writes to inside watched paths, resolved; events
+    /// inside them are ignored.
+    output_dirs: Vec<PathBuf>,
 }
 
 impl Deref for FileWatcher {
@@ -47,7 +54,35 @@ impl FileWatcher {
         update_notifier: Option<UpdateNotifier>,
         library_repo: Option<LibraryRepository>,
         recent_writes: Option<RecentWrites>,
+        stats_cache: StatisticsParseCache,
     ) -> Self {
+        // Only output inside a recursively watched directory can loop, so
+        // other setups skip resolving every event path.
+        let mut watched_roots: Vec<&Path> =
+            config.library_paths.iter().map(PathBuf::as_path).collect();
+        match &config.metadata_location {
+            MetadataLocation::DocSettings(path) | MetadataLocation::HashDocSettings(path) => {
+                watched_roots.push(path)
+            }
+            MetadataLocation::InBookFolder => {}
+        }
+        let output_dirs: Vec<PathBuf> = output_dirs(&config)
+            .into_iter()
+            .filter(|output_dir| {
+                let Some(root) = watched_roots
+                    .iter()
+                    .find(|root| output_dir.starts_with(normalize_path(root)))
+                else {
+                    return false;
+                };
+                warn!(
+                    "{:?} is inside the watched path {:?}; changes KoShelf writes there are ignored",
+                    output_dir, root
+                );
+                true
+            })
+            .collect();
+
         Self {
             config,
             site_store,
@@ -55,11 +90,15 @@ impl FileWatcher {
             update_notifier,
             library_repo,
             recent_writes,
+            stats_cache,
+            output_dirs,
         }
     }
 
-    /// Start watching and processing file changes. Blocks until an error occurs.
-    pub async fn run(self) -> Result<()> {
+    /// Start watching and processing file changes. Blocks until an error occurs
+    /// or `shutdown` is requested, in which case a rebuild already in progress
+    /// is allowed to finish first.
+    pub async fn run(mut self, shutdown: Shutdown) -> Result<()> {
         let (file_tx, mut file_rx) = mpsc::unbounded_channel();
         let (rebuild_tx, mut rebuild_rx) = mpsc::unbounded_channel::<Vec<PathBuf>>();
 
@@ -96,12 +135,12 @@ impl FileWatcher {
             }
         }
 
-        let mut watched_db_parents: HashSet<PathBuf> = HashSet::new();
+        let mut watched_parents: HashSet<PathBuf> = HashSet::new();
         for stats_path in &self.statistics_db_paths {
             if stats_path.exists()
                 && let Some(parent) = stats_path.parent()
             {
-                if watched_db_parents.insert(parent.to_path_buf()) {
+                if watched_parents.insert(parent.to_path_buf()) {
                     watcher.watch(parent, RecursiveMode::NonRecursive)?;
                 }
                 watched.push(format!("{}", stats_path.display()));
@@ -112,13 +151,27 @@ impl FileWatcher {
             && kobo_db_path.exists()
             && let Some(parent) = kobo_db_path.parent()
         {
-            if watched_db_parents.insert(parent.to_path_buf()) {
+            if watched_parents.insert(parent.to_path_buf()) {
                 watcher.watch(parent, RecursiveMode::NonRecursive)?;
             }
             watched.push(format!("{}", kobo_db_path.display()));
         }
 
+        for watch_path in &self.watch_paths {
+            if watch_path.is_dir() {
+                watcher.watch(watch_path, RecursiveMode::Recursive)?;
+            } else if let Some(parent) = watch_path.parent() {
+                // Watch the directory so a file that editors replace on save
+                // keeps being watched.
+                if watched_parents.insert(parent.to_path_buf()) {
+                    watcher.watch(parent, RecursiveMode::NonRecursive)?;
+                }
+            }
+            watched.push(format!("{}", watch_path.display()));
+        }
+
         info!(
+            phase = "watch_started", paths = watched.len();
             "File watcher started for {} paths: {}",
             watched.len(),
             collapse_paths(&watched)
@@ -129,6 +182,9 @@ impl FileWatcher {
         let reading_data_store_clone = self.reading_data_store.clone();
         let update_notifier_clone = self.update_notifier.clone();
         let library_repo_clone = self.library_repo.clone();
+        let shutdown_clone = shutdown.clone();
+        let rebuild_hook = self.config.on_rebuild.clone().map(RebuildHook::new);
+        let mut stats_cache = std::mem::take(&mut self.stats_cache);
 
         // NOTE: Statistics loading uses non-Send types (e.g. mlua::Lua, Rc-based translations),
         // so this rebuild loop must not be spawned onto the multithreaded executor.
@@ -159,12 +215,23 @@ impl FileWatcher {
                         }
                     }
 
+                    // Changes that arrived after shutdown was requested are
+                    // picked up on the next start.
+                    if shutdown_clone.is_requested() {
+                        return;
+                    }
+
                     log_accumulated_paths(
                         &accumulated_paths,
                         &config_clone.statistics_db_paths,
                         config_clone.kobo_db_path.as_deref(),
+                        &config_clone.watch_paths,
                     );
 
+                    let _rebuild_guard = update_notifier_clone
+                        .as_ref()
+                        .map(|notifier| notifier.begin_rebuild());
+
                     let result = if let Some(ref repo) = library_repo_clone {
                         rebuild(
                             accumulated_paths,
@@ -173,6 +240,7 @@ impl FileWatcher {
                             site_store_clone.as_ref(),
                             reading_data_store_clone.as_ref(),
                             update_notifier_clone.as_ref(),
+                            &mut stats_cache,
                         )
                         .await
                     } else {
@@ -182,18 +250,37 @@ impl FileWatcher {
                         continue;
                     };
 
-                    if let Err(e) = result {
-                        warn!("Rebuild failed: {}", e);
+                    match result {
+                        Ok(summary) => {
+                            if let Some(ref notifier) = update_notifier_clone {
+                                notifier.record_build(config_clone.time_config.now_rfc3339());
+                            }
+                            if let Some(ref hook) = rebuild_hook {
+                                hook.notify(summary);
+                            }
+                        }
+                        Err(e) => {
+                            warn!(phase = "rebuild_failed", error:% = e; "Rebuild failed: {}", e)
+                        }
                     }
                 }
             })
         });
 
         // Main file event processing loop
-        while let Some(event) = file_rx.recv().await {
+        while let Some(event) = tokio::select! {
+            event = file_rx.recv() => event,
+            () = shutdown.requested() => None,
+        } {
             if self.is_relevant_event(&event) {
-                // Filter out paths recently written by our own write handlers.
-                let paths = self.filter_recent_writes(event.paths);
+                // Filter out our own output and paths recently written by our
+                // own write handlers.
+                let paths: Vec<PathBuf> = event
+                    .paths
+                    .into_iter()
+                    .filter(|path| !is_inside_any(path, &self.output_dirs))
+                    .collect();
+                let paths = self.filter_recent_writes(paths);
                 if paths.is_empty() {
                     continue;
                 }
@@ -202,7 +289,18 @@ impl FileWatcher {
             }
         }
 
-        rebuild_task.abort();
+        if !shutdown.is_requested() {
+            rebuild_task.abort();
+            return Ok(());
+        }
+
+        // Closing the channel ends the rebuild loop once the current rebuild,
+        // if any, has finished.
+        drop(watcher);
+        drop(rebuild_tx);
+        if let Err(e) = rebuild_task.await {
+            warn!("Rebuild task failed during shutdown: {}", e);
+        }
         Ok(())
     }
 
@@ -232,6 +330,13 @@ impl FileWatcher {
         paths.iter().any(|path| {
             let filename = path.file_name().and_then(|s| s.to_str());
 
+            if self
+                .watch_paths
+                .iter()
+                .any(|watch_path| path.starts_with(watch_path))
+            {
+                return true;
+            }
             if LibraryItemFormat::from_path(path).is_some() {
                 return true;
             }
@@ -296,6 +401,42 @@ impl FileWatcher {
     }
 }
 
+/// The output directory and persistent data directory, resolved like event
+/// paths so that symlinks and relative paths cannot hide an overlap.
+fn output_dirs(config: &SiteConfig) -> Vec<PathBuf> {
+    [
+        Some(config.output_dir.as_path()),
+        config.runtime_data_policy.persistent_data_dir(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|dir| !dir.as_os_str().is_empty())
+    .map(normalize_path)
+    .collect()
+}
+
+/// Resolve symlinks and make `path` absolute. A path that no longer exists,
+/// such as a removed file, is resolved through its parent directory.
+fn normalize_path(path: &Path) -> PathBuf {
+    if let Ok(resolved) = std::fs::canonicalize(path) {
+        return resolved;
+    }
+    if let (Some(parent), Some(name)) = (path.parent(), path.file_name())
+        && let Ok(parent) = std::fs::canonicalize(parent)
+    {
+        return parent.join(name);
+    }
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn is_inside_any(path: &Path, dirs: &[PathBuf]) -> bool {
+    if dirs.is_empty() {
+        return false;
+    }
+    let path = normalize_path(path);
+    dirs.iter().any(|dir| path.starts_with(dir))
+}
+
 /// Format a list of paths by factoring out the longest common directory prefix.
 /// e.g. ["/a/b/Books", "/a/b/Comics", "/a/b/Stats/db"] → "/a/b/{Books, Comics, Stats/db}"
 fn collapse_paths(paths: &[String]) -> String {
@@ -331,29 +472,99 @@ fn log_accumulated_paths(
     paths: &HashSet<PathBuf>,
     statistics_db_paths: &[PathBuf],
     kobo_db_path: Option<&std::path::Path>,
+    watch_paths: &[PathBuf],
 ) {
     for path in paths {
         let filename = path.file_name().and_then(|s| s.to_str());
 
         if let Some(format) = LibraryItemFormat::from_path(path) {
-            info!("{:?} file changed: {:?}", format, path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "{:?} file changed: {:?}",
+                format, path
+            );
         } else if LibraryItemFormat::is_metadata_path(path) {
-            info!("Metadata file changed: {:?}", path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "Metadata file changed: {:?}",
+                path
+            );
         } else if let Some(filename) = filename
             && filename.ends_with(".sdr")
         {
-            info!("KoReader metadata directory changed: {:?}", path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "KoReader metadata directory changed: {:?}",
+                path
+            );
         } else if statistics_db_paths
             .iter()
             .any(|stats_path| is_sqlite_db_or_companion(path, stats_path))
         {
-            info!("Statistics database changed: {:?}", path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "Statistics database changed: {:?}",
+                path
+            );
         } else if let Some(kobo_db_path) = kobo_db_path
             && is_sqlite_db_or_companion(path, kobo_db_path)
         {
-            info!("Kobo database changed: {:?}", path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "Kobo database changed: {:?}",
+                path
+            );
         } else if kobo_db_path.is_some() && path.extension().is_none() {
-            info!("Extensionless Kobo candidate changed: {:?}", path);
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "Extensionless Kobo candidate changed: {:?}",
+                path
+            );
+        } else if watch_paths
+            .iter()
+            .any(|watch_path| path.starts_with(watch_path))
+        {
+            info!(
+                phase = "change_detected", path:% = path.display();
+                "Watched path changed: {:?}",
+                path
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_inside_any, normalize_path};
+    use std::fs;
+
+    #[test]
+    fn own_output_is_recognized_through_symlinks_and_removed_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let library = dir.path().join("library");
+        let output = library.join("site");
+        fs::create_dir_all(output.join("assets")).expect("output dir");
+        let output_dirs = vec![normalize_path(&output)];
+
+        let cover = output.join("assets").join("cover.webp");
+        fs::write(&cover, b"webp").expect("cover");
+        assert!(is_inside_any(&cover, &output_dirs));
+        // Removed files are still matched through their directory.
+        assert!(is_inside_any(
+            &output.join("assets").join("gone.webp"),
+            &output_dirs
+        ));
+        assert!(!is_inside_any(&library.join("book.epub"), &output_dirs));
+
+        #[cfg(unix)]
+        {
+            let link = dir.path().join("linked-library");
+            std::os::unix::fs::symlink(&library, &link).expect("symlink");
+            assert!(is_inside_any(
+                &link.join("site").join("assets").join("cover.webp"),
+                &output_dirs
+            ));
+            assert!(!is_inside_any(&link.join("book.epub"), &output_dirs));
         }
     }
 }
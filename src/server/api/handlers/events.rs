@@ -2,7 +2,7 @@ use axum::{
     extract::State,
     response::sse::{Event, KeepAlive, Sse},
 };
-use futures::stream;
+use futures::{StreamExt, stream};
 use std::{convert::Infallible, time::Duration};
 
 use crate::server::ServerState;
@@ -21,6 +21,7 @@ pub(crate) async fn events_stream(
     State(state): State<ServerState>,
 ) -> Sse<impl futures::Stream<Item = Result<Event, Infallible>>> {
     let receiver = state.update_notifier.subscribe();
+    let shutdown = state.shutdown.clone();
     let events = stream::unfold(
         (receiver, true),
         |(mut receiver, include_current)| async move {
@@ -37,7 +38,10 @@ pub(crate) async fn events_stream(
                 Err(_) => None,
             }
         },
-    );
+    )
+    // End the stream on shutdown so graceful shutdown does not wait on it;
+    // the client reconnects once the server is back.
+    .take_until(async move { shutdown.requested().await });
 
     Sse::new(events).keep_alive(
         KeepAlive::new()
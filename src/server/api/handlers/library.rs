@@ -12,8 +12,8 @@ use crate::source::koreader::{lua_writer, mutations};
 use axum::{
     Json,
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{StatusCode, Uri, header::LOCATION},
+    response::{IntoResponse, Response},
 };
 use chrono::{Local, TimeZone, Utc};
 use log::warn;
@@ -39,6 +39,64 @@ fn now_in_tz(tz: Option<&chrono_tz::Tz>, fmt: &str) -> String {
     }
 }
 
+/// Resolve the `{id}` path segment, which is an item ID or, with
+/// `--id-scheme slug`, a current URL slug, or a retired slug or ID.
+async fn resolve_item_id(state: &ServerState, key: &str) -> ApiResult<String> {
+    state
+        .library_repo
+        .resolve_item_key(key)
+        .await
+        .map_err(|e| {
+            warn!("Failed to resolve item {}: {}", key, e);
+            ApiResponseError::internal_server_error()
+        })?
+        .ok_or_else(ApiResponseError::not_found)
+}
+
+/// A `301` to the item's current key when `key` is a slug or ID the item no
+/// longer uses. `suffix` is the part of the path after the key.
+async fn retired_slug_redirect(
+    state: &ServerState,
+    key: &str,
+    suffix: &str,
+    uri: &Uri,
+) -> ApiResult<Option<Response>> {
+    let target = state
+        .library_repo
+        .retired_slug_target(key)
+        .await
+        .map_err(|e| {
+            warn!("Failed to look up retired slug {}: {}", key, e);
+            ApiResponseError::internal_server_error()
+        })?;
+    let Some(target) = target else {
+        return Ok(None);
+    };
+
+    let mut location = format!("/api/items/{}{}", encode_path_segment(&target), suffix);
+    if let Some(query) = uri.query() {
+        location.push('?');
+        location.push_str(query);
+    }
+    Ok(Some(
+        (StatusCode::MOVED_PERMANENTLY, [(LOCATION, location)]).into_response(),
+    ))
+}
+
+/// Percent-encode everything but ASCII letters, digits and `-`, which covers
+/// item IDs and slugs.
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
 pub(crate) async fn items(
     State(state): State<ServerState>,
     Query(query): Query<ScopeQuery>,
@@ -63,10 +121,16 @@ pub(crate) async fn item_detail(
     State(state): State<ServerState>,
     Path(id): Path<String>,
     Query(detail_query): Query<DetailQuery>,
-) -> ApiResult<impl IntoResponse> {
+    uri: Uri,
+) -> ApiResult<Response> {
+    if let Some(redirect) = retired_slug_redirect(&state, &id, "", &uri).await? {
+        return Ok(redirect);
+    }
+    let id = resolve_item_id(&state, &id).await?;
     let includes = parse_include(detail_query.include.as_deref())?;
 
-    let query = LibraryDetailQuery::new(id, includes);
+    let query = LibraryDetailQuery::new(id, includes)
+        .with_description_max_chars(state.description_max_chars);
     let reading_data = state.reading_data_store.get();
 
     let payload = library::detail(&state.library_repo, &query, reading_data.as_deref())
@@ -74,14 +138,19 @@ pub(crate) async fn item_detail(
         .map_err(|_| ApiResponseError::internal_server_error())?
         .ok_or_else(ApiResponseError::not_found)?;
 
-    Ok(Json(ApiResponse::new(payload)))
+    Ok(Json(ApiResponse::new(payload)).into_response())
 }
 
 pub(crate) async fn item_page_activity(
     State(state): State<ServerState>,
     Path(id): Path<String>,
     Query(params): Query<PageActivityParams>,
-) -> ApiResult<impl IntoResponse> {
+    uri: Uri,
+) -> ApiResult<Response> {
+    if let Some(redirect) = retired_slug_redirect(&state, &id, "/page-activity", &uri).await? {
+        return Ok(redirect);
+    }
+    let id = resolve_item_id(&state, &id).await?;
     let completion_filter = parse_page_activity_completion(params.completion.as_deref())?;
     let reading_data = state.reading_data_store.get();
 
@@ -98,7 +167,94 @@ pub(crate) async fn item_page_activity(
     })?
     .ok_or_else(ApiResponseError::not_found)?;
 
-    Ok(Json(ApiResponse::new(result.response)))
+    Ok(Json(ApiResponse::new(result.response)).into_response())
+}
+
+pub(crate) async fn highlights(State(state): State<ServerState>) -> ApiResult<impl IntoResponse> {
+    let payload = library::highlights(&state.library_repo)
+        .await
+        .map_err(|e| {
+            warn!("Failed to list highlights: {}", e);
+            ApiResponseError::internal_server_error()
+        })?;
+
+    Ok(Json(ApiResponse::new(payload)))
+}
+
+pub(crate) async fn series(State(state): State<ServerState>) -> ApiResult<impl IntoResponse> {
+    let reading_data = state.reading_data_store.get();
+    let payload = library::series_list(
+        &state.library_repo,
+        reading_data.as_deref(),
+        state.series_min_items,
+    )
+    .await
+    .map_err(|e| {
+        warn!("Failed to list series: {}", e);
+        ApiResponseError::internal_server_error()
+    })?;
+
+    Ok(Json(ApiResponse::new(payload)))
+}
+
+pub(crate) async fn series_detail(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let reading_data = state.reading_data_store.get();
+    let payload = library::series_detail(
+        &state.library_repo,
+        &slug,
+        reading_data.as_deref(),
+        state.series_min_items,
+    )
+    .await
+    .map_err(|e| {
+        warn!("Failed to load series {}: {}", slug, e);
+        ApiResponseError::internal_server_error()
+    })?
+    .ok_or_else(ApiResponseError::not_found)?;
+
+    Ok(Json(ApiResponse::new(payload)))
+}
+
+pub(crate) async fn up_next(State(state): State<ServerState>) -> ApiResult<impl IntoResponse> {
+    let payload = library::up_next(&state.library_repo, state.series_min_items)
+        .await
+        .map_err(|e| {
+            warn!("Failed to list up next items: {}", e);
+            ApiResponseError::internal_server_error()
+        })?;
+
+    Ok(Json(ApiResponse::new(payload)))
+}
+
+pub(crate) async fn authors(State(state): State<ServerState>) -> ApiResult<impl IntoResponse> {
+    let reading_data = state.reading_data_store.get();
+    let payload = library::author_list(&state.library_repo, reading_data.as_deref())
+        .await
+        .map_err(|e| {
+            warn!("Failed to list authors: {}", e);
+            ApiResponseError::internal_server_error()
+        })?;
+
+    Ok(Json(ApiResponse::new(payload)))
+}
+
+pub(crate) async fn author_detail(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let reading_data = state.reading_data_store.get();
+    let payload = library::author_detail(&state.library_repo, &slug, reading_data.as_deref())
+        .await
+        .map_err(|e| {
+            warn!("Failed to load author {}: {}", slug, e);
+            ApiResponseError::internal_server_error()
+        })?
+        .ok_or_else(ApiResponseError::not_found)?;
+
+    Ok(Json(ApiResponse::new(payload)))
 }
 
 // ── Write handlers (requires enable_writeback) ───────────────────────────
@@ -290,6 +446,7 @@ pub(crate) async fn update_item(
     Path(id): Path<String>,
     Json(body): Json<UpdateItemRequest>,
 ) -> ApiResult<impl IntoResponse> {
+    let id = resolve_item_id(&state, &id).await?;
     if let Patch::Value(rating) = body.rating
         && rating > 5
     {
@@ -356,6 +513,7 @@ pub(crate) async fn update_annotation(
     Path((id, annotation_id)): Path<(String, String)>,
     Json(body): Json<UpdateAnnotationRequest>,
 ) -> ApiResult<impl IntoResponse> {
+    let id = resolve_item_id(&state, &id).await?;
     if let Some(ref color) = body.color
         && !VALID_COLORS.contains(&color.as_str())
     {
@@ -444,6 +602,7 @@ pub(crate) async fn delete_annotation(
     State(state): State<ServerState>,
     Path((id, annotation_id)): Path<(String, String)>,
 ) -> ApiResult<impl IntoResponse> {
+    let id = resolve_item_id(&state, &id).await?;
     let (lua_index, had_note) = state
         .library_repo
         .find_annotation_write_info(&id, &annotation_id)
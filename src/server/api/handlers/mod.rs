@@ -4,10 +4,11 @@ mod reading;
 mod site;
 pub(crate) use events::events_stream;
 pub(crate) use library::{
-    delete_annotation, item_detail, item_page_activity, items, update_annotation, update_item,
+    author_detail, authors, delete_annotation, highlights, item_detail, item_page_activity, items,
+    series, series_detail, up_next, update_annotation, update_item,
 };
 pub(crate) use reading::{
-    reading_available_periods, reading_calendar, reading_completions, reading_metrics,
-    reading_summary,
+    reading_abandoned, reading_available_periods, reading_calendar, reading_completions,
+    reading_finished, reading_genres, reading_metrics, reading_pace, reading_summary,
 };
 pub(crate) use site::site;
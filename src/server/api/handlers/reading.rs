@@ -4,13 +4,15 @@ use crate::server::ServerState;
 use crate::server::api::error::{ApiResponseError, ApiResult};
 use crate::server::api::extractors::ReadingDataGuard;
 use crate::server::api::params::{
-    ReadingAvailablePeriodsParams, ReadingCalendarParams, ReadingCompletionsParams,
-    ReadingMetricsParams, ReadingSummaryParams, parse_reading_available_periods_query,
+    ReadingAbandonedParams, ReadingAvailablePeriodsParams, ReadingCalendarParams,
+    ReadingCompletionsParams, ReadingFinishedParams, ReadingGenresParams, ReadingMetricsParams,
+    ReadingPaceParams, ReadingSummaryParams, parse_reading_available_periods_query,
     parse_reading_calendar_query, parse_reading_completions_query, parse_reading_metrics_query,
-    parse_reading_summary_query,
+    parse_reading_summary_query, parse_scope,
 };
 use crate::server::api::responses::common::ApiResponse;
 use crate::shelf::statistics;
+use log::warn;
 
 pub(crate) async fn reading_summary(
     reading_data: ReadingDataGuard,
@@ -64,3 +66,53 @@ pub(crate) async fn reading_completions(
     let data = statistics::completions(&reading_data, &state.library_repo, query).await;
     Ok(Json(ApiResponse::new(data)))
 }
+
+pub(crate) async fn reading_genres(
+    State(state): State<ServerState>,
+    Query(params): Query<ReadingGenresParams>,
+) -> ApiResult<impl IntoResponse> {
+    let scope = parse_scope(params.scope.as_deref())?;
+    let data = statistics::genres(&state.library_repo, scope)
+        .await
+        .map_err(|e| {
+            warn!("Failed to compute genre counts: {}", e);
+            ApiResponseError::internal_server_error()
+        })?;
+    Ok(Json(ApiResponse::new(data)))
+}
+
+pub(crate) async fn reading_pace(
+    reading_data: ReadingDataGuard,
+    Query(params): Query<ReadingPaceParams>,
+) -> ApiResult<impl IntoResponse> {
+    let scope = parse_scope(params.scope.as_deref())?;
+    let data = statistics::pace(&reading_data, scope);
+    Ok(Json(ApiResponse::new(data)))
+}
+
+pub(crate) async fn reading_finished(
+    reading_data: ReadingDataGuard,
+    Query(params): Query<ReadingFinishedParams>,
+) -> ApiResult<impl IntoResponse> {
+    let scope = parse_scope(params.scope.as_deref())?;
+    let data = statistics::finished(&reading_data, scope);
+    Ok(Json(ApiResponse::new(data)))
+}
+
+pub(crate) async fn reading_abandoned(
+    State(state): State<ServerState>,
+    Query(params): Query<ReadingAbandonedParams>,
+) -> ApiResult<impl IntoResponse> {
+    let reading_data = state
+        .reading_data_store
+        .get()
+        .ok_or_else(ApiResponseError::internal_server_error)?;
+    let scope = parse_scope(params.scope.as_deref())?;
+    let data = statistics::abandoned(&reading_data, &state.library_repo, scope)
+        .await
+        .map_err(|e| {
+            warn!("Failed to compute abandoned items: {}", e);
+            ApiResponseError::internal_server_error()
+        })?;
+    Ok(Json(ApiResponse::new(data)))
+}
@@ -18,6 +18,12 @@ pub fn routes() -> Router<ServerState> {
             "/api/items/{id}/page-activity",
             get(handlers::item_page_activity),
         )
+        .route("/api/highlights", get(handlers::highlights))
+        .route("/api/series", get(handlers::series))
+        .route("/api/series/{slug}", get(handlers::series_detail))
+        .route("/api/up-next", get(handlers::up_next))
+        .route("/api/authors", get(handlers::authors))
+        .route("/api/authors/{slug}", get(handlers::author_detail))
         .route("/api/reading/summary", get(handlers::reading_summary))
         .route("/api/reading/metrics", get(handlers::reading_metrics))
         .route(
@@ -29,6 +35,10 @@ pub fn routes() -> Router<ServerState> {
             "/api/reading/completions",
             get(handlers::reading_completions),
         )
+        .route("/api/reading/genres", get(handlers::reading_genres))
+        .route("/api/reading/pace", get(handlers::reading_pace))
+        .route("/api/reading/finished", get(handlers::reading_finished))
+        .route("/api/reading/abandoned", get(handlers::reading_abandoned))
         .route("/api/events/stream", get(handlers::events_stream))
 }
 
@@ -43,11 +53,21 @@ pub fn route_paths() -> &'static [&'static str] {
         "/api/items",
         "/api/items/{id}",
         "/api/items/{id}/page-activity",
+        "/api/highlights",
+        "/api/series",
+        "/api/series/{slug}",
+        "/api/up-next",
+        "/api/authors",
+        "/api/authors/{slug}",
         "/api/reading/summary",
         "/api/reading/metrics",
         "/api/reading/available-periods",
         "/api/reading/calendar",
         "/api/reading/completions",
+        "/api/reading/genres",
+        "/api/reading/pace",
+        "/api/reading/finished",
+        "/api/reading/abandoned",
         "/api/events/stream",
     ]
 }
@@ -76,6 +76,26 @@ pub(crate) struct ReadingCompletionsParams {
     pub tz: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct ReadingGenresParams {
+    pub scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ReadingPaceParams {
+    pub scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ReadingFinishedParams {
+    pub scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ReadingAbandonedParams {
+    pub scope: Option<String>,
+}
+
 // ── Parsing helpers ────────────────────────────────────────────────────────
 
 pub(crate) fn parse_scope(value: Option<&str>) -> ApiResult<ContentTypeFilter> {
@@ -97,7 +117,7 @@ pub(crate) fn parse_item_sort(value: Option<&str>) -> ApiResult<ItemSort> {
         Some(v) => ItemSort::parse(v).map_err(|_| {
             ApiResponseError::bad_request_with_message(
                 ApiErrorCode::InvalidQuery,
-                "sort must be one of: title, author, status, progress, rating, annotations, last_open_at",
+                "sort must be one of: title, author, status, progress, rating, annotations, last_open_at, added_at",
             )
         }),
     }
@@ -28,6 +28,15 @@ pub struct LibrarySeries {
     pub index: Option<String>,
 }
 
+/// Non-author contributors of an item.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryContributors {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub editors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translators: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExternalIdentifier {
     pub scheme: String,
@@ -43,6 +52,9 @@ pub struct ExternalIdentifier {
 pub struct LibraryListItem {
     pub id: String,
     pub title: String,
+    /// Sort form of the title, e.g. "Hobbit, The".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_sort: Option<String>,
     #[sqlx(rename = "authors_json")]
     pub authors: Json<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,8 +67,18 @@ pub struct LibraryListItem {
     pub rating: Option<i32>,
     #[serde(default)]
     pub annotation_count: i32,
+    /// Highlights and notes; `annotation_count` minus the bookmarks.
+    #[serde(default)]
+    pub highlight_count: i32,
+    #[serde(default)]
+    pub bookmark_count: i32,
     pub cover_url: String,
     pub content_type: LibraryContentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<String>,
+    /// Readable URL alias, only set with `--id-scheme slug`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -77,11 +99,24 @@ pub struct LibraryDetailItem {
     pub content_type: LibraryContentType,
     pub format: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[sqlx(rename = "contributors_json")]
+    pub contributors: Option<Json<LibraryContributors>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<String>,
+    /// Annotations and the review note are withheld for private items.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_private: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub publisher: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Shortened `description` shown until "read more" is clicked; only set
+    /// when the description is longer than `--description-max-chars`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub description_excerpt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub review_note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,6 +136,8 @@ pub struct LibraryDetailItem {
     /// Used internally for statistics lookup; not exposed in API responses.
     #[serde(skip)]
     pub partial_md5_checksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -128,6 +165,17 @@ pub struct LibraryAnnotation {
     pub drawer: Option<String>,
 }
 
+/// Highlight text joined with its owning item, used by `/api/highlights`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct HighlightQuote {
+    pub item_id: String,
+    pub item_title: String,
+    pub content_type: LibraryContentType,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapter: Option<String>,
+}
+
 // ── Response wrappers ─────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -135,6 +183,11 @@ pub struct LibraryListData {
     pub items: Vec<LibraryListItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighlightsData {
+    pub highlights: Vec<HighlightQuote>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LibraryDetailData {
     pub item: LibraryDetailItem,
@@ -150,6 +203,8 @@ pub struct LibraryDetailData {
     pub reader_presentation: Option<Json<LibraryReaderPresentation>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chapters: Option<Vec<ChapterEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeline: Option<LibraryTimeline>,
 }
 
 // ── Statistics (non-DB, mapped in service layer) ──────────────────────
@@ -168,6 +223,8 @@ pub struct LibraryItemStats {
     pub pages: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_reading_time_sec: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_read_pages: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -181,6 +238,8 @@ pub struct LibrarySessionStats {
     pub last_read_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reading_speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages_per_day: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -208,6 +267,43 @@ pub struct LibraryCompletions {
     pub last_completion_date: Option<String>,
 }
 
+// ── Timeline ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LibraryTimelineEntryKind {
+    Session,
+    Highlight,
+    Note,
+    Bookmark,
+}
+
+/// One reading session or annotation on the item's reading history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryTimelineEntry {
+    pub kind: LibraryTimelineEntryKind,
+    /// RFC 3339 start of the session, or when the annotation was made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Reading day (`YYYY-MM-DD`) under the configured day start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_sec: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages_read: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<LibraryAnnotation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryTimeline {
+    /// Sessions and dated annotations, oldest first.
+    pub entries: Vec<LibraryTimelineEntry>,
+    /// Annotations without a usable datetime, in KOReader's order.
+    pub undated: Vec<LibraryTimelineEntry>,
+}
+
 // ── PageActivity ──────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -251,3 +347,79 @@ pub struct PageActivityEvent {
     pub start_time: i64,
     pub duration: i64,
 }
+
+// ── Series ────────────────────────────────────────────────────────────
+
+/// A series with its combined reading progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibrarySeriesSummary {
+    pub slug: String,
+    pub name: String,
+    pub item_count: usize,
+    pub completed_count: usize,
+    pub reading_count: usize,
+    pub abandoned_count: usize,
+    /// Reading time across all items, from the statistics database.
+    pub total_reading_time_sec: i64,
+    /// Cover of the first item in reading order.
+    pub cover_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibrarySeriesListData {
+    pub series: Vec<LibrarySeriesSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibrarySeriesDetailData {
+    pub series: LibrarySeriesSummary,
+    /// Items in reading order; items without a series number come last.
+    pub items: Vec<LibraryListItem>,
+}
+
+/// The next unread item of a series the reader has started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryUpNextEntry {
+    pub series_slug: String,
+    pub series_name: String,
+    pub completed_count: usize,
+    pub item_count: usize,
+    pub item: LibraryListItem,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryUpNextData {
+    pub entries: Vec<LibraryUpNextEntry>,
+}
+
+// ── Authors ───────────────────────────────────────────────────────────
+
+/// An author with the ratings and reading time of their items.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryAuthorSummary {
+    pub slug: String,
+    pub name: String,
+    pub item_count: usize,
+    pub completed_count: usize,
+    pub reading_count: usize,
+    pub rated_count: usize,
+    /// Mean rating of the rated items, `None` when none are rated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_rating: Option<f64>,
+    /// Reading time across all items, from the statistics database.
+    pub total_reading_time_sec: i64,
+    /// Cover of the first item by title.
+    pub cover_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryAuthorListData {
+    pub authors: Vec<LibraryAuthorSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryAuthorDetailData {
+    pub author: LibraryAuthorSummary,
+    /// Items ordered by title, including those written with co-authors.
+    pub items: Vec<LibraryListItem>,
+}
@@ -12,6 +12,7 @@ pub struct ReadingSummaryData {
     pub range: ResolvedRange,
     pub overview: ReadingOverview,
     pub streaks: ReadingStreaks,
+    pub weekday_split: WeekdaySplit,
     pub heatmap_config: HeatmapConfig,
 }
 
@@ -52,6 +53,24 @@ pub struct StreakData {
     pub end_date: Option<String>,
 }
 
+/// Reading on weekdays (Monday to Friday) compared with weekends.
+#[derive(Debug, Clone, Serialize)]
+pub struct WeekdaySplit {
+    pub weekdays: DayKindStats,
+    pub weekends: DayKindStats,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayKindStats {
+    pub reading_time_sec: i64,
+    pub pages_read: i64,
+    /// Days of this kind with any reading.
+    pub active_days: i64,
+    /// Averages over every day of this kind in the range, read or not.
+    pub average_reading_time_sec: i64,
+    pub average_pages_read: i64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct HeatmapConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -106,9 +125,19 @@ pub struct ReadingCalendarData {
     pub month: String,
     pub events: Vec<ReadingCalendarEvent>,
     pub items: BTreeMap<String, CalendarItemRef>,
+    /// Reading per day of the month (`YYYY-MM-DD`), most read first.
+    pub days: BTreeMap<String, Vec<CalendarDayEntry>>,
     pub stats_by_scope: CalendarStatsByScope,
 }
 
+/// What was read of one item on one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarDayEntry {
+    pub item_ref: String,
+    pub reading_time_sec: i64,
+    pub pages_read: i64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ReadingCalendarEvent {
     pub item_ref: String,
@@ -126,8 +155,13 @@ pub struct CalendarItemRef {
     pub content_type: LibraryContentType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub item_id: Option<String>,
+    /// Index into the calendar's event color palette.
+    pub color_index: u8,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub item_cover: Option<String>,
+    /// Set when the book's file is gone and only its statistics remain.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub removed: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -145,6 +179,82 @@ pub struct CalendarScopeStats {
     pub active_days_percentage: u8,
 }
 
+// ── GET /api/reading/genres ───────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadingGenresData {
+    pub genres: Vec<GenreCount>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenreCount {
+    pub name: String,
+    pub count: usize,
+}
+
+// ── GET /api/reading/pace ─────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadingPaceData {
+    pub sessions: usize,
+    pub median_pages_per_hour: Option<f64>,
+    pub buckets: Vec<PaceBucket>,
+}
+
+/// Sessions whose pace falls in `[min, max)` pages per hour. The last bucket
+/// has no `max` when it collects outliers above the regular range.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaceBucket {
+    pub min_pages_per_hour: u32,
+    pub max_pages_per_hour: Option<u32>,
+    pub sessions: usize,
+}
+
+// ── GET /api/reading/finished ─────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadingFinishedData {
+    pub months: Vec<FinishedCount>,
+    pub years: Vec<FinishedCount>,
+}
+
+/// Completions that ended in a period (`YYYY-MM` or `YYYY`).
+#[derive(Debug, Clone, Serialize)]
+pub struct FinishedCount {
+    pub period: String,
+    pub completions: usize,
+}
+
+// ── GET /api/reading/abandoned ────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadingAbandonedData {
+    pub years: Vec<AbandonedYear>,
+    pub items: Vec<AbandonedItem>,
+}
+
+/// Items given up on in a year, by the date of their last reading session.
+#[derive(Debug, Clone, Serialize)]
+pub struct AbandonedYear {
+    pub year: i32,
+    pub items: usize,
+    pub reading_time_sec: i64,
+}
+
+/// A library item marked as abandoned, with the time spent on its unfinished read.
+#[derive(Debug, Clone, Serialize)]
+pub struct AbandonedItem {
+    pub id: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub cover_url: String,
+    pub content_type: LibraryContentType,
+    pub reading_time_sec: i64,
+    /// Omitted when the item has no reading statistics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read_date: Option<String>,
+}
+
 // ── GET /api/reading/completions ──────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize)]
@@ -157,12 +267,16 @@ pub struct ReadingCompletionsData {
     pub summary: Option<CompletionsSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub share_assets: Option<CompletionsShareAssets>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_items: Option<CompletionsTopItems>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CompletionGroup {
     pub key: String,
     pub items_finished: usize,
+    /// Reads started this month, counted by the date of their first session.
+    pub items_started: usize,
     pub reading_time_sec: i64,
     pub items: Vec<CompletionItem>,
 }
@@ -176,6 +290,9 @@ pub struct CompletionItem {
     pub reading_time_sec: i64,
     pub session_count: i64,
     pub pages_read: i64,
+    /// Page count of the book itself; omitted when KOReader never recorded one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calendar_length_days: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -194,11 +311,15 @@ pub struct CompletionItem {
     pub item_cover: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<LibraryContentType>,
+    /// Set when the book's file is gone and only its statistics remain.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub removed: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CompletionsSummary {
     pub total_items: usize,
+    pub total_started: usize,
     pub total_reading_time_sec: i64,
     pub longest_session_duration_sec: i64,
     pub average_session_duration_sec: i64,
@@ -215,3 +336,34 @@ pub struct CompletionsShareAssets {
     pub square_url: String,
     pub banner_url: String,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionsTopItems {
+    pub highest_rated: Vec<CompletionItem>,
+    pub longest: Vec<CompletionItem>,
+    /// Omitted when no completed item in the range has a rating.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating_distribution: Option<Vec<RatingBucket>>,
+    pub superlatives: CompletionSuperlatives,
+}
+
+/// Longest and shortest completed items in the range.
+///
+/// The page-based entries only consider items with a known page count.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionSuperlatives {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_by_pages: Option<CompletionItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortest_by_pages: Option<CompletionItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_by_time: Option<CompletionItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortest_by_time: Option<CompletionItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RatingBucket {
+    pub rating: i32,
+    pub count: usize,
+}
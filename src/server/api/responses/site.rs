@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::app::config::{DateFormat, DurationGranularity};
+use crate::shelf::time_config::WeekStart;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SiteCapabilities {
     pub has_books: bool,
@@ -24,7 +27,17 @@ pub struct SiteAuth {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SiteData {
     pub title: String,
+    /// Navbar image from `--logo`, shown instead of the title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
     pub language: String,
+    pub week_start: WeekStart,
+    /// Library cards shown per section before more load on scroll; 0 = all.
+    pub page_size: usize,
+    pub date_format: DateFormat,
+    pub duration_granularity: DurationGranularity,
+    /// Most units shown per duration; 0 = no limit.
+    pub duration_max_units: usize,
     pub capabilities: SiteCapabilities,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auth: Option<SiteAuth>,
@@ -34,7 +47,13 @@ impl Default for SiteData {
     fn default() -> Self {
         Self {
             title: String::new(),
+            logo_url: None,
             language: "en_US".to_string(),
+            week_start: WeekStart::default(),
+            page_size: 0,
+            date_format: DateFormat::default(),
+            duration_granularity: DurationGranularity::default(),
+            duration_max_units: 0,
             capabilities: SiteCapabilities::default(),
             auth: None,
         }
@@ -0,0 +1,146 @@
+//! Optional HTTP Basic / bearer-token gate in front of every route.
+//!
+//! Independent of the session login (`--enable-auth`): when configured, each
+//! request must carry an `Authorization` header matching the configured
+//! credentials before it reaches any other handler or middleware.
+
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::{Engine as _, engine::general_purpose};
+
+#[derive(Clone)]
+pub struct AccessGate {
+    /// Expected `Basic` credentials, base64 of `user:password`.
+    basic: Option<String>,
+    bearer: Option<String>,
+}
+
+impl AccessGate {
+    /// Build a gate from the configured credentials, or `None` when neither
+    /// Basic credentials nor a token are set.
+    pub fn new(basic: Option<(&str, &str)>, token: Option<&str>) -> Option<Self> {
+        if basic.is_none() && token.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            basic: basic.map(|(user, password)| {
+                general_purpose::STANDARD.encode(format!("{user}:{password}"))
+            }),
+            bearer: token.map(str::to_string),
+        })
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> bool {
+        let Some((scheme, credentials)) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().split_once(' '))
+        else {
+            return false;
+        };
+
+        let expected = if scheme.eq_ignore_ascii_case("basic") {
+            self.basic.as_deref()
+        } else if scheme.eq_ignore_ascii_case("bearer") {
+            self.bearer.as_deref()
+        } else {
+            None
+        };
+
+        expected.is_some_and(|expected| constant_time_eq(credentials.trim(), expected))
+    }
+
+    fn challenges(&self) -> Vec<HeaderValue> {
+        let mut challenges = Vec::new();
+        if self.basic.is_some() {
+            challenges.push(HeaderValue::from_static(
+                "Basic realm=\"KoShelf\", charset=\"UTF-8\"",
+            ));
+        }
+        if self.bearer.is_some() {
+            challenges.push(HeaderValue::from_static("Bearer realm=\"KoShelf\""));
+        }
+        challenges
+    }
+}
+
+pub async fn access_middleware(
+    State(gate): State<AccessGate>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if is_exempt_path(request.uri().path()) || gate.authorize(request.headers()) {
+        return next.run(request).await;
+    }
+
+    let mut response = StatusCode::UNAUTHORIZED.into_response();
+    for challenge in gate.challenges() {
+        response
+            .headers_mut()
+            .append(header::WWW_AUTHENTICATE, challenge);
+    }
+    response
+}
+
+/// Liveness probes stay reachable for monitors, and KOReader's progress sync
+/// authenticates with its own headers and cannot send these credentials.
+fn is_exempt_path(path: &str) -> bool {
+    path == "/healthz" || path == "/users/auth" || path.starts_with("/syncs/")
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(authorization).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn accepts_matching_basic_credentials_or_token() {
+        let gate = AccessGate::new(Some(("reader", "s3cret")), Some("tok")).unwrap();
+
+        // base64("reader:s3cret")
+        assert!(gate.authorize(&headers("Basic cmVhZGVyOnMzY3JldA==")));
+        assert!(gate.authorize(&headers("bearer tok")));
+        assert!(!gate.authorize(&headers("Basic cmVhZGVyOndyb25n")));
+        assert!(!gate.authorize(&headers("Bearer other")));
+        assert!(!gate.authorize(&HeaderMap::new()));
+        assert_eq!(gate.challenges().len(), 2);
+    }
+
+    #[test]
+    fn token_only_gate_rejects_basic_credentials() {
+        let gate = AccessGate::new(None, Some("tok")).unwrap();
+
+        assert!(!gate.authorize(&headers("Basic dG9rOg==")));
+        assert!(gate.authorize(&headers("Bearer tok")));
+        assert!(AccessGate::new(None, None).is_none());
+    }
+
+    #[test]
+    fn health_and_kosync_paths_are_exempt() {
+        assert!(is_exempt_path("/healthz"));
+        assert!(is_exempt_path("/users/auth"));
+        assert!(is_exempt_path("/syncs/progress/abc"));
+        assert!(!is_exempt_path("/status"));
+        assert!(!is_exempt_path("/api/events/stream"));
+        assert!(!is_exempt_path("/"));
+    }
+}
@@ -71,6 +71,11 @@ fn is_protected_path(method: &Method, path: &str) -> bool {
         return true;
     }
 
+    // Icons are needed by the login page and home screen installs.
+    if path.starts_with("/assets/icons/") {
+        return false;
+    }
+
     path == "/assets" || path.starts_with("/assets/")
 }
 
@@ -110,6 +115,21 @@ mod tests {
         assert!(!is_protected_path(&Method::POST, "/api/auth/login"));
     }
 
+    #[test]
+    fn health_probes_are_unprotected() {
+        assert!(!is_protected_path(&Method::GET, "/healthz"));
+        assert!(!is_protected_path(&Method::GET, "/status"));
+    }
+
+    #[test]
+    fn app_icons_are_unprotected() {
+        assert!(!is_protected_path(
+            &Method::GET,
+            "/assets/icons/favicon.ico"
+        ));
+        assert!(is_protected_path(&Method::GET, "/assets/iconsx/secret"));
+    }
+
     #[test]
     fn api_and_assets_routes_are_protected() {
         assert!(is_protected_path(&Method::GET, "/api/items"));
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use rusty_paseto::core::{Local, PasetoSymmetricKey, V4};
 use sqlx::SqlitePool;
 
+pub mod access;
 pub mod client_addr;
 pub mod login;
 pub mod middleware;
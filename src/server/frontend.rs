@@ -2,7 +2,7 @@ use std::io::Read as _;
 
 use axum::{
     Router,
-    extract::Path,
+    extract::{Path, State},
     http::{HeaderMap, StatusCode, header, header::CONTENT_TYPE},
     response::{IntoResponse, Response},
     routing::get,
@@ -10,20 +10,75 @@ use axum::{
 use flate2::read::GzDecoder;
 use include_dir::{Dir, include_dir};
 
-use crate::pipeline::embed::is_precompressed;
+use crate::pipeline::custom_css::custom_css_version;
+use crate::pipeline::embed::{gz_decompress, is_precompressed};
+use crate::pipeline::frontend::{
+    inject_custom_css_link, inject_icon_links, service_worker_script, web_app_manifest,
+};
+use crate::server::ServerState;
 
 static FRONTEND_DIST: Dir = include_dir!("$OUT_DIR/frontend_dist");
 
-pub(crate) fn routes() -> Router {
+pub(crate) fn routes(state: ServerState) -> Router {
     Router::new()
         .route("/", get(react_shell_index_handler))
         .route("/index.html", get(react_shell_index_handler))
+        .route("/manifest.json", get(manifest_handler))
+        .route("/service-worker.js", get(service_worker_handler))
         .route("/core/{*path}", get(react_shell_core_asset_handler))
         .route("/{path}", get(react_shell_root_asset_handler))
+        .with_state(state)
+}
+
+async fn manifest_handler(State(state): State<ServerState>) -> Response {
+    let title = state
+        .site_store
+        .get()
+        .map(|site| site.title.clone())
+        .unwrap_or_default();
+
+    (
+        StatusCode::OK,
+        [(CONTENT_TYPE, "application/manifest+json; charset=utf-8")],
+        web_app_manifest(&title, state.custom_icons),
+    )
+        .into_response()
+}
+
+async fn react_shell_index_handler(State(state): State<ServerState>) -> Response {
+    let Some(file) = FRONTEND_DIST.get_file("index.html") else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let Ok(raw) = gz_decompress(file.contents()) else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    let html = inject_icon_links(&String::from_utf8_lossy(&raw), state.custom_icons);
+    let html = state
+        .html_includes
+        .read()
+        .expect("html includes lock poisoned while reading")
+        .apply(&html);
+    let custom_css = custom_css_version(&state.custom_css_dir);
+    (
+        StatusCode::OK,
+        [(CONTENT_TYPE, "text/html; charset=utf-8")],
+        inject_custom_css_link(&html, custom_css.as_deref()),
+    )
+        .into_response()
 }
 
-async fn react_shell_index_handler(headers: HeaderMap) -> Response {
-    serve_embedded_frontend_file("index.html", &headers)
+async fn service_worker_handler() -> Response {
+    (
+        StatusCode::OK,
+        [
+            (CONTENT_TYPE, "application/javascript; charset=utf-8"),
+            // Browsers must revalidate so a new build's worker is picked up.
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        service_worker_script(),
+    )
+        .into_response()
 }
 
 async fn react_shell_root_asset_handler(headers: HeaderMap, Path(path): Path<String>) -> Response {
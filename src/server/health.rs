@@ -0,0 +1,52 @@
+//! Liveness and build-status endpoints (`GET /healthz`, `GET /status`).
+//!
+//! Both are served outside `/api/` so container orchestrators and uptime
+//! monitors can probe them without a session.
+
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use log::warn;
+use serde::Serialize;
+
+use crate::server::ServerState;
+
+/// Build status reported by `GET /status`.
+#[derive(Debug, Serialize)]
+struct BuildStatus {
+    /// True while the file watcher is rebuilding; the data served is still
+    /// the last complete build until it finishes.
+    rebuilding: bool,
+    last_build_at: String,
+    revision: u64,
+    items: i64,
+}
+
+/// Responds with 200 as long as the server is accepting requests.
+pub(crate) async fn healthz() -> &'static str {
+    "ok"
+}
+
+/// Reports the last successful build and whether a rebuild is running.
+///
+/// Stays 200 during a rebuild so health checks do not flap; callers that care
+/// should read the `rebuilding` field.
+pub(crate) async fn status(State(state): State<ServerState>) -> Response {
+    let items = match state.library_repo.count_items_by_content_type().await {
+        Ok((books, comics)) => books + comics,
+        Err(e) => {
+            warn!("Failed to count library items for status: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let notifier = &state.update_notifier;
+    Json(BuildStatus {
+        rebuilding: notifier.is_rebuilding(),
+        last_build_at: notifier.last_build_at(),
+        revision: notifier.latest().revision,
+        items,
+    })
+    .into_response()
+}
@@ -0,0 +1,316 @@
+//! KOReader progress sync ("kosync") compatibility endpoints.
+//!
+//! Implements the subset of the kosync API used by KOReader's progress sync
+//! plugin, so devices can use KoShelf as their sync server:
+//!
+//! - `GET /users/auth`
+//! - `PUT /syncs/progress`
+//! - `GET /syncs/progress/{document}`
+//!
+//! Devices authenticate with the `x-auth-user` and `x-auth-key` headers,
+//! where the key is the MD5 hex digest of the password. Progress is stored
+//! in the KoShelf app DB keyed by user and document hash. When two devices
+//! update the same document, the most recent write (by server timestamp) wins.
+
+use anyhow::Result;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::{Row, SqlitePool};
+
+use crate::server::ServerState;
+
+const AUTH_USER_HEADER: &str = "x-auth-user";
+const AUTH_KEY_HEADER: &str = "x-auth-key";
+
+#[derive(Clone)]
+pub struct KosyncState {
+    pub pool: SqlitePool,
+    pub username: String,
+    /// MD5 hex digest of the configured password, as sent by KOReader.
+    pub key: String,
+}
+
+impl KosyncState {
+    pub fn new(pool: SqlitePool, username: &str, password: &str) -> Self {
+        Self {
+            pool,
+            username: username.to_string(),
+            key: format!("{:x}", md5::compute(password)),
+        }
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), KosyncError> {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        match (header(AUTH_USER_HEADER), header(AUTH_KEY_HEADER)) {
+            (Some(user), Some(key))
+                if user == self.username && key.eq_ignore_ascii_case(&self.key) =>
+            {
+                Ok(())
+            }
+            _ => Err(KosyncError::Unauthorized),
+        }
+    }
+}
+
+/// Stored reading position for one document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentProgress {
+    pub document: String,
+    pub progress: String,
+    pub percentage: f64,
+    pub device: String,
+    pub device_id: String,
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+/// Error codes and messages follow the reference kosync server so KOReader
+/// shows its usual messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KosyncError {
+    Internal,
+    Unauthorized,
+    InvalidFields,
+    MissingDocument,
+}
+
+impl IntoResponse for KosyncError {
+    fn into_response(self) -> Response {
+        let (status, code, message) = match self {
+            Self::Internal => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                1001,
+                "Unknown server error.",
+            ),
+            Self::Unauthorized => (StatusCode::UNAUTHORIZED, 2001, "Unauthorized"),
+            Self::InvalidFields => (StatusCode::FORBIDDEN, 2003, "Invalid request"),
+            Self::MissingDocument => (
+                StatusCode::FORBIDDEN,
+                2004,
+                "Field 'document' not provided.",
+            ),
+        };
+        (status, Json(json!({ "code": code, "message": message }))).into_response()
+    }
+}
+
+fn kosync_state(state: &ServerState) -> Result<&KosyncState, KosyncError> {
+    state.kosync_state.as_ref().ok_or(KosyncError::Internal)
+}
+
+pub(crate) async fn auth_user(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, KosyncError> {
+    kosync_state(&state)?.authorize(&headers)?;
+    Ok(Json(json!({ "authorized": "OK" })))
+}
+
+pub(crate) async fn update_progress(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> Result<impl IntoResponse, KosyncError> {
+    let kosync = kosync_state(&state)?;
+    kosync.authorize(&headers)?;
+
+    let document = body
+        .get("document")
+        .and_then(|value| value.as_str())
+        .filter(|value| !value.is_empty())
+        .ok_or(KosyncError::MissingDocument)?;
+    let progress = serde_json::from_value::<DocumentProgress>(body.clone())
+        .ok()
+        .filter(|progress| progress.percentage.is_finite())
+        .ok_or(KosyncError::InvalidFields)?;
+
+    let timestamp = Utc::now().timestamp();
+    let stored = save_progress(
+        &kosync.pool,
+        &kosync.username,
+        &DocumentProgress {
+            timestamp,
+            ..progress
+        },
+    )
+    .await
+    .map_err(|e| {
+        log::warn!("Failed to store kosync progress for {}: {}", document, e);
+        KosyncError::Internal
+    })?;
+
+    Ok(Json(json!({
+        "document": stored.document,
+        "timestamp": stored.timestamp,
+    })))
+}
+
+pub(crate) async fn get_progress(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(document): Path<String>,
+) -> Result<Response, KosyncError> {
+    let kosync = kosync_state(&state)?;
+    kosync.authorize(&headers)?;
+
+    let progress = load_progress(&kosync.pool, &kosync.username, &document)
+        .await
+        .map_err(|e| {
+            log::warn!("Failed to load kosync progress for {}: {}", document, e);
+            KosyncError::Internal
+        })?;
+
+    // The reference server answers unknown documents with an empty object.
+    Ok(match progress {
+        Some(progress) => Json(progress).into_response(),
+        None => Json(json!({})).into_response(),
+    })
+}
+
+/// Store `progress` unless a newer update for the same document already
+/// exists, and return whichever update is stored afterwards.
+pub async fn save_progress(
+    pool: &SqlitePool,
+    username: &str,
+    progress: &DocumentProgress,
+) -> Result<DocumentProgress> {
+    sqlx::query(
+        "INSERT INTO kosync_progress (
+            username, document, progress, percentage, device, device_id, timestamp
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        ON CONFLICT (username, document) DO UPDATE SET
+            progress = excluded.progress,
+            percentage = excluded.percentage,
+            device = excluded.device,
+            device_id = excluded.device_id,
+            timestamp = excluded.timestamp
+        WHERE excluded.timestamp >= kosync_progress.timestamp",
+    )
+    .bind(username)
+    .bind(&progress.document)
+    .bind(&progress.progress)
+    .bind(progress.percentage)
+    .bind(&progress.device)
+    .bind(&progress.device_id)
+    .bind(progress.timestamp)
+    .execute(pool)
+    .await?;
+
+    load_progress(pool, username, &progress.document)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("kosync progress missing after write"))
+}
+
+pub async fn load_progress(
+    pool: &SqlitePool,
+    username: &str,
+    document: &str,
+) -> Result<Option<DocumentProgress>> {
+    let row = sqlx::query(
+        "SELECT document, progress, percentage, device, device_id, timestamp
+         FROM kosync_progress WHERE username = ?1 AND document = ?2",
+    )
+    .bind(username)
+    .bind(document)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| DocumentProgress {
+        document: row.get("document"),
+        progress: row.get("progress"),
+        percentage: row.get("percentage"),
+        device: row.get("device"),
+        device_id: row.get("device_id"),
+        timestamp: row.get("timestamp"),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::sqlite::{open_library_pool_in_memory, run_koshelf_migrations};
+    use axum::http::HeaderValue;
+
+    async fn pool() -> SqlitePool {
+        let pool = open_library_pool_in_memory()
+            .await
+            .expect("in-memory pool should open");
+        run_koshelf_migrations(&pool)
+            .await
+            .expect("migrations should succeed");
+        pool
+    }
+
+    fn progress(device: &str, percentage: f64, timestamp: i64) -> DocumentProgress {
+        DocumentProgress {
+            document: "0123456789abcdef0123456789abcdef".to_string(),
+            progress: format!("/body/DocFragment[{}]", (percentage * 100.0) as i64),
+            percentage,
+            device: device.to_string(),
+            device_id: format!("{device}-id"),
+            timestamp,
+        }
+    }
+
+    #[tokio::test]
+    async fn authorizes_with_md5_of_password() {
+        let state = KosyncState::new(pool().await, "reader", "secret");
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTH_USER_HEADER, HeaderValue::from_static("reader"));
+        headers.insert(
+            AUTH_KEY_HEADER,
+            HeaderValue::from_static("5ebe2294ecd0e0f08eab7690d2a6ee69"),
+        );
+        assert_eq!(state.authorize(&headers), Ok(()));
+
+        headers.insert(AUTH_KEY_HEADER, HeaderValue::from_static("secret"));
+        assert_eq!(state.authorize(&headers), Err(KosyncError::Unauthorized));
+
+        assert_eq!(
+            state.authorize(&HeaderMap::new()),
+            Err(KosyncError::Unauthorized)
+        );
+    }
+
+    #[tokio::test]
+    async fn stores_and_loads_progress_per_user() {
+        let pool = pool().await;
+        save_progress(&pool, "reader", &progress("kindle", 0.25, 100))
+            .await
+            .expect("save should succeed");
+
+        let loaded = load_progress(&pool, "reader", &progress("", 0.0, 0).document)
+            .await
+            .expect("load should succeed");
+        assert_eq!(loaded, Some(progress("kindle", 0.25, 100)));
+
+        let other_user = load_progress(&pool, "someone-else", &progress("", 0.0, 0).document)
+            .await
+            .expect("load should succeed");
+        assert_eq!(other_user, None);
+    }
+
+    #[tokio::test]
+    async fn older_update_does_not_overwrite_newer_one() {
+        let pool = pool().await;
+        save_progress(&pool, "reader", &progress("kobo", 0.5, 200))
+            .await
+            .expect("save should succeed");
+
+        let stored = save_progress(&pool, "reader", &progress("kindle", 0.25, 100))
+            .await
+            .expect("save should succeed");
+        assert_eq!(stored, progress("kobo", 0.5, 200));
+
+        let stored = save_progress(&pool, "reader", &progress("kindle", 0.6, 300))
+            .await
+            .expect("save should succeed");
+        assert_eq!(stored, progress("kindle", 0.6, 300));
+    }
+}
@@ -0,0 +1,201 @@
+//! Prometheus metrics endpoint (`GET /metrics`).
+//!
+//! Exposes library counts and, when reading statistics are configured,
+//! reading totals in the Prometheus text exposition format so KoShelf can be
+//! scraped by Prometheus or compatible collectors. Values are computed on
+//! request from the same stores the API serves.
+
+use std::fmt::Write;
+
+use axum::extract::State;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use chrono::DateTime;
+use log::warn;
+
+use crate::server::ServerState;
+use crate::server::api::responses::common::ContentTypeFilter;
+use crate::shelf::statistics;
+use crate::shelf::statistics::queries::ReadingSummaryQuery;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Reading totals exposed when statistics are configured.
+struct ReadingMetrics {
+    reading_time_sec: i64,
+    pages_read: i64,
+    completions: i64,
+    current_streak_days: i64,
+    longest_streak_days: i64,
+}
+
+struct Snapshot {
+    books: i64,
+    comics: i64,
+    reading: Option<ReadingMetrics>,
+    last_build_timestamp: Option<i64>,
+}
+
+pub(crate) async fn metrics(State(state): State<ServerState>) -> Response {
+    let (books, comics) = match state.library_repo.count_items_by_content_type().await {
+        Ok(counts) => counts,
+        Err(e) => {
+            warn!("Failed to count library items for metrics: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let reading = state.reading_data_store.get().map(|reading_data| {
+        let summary = statistics::summary(
+            &reading_data,
+            ReadingSummaryQuery {
+                scope: ContentTypeFilter::All,
+                range: None,
+                tz: None,
+            },
+        );
+        ReadingMetrics {
+            reading_time_sec: summary.overview.reading_time_sec,
+            pages_read: summary.overview.pages_read,
+            completions: summary.overview.completions,
+            current_streak_days: summary.streaks.current.days,
+            longest_streak_days: summary.streaks.longest.days,
+        }
+    });
+
+    let last_build_timestamp =
+        DateTime::parse_from_rfc3339(&state.update_notifier.latest().generated_at)
+            .ok()
+            .map(|generated_at| generated_at.timestamp());
+
+    let body = render(&Snapshot {
+        books,
+        comics,
+        reading,
+        last_build_timestamp,
+    });
+
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], body).into_response()
+}
+
+fn render(snapshot: &Snapshot) -> String {
+    let mut out = String::new();
+
+    write_metric(
+        &mut out,
+        "koshelf_books_total",
+        "gauge",
+        "Books in the library.",
+        snapshot.books,
+    );
+    write_metric(
+        &mut out,
+        "koshelf_comics_total",
+        "gauge",
+        "Comics in the library.",
+        snapshot.comics,
+    );
+
+    if let Some(reading) = &snapshot.reading {
+        write_metric(
+            &mut out,
+            "koshelf_reading_time_seconds_total",
+            "counter",
+            "Time spent reading, in seconds.",
+            reading.reading_time_sec,
+        );
+        write_metric(
+            &mut out,
+            "koshelf_pages_read_total",
+            "counter",
+            "Pages read.",
+            reading.pages_read,
+        );
+        write_metric(
+            &mut out,
+            "koshelf_completions_total",
+            "counter",
+            "Completed reads, including re-reads.",
+            reading.completions,
+        );
+        write_metric(
+            &mut out,
+            "koshelf_current_streak_days",
+            "gauge",
+            "Length of the current reading streak, in days.",
+            reading.current_streak_days,
+        );
+        write_metric(
+            &mut out,
+            "koshelf_longest_streak_days",
+            "gauge",
+            "Length of the longest reading streak, in days.",
+            reading.longest_streak_days,
+        );
+    }
+
+    if let Some(timestamp) = snapshot.last_build_timestamp {
+        write_metric(
+            &mut out,
+            "koshelf_last_build_timestamp",
+            "gauge",
+            "Unix time of the last data rebuild.",
+            timestamp,
+        );
+    }
+
+    out
+}
+
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, value: i64) {
+    // Writing to a String cannot fail.
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+    let _ = writeln!(out, "{name} {value}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(reading: Option<ReadingMetrics>) -> Snapshot {
+        Snapshot {
+            books: 12,
+            comics: 3,
+            reading,
+            last_build_timestamp: Some(1_700_000_000),
+        }
+    }
+
+    #[test]
+    fn renders_prometheus_text_format() {
+        let body = render(&snapshot(Some(ReadingMetrics {
+            reading_time_sec: 7200,
+            pages_read: 150,
+            completions: 4,
+            current_streak_days: 5,
+            longest_streak_days: 21,
+        })));
+
+        assert!(body.contains(
+            "# HELP koshelf_books_total Books in the library.\n\
+             # TYPE koshelf_books_total gauge\n\
+             koshelf_books_total 12\n"
+        ));
+        assert!(body.contains("koshelf_comics_total 3\n"));
+        assert!(body.contains("# TYPE koshelf_reading_time_seconds_total counter\n"));
+        assert!(body.contains("koshelf_reading_time_seconds_total 7200\n"));
+        assert!(body.contains("koshelf_current_streak_days 5\n"));
+        assert!(body.contains("koshelf_last_build_timestamp 1700000000\n"));
+    }
+
+    #[test]
+    fn reading_metrics_are_omitted_without_statistics() {
+        let body = render(&snapshot(None));
+
+        assert!(body.contains("koshelf_books_total 12\n"));
+        assert!(body.contains("koshelf_comics_total 3\n"));
+        assert!(!body.contains("koshelf_reading_time_seconds_total"));
+        assert!(!body.contains("koshelf_current_streak_days"));
+    }
+}
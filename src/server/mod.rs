@@ -3,22 +3,32 @@
 pub mod api;
 pub mod auth;
 mod frontend;
+mod health;
+pub mod kosync;
+mod metrics;
+mod startup;
 
+use crate::app::shutdown::Shutdown;
+use crate::pipeline::html_includes::SharedHtmlIncludes;
+use crate::pipeline::{custom_css, icons, logo};
 use crate::store::memory::{SharedReadingDataStore, SharedSiteStore, UpdateNotifier};
 use crate::store::sqlite::repo::LibraryRepository;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use axum::Router;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use axum::response::Response;
 use axum::routing::{delete, get, patch, post, put};
+use axum_server::tls_rustls::RustlsConfig;
 use dashmap::DashMap;
-use log::info;
-use std::net::SocketAddr;
+use log::{info, warn};
+use std::net::{IpAddr, SocketAddr};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tower::ServiceBuilder;
 use tower_http::compression::CompressionLayer;
-use tower_http::services::ServeDir;
+use tower_http::services::{ServeDir, ServeFile};
 use tower_http::set_header::SetResponseHeaderLayer;
 
 /// Per-file mutex coordinator for serializing metadata writes.
@@ -79,62 +89,147 @@ pub struct ServerState {
     pub library_repo: LibraryRepository,
     pub auth_state: Option<auth::AuthState>,
     pub write_coordinator: Option<WriteCoordinator>,
+    pub kosync_state: Option<kosync::KosyncState>,
     pub timezone: Option<chrono_tz::Tz>,
+    /// Whether icons generated from `--icon` replace the built-in ones.
+    pub custom_icons: bool,
+    /// Directory of the copied `--custom-css` stylesheet. Its content hash is
+    /// read per request, as the watcher may replace it.
+    pub custom_css_dir: PathBuf,
+    /// Snippets from `--head-include` and `--footer-include`.
+    pub html_includes: SharedHtmlIncludes,
+    /// Description characters shown before "read more" (0 = no limit).
+    pub description_max_chars: usize,
+    /// Items a series needs before it gets a series page.
+    pub series_min_items: usize,
+    /// Ends long-lived responses such as the event stream on shutdown.
+    pub shutdown: Shutdown,
+}
+
+/// Default Content-Security-Policy for every response. Mirrors the policy in
+/// the frontend's `index.html` and adds the directives that only take effect
+/// as a header. `connect-src 'self'` covers the API and the SSE event stream.
+const DEFAULT_CONTENT_SECURITY_POLICY: &str = "default-src 'self'; script-src 'self'; \
+    style-src 'self' 'unsafe-inline' blob:; img-src 'self' blob: data:; \
+    connect-src 'self' blob: data:; font-src 'self' data: blob:; worker-src 'self' blob:; \
+    frame-src blob:; object-src 'none'; form-action 'none'; base-uri 'self'; \
+    frame-ancestors 'none'";
+
+/// How long in-flight requests may take to finish after shutdown is requested.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// PEM certificate chain and private key for serving HTTPS.
+pub struct TlsPaths {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
 }
 
 /// Axum-based HTTP server serving the API, embedded React frontend, and media assets.
 pub struct WebServer {
     media_cache_dir: PathBuf,
+    bind: IpAddr,
     port: u16,
+    tls: Option<TlsPaths>,
+    access_gate: Option<auth::access::AccessGate>,
+    custom_headers: Vec<(HeaderName, HeaderValue)>,
     site_store: SharedSiteStore,
     reading_data_store: SharedReadingDataStore,
     update_notifier: UpdateNotifier,
     library_repo: LibraryRepository,
     auth_state: Option<auth::AuthState>,
     write_coordinator: Option<WriteCoordinator>,
+    kosync_state: Option<kosync::KosyncState>,
     timezone: Option<chrono_tz::Tz>,
+    html_includes: SharedHtmlIncludes,
+    description_max_chars: usize,
+    series_min_items: usize,
+    enable_metrics: bool,
+    open_browser: bool,
+    shutdown: Shutdown,
 }
 
 pub struct WebServerOptions {
     pub media_cache_dir: PathBuf,
+    pub bind: IpAddr,
     pub port: u16,
+    /// Serve HTTPS with these files instead of plain HTTP.
+    pub tls: Option<TlsPaths>,
+    /// HTTP Basic / bearer credentials required on every route.
+    pub access_gate: Option<auth::access::AccessGate>,
+    /// Headers from `--header`, applied over the defaults; empty values remove.
+    pub custom_headers: Vec<(HeaderName, HeaderValue)>,
     pub site_store: SharedSiteStore,
     pub reading_data_store: SharedReadingDataStore,
     pub update_notifier: UpdateNotifier,
     pub library_repo: LibraryRepository,
     pub auth_state: Option<auth::AuthState>,
     pub write_coordinator: Option<WriteCoordinator>,
+    pub kosync_state: Option<kosync::KosyncState>,
     pub timezone: Option<chrono_tz::Tz>,
+    /// Custom HTML added to the app shell.
+    pub html_includes: SharedHtmlIncludes,
+    /// Book descriptions longer than this get a "read more" excerpt (0 = never).
+    pub description_max_chars: usize,
+    /// Series with fewer items get no series page.
+    pub series_min_items: usize,
+    pub enable_metrics: bool,
+    /// Open the default browser at the server URL once listening.
+    pub open_browser: bool,
+    /// Stops accepting connections and drains in-flight requests once set.
+    pub shutdown: Shutdown,
 }
 
 impl WebServer {
     pub fn new(options: WebServerOptions) -> Self {
         let WebServerOptions {
             media_cache_dir,
+            bind,
             port,
+            tls,
+            access_gate,
+            custom_headers,
             site_store,
             reading_data_store,
             update_notifier,
             library_repo,
             auth_state,
             write_coordinator,
+            kosync_state,
             timezone,
+            html_includes,
+            description_max_chars,
+            series_min_items,
+            enable_metrics,
+            open_browser,
+            shutdown,
         } = options;
 
         Self {
             media_cache_dir,
+            bind,
             port,
+            tls,
+            access_gate,
+            custom_headers,
             site_store,
             reading_data_store,
             update_notifier,
             library_repo,
             auth_state,
             write_coordinator,
+            kosync_state,
             timezone,
+            html_includes,
+            description_max_chars,
+            series_min_items,
+            enable_metrics,
+            open_browser,
+            shutdown,
         }
     }
 
-    /// Start listening and serving requests. Blocks until the server shuts down.
+    /// Start listening and serving requests. Blocks until the server fails or
+    /// has shut down gracefully after shutdown was requested.
     pub async fn run(self) -> Result<()> {
         let state = ServerState {
             site_store: self.site_store.clone(),
@@ -143,19 +238,39 @@ impl WebServer {
             library_repo: self.library_repo,
             auth_state: self.auth_state,
             write_coordinator: self.write_coordinator,
+            kosync_state: self.kosync_state,
             timezone: self.timezone,
+            custom_icons: icons::has_custom_icons(&self.media_cache_dir.join("icons")),
+            custom_css_dir: self.media_cache_dir.clone(),
+            html_includes: self.html_includes,
+            description_max_chars: self.description_max_chars,
+            series_min_items: self.series_min_items,
+            shutdown: self.shutdown.clone(),
         };
         let covers_cache_dir = self.media_cache_dir.join("covers");
         let files_cache_dir = self.media_cache_dir.join("files");
         let recap_cache_dir = self.media_cache_dir.join("recap");
+        let icons_cache_dir = self.media_cache_dir.join("icons");
+        let logo_cache_dir = self.media_cache_dir.join("logo");
+        let custom_css_path = self.media_cache_dir.join(custom_css::CUSTOM_CSS_FILE);
 
         let mut app = api::routes()
             .with_state(state.clone())
-            .merge(frontend::routes())
+            .merge(frontend::routes(state.clone()))
             // Runtime-generated media cache directories are mounted under public /assets URLs.
             .nest_service("/assets/covers", ServeDir::new(covers_cache_dir))
             .nest_service("/assets/files", ServeDir::new(files_cache_dir))
-            .nest_service("/assets/recap", ServeDir::new(recap_cache_dir));
+            .nest_service("/assets/recap", ServeDir::new(recap_cache_dir))
+            .nest_service("/assets/icons", ServeDir::new(icons_cache_dir))
+            .nest_service(logo::LOGO_URL_PREFIX, ServeDir::new(logo_cache_dir))
+            .route_service(custom_css::CUSTOM_CSS_URL, ServeFile::new(custom_css_path));
+
+        // Probes run without a session, so these stay outside `/api/`.
+        let health_routes = Router::new()
+            .route("/healthz", get(health::healthz))
+            .route("/status", get(health::status))
+            .with_state(state.clone());
+        app = app.merge(health_routes);
 
         if state.auth_state.is_some() {
             let auth_routes = Router::new()
@@ -186,11 +301,40 @@ impl WebServer {
             app = app.merge(write_routes);
         }
 
+        if state.kosync_state.is_some() {
+            // KOReader progress sync authenticates with its own headers, so
+            // these routes live outside `/api/` and the session middleware.
+            let kosync_routes = Router::new()
+                .route("/users/auth", get(kosync::auth_user))
+                .route("/syncs/progress", put(kosync::update_progress))
+                .route("/syncs/progress/{document}", get(kosync::get_progress))
+                .with_state(state.clone());
+            app = app.merge(kosync_routes);
+        }
+
+        if self.enable_metrics {
+            // Scrapers cannot log in, so metrics stay outside `/api/` and
+            // the session middleware.
+            let metrics_routes = Router::new()
+                .route("/metrics", get(metrics::metrics))
+                .with_state(state.clone());
+            app = app.merge(metrics_routes);
+        }
+
         app = app.layer(axum::middleware::from_fn_with_state(
             state.clone(),
             auth::middleware::auth_middleware,
         ));
 
+        // Added after the session middleware so it runs first and covers every
+        // route, including the SSE stream and the embedded frontend.
+        if let Some(gate) = self.access_gate.clone() {
+            app = app.layer(axum::middleware::from_fn_with_state(
+                gate,
+                auth::access::access_middleware,
+            ));
+        }
+
         app = app.layer(
             ServiceBuilder::new()
                 .layer(CompressionLayer::new())
@@ -205,14 +349,58 @@ impl WebServer {
                 .layer(SetResponseHeaderLayer::overriding(
                     axum::http::header::REFERRER_POLICY,
                     axum::http::HeaderValue::from_static("strict-origin-when-cross-origin"),
+                ))
+                .layer(SetResponseHeaderLayer::overriding(
+                    axum::http::header::CONTENT_SECURITY_POLICY,
+                    axum::http::HeaderValue::from_static(DEFAULT_CONTENT_SECURITY_POLICY),
                 )),
         );
 
-        let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", self.port)).await?;
+        if !self.custom_headers.is_empty() {
+            let custom_headers = Arc::new(self.custom_headers);
+            app = app.layer(axum::middleware::map_response(
+                move |mut response: Response| {
+                    let custom_headers = custom_headers.clone();
+                    async move {
+                        apply_custom_headers(response.headers_mut(), &custom_headers);
+                        response
+                    }
+                },
+            ));
+        }
+
+        // Load certificates before binding so a bad path fails fast.
+        let tls_config = match &self.tls {
+            Some(tls) => {
+                // Only the ring backend is compiled in; an error here just
+                // means a provider was already installed.
+                let _ = rustls::crypto::ring::default_provider().install_default();
+                let config = RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to load TLS certificate {:?} and key {:?}",
+                            tls.cert_path, tls.key_path
+                        )
+                    })?;
+                Some(config)
+            }
+            None => None,
+        };
+
+        let addr = SocketAddr::new(self.bind, self.port);
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed to bind {}", addr))?;
 
         info!(
-            "Listening on http://0.0.0.0:{} (auth: {}, writeback: {})",
-            self.port,
+            "Listening on {}://{} (auth: {}, writeback: {}, kosync: {}, metrics: {})",
+            if tls_config.is_some() {
+                "https"
+            } else {
+                "http"
+            },
+            addr,
             if state.auth_state.is_some() {
                 "on"
             } else {
@@ -223,14 +411,108 @@ impl WebServer {
             } else {
                 "off"
             },
+            if state.kosync_state.is_some() {
+                "on"
+            } else {
+                "off"
+            },
+            if self.enable_metrics { "on" } else { "off" },
         );
+        startup::announce_urls(addr, tls_config.is_some());
+        if self.open_browser {
+            startup::open_browser(addr, tls_config.is_some());
+        }
+
+        let make_service = app.into_make_service_with_connect_info::<SocketAddr>();
+
+        match tls_config {
+            Some(tls_config) => {
+                let handle = axum_server::Handle::new();
+                let shutdown_handle = handle.clone();
+                let shutdown = self.shutdown.clone();
+                tokio::spawn(async move {
+                    shutdown.requested().await;
+                    shutdown_handle.graceful_shutdown(Some(SHUTDOWN_GRACE_PERIOD));
+                });
 
-        axum::serve(
-            listener,
-            app.into_make_service_with_connect_info::<SocketAddr>(),
-        )
-        .await?;
+                axum_server::from_tcp_rustls(listener.into_std()?, tls_config)?
+                    .handle(handle)
+                    .serve(make_service)
+                    .await?;
+            }
+            None => {
+                let shutdown = self.shutdown.clone();
+                let server = axum::serve(listener, make_service)
+                    .with_graceful_shutdown(async move { shutdown.requested().await });
+                let grace_period_elapsed = async {
+                    self.shutdown.requested().await;
+                    tokio::time::sleep(SHUTDOWN_GRACE_PERIOD).await;
+                };
+
+                tokio::select! {
+                    result = server => result?,
+                    () = grace_period_elapsed => {
+                        warn!("Closing connections still open after the shutdown grace period");
+                    }
+                }
+            }
+        }
 
         Ok(())
     }
 }
+
+/// Replace response headers with the configured ones. A name given several
+/// times yields several headers; an empty value removes the header.
+fn apply_custom_headers(headers: &mut HeaderMap, custom: &[(HeaderName, HeaderValue)]) {
+    for (name, _) in custom {
+        headers.remove(name);
+    }
+    for (name, value) in custom {
+        if !value.is_empty() {
+            headers.append(name.clone(), value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::header;
+
+    #[test]
+    fn custom_headers_override_append_and_remove() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static(DEFAULT_CONTENT_SECURITY_POLICY),
+        );
+        headers.insert(header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+
+        apply_custom_headers(
+            &mut headers,
+            &[
+                (
+                    header::CONTENT_SECURITY_POLICY,
+                    HeaderValue::from_static("default-src 'self'"),
+                ),
+                (header::X_FRAME_OPTIONS, HeaderValue::from_static("")),
+                (
+                    HeaderName::from_static("x-extra"),
+                    HeaderValue::from_static("a"),
+                ),
+                (
+                    HeaderName::from_static("x-extra"),
+                    HeaderValue::from_static("b"),
+                ),
+            ],
+        );
+
+        assert_eq!(
+            headers[header::CONTENT_SECURITY_POLICY],
+            "default-src 'self'"
+        );
+        assert!(!headers.contains_key(header::X_FRAME_OPTIONS));
+        assert_eq!(headers.get_all("x-extra").iter().count(), 2);
+    }
+}
@@ -0,0 +1,238 @@
+//! Startup announcement of the URLs the server is reachable at, with a QR
+//! code for opening KoShelf on a phone, and the optional browser launch.
+
+use std::io::IsTerminal;
+use std::net::{IpAddr, SocketAddr};
+use std::process::{Command, Stdio};
+
+use log::{debug, info};
+use qrcode::QrCode;
+use qrcode::render::unicode::Dense1x2;
+
+/// Log the URLs the server can be opened at and, in a terminal that can draw
+/// it, print a QR code for the first LAN address.
+pub(crate) fn announce_urls(addr: SocketAddr, https: bool) {
+    let scheme = if https { "https" } else { "http" };
+    let port = addr.port();
+
+    let interfaces = if addr.ip().is_unspecified() {
+        if_addrs::get_if_addrs()
+            .map(|interfaces| {
+                interfaces
+                    .into_iter()
+                    .filter(|interface| interface.is_oper_up())
+                    .map(|interface| interface.ip())
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let urls: Vec<String> = lan_addresses(addr, &interfaces)
+        .into_iter()
+        .map(|ip| format!("{scheme}://{}", SocketAddr::new(ip, port)))
+        .collect();
+
+    for url in &urls {
+        info!("Open KoShelf at {}", url);
+    }
+    // Inside a container the detected addresses are the container's own, so
+    // also point at the published port on the host.
+    if addr.ip().is_unspecified() {
+        info!(
+            "Open KoShelf at {scheme}://localhost:{port} on this machine (listening on all interfaces, {})",
+            addr.ip()
+        );
+    }
+
+    if let Some(url) = urls.first() {
+        print_qr_code(url);
+    }
+}
+
+/// Addresses other devices can reach the server at. A wildcard bind yields
+/// every non-loopback interface address, private IPv4 LAN addresses first;
+/// a specific bind yields that address unless it is loopback.
+fn lan_addresses(addr: SocketAddr, interfaces: &[IpAddr]) -> Vec<IpAddr> {
+    let mut ips: Vec<IpAddr> = if addr.ip().is_unspecified() {
+        interfaces
+            .iter()
+            .copied()
+            .filter(|ip| !ip.is_loopback() && !is_link_local(ip))
+            // An IPv4 wildcard only accepts IPv4 connections.
+            .filter(|ip| addr.is_ipv6() || ip.is_ipv4())
+            .collect()
+    } else if addr.ip().is_loopback() {
+        Vec::new()
+    } else {
+        vec![addr.ip()]
+    };
+    ips.sort_by_key(|ip| match ip {
+        IpAddr::V4(v4) if v4.is_private() => 0,
+        IpAddr::V4(_) => 1,
+        IpAddr::V6(_) => 2,
+    });
+    ips.dedup();
+    ips
+}
+
+/// Open the default browser at the server. Failure, e.g. on a headless
+/// machine without a browser, is only logged at debug level.
+pub(crate) fn open_browser(addr: SocketAddr, https: bool) {
+    let url = browser_url(addr, https);
+    match opener_command(&url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(mut child) => {
+            // Reap the opener once it exits; it hands off to the browser quickly.
+            std::thread::spawn(move || match child.wait() {
+                Ok(status) if !status.success() => {
+                    debug!("Browser opener exited with {} for {}", status, url)
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Failed to wait for browser opener: {}", e),
+            });
+        }
+        Err(e) => debug!("Could not open a browser at {}: {}", url, e),
+    }
+}
+
+/// The URL to open on this machine: localhost for wildcard and loopback
+/// binds, the bound address otherwise.
+fn browser_url(addr: SocketAddr, https: bool) -> String {
+    let scheme = if https { "https" } else { "http" };
+    if addr.ip().is_unspecified() || addr.ip().is_loopback() {
+        format!("{scheme}://localhost:{}", addr.port())
+    } else {
+        format!("{scheme}://{addr}")
+    }
+}
+
+fn opener_command(url: &str) -> Command {
+    if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", "", url]);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    }
+}
+
+fn is_link_local(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_link_local(),
+        IpAddr::V6(v6) => v6.is_unicast_link_local(),
+    }
+}
+
+/// Print a QR code for `url`. Skipped when stdout is not a terminal, and
+/// reduced to the plain URL when the terminal cannot draw block characters.
+fn print_qr_code(url: &str) {
+    if !std::io::stdout().is_terminal() {
+        return;
+    }
+
+    println!();
+    match render_qr(url).filter(|_| supports_block_characters()) {
+        Some(qr) => println!("{qr}\nScan to open {url}"),
+        None => println!("Open {url} on another device"),
+    }
+    println!();
+}
+
+fn render_qr(url: &str) -> Option<String> {
+    let code = QrCode::new(url.as_bytes()).ok()?;
+    // Inverted so the code reads correctly on the usual dark terminal background.
+    Some(
+        code.render::<Dense1x2>()
+            .dark_color(Dense1x2::Light)
+            .light_color(Dense1x2::Dark)
+            .quiet_zone(true)
+            .build(),
+    )
+}
+
+/// Half-block characters need a UTF-8 locale and a terminal that is not "dumb".
+fn supports_block_characters() -> bool {
+    if std::env::var("TERM").is_ok_and(|term| term == "dumb") {
+        return false;
+    }
+    if cfg!(windows) {
+        return true;
+    }
+
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn wildcard_bind_lists_lan_addresses_first() {
+        let addr: SocketAddr = "0.0.0.0:3000".parse().unwrap();
+        let interfaces = [
+            ip("127.0.0.1"),
+            ip("203.0.113.5"),
+            ip("192.168.1.20"),
+            ip("169.254.10.1"),
+            ip("fd00::1"),
+        ];
+
+        assert_eq!(
+            lan_addresses(addr, &interfaces),
+            [ip("192.168.1.20"), ip("203.0.113.5")]
+        );
+    }
+
+    #[test]
+    fn specific_and_ipv6_binds() {
+        let lan: SocketAddr = "192.168.1.20:8443".parse().unwrap();
+        assert_eq!(lan_addresses(lan, &[ip("10.0.0.2")]), [ip("192.168.1.20")]);
+
+        let local: SocketAddr = "127.0.0.1:3000".parse().unwrap();
+        assert!(lan_addresses(local, &[ip("192.168.1.20")]).is_empty());
+
+        let wildcard: SocketAddr = "[::]:3000".parse().unwrap();
+        assert!(lan_addresses(wildcard, &[ip("::1")]).is_empty());
+        assert_eq!(
+            lan_addresses(wildcard, &[ip("fd00::1"), ip("10.0.0.2")]),
+            [ip("10.0.0.2"), ip("fd00::1")]
+        );
+    }
+
+    #[test]
+    fn browser_url_prefers_localhost() {
+        let wildcard: SocketAddr = "0.0.0.0:3000".parse().unwrap();
+        assert_eq!(browser_url(wildcard, false), "http://localhost:3000");
+
+        let lan: SocketAddr = "192.168.1.20:8443".parse().unwrap();
+        assert_eq!(browser_url(lan, true), "https://192.168.1.20:8443");
+    }
+
+    #[test]
+    fn qr_code_uses_half_blocks() {
+        let qr = render_qr("http://192.168.1.20:3000").unwrap();
+        assert!(qr.lines().count() > 10);
+        assert!(qr.contains('\u{2588}') || qr.contains('\u{2580}'));
+    }
+}
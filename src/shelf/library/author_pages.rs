@@ -0,0 +1,263 @@
+//! Author pages: library items grouped by author.
+//!
+//! Author names are already normalized and merged by aliases at ingest, so
+//! items join an author when the names match ignoring case and surrounding
+//! whitespace. Items with several authors appear under each of them.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+
+use crate::server::api::responses::library::{
+    LibraryAuthorDetailData, LibraryAuthorListData, LibraryAuthorSummary, LibraryListItem,
+    LibraryStatus,
+};
+use crate::shelf::library::item_reading_times;
+use crate::shelf::library::queries::LibraryListQuery;
+use crate::shelf::library::slugs::slugify;
+use crate::store::memory::ReadingData;
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// Used when an author name has no letters or digits.
+const FALLBACK_SLUG: &str = "author";
+
+/// Items of one author, ordered by title.
+struct AuthorGroup {
+    slug: String,
+    name: String,
+    items: Vec<LibraryListItem>,
+}
+
+/// Every author in the library, ordered by name.
+pub async fn author_list(
+    repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+) -> Result<LibraryAuthorListData> {
+    let groups = load_authors(repo).await?;
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    let authors = groups
+        .iter()
+        .map(|group| summarize(group, &reading_times))
+        .collect();
+    Ok(LibraryAuthorListData { authors })
+}
+
+/// Every author page at once, for the static export.
+pub async fn all_author_details(
+    repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+) -> Result<Vec<LibraryAuthorDetailData>> {
+    let groups = load_authors(repo).await?;
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    Ok(groups
+        .into_iter()
+        .map(|group| LibraryAuthorDetailData {
+            author: summarize(&group, &reading_times),
+            items: group.items,
+        })
+        .collect())
+}
+
+/// The author with URL slug `slug` and their items, `None` for unknown slugs.
+pub async fn author_detail(
+    repo: &LibraryRepository,
+    slug: &str,
+    reading_data: Option<&ReadingData>,
+) -> Result<Option<LibraryAuthorDetailData>> {
+    let Some(group) = load_authors(repo)
+        .await?
+        .into_iter()
+        .find(|group| group.slug == slug)
+    else {
+        return Ok(None);
+    };
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    Ok(Some(LibraryAuthorDetailData {
+        author: summarize(&group, &reading_times),
+        items: group.items,
+    }))
+}
+
+async fn load_authors(repo: &LibraryRepository) -> Result<Vec<AuthorGroup>> {
+    let items = repo.list_items(&LibraryListQuery::default()).await?;
+    Ok(group_by_author(items))
+}
+
+fn group_by_author(items: Vec<LibraryListItem>) -> Vec<AuthorGroup> {
+    let mut by_key: BTreeMap<String, Vec<LibraryListItem>> = BTreeMap::new();
+    for item in items {
+        // A book listing the same author twice still counts once.
+        let keys: HashSet<String> = item
+            .authors
+            .iter()
+            .map(|author| author.trim().to_lowercase())
+            .filter(|key| !key.is_empty())
+            .collect();
+        for key in keys {
+            by_key.entry(key).or_default().push(item.clone());
+        }
+    }
+
+    let mut keys_by_base: HashMap<String, Vec<&str>> = HashMap::new();
+    for key in by_key.keys() {
+        keys_by_base.entry(slug_base(key)).or_default().push(key);
+    }
+
+    let mut groups: Vec<AuthorGroup> = by_key
+        .iter()
+        .map(|(key, items)| {
+            let mut items = items.clone();
+            items.sort_by_cached_key(|item| {
+                let title = item.title_sort.as_deref().unwrap_or(&item.title);
+                (title.to_lowercase(), item.id.clone())
+            });
+            let name = items[0]
+                .authors
+                .iter()
+                .map(|author| author.trim())
+                .find(|author| author.to_lowercase() == *key)
+                .unwrap_or(key)
+                .to_string();
+            let base = slug_base(key);
+            let slug = author_slug(key, &base, &keys_by_base[&base]);
+            AuthorGroup { slug, name, items }
+        })
+        .collect();
+
+    groups.sort_by_cached_key(|group| group.name.to_lowercase());
+    groups
+}
+
+fn slug_base(key: &str) -> String {
+    match slugify(key) {
+        // `index` is the author list in the static export.
+        base if base.is_empty() || base == "index" => FALLBACK_SLUG.to_string(),
+        base => base,
+    }
+}
+
+/// The first author by name keeps the bare slug; others sharing it get a
+/// suffix derived from their own name. An author's slug therefore only
+/// changes when an author with the same slug and an earlier name is added.
+fn author_slug(key: &str, base: &str, keys_with_base: &[&str]) -> String {
+    if base != FALLBACK_SLUG && keys_with_base.first() == Some(&key) {
+        return base.to_string();
+    }
+    let digest = format!("{:x}", md5::compute(key));
+    format!("{base}-{}", &digest[..6])
+}
+
+fn summarize(group: &AuthorGroup, reading_times: &HashMap<String, i64>) -> LibraryAuthorSummary {
+    let count = |status| {
+        group
+            .items
+            .iter()
+            .filter(|item| item.status == status)
+            .count()
+    };
+    let ratings: Vec<i32> = group
+        .items
+        .iter()
+        .filter_map(|item| item.rating.filter(|rating| *rating > 0))
+        .collect();
+    let average_rating = (!ratings.is_empty()).then(|| {
+        let mean = ratings.iter().sum::<i32>() as f64 / ratings.len() as f64;
+        (mean * 10.0).round() / 10.0
+    });
+
+    LibraryAuthorSummary {
+        slug: group.slug.clone(),
+        name: group.name.clone(),
+        item_count: group.items.len(),
+        completed_count: count(LibraryStatus::Complete),
+        reading_count: count(LibraryStatus::Reading),
+        rated_count: ratings.len(),
+        average_rating,
+        total_reading_time_sec: group
+            .items
+            .iter()
+            .filter_map(|item| reading_times.get(&item.id))
+            .sum(),
+        cover_url: group.items[0].cover_url.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::api::responses::library::LibraryContentType;
+    use sqlx::types::Json;
+
+    fn item(id: &str, title: &str, authors: &[&str]) -> LibraryListItem {
+        LibraryListItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            title_sort: None,
+            authors: Json(authors.iter().map(|author| author.to_string()).collect()),
+            series: None,
+            status: LibraryStatus::Unknown,
+            progress_percentage: None,
+            rating: None,
+            annotation_count: 0,
+            highlight_count: 0,
+            bookmark_count: 0,
+            cover_url: format!("/assets/covers/{id}.webp"),
+            content_type: LibraryContentType::Book,
+            added_at: None,
+            slug: None,
+        }
+    }
+
+    fn ids(group: &AuthorGroup) -> Vec<&str> {
+        group.items.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn co_authored_items_appear_under_each_author() {
+        let groups = group_by_author(vec![
+            item("b", "Good Omens", &["Terry Pratchett", "Neil Gaiman"]),
+            item("a", "Mort", &["terry pratchett "]),
+            item("c", "Dune", &[]),
+        ]);
+
+        let names: Vec<&str> = groups.iter().map(|group| group.name.as_str()).collect();
+        assert_eq!(names, vec!["Neil Gaiman", "Terry Pratchett"]);
+        assert_eq!(groups[0].slug, "neil-gaiman");
+        assert_eq!(ids(&groups[0]), vec!["b"]);
+        assert_eq!(ids(&groups[1]), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn colliding_slugs_get_a_name_based_suffix() {
+        let groups = group_by_author(vec![
+            item("a", "One", &["Anne-Marie"]),
+            item("b", "Two", &["Anne Marie"]),
+            item("c", "Three", &["Index"]),
+        ]);
+        let slug = |name: &str| {
+            groups
+                .iter()
+                .find(|group| group.name == name)
+                .map(|group| group.slug.clone())
+                .unwrap()
+        };
+
+        assert_eq!(slug("Anne Marie"), "anne-marie");
+        let suffixed = slug("Anne-Marie");
+        assert!(suffixed.starts_with("anne-marie-") && suffixed.len() == "anne-marie-".len() + 6);
+        assert!(slug("Index").starts_with("author-"));
+
+        // Adding an author with a later name leaves existing slugs alone.
+        let more = group_by_author(vec![
+            item("a", "One", &["Anne-Marie"]),
+            item("b", "Two", &["Anne Marie"]),
+            item("d", "Four", &["Anne, Marie"]),
+        ]);
+        let slugs: HashSet<&str> = more.iter().map(|group| group.slug.as_str()).collect();
+        assert!(slugs.contains("anne-marie") && slugs.contains(suffixed.as_str()));
+        assert_eq!(slugs.len(), 3);
+    }
+}
@@ -0,0 +1,236 @@
+//! Author name clean-up applied to parsed book metadata during ingest.
+//!
+//! Two independent steps, both opt-in: reordering "Last, First" names and
+//! mapping spelling variants to one canonical name via an alias file:
+//!
+//! ```toml
+//! "J. R. R. Tolkien" = ["JRR Tolkien", "Tolkien, J. R. R."]
+//! ```
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, bail};
+
+/// Name suffixes that make a comma ambiguous ("Smith, John Jr." could be
+/// either order), so names containing them are left alone.
+const NAME_SUFFIXES: [&str; 9] = ["jr", "sr", "ii", "iii", "iv", "phd", "md", "esq", "dr"];
+
+/// Canonical author names keyed by their case-folded variants.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorAliases {
+    canonical_by_variant: HashMap<String, String>,
+}
+
+impl AuthorAliases {
+    /// Parse an alias map of canonical name → variants. Matching ignores case
+    /// and repeated whitespace; a variant listed under two canonical names is
+    /// rejected.
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let raw: HashMap<String, Vec<String>> =
+            toml::from_str(contents).context("Invalid author aliases")?;
+
+        let mut canonical_by_variant: HashMap<String, String> = HashMap::new();
+        for (canonical, variants) in &raw {
+            let canonical = canonical.trim();
+            if canonical.is_empty() {
+                bail!("Author aliases contain an empty canonical name");
+            }
+            for variant in std::iter::once(canonical).chain(variants.iter().map(String::as_str)) {
+                let key = alias_key(variant);
+                if key.is_empty() {
+                    continue;
+                }
+                if let Some(existing) = canonical_by_variant.get(&key)
+                    && existing != canonical
+                {
+                    bail!(
+                        "Author alias '{}' is listed for both '{}' and '{}'",
+                        variant.trim(),
+                        existing,
+                        canonical
+                    );
+                }
+                canonical_by_variant.insert(key, canonical.to_string());
+            }
+        }
+
+        Ok(Self {
+            canonical_by_variant,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.canonical_by_variant.is_empty()
+    }
+
+    /// Number of canonical authors.
+    pub fn len(&self) -> usize {
+        let mut canonical: Vec<&String> = self.canonical_by_variant.values().collect();
+        canonical.sort();
+        canonical.dedup();
+        canonical.len()
+    }
+
+    fn resolve(&self, name: &str) -> Option<&str> {
+        self.canonical_by_variant
+            .get(&alias_key(name))
+            .map(String::as_str)
+    }
+
+    /// Stable text form of the map, stored to notice when it changes.
+    pub fn fingerprint(&self) -> String {
+        let mut pairs: Vec<String> = self
+            .canonical_by_variant
+            .iter()
+            .map(|(variant, canonical)| format!("{variant}={canonical}"))
+            .collect();
+        pairs.sort();
+        pairs.join("\n")
+    }
+}
+
+fn alias_key(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Rewrite author names in place: optionally reorder "Last, First" names to
+/// "First Last", then replace known variants with their canonical name.
+/// Duplicates this produces (a book listing both "King, Stephen" and
+/// "Stephen King") are dropped.
+pub fn normalize_author_names(authors: &mut Vec<String>, reorder: bool, aliases: &AuthorAliases) {
+    if !reorder && aliases.is_empty() {
+        return;
+    }
+
+    let mut seen = Vec::with_capacity(authors.len());
+    authors.retain_mut(|name| {
+        // Aliases are checked as written first so a listed "Last, First"
+        // variant wins over the generic reordering.
+        if let Some(canonical) = aliases.resolve(name) {
+            *name = canonical.to_string();
+        } else if reorder && let Some(reordered) = reorder_inverted_name(name) {
+            *name = aliases
+                .resolve(&reordered)
+                .unwrap_or(&reordered)
+                .to_string();
+        }
+        let key = alias_key(name);
+        if seen.contains(&key) {
+            false
+        } else {
+            seen.push(key);
+            true
+        }
+    });
+}
+
+/// "Le Guin, Ursula K." → "Ursula K. Le Guin". Returns `None` for anything
+/// that isn't clearly a single inverted name: no comma or several commas,
+/// name lists, suffixes like "Jr.", or more words than a name usually has.
+fn reorder_inverted_name(name: &str) -> Option<String> {
+    let (last, first) = name.split_once(',')?;
+    let (last, first) = (last.trim(), first.trim());
+    if last.is_empty() || first.is_empty() || first.contains(',') {
+        return None;
+    }
+    if [";", "&", " and "].iter().any(|sep| name.contains(sep)) {
+        return None;
+    }
+
+    let last_words: Vec<&str> = last.split_whitespace().collect();
+    let first_words: Vec<&str> = first.split_whitespace().collect();
+    if last_words.len() > 3 || first_words.len() > 4 {
+        return None;
+    }
+    let has_suffix = last_words.iter().chain(&first_words).any(|word| {
+        let word = word.trim_end_matches('.').to_lowercase();
+        NAME_SUFFIXES.contains(&word.as_str())
+    });
+    if has_suffix || name.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!(
+        "{} {}",
+        first_words.join(" "),
+        last_words.join(" ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverted_names_are_reordered() {
+        assert_eq!(
+            reorder_inverted_name("Le Guin, Ursula K.").as_deref(),
+            Some("Ursula K. Le Guin")
+        );
+        assert_eq!(
+            reorder_inverted_name("King,  Stephen ").as_deref(),
+            Some("Stephen King")
+        );
+    }
+
+    #[test]
+    fn ambiguous_names_are_left_alone() {
+        for name in [
+            "Smith, John Jr.",
+            "Smith, Jr., John",
+            "Stephen King",
+            "King, Stephen, Straub, Peter",
+            "Pratchett, Terry and Gaiman, Neil",
+            "Doe, ",
+        ] {
+            assert_eq!(reorder_inverted_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn normalizing_merges_duplicates_in_order() {
+        let mut authors = vec![
+            "King, Stephen".to_string(),
+            "Peter Straub".to_string(),
+            "Stephen King".to_string(),
+        ];
+        normalize_author_names(&mut authors, true, &AuthorAliases::default());
+        assert_eq!(authors, ["Stephen King", "Peter Straub"]);
+    }
+
+    #[test]
+    fn aliases_match_case_insensitively_and_after_reordering() {
+        let aliases = AuthorAliases::from_toml_str(
+            r#""J. R. R. Tolkien" = ["JRR Tolkien", "Tolkien, J. R. R."]"#,
+        )
+        .unwrap();
+        assert_eq!(aliases.len(), 1);
+
+        let mut authors = vec![
+            "jrr  tolkien".to_string(),
+            "Tolkien, J. R. R.".to_string(),
+            "j. r. r. tolkien".to_string(),
+            "Tolkien, Christopher".to_string(),
+        ];
+        normalize_author_names(&mut authors, false, &aliases);
+        assert_eq!(authors, ["J. R. R. Tolkien", "Tolkien, Christopher"]);
+
+        let aliases =
+            AuthorAliases::from_toml_str(r#""Ursula K. Le Guin" = ["Ursula Le Guin"]"#).unwrap();
+        let mut authors = vec!["Le Guin, Ursula".to_string()];
+        normalize_author_names(&mut authors, true, &aliases);
+        assert_eq!(authors, ["Ursula K. Le Guin"]);
+    }
+
+    #[test]
+    fn conflicting_aliases_are_rejected() {
+        let error = AuthorAliases::from_toml_str(
+            "\"Stephen King\" = [\"S. King\"]\n\"Steve King\" = [\"s. king\"]",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("listed for both"), "{error}");
+    }
+}
@@ -0,0 +1,45 @@
+//! Library-wide highlight quotes (used by the highlight-of-the-day widget).
+
+use anyhow::Result;
+
+use crate::server::api::responses::library::HighlightsData;
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// Quotes longer than this many characters are cut off with an ellipsis to
+/// keep the payload (and the exported JSON file) bounded.
+const MAX_QUOTE_CHARS: usize = 400;
+
+/// Fetch all highlight texts in the library with their owning item.
+pub async fn highlights(repo: &LibraryRepository) -> Result<HighlightsData> {
+    let mut highlights = repo.list_highlight_quotes().await?;
+    for quote in &mut highlights {
+        quote.text = truncate_quote(&quote.text);
+    }
+    Ok(HighlightsData { highlights })
+}
+
+fn truncate_quote(text: &str) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(MAX_QUOTE_CHARS) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_quotes_are_kept_verbatim() {
+        assert_eq!(truncate_quote("  A short line. "), "A short line.");
+    }
+
+    #[test]
+    fn long_quotes_are_truncated_on_char_boundary() {
+        let text = "ä".repeat(MAX_QUOTE_CHARS + 10);
+        let truncated = truncate_quote(&text);
+        assert_eq!(truncated.chars().count(), MAX_QUOTE_CHARS + 1);
+        assert!(truncated.ends_with('…'));
+    }
+}
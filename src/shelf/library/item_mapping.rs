@@ -4,7 +4,9 @@
 //! `LibraryItem` model methods and produce flattened row types ready for
 //! SQLite upsert.  No I/O happens here.
 
-use crate::server::api::responses::library::{ExternalIdentifier, LibrarySeries};
+use crate::server::api::responses::library::{
+    ExternalIdentifier, LibraryContributors, LibrarySeries,
+};
 use crate::shelf::models::{ContentType, LibraryItem, LibraryItemFormat};
 use crate::shelf::time_config::TimeConfig;
 use crate::source::fingerprints::FileFingerprint;
@@ -29,6 +31,16 @@ pub fn map_item_to_row(item: &LibraryItem, time_config: &TimeConfig) -> LibraryI
         serde_json::to_string(&series).unwrap_or_default()
     });
 
+    let book_info = &item.book_info;
+    let contributors_json = (!book_info.editors.is_empty() || !book_info.translators.is_empty())
+        .then(|| {
+            let contributors = LibraryContributors {
+                editors: book_info.editors.clone(),
+                translators: book_info.translators.clone(),
+            };
+            serde_json::to_string(&contributors).unwrap_or_default()
+        });
+
     let identifiers: Vec<ExternalIdentifier> = item
         .identifiers()
         .into_iter()
@@ -50,12 +62,15 @@ pub fn map_item_to_row(item: &LibraryItem, time_config: &TimeConfig) -> LibraryI
         format: format_str(item.format).to_string(),
         content_type: content_type.to_string(),
         title: item.book_info.title.clone(),
+        title_sort: Some(item.sort_title()),
         authors_json: serde_json::to_string(&item.book_info.authors).unwrap_or_default(),
+        author_sort: item.book_info.author_sort.clone(),
+        contributors_json,
         series_json,
         description: item.book_info.description.clone(),
         language: item.language().cloned(),
         publisher: item.publisher().cloned(),
-        subjects_json: serde_json::to_string(item.subjects()).unwrap_or_default(),
+        subjects_json: serde_json::to_string(&item.subjects()).unwrap_or_default(),
         identifiers_json: serde_json::to_string(&identifiers).unwrap_or_default(),
         status: item.status().to_string(),
         progress_percentage: item.progress_percentage(),
@@ -92,6 +107,10 @@ pub fn map_item_to_row(item: &LibraryItem, time_config: &TimeConfig) -> LibraryI
         chapters_json: serde_json::to_string(&item.book_info.chapters).unwrap_or_default(),
         last_open_at: None,
         total_reading_time_sec: None,
+        added_at: item
+            .added_at
+            .map(|ts| time_config.format_timestamp_rfc3339(ts)),
+        is_private: item.is_private(),
         created_at: now.clone(),
         updated_at: now,
     }
@@ -118,12 +137,25 @@ pub fn map_annotations_to_rows(
             .as_deref()
             .and_then(|v| time_config.normalize_naive_datetime_to_rfc3339(v));
 
+        // KOReader does not always record the chapter; place such
+        // annotations in the book's TOC by page number where possible.
+        let chapter = annotation
+            .chapter
+            .clone()
+            .filter(|chapter| !chapter.trim().is_empty())
+            .or_else(|| {
+                annotation
+                    .pageno
+                    .and_then(|pageno| item.chapter_at_page(pageno))
+                    .map(str::to_string)
+            });
+
         rows.push(AnnotationRow {
             id: uuid::Uuid::new_v4().to_string(),
             item_id: item_id.to_string(),
             annotation_kind: kind.to_string(),
             lua_index: lua_index as i32,
-            chapter: annotation.chapter.clone(),
+            chapter,
             datetime,
             datetime_updated: annotation.datetime_updated.clone(),
             pageno: annotation.pageno.map(|p| p as i32),
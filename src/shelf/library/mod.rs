@@ -1,14 +1,24 @@
 //! Library-domain boundaries for list/detail queries and item persistence.
 
+pub mod author_pages;
+pub mod authors;
 pub mod build;
+pub mod highlights;
 pub mod item_mapping;
 pub mod page_activity;
 pub mod queries;
+pub mod series;
 pub mod service;
+pub mod slugs;
+pub mod timeline;
 
+pub use author_pages::{all_author_details, author_detail, author_list};
+pub use authors::normalize_author_names;
 pub use build::upsert_single_item;
+pub use highlights::highlights;
 pub use page_activity::page_activity;
 pub use queries::{LibraryDetailQuery, LibraryListQuery};
+pub use series::{all_series_details, series_detail, series_list, up_next};
 pub use service::{detail, list};
 
 /// Case-insensitive lookup into `stats_by_md5`.
@@ -22,3 +32,23 @@ pub(crate) fn lookup_stat_book<'a>(
         .or_else(|| stats_data.stats_by_md5.get(&md5.to_lowercase()))
         .or_else(|| stats_data.stats_by_md5.get(&md5.to_uppercase()))
 }
+
+/// Reading time in seconds per item ID, from the statistics database. Empty
+/// without reading data.
+pub(crate) async fn item_reading_times(
+    repo: &crate::store::sqlite::repo::LibraryRepository,
+    reading_data: Option<&crate::store::memory::ReadingData>,
+) -> anyhow::Result<std::collections::HashMap<String, i64>> {
+    let Some(reading_data) = reading_data else {
+        return Ok(std::collections::HashMap::new());
+    };
+    Ok(repo
+        .load_partial_md5s()
+        .await?
+        .into_iter()
+        .filter_map(|(id, md5)| {
+            let stat_book = lookup_stat_book(&reading_data.stats_data, md5.as_deref()?)?;
+            Some((id, stat_book.total_read_time?))
+        })
+        .collect())
+}
@@ -11,6 +11,7 @@ pub enum ItemSort {
     Rating,
     Annotations,
     LastOpenAt,
+    AddedAt,
 }
 
 query_enum_bare! {
@@ -22,6 +23,7 @@ query_enum_bare! {
         Rating => "rating",
         Annotations => "annotations",
         LastOpenAt => "last_open_at",
+        AddedAt => "added_at",
     }
 }
 
@@ -29,7 +31,11 @@ impl ItemSort {
     pub fn default_order(self) -> SortOrder {
         match self {
             Self::Title | Self::Author | Self::Status => SortOrder::Asc,
-            Self::Progress | Self::Rating | Self::Annotations | Self::LastOpenAt => SortOrder::Desc,
+            Self::Progress
+            | Self::Rating
+            | Self::Annotations
+            | Self::LastOpenAt
+            | Self::AddedAt => SortOrder::Desc,
         }
     }
 }
@@ -57,6 +63,7 @@ pub enum IncludeToken {
     Completions,
     ReaderPresentation,
     Chapters,
+    Timeline,
 }
 
 impl IncludeToken {
@@ -67,6 +74,7 @@ impl IncludeToken {
         IncludeToken::Completions,
         IncludeToken::ReaderPresentation,
         IncludeToken::Chapters,
+        IncludeToken::Timeline,
     ];
 }
 
@@ -79,12 +87,13 @@ impl SetToken for IncludeToken {
             "completions" => Some(Self::Completions),
             "reader_presentation" => Some(Self::ReaderPresentation),
             "chapters" => Some(Self::Chapters),
+            "timeline" => Some(Self::Timeline),
             _ => None,
         }
     }
 
     fn valid_tokens() -> &'static str {
-        "highlights, bookmarks, statistics, completions, reader_presentation, chapters, all"
+        "highlights, bookmarks, statistics, completions, reader_presentation, chapters, timeline, all"
     }
 
     fn all_variants() -> Option<&'static [Self]> {
@@ -107,6 +116,8 @@ pub struct LibraryListQuery {
 pub struct LibraryDetailQuery {
     pub id: String,
     pub includes: IncludeSet,
+    /// Description length above which a shortened excerpt is added (0 = never).
+    pub description_max_chars: usize,
 }
 
 impl LibraryDetailQuery {
@@ -114,8 +125,14 @@ impl LibraryDetailQuery {
         Self {
             id: id.into(),
             includes,
+            description_max_chars: 0,
         }
     }
+
+    pub fn with_description_max_chars(mut self, max_chars: usize) -> Self {
+        self.description_max_chars = max_chars;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +149,7 @@ mod tests {
         assert!(!set.has(IncludeToken::Completions));
         assert!(!set.has(IncludeToken::ReaderPresentation));
         assert!(!set.has(IncludeToken::Chapters));
+        assert!(!set.has(IncludeToken::Timeline));
     }
 
     #[test]
@@ -164,6 +182,7 @@ mod tests {
         assert!(set.has(IncludeToken::Completions));
         assert!(set.has(IncludeToken::ReaderPresentation));
         assert!(set.has(IncludeToken::Chapters));
+        assert!(set.has(IncludeToken::Timeline));
     }
 
     #[test]
@@ -175,6 +194,7 @@ mod tests {
         assert!(set.has(IncludeToken::Completions));
         assert!(set.has(IncludeToken::ReaderPresentation));
         assert!(set.has(IncludeToken::Chapters));
+        assert!(set.has(IncludeToken::Timeline));
     }
 
     #[test]
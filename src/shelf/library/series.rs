@@ -0,0 +1,340 @@
+//! Series pages: library items grouped by their series name.
+//!
+//! Items join a series when their series names match ignoring case and
+//! surrounding whitespace. Within a series, numbered items come first in
+//! number order; items without a (numeric) number follow, ordered by title.
+//! Series with fewer than `min_items` items get no page.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+
+use crate::server::api::responses::library::{
+    LibraryListItem, LibrarySeriesDetailData, LibrarySeriesListData, LibrarySeriesSummary,
+    LibraryStatus, LibraryUpNextData, LibraryUpNextEntry,
+};
+use crate::shelf::library::item_reading_times;
+use crate::shelf::library::queries::LibraryListQuery;
+use crate::shelf::library::slugs::slugify;
+use crate::store::memory::ReadingData;
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// Used when a series name has no letters or digits.
+const FALLBACK_SLUG: &str = "series";
+
+/// Items of one series, in reading order.
+struct SeriesGroup {
+    slug: String,
+    name: String,
+    items: Vec<LibraryListItem>,
+}
+
+/// All series with at least `min_items` items, ordered by name.
+pub async fn series_list(
+    repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+    min_items: usize,
+) -> Result<LibrarySeriesListData> {
+    let groups = load_series(repo, min_items).await?;
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    let series = groups
+        .iter()
+        .map(|group| summarize(group, &reading_times))
+        .collect();
+    Ok(LibrarySeriesListData { series })
+}
+
+/// Every series page at once, for the static export.
+pub async fn all_series_details(
+    repo: &LibraryRepository,
+    reading_data: Option<&ReadingData>,
+    min_items: usize,
+) -> Result<Vec<LibrarySeriesDetailData>> {
+    let groups = load_series(repo, min_items).await?;
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    Ok(groups
+        .into_iter()
+        .map(|group| LibrarySeriesDetailData {
+            series: summarize(&group, &reading_times),
+            items: group.items,
+        })
+        .collect())
+}
+
+/// The series with URL slug `slug` and its items. `None` when no series with
+/// at least `min_items` items has that slug.
+pub async fn series_detail(
+    repo: &LibraryRepository,
+    slug: &str,
+    reading_data: Option<&ReadingData>,
+    min_items: usize,
+) -> Result<Option<LibrarySeriesDetailData>> {
+    let Some(group) = load_series(repo, min_items)
+        .await?
+        .into_iter()
+        .find(|group| group.slug == slug)
+    else {
+        return Ok(None);
+    };
+    let reading_times = item_reading_times(repo, reading_data).await?;
+
+    Ok(Some(LibrarySeriesDetailData {
+        series: summarize(&group, &reading_times),
+        items: group.items,
+    }))
+}
+
+/// The next unread item of every series with a page that the reader has
+/// started, ordered by series name.
+pub async fn up_next(repo: &LibraryRepository, min_items: usize) -> Result<LibraryUpNextData> {
+    let entries = load_series(repo, min_items)
+        .await?
+        .into_iter()
+        .filter_map(|group| {
+            let item = next_unread(&group.items)?.clone();
+            Some(LibraryUpNextEntry {
+                completed_count: group
+                    .items
+                    .iter()
+                    .filter(|item| item.status == LibraryStatus::Complete)
+                    .count(),
+                item_count: group.items.len(),
+                series_slug: group.slug,
+                series_name: group.name,
+                item,
+            })
+        })
+        .collect();
+    Ok(LibraryUpNextData { entries })
+}
+
+async fn load_series(repo: &LibraryRepository, min_items: usize) -> Result<Vec<SeriesGroup>> {
+    let items = repo.list_items(&LibraryListQuery::default()).await?;
+    Ok(group_by_series(items, min_items))
+}
+
+fn group_by_series(items: Vec<LibraryListItem>, min_items: usize) -> Vec<SeriesGroup> {
+    let mut by_key: BTreeMap<String, Vec<LibraryListItem>> = BTreeMap::new();
+    for item in items {
+        let Some(key) = item
+            .series
+            .as_ref()
+            .map(|series| series.0.name.trim().to_lowercase())
+            .filter(|key| !key.is_empty())
+        else {
+            continue;
+        };
+        by_key.entry(key).or_default().push(item);
+    }
+
+    // `index` is the series list in the static export.
+    let mut taken = HashSet::from(["index".to_string()]);
+    let mut groups = Vec::new();
+    for (_, mut items) in by_key {
+        if items.len() < min_items {
+            continue;
+        }
+        items.sort_by(reading_order);
+
+        let name = items[0]
+            .series
+            .as_ref()
+            .map(|series| series.0.name.trim().to_string())
+            .unwrap_or_default();
+        let base = match slugify(&name) {
+            base if base.is_empty() => FALLBACK_SLUG.to_string(),
+            base => base,
+        };
+        let mut slug = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(slug.clone()) {
+            slug = format!("{base}-{suffix}");
+            suffix += 1;
+        }
+        groups.push(SeriesGroup { slug, name, items });
+    }
+
+    groups.sort_by_cached_key(|group| group.name.to_lowercase());
+    groups
+}
+
+/// The item right after the last completed one, when it is still unread.
+/// Series without a completed item, with the following item already being
+/// read or set aside, or with nothing after it have no next item.
+fn next_unread(items: &[LibraryListItem]) -> Option<&LibraryListItem> {
+    let last_completed = items
+        .iter()
+        .rposition(|item| item.status == LibraryStatus::Complete)?;
+    items
+        .get(last_completed + 1)
+        .filter(|item| item.status == LibraryStatus::Unknown)
+}
+
+fn reading_order(left: &LibraryListItem, right: &LibraryListItem) -> Ordering {
+    let by_number = match (series_number(left), series_number(right)) {
+        (Some(left), Some(right)) => left.total_cmp(&right),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+    by_number
+        .then_with(|| sort_title(left).cmp(&sort_title(right)))
+        .then_with(|| left.id.cmp(&right.id))
+}
+
+/// The item's number within its series, when it is numeric (e.g. `2`, `2.5`).
+fn series_number(item: &LibraryListItem) -> Option<f64> {
+    item.series
+        .as_ref()?
+        .0
+        .index
+        .as_deref()?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+fn sort_title(item: &LibraryListItem) -> String {
+    item.title_sort
+        .as_deref()
+        .unwrap_or(&item.title)
+        .to_lowercase()
+}
+
+fn summarize(group: &SeriesGroup, reading_times: &HashMap<String, i64>) -> LibrarySeriesSummary {
+    let count = |status| {
+        group
+            .items
+            .iter()
+            .filter(|item| item.status == status)
+            .count()
+    };
+    LibrarySeriesSummary {
+        slug: group.slug.clone(),
+        name: group.name.clone(),
+        item_count: group.items.len(),
+        completed_count: count(LibraryStatus::Complete),
+        reading_count: count(LibraryStatus::Reading),
+        abandoned_count: count(LibraryStatus::Abandoned),
+        total_reading_time_sec: group
+            .items
+            .iter()
+            .filter_map(|item| reading_times.get(&item.id))
+            .sum(),
+        cover_url: group.items[0].cover_url.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::api::responses::library::{LibraryContentType, LibrarySeries};
+    use sqlx::types::Json;
+
+    fn item(id: &str, title: &str, series: Option<(&str, Option<&str>)>) -> LibraryListItem {
+        LibraryListItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            title_sort: None,
+            authors: Json(Vec::new()),
+            series: series.map(|(name, index)| {
+                Json(LibrarySeries {
+                    name: name.to_string(),
+                    index: index.map(str::to_string),
+                })
+            }),
+            status: LibraryStatus::Unknown,
+            progress_percentage: None,
+            rating: None,
+            annotation_count: 0,
+            highlight_count: 0,
+            bookmark_count: 0,
+            cover_url: format!("/assets/covers/{id}.webp"),
+            content_type: LibraryContentType::Book,
+            added_at: None,
+            slug: None,
+        }
+    }
+
+    fn ids(group: &SeriesGroup) -> Vec<&str> {
+        group.items.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn numbered_items_come_first_and_the_rest_by_title() {
+        let mut unnumbered = item("c", "The Companion", Some(("Discworld", None)));
+        unnumbered.title_sort = Some("Companion, The".to_string());
+        let groups = group_by_series(
+            vec![
+                item("b", "Mort", Some(("Discworld", Some("4")))),
+                item("d", "Appendix", Some(("discworld ", Some("n/a")))),
+                unnumbered,
+                item("a", "The Colour of Magic", Some(("Discworld", Some("1")))),
+                item("e", "Dune", None),
+            ],
+            1,
+        );
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].slug, "discworld");
+        assert_eq!(ids(&groups[0]), vec!["a", "b", "d", "c"]);
+    }
+
+    #[test]
+    fn next_unread_follows_the_last_completed_item() {
+        let with_status = |id: &str, index: &str, status: LibraryStatus| LibraryListItem {
+            status,
+            ..item(id, id, Some(("Saga", Some(index))))
+        };
+
+        let started = vec![
+            with_status("a", "1", LibraryStatus::Complete),
+            with_status("b", "2", LibraryStatus::Unknown),
+            with_status("c", "3", LibraryStatus::Complete),
+            with_status("d", "4", LibraryStatus::Unknown),
+        ];
+        assert_eq!(
+            next_unread(&started).map(|item| item.id.as_str()),
+            Some("d")
+        );
+
+        let finished = vec![
+            with_status("a", "1", LibraryStatus::Complete),
+            with_status("b", "2", LibraryStatus::Complete),
+        ];
+        assert!(next_unread(&finished).is_none());
+
+        let continuing = vec![
+            with_status("a", "1", LibraryStatus::Complete),
+            with_status("b", "2", LibraryStatus::Reading),
+        ];
+        assert!(next_unread(&continuing).is_none());
+
+        let unstarted = vec![
+            with_status("a", "1", LibraryStatus::Unknown),
+            with_status("b", "2", LibraryStatus::Unknown),
+        ];
+        assert!(next_unread(&unstarted).is_none());
+    }
+
+    #[test]
+    fn small_series_are_skipped_and_slugs_stay_unique() {
+        let groups = group_by_series(
+            vec![
+                item("a", "One", Some(("Solo", Some("1")))),
+                item("b", "One", Some(("Saga!", Some("1")))),
+                item("c", "Two", Some(("Saga!", Some("2")))),
+                item("d", "One", Some(("Saga?", Some("1")))),
+                item("e", "Two", Some(("Saga?", Some("2")))),
+            ],
+            2,
+        );
+
+        let slugs: Vec<&str> = groups.iter().map(|group| group.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["saga", "saga-2"]);
+    }
+}
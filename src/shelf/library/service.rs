@@ -8,9 +8,12 @@ use crate::server::api::responses::library::{
 };
 use crate::shelf::library::lookup_stat_book;
 use crate::shelf::library::queries::{IncludeToken, LibraryDetailQuery, LibraryListQuery};
+use crate::shelf::library::timeline::build_timeline;
 use crate::shelf::statistics::BookStatistics;
+use crate::shelf::statistics::compute::sessions::DEFAULT_SESSION_GAP_SECONDS;
 use crate::shelf::time_config::TimeConfig;
-use crate::source::koreader::types::{BookSessionStats, StatBook};
+use crate::shelf::utils::truncate_html;
+use crate::source::koreader::types::{BookSessionStats, PageStat, StatBook};
 use crate::store::memory::ReadingData;
 use crate::store::sqlite::repo::LibraryRepository;
 
@@ -31,6 +34,13 @@ pub async fn detail(
         return Ok(None);
     };
 
+    if query.description_max_chars > 0 {
+        item.description_excerpt = item
+            .description
+            .as_deref()
+            .and_then(|description| truncate_html(description, query.description_max_chars));
+    }
+
     let includes = &query.includes;
 
     let reader_presentation = if includes.has(IncludeToken::ReaderPresentation) {
@@ -39,35 +49,44 @@ pub async fn detail(
         None
     };
 
-    let highlights = if includes.has(IncludeToken::Highlights) {
+    // Private items keep their annotation content off the site; counts still
+    // show up in the statistics below.
+    let show_annotations = !item.is_private;
+
+    let highlights = if show_annotations && includes.has(IncludeToken::Highlights) {
         Some(repo.get_annotations(&query.id, Some("highlight")).await?)
     } else {
         None
     };
 
-    let bookmarks = if includes.has(IncludeToken::Bookmarks) {
+    let bookmarks = if show_annotations && includes.has(IncludeToken::Bookmarks) {
         Some(repo.get_annotations(&query.id, Some("bookmark")).await?)
     } else {
         None
     };
 
-    // Resolve per-item statistics and completions via partial_md5_checksum
+    // Resolve per-item statistics, completions and sessions via partial_md5_checksum
     // linkage into the in-memory reading data.
-    let stat_book =
-        if includes.has(IncludeToken::Statistics) || includes.has(IncludeToken::Completions) {
-            reading_data
-                .zip(item.partial_md5_checksum.as_deref())
-                .and_then(|(rd, md5)| lookup_stat_book(&rd.stats_data, md5))
-        } else {
-            None
-        };
+    let stat_book = if includes.has(IncludeToken::Statistics)
+        || includes.has(IncludeToken::Completions)
+        || includes.has(IncludeToken::Timeline)
+    {
+        reading_data
+            .zip(item.partial_md5_checksum.as_deref())
+            .and_then(|(rd, md5)| lookup_stat_book(&rd.stats_data, md5))
+    } else {
+        None
+    };
 
     let statistics = if includes.has(IncludeToken::Statistics) {
         let annotation_counts = repo.get_annotation_counts(&query.id).await?;
         let session_and_reading = stat_book.as_ref().and_then(|sb| {
             let rd = reading_data?;
-            let session_stats =
-                sb.calculate_session_stats(&rd.stats_data.page_stats, &rd.time_config);
+            let session_stats = sb.calculate_session_stats(
+                &rd.stats_data.page_stats,
+                &rd.time_config,
+                &rd.stats_config,
+            );
             Some(map_reading_stats(sb, &session_stats, &rd.time_config))
         });
         Some(map_detail_statistics(
@@ -98,6 +117,43 @@ pub async fn detail(
         None
     };
 
+    // Sessions come from the reading data; annotations join them only for
+    // items that show their annotations at all.
+    let timeline = if includes.has(IncludeToken::Timeline) {
+        let annotations = if show_annotations {
+            repo.get_annotations(&query.id, None).await?
+        } else {
+            Vec::new()
+        };
+        let page_stats: Vec<PageStat> = stat_book
+            .as_ref()
+            .zip(reading_data)
+            .map(|(sb, rd)| {
+                rd.stats_data
+                    .page_stats
+                    .iter()
+                    .filter(|ps| ps.id_book == sb.id && ps.duration > 0)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Without reading data there is no configured timezone, so annotation
+        // datetimes are read as server-local time.
+        let local_time = TimeConfig::new(None, 0);
+        let time_config = reading_data.map_or(&local_time, |rd| &rd.time_config);
+        let session_gap_sec = reading_data.map_or(DEFAULT_SESSION_GAP_SECONDS, |rd| {
+            rd.stats_config.session_gap_sec
+        });
+        Some(build_timeline(
+            &page_stats,
+            annotations,
+            time_config,
+            session_gap_sec,
+        ))
+    } else {
+        None
+    };
+
     Ok(Some(LibraryDetailData {
         item,
         highlights,
@@ -106,6 +162,7 @@ pub async fn detail(
         completions,
         reader_presentation,
         chapters,
+        timeline,
     }))
 }
 
@@ -116,6 +173,7 @@ struct ReadingStats {
     last_open_at: Option<String>,
     pages: Option<i64>,
     total_reading_time_sec: Option<i64>,
+    total_read_pages: Option<i64>,
     session_stats: LibrarySessionStats,
 }
 
@@ -130,12 +188,14 @@ fn map_reading_stats(
             .map(|ts| time_config.format_timestamp_rfc3339(ts)),
         pages: stat_book.pages,
         total_reading_time_sec: stat_book.total_read_time,
+        total_read_pages: stat_book.total_read_pages.filter(|&pages| pages > 0),
         session_stats: LibrarySessionStats {
             session_count: session_stats.session_count,
             average_session_duration_sec: session_stats.average_session_duration,
             longest_session_duration_sec: session_stats.longest_session_duration,
             last_read_date: session_stats.last_read_date.clone(),
             reading_speed: session_stats.reading_speed,
+            pages_per_day: session_stats.pages_per_day,
         },
     }
 }
@@ -153,6 +213,7 @@ fn map_detail_statistics(
             last_open_at: reading.as_ref().and_then(|r| r.last_open_at.clone()),
             pages: reading.as_ref().and_then(|r| r.pages),
             total_reading_time_sec: reading.as_ref().and_then(|r| r.total_reading_time_sec),
+            total_read_pages: reading.as_ref().and_then(|r| r.total_read_pages),
         }),
         session_stats: reading
             .map(|r| r.session_stats)
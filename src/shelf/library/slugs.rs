@@ -0,0 +1,212 @@
+//! Readable URL slugs for library items (`--id-scheme slug`).
+//!
+//! A slug is the title followed by the first author's surname, e.g.
+//! `the-hobbit-tolkien`. Items whose bases collide get `-2`, `-3`, … in
+//! item ID order. An item keeps the slug it already has as long as its title
+//! and author still produce the same base, so adding a book never renames
+//! the URL of an existing one. Slugs an item used to have stay reserved for
+//! it, so an old link never lands on a different book.
+
+use std::collections::{HashMap, HashSet};
+
+/// Longest base before the collision suffix, in characters.
+const MAX_BASE_CHARS: usize = 80;
+
+/// Used when neither title nor author contain anything usable.
+const FALLBACK_BASE: &str = "item";
+
+/// An item to assign a slug to.
+pub struct SlugCandidate {
+    pub id: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    /// Slug from the previous run, if any.
+    pub current: Option<String>,
+}
+
+/// Assign a unique slug to every candidate. `retired` maps slugs items used
+/// to have to their item ID. Returns `id -> slug`.
+pub fn assign_slugs(
+    candidates: &[SlugCandidate],
+    retired: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut ordered: Vec<(&SlugCandidate, String)> = candidates
+        .iter()
+        .map(|candidate| (candidate, slug_base(&candidate.title, &candidate.authors)))
+        .collect();
+    ordered.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
+
+    let mut taken = HashSet::new();
+    let mut assigned = HashMap::new();
+
+    for (candidate, base) in &ordered {
+        if let Some(current) = &candidate.current
+            && belongs_to_base(current, base)
+            && taken.insert(current.clone())
+        {
+            assigned.insert(candidate.id.clone(), current.clone());
+        }
+    }
+
+    for (candidate, base) in &ordered {
+        if assigned.contains_key(&candidate.id) {
+            continue;
+        }
+        let mut slug = base.clone();
+        let mut suffix = 2;
+        while taken.contains(&slug)
+            || looks_like_item_id(&slug)
+            || retired
+                .get(&slug)
+                .is_some_and(|owner| owner != &candidate.id)
+        {
+            slug = format!("{base}-{suffix}");
+            suffix += 1;
+        }
+        taken.insert(slug.clone());
+        assigned.insert(candidate.id.clone(), slug);
+    }
+
+    assigned
+}
+
+/// `the-hobbit-tolkien` for "The Hobbit" by "J.R.R. Tolkien".
+pub fn slug_base(title: &str, authors: &[String]) -> String {
+    let title = slugify(title);
+    let author = authors
+        .first()
+        .map(|author| slugify(surname(author)))
+        .unwrap_or_default();
+
+    let base = match (title.is_empty(), author.is_empty()) {
+        (false, false) => format!("{title}-{author}"),
+        (false, true) => title,
+        (true, false) => author,
+        (true, true) => return FALLBACK_BASE.to_string(),
+    };
+    let truncated: String = base.chars().take(MAX_BASE_CHARS).collect();
+    truncated.trim_end_matches('-').to_string()
+}
+
+/// "Tolkien, J.R.R." and "J.R.R. Tolkien" both give "Tolkien".
+fn surname(author: &str) -> &str {
+    match author.split_once(',') {
+        Some((last, _)) => last.trim(),
+        None => author.split_whitespace().last().unwrap_or_default(),
+    }
+}
+
+/// Lowercase letters and digits separated by single dashes. Apostrophes are
+/// dropped so "Ender's Game" becomes `enders-game`.
+pub(crate) fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if matches!(c, '\'' | '\u{2019}') {
+            continue;
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Whether `slug` is `base` or `base` with a collision suffix.
+fn belongs_to_base(slug: &str, base: &str) -> bool {
+    slug == base
+        || slug
+            .strip_prefix(base)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .and_then(|suffix| suffix.parse::<u32>().ok())
+            .is_some_and(|suffix| suffix >= 2)
+}
+
+/// A slug must never shadow an item ID, which is resolved first.
+fn looks_like_item_id(slug: &str) -> bool {
+    slug.len() == 32 && slug.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, title: &str, author: &str, current: Option<&str>) -> SlugCandidate {
+        SlugCandidate {
+            id: id.to_string(),
+            title: title.to_string(),
+            authors: vec![author.to_string()],
+            current: current.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn base_is_title_and_surname() {
+        let authors = |name: &str| vec![name.to_string()];
+        assert_eq!(
+            slug_base("The Hobbit", &authors("J.R.R. Tolkien")),
+            "the-hobbit-tolkien"
+        );
+        assert_eq!(
+            slug_base("Ender's Game", &authors("Card, Orson Scott")),
+            "enders-game-card"
+        );
+        assert_eq!(slug_base("Über Bücher!", &[]), "über-bücher");
+        assert_eq!(slug_base("???", &[]), "item");
+        assert!(slug_base(&"long ".repeat(40), &[]).chars().count() <= MAX_BASE_CHARS);
+    }
+
+    #[test]
+    fn collisions_are_numbered_in_id_order() {
+        let slugs = assign_slugs(
+            &[
+                candidate("bbb", "Dune", "Frank Herbert", None),
+                candidate("aaa", "Dune", "Frank Herbert", None),
+                candidate("ccc", "Dune", "Frank Herbert", None),
+            ],
+            &HashMap::new(),
+        );
+        assert_eq!(slugs["aaa"], "dune-herbert");
+        assert_eq!(slugs["bbb"], "dune-herbert-2");
+        assert_eq!(slugs["ccc"], "dune-herbert-3");
+    }
+
+    #[test]
+    fn existing_slugs_survive_new_collisions() {
+        // "bbb" was alone and got the bare slug; "aaa" sorts first but is new.
+        let slugs = assign_slugs(
+            &[
+                candidate("bbb", "Dune", "Frank Herbert", Some("dune-herbert")),
+                candidate("aaa", "Dune", "Frank Herbert", None),
+            ],
+            &HashMap::new(),
+        );
+        assert_eq!(slugs["bbb"], "dune-herbert");
+        assert_eq!(slugs["aaa"], "dune-herbert-2");
+
+        // A retitled book drops its old slug.
+        let slugs = assign_slugs(
+            &[candidate(
+                "bbb",
+                "Dune Messiah",
+                "Frank Herbert",
+                Some("dune-herbert"),
+            )],
+            &HashMap::new(),
+        );
+        assert_eq!(slugs["bbb"], "dune-messiah-herbert");
+    }
+
+    #[test]
+    fn retired_slugs_stay_with_their_item() {
+        let retired = HashMap::from([("dune-herbert".to_string(), "bbb".to_string())]);
+
+        // "bbb" was renamed away from "dune-herbert"; a new "Dune" skips it.
+        let slugs = assign_slugs(&[candidate("aaa", "Dune", "Frank Herbert", None)], &retired);
+        assert_eq!(slugs["aaa"], "dune-herbert-2");
+
+        // Renamed back, "bbb" gets its old slug again.
+        let slugs = assign_slugs(&[candidate("bbb", "Dune", "Frank Herbert", None)], &retired);
+        assert_eq!(slugs["bbb"], "dune-herbert");
+    }
+}
@@ -0,0 +1,192 @@
+//! Reading-history timeline — sessions and annotations of one item by date.
+
+use std::collections::HashSet;
+
+use crate::server::api::responses::library::{
+    LibraryAnnotation, LibraryTimeline, LibraryTimelineEntry, LibraryTimelineEntryKind,
+};
+use crate::shelf::statistics::compute::sessions;
+use crate::shelf::time_config::TimeConfig;
+use crate::source::koreader::types::PageStat;
+
+/// Merge an item's reading sessions and annotations into one timeline.
+///
+/// `page_stats` are the item's own page reads, split into sessions at pauses
+/// longer than `session_gap_sec`. Annotations whose datetime cannot be parsed
+/// end up in `undated` instead of breaking the order.
+pub fn build_timeline(
+    page_stats: &[PageStat],
+    annotations: Vec<LibraryAnnotation>,
+    time_config: &TimeConfig,
+    session_gap_sec: i64,
+) -> LibraryTimeline {
+    let mut dated: Vec<(i64, LibraryTimelineEntry)> = Vec::new();
+
+    for session in sessions::split_sessions(page_stats, session_gap_sec) {
+        let Some(start) = session.first().map(|stat| stat.start_time) else {
+            continue;
+        };
+        let pages: HashSet<i64> = session.iter().map(|stat| stat.page).collect();
+        dated.push((
+            start,
+            LibraryTimelineEntry {
+                kind: LibraryTimelineEntryKind::Session,
+                time: Some(time_config.format_timestamp_rfc3339(start)),
+                date: Some(time_config.format_date(start)),
+                duration_sec: Some(session.iter().map(|stat| stat.duration).sum()),
+                pages_read: Some(pages.len() as i64),
+                annotation: None,
+            },
+        ));
+    }
+
+    let mut undated = Vec::new();
+    for annotation in annotations {
+        let kind = annotation_kind(&annotation);
+        let timestamp = annotation
+            .datetime
+            .as_deref()
+            .and_then(|value| time_config.naive_datetime_timestamp(value));
+        let mut entry = LibraryTimelineEntry {
+            kind,
+            time: None,
+            date: None,
+            duration_sec: None,
+            pages_read: None,
+            annotation: Some(annotation),
+        };
+        match timestamp {
+            Some(timestamp) => {
+                entry.time = Some(time_config.format_timestamp_rfc3339(timestamp));
+                entry.date = Some(time_config.format_date(timestamp));
+                dated.push((timestamp, entry));
+            }
+            None => undated.push(entry),
+        }
+    }
+
+    // Stable, so a session keeps its place before annotations made the
+    // second it started.
+    dated.sort_by_key(|(timestamp, _)| *timestamp);
+
+    LibraryTimeline {
+        entries: dated.into_iter().map(|(_, entry)| entry).collect(),
+        undated,
+    }
+}
+
+fn annotation_kind(annotation: &LibraryAnnotation) -> LibraryTimelineEntryKind {
+    if annotation.note.is_some() {
+        LibraryTimelineEntryKind::Note
+    } else if annotation.text.is_some() {
+        LibraryTimelineEntryKind::Highlight
+    } else {
+        LibraryTimelineEntryKind::Bookmark
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::statistics::compute::sessions::DEFAULT_SESSION_GAP_SECONDS;
+
+    fn utc() -> TimeConfig {
+        TimeConfig::new(Some("UTC".parse().expect("timezone should parse")), 0)
+    }
+
+    fn page_stat(page: i64, start_time: i64, duration: i64) -> PageStat {
+        PageStat {
+            id_book: 1,
+            page,
+            start_time,
+            duration,
+        }
+    }
+
+    fn annotation(id: &str, datetime: Option<&str>, text: Option<&str>) -> LibraryAnnotation {
+        LibraryAnnotation {
+            id: id.to_string(),
+            chapter: None,
+            datetime: datetime.map(str::to_string),
+            datetime_updated: None,
+            pageno: Some(3),
+            text: text.map(str::to_string),
+            note: None,
+            pos0: None,
+            pos1: None,
+            color: None,
+            drawer: None,
+        }
+    }
+
+    #[test]
+    fn sessions_and_annotations_are_interleaved_by_time() {
+        // 2024-03-01 10:00 UTC and 2024-03-06 10:00 UTC.
+        let day_one = 1_709_287_200;
+        let day_six = day_one + 5 * 86_400;
+        let stats = [
+            page_stat(1, day_one, 60),
+            page_stat(2, day_one + 60, 60),
+            page_stat(2, day_one + 120, 30),
+            page_stat(10, day_six, 120),
+        ];
+        let annotations = vec![
+            annotation("late", Some("2024-03-06 10:01:00"), None),
+            annotation("early", Some("2024-03-01 10:01:30"), Some("quote")),
+        ];
+
+        let timeline = build_timeline(&stats, annotations, &utc(), DEFAULT_SESSION_GAP_SECONDS);
+
+        let order: Vec<_> = timeline
+            .entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.kind,
+                    entry.date.as_deref().unwrap(),
+                    entry.annotation.as_ref().map(|a| a.id.as_str()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (LibraryTimelineEntryKind::Session, "2024-03-01", None),
+                (
+                    LibraryTimelineEntryKind::Highlight,
+                    "2024-03-01",
+                    Some("early")
+                ),
+                (LibraryTimelineEntryKind::Session, "2024-03-06", None),
+                (
+                    LibraryTimelineEntryKind::Bookmark,
+                    "2024-03-06",
+                    Some("late")
+                ),
+            ]
+        );
+        assert_eq!(timeline.entries[0].duration_sec, Some(150));
+        assert_eq!(timeline.entries[0].pages_read, Some(2));
+        assert!(timeline.undated.is_empty());
+    }
+
+    #[test]
+    fn unparseable_annotation_dates_are_undated() {
+        let annotations = vec![
+            annotation("missing", None, Some("quote")),
+            annotation("garbled", Some("last tuesday"), Some("quote")),
+            annotation("dated", Some("2024-03-01 10:00:00"), Some("quote")),
+        ];
+
+        let timeline = build_timeline(&[], annotations, &utc(), DEFAULT_SESSION_GAP_SECONDS);
+
+        assert_eq!(timeline.entries.len(), 1);
+        let undated: Vec<_> = timeline
+            .undated
+            .iter()
+            .map(|entry| entry.annotation.as_ref().unwrap().id.as_str())
+            .collect();
+        assert_eq!(undated, ["missing", "garbled"]);
+        assert!(timeline.undated.iter().all(|entry| entry.date.is_none()));
+    }
+}
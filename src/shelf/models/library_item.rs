@@ -235,6 +235,8 @@ pub struct LibraryItem {
     pub koreader_metadata: Option<KoReaderMetadata>,
     pub file_path: PathBuf,
     pub format: LibraryItemFormat,
+    /// Unix timestamp of when the file was added to the library
+    pub added_at: Option<i64>,
 }
 
 impl LibraryItem {
@@ -313,6 +315,58 @@ impl LibraryItem {
         metadata.pagemap_doc_pages.filter(|pages| *pages > 0)
     }
 
+    /// Table of contents chapter that page `pageno` falls in, estimated from
+    /// the page's share of KOReader's page total.
+    ///
+    /// `None` without a TOC or page total, or when several TOC entries start
+    /// at the matched position (e.g. chapters sharing one EPUB file), since
+    /// the page could belong to any of them.
+    pub fn chapter_at_page(&self, pageno: u32) -> Option<&str> {
+        let total = self.koreader_metadata.as_ref()?.doc_pages?;
+        if pageno == 0 || pageno > total {
+            return None;
+        }
+        let fraction = f64::from(pageno - 1) / f64::from(total);
+
+        let start = self
+            .book_info
+            .chapters
+            .iter()
+            .map(|chapter| chapter.position)
+            .filter(|position| *position <= fraction)
+            .max_by(f64::total_cmp)?;
+        let mut at_start = self
+            .book_info
+            .chapters
+            .iter()
+            .filter(|chapter| chapter.position == start);
+        match (at_start.next(), at_start.next()) {
+            (Some(chapter), None) => Some(chapter.title.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Title to sort by: the file's sort name when it has one, otherwise the
+    /// title with a leading English article moved to the end ("Hobbit, The").
+    pub fn sort_title(&self) -> String {
+        if let Some(sort) = self.book_info.title_sort.as_deref().map(str::trim)
+            && !sort.is_empty()
+        {
+            return sort.to_string();
+        }
+
+        let title = self.book_info.title.trim();
+        match title.split_once(char::is_whitespace) {
+            Some((article, rest))
+                if ["the", "a", "an"].contains(&article.to_lowercase().as_str())
+                    && !rest.trim().is_empty() =>
+            {
+                format!("{}, {article}", rest.trim_start())
+            }
+            _ => title.to_string(),
+        }
+    }
+
     /// Get language, preferring EPUB metadata over KoReader metadata
     pub fn language(&self) -> Option<&String> {
         resolve_language(
@@ -365,9 +419,30 @@ impl LibraryItem {
             .collect()
     }
 
-    /// Get subjects/genres from EPUB metadata
-    pub fn subjects(&self) -> &Vec<String> {
-        &self.book_info.subjects
+    /// Get subjects/genres from EPUB metadata, without KoShelf's own tags
+    pub fn subjects(&self) -> Vec<&String> {
+        self.book_info
+            .subjects
+            .iter()
+            .filter(|subject| !is_private_tag(subject))
+            .collect()
+    }
+
+    /// Whether the item is tagged private, either in the book's subjects or
+    /// in the keywords edited in KOReader's book information.
+    pub fn is_private(&self) -> bool {
+        let keywords = self
+            .koreader_metadata
+            .as_ref()
+            .and_then(|m| m.doc_props.as_ref())
+            .and_then(|props| props.keywords.as_deref())
+            .unwrap_or_default();
+        self.book_info
+            .subjects
+            .iter()
+            .map(String::as_str)
+            .chain(keywords.split(['\n', ',', ';']))
+            .any(is_private_tag)
     }
 
     /// Get normalized Hardcover-related identifiers (slug and editions).
@@ -420,8 +495,18 @@ impl LibraryItem {
     }
 }
 
+/// Subject or keyword that marks an item as private: it stays on the shelf
+/// and in statistics, but its annotations and review note are not shown.
+pub const PRIVATE_TAG: &str = "koshelf:private";
+
+fn is_private_tag(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case(PRIVATE_TAG)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::ChapterEntry;
+    use crate::shelf::models::DocProps;
     use crate::tests::fixtures;
 
     #[test]
@@ -457,6 +542,75 @@ mod tests {
 
         assert_eq!(item.language().map(String::as_str), Some("en-US"));
     }
+
+    #[test]
+    fn private_tag_is_read_from_subjects_or_koreader_keywords() {
+        let mut item = fixtures::library_item("id-1", None);
+        item.book_info.subjects = vec!["Fantasy".to_string(), "KoShelf:Private ".to_string()];
+        assert!(item.is_private());
+        assert_eq!(item.subjects(), [&"Fantasy".to_string()]);
+
+        let mut metadata = fixtures::koreader_metadata_for_pages("md5", true, false, 300);
+        metadata.doc_props = Some(DocProps {
+            authors: None,
+            description: None,
+            identifiers: None,
+            keywords: Some("journal\nkoshelf:private".to_string()),
+            language: None,
+            title: None,
+        });
+        let item = fixtures::library_item("id-2", Some(metadata));
+        assert!(item.is_private());
+
+        assert!(!fixtures::library_item("id-3", None).is_private());
+    }
+
+    #[test]
+    fn chapter_at_page_uses_the_nearest_preceding_toc_entry() {
+        let metadata = fixtures::koreader_metadata_for_pages("md5", true, false, 300);
+        let mut item = fixtures::library_item("id-1", Some(metadata));
+        assert_eq!(item.chapter_at_page(50), None);
+
+        let chapter = |title: &str, position| ChapterEntry {
+            title: title.to_string(),
+            position,
+        };
+        item.book_info.chapters = vec![
+            chapter("Prologue", 0.0),
+            chapter("One", 0.1),
+            chapter("Two", 0.5),
+            chapter("Three", 0.5),
+        ];
+
+        // 200 pages in the fixture: page 1 opens the book, page 21 is at 10%.
+        assert_eq!(item.chapter_at_page(1), Some("Prologue"));
+        assert_eq!(item.chapter_at_page(20), Some("Prologue"));
+        assert_eq!(item.chapter_at_page(21), Some("One"));
+        // "Two" and "Three" share a start, so the page cannot be placed.
+        assert_eq!(item.chapter_at_page(150), None);
+        assert_eq!(item.chapter_at_page(0), None);
+        assert_eq!(item.chapter_at_page(201), None);
+    }
+
+    #[test]
+    fn sort_title_prefers_the_sort_name_and_moves_leading_articles() {
+        let mut item = fixtures::library_item("id-1", None);
+        item.book_info.title = "The Hobbit".to_string();
+        assert_eq!(item.sort_title(), "Hobbit, The");
+
+        item.book_info.title = "An Elegant Puzzle".to_string();
+        assert_eq!(item.sort_title(), "Elegant Puzzle, An");
+
+        item.book_info.title = "Theory of Everything".to_string();
+        assert_eq!(item.sort_title(), "Theory of Everything");
+
+        item.book_info.title = "A".to_string();
+        assert_eq!(item.sort_title(), "A");
+
+        item.book_info.title = "A Wizard of Earthsea".to_string();
+        item.book_info.title_sort = Some("Earthsea 1: Wizard".to_string());
+        assert_eq!(item.sort_title(), "Earthsea 1: Wizard");
+    }
 }
 
 /// A single chapter entry from a book table of contents.
@@ -470,7 +624,19 @@ pub struct ChapterEntry {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookInfo {
     pub title: String,
+    /// Sort form of the title (`file-as` or `calibre:title_sort`), e.g. "Hobbit, The"
+    #[serde(default)]
+    pub title_sort: Option<String>,
     pub authors: Vec<String>,
+    /// Sort form of the first author (`file-as`), e.g. "Tolkien, J. R. R."
+    #[serde(default)]
+    pub author_sort: Option<String>,
+    /// Editors listed as EPUB creators with the `edt` role
+    #[serde(default)]
+    pub editors: Vec<String>,
+    /// Translators listed as EPUB creators with the `trl` role
+    #[serde(default)]
+    pub translators: Vec<String>,
     pub description: Option<String>,
     pub language: Option<String>,
     pub publisher: Option<String>,
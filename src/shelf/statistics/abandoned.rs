@@ -0,0 +1,243 @@
+//! Books given up on: library items marked as abandoned, with the time spent on them.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+
+use crate::server::api::responses::common::ContentTypeFilter;
+use crate::server::api::responses::library::{LibraryListItem, LibraryStatus};
+use crate::server::api::responses::reading::{AbandonedItem, AbandonedYear, ReadingAbandonedData};
+use crate::shelf::library::queries::{ItemSort, LibraryListQuery};
+use crate::shelf::statistics::shared;
+use crate::shelf::time_config::TimeConfig;
+use crate::source::koreader::types::StatisticsData;
+use crate::store::memory::ReadingData;
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// List abandoned library items with the time invested in their unfinished read.
+///
+/// Items are matched to statistics by MD5. Only reading after an item's last
+/// completion counts, and an item with a completion but no reading after it
+/// was finished after all, so it is left out. Items without any statistics are
+/// still listed with zero time; they have no last read date and therefore no year.
+pub async fn abandoned(
+    reading_data: &ReadingData,
+    repo: &LibraryRepository,
+    scope: ContentTypeFilter,
+) -> Result<ReadingAbandonedData> {
+    let stats = shared::filter_stats_by_scope(&reading_data.stats_data, scope);
+    let library_items = repo
+        .list_items(&LibraryListQuery {
+            scope,
+            sort: ItemSort::Title,
+            order: None,
+        })
+        .await?;
+
+    Ok(build_abandoned(
+        &stats,
+        &reading_data.time_config,
+        &library_items,
+    ))
+}
+
+fn build_abandoned(
+    stats: &StatisticsData,
+    time_config: &TimeConfig,
+    library_items: &[LibraryListItem],
+) -> ReadingAbandonedData {
+    let mut items = Vec::new();
+
+    for library_item in library_items
+        .iter()
+        .filter(|item| item.status == LibraryStatus::Abandoned)
+    {
+        let book = stats
+            .books
+            .iter()
+            .find(|book| book.md5.eq_ignore_ascii_case(&library_item.id));
+
+        let (reading_time_sec, last_read_date) = match book {
+            Some(book) => {
+                let last_end = book.completions.as_ref().and_then(|completions| {
+                    completions
+                        .entries
+                        .iter()
+                        .filter_map(|entry| {
+                            NaiveDate::parse_from_str(&entry.end_date, "%Y-%m-%d").ok()
+                        })
+                        .max()
+                });
+
+                let mut reading_time_sec = 0;
+                let mut last_read_date: Option<NaiveDate> = None;
+                for ps in stats
+                    .page_stats
+                    .iter()
+                    .filter(|ps| ps.id_book == book.id && ps.duration > 0)
+                {
+                    let date = time_config.date_for_timestamp(ps.start_time);
+                    if last_end.is_some_and(|end| date <= end) {
+                        continue;
+                    }
+                    reading_time_sec += ps.duration;
+                    last_read_date = last_read_date.max(Some(date));
+                }
+
+                if last_end.is_some() && last_read_date.is_none() {
+                    continue;
+                }
+                (reading_time_sec, last_read_date)
+            }
+            None => (0, None),
+        };
+
+        items.push(AbandonedItem {
+            id: library_item.id.clone(),
+            title: library_item.title.clone(),
+            authors: library_item.authors.0.clone(),
+            cover_url: library_item.cover_url.clone(),
+            content_type: library_item.content_type,
+            reading_time_sec,
+            last_read_date: last_read_date.map(|date| date.format("%Y-%m-%d").to_string()),
+        });
+    }
+
+    // Most recently abandoned first; items never read go last, by title.
+    items.sort_by(|a, b| b.last_read_date.cmp(&a.last_read_date));
+
+    let mut years: BTreeMap<i32, (usize, i64)> = BTreeMap::new();
+    for item in &items {
+        if let Some(date) = item
+            .last_read_date
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        {
+            let entry = years.entry(date.year()).or_default();
+            entry.0 += 1;
+            entry.1 += item.reading_time_sec;
+        }
+    }
+
+    ReadingAbandonedData {
+        years: years
+            .into_iter()
+            .map(|(year, (count, reading_time_sec))| AbandonedYear {
+                year,
+                items: count,
+                reading_time_sec,
+            })
+            .collect(),
+        items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::models::ContentType;
+    use crate::shelf::statistics::PageScaling;
+    use crate::source::koreader::types::{BookCompletions, PageStat, ReadCompletion, StatBook};
+    use crate::store::sqlite::repo::tests::{sample_item, test_repo};
+    use crate::tests::fixtures;
+
+    // 2024-06-10 and 2025-03-10 00:00:00 UTC
+    const JUNE_2024: i64 = 1717977600;
+    const MARCH_2025: i64 = 1741564800;
+
+    async fn repo_with(items: &[(&str, &str)]) -> LibraryRepository {
+        let repo = test_repo().await;
+        for (id, status) in items {
+            let mut row = sample_item(id);
+            row.status = status.to_string();
+            repo.upsert_item(&row).await.unwrap();
+        }
+        repo
+    }
+
+    fn reading_data(books: Vec<StatBook>, page_stats: Vec<PageStat>) -> ReadingData {
+        ReadingData {
+            stats_data: fixtures::statistics_data(books, page_stats),
+            time_config: TimeConfig::new(None, 0),
+            stats_config: Default::default(),
+            heatmap_scale_max: None,
+            page_scaling: PageScaling::disabled(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn abandoned_items_are_counted_in_the_year_last_read() {
+        let repo = repo_with(&[
+            ("dnf", "abandoned"),
+            ("unread", "abandoned"),
+            ("current", "reading"),
+        ])
+        .await;
+        let data = reading_data(
+            vec![
+                fixtures::stat_book(1, "dnf", 100, ContentType::Book),
+                fixtures::stat_book(2, "current", 100, ContentType::Book),
+            ],
+            vec![
+                fixtures::page_stat(1, 1, MARCH_2025, 600),
+                fixtures::page_stat(1, 2, MARCH_2025 + 86400, 900),
+                fixtures::page_stat(2, 1, MARCH_2025, 300),
+            ],
+        );
+
+        let result = abandoned(&data, &repo, ContentTypeFilter::All)
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = result.items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, ["dnf", "unread"]);
+        assert_eq!(result.items[0].reading_time_sec, 1500);
+        assert_eq!(
+            result.items[0].last_read_date.as_deref(),
+            Some("2025-03-11")
+        );
+        assert_eq!(result.items[1].reading_time_sec, 0);
+        assert!(result.items[1].last_read_date.is_none());
+
+        assert_eq!(result.years.len(), 1);
+        assert_eq!(result.years[0].year, 2025);
+        assert_eq!(result.years[0].items, 1);
+        assert_eq!(result.years[0].reading_time_sec, 1500);
+    }
+
+    #[tokio::test]
+    async fn items_finished_after_being_abandoned_are_left_out() {
+        let repo = repo_with(&[("finished", "abandoned"), ("reread", "abandoned")]).await;
+
+        let mut finished = fixtures::stat_book(1, "finished", 100, ContentType::Book);
+        finished.completions = Some(BookCompletions::new(vec![ReadCompletion::new(
+            "2024-06-01".into(),
+            "2024-06-10".into(),
+            600,
+            1,
+            100,
+        )]));
+        let mut reread = fixtures::stat_book(2, "reread", 100, ContentType::Book);
+        reread.completions = finished.completions.clone();
+
+        let data = reading_data(
+            vec![finished, reread],
+            vec![
+                fixtures::page_stat(1, 1, JUNE_2024, 600),
+                fixtures::page_stat(2, 1, JUNE_2024, 600),
+                fixtures::page_stat(2, 1, MARCH_2025, 300),
+            ],
+        );
+
+        let result = abandoned(&data, &repo, ContentTypeFilter::All)
+            .await
+            .unwrap();
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].id, "reread");
+        assert_eq!(result.items[0].reading_time_sec, 300);
+    }
+}
@@ -10,7 +10,7 @@ use crate::shelf::statistics::queries::{
     DateRange, PeriodGroupBy, PeriodSource, ReadingAvailablePeriodsQuery,
 };
 use crate::shelf::statistics::shared;
-use crate::shelf::time_config::TimeConfig;
+use crate::shelf::time_config::{TimeConfig, WeekStart};
 use crate::source::koreader::types::StatisticsData;
 use crate::store::memory::ReadingData;
 
@@ -31,7 +31,7 @@ pub fn available_periods(
             &reading_data.page_scaling,
         ),
         PeriodSource::Completions => {
-            completions_periods(&stats, query.group_by, query.range.as_ref())
+            completions_periods(&stats, &time_config, query.group_by, query.range.as_ref())
         }
     };
 
@@ -83,7 +83,7 @@ fn reading_data_periods(
     let mut buckets: BTreeMap<String, PeriodBucket> = BTreeMap::new();
     for stat in &page_stats {
         let date = time_config.date_for_timestamp(stat.start_time);
-        let key = period_bucket_key(date, group_by);
+        let key = period_bucket_key(date, group_by, time_config.week_start);
         let bucket = buckets.entry(key).or_insert_with(PeriodBucket::new);
         bucket.reading_time_sec += stat.duration;
         bucket.scaled_pages += page_scaling.factor_for_book_id(stat.id_book);
@@ -98,7 +98,7 @@ fn reading_data_periods(
                 && end_date >= resolved_from
                 && end_date <= resolved_to
             {
-                let key = period_bucket_key(end_date, group_by);
+                let key = period_bucket_key(end_date, group_by, time_config.week_start);
                 if let Some(bucket) = buckets.get_mut(&key) {
                     bucket.completions += 1;
                 } else {
@@ -130,6 +130,7 @@ fn reading_data_periods(
 /// Compute periods from completion data only.
 fn completions_periods(
     stats: &StatisticsData,
+    time_config: &TimeConfig,
     group_by: PeriodGroupBy,
     range: Option<&DateRange>,
 ) -> Vec<PeriodEntry> {
@@ -148,7 +149,7 @@ fn completions_periods(
             {
                 continue;
             }
-            let key = period_bucket_key(end_date, group_by);
+            let key = period_bucket_key(end_date, group_by, time_config.week_start);
             *buckets.entry(key).or_insert(0) += 1;
         }
     }
@@ -172,9 +173,9 @@ fn completions_periods(
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
 /// Map a date to its period bucket key string.
-fn period_bucket_key(date: NaiveDate, group_by: PeriodGroupBy) -> String {
+fn period_bucket_key(date: NaiveDate, group_by: PeriodGroupBy, week_start: WeekStart) -> String {
     match group_by {
-        PeriodGroupBy::Week => shared::bucket_key_week(date),
+        PeriodGroupBy::Week => shared::bucket_key_week(date, week_start),
         PeriodGroupBy::Month => shared::bucket_key_month(date),
         PeriodGroupBy::Year => shared::bucket_key_year(date),
     }
@@ -184,14 +185,11 @@ fn period_bucket_key(date: NaiveDate, group_by: PeriodGroupBy) -> String {
 fn period_date_bounds(key: &str, group_by: PeriodGroupBy) -> (String, String) {
     match group_by {
         PeriodGroupBy::Week => {
-            // Key is Monday date: YYYY-MM-DD
-            let monday =
+            // Key is the first day of the week: YYYY-MM-DD
+            let start =
                 NaiveDate::parse_from_str(key, "%Y-%m-%d").expect("valid week key expected");
-            let sunday = monday + chrono::Duration::days(6);
-            (
-                shared::bucket_key_day(monday),
-                shared::bucket_key_day(sunday),
-            )
+            let end = start + chrono::Duration::days(6);
+            (shared::bucket_key_day(start), shared::bucket_key_day(end))
         }
         PeriodGroupBy::Month => {
             // Key is YYYY-MM
@@ -226,19 +224,45 @@ mod tests {
     fn period_bucket_key_week_returns_monday() {
         // 2026-03-12 is a Thursday
         let date = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
-        assert_eq!(period_bucket_key(date, PeriodGroupBy::Week), "2026-03-09");
+        assert_eq!(
+            period_bucket_key(date, PeriodGroupBy::Week, WeekStart::Monday),
+            "2026-03-09"
+        );
+    }
+
+    #[test]
+    fn period_bucket_key_week_returns_sunday_when_configured() {
+        // 2026-03-12 is a Thursday
+        let date = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+        assert_eq!(
+            period_bucket_key(date, PeriodGroupBy::Week, WeekStart::Sunday),
+            "2026-03-08"
+        );
+    }
+
+    #[test]
+    fn period_date_bounds_week_from_sunday() {
+        let (start, end) = period_date_bounds("2026-03-08", PeriodGroupBy::Week);
+        assert_eq!(start, "2026-03-08");
+        assert_eq!(end, "2026-03-14");
     }
 
     #[test]
     fn period_bucket_key_month_returns_yyyy_mm() {
         let date = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
-        assert_eq!(period_bucket_key(date, PeriodGroupBy::Month), "2026-03");
+        assert_eq!(
+            period_bucket_key(date, PeriodGroupBy::Month, WeekStart::Monday),
+            "2026-03"
+        );
     }
 
     #[test]
     fn period_bucket_key_year_returns_yyyy() {
         let date = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
-        assert_eq!(period_bucket_key(date, PeriodGroupBy::Year), "2026");
+        assert_eq!(
+            period_bucket_key(date, PeriodGroupBy::Year, WeekStart::Monday),
+            "2026"
+        );
     }
 
     #[test]
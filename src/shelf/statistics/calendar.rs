@@ -1,10 +1,12 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+use anyhow::{Result, anyhow};
 use chrono::NaiveDate;
 
+use crate::server::api::responses::library::{LibraryContentType, LibraryDetailItem};
 use crate::server::api::responses::reading::{
-    CalendarItemRef, CalendarScopeStats, CalendarStatsByScope, ReadingCalendarData,
-    ReadingCalendarEvent,
+    CalendarDayEntry, CalendarItemRef, CalendarScopeStats, CalendarStatsByScope,
+    ReadingCalendarData, ReadingCalendarEvent,
 };
 use crate::shelf::models::ContentType;
 use crate::shelf::statistics::compute::scaling::{PageScaling, round_pages};
@@ -15,6 +17,74 @@ use crate::source::koreader::types::{PageStat, StatisticsData};
 use crate::store::memory::ReadingData;
 use crate::store::sqlite::repo::LibraryRepository;
 
+/// Number of event colors the frontend palette defines.
+const EVENT_COLOR_COUNT: u8 = 10;
+
+/// What decides the color of a book's calendar events.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CalendarColorBy {
+    /// Each book gets its own color, hashed from its MD5.
+    #[default]
+    Book,
+    /// Books and comics get one color each.
+    ContentType,
+    /// One color per star rating; unrated books share a color.
+    Rating,
+    /// Books of the same series share a color; books outside a series fall
+    /// back to their own.
+    Series,
+}
+
+impl CalendarColorBy {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "book" => Ok(Self::Book),
+            "content-type" => Ok(Self::ContentType),
+            "rating" => Ok(Self::Rating),
+            "series" => Ok(Self::Series),
+            other => Err(anyhow!(
+                "Invalid --calendar-color-by value: {}. Use book, content-type, rating or series",
+                other
+            )),
+        }
+    }
+
+    /// Palette index for a book. Only depends on the book's own data, so a
+    /// book keeps its color across rebuilds and months.
+    fn color_index(
+        self,
+        md5: &str,
+        content_type: LibraryContentType,
+        detail: Option<&LibraryDetailItem>,
+    ) -> u8 {
+        match self {
+            Self::Book => hash_color_index(md5),
+            Self::ContentType => match content_type {
+                LibraryContentType::Book => 1,
+                LibraryContentType::Comic => 4,
+            },
+            Self::Rating => match detail.and_then(|d| d.rating) {
+                Some(rating @ 1..=5) => [7, 9, 0, 8, 1][rating as usize - 1],
+                _ => 5,
+            },
+            Self::Series => match detail.and_then(|d| d.series.as_ref()) {
+                Some(series) => hash_color_index(&series.name.trim().to_lowercase()),
+                None => hash_color_index(md5),
+            },
+        }
+    }
+}
+
+/// 31-multiplier string hash over UTF-16 code units, matching the hash the
+/// frontend used before colors were assigned here, so per-book colors stay
+/// the same.
+fn hash_color_index(key: &str) -> u8 {
+    let hash = key.encode_utf16().fold(0i32, |hash, unit| {
+        hash.wrapping_mul(31).wrapping_add(i32::from(unit))
+    });
+    (i64::from(hash).abs() % i64::from(EVENT_COLOR_COUNT)) as u8
+}
+
 /// Compute the calendar response for a specific month from reading data.
 pub async fn reading_calendar(
     reading_data: &ReadingData,
@@ -35,13 +105,14 @@ pub async fn reading_calendar(
 
     // Events are filtered by the requested scope.
     let scoped_stats = shared::filter_stats_by_scope(&reading_data.stats_data, query.scope);
-    let (events, items) = build_events_and_items(
+    let (events, items, days) = build_events_and_items(
         &scoped_stats,
         &time_config,
         month_from,
         month_to,
         repo,
         &reading_data.page_scaling,
+        reading_data.calendar_color_by,
     )
     .await;
 
@@ -49,6 +120,7 @@ pub async fn reading_calendar(
         month: query.month,
         events,
         items,
+        days,
         stats_by_scope,
     }
 }
@@ -152,12 +224,15 @@ fn compute_scope_stats(
     }
 }
 
-/// Build scope-filtered events and item reference map for the month.
+/// Build scope-filtered events, the item reference map and the per-day
+/// breakdown for the month.
 ///
 /// Events are built from the entire reading history so that streaks spanning
 /// month boundaries remain intact. Only events that overlap [month_from,
 /// month_to] are included in the result — a cross-month event appears
-/// identically (with full stats) in both months.
+/// identically (with full stats) in both months. The per-day breakdown comes
+/// straight from the page stats of each day, not from span totals, and only
+/// covers days inside the month.
 async fn build_events_and_items(
     stats_data: &StatisticsData,
     time_config: &TimeConfig,
@@ -165,7 +240,12 @@ async fn build_events_and_items(
     month_to: NaiveDate,
     repo: &LibraryRepository,
     page_scaling: &PageScaling,
-) -> (Vec<ReadingCalendarEvent>, BTreeMap<String, CalendarItemRef>) {
+    color_by: CalendarColorBy,
+) -> (
+    Vec<ReadingCalendarEvent>,
+    BTreeMap<String, CalendarItemRef>,
+    BTreeMap<String, Vec<CalendarDayEntry>>,
+) {
     let book_by_id: HashMap<i64, &crate::source::koreader::types::StatBook> =
         stats_data.books.iter().map(|b| (b.id, b)).collect();
 
@@ -179,6 +259,7 @@ async fn build_events_and_items(
     }
 
     let mut all_events = Vec::new();
+    let mut days_in_month: BTreeMap<NaiveDate, Vec<CalendarDayEntry>> = BTreeMap::new();
 
     for (book_id, page_stats) in &stats_by_book {
         let stat_book = match book_by_id.get(book_id) {
@@ -196,6 +277,17 @@ async fn build_events_and_items(
             acc.scaled_pages += page_scaling.factor_for_book_id(ps.id_book);
         }
 
+        for (date, acc) in by_day.range(month_from..=month_to) {
+            days_in_month
+                .entry(*date)
+                .or_default()
+                .push(CalendarDayEntry {
+                    item_ref: item_ref_key.clone(),
+                    reading_time_sec: acc.reading_time_sec,
+                    pages_read: acc.pages_read(),
+                });
+        }
+
         let days: Vec<NaiveDate> = by_day.keys().copied().collect();
         merge_into_events(&mut all_events, &item_ref_key, &days, &by_day);
     }
@@ -211,43 +303,65 @@ async fn build_events_and_items(
             continue;
         }
         if let Some(stat_book) = stats_data.stats_by_md5.get(&ev.item_ref) {
-            let item_cover = repo
-                .get_item(&stat_book.md5)
-                .await
-                .ok()
-                .flatten()
-                .map(|detail| detail.cover_url);
+            let detail = if stat_book.removed {
+                None
+            } else {
+                repo.get_item(&stat_book.md5).await.ok().flatten()
+            };
+            let content_type = shared::to_library_content_type(stat_book.content_type);
 
             items.insert(
                 ev.item_ref.clone(),
                 CalendarItemRef {
                     title: stat_book.title.clone(),
                     authors: shared::parse_authors(&stat_book.authors),
-                    content_type: shared::to_library_content_type(stat_book.content_type),
-                    item_id: Some(stat_book.md5.clone()),
-                    item_cover,
+                    content_type,
+                    item_id: (!stat_book.removed).then(|| stat_book.md5.clone()),
+                    color_index: color_by.color_index(
+                        &stat_book.md5,
+                        content_type,
+                        detail.as_ref(),
+                    ),
+                    item_cover: detail.map(|detail| detail.cover_url),
+                    removed: stat_book.removed,
                 },
             );
         }
     }
 
-    // Sort by start date, then by title for deterministic output.
+    // Sort by start date, then by title and item for deterministic output.
     let mut events = events;
     events.sort_by(|a, b| {
-        a.start.cmp(&b.start).then_with(|| {
-            let title_a = items
-                .get(&a.item_ref)
-                .map(|i| i.title.as_str())
-                .unwrap_or("");
-            let title_b = items
-                .get(&b.item_ref)
-                .map(|i| i.title.as_str())
-                .unwrap_or("");
-            title_a.cmp(title_b)
-        })
+        a.start
+            .cmp(&b.start)
+            .then_with(|| {
+                let title_a = items
+                    .get(&a.item_ref)
+                    .map(|i| i.title.as_str())
+                    .unwrap_or("");
+                let title_b = items
+                    .get(&b.item_ref)
+                    .map(|i| i.title.as_str())
+                    .unwrap_or("");
+                title_a.cmp(title_b)
+            })
+            .then_with(|| a.item_ref.cmp(&b.item_ref))
+            .then_with(|| a.end.cmp(&b.end))
     });
 
-    (events, items)
+    let days = days_in_month
+        .into_iter()
+        .map(|(date, mut entries)| {
+            entries.sort_by(|a, b| {
+                b.reading_time_sec
+                    .cmp(&a.reading_time_sec)
+                    .then_with(|| a.item_ref.cmp(&b.item_ref))
+            });
+            (date.format("%Y-%m-%d").to_string(), entries)
+        })
+        .collect();
+
+    (events, items, days)
 }
 
 #[derive(Default)]
@@ -375,6 +489,7 @@ mod tests {
             total_read_time: None,
             total_read_pages: None,
             completions: None,
+            removed: false,
         }
     }
 
@@ -391,8 +506,11 @@ mod tests {
         ReadingData {
             stats_data: stats,
             time_config: TimeConfig::new(None, 0),
+            stats_config: Default::default(),
             heatmap_scale_max: None,
             page_scaling: PageScaling::disabled(),
+            calendar_color_by: CalendarColorBy::default(),
+            recap_show_empty_months: false,
         }
     }
 
@@ -475,6 +593,28 @@ mod tests {
         assert_eq!(result.events[0].pages_read, 2);
     }
 
+    #[tokio::test]
+    async fn day_start_moves_early_morning_reading_to_previous_day() {
+        let repo = crate::store::sqlite::repo::tests::test_repo().await;
+        let book = make_book(1, "Test Book", "abc123", Some(ContentType::Book));
+        // 2026-03-10 02:00 UTC, before the 04:00 day start.
+        let ps1 = make_page_stat(1, 1773100800 + 2 * 3600, 200);
+        // 2026-03-10 05:00 UTC, after the day start.
+        let ps2 = make_page_stat(1, 1773100800 + 5 * 3600, 300);
+        let mut reading_data = make_reading_data(make_stats_data(vec![book], vec![ps1, ps2]));
+        reading_data.time_config = TimeConfig::new(Some(chrono_tz::UTC), 240);
+        let query = ReadingCalendarQuery {
+            month: "2026-03".to_string(),
+            scope: ContentTypeFilter::All,
+            tz: None,
+        };
+        let result = reading_calendar(&reading_data, &repo, query).await;
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].start, "2026-03-09");
+        assert_eq!(result.events[0].end, Some("2026-03-11".to_string()));
+        assert_eq!(result.events[0].reading_time_sec, 500);
+    }
+
     #[tokio::test]
     async fn gap_creates_separate_events() {
         let repo = crate::store::sqlite::repo::tests::test_repo().await;
@@ -569,6 +709,11 @@ mod tests {
         assert_eq!(result_mar.events[0].reading_time_sec, 500);
         assert_eq!(result_mar.events[0].pages_read, 2);
 
+        // The day breakdown only covers March and uses that day's own reading.
+        assert_eq!(result_mar.days.len(), 1);
+        assert_eq!(result_mar.days["2026-03-01"][0].reading_time_sec, 300);
+        assert_eq!(result_mar.days["2026-03-01"][0].pages_read, 1);
+
         // Query February: the same cross-month event should appear identically.
         let query_feb = ReadingCalendarQuery {
             month: "2026-02".to_string(),
@@ -581,5 +726,87 @@ mod tests {
         assert_eq!(result_feb.events[0].end, Some("2026-03-02".to_string()));
         assert_eq!(result_feb.events[0].reading_time_sec, 500);
         assert_eq!(result_feb.events[0].pages_read, 2);
+        assert_eq!(result_feb.days.len(), 1);
+        assert_eq!(result_feb.days["2026-02-28"][0].reading_time_sec, 200);
+    }
+
+    #[test]
+    fn book_colors_match_the_previous_frontend_hash() {
+        // JS: "abc" hashes to 96354.
+        assert_eq!(hash_color_index("abc"), 4);
+        assert_eq!(
+            CalendarColorBy::parse(" Content-Type ").unwrap(),
+            CalendarColorBy::ContentType
+        );
+        assert!(CalendarColorBy::parse("title").is_err());
+    }
+
+    #[tokio::test]
+    async fn items_are_colored_by_content_type() {
+        let repo = crate::store::sqlite::repo::tests::test_repo().await;
+        let books = vec![
+            make_book(1, "Book One", "md5a", Some(ContentType::Book)),
+            make_book(2, "Book Two", "md5b", Some(ContentType::Book)),
+            make_book(3, "Comic", "md5c", Some(ContentType::Comic)),
+        ];
+        let stats = (1..=3)
+            .map(|id| make_page_stat(id, 1772409600, 300))
+            .collect();
+        let mut reading_data = make_reading_data(make_stats_data(books, stats));
+        let query = || ReadingCalendarQuery {
+            month: "2026-03".to_string(),
+            scope: ContentTypeFilter::All,
+            tz: None,
+        };
+
+        let by_book = reading_calendar(&reading_data, &repo, query()).await;
+        assert_eq!(by_book.items["md5a"].color_index, hash_color_index("md5a"));
+
+        reading_data.calendar_color_by = CalendarColorBy::ContentType;
+        let result = reading_calendar(&reading_data, &repo, query()).await;
+        assert_eq!(
+            result.items["md5a"].color_index,
+            result.items["md5b"].color_index
+        );
+        assert_ne!(
+            result.items["md5a"].color_index,
+            result.items["md5c"].color_index
+        );
+    }
+
+    #[tokio::test]
+    async fn day_breakdown_lists_each_book_most_read_first() {
+        let repo = crate::store::sqlite::repo::tests::test_repo().await;
+        let books = vec![
+            make_book(1, "Book A", "md5a", Some(ContentType::Book)),
+            make_book(2, "Book B", "md5b", Some(ContentType::Book)),
+        ];
+        // 2026-03-07 00:00:00 UTC = 1772841600
+        let stats = vec![
+            make_page_stat(1, 1772841600, 600),
+            make_page_stat(2, 1772841600 + 700, 1200),
+            make_page_stat(1, 1772841600 + 2000, 600),
+            make_page_stat(2, 1772841600 + 86400, 300),
+        ];
+        let reading_data = make_reading_data(make_stats_data(books, stats));
+        let query = ReadingCalendarQuery {
+            month: "2026-03".to_string(),
+            scope: ContentTypeFilter::All,
+            tz: None,
+        };
+        let result = reading_calendar(&reading_data, &repo, query).await;
+
+        let day = &result.days["2026-03-07"];
+        assert_eq!(day.len(), 2);
+        assert_eq!(day[0].item_ref, "md5a");
+        assert_eq!(day[0].reading_time_sec, 1200);
+        assert_eq!(day[0].pages_read, 2);
+        assert_eq!(day[1].item_ref, "md5b");
+        assert_eq!(day[1].reading_time_sec, 1200);
+
+        // Book B's two-day span contributes only the second day's reading.
+        let next_day = &result.days["2026-03-08"];
+        assert_eq!(next_day.len(), 1);
+        assert_eq!(next_day[0].reading_time_sec, 300);
     }
 }
@@ -1,15 +1,18 @@
 //! Reading completions: query-layer response building.
 
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
-use chrono::NaiveDate;
+use chrono::{Datelike, Months, NaiveDate};
 
 use crate::server::api::responses::reading::{
-    CompletionGroup, CompletionItem, CompletionsShareAssets, CompletionsSummary,
-    ReadingCompletionsData,
+    CompletionGroup, CompletionItem, CompletionSuperlatives, CompletionsShareAssets,
+    CompletionsSummary, CompletionsTopItems, RatingBucket, ReadingCompletionsData,
 };
+use crate::shelf::statistics::StatisticsConfig;
 use crate::shelf::statistics::compute::scaling::PageScaling;
 use crate::shelf::statistics::compute::sessions;
+use crate::shelf::statistics::compute::streaks;
 use crate::shelf::statistics::queries::{
     CompletionsGroupBy, CompletionsSelector, ReadingCompletionsQuery,
 };
@@ -36,27 +39,44 @@ pub async fn reading_completions(
     all_items.sort_by(|a, b| b.end_date.cmp(&a.end_date));
 
     let total_items = all_items.len();
+    let start_dates = collect_start_dates(&stats, &time_config, range.as_ref());
 
     // Must be computed before items are moved into groups.
     let summary = if query.includes.has_summary() {
         Some(compute_completions_summary(
             &stats,
             &time_config,
+            &reading_data.stats_config,
             range.as_ref(),
             total_items,
+            start_dates.len(),
         ))
     } else {
         None
     };
 
+    let top_items = if query.includes.has_top_items() {
+        Some(compute_top_items(&all_items))
+    } else {
+        None
+    };
+
     let (groups, items) = match query.group_by {
         CompletionsGroupBy::Month => {
             let month_reading_time =
                 compute_month_reading_times(&stats, &time_config, range.as_ref());
-            (
-                Some(build_month_groups(all_items, &month_reading_time)),
-                None,
-            )
+            let month_started = count_starts_by_month(&start_dates);
+            let mut groups = build_month_groups(all_items, &month_reading_time, &month_started);
+            if reading_data.recap_show_empty_months {
+                fill_empty_months(
+                    &mut groups,
+                    &month_reading_time,
+                    &month_started,
+                    range.as_ref(),
+                    time_config.today_date(),
+                );
+            }
+            (Some(groups), None)
         }
         CompletionsGroupBy::None => (None, Some(all_items)),
     };
@@ -76,6 +96,7 @@ pub async fn reading_completions(
         items,
         summary,
         share_assets,
+        top_items,
     }
 }
 
@@ -127,6 +148,10 @@ fn collect_completion_items(
             }
 
             let pages_read = page_scaling.scale_pages_for_md5(&book.md5, entry.pages_read);
+            let total_pages = book
+                .pages
+                .filter(|&pages| pages > 0)
+                .map(|pages| page_scaling.scale_pages_for_md5(&book.md5, pages));
             let average_speed = if entry.reading_time > 0 && pages_read > 0 {
                 Some(pages_read as f64 / (entry.reading_time as f64 / 3600.0))
             } else {
@@ -141,15 +166,17 @@ fn collect_completion_items(
                 reading_time_sec: entry.reading_time,
                 session_count: entry.session_count,
                 pages_read,
+                total_pages,
                 calendar_length_days: entry.calendar_length_days(),
                 average_speed,
                 average_session_duration_sec: entry.avg_session_duration(),
                 rating: None,
                 review_note: None,
                 series: None,
-                item_id: Some(book.md5.clone()),
+                item_id: (!book.removed).then(|| book.md5.clone()),
                 item_cover: None,
                 content_type: Some(shared::to_library_content_type(book.content_type)),
+                removed: book.removed,
             });
         }
     }
@@ -236,12 +263,76 @@ fn compute_month_reading_times(
     month_times
 }
 
+/// Dates on which reads were started, optionally filtered to `[from, to]`.
+///
+/// Every completion contributes its start date. Reading after a book's last
+/// completion (or any reading of a never-completed book) contributes the date of
+/// its first session, so a read begun in one month and finished in another is
+/// started once, in the month it began.
+fn collect_start_dates(
+    stats: &StatisticsData,
+    time_config: &TimeConfig,
+    range: Option<&(NaiveDate, NaiveDate)>,
+) -> Vec<NaiveDate> {
+    let mut dates = Vec::new();
+    let mut last_end_by_book: HashMap<i64, Option<NaiveDate>> = HashMap::new();
+
+    for book in &stats.books {
+        let mut last_end = None;
+        if let Some(ref completions) = book.completions {
+            for entry in &completions.entries {
+                if let Ok(start) = NaiveDate::parse_from_str(&entry.start_date, "%Y-%m-%d") {
+                    dates.push(start);
+                }
+                if let Ok(end) = NaiveDate::parse_from_str(&entry.end_date, "%Y-%m-%d") {
+                    last_end = last_end.max(Some(end));
+                }
+            }
+        }
+        last_end_by_book.insert(book.id, last_end);
+    }
+
+    let mut open_read_starts: HashMap<i64, NaiveDate> = HashMap::new();
+    for ps in &stats.page_stats {
+        if ps.duration <= 0 {
+            continue;
+        }
+        let Some(last_end) = last_end_by_book.get(&ps.id_book) else {
+            continue;
+        };
+        let date = time_config.date_for_timestamp(ps.start_time);
+        if last_end.is_some_and(|end| date <= end) {
+            continue;
+        }
+        open_read_starts
+            .entry(ps.id_book)
+            .and_modify(|first| *first = (*first).min(date))
+            .or_insert(date);
+    }
+    dates.extend(open_read_starts.into_values());
+
+    if let Some((from, to)) = range {
+        dates.retain(|date| date >= from && date <= to);
+    }
+    dates
+}
+
+/// Count start dates per month key (`YYYY-MM`).
+fn count_starts_by_month(start_dates: &[NaiveDate]) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for date in start_dates {
+        *counts.entry(shared::bucket_key_month(*date)).or_insert(0) += 1;
+    }
+    counts
+}
+
 /// Group completion items by their end-date month key (`YYYY-MM`).
 /// `month_reading_times` provides total reading time per month from all reading
-/// activity (not just completed items)
+/// activity (not just completed items), `month_started` the reads begun per month.
 fn build_month_groups(
     items: Vec<CompletionItem>,
     month_reading_times: &HashMap<String, i64>,
+    month_started: &HashMap<String, usize>,
 ) -> Vec<CompletionGroup> {
     let mut groups: BTreeMap<String, Vec<CompletionItem>> = BTreeMap::new();
 
@@ -256,9 +347,11 @@ fn build_month_groups(
         .map(|(key, group_items)| {
             let items_finished = group_items.len();
             let reading_time_sec = *month_reading_times.get(&key).unwrap_or(&0);
+            let items_started = *month_started.get(&key).unwrap_or(&0);
             CompletionGroup {
                 key,
                 items_finished,
+                items_started,
                 reading_time_sec,
                 items: group_items,
             }
@@ -266,6 +359,61 @@ fn build_month_groups(
         .collect()
 }
 
+/// Insert a group for every month in the span that finished nothing, so idle
+/// months still show up in the recap with the time read in them.
+///
+/// The span is the query range, or the months with any activity when no range
+/// is given, and never extends past the current month. Nothing is filled when
+/// there was no activity at all.
+fn fill_empty_months(
+    groups: &mut Vec<CompletionGroup>,
+    month_reading_times: &HashMap<String, i64>,
+    month_started: &HashMap<String, usize>,
+    range: Option<&(NaiveDate, NaiveDate)>,
+    today: NaiveDate,
+) {
+    let active_keys = groups
+        .iter()
+        .map(|group| group.key.as_str())
+        .chain(month_reading_times.keys().map(String::as_str));
+    let (Some(first_key), Some(last_key)) = (active_keys.clone().min(), active_keys.max()) else {
+        return;
+    };
+
+    let (from, to) = match range {
+        Some((from, to)) => (*from, *to),
+        None => {
+            let parse = |key: &str| NaiveDate::parse_from_str(&format!("{key}-01"), "%Y-%m-%d");
+            let (Ok(from), Ok(to)) = (parse(first_key), parse(last_key)) else {
+                return;
+            };
+            (from, to)
+        }
+    };
+    let to = to.min(today);
+
+    let existing: HashSet<String> = groups.iter().map(|group| group.key.clone()).collect();
+    let mut month = from.with_day(1).unwrap_or(from);
+    while month <= to {
+        let key = shared::bucket_key_month(month);
+        if !existing.contains(&key) {
+            groups.push(CompletionGroup {
+                reading_time_sec: *month_reading_times.get(&key).unwrap_or(&0),
+                items_started: *month_started.get(&key).unwrap_or(&0),
+                key,
+                items_finished: 0,
+                items: Vec::new(),
+            });
+        }
+        let Some(next) = month.checked_add_months(Months::new(1)) else {
+            break;
+        };
+        month = next;
+    }
+
+    groups.sort_by(|a, b| a.key.cmp(&b.key));
+}
+
 // ── Summary computation ─────────────────────────────────────────────────────
 
 /// Compute the optional recap summary from reading activity, optionally within a range.
@@ -273,8 +421,10 @@ fn build_month_groups(
 fn compute_completions_summary(
     stats: &StatisticsData,
     time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
     range: Option<&(NaiveDate, NaiveDate)>,
     total_items: usize,
+    total_started: usize,
 ) -> CompletionsSummary {
     let range_page_stats: Vec<PageStat> = stats
         .page_stats
@@ -295,7 +445,8 @@ fn compute_completions_summary(
 
     let total_reading_time_sec: i64 = range_page_stats.iter().map(|ps| ps.duration).sum();
 
-    let (avg_session, longest_session) = sessions::session_metrics(&range_page_stats);
+    let (avg_session, longest_session) =
+        sessions::session_metrics(&range_page_stats, stats_config.session_gap_sec);
     let longest_session_duration_sec = longest_session.unwrap_or(0);
     let average_session_duration_sec = avg_session.unwrap_or(0);
 
@@ -319,11 +470,12 @@ fn compute_completions_summary(
         0
     };
 
-    let longest_streak_days = compute_longest_streak(&active_dates);
+    let longest_streak_days = compute_longest_streak(&active_dates, stats_config.streak_grace_days);
     let best_month = compute_best_month(&range_page_stats, time_config);
 
     CompletionsSummary {
         total_items,
+        total_started,
         total_reading_time_sec,
         longest_session_duration_sec,
         average_session_duration_sec,
@@ -334,27 +486,15 @@ fn compute_completions_summary(
     }
 }
 
-fn compute_longest_streak(active_dates: &HashSet<NaiveDate>) -> i64 {
-    if active_dates.is_empty() {
-        return 0;
-    }
-
+fn compute_longest_streak(active_dates: &HashSet<NaiveDate>, grace_days: u16) -> i64 {
     let mut sorted: Vec<NaiveDate> = active_dates.iter().copied().collect();
     sorted.sort();
 
-    let mut max_streak = 1i64;
-    let mut current_streak = 1i64;
-
-    for i in 1..sorted.len() {
-        if sorted[i] == sorted[i - 1] + chrono::Duration::days(1) {
-            current_streak += 1;
-            max_streak = max_streak.max(current_streak);
-        } else {
-            current_streak = 1;
-        }
-    }
-
-    max_streak
+    streaks::streak_runs(&sorted, grace_days)
+        .iter()
+        .map(|&(days, _, _)| days)
+        .max()
+        .unwrap_or(0)
 }
 
 fn compute_best_month(page_stats: &[PageStat], time_config: &TimeConfig) -> Option<String> {
@@ -369,13 +509,130 @@ fn compute_best_month(page_stats: &[PageStat], time_config: &TimeConfig) -> Opti
         *month_times.entry(key).or_insert(0) += ps.duration;
     }
 
+    // Earliest month wins ties so the result does not depend on map order.
     month_times
         .into_iter()
-        .max_by_key(|(_, seconds)| *seconds)
+        .max_by(|(a_key, a_secs), (b_key, b_secs)| a_secs.cmp(b_secs).then(b_key.cmp(a_key)))
         .filter(|(_, seconds)| *seconds > 0)
         .map(|(key, _)| key)
 }
 
+// ── Top items ───────────────────────────────────────────────────────────────
+
+/// Maximum number of entries in each top-items list.
+const TOP_ITEMS_LIMIT: usize = 5;
+
+/// Build the highest-rated and longest lists plus the rating distribution.
+///
+/// Items completed more than once appear only once per list. Ties are broken
+/// by end date (newest first), then title, so the output is stable.
+fn compute_top_items(items: &[CompletionItem]) -> CompletionsTopItems {
+    let rated: Vec<&CompletionItem> = items
+        .iter()
+        .filter(|item| item.rating.is_some_and(|r| (1..=5).contains(&r)))
+        .collect();
+
+    let highest_rated = rank_unique(rated.iter().copied(), |a, b| {
+        b.rating
+            .cmp(&a.rating)
+            .then_with(|| compare_recency_then_title(a, b))
+    });
+
+    let longest = rank_unique(items.iter(), |a, b| {
+        b.reading_time_sec
+            .cmp(&a.reading_time_sec)
+            .then_with(|| compare_recency_then_title(a, b))
+    });
+
+    let rating_distribution = if rated.is_empty() {
+        None
+    } else {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut counts = [0usize; 5];
+        for item in &rated {
+            if seen.insert(item_identity(item))
+                && let Some(rating) = item.rating
+            {
+                counts[(rating - 1) as usize] += 1;
+            }
+        }
+        Some(
+            (1..=5)
+                .map(|rating| RatingBucket {
+                    rating,
+                    count: counts[(rating - 1) as usize],
+                })
+                .collect(),
+        )
+    };
+
+    CompletionsTopItems {
+        highest_rated,
+        longest,
+        rating_distribution,
+        superlatives: compute_superlatives(items),
+    }
+}
+
+/// Pick the longest and shortest completions by page count and reading time.
+///
+/// Ties are broken by title, then end date, so the pick does not depend on
+/// input order. Items without a page count are only eligible by time.
+fn compute_superlatives(items: &[CompletionItem]) -> CompletionSuperlatives {
+    let by_title = |a: &&CompletionItem, b: &&CompletionItem| {
+        a.title
+            .cmp(&b.title)
+            .then_with(|| a.end_date.cmp(&b.end_date))
+    };
+    let pick = |key: fn(&CompletionItem) -> Option<i64>, longest: bool| {
+        items
+            .iter()
+            .filter_map(|item| key(item).map(|value| (value, item)))
+            .min_by(|(va, a), (vb, b)| {
+                let by_value = if longest { vb.cmp(va) } else { va.cmp(vb) };
+                by_value.then_with(|| by_title(a, b))
+            })
+            .map(|(_, item)| item.clone())
+    };
+    let pages = |item: &CompletionItem| item.total_pages;
+    let time = |item: &CompletionItem| Some(item.reading_time_sec).filter(|&sec| sec > 0);
+
+    CompletionSuperlatives {
+        longest_by_pages: pick(pages, true),
+        shortest_by_pages: pick(pages, false),
+        longest_by_time: pick(time, true),
+        shortest_by_time: pick(time, false),
+    }
+}
+
+/// Sort `items` with `compare`, keep the first entry per item, and truncate.
+fn rank_unique<'a>(
+    items: impl Iterator<Item = &'a CompletionItem>,
+    compare: impl Fn(&CompletionItem, &CompletionItem) -> Ordering,
+) -> Vec<CompletionItem> {
+    let mut sorted: Vec<&CompletionItem> = items.collect();
+    sorted.sort_by(|a, b| compare(a, b));
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    sorted
+        .into_iter()
+        .filter(|item| seen.insert(item_identity(item)))
+        .take(TOP_ITEMS_LIMIT)
+        .cloned()
+        .collect()
+}
+
+fn compare_recency_then_title(a: &CompletionItem, b: &CompletionItem) -> Ordering {
+    b.end_date
+        .cmp(&a.end_date)
+        .then_with(|| a.title.cmp(&b.title))
+}
+
+/// Items without a library ID fall back to their title for deduplication.
+fn item_identity(item: &CompletionItem) -> &str {
+    item.item_id.as_deref().unwrap_or(&item.title)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,6 +666,7 @@ mod tests {
             total_read_time: None,
             total_read_pages: None,
             completions: None,
+            removed: false,
         }
     }
 
@@ -435,8 +693,11 @@ mod tests {
         ReadingData {
             stats_data: stats,
             time_config: TimeConfig::new(None, 0),
+            stats_config: Default::default(),
             heatmap_scale_max: None,
             page_scaling: PageScaling::disabled(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
         }
     }
 
@@ -484,6 +745,89 @@ mod tests {
         assert_eq!(groups[1].items_finished, 1);
     }
 
+    #[tokio::test]
+    async fn empty_months_are_filled_up_to_today_when_enabled() {
+        let repo = crate::store::sqlite::repo::tests::test_repo().await;
+        let mut book = make_book(1, "Test Book", "abc123");
+        book.completions = Some(BookCompletions::new(vec![make_completion(
+            "2025-01-01",
+            "2025-02-15",
+            3600,
+            10,
+            200,
+        )]));
+
+        // 2025-03-10 00:00:00 UTC: reading in a month without completions.
+        let ps = make_page_stat(1, 1741564800, 1800);
+        let mut reading_data = make_reading_data(make_stats_data(vec![book], vec![ps]));
+        reading_data.recap_show_empty_months = true;
+        // 2025-05-20 00:00:00 UTC
+        reading_data.time_config = TimeConfig::new(None, 0).with_fixed_now(Some(1747699200));
+
+        let query = ReadingCompletionsQuery {
+            scope: ContentTypeFilter::All,
+            selector: CompletionsSelector::Year(2025),
+            group_by: CompletionsGroupBy::Month,
+            includes: CompletionsIncludeSet::default(),
+            tz: None,
+        };
+        let result = reading_completions(&reading_data, &repo, query).await;
+        let groups = result.groups.unwrap();
+        let keys: Vec<&str> = groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["2025-01", "2025-02", "2025-03", "2025-04", "2025-05"]
+        );
+        assert_eq!(groups[1].items_finished, 1);
+        assert_eq!(groups[2].items_finished, 0);
+        assert!(groups[2].items.is_empty());
+        assert_eq!(groups[2].reading_time_sec, 1800);
+        assert_eq!(groups[3].reading_time_sec, 0);
+    }
+
+    #[tokio::test]
+    async fn reads_count_as_started_once_in_the_month_they_began() {
+        let repo = crate::store::sqlite::repo::tests::test_repo().await;
+        let mut finished = make_book(1, "Finished Book", "abc123");
+        finished.completions = Some(BookCompletions::new(vec![make_completion(
+            "2024-12-20",
+            "2025-01-10",
+            3600,
+            10,
+            200,
+        )]));
+        let in_progress = make_book(2, "Open Book", "def456");
+
+        let page_stats = vec![
+            // 2025-01-05 00:00:00 UTC: part of the completed read.
+            make_page_stat(1, 1736035200, 600),
+            // 2025-03-10 and 2025-04-02 00:00:00 UTC: an unfinished read.
+            make_page_stat(2, 1741564800, 600),
+            make_page_stat(2, 1743552000, 600),
+        ];
+        let reading_data =
+            make_reading_data(make_stats_data(vec![finished, in_progress], page_stats));
+
+        let query_for = |year| ReadingCompletionsQuery {
+            scope: ContentTypeFilter::All,
+            selector: CompletionsSelector::Year(year),
+            group_by: CompletionsGroupBy::Month,
+            includes: CompletionsIncludeSet::parse(Some("summary")).unwrap(),
+            tz: None,
+        };
+
+        let result = reading_completions(&reading_data, &repo, query_for(2025)).await;
+        assert_eq!(result.summary.unwrap().total_started, 1);
+        let groups = result.groups.unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].key, "2025-01");
+        assert_eq!(groups[0].items_finished, 1);
+        assert_eq!(groups[0].items_started, 0);
+
+        let result = reading_completions(&reading_data, &repo, query_for(2024)).await;
+        assert_eq!(result.summary.unwrap().total_started, 1);
+    }
+
     #[tokio::test]
     async fn completions_flat_list_when_group_by_none() {
         let repo = crate::store::sqlite::repo::tests::test_repo().await;
@@ -639,11 +983,127 @@ mod tests {
         dates.insert(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap());
         dates.insert(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap());
 
-        assert_eq!(compute_longest_streak(&dates), 3);
+        assert_eq!(compute_longest_streak(&dates, 0), 3);
+        // One grace day bridges the gap on 2025-01-04.
+        assert_eq!(compute_longest_streak(&dates, 1), 6);
+    }
+
+    #[test]
+    fn daily_streak_has_no_gap_across_dst_transitions() {
+        let timezone = "America/New_York".parse().expect("timezone should parse");
+        let time_config = TimeConfig::new(Some(timezone), 240);
+
+        // Reading at 04:30 local every day around spring-forward (2024-03-10)
+        // and fall-back (2024-11-03), just after the 04:00 day start.
+        for timestamps in [
+            [1709890200, 1709976600, 1710059400, 1710145800, 1710232200],
+            [1730449800, 1730536200, 1730626200, 1730712600, 1730799000],
+        ] {
+            let dates: HashSet<NaiveDate> = timestamps
+                .iter()
+                .map(|&ts| time_config.date_for_timestamp(ts))
+                .collect();
+            assert_eq!(dates.len(), 5);
+            assert_eq!(compute_longest_streak(&dates, 0), 5);
+        }
     }
 
     #[test]
     fn longest_streak_empty_returns_zero() {
-        assert_eq!(compute_longest_streak(&HashSet::new()), 0);
+        assert_eq!(compute_longest_streak(&HashSet::new(), 0), 0);
+    }
+
+    fn make_item(id: &str, end: &str, time: i64, rating: Option<i32>) -> CompletionItem {
+        CompletionItem {
+            title: format!("Book {id}"),
+            authors: vec![],
+            start_date: "2025-01-01".to_string(),
+            end_date: end.to_string(),
+            reading_time_sec: time,
+            session_count: 1,
+            pages_read: 100,
+            total_pages: None,
+            calendar_length_days: None,
+            average_speed: None,
+            average_session_duration_sec: None,
+            rating,
+            review_note: None,
+            series: None,
+            item_id: Some(id.to_string()),
+            item_cover: None,
+            content_type: None,
+            removed: false,
+        }
+    }
+
+    #[test]
+    fn top_items_rank_by_rating_and_reading_time() {
+        let items = vec![
+            make_item("a", "2025-03-01", 3600, Some(3)),
+            make_item("b", "2025-02-01", 9000, Some(5)),
+            make_item("c", "2025-01-01", 1200, None),
+            make_item("d", "2025-04-01", 500, Some(4)),
+        ];
+        let top = compute_top_items(&items);
+
+        let rated: Vec<_> = top.highest_rated.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(rated, vec!["Book b", "Book d", "Book a"]);
+        let longest: Vec<_> = top.longest.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(longest, vec!["Book b", "Book a", "Book c", "Book d"]);
+
+        let distribution = top.rating_distribution.unwrap();
+        let counts: Vec<_> = distribution.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![0, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn top_items_ties_are_ordered_by_recency_then_title() {
+        let items = vec![
+            make_item("b", "2025-05-01", 3600, Some(4)),
+            make_item("a", "2025-05-01", 3600, Some(4)),
+            make_item("c", "2025-06-01", 3600, Some(4)),
+        ];
+        let top = compute_top_items(&items);
+
+        let rated: Vec<_> = top.highest_rated.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(rated, vec!["Book c", "Book a", "Book b"]);
+        let longest: Vec<_> = top.longest.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(longest, vec!["Book c", "Book a", "Book b"]);
+    }
+
+    #[test]
+    fn top_items_deduplicate_rereads_and_omit_empty_distribution() {
+        let items = vec![
+            make_item("a", "2025-09-01", 1800, None),
+            make_item("a", "2025-02-01", 7200, None),
+            make_item("b", "2025-03-01", 3600, None),
+        ];
+        let top = compute_top_items(&items);
+
+        assert!(top.highest_rated.is_empty());
+        assert!(top.rating_distribution.is_none());
+        assert_eq!(top.longest.len(), 2);
+        assert_eq!(top.longest[0].reading_time_sec, 7200);
+    }
+
+    #[test]
+    fn superlatives_skip_missing_page_counts_and_break_ties_by_title() {
+        let mut items = vec![
+            make_item("c", "2025-01-01", 3600, None),
+            make_item("b", "2025-02-01", 900, None),
+            make_item("a", "2025-03-01", 900, None),
+            make_item("d", "2025-04-01", 9000, None),
+        ];
+        items[0].total_pages = Some(400);
+        items[1].total_pages = Some(120);
+        items[2].total_pages = Some(400);
+
+        let superlatives = compute_top_items(&items).superlatives;
+        let title = |item: Option<CompletionItem>| item.map(|i| i.title);
+
+        assert_eq!(title(superlatives.longest_by_pages), Some("Book a".into()));
+        assert_eq!(title(superlatives.shortest_by_pages), Some("Book b".into()));
+        assert_eq!(title(superlatives.longest_by_time), Some("Book d".into()));
+        assert_eq!(title(superlatives.shortest_by_time), Some("Book a".into()));
     }
 }
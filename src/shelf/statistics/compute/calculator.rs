@@ -1,15 +1,18 @@
-use chrono::{Datelike, Duration, NaiveDate};
-use log::debug;
+use chrono::{Duration, NaiveDate};
+use log::{debug, info};
 use std::collections::{HashMap, HashSet};
 
+use crate::shelf::statistics::StatisticsConfig;
 use crate::shelf::statistics::compute::completion_detection::{
     CompletionConfig, ReadCompletionDetector,
 };
+use crate::shelf::statistics::compute::completion_overrides::CompletionOverrides;
 use crate::shelf::statistics::compute::sessions;
+use crate::shelf::statistics::compute::streaks;
 use crate::shelf::time_config::TimeConfig;
 use crate::source::koreader::types::{
-    BookSessionStats, DailyStats, PageStat, ReadingStats, StatBook, StatisticsData, StreakInfo,
-    WeeklyStats,
+    BookCompletions, BookSessionStats, DailyStats, PageStat, ReadCompletion, ReadingStats,
+    StatBook, StatisticsData, StreakInfo, WeeklyStats,
 };
 
 /// Trait for calculating book session statistics
@@ -18,6 +21,7 @@ pub trait BookStatistics {
         &self,
         page_stats: &[PageStat],
         time_config: &TimeConfig,
+        stats_config: &StatisticsConfig,
     ) -> BookSessionStats;
 }
 
@@ -27,6 +31,7 @@ impl BookStatistics for StatBook {
         &self,
         page_stats: &[PageStat],
         time_config: &TimeConfig,
+        stats_config: &StatisticsConfig,
     ) -> BookSessionStats {
         let book_sessions: Vec<&PageStat> = page_stats
             .iter()
@@ -34,7 +39,7 @@ impl BookStatistics for StatBook {
             .collect();
 
         let book_stats: Vec<PageStat> = book_sessions.iter().cloned().cloned().collect();
-        let durations = sessions::session_durations(&book_stats);
+        let durations = sessions::session_durations(&book_stats, stats_config.session_gap_sec);
         let session_count = durations.len() as i64;
         let longest_session_duration = durations.iter().max().copied();
         let average_session_duration = if !durations.is_empty() {
@@ -64,16 +69,37 @@ impl BookStatistics for StatBook {
             None
         };
 
+        // `total_read_pages` counts distinct pages, so re-read pages are not
+        // counted twice here.
+        let reading_days: HashSet<NaiveDate> = book_sessions
+            .iter()
+            .map(|s| time_config.date_for_timestamp(s.start_time))
+            .collect();
+        let pages_per_day = self
+            .total_read_pages
+            .filter(|&pages| pages > 0 && !reading_days.is_empty())
+            .map(|pages| pages as f64 / reading_days.len() as f64);
+
         BookSessionStats {
             session_count,
             average_session_duration,
             longest_session_duration,
             last_read_date,
             reading_speed,
+            pages_per_day,
         }
     }
 }
 
+/// Upper limit on the duration of a single page read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationCap {
+    /// Count longer page reads as this many seconds.
+    Clamp(u32),
+    /// Leave out page reads longer than this many seconds.
+    Drop(u32),
+}
+
 /// Computes aggregate reading statistics, streaks, and completions from raw page stats.
 ///
 /// Provides filtering (`filter_stats`, `filter_to_library`) and completion
@@ -84,13 +110,17 @@ pub struct StatisticsCalculator;
 
 impl StatisticsCalculator {
     /// Calculate reading statistics based on the parsed data and populate completions
-    pub fn calculate_stats(stats_data: &StatisticsData, time_config: &TimeConfig) -> ReadingStats {
+    pub fn calculate_stats(
+        stats_data: &StatisticsData,
+        time_config: &TimeConfig,
+        stats_config: &StatisticsConfig,
+    ) -> ReadingStats {
         let mut total_read_time = 0;
         let mut total_page_reads = 0;
         let mut daily_read_time: HashMap<String, i64> = HashMap::new();
         let mut daily_page_reads: HashMap<String, i64> = HashMap::new();
-        // (year, week) -> (read_time, pages_read, page_stats)
-        let mut weekly_stats: HashMap<(i32, u32), (i64, i64, Vec<PageStat>)> = HashMap::new();
+        // week start date -> (read_time, pages_read, page_stats)
+        let mut weekly_stats: HashMap<NaiveDate, (i64, i64, Vec<PageStat>)> = HashMap::new();
 
         for stat in &stats_data.page_stats {
             if stat.duration <= 0 {
@@ -106,10 +136,8 @@ impl StatisticsCalculator {
             *daily_read_time.entry(date_str.clone()).or_insert(0) += stat.duration;
             *daily_page_reads.entry(date_str).or_insert(0) += 1;
 
-            let year = date.year();
-            let week = date.iso_week().week();
-            let key = (year, week);
-            let entry = weekly_stats.entry(key).or_insert((0, 0, Vec::new()));
+            let week_start = time_config.week_start.week_start_date(date);
+            let entry = weekly_stats.entry(week_start).or_insert((0, 0, Vec::new()));
             entry.0 += stat.duration;
             entry.1 += 1;
             entry.2.push(stat.clone());
@@ -119,15 +147,15 @@ impl StatisticsCalculator {
         let most_pages_in_day = daily_page_reads.values().cloned().max().unwrap_or(0);
 
         let (average_session_duration, longest_session_duration) =
-            sessions::session_metrics(&stats_data.page_stats);
+            sessions::session_metrics(&stats_data.page_stats, stats_config.session_gap_sec);
 
         let (total_completions, books_completed, most_completions) =
             Self::calculate_completion_stats(stats_data);
 
-        let weeks = Self::build_weekly_stats(weekly_stats);
+        let weeks = Self::build_weekly_stats(weekly_stats, stats_config.session_gap_sec);
         let daily_activity = Self::build_daily_activity(daily_read_time, daily_page_reads);
         let (longest_streak, current_streak) =
-            Self::calculate_streaks(&daily_activity, time_config);
+            Self::calculate_streaks(&daily_activity, time_config, stats_config.streak_grace_days);
 
         ReadingStats {
             total_read_time,
@@ -146,25 +174,20 @@ impl StatisticsCalculator {
         }
     }
 
-    /// Build weekly statistics from raw weekly data
+    /// Build weekly statistics from raw weekly data keyed by week start date
     fn build_weekly_stats(
-        weekly_stats: HashMap<(i32, u32), (i64, i64, Vec<PageStat>)>,
+        weekly_stats: HashMap<NaiveDate, (i64, i64, Vec<PageStat>)>,
+        session_gap_sec: i64,
     ) -> Vec<WeeklyStats> {
         let mut weeks = Vec::new();
-        for ((year, week), (read_time, pages_read, page_stats)) in weekly_stats {
-            let start_date_approx = NaiveDate::from_isoywd_opt(year, week, chrono::Weekday::Mon)
-                .unwrap_or_else(|| {
-                    NaiveDate::from_ymd_opt(year, 1, 1)
-                        .unwrap_or_else(|| NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
-                });
-
-            let end_date = start_date_approx + Duration::days(6);
+        for (start_date, (read_time, pages_read, page_stats)) in weekly_stats {
+            let end_date = start_date + Duration::days(6);
 
             let (average_session_duration, longest_session_duration) =
-                sessions::session_metrics(&page_stats);
+                sessions::session_metrics(&page_stats, session_gap_sec);
 
             let weekly_stat = WeeklyStats {
-                start_date: start_date_approx.format("%Y-%m-%d").to_string(),
+                start_date: start_date.format("%Y-%m-%d").to_string(),
                 end_date: end_date.format("%Y-%m-%d").to_string(),
                 read_time,
                 pages_read,
@@ -198,11 +221,13 @@ impl StatisticsCalculator {
         daily_activity
     }
 
-    /// Calculate reading streaks from daily activity data
+    /// Calculate reading streaks from daily activity data, allowing
+    /// `grace_days` skipped days inside a streak.
     /// Returns (longest_streak_info, current_streak_info)
     fn calculate_streaks(
         daily_activity: &[DailyStats],
         time_config: &TimeConfig,
+        grace_days: u16,
     ) -> (StreakInfo, StreakInfo) {
         if daily_activity.is_empty() {
             return (
@@ -230,26 +255,7 @@ impl StatisticsCalculator {
         let today = time_config.today_date();
 
         // (length, start_date, end_date)
-        let mut streaks: Vec<(i64, NaiveDate, NaiveDate)> = Vec::new();
-        let mut current_streak_start = sorted_dates[0];
-        let mut current_streak_length = 1i64;
-
-        for i in 1..sorted_dates.len() {
-            let prev_date = sorted_dates[i - 1];
-            let curr_date = sorted_dates[i];
-
-            if curr_date == prev_date + Duration::days(1) {
-                current_streak_length += 1;
-            } else {
-                streaks.push((current_streak_length, current_streak_start, prev_date));
-                current_streak_start = curr_date;
-                current_streak_length = 1;
-            }
-        }
-
-        if let Some(&last_date) = sorted_dates.last() {
-            streaks.push((current_streak_length, current_streak_start, last_date));
-        }
+        let streaks = streaks::streak_runs(&sorted_dates, grace_days);
 
         let longest_streak_info =
             if let Some(&(length, start, end)) = streaks.iter().max_by_key(|&&(len, _, _)| len) {
@@ -263,10 +269,8 @@ impl StatisticsCalculator {
             };
 
         let current_streak_info = if let Some(&last_reading_date) = sorted_dates.last() {
-            let days_since_last_read = (today - last_reading_date).num_days();
-
-            if days_since_last_read <= 1 {
-                // Last read was today or yesterday, find the streak that ends with last_reading_date
+            if streaks::is_streak_current(last_reading_date, today, grace_days) {
+                // Last read was recent enough, find the streak that ends with last_reading_date
                 if let Some(&(length, start, _)) = streaks
                     .iter()
                     .find(|&&(_, _, end)| end == last_reading_date)
@@ -280,7 +284,7 @@ impl StatisticsCalculator {
                     StreakInfo::new(0, None, None)
                 }
             } else {
-                StreakInfo::new(0, None, None) // No current streak if last read was more than 1 day ago
+                StreakInfo::new(0, None, None) // No current streak if the last read is too long ago
             }
         } else {
             StreakInfo::new(0, None, None)
@@ -289,24 +293,48 @@ impl StatisticsCalculator {
         (longest_streak_info, current_streak_info)
     }
 
-    /// Populate completion data for all books in the statistics data
-    pub fn populate_completions(stats_data: &mut StatisticsData, time_config: &TimeConfig) {
+    /// Populate completion data for all books in the statistics data.
+    /// Books listed in `overrides` use the manual ranges instead of detection.
+    pub fn populate_completions(
+        stats_data: &mut StatisticsData,
+        time_config: &TimeConfig,
+        stats_config: &StatisticsConfig,
+        completion_config: &CompletionConfig,
+        overrides: &CompletionOverrides,
+    ) {
         let detector = ReadCompletionDetector::with_config_and_time(
-            CompletionConfig::default(),
+            completion_config.clone(),
             time_config.clone(),
+            stats_config,
         );
-        let all_completions = detector.detect_all_completions(stats_data);
+        let mut all_completions = detector.detect_all_completions(stats_data);
 
-        for book in &mut stats_data.books {
-            if let Some(completions) = all_completions.get(&book.md5) {
-                book.completions = Some(completions.clone());
+        for book in &stats_data.books {
+            if let Some(completions) =
+                overrides.completions_for(book, &stats_data.page_stats, time_config, stats_config)
+            {
+                debug!(
+                    "Using {} manual completion(s) for '{}'",
+                    completions.total_completions, book.title
+                );
+                all_completions.insert(book.md5.clone(), completions);
             }
         }
 
+        // Overrides may list no reads at all, which clears the book's completions.
+        let lookup = |md5: &str| {
+            all_completions
+                .get(md5)
+                .filter(|completions| completions.has_completions())
+                .cloned()
+        };
+
+        for book in &mut stats_data.books {
+            book.completions = lookup(&book.md5);
+        }
+
         for (md5, book) in &mut stats_data.stats_by_md5 {
-            if let Some(completions) = all_completions.get(md5) {
-                book.completions = Some(completions.clone());
-            }
+            book.completions = lookup(md5);
         }
     }
 
@@ -328,6 +356,40 @@ impl StatisticsCalculator {
 
         (total_completions, books_completed, most_completions)
     }
+
+    /// Drop page reads shorter than `min_duration` and clamp or drop those
+    /// longer than `max_duration`. Runs before [`Self::filter_stats`], so the
+    /// per-day minimums see the adjusted durations.
+    pub fn apply_duration_limits(
+        stats_data: &mut StatisticsData,
+        min_duration: Option<u32>,
+        max_duration: Option<DurationCap>,
+    ) {
+        let original_count = stats_data.page_stats.len();
+        let mut clamped = 0usize;
+
+        stats_data.page_stats.retain_mut(|stat| {
+            if min_duration.is_some_and(|min| stat.duration < min as i64) {
+                return false;
+            }
+            match max_duration {
+                Some(DurationCap::Clamp(max)) if stat.duration > max as i64 => {
+                    stat.duration = max as i64;
+                    clamped += 1;
+                }
+                Some(DurationCap::Drop(max)) if stat.duration > max as i64 => return false,
+                _ => {}
+            }
+            true
+        });
+
+        debug!(
+            "Duration limits: dropped {} page reads, clamped {}",
+            original_count - stats_data.page_stats.len(),
+            clamped
+        );
+    }
+
     /// Filter statistics to exclude days that don't meet the minimum requirements per book
     pub fn filter_stats(
         stats_data: &mut StatisticsData,
@@ -387,6 +449,206 @@ impl StatisticsCalculator {
         });
     }
 
+    /// Keep only page reads whose logical day falls within `from..=to`.
+    ///
+    /// Runs after completion detection so reads are detected from the full
+    /// history. A completion is kept when its `end_date` is in range, even if
+    /// the read started earlier, and dropped otherwise.
+    pub fn filter_date_range(
+        stats_data: &mut StatisticsData,
+        time_config: &TimeConfig,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) {
+        if from.is_none() && to.is_none() {
+            return;
+        }
+        let in_range = |date: NaiveDate| {
+            from.is_none_or(|from| date >= from) && to.is_none_or(|to| date <= to)
+        };
+
+        let original_count = stats_data.page_stats.len();
+        stats_data
+            .page_stats
+            .retain(|stat| in_range(time_config.date_for_timestamp(stat.start_time)));
+
+        let clip = |completions: &mut Option<BookCompletions>| {
+            if let Some(existing) = completions.take() {
+                let entries: Vec<ReadCompletion> = existing
+                    .entries
+                    .into_iter()
+                    .filter(|entry| {
+                        NaiveDate::parse_from_str(&entry.end_date, "%Y-%m-%d").is_ok_and(in_range)
+                    })
+                    .collect();
+                *completions = (!entries.is_empty()).then(|| BookCompletions::new(entries));
+            }
+        };
+        for book in &mut stats_data.books {
+            clip(&mut book.completions);
+        }
+        for book in stats_data.stats_by_md5.values_mut() {
+            clip(&mut book.completions);
+        }
+
+        debug!(
+            "Date range {:?}..={:?}: kept {} of {} page reads",
+            from,
+            to,
+            stats_data.page_stats.len(),
+            original_count
+        );
+    }
+
+    /// Remove books matching any of `patterns` from statistics: a pattern
+    /// matches a book whose MD5 equals it or whose title contains it, both
+    /// compared case-insensitively. Drops the book rows, their page stats, and
+    /// their `stats_by_md5` entries.
+    pub fn exclude_books(stats_data: &mut StatisticsData, patterns: &[String]) {
+        let patterns: Vec<String> = patterns
+            .iter()
+            .map(|pattern| pattern.trim().to_lowercase())
+            .filter(|pattern| !pattern.is_empty())
+            .collect();
+        if patterns.is_empty() {
+            return;
+        }
+        let is_excluded = |book: &StatBook| {
+            let md5 = book.md5.to_lowercase();
+            let title = book.title.to_lowercase();
+            patterns
+                .iter()
+                .any(|pattern| md5 == *pattern || title.contains(pattern.as_str()))
+        };
+
+        let mut excluded_ids: HashSet<i64> = HashSet::new();
+        stats_data.books.retain(|book| {
+            if is_excluded(book) {
+                debug!(
+                    "Excluding statistics for '{}' by {} (md5: {})",
+                    book.title, book.authors, book.md5
+                );
+                excluded_ids.insert(book.id);
+                false
+            } else {
+                true
+            }
+        });
+
+        stats_data
+            .page_stats
+            .retain(|stat| !excluded_ids.contains(&stat.id_book));
+        stats_data.stats_by_md5.retain(|_, book| !is_excluded(book));
+
+        info!(
+            "Excluded {} books from statistics via --stats-exclude",
+            excluded_ids.len()
+        );
+    }
+
+    /// Link statistics books whose MD5 matches no library item to the library
+    /// item with the same title and author, e.g. after a book file was
+    /// recompressed. `library` holds `(item MD5, title, authors)`.
+    ///
+    /// Only items without statistics of their own are linked, and only when
+    /// exactly one statistics book and one item share the title and at least
+    /// one author; anything ambiguous stays unlinked.
+    pub fn link_by_title_and_author(
+        stats_data: &mut StatisticsData,
+        library: &[(String, String, Vec<String>)],
+    ) {
+        let stat_md5s: HashSet<String> = stats_data
+            .books
+            .iter()
+            .map(|book| book.md5.to_lowercase())
+            .collect();
+        let library_md5s: HashSet<String> = library
+            .iter()
+            .map(|(md5, _, _)| md5.to_lowercase())
+            .collect();
+
+        let orphaned: Vec<usize> = stats_data
+            .books
+            .iter()
+            .enumerate()
+            .filter(|(_, book)| !library_md5s.contains(&book.md5.to_lowercase()))
+            .map(|(index, _)| index)
+            .collect();
+        let mut by_title: HashMap<String, Vec<usize>> = HashMap::new();
+        for &index in &orphaned {
+            by_title
+                .entry(match_key(&stats_data.books[index].title))
+                .or_default()
+                .push(index);
+        }
+
+        // Items each statistics book matched, so books claimed by two
+        // items are left alone.
+        let mut candidates: HashMap<usize, Vec<&str>> = HashMap::new();
+        let mut item_matches: Vec<(&str, Vec<usize>)> = Vec::new();
+        for (md5, title, authors) in library {
+            if stat_md5s.contains(&md5.to_lowercase()) {
+                continue;
+            }
+            let title_key = match_key(title);
+            if title_key.is_empty() {
+                continue;
+            }
+            let Some(same_title) = by_title.get(&title_key) else {
+                continue;
+            };
+            let item_authors: HashSet<String> = authors
+                .iter()
+                .map(|author| match_key(author))
+                .filter(|key| !key.is_empty())
+                .collect();
+            let matches: Vec<usize> = same_title
+                .iter()
+                .copied()
+                .filter(|&index| {
+                    stats_data.books[index]
+                        .authors
+                        .split('\n')
+                        .map(match_key)
+                        .any(|key| item_authors.contains(&key))
+                })
+                .collect();
+            for &index in &matches {
+                candidates.entry(index).or_default().push(md5);
+            }
+            item_matches.push((md5, matches));
+        }
+
+        let mut relinked: HashMap<String, String> = HashMap::new();
+        for (md5, matches) in item_matches {
+            let [index] = matches[..] else {
+                continue;
+            };
+            if candidates.get(&index).map_or(0, Vec::len) != 1 {
+                continue;
+            }
+            let book = &mut stats_data.books[index];
+            info!(
+                "Linked statistics for '{}' by {} (MD5 {}) to library item {} by title and author",
+                book.title,
+                book.authors.replace('\n', ", "),
+                book.md5,
+                md5
+            );
+            relinked.insert(
+                std::mem::replace(&mut book.md5, md5.to_string()),
+                md5.to_string(),
+            );
+        }
+
+        for (old_md5, new_md5) in relinked {
+            if let Some(mut book) = stats_data.stats_by_md5.remove(&old_md5) {
+                book.md5 = new_md5.clone();
+                stats_data.stats_by_md5.insert(new_md5, book);
+            }
+        }
+    }
+
     /// Filter statistics to only include books present in the library.
     /// The library_md5s set contains MD5 hashes of books in the scanned library.
     /// This filters out statistics for deleted books or books in other directories.
@@ -422,4 +684,301 @@ impl StatisticsCalculator {
             filtered_count
         );
     }
+
+    /// Mark books missing from the library as removed instead of dropping them.
+    ///
+    /// Used with `--keep-ghost-stats` so lifetime totals keep the history of
+    /// books whose files were deleted.
+    pub fn mark_removed_books(stats_data: &mut StatisticsData, library_md5s: &HashSet<String>) {
+        let in_library =
+            |md5: &str| library_md5s.contains(md5) || library_md5s.contains(&md5.to_lowercase());
+
+        let mut removed_count = 0;
+        for book in &mut stats_data.books {
+            book.removed = !in_library(&book.md5);
+            if book.removed {
+                removed_count += 1;
+            }
+        }
+        for book in stats_data.stats_by_md5.values_mut() {
+            book.removed = !in_library(&book.md5);
+        }
+
+        log::debug!(
+            "Keeping statistics for {} books no longer in library",
+            removed_count
+        );
+    }
+}
+
+/// Title or author name reduced to its lowercase words in sorted order, so
+/// punctuation, case and "Last, First" ordering do not prevent a match.
+fn match_key(value: &str) -> String {
+    let mut words: Vec<String> = value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    words.sort_unstable();
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_stat(start_time: i64, duration: i64) -> PageStat {
+        PageStat {
+            id_book: 1,
+            page: 1,
+            start_time,
+            duration,
+        }
+    }
+
+    fn stats_data(page_stats: Vec<PageStat>) -> StatisticsData {
+        StatisticsData {
+            books: Vec::new(),
+            page_stats,
+            stats_by_md5: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn duration_limits_drop_short_reads_and_clamp_long_ones() {
+        let mut data = stats_data(vec![
+            page_stat(1_000, 1),
+            page_stat(2_000, 90),
+            page_stat(10_000, 6 * 3600),
+        ]);
+
+        StatisticsCalculator::apply_duration_limits(
+            &mut data,
+            Some(5),
+            Some(DurationCap::Clamp(3600)),
+        );
+
+        let durations: Vec<i64> = data.page_stats.iter().map(|s| s.duration).collect();
+        assert_eq!(durations, vec![90, 3600]);
+    }
+
+    #[test]
+    fn duration_limits_can_drop_long_reads() {
+        let mut data = stats_data(vec![
+            page_stat(1_000, 1),
+            page_stat(2_000, 90),
+            page_stat(3_000, 3600),
+            page_stat(10_000, 6 * 3600),
+        ]);
+
+        StatisticsCalculator::apply_duration_limits(
+            &mut data,
+            Some(5),
+            Some(DurationCap::Drop(3600)),
+        );
+
+        let durations: Vec<i64> = data.page_stats.iter().map(|s| s.duration).collect();
+        assert_eq!(durations, vec![90, 3600]);
+    }
+
+    #[test]
+    fn duration_limits_feed_session_metrics() {
+        let mut data = stats_data(vec![page_stat(1_000, 1), page_stat(100_000, 6 * 3600)]);
+
+        StatisticsCalculator::apply_duration_limits(
+            &mut data,
+            Some(5),
+            Some(DurationCap::Clamp(3600)),
+        );
+
+        let (average, longest) =
+            sessions::session_metrics(&data.page_stats, sessions::DEFAULT_SESSION_GAP_SECONDS);
+        assert_eq!(average, Some(3600));
+        assert_eq!(longest, Some(3600));
+    }
+
+    #[test]
+    fn duration_limits_are_optional() {
+        let mut data = stats_data(vec![page_stat(1_000, 1), page_stat(2_000, 6 * 3600)]);
+
+        StatisticsCalculator::apply_duration_limits(&mut data, None, None);
+        assert_eq!(data.page_stats.len(), 2);
+        assert_eq!(data.page_stats[1].duration, 6 * 3600);
+
+        StatisticsCalculator::apply_duration_limits(
+            &mut data,
+            None,
+            Some(DurationCap::Clamp(3600)),
+        );
+        assert_eq!(data.page_stats.len(), 2);
+        assert_eq!(data.page_stats[1].duration, 3600);
+    }
+
+    #[test]
+    fn session_stats_spread_pages_over_reading_days() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        let mut book = fixtures::stat_book(1, "abc", 100, ContentType::Book);
+        // Two reads on 2024-01-01 and one on 2024-01-03 (UTC).
+        let page_stats = vec![
+            fixtures::page_stat(1, 1, 1_704_110_400, 60),
+            fixtures::page_stat(1, 2, 1_704_110_460, 60),
+            fixtures::page_stat(1, 3, 1_704_283_200, 60),
+        ];
+        let time_config = TimeConfig::new(Some(chrono_tz::UTC), 0);
+
+        let stats =
+            book.calculate_session_stats(&page_stats, &time_config, &StatisticsConfig::default());
+        assert_eq!(stats.pages_per_day, Some(5.0));
+
+        // Without page data there is nothing to spread.
+        book.total_read_pages = None;
+        let stats =
+            book.calculate_session_stats(&page_stats, &time_config, &StatisticsConfig::default());
+        assert_eq!(stats.pages_per_day, None);
+    }
+
+    #[test]
+    fn date_range_keeps_reads_and_completions_ending_in_range() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        let mut book = fixtures::stat_book(1, "abc", 100, ContentType::Book);
+        book.completions = Some(BookCompletions::new(vec![
+            ReadCompletion::new("2023-11-01".into(), "2023-12-30".into(), 3600, 3, 100),
+            // Straddles the start of the range; kept because it ends inside.
+            ReadCompletion::new("2023-12-20".into(), "2024-01-05".into(), 7200, 5, 100),
+        ]));
+        // 2023-12-31 12:00 UTC and 2024-01-02 12:00 UTC.
+        let mut data = fixtures::statistics_data(
+            vec![book],
+            vec![
+                fixtures::page_stat(1, 1, 1_704_024_000, 60),
+                fixtures::page_stat(1, 2, 1_704_196_800, 60),
+            ],
+        );
+        let time_config = TimeConfig::new(Some(chrono_tz::UTC), 0);
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1);
+        let to = NaiveDate::from_ymd_opt(2024, 12, 31);
+
+        StatisticsCalculator::filter_date_range(&mut data, &time_config, from, to);
+
+        assert_eq!(data.page_stats.len(), 1);
+        assert_eq!(data.page_stats[0].page, 2);
+        let completions = data.books[0].completions.as_ref().expect("completion kept");
+        assert_eq!(completions.total_completions, 1);
+        assert_eq!(completions.entries[0].start_date, "2023-12-20");
+        assert_eq!(
+            completions.last_completion_date.as_deref(),
+            Some("2024-01-05")
+        );
+
+        // A range without any reading leaves empty stats, not an error.
+        let later = NaiveDate::from_ymd_opt(2025, 1, 1);
+        StatisticsCalculator::filter_date_range(&mut data, &time_config, later, None);
+        assert!(data.page_stats.is_empty());
+        assert!(data.books[0].completions.is_none());
+    }
+
+    #[test]
+    fn exclude_books_matches_md5_and_title_substring() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        let mut books = vec![
+            fixtures::stat_book(1, "AAA111", 100, ContentType::Book),
+            fixtures::stat_book(2, "bbb222", 100, ContentType::Book),
+            fixtures::stat_book(3, "ccc333", 100, ContentType::Book),
+        ];
+        books[1].title = "PDF Reference Manual".to_string();
+        books[2].title = "A Novel".to_string();
+        let mut data = fixtures::statistics_data(
+            books,
+            vec![
+                fixtures::page_stat(1, 1, 1_000, 60),
+                fixtures::page_stat(2, 1, 2_000, 60),
+                fixtures::page_stat(3, 1, 3_000, 60),
+            ],
+        );
+
+        StatisticsCalculator::exclude_books(
+            &mut data,
+            &["aaa111".to_string(), "reference".to_string()],
+        );
+
+        assert_eq!(data.books.len(), 1);
+        assert_eq!(data.books[0].id, 3);
+        assert_eq!(data.page_stats.len(), 1);
+        assert_eq!(data.page_stats[0].id_book, 3);
+        assert_eq!(data.stats_by_md5.keys().collect::<Vec<_>>(), vec!["ccc333"]);
+    }
+
+    #[test]
+    fn mark_removed_books_keeps_stats_for_missing_files() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        let mut data = fixtures::statistics_data(
+            vec![
+                fixtures::stat_book(1, "aaa111", 100, ContentType::Book),
+                fixtures::stat_book(2, "bbb222", 100, ContentType::Book),
+            ],
+            vec![
+                fixtures::page_stat(1, 1, 1_000, 60),
+                fixtures::page_stat(2, 1, 2_000, 60),
+            ],
+        );
+        let library: HashSet<String> = ["aaa111".to_string()].into_iter().collect();
+
+        StatisticsCalculator::mark_removed_books(&mut data, &library);
+
+        assert_eq!(data.books.len(), 2);
+        assert_eq!(data.page_stats.len(), 2);
+        let removed: Vec<_> = data.books.iter().map(|b| b.removed).collect();
+        assert_eq!(removed, vec![false, true]);
+        assert!(data.stats_by_md5["bbb222"].removed);
+    }
+
+    #[test]
+    fn title_and_author_links_only_unambiguous_orphaned_stats() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        let book = |id, md5: &str, title: &str, authors: &str| {
+            let mut book = fixtures::stat_book(id, md5, 100, ContentType::Book);
+            book.title = title.to_string();
+            book.authors = authors.to_string();
+            book
+        };
+        let mut data = fixtures::statistics_data(
+            vec![
+                book(1, "old-dune", "Dune", "Herbert, Frank"),
+                book(2, "old-emma-1", "Emma", "Jane Austen"),
+                book(3, "old-emma-2", "Emma", "Jane Austen"),
+                book(4, "old-it", "It", "Someone Else"),
+                book(5, "kept", "Kept", "Author"),
+            ],
+            vec![fixtures::page_stat(1, 1, 1_000, 60)],
+        );
+        let item = |md5: &str, title: &str, author: &str| {
+            (md5.to_string(), title.to_string(), vec![author.to_string()])
+        };
+        let library = vec![
+            item("new-dune", "Dune.", "Frank Herbert"),
+            item("new-emma", "Emma", "Jane Austen"),
+            item("new-it", "It", "Stephen King"),
+            item("kept", "Kept", "Author"),
+        ];
+
+        StatisticsCalculator::link_by_title_and_author(&mut data, &library);
+
+        let md5s: Vec<&str> = data.books.iter().map(|b| b.md5.as_str()).collect();
+        assert_eq!(
+            md5s,
+            vec!["new-dune", "old-emma-1", "old-emma-2", "old-it", "kept"]
+        );
+        assert_eq!(data.stats_by_md5["new-dune"].md5, "new-dune");
+        assert!(!data.stats_by_md5.contains_key("old-dune"));
+    }
 }
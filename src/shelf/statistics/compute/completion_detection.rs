@@ -17,7 +17,8 @@
 //!    - Pages from the end (`min_late_percentage`, last 2%) were read
 //!
 //! 3. **Progressions are split** when ALL conditions are met:
-//!    - Reading jumps backwards to early pages (within `min_early_percentage`)
+//!    - Reading jumps backwards to the first pages (within `restart_percentage`,
+//!      first 5%) from beyond the early section
 //!    - The remaining reading from that point would form a valid completion on its own
 //!
 //! ## Key Behaviors
@@ -31,11 +32,15 @@
 //!
 //! - **True re-reads**: When a user finishes a book, then starts again from the beginning
 //!   and reads through again, two separate completions are detected.
+//!
+//! - **No time gap**: reads are told apart by page positions only, so the time
+//!   between finishing and starting again plays no part.
 
 use std::collections::{HashMap, HashSet};
 
 use log::debug;
 
+use crate::shelf::statistics::StatisticsConfig;
 use crate::shelf::statistics::compute::sessions;
 use crate::shelf::time_config::TimeConfig;
 use crate::source::koreader::types::{
@@ -51,6 +56,9 @@ pub struct CompletionConfig {
     pub min_early_percentage: f64,
     /// Minimum percentage of book's end that must be read (0.0 - 1.0)
     pub min_late_percentage: f64,
+    /// Jumping back to within this percentage of the book's start may begin a
+    /// new read (0.0 - 1.0)
+    pub restart_percentage: f64,
 }
 
 impl Default for CompletionConfig {
@@ -59,11 +67,36 @@ impl Default for CompletionConfig {
             min_completion_percentage: 0.78, // Must read 78% of the book
             min_early_percentage: 0.20,      // Must read 20% from beginning
             min_late_percentage: 0.02,       // Must read 2% from end
+            restart_percentage: 0.05,        // Re-reads restart in the first 5%
         }
     }
 }
 
 impl CompletionConfig {
+    /// Build from optional CLI percentages (0-100), keeping defaults for the rest.
+    pub fn from_cli(
+        min_pages_percent: Option<u8>,
+        min_early_percent: Option<u8>,
+        min_late_percent: Option<u8>,
+        restart_window_percent: Option<u8>,
+    ) -> Self {
+        let mut config = Self::default();
+        let fraction = |percent: u8| percent as f64 / 100.0;
+        if let Some(percent) = min_pages_percent {
+            config.min_completion_percentage = fraction(percent);
+        }
+        if let Some(percent) = min_early_percent {
+            config.min_early_percentage = fraction(percent);
+        }
+        if let Some(percent) = min_late_percent {
+            config.min_late_percentage = fraction(percent);
+        }
+        if let Some(percent) = restart_window_percent {
+            config.restart_percentage = fraction(percent);
+        }
+        config
+    }
+
     /// Calculate the early page threshold (pages considered "beginning" of the book)
     fn early_threshold(&self, total_pages: i64) -> i64 {
         (total_pages as f64 * self.min_early_percentage) as i64
@@ -73,6 +106,11 @@ impl CompletionConfig {
     fn late_threshold(&self, total_pages: i64) -> i64 {
         (total_pages as f64 * (1.0 - self.min_late_percentage)) as i64
     }
+
+    /// Calculate the restart page threshold (pages where a re-read may begin)
+    fn restart_threshold(&self, total_pages: i64) -> i64 {
+        (total_pages as f64 * self.restart_percentage) as i64
+    }
 }
 
 /// Represents a reading progression through a book
@@ -150,13 +188,19 @@ impl ReadingProgression {
 pub struct ReadCompletionDetector {
     config: CompletionConfig,
     time_config: TimeConfig,
+    session_gap_sec: i64,
 }
 
 impl ReadCompletionDetector {
-    pub fn with_config_and_time(config: CompletionConfig, time_config: TimeConfig) -> Self {
+    pub fn with_config_and_time(
+        config: CompletionConfig,
+        time_config: TimeConfig,
+        stats_config: &StatisticsConfig,
+    ) -> Self {
         Self {
             config,
             time_config,
+            session_gap_sec: stats_config.session_gap_sec,
         }
     }
 
@@ -244,7 +288,7 @@ impl ReadCompletionDetector {
 
     /// Group page stats into reading progressions based on re-read detection.
     /// A split occurs when:
-    /// 1. Reading restarts from early pages (within min_early_percentage)
+    /// 1. Reading restarts from the first pages (within restart_percentage)
     /// 2. The remaining stats from that point would form a valid completion on their own
     ///
     /// This handles both abandoned reads (split off incomplete portion) and true re-reads.
@@ -257,14 +301,14 @@ impl ReadCompletionDetector {
         let mut current_progression = ReadingProgression::new();
 
         let early_page_threshold = self.config.early_threshold(total_pages);
+        let restart_threshold = self.config.restart_threshold(total_pages);
 
         for (i, stat) in sorted_stats.iter().enumerate() {
             // Split when ALL conditions are met:
-            // - Current page is in first 5% of the book
+            // - Current page is within the restart threshold (first 5% by default)
             // - Previous page was beyond early threshold (actual backwards jump)
             // - Current progression already contains early pages
             // - Remaining reading from this point would form a valid completion
-            let restart_threshold = (total_pages as f64 * 0.05) as i64;
             let prev_page = if i > 0 { sorted_stats[i - 1].page } else { 0 };
             let is_jumping_back = prev_page > early_page_threshold;
             let already_started_reading = current_progression
@@ -317,7 +361,7 @@ impl ReadCompletionDetector {
         }
 
         let pages_covered = progression.pages_visited.len() as i64;
-        let session_count = sessions::session_count(&progression.stats);
+        let session_count = sessions::session_count(&progression.stats, self.session_gap_sec);
         let start_date = self.time_config.format_date(progression.start_time);
         let end_date = self.time_config.format_date(progression.end_time);
 
@@ -360,3 +404,98 @@ impl ReadCompletionDetector {
         all_completions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::models::ContentType;
+    use crate::tests::fixtures;
+
+    /// One page per minute, read in order, starting at `start_time`.
+    fn read_pages(pages: impl Iterator<Item = i64>, start_time: i64) -> Vec<PageStat> {
+        pages
+            .enumerate()
+            .map(|(i, page)| fixtures::page_stat(1, page, start_time + i as i64 * 60, 60))
+            .collect()
+    }
+
+    fn detect(config: CompletionConfig, page_stats: &[PageStat]) -> BookCompletions {
+        let book = fixtures::stat_book(1, "abc", 100, ContentType::Book);
+        let detector = ReadCompletionDetector::with_config_and_time(
+            config,
+            TimeConfig::new(None, 0),
+            &StatisticsConfig::default(),
+        );
+        detector.detect_completions(&book, page_stats)
+    }
+
+    // 2023-01-01 00:00:00 UTC
+    const FIRST_READ: i64 = 1672531200;
+    // 2023-02-01 00:00:00 UTC
+    const SECOND_READ: i64 = 1675209600;
+
+    #[test]
+    fn full_reread_splits_with_default_config() {
+        let mut stats = read_pages(1..=100, FIRST_READ);
+        stats.extend(read_pages(1..=100, SECOND_READ));
+
+        let completions = detect(CompletionConfig::default(), &stats);
+        assert_eq!(completions.total_completions, 2);
+    }
+
+    #[test]
+    fn wider_restart_window_splits_reread_that_skips_front_matter() {
+        // The second read starts at page 8, past the default 5% restart zone.
+        let mut stats = read_pages(1..=100, FIRST_READ);
+        stats.extend(read_pages(8..=100, SECOND_READ));
+
+        let completions = detect(CompletionConfig::default(), &stats);
+        assert_eq!(completions.total_completions, 1);
+
+        let completions = detect(
+            CompletionConfig::from_cli(None, None, None, Some(10)),
+            &stats,
+        );
+        assert_eq!(completions.total_completions, 2);
+        assert_eq!(completions.entries[1].start_date, "2023-02-01");
+    }
+
+    #[test]
+    fn lower_min_pages_splits_partial_reread() {
+        // The second read skims 70 of 100 pages, below the default 78%.
+        let mut stats = read_pages(1..=100, FIRST_READ);
+        stats.extend(read_pages(
+            (1..=100).filter(|page| page % 10 < 7),
+            SECOND_READ,
+        ));
+
+        let completions = detect(CompletionConfig::default(), &stats);
+        assert_eq!(completions.total_completions, 1);
+
+        let completions = detect(
+            CompletionConfig::from_cli(Some(60), None, None, None),
+            &stats,
+        );
+        assert_eq!(completions.total_completions, 2);
+    }
+
+    #[test]
+    fn lower_min_early_splits_reread_after_looking_back() {
+        // After finishing, the reader looks up a map on page 15, inside the
+        // default 20% start section, and later rereads from page 1.
+        let mut stats = read_pages(1..=100, FIRST_READ);
+        stats.extend(read_pages(15..=15, FIRST_READ + 100 * 60));
+        stats.extend(read_pages(1..=100, SECOND_READ));
+
+        let completions = detect(CompletionConfig::default(), &stats);
+        assert_eq!(completions.total_completions, 1);
+
+        let completions = detect(
+            CompletionConfig::from_cli(None, Some(10), None, None),
+            &stats,
+        );
+        assert_eq!(completions.total_completions, 2);
+        assert_eq!(completions.entries[0].start_date, "2023-01-01");
+        assert_eq!(completions.entries[1].start_date, "2023-02-01");
+    }
+}
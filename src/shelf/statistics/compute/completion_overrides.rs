@@ -0,0 +1,320 @@
+//! Manual completion overrides.
+//!
+//! Some reading histories fool [`ReadCompletionDetector`](super::completion_detection::ReadCompletionDetector),
+//! e.g. two re-reads separated by a short pause get merged into one completion.
+//! Overrides let the user list the reads of a book explicitly, keyed by the
+//! book's KOReader MD5:
+//!
+//! ```toml
+//! "0123456789abcdef0123456789abcdef" = [
+//!     { start = "2024-01-03", end = "2024-01-20" },
+//!     { start = "2024-02-01", end = "2024-02-14" },
+//! ]
+//! ```
+//!
+//! Books listed here skip detection entirely; an empty list removes all of a
+//! book's completions. Reading time, sessions, and pages for each completion
+//! are taken from the page stats that fall inside its date range.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::shelf::statistics::StatisticsConfig;
+use crate::shelf::statistics::compute::sessions;
+use crate::shelf::time_config::TimeConfig;
+use crate::source::koreader::types::{BookCompletions, PageStat, ReadCompletion, StatBook};
+
+/// A manually specified read of a book (both dates inclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompletionOverride {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawCompletionOverride {
+    start: String,
+    end: String,
+}
+
+/// Completion overrides keyed by lowercase book MD5.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionOverrides {
+    by_md5: HashMap<String, Vec<CompletionOverride>>,
+}
+
+impl CompletionOverrides {
+    /// Parse and validate overrides from TOML. Rejects unparseable dates,
+    /// ranges that end before they start, overlapping ranges for one book, and
+    /// books listed twice with differently cased MD5s.
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let raw: BTreeMap<String, Vec<RawCompletionOverride>> =
+            toml::from_str(contents).context("Invalid completion overrides")?;
+
+        let mut by_md5 = HashMap::new();
+        let mut keys: HashMap<String, &str> = HashMap::new();
+        for (md5, entries) in &raw {
+            match keys.entry(md5.to_lowercase()) {
+                Entry::Occupied(first) => bail!(
+                    "Completion overrides list the same book twice: {} and {}",
+                    first.get(),
+                    md5
+                ),
+                Entry::Vacant(slot) => {
+                    slot.insert(md5);
+                }
+            }
+
+            let mut ranges = entries
+                .iter()
+                .map(|entry| {
+                    let start = parse_date(md5, "start", &entry.start)?;
+                    let end = parse_date(md5, "end", &entry.end)?;
+                    if end < start {
+                        bail!(
+                            "Completion override for {} ends ({}) before it starts ({})",
+                            md5,
+                            entry.end,
+                            entry.start
+                        );
+                    }
+                    Ok(CompletionOverride { start, end })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            ranges.sort_by_key(|range| range.start);
+            if let Some(pair) = ranges.windows(2).find(|pair| pair[1].start <= pair[0].end) {
+                bail!(
+                    "Completion overrides for {} overlap: {} to {} and {} to {}",
+                    md5,
+                    pair[0].start,
+                    pair[0].end,
+                    pair[1].start,
+                    pair[1].end
+                );
+            }
+
+            by_md5.insert(md5.to_lowercase(), ranges);
+        }
+
+        Ok(Self { by_md5 })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_md5.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_md5.len()
+    }
+
+    pub fn get(&self, md5: &str) -> Option<&[CompletionOverride]> {
+        self.by_md5.get(&md5.to_lowercase()).map(Vec::as_slice)
+    }
+
+    /// Build the completions for `book` from its overrides, or `None` when
+    /// the book has no overrides and detection should be used instead.
+    pub fn completions_for(
+        &self,
+        book: &StatBook,
+        page_stats: &[PageStat],
+        time_config: &TimeConfig,
+        stats_config: &StatisticsConfig,
+    ) -> Option<BookCompletions> {
+        let ranges = self.get(&book.md5)?;
+        let book_stats: Vec<&PageStat> = page_stats
+            .iter()
+            .filter(|stat| stat.id_book == book.id && stat.duration > 0)
+            .collect();
+
+        let entries = ranges
+            .iter()
+            .map(|range| build_completion(range, &book_stats, time_config, stats_config))
+            .collect();
+        Some(BookCompletions::new(entries))
+    }
+}
+
+fn parse_date(md5: &str, field: &str, value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").with_context(|| {
+        format!(
+            "Invalid {} date '{}' in completion override for {} (expected YYYY-MM-DD)",
+            field, value, md5
+        )
+    })
+}
+
+fn build_completion(
+    range: &CompletionOverride,
+    book_stats: &[&PageStat],
+    time_config: &TimeConfig,
+    stats_config: &StatisticsConfig,
+) -> ReadCompletion {
+    let in_range: Vec<PageStat> = book_stats
+        .iter()
+        .filter(|stat| {
+            let date = time_config.date_for_timestamp(stat.start_time);
+            date >= range.start && date <= range.end
+        })
+        .map(|stat| (*stat).clone())
+        .collect();
+
+    let reading_time = in_range.iter().map(|stat| stat.duration).sum();
+    let session_count = sessions::session_count(&in_range, stats_config.session_gap_sec);
+    let pages_read = in_range
+        .iter()
+        .map(|stat| stat.page)
+        .collect::<HashSet<_>>()
+        .len() as i64;
+
+    ReadCompletion::new(
+        range.start.format("%Y-%m-%d").to_string(),
+        range.end.format("%Y-%m-%d").to_string(),
+        reading_time,
+        session_count,
+        pages_read,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> StatBook {
+        StatBook {
+            id: 1,
+            title: "Book".to_string(),
+            authors: String::new(),
+            notes: None,
+            last_open: None,
+            highlights: None,
+            pages: Some(100),
+            md5: "abc123".to_string(),
+            content_type: None,
+            total_read_time: None,
+            total_read_pages: None,
+            completions: None,
+            removed: false,
+        }
+    }
+
+    fn page_stat(page: i64, start_time: i64, duration: i64) -> PageStat {
+        PageStat {
+            id_book: 1,
+            page,
+            start_time,
+            duration,
+        }
+    }
+
+    fn utc() -> TimeConfig {
+        TimeConfig::new(Some(chrono_tz::UTC), 0)
+    }
+
+    #[test]
+    fn parses_ranges_and_matches_md5_case_insensitively() {
+        let overrides = CompletionOverrides::from_toml_str(
+            r#""ABC123" = [
+                { start = "2024-02-01", end = "2024-02-14" },
+                { start = "2024-01-03", end = "2024-01-20" },
+            ]"#,
+        )
+        .expect("overrides should parse");
+
+        let ranges = overrides.get("abc123").expect("book should have overrides");
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(
+            ranges[0].start,
+            NaiveDate::from_ymd_opt(2024, 1, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_range_ending_before_start() {
+        let err = CompletionOverrides::from_toml_str(
+            r#"abc123 = [{ start = "2024-02-01", end = "2024-01-20" }]"#,
+        )
+        .expect_err("inverted range should fail");
+        assert!(err.to_string().contains("before it starts"), "{err}");
+    }
+
+    #[test]
+    fn rejects_invalid_dates_and_overlaps() {
+        let err = CompletionOverrides::from_toml_str(
+            r#"abc123 = [{ start = "2024-13-01", end = "2024-12-20" }]"#,
+        )
+        .expect_err("invalid date should fail");
+        assert!(err.to_string().contains("Invalid start date"), "{err}");
+
+        let err = CompletionOverrides::from_toml_str(
+            r#"abc123 = [
+                { start = "2024-01-01", end = "2024-01-10" },
+                { start = "2024-01-10", end = "2024-01-20" },
+            ]"#,
+        )
+        .expect_err("overlapping ranges should fail");
+        assert!(err.to_string().contains("overlap"), "{err}");
+    }
+
+    #[test]
+    fn rejects_md5s_differing_only_in_case() {
+        let err = CompletionOverrides::from_toml_str(
+            r#"
+            "ABC123" = [{ start = "2024-01-01", end = "2024-01-10" }]
+            "abc123" = [{ start = "2024-02-01", end = "2024-02-10" }]
+            "#,
+        )
+        .expect_err("duplicate book should fail");
+        let message = err.to_string();
+        assert!(
+            message.contains("ABC123") && message.contains("abc123"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn completions_use_page_stats_inside_each_range() {
+        let overrides = CompletionOverrides::from_toml_str(
+            r#"abc123 = [
+                { start = "2024-01-01", end = "2024-01-01" },
+                { start = "2024-01-02", end = "2024-01-02" },
+            ]"#,
+        )
+        .expect("overrides should parse");
+
+        // 2024-01-01 00:00:00 UTC = 1704067200
+        let stats = vec![
+            page_stat(1, 1704067200, 60),
+            page_stat(2, 1704067200 + 60, 60),
+            page_stat(1, 1704067200 + 86400, 120),
+        ];
+
+        let completions = overrides
+            .completions_for(&book(), &stats, &utc(), &StatisticsConfig::default())
+            .expect("book has overrides");
+        assert_eq!(completions.total_completions, 2);
+        assert_eq!(completions.entries[0].reading_time, 120);
+        assert_eq!(completions.entries[0].pages_read, 2);
+        assert_eq!(completions.entries[1].reading_time, 120);
+        assert_eq!(completions.entries[1].pages_read, 1);
+        assert_eq!(
+            completions.last_completion_date.as_deref(),
+            Some("2024-01-02")
+        );
+    }
+
+    #[test]
+    fn books_without_overrides_fall_back_to_detection() {
+        let overrides = CompletionOverrides::default();
+        assert!(
+            overrides
+                .completions_for(&book(), &[], &utc(), &StatisticsConfig::default())
+                .is_none()
+        );
+    }
+}
@@ -2,5 +2,7 @@
 
 pub mod calculator;
 pub mod completion_detection;
+pub mod completion_overrides;
 pub mod scaling;
 pub mod sessions;
+pub mod streaks;
@@ -3,12 +3,12 @@ use std::collections::HashMap;
 use crate::source::koreader::types::PageStat;
 
 /// Default time gap that separates two reading events into different sessions (in seconds)
-const DEFAULT_SESSION_GAP_SECONDS: i64 = 300; // 5 minutes
+pub const DEFAULT_SESSION_GAP_SECONDS: i64 = 300; // 5 minutes
 
-/// Calculate the duration (in seconds) of each reading session for a single book.
+/// Split a single book's page reads into reading sessions, ordered by time.
 /// Two consecutive page reads belong to the same session when the gap between
-/// them is less than or equal to the default gap (5 minutes).
-pub fn session_durations(stats: &[PageStat]) -> Vec<i64> {
+/// them is less than or equal to `gap_sec` (see `StatisticsConfig::session_gap_sec`).
+pub fn split_sessions(stats: &[PageStat], gap_sec: i64) -> Vec<Vec<PageStat>> {
     if stats.is_empty() {
         return Vec::new();
     }
@@ -16,34 +16,40 @@ pub fn session_durations(stats: &[PageStat]) -> Vec<i64> {
     let mut sorted = stats.to_vec();
     sorted.sort_by_key(|s| s.start_time);
 
-    let mut durations = Vec::new();
-    let mut current = sorted[0].duration;
+    let mut sessions = Vec::new();
+    let mut current = vec![sorted[0].clone()];
     let mut last_end = sorted[0].start_time + sorted[0].duration;
 
     for stat in &sorted[1..] {
-        if stat.start_time - last_end <= DEFAULT_SESSION_GAP_SECONDS {
-            current += stat.duration;
-        } else {
-            durations.push(current);
-            current = stat.duration;
+        if stat.start_time - last_end > gap_sec {
+            sessions.push(std::mem::take(&mut current));
         }
         last_end = stat.start_time + stat.duration;
+        current.push(stat.clone());
     }
 
-    durations.push(current);
-    durations
+    sessions.push(current);
+    sessions
+}
+
+/// Calculate the duration (in seconds) of each reading session for a single book.
+pub fn session_durations(stats: &[PageStat], gap_sec: i64) -> Vec<i64> {
+    split_sessions(stats, gap_sec)
+        .iter()
+        .map(|session| session.iter().map(|stat| stat.duration).sum())
+        .collect()
 }
 
 /// Convenience helper that only returns the number of sessions.
 #[inline]
-pub fn session_count(stats: &[PageStat]) -> i64 {
-    session_durations(stats).len() as i64
+pub fn session_count(stats: &[PageStat], gap_sec: i64) -> i64 {
+    session_durations(stats, gap_sec).len() as i64
 }
 
 /// Aggregate session durations across *all* books contained in `page_stats`.
 /// The returned vector contains the duration (in seconds) of **every** session
 /// across the dataset.
-pub fn aggregate_session_durations(page_stats: &[PageStat]) -> Vec<i64> {
+pub fn aggregate_session_durations(page_stats: &[PageStat], gap_sec: i64) -> Vec<i64> {
     let mut by_book: HashMap<i64, Vec<PageStat>> = HashMap::new();
     for stat in page_stats.iter().filter(|s| s.duration > 0) {
         by_book.entry(stat.id_book).or_default().push(stat.clone());
@@ -51,7 +57,7 @@ pub fn aggregate_session_durations(page_stats: &[PageStat]) -> Vec<i64> {
 
     let mut all = Vec::new();
     for stats in by_book.values() {
-        let mut durations = session_durations(stats);
+        let mut durations = session_durations(stats, gap_sec);
         all.append(&mut durations);
     }
 
@@ -60,8 +66,8 @@ pub fn aggregate_session_durations(page_stats: &[PageStat]) -> Vec<i64> {
 
 /// Compute (average_session_duration, longest_session_duration) from the provided
 /// `page_stats` slice. Returns `(None, None)` if no valid sessions exist.
-pub fn session_metrics(page_stats: &[PageStat]) -> (Option<i64>, Option<i64>) {
-    let sessions = aggregate_session_durations(page_stats);
+pub fn session_metrics(page_stats: &[PageStat], gap_sec: i64) -> (Option<i64>, Option<i64>) {
+    let sessions = aggregate_session_durations(page_stats, gap_sec);
     if sessions.is_empty() {
         return (None, None);
     }
@@ -70,3 +76,53 @@ pub fn session_metrics(page_stats: &[PageStat]) -> (Option<i64>, Option<i64>) {
     let longest = sessions.iter().max().copied();
     (average, longest)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_stat(start_time: i64, duration: i64) -> PageStat {
+        PageStat {
+            id_book: 1,
+            page: 1,
+            start_time,
+            duration,
+        }
+    }
+
+    /// Two clusters of page turns with a 45-minute pause in between.
+    fn two_clusters() -> Vec<PageStat> {
+        let second_start = 3 * 60 + 45 * 60;
+        vec![
+            page_stat(0, 60),
+            page_stat(60, 60),
+            page_stat(120, 60),
+            page_stat(second_start, 60),
+            page_stat(second_start + 60, 120),
+        ]
+    }
+
+    #[test]
+    fn gap_above_threshold_splits_sessions() {
+        let durations = session_durations(&two_clusters(), 30 * 60);
+        assert_eq!(durations, vec![180, 180]);
+
+        let (average, longest) = session_metrics(&two_clusters(), 30 * 60);
+        assert_eq!(average, Some(180));
+        assert_eq!(longest, Some(180));
+    }
+
+    #[test]
+    fn gap_within_threshold_keeps_one_session() {
+        assert_eq!(session_durations(&two_clusters(), 60 * 60), vec![360]);
+        assert_eq!(session_count(&two_clusters(), 60 * 60), 1);
+    }
+
+    #[test]
+    fn default_gap_splits_long_pauses() {
+        assert_eq!(
+            session_count(&two_clusters(), DEFAULT_SESSION_GAP_SECONDS),
+            2
+        );
+    }
+}
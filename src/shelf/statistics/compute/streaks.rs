@@ -0,0 +1,105 @@
+//! Reading streaks: runs of consecutive reading days.
+
+use chrono::NaiveDate;
+
+/// Split sorted, deduplicated reading dates into streaks of `(days, start, end)`.
+///
+/// Gaps of up to `grace_days` non-reading days keep a streak going. Those days
+/// count towards its length, but a streak always starts and ends on a reading
+/// day, so grace days never extend it at either end.
+pub fn streak_runs(
+    sorted_dates: &[NaiveDate],
+    grace_days: u16,
+) -> Vec<(i64, NaiveDate, NaiveDate)> {
+    let Some(&first) = sorted_dates.first() else {
+        return Vec::new();
+    };
+
+    let max_step = 1 + grace_days as i64;
+    let mut runs = Vec::new();
+    let mut start = first;
+    let mut end = first;
+
+    for &date in &sorted_dates[1..] {
+        if (date - end).num_days() > max_step {
+            runs.push(((end - start).num_days() + 1, start, end));
+            start = date;
+        }
+        end = date;
+    }
+    runs.push(((end - start).num_days() + 1, start, end));
+
+    runs
+}
+
+/// Whether a streak whose last reading day is `last_reading_date` is still
+/// running on `today`, allowing for `grace_days` skipped days.
+pub fn is_streak_current(last_reading_date: NaiveDate, today: NaiveDate, grace_days: u16) -> bool {
+    (today - last_reading_date).num_days() <= 1 + grace_days as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn dates(values: &[&str]) -> Vec<NaiveDate> {
+        values.iter().map(|value| date(value)).collect()
+    }
+
+    #[test]
+    fn streak_runs_without_grace_break_on_any_gap() {
+        let runs = streak_runs(
+            &dates(&["2024-03-01", "2024-03-02", "2024-03-04", "2024-03-05"]),
+            0,
+        );
+        assert_eq!(
+            runs,
+            vec![
+                (2, date("2024-03-01"), date("2024-03-02")),
+                (2, date("2024-03-04"), date("2024-03-05")),
+            ]
+        );
+    }
+
+    #[test]
+    fn streak_runs_bridge_gaps_up_to_grace_days() {
+        // Six days a week with Wednesday (2024-03-06) skipped.
+        let reading = dates(&[
+            "2024-03-04",
+            "2024-03-05",
+            "2024-03-07",
+            "2024-03-08",
+            "2024-03-09",
+            "2024-03-10",
+        ]);
+        assert_eq!(
+            streak_runs(&reading, 1),
+            vec![(7, date("2024-03-04"), date("2024-03-10"))]
+        );
+
+        // A two-day gap is more than one grace day.
+        let reading = dates(&["2024-03-04", "2024-03-07"]);
+        assert_eq!(streak_runs(&reading, 1).len(), 2);
+        assert_eq!(streak_runs(&reading, 2).len(), 1);
+    }
+
+    #[test]
+    fn grace_days_do_not_extend_streak_edges() {
+        let runs = streak_runs(&dates(&["2024-03-04"]), 3);
+        assert_eq!(runs, vec![(1, date("2024-03-04"), date("2024-03-04"))]);
+        assert!(streak_runs(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn current_streak_survives_grace_days_before_today() {
+        let last = date("2024-03-04");
+        assert!(is_streak_current(last, date("2024-03-05"), 0));
+        assert!(!is_streak_current(last, date("2024-03-06"), 0));
+        assert!(is_streak_current(last, date("2024-03-06"), 1));
+        assert!(!is_streak_current(last, date("2024-03-07"), 1));
+    }
+}
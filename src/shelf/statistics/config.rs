@@ -0,0 +1,38 @@
+//! Settings that shape how reading statistics are derived from page reads.
+
+use crate::shelf::statistics::compute::sessions::DEFAULT_SESSION_GAP_SECONDS;
+
+/// Session and streak rules passed alongside [`TimeConfig`](crate::shelf::time_config::TimeConfig).
+///
+/// `session_gap_sec` is the longest pause between two page reads that still
+/// keeps them in the same reading session. `streak_grace_days` is how many
+/// days in a row may be skipped without breaking a reading streak.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatisticsConfig {
+    pub session_gap_sec: i64,
+    pub streak_grace_days: u16,
+}
+
+impl Default for StatisticsConfig {
+    fn default() -> Self {
+        Self {
+            session_gap_sec: DEFAULT_SESSION_GAP_SECONDS,
+            streak_grace_days: 0,
+        }
+    }
+}
+
+impl StatisticsConfig {
+    /// Override the session gap; `None` keeps the default of 5 minutes.
+    pub fn with_session_gap_minutes(mut self, minutes: Option<u32>) -> Self {
+        if let Some(minutes) = minutes {
+            self.session_gap_sec = minutes as i64 * 60;
+        }
+        self
+    }
+
+    pub fn with_streak_grace_days(mut self, grace_days: Option<u16>) -> Self {
+        self.streak_grace_days = grace_days.unwrap_or(0);
+        self
+    }
+}
@@ -0,0 +1,141 @@
+//! Completions per month and per year, for the "finished over time" chart.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::server::api::responses::common::ContentTypeFilter;
+use crate::server::api::responses::reading::{FinishedCount, ReadingFinishedData};
+use crate::shelf::statistics::shared;
+use crate::store::memory::ReadingData;
+
+/// Count completions by the month and year of their end date.
+///
+/// Every completion counts, so a re-read adds to the period it finished in,
+/// matching the recap's finished totals. Periods between the first and last
+/// completion are filled with zero counts so the chart has no gaps.
+pub fn finished(reading_data: &ReadingData, scope: ContentTypeFilter) -> ReadingFinishedData {
+    let stats = shared::filter_stats_by_scope(&reading_data.stats_data, scope);
+
+    let mut months: BTreeMap<(i32, u32), usize> = BTreeMap::new();
+    for completions in stats
+        .books
+        .iter()
+        .filter_map(|book| book.completions.as_ref())
+    {
+        for entry in &completions.entries {
+            if let Ok(date) = NaiveDate::parse_from_str(&entry.end_date, "%Y-%m-%d") {
+                *months.entry((date.year(), date.month())).or_default() += 1;
+            }
+        }
+    }
+
+    let (Some(&first), Some(&last)) = (months.keys().next(), months.keys().next_back()) else {
+        return ReadingFinishedData {
+            months: Vec::new(),
+            years: Vec::new(),
+        };
+    };
+
+    let mut month_counts = Vec::new();
+    let mut years: BTreeMap<i32, usize> = (first.0..=last.0).map(|year| (year, 0)).collect();
+    let (mut year, mut month) = first;
+    while (year, month) <= last {
+        let completions = months.get(&(year, month)).copied().unwrap_or(0);
+        *years.entry(year).or_default() += completions;
+        month_counts.push(FinishedCount {
+            period: format!("{year:04}-{month:02}"),
+            completions,
+        });
+        (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+    }
+
+    ReadingFinishedData {
+        months: month_counts,
+        years: years
+            .into_iter()
+            .map(|(year, completions)| FinishedCount {
+                period: year.to_string(),
+                completions,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::models::ContentType;
+    use crate::shelf::statistics::PageScaling;
+    use crate::shelf::time_config::TimeConfig;
+    use crate::source::koreader::types::{BookCompletions, ReadCompletion};
+    use crate::tests::fixtures;
+
+    fn completion(end_date: &str) -> ReadCompletion {
+        ReadCompletion::new("2023-01-01".into(), end_date.into(), 3600, 2, 100)
+    }
+
+    fn reading_data(completions: Vec<Vec<ReadCompletion>>) -> ReadingData {
+        let books = completions
+            .into_iter()
+            .enumerate()
+            .map(|(index, entries)| {
+                let mut book = fixtures::stat_book(
+                    index as i64 + 1,
+                    &format!("md5-{index}"),
+                    100,
+                    ContentType::Book,
+                );
+                book.completions = Some(BookCompletions::new(entries));
+                book
+            })
+            .collect();
+        ReadingData {
+            stats_data: fixtures::statistics_data(books, Vec::new()),
+            time_config: TimeConfig::new(None, 0),
+            stats_config: Default::default(),
+            heatmap_scale_max: None,
+            page_scaling: PageScaling::disabled(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
+        }
+    }
+
+    fn counts(entries: &[FinishedCount]) -> Vec<(&str, usize)> {
+        entries
+            .iter()
+            .map(|entry| (entry.period.as_str(), entry.completions))
+            .collect()
+    }
+
+    #[test]
+    fn rereads_count_per_completion_and_gaps_are_filled() {
+        let data = reading_data(vec![
+            vec![completion("2023-11-20"), completion("2024-02-03")],
+            vec![completion("2024-02-28")],
+        ]);
+
+        let finished = finished(&data, ContentTypeFilter::All);
+        assert_eq!(
+            counts(&finished.months),
+            vec![
+                ("2023-11", 1),
+                ("2023-12", 0),
+                ("2024-01", 0),
+                ("2024-02", 2)
+            ]
+        );
+        assert_eq!(counts(&finished.years), vec![("2023", 1), ("2024", 2)]);
+    }
+
+    #[test]
+    fn no_completions_yield_empty_series() {
+        let finished = finished(&reading_data(Vec::new()), ContentTypeFilter::All);
+        assert!(finished.months.is_empty());
+        assert!(finished.years.is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+//! Subject/genre frequencies across the library (genre cloud).
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::server::api::responses::common::ContentTypeFilter;
+use crate::server::api::responses::reading::{GenreCount, ReadingGenresData};
+use crate::store::sqlite::repo::LibraryRepository;
+
+/// Spellings that are merged into a canonical genre. Keys are normalized
+/// (lowercase, single spaces); values are the display name of the merged tag.
+const GENRE_ALIASES: &[(&str, &str)] = &[
+    ("sci-fi", "Science Fiction"),
+    ("scifi", "Science Fiction"),
+    ("sf", "Science Fiction"),
+    ("science-fiction", "Science Fiction"),
+    ("science fiction", "Science Fiction"),
+    ("sff", "Science Fiction & Fantasy"),
+    ("science fiction & fantasy", "Science Fiction & Fantasy"),
+    ("science fiction and fantasy", "Science Fiction & Fantasy"),
+    ("ya", "Young Adult"),
+    ("young adult", "Young Adult"),
+    ("young adult fiction", "Young Adult"),
+    ("nonfiction", "Non-Fiction"),
+    ("non fiction", "Non-Fiction"),
+    ("non-fiction", "Non-Fiction"),
+    ("biography & autobiography", "Biography"),
+    ("biography", "Biography"),
+    ("autobiography", "Biography"),
+];
+
+/// Count how many items carry each subject, merging case variants and
+/// known aliases. Items without subjects are ignored; an item tagged with
+/// two spellings of the same genre is only counted once.
+pub async fn genres(
+    repo: &LibraryRepository,
+    scope: ContentTypeFilter,
+) -> Result<ReadingGenresData> {
+    let subjects = repo.list_item_subjects(scope.sql_value()).await?;
+    Ok(ReadingGenresData {
+        genres: count_genres(&subjects),
+    })
+}
+
+fn count_genres(items: &[Vec<String>]) -> Vec<GenreCount> {
+    // key -> (item count, spelling -> occurrences)
+    let mut buckets: HashMap<String, (usize, HashMap<String, usize>)> = HashMap::new();
+
+    for subjects in items {
+        let mut seen: Vec<String> = Vec::new();
+        for subject in subjects {
+            let Some((key, spelling)) = normalize_subject(subject) else {
+                continue;
+            };
+            let bucket = buckets.entry(key.clone()).or_default();
+            *bucket.1.entry(spelling).or_default() += 1;
+            if !seen.contains(&key) {
+                bucket.0 += 1;
+                seen.push(key);
+            }
+        }
+    }
+
+    let mut genres: Vec<GenreCount> = buckets
+        .into_iter()
+        .map(|(key, (count, spellings))| GenreCount {
+            name: display_name(&key, spellings),
+            count,
+        })
+        .collect();
+    genres.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    genres
+}
+
+/// Returns the merge key and the trimmed original spelling, or `None` for
+/// blank subjects.
+fn normalize_subject(subject: &str) -> Option<(String, String)> {
+    let spelling = subject.split_whitespace().collect::<Vec<_>>().join(" ");
+    if spelling.is_empty() {
+        return None;
+    }
+
+    let lower = spelling.to_lowercase();
+    let key = match GENRE_ALIASES.iter().find(|(alias, _)| *alias == lower) {
+        Some((_, canonical)) => canonical.to_lowercase(),
+        None => lower,
+    };
+    Some((key, spelling))
+}
+
+/// Aliased genres use their canonical name; everything else uses the most
+/// common spelling seen in the library (alphabetical on ties).
+fn display_name(key: &str, spellings: HashMap<String, usize>) -> String {
+    if let Some((_, canonical)) = GENRE_ALIASES
+        .iter()
+        .find(|(_, canonical)| canonical.to_lowercase() == key)
+    {
+        return (*canonical).to_string();
+    }
+
+    spellings
+        .into_iter()
+        .max_by(|(a_name, a_count), (b_name, b_count)| {
+            a_count.cmp(b_count).then_with(|| b_name.cmp(a_name))
+        })
+        .map(|(name, _)| name)
+        .unwrap_or_else(|| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subjects(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn merges_case_variants_and_aliases() {
+        let items = vec![
+            subjects(&["Sci-Fi", "fantasy"]),
+            subjects(&["Science Fiction"]),
+            subjects(&["Fantasy", "  Fantasy  "]),
+            subjects(&["Horror"]),
+        ];
+
+        let genres = count_genres(&items);
+        let pairs: Vec<(&str, usize)> = genres
+            .iter()
+            .map(|genre| (genre.name.as_str(), genre.count))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![("Fantasy", 2), ("Science Fiction", 2), ("Horror", 1)]
+        );
+    }
+
+    #[test]
+    fn same_genre_twice_on_one_item_counts_once() {
+        let items = vec![subjects(&["Sci-Fi", "science fiction", "SF"])];
+
+        let genres = count_genres(&items);
+        assert_eq!(genres.len(), 1);
+        assert_eq!(genres[0].count, 1);
+    }
+
+    #[test]
+    fn blank_subjects_are_ignored() {
+        let items = vec![subjects(&["", "   "]), Vec::new()];
+        assert!(count_genres(&items).is_empty());
+    }
+}
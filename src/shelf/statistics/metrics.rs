@@ -8,13 +8,10 @@ use crate::server::api::responses::reading::{MetricPoint, ReadingMetricsData};
 use crate::shelf::statistics::compute::scaling::round_pages;
 use crate::shelf::statistics::queries::{MetricsGroupBy, ReadingMetric, ReadingMetricsQuery};
 use crate::shelf::statistics::shared;
-use crate::shelf::time_config::TimeConfig;
+use crate::shelf::time_config::{TimeConfig, WeekStart};
 use crate::source::koreader::types::PageStat;
 use crate::store::memory::ReadingData;
 
-/// Default time gap that separates two reading events into different sessions (seconds).
-const SESSION_GAP_SECONDS: i64 = 300;
-
 /// Compute the metrics response from reading data and a validated query.
 pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> ReadingMetricsData {
     let time_config = shared::resolve_time_config(&reading_data.time_config, query.tz);
@@ -25,7 +22,12 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
         &time_config,
     );
 
-    let all_keys = all_bucket_keys(resolved_from, resolved_to, query.group_by);
+    let all_keys = all_bucket_keys(
+        resolved_from,
+        resolved_to,
+        query.group_by,
+        time_config.week_start,
+    );
 
     // Lazily compute sessions only if needed.
     let needs_sessions = query.metrics.iter().any(|m| {
@@ -37,7 +39,11 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
         )
     });
     let sessions = if needs_sessions {
-        sessions_with_dates(&page_stats, &time_config)
+        sessions_with_dates(
+            &page_stats,
+            &time_config,
+            reading_data.stats_config.session_gap_sec,
+        )
     } else {
         Vec::new()
     };
@@ -50,7 +56,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
                 let mut b: BTreeMap<String, i64> = BTreeMap::new();
                 for stat in &page_stats {
                     let date = time_config.date_for_timestamp(stat.start_time);
-                    let key = bucket_key(date, query.group_by);
+                    let key = bucket_key(date, query.group_by, time_config.week_start);
                     *b.entry(key).or_insert(0) += stat.duration;
                 }
                 b
@@ -61,7 +67,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
                 let mut scaled: BTreeMap<String, f64> = BTreeMap::new();
                 for stat in &page_stats {
                     let date = time_config.date_for_timestamp(stat.start_time);
-                    let key = bucket_key(date, query.group_by);
+                    let key = bucket_key(date, query.group_by, time_config.week_start);
                     let factor = reading_data.page_scaling.factor_for_book_id(stat.id_book);
                     *scaled.entry(key).or_insert(0.0) += factor;
                 }
@@ -73,7 +79,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
             ReadingMetric::Sessions => {
                 let mut b: BTreeMap<String, i64> = BTreeMap::new();
                 for (date, _) in &sessions {
-                    let key = bucket_key(*date, query.group_by);
+                    let key = bucket_key(*date, query.group_by, time_config.week_start);
                     *b.entry(key).or_insert(0) += 1;
                 }
                 b
@@ -89,7 +95,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
                             && end_date >= resolved_from
                             && end_date <= resolved_to
                         {
-                            let key = bucket_key(end_date, query.group_by);
+                            let key = bucket_key(end_date, query.group_by, time_config.week_start);
                             *b.entry(key).or_insert(0) += 1;
                         }
                     }
@@ -99,7 +105,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
             ReadingMetric::AverageSessionDurationSec => {
                 let mut bucket_sessions: BTreeMap<String, Vec<i64>> = BTreeMap::new();
                 for (date, duration) in &sessions {
-                    let key = bucket_key(*date, query.group_by);
+                    let key = bucket_key(*date, query.group_by, time_config.week_start);
                     bucket_sessions.entry(key).or_default().push(*duration);
                 }
                 let mut b: BTreeMap<String, i64> = BTreeMap::new();
@@ -112,7 +118,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
             ReadingMetric::LongestSessionDurationSec => {
                 let mut bucket_sessions: BTreeMap<String, Vec<i64>> = BTreeMap::new();
                 for (date, duration) in &sessions {
-                    let key = bucket_key(*date, query.group_by);
+                    let key = bucket_key(*date, query.group_by, time_config.week_start);
                     bucket_sessions.entry(key).or_default().push(*duration);
                 }
                 let mut b: BTreeMap<String, i64> = BTreeMap::new();
@@ -125,7 +131,7 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
                 let mut days_per_bucket: BTreeMap<String, HashSet<NaiveDate>> = BTreeMap::new();
                 for stat in &page_stats {
                     let date = time_config.date_for_timestamp(stat.start_time);
-                    let key = bucket_key(date, query.group_by);
+                    let key = bucket_key(date, query.group_by, time_config.week_start);
                     days_per_bucket.entry(key).or_default().insert(date);
                 }
                 days_per_bucket
@@ -170,18 +176,23 @@ pub fn metrics(reading_data: &ReadingData, query: ReadingMetricsQuery) -> Readin
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
 /// Map a date to its bucket key string for the given grouping.
-fn bucket_key(date: NaiveDate, group_by: MetricsGroupBy) -> String {
+fn bucket_key(date: NaiveDate, group_by: MetricsGroupBy, week_start: WeekStart) -> String {
     match group_by {
         MetricsGroupBy::Total => "total".to_string(),
         MetricsGroupBy::Day => shared::bucket_key_day(date),
-        MetricsGroupBy::Week => shared::bucket_key_week(date),
+        MetricsGroupBy::Week => shared::bucket_key_week(date, week_start),
         MetricsGroupBy::Month => shared::bucket_key_month(date),
         MetricsGroupBy::Year => shared::bucket_key_year(date),
     }
 }
 
 /// Generate all contiguous bucket keys covering `[from, to]`.
-fn all_bucket_keys(from: NaiveDate, to: NaiveDate, group_by: MetricsGroupBy) -> Vec<String> {
+fn all_bucket_keys(
+    from: NaiveDate,
+    to: NaiveDate,
+    group_by: MetricsGroupBy,
+    week_start: WeekStart,
+) -> Vec<String> {
     let mut keys = Vec::new();
     match group_by {
         MetricsGroupBy::Total => {
@@ -195,11 +206,11 @@ fn all_bucket_keys(from: NaiveDate, to: NaiveDate, group_by: MetricsGroupBy) ->
             }
         }
         MetricsGroupBy::Week => {
-            let mut monday = shared::week_monday(from);
-            let end_monday = shared::week_monday(to);
-            while monday <= end_monday {
-                keys.push(monday.format("%Y-%m-%d").to_string());
-                monday += chrono::Duration::days(7);
+            let mut start = week_start.week_start_date(from);
+            let end_start = week_start.week_start_date(to);
+            while start <= end_start {
+                keys.push(start.format("%Y-%m-%d").to_string());
+                start += chrono::Duration::days(7);
             }
         }
         MetricsGroupBy::Month => {
@@ -229,9 +240,13 @@ fn all_bucket_keys(from: NaiveDate, to: NaiveDate, group_by: MetricsGroupBy) ->
 
 /// Compute reading sessions with their logical start dates.
 ///
-/// Groups page stats by book, identifies sessions using a 5-minute gap threshold,
+/// Groups page stats by book, identifies sessions using the configured gap threshold,
 /// and returns `(session_start_date, session_duration)` pairs.
-fn sessions_with_dates(page_stats: &[PageStat], time_config: &TimeConfig) -> Vec<(NaiveDate, i64)> {
+fn sessions_with_dates(
+    page_stats: &[PageStat],
+    time_config: &TimeConfig,
+    gap_sec: i64,
+) -> Vec<(NaiveDate, i64)> {
     let mut by_book: HashMap<i64, Vec<PageStat>> = HashMap::new();
     for stat in page_stats.iter().filter(|s| s.duration > 0) {
         by_book.entry(stat.id_book).or_default().push(stat.clone());
@@ -247,7 +262,7 @@ fn sessions_with_dates(page_stats: &[PageStat], time_config: &TimeConfig) -> Vec
         let mut last_end = sorted[0].start_time + sorted[0].duration;
 
         for stat in &sorted[1..] {
-            if stat.start_time - last_end <= SESSION_GAP_SECONDS {
+            if stat.start_time - last_end <= gap_sec {
                 session_duration += stat.duration;
             } else {
                 result.push((
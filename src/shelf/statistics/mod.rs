@@ -1,19 +1,29 @@
-//! Reading statistics: calendar, completions, metrics, summary, and available periods.
+//! Reading statistics: abandoned items, calendar, completions, finished counts, genres, metrics, pace, summary, and available periods.
 
+pub mod abandoned;
 pub mod available_periods;
 pub mod calendar;
 pub mod completions;
 pub mod compute;
+pub mod config;
+pub mod finished;
+pub mod genres;
 pub mod metrics;
+pub mod pace;
 pub mod queries;
 pub mod shared;
 pub mod summary;
 pub mod types;
 
+pub use abandoned::abandoned;
 pub use available_periods::available_periods;
 pub use calendar::reading_calendar as calendar;
 pub use completions::reading_completions as completions;
-pub use compute::calculator::{BookStatistics, StatisticsCalculator};
+pub use compute::calculator::{BookStatistics, DurationCap, StatisticsCalculator};
 pub use compute::scaling::PageScaling;
+pub use config::StatisticsConfig;
+pub use finished::finished;
+pub use genres::genres;
 pub use metrics::metrics;
+pub use pace::pace;
 pub use summary::summary;
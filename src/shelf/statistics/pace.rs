@@ -0,0 +1,202 @@
+//! Reading pace distribution: a histogram of pages per hour across sessions.
+
+use std::collections::HashMap;
+
+use crate::server::api::responses::common::ContentTypeFilter;
+use crate::server::api::responses::reading::{PaceBucket, ReadingPaceData};
+use crate::shelf::statistics::compute::sessions;
+use crate::shelf::statistics::shared;
+use crate::source::koreader::types::PageStat;
+use crate::store::memory::ReadingData;
+
+/// Roughly how many buckets the histogram aims for.
+const TARGET_BUCKETS: f64 = 10.0;
+
+/// Sessions above this percentile share one open-ended bucket, so a few
+/// outliers cannot stretch the histogram into mostly empty buckets.
+const OVERFLOW_PERCENTILE: f64 = 0.95;
+
+/// Compute the pages-per-hour distribution of reading sessions.
+///
+/// Sessions without reading time or with a single page turn are skipped,
+/// since they say nothing about reading speed.
+pub fn pace(reading_data: &ReadingData, scope: ContentTypeFilter) -> ReadingPaceData {
+    let stats = shared::filter_stats_by_scope(&reading_data.stats_data, scope);
+    let gap_sec = reading_data.stats_config.session_gap_sec;
+
+    let mut by_book: HashMap<i64, Vec<PageStat>> = HashMap::new();
+    for stat in stats.page_stats.iter().filter(|s| s.duration > 0) {
+        by_book.entry(stat.id_book).or_default().push(stat.clone());
+    }
+
+    let mut paces = Vec::new();
+    for (book_id, book_stats) in &by_book {
+        let factor = reading_data.page_scaling.factor_for_book_id(*book_id);
+        for session in sessions::split_sessions(book_stats, gap_sec) {
+            let duration: i64 = session.iter().map(|stat| stat.duration).sum();
+            if duration <= 0 || session.len() < 2 {
+                continue;
+            }
+            let pages = session.len() as f64 * factor;
+            paces.push(pages * 3600.0 / duration as f64);
+        }
+    }
+
+    build_histogram(paces)
+}
+
+fn build_histogram(mut paces: Vec<f64>) -> ReadingPaceData {
+    if paces.is_empty() {
+        return ReadingPaceData {
+            sessions: 0,
+            median_pages_per_hour: None,
+            buckets: Vec::new(),
+        };
+    }
+    paces.sort_by(f64::total_cmp);
+
+    let min = paces[0];
+    let cutoff_index = ((paces.len() - 1) as f64 * OVERFLOW_PERCENTILE).round() as usize;
+    let cutoff = paces[cutoff_index];
+
+    let width = nice_bucket_width((cutoff - min) / TARGET_BUCKETS);
+    let first = (min / width as f64).floor() as u32 * width;
+    let mut bucket_count = ((cutoff - first as f64) / width as f64).floor() as usize + 1;
+    let max = paces[paces.len() - 1];
+    let has_overflow = max >= (first + bucket_count as u32 * width) as f64;
+    if has_overflow {
+        bucket_count += 1;
+    }
+
+    let mut buckets: Vec<PaceBucket> = (0..bucket_count)
+        .map(|index| {
+            let min_pages_per_hour = first + index as u32 * width;
+            let is_last = index + 1 == bucket_count;
+            PaceBucket {
+                min_pages_per_hour,
+                max_pages_per_hour: (!(is_last && has_overflow))
+                    .then_some(min_pages_per_hour + width),
+                sessions: 0,
+            }
+        })
+        .collect();
+
+    for pace in &paces {
+        let index = ((pace - first as f64) / width as f64).floor() as usize;
+        buckets[index.min(bucket_count - 1)].sessions += 1;
+    }
+
+    ReadingPaceData {
+        sessions: paces.len(),
+        median_pages_per_hour: Some(median(&paces)),
+        buckets,
+    }
+}
+
+/// Round a raw bucket width up to 1, 2, or 5 times a power of ten.
+fn nice_bucket_width(raw: f64) -> u32 {
+    if !raw.is_finite() || raw <= 1.0 {
+        return 1;
+    }
+
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude);
+    step as u32
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    let value = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shelf::models::ContentType;
+    use crate::shelf::statistics::PageScaling;
+    use crate::shelf::time_config::TimeConfig;
+    use crate::tests::fixtures;
+
+    fn reading_data(page_stats: Vec<PageStat>) -> ReadingData {
+        let books = vec![fixtures::stat_book(1, "abc", 300, ContentType::Book)];
+        ReadingData {
+            stats_data: fixtures::statistics_data(books, page_stats),
+            time_config: TimeConfig::new(None, 0),
+            stats_config: Default::default(),
+            heatmap_scale_max: None,
+            page_scaling: PageScaling::disabled(),
+            calendar_color_by: Default::default(),
+            recap_show_empty_months: false,
+        }
+    }
+
+    /// A session of `pages` page turns of `seconds_per_page` each.
+    fn session(start_time: i64, pages: i64, seconds_per_page: i64) -> Vec<PageStat> {
+        (0..pages)
+            .map(|i| {
+                fixtures::page_stat(
+                    1,
+                    i + 1,
+                    start_time + i * seconds_per_page,
+                    seconds_per_page,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_single_page_sessions() {
+        let mut stats = session(0, 10, 60); // 60 pages/hour
+        stats.extend(session(100_000, 1, 600));
+
+        let data = pace(&reading_data(stats), ContentTypeFilter::All);
+        assert_eq!(data.sessions, 1);
+        assert_eq!(data.median_pages_per_hour, Some(60.0));
+        assert_eq!(data.buckets.len(), 1);
+        assert_eq!(data.buckets[0].min_pages_per_hour, 60);
+        assert_eq!(data.buckets[0].sessions, 1);
+    }
+
+    #[test]
+    fn buckets_start_at_the_slowest_session() {
+        // 30, 40, 45, 60 pages/hour.
+        let paces = vec![30.0, 40.0, 45.0, 60.0];
+        let data = build_histogram(paces);
+
+        assert_eq!(data.buckets[0].min_pages_per_hour, 30);
+        assert_eq!(data.buckets[0].max_pages_per_hour, Some(35));
+        let total: usize = data.buckets.iter().map(|bucket| bucket.sessions).sum();
+        assert_eq!(total, 4);
+    }
+
+    #[test]
+    fn outlier_goes_into_open_ended_bucket() {
+        let mut paces: Vec<f64> = (0..40).map(|i| 20.0 + i as f64).collect();
+        paces.push(5000.0);
+
+        let data = build_histogram(paces);
+        assert!(data.buckets.len() <= 12, "{} buckets", data.buckets.len());
+        let last = data.buckets.last().unwrap();
+        assert_eq!(last.max_pages_per_hour, None);
+        assert!(last.sessions >= 1);
+        let total: usize = data.buckets.iter().map(|bucket| bucket.sessions).sum();
+        assert_eq!(total, 41);
+    }
+
+    #[test]
+    fn nice_bucket_width_rounds_up() {
+        assert_eq!(nice_bucket_width(0.4), 1);
+        assert_eq!(nice_bucket_width(3.0), 5);
+        assert_eq!(nice_bucket_width(7.0), 10);
+        assert_eq!(nice_bucket_width(13.0), 20);
+    }
+}
@@ -210,6 +210,7 @@ pub enum CompletionsSelector {
 pub enum CompletionsIncludeToken {
     Summary,
     ShareAssets,
+    TopItems,
 }
 
 impl crate::shelf::token_set::SetToken for CompletionsIncludeToken {
@@ -217,12 +218,13 @@ impl crate::shelf::token_set::SetToken for CompletionsIncludeToken {
         match value {
             "summary" => Some(Self::Summary),
             "share_assets" => Some(Self::ShareAssets),
+            "top_items" => Some(Self::TopItems),
             _ => None,
         }
     }
 
     fn valid_tokens() -> &'static str {
-        "summary, share_assets"
+        "summary, share_assets, top_items"
     }
 }
 
@@ -236,6 +238,10 @@ impl CompletionsIncludeSet {
     pub fn has_share_assets(&self) -> bool {
         self.has(CompletionsIncludeToken::ShareAssets)
     }
+
+    pub fn has_top_items(&self) -> bool {
+        self.has(CompletionsIncludeToken::TopItems)
+    }
 }
 
 // ── Composed query types ──────────────────────────────────────────────────
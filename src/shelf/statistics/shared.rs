@@ -5,13 +5,16 @@ use chrono::{Datelike, NaiveDate};
 use crate::server::api::responses::common::ContentTypeFilter;
 use crate::server::api::responses::library::LibraryContentType;
 use crate::shelf::models::ContentType;
-use crate::shelf::time_config::TimeConfig;
+use crate::shelf::time_config::{TimeConfig, WeekStart};
 use crate::source::koreader::types::{PageStat, StatisticsData};
 
 /// Build a `TimeConfig` with an optional per-request timezone override.
 pub fn resolve_time_config(base: &TimeConfig, tz_override: Option<chrono_tz::Tz>) -> TimeConfig {
     match tz_override {
-        Some(tz) => TimeConfig::new(Some(tz), base.day_start_minutes),
+        Some(tz) => TimeConfig {
+            timezone: Some(tz),
+            ..base.clone()
+        },
         None => base.clone(),
     }
 }
@@ -107,19 +110,17 @@ pub fn count_completions_in_range(
 
 // ── Bucket key helpers ──────────────────────────────────────────────────────
 
-/// Return the Monday of the ISO week containing `date`.
-pub fn week_monday(date: NaiveDate) -> NaiveDate {
-    date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
-}
-
 /// Bucket key for day grouping: `YYYY-MM-DD`.
 pub fn bucket_key_day(date: NaiveDate) -> String {
     date.format("%Y-%m-%d").to_string()
 }
 
-/// Bucket key for week grouping: `YYYY-MM-DD` (Monday of that week).
-pub fn bucket_key_week(date: NaiveDate) -> String {
-    week_monday(date).format("%Y-%m-%d").to_string()
+/// Bucket key for week grouping: `YYYY-MM-DD` (first day of that week).
+pub fn bucket_key_week(date: NaiveDate, week_start: WeekStart) -> String {
+    week_start
+        .week_start_date(date)
+        .format("%Y-%m-%d")
+        .to_string()
 }
 
 /// Bucket key for month grouping: `YYYY-MM`.
@@ -2,13 +2,15 @@
 
 use std::collections::HashMap;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, Weekday};
 
 use crate::server::api::responses::reading::{
-    HeatmapConfig, ReadingOverview, ReadingStreaks, ReadingSummaryData, ResolvedRange, StreakData,
+    DayKindStats, HeatmapConfig, ReadingOverview, ReadingStreaks, ReadingSummaryData,
+    ResolvedRange, StreakData, WeekdaySplit,
 };
 use crate::shelf::statistics::compute::scaling::round_pages;
 use crate::shelf::statistics::compute::sessions;
+use crate::shelf::statistics::compute::streaks;
 use crate::shelf::statistics::queries::ReadingSummaryQuery;
 use crate::shelf::statistics::shared;
 use crate::shelf::time_config::TimeConfig;
@@ -53,13 +55,27 @@ pub fn summary(reading_data: &ReadingData, query: ReadingSummaryQuery) -> Readin
     let most_pages_in_day = daily_page_reads.values().copied().max().unwrap_or(0);
 
     let (average_session_duration_sec, longest_session_duration_sec) =
-        sessions::session_metrics(&page_stats);
-    let session_count = sessions::aggregate_session_durations(&page_stats).len() as i64;
+        sessions::session_metrics(&page_stats, reading_data.stats_config.session_gap_sec);
+    let session_count = sessions::aggregate_session_durations(
+        &page_stats,
+        reading_data.stats_config.session_gap_sec,
+    )
+    .len() as i64;
 
     let (total_completions, items_completed) =
         shared::count_completions_in_range(&stats, &resolved_from, &resolved_to);
 
-    let streaks = compute_streaks(&daily_read_time, &time_config);
+    let streaks = compute_streaks(
+        &daily_read_time,
+        &time_config,
+        reading_data.stats_config.streak_grace_days,
+    );
+    let weekday_split = compute_weekday_split(
+        &daily_read_time,
+        &daily_page_reads,
+        resolved_from,
+        resolved_to,
+    );
     let heatmap_config = HeatmapConfig {
         max_scale_sec: reading_data.heatmap_scale_max.map(|v| v as i64),
     };
@@ -82,14 +98,61 @@ pub fn summary(reading_data: &ReadingData, query: ReadingSummaryQuery) -> Readin
             longest_session_duration_sec,
         },
         streaks,
+        weekday_split,
         heatmap_config,
     }
 }
 
+/// Split reading into weekdays and weekends. Days are the logical local days
+/// the daily maps are keyed by, so late-night reading before the day start
+/// counts toward the previous day's kind. Saturday and Sunday are the weekend
+/// whatever `--week-start` is.
+fn compute_weekday_split(
+    daily_read_time: &HashMap<NaiveDate, i64>,
+    daily_page_reads: &HashMap<NaiveDate, i64>,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> WeekdaySplit {
+    let is_weekend = |date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+
+    let mut totals = [(0i64, 0i64, 0i64); 2];
+    for (date, &read_time) in daily_read_time {
+        let entry = &mut totals[usize::from(is_weekend(*date))];
+        entry.0 += read_time;
+        entry.1 += daily_page_reads.get(date).copied().unwrap_or(0);
+        entry.2 += 1;
+    }
+
+    let mut days_in_range = [0i64; 2];
+    if from <= to {
+        for date in from.iter_days().take_while(|date| *date <= to) {
+            days_in_range[usize::from(is_weekend(date))] += 1;
+        }
+    }
+
+    let stats = |kind: usize| {
+        let (reading_time_sec, pages_read, active_days) = totals[kind];
+        let days = days_in_range[kind].max(1);
+        DayKindStats {
+            reading_time_sec,
+            pages_read,
+            active_days,
+            average_reading_time_sec: reading_time_sec / days,
+            average_pages_read: (pages_read as f64 / days as f64).round() as i64,
+        }
+    };
+
+    WeekdaySplit {
+        weekdays: stats(0),
+        weekends: stats(1),
+    }
+}
+
 /// Compute current and longest reading streaks from daily read time data.
 fn compute_streaks(
     daily_read_time: &HashMap<NaiveDate, i64>,
     time_config: &TimeConfig,
+    grace_days: u16,
 ) -> ReadingStreaks {
     if daily_read_time.is_empty() {
         return ReadingStreaks {
@@ -111,20 +174,7 @@ fn compute_streaks(
 
     let today = time_config.today_date();
 
-    let mut streaks: Vec<(i64, NaiveDate, NaiveDate)> = Vec::new();
-    let mut streak_start = sorted_dates[0];
-    let mut streak_len = 1i64;
-
-    for i in 1..sorted_dates.len() {
-        if sorted_dates[i] == sorted_dates[i - 1] + chrono::Duration::days(1) {
-            streak_len += 1;
-        } else {
-            streaks.push((streak_len, streak_start, sorted_dates[i - 1]));
-            streak_start = sorted_dates[i];
-            streak_len = 1;
-        }
-    }
-    streaks.push((streak_len, streak_start, *sorted_dates.last().unwrap()));
+    let streaks = streaks::streak_runs(&sorted_dates, grace_days);
 
     let longest = streaks
         .iter()
@@ -140,11 +190,10 @@ fn compute_streaks(
             end_date: None,
         });
 
-    // Streak ending today or yesterday counts as "current".
+    // Streak ending today or yesterday (plus grace days) counts as "current".
     let last_reading_date = *sorted_dates.last().unwrap();
-    let days_since_last = (today - last_reading_date).num_days();
 
-    let current = if days_since_last <= 1 {
+    let current = if streaks::is_streak_current(last_reading_date, today, grace_days) {
         streaks
             .iter()
             .find(|&&(_, _, end)| end == last_reading_date)
@@ -168,3 +217,30 @@ fn compute_streaks(
 
     ReadingStreaks { current, longest }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn weekday_split_averages_over_all_days_of_each_kind() {
+        // 2026-03-02 is a Monday; the range covers one full week.
+        let read_time = HashMap::from([(date(2), 600), (date(4), 1200), (date(7), 3600)]);
+        let pages = HashMap::from([(date(2), 5), (date(4), 10), (date(7), 30)]);
+
+        let split = compute_weekday_split(&read_time, &pages, date(2), date(8));
+
+        assert_eq!(split.weekdays.reading_time_sec, 1800);
+        assert_eq!(split.weekdays.active_days, 2);
+        assert_eq!(split.weekdays.average_reading_time_sec, 360);
+        assert_eq!(split.weekdays.average_pages_read, 3);
+        assert_eq!(split.weekends.reading_time_sec, 3600);
+        assert_eq!(split.weekends.active_days, 1);
+        assert_eq!(split.weekends.average_reading_time_sec, 1800);
+        assert_eq!(split.weekends.average_pages_read, 15);
+    }
+}
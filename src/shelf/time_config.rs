@@ -1,16 +1,56 @@
 use anyhow::{Result, anyhow};
-use chrono::{DateTime, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc,
+};
 use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// First day of the week used for weekly grouping, the calendar, and the
+/// heatmap's weekday order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "monday" => Ok(Self::Monday),
+            "sunday" => Ok(Self::Sunday),
+            other => Err(anyhow!(
+                "Invalid --week-start value: {}. Use monday or sunday",
+                other
+            )),
+        }
+    }
+
+    /// First day of the week containing `date`.
+    pub fn week_start_date(self, date: NaiveDate) -> NaiveDate {
+        let offset = match self {
+            Self::Monday => date.weekday().num_days_from_monday(),
+            Self::Sunday => date.weekday().num_days_from_sunday(),
+        };
+        date - Duration::days(offset as i64)
+    }
+}
 
 /// Timezone and logical day-start offset used to map Unix timestamps to reading dates.
 ///
 /// When `timezone` is `None`, the system local timezone is used.
 /// `day_start_minutes` shifts the day boundary (e.g. 240 = 04:00, so reading at
-/// 02:00 counts as the previous calendar day).
+/// 02:00 counts as the previous calendar day), and `week_start` decides where
+/// weekly buckets begin.
 #[derive(Clone, Debug)]
 pub struct TimeConfig {
     pub timezone: Option<Tz>,
     pub day_start_minutes: u16,
+    pub week_start: WeekStart,
+    /// Fixed Unix timestamp used as "now" for reproducible output
+    /// (`--source-date` / `SOURCE_DATE_EPOCH`).
+    pub fixed_now: Option<i64>,
 }
 
 impl TimeConfig {
@@ -18,11 +58,33 @@ impl TimeConfig {
         Self {
             timezone,
             day_start_minutes,
+            week_start: WeekStart::default(),
+            fixed_now: None,
         }
     }
 
-    /// Build from optional CLI strings (timezone IANA name, day start as HH:MM)
-    pub fn from_cli(timezone: &Option<String>, day_start_time: &Option<String>) -> Result<Self> {
+    pub fn with_week_start(mut self, week_start: WeekStart) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn with_fixed_now(mut self, timestamp: Option<i64>) -> Self {
+        self.fixed_now = timestamp;
+        self
+    }
+
+    /// Current Unix timestamp, or the fixed source date when one is set.
+    pub fn now_timestamp(&self) -> i64 {
+        self.fixed_now.unwrap_or_else(|| Utc::now().timestamp())
+    }
+
+    /// Build from optional CLI strings (timezone IANA name, day start as HH:MM,
+    /// week start as `monday`/`sunday`)
+    pub fn from_cli(
+        timezone: &Option<String>,
+        day_start_time: &Option<String>,
+        week_start: &Option<String>,
+    ) -> Result<Self> {
         let tz = match timezone {
             Some(tz_str) if !tz_str.trim().is_empty() => {
                 let parsed: Tz = tz_str.parse().map_err(|_| {
@@ -38,7 +100,12 @@ impl TimeConfig {
             _ => 0u16,
         };
 
-        Ok(Self::new(tz, minutes))
+        let week_start = match week_start {
+            Some(s) if !s.trim().is_empty() => WeekStart::parse(s)?,
+            _ => WeekStart::default(),
+        };
+
+        Ok(Self::new(tz, minutes).with_week_start(week_start))
     }
 
     fn parse_day_start_minutes(s: &str) -> Result<u16> {
@@ -61,17 +128,17 @@ impl TimeConfig {
     }
 
     /// Get the logical local date for a Unix timestamp, applying timezone and day-start offset.
+    ///
+    /// The day-start offset is subtracted from the local wall-clock time rather
+    /// than the instant, so the day boundary stays at the configured local time
+    /// on days where DST shifts the UTC offset.
     pub fn date_for_timestamp(&self, timestamp: i64) -> NaiveDate {
-        if let Some(tz) = self.timezone {
-            let utc_dt = Self::timestamp_utc(timestamp);
-            let dt_tz = tz.from_utc_datetime(&utc_dt.naive_utc());
-            let adjusted = dt_tz - Duration::minutes(self.day_start_minutes as i64);
-            adjusted.date_naive()
-        } else {
-            let dt_local = Self::timestamp_utc(timestamp).with_timezone(&Local);
-            let adjusted = dt_local - Duration::minutes(self.day_start_minutes as i64);
-            adjusted.date_naive()
-        }
+        let utc_dt = Self::timestamp_utc(timestamp);
+        let wall_clock = match self.timezone {
+            Some(tz) => tz.from_utc_datetime(&utc_dt.naive_utc()).naive_local(),
+            None => utc_dt.with_timezone(&Local).naive_local(),
+        };
+        (wall_clock - Duration::minutes(self.day_start_minutes as i64)).date()
     }
 
     /// Format a timestamp as YYYY-MM-DD under configured timezone/day-start.
@@ -83,8 +150,7 @@ impl TimeConfig {
 
     /// Today's logical date under configured timezone/day-start.
     pub fn today_date(&self) -> NaiveDate {
-        let now_ts = Utc::now().timestamp();
-        self.date_for_timestamp(now_ts)
+        self.date_for_timestamp(self.now_timestamp())
     }
 
     fn timestamp_utc(timestamp: i64) -> DateTime<Utc> {
@@ -136,7 +202,18 @@ impl TimeConfig {
 
     /// Format current time as an RFC3339 instant in the configured timezone.
     pub fn now_rfc3339(&self) -> String {
-        self.format_timestamp_rfc3339(Utc::now().timestamp())
+        self.format_timestamp_rfc3339(self.now_timestamp())
+    }
+
+    /// Unix timestamp of a timezone-less wall-clock datetime under the configured timezone.
+    pub fn naive_datetime_timestamp(&self, value: &str) -> Option<i64> {
+        let parsed = Self::parse_naive_datetime(value)?;
+        match self.timezone {
+            Some(tz) => Self::choose_local_datetime(tz.from_local_datetime(&parsed))
+                .map(|date_time| date_time.timestamp()),
+            None => Self::choose_local_datetime(Local.from_local_datetime(&parsed))
+                .map(|date_time| date_time.timestamp()),
+        }
     }
 
     /// Interpret a timezone-less wall-clock datetime under the configured timezone.
@@ -153,7 +230,133 @@ impl TimeConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::TimeConfig;
+    use super::{TimeConfig, WeekStart};
+    use chrono::NaiveDate;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn week_start_date_follows_configured_first_day() {
+        // 2024-03-13 is a Wednesday.
+        assert_eq!(
+            WeekStart::Monday.week_start_date(date("2024-03-13")),
+            date("2024-03-11")
+        );
+        assert_eq!(
+            WeekStart::Sunday.week_start_date(date("2024-03-13")),
+            date("2024-03-10")
+        );
+        // A Sunday starts its own week when weeks start on Sunday, but
+        // belongs to the previous Monday-start week.
+        assert_eq!(
+            WeekStart::Sunday.week_start_date(date("2024-03-17")),
+            date("2024-03-17")
+        );
+        assert_eq!(
+            WeekStart::Monday.week_start_date(date("2024-03-17")),
+            date("2024-03-11")
+        );
+    }
+
+    #[test]
+    fn from_cli_parses_week_start() {
+        let config = TimeConfig::from_cli(&None, &None, &Some("Sunday".to_string()))
+            .expect("week start should parse");
+        assert_eq!(config.week_start, WeekStart::Sunday);
+
+        let config = TimeConfig::from_cli(&None, &None, &None).expect("defaults should parse");
+        assert_eq!(config.week_start, WeekStart::Monday);
+
+        assert!(TimeConfig::from_cli(&None, &None, &Some("friday".to_string())).is_err());
+    }
+
+    fn utc(day_start_minutes: u16) -> TimeConfig {
+        let timezone = "UTC".parse().expect("timezone should parse");
+        TimeConfig::new(Some(timezone), day_start_minutes)
+    }
+
+    #[test]
+    fn reading_before_day_start_counts_toward_previous_day() {
+        let config = utc(240);
+
+        // 2024-03-15 02:00 UTC, before the 04:00 day start.
+        assert_eq!(config.date_for_timestamp(1710468000), date("2024-03-14"));
+        // 2024-03-15 04:00 UTC, exactly at the day start.
+        assert_eq!(config.date_for_timestamp(1710475200), date("2024-03-15"));
+        // 2024-03-15 05:00 UTC, after the day start.
+        assert_eq!(config.date_for_timestamp(1710478800), date("2024-03-15"));
+        assert_eq!(config.format_date(1710468000), "2024-03-14");
+    }
+
+    #[test]
+    fn day_start_after_noon_shifts_the_whole_morning() {
+        let config = utc(13 * 60);
+
+        // 2024-03-15 12:59 UTC still belongs to 2024-03-14.
+        assert_eq!(config.date_for_timestamp(1710507540), date("2024-03-14"));
+        // 2024-03-15 13:00 UTC starts 2024-03-15.
+        assert_eq!(config.date_for_timestamp(1710507600), date("2024-03-15"));
+    }
+
+    #[test]
+    fn from_cli_parses_day_start_time() {
+        let config = TimeConfig::from_cli(&None, &Some("04:00".to_string()), &None)
+            .expect("day start should parse");
+        assert_eq!(config.day_start_minutes, 240);
+
+        let config = TimeConfig::from_cli(&None, &Some("23:59".to_string()), &None)
+            .expect("late day start should parse");
+        assert_eq!(config.day_start_minutes, 23 * 60 + 59);
+
+        assert!(TimeConfig::from_cli(&None, &Some("24:00".to_string()), &None).is_err());
+        assert!(TimeConfig::from_cli(&None, &Some("4".to_string()), &None).is_err());
+    }
+
+    fn new_york(day_start_minutes: u16) -> TimeConfig {
+        let timezone = "America/New_York".parse().expect("timezone should parse");
+        TimeConfig::new(Some(timezone), day_start_minutes)
+    }
+
+    #[test]
+    fn day_start_uses_wall_clock_across_spring_forward() {
+        // Clocks jump from 02:00 EST to 03:00 EDT on 2024-03-10.
+        let config = new_york(240);
+
+        // 2024-03-10 03:59 EDT (07:59 UTC) is still before the 04:00 day start.
+        assert_eq!(config.date_for_timestamp(1710057540), date("2024-03-09"));
+        // 2024-03-10 04:00 EDT (08:00 UTC) starts the new logical day.
+        assert_eq!(config.date_for_timestamp(1710057600), date("2024-03-10"));
+        // 2024-03-10 04:30 EDT (08:30 UTC).
+        assert_eq!(config.date_for_timestamp(1710059400), date("2024-03-10"));
+    }
+
+    #[test]
+    fn day_start_uses_wall_clock_across_fall_back() {
+        // Clocks fall back from 02:00 EDT to 01:00 EST on 2024-11-03.
+        let config = new_york(240);
+
+        // 2024-11-03 03:30 EST (08:30 UTC) is before the 04:00 day start.
+        assert_eq!(config.date_for_timestamp(1730622600), date("2024-11-02"));
+        // 2024-11-03 04:00 EST (09:00 UTC) starts the new logical day.
+        assert_eq!(config.date_for_timestamp(1730624400), date("2024-11-03"));
+    }
+
+    #[test]
+    fn midnight_boundary_is_unaffected_by_dst() {
+        let config = new_york(0);
+
+        // 2024-03-09 23:59 EST (04:59 UTC) and 2024-03-10 00:00 EST (05:00 UTC).
+        assert_eq!(config.date_for_timestamp(1710046740), date("2024-03-09"));
+        assert_eq!(config.date_for_timestamp(1710046800), date("2024-03-10"));
+        // 2024-11-03 23:59 EST (2024-11-04 04:59 UTC) and the next midnight.
+        assert_eq!(config.date_for_timestamp(1730696340), date("2024-11-03"));
+        assert_eq!(config.date_for_timestamp(1730696400), date("2024-11-04"));
+        // Both 01:30 readings on the repeated hour land on the same day.
+        assert_eq!(config.date_for_timestamp(1730611800), date("2024-11-03"));
+        assert_eq!(config.date_for_timestamp(1730615400), date("2024-11-03"));
+    }
 
     #[test]
     fn format_timestamp_rfc3339_respects_configured_timezone() {
@@ -176,4 +379,12 @@ mod tests {
             Some("2024-03-15T14:30:00+00:00".to_string())
         );
     }
+
+    #[test]
+    fn fixed_now_drives_current_time_and_today() {
+        let config = utc(240).with_fixed_now(Some(1710468000));
+
+        assert_eq!(config.now_rfc3339(), "2024-03-15T02:00:00+00:00");
+        assert_eq!(config.today_date(), date("2024-03-14"));
+    }
 }
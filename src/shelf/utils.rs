@@ -39,3 +39,212 @@ pub fn sanitize_html(input: &str) -> String {
         .clean(&decoded)
         .to_string()
 }
+
+/// Whether sanitized description HTML still contains tags. Text that was not
+/// markup comes out of `sanitize_html` with `<` escaped, so any `<` left is
+/// the start of a kept tag.
+pub fn contains_html_tags(sanitized: &str) -> bool {
+    sanitized
+        .match_indices('<')
+        .any(|(i, _)| sanitized[i + 1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/'))
+}
+
+/// Render a description that went through `sanitize_html` as Markdown. The
+/// entities the sanitizer added are decoded first so Markdown syntax such as
+/// `> quote` survives, and the rendered HTML is sanitized again.
+pub fn render_markdown_description(sanitized: &str) -> String {
+    let source = sanitized
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&");
+
+    let mut html = String::with_capacity(source.len() * 2);
+    pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(&source));
+    sanitize_html(html.trim())
+}
+
+/// Shorten sanitized HTML to about `max_chars` characters of visible text,
+/// ending at a word or tag boundary. Tags and entities count as markup and
+/// are never cut; elements left open are closed after the ellipsis. Returns
+/// `None` when the text already fits.
+pub fn truncate_html(html: &str, max_chars: usize) -> Option<String> {
+    let mut open_tags: Vec<&str> = Vec::new();
+    // Byte offset and open elements at the last place the text may end.
+    let mut boundary: Option<(usize, Vec<&str>)> = None;
+    let mut visible = 0;
+    let mut pos = 0;
+
+    while pos < html.len() {
+        let rest = &html[pos..];
+        if let Some(markup) = rest.strip_prefix('<') {
+            if markup.starts_with('/') {
+                boundary = Some((pos, open_tags.clone()));
+            }
+            let (tag, len) = match tag_end(rest) {
+                Some(len) => (&markup[..len - 2], len),
+                None => (markup, rest.len()),
+            };
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = tag_name(name);
+                if let Some(index) = open_tags.iter().rposition(|open| *open == name) {
+                    open_tags.truncate(index);
+                }
+            } else if !tag.ends_with('/') {
+                let name = tag_name(tag);
+                if !name.is_empty() && !name.eq_ignore_ascii_case("br") {
+                    open_tags.push(name);
+                }
+            }
+            pos += len;
+            continue;
+        }
+
+        let len = if rest.starts_with('&') {
+            rest.find(';').map_or(1, |semi| semi + 1)
+        } else {
+            rest.chars().next().map_or(1, char::len_utf8)
+        };
+
+        let is_space = rest.starts_with(char::is_whitespace);
+        if visible >= max_chars && !is_space {
+            let (cut, open) = boundary.unwrap_or((pos, open_tags));
+            let mut truncated = html[..cut].trim_end().to_string();
+            truncated.push('…');
+            for name in open.iter().rev() {
+                truncated.push_str("</");
+                truncated.push_str(name);
+                truncated.push('>');
+            }
+            return Some(truncated);
+        }
+
+        if is_space {
+            boundary = Some((pos, open_tags.clone()));
+        }
+        visible += 1;
+        pos += len;
+    }
+
+    None
+}
+
+/// Length of the tag at the start of `html`, including both brackets.
+/// Brackets inside quoted attribute values do not end the tag.
+fn tag_end(html: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in html.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tag_name(tag: &str) -> &str {
+    let end = tag
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .unwrap_or(tag.len());
+    &tag[..end]
+}
+
+/// Decode `%XX` escapes (as used in URLs and EPUB paths). Invalid escapes are
+/// kept as-is.
+pub fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2]))
+        {
+            output.push(high * 16 + low);
+            i += 3;
+            continue;
+        }
+
+        output.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kept_tags_are_detected_but_escaped_text_is_not() {
+        assert!(contains_html_tags(&sanitize_html(
+            "<p>A <em>good</em> book</p>"
+        )));
+        assert!(!contains_html_tags(&sanitize_html("Plain *Markdown* text")));
+        assert!(!contains_html_tags(&sanitize_html("if a < b and b > c")));
+    }
+
+    #[test]
+    fn markdown_descriptions_render_to_html() {
+        let sanitized = sanitize_html("A *very* **good** book.\n\n> Quoted & praised");
+        assert_eq!(
+            render_markdown_description(&sanitized),
+            "<p>A <em>very</em> <strong>good</strong> book.</p>\n<blockquote>\n<p>Quoted &amp; praised</p>\n</blockquote>"
+        );
+    }
+
+    #[test]
+    fn markdown_output_is_still_sanitized() {
+        let sanitized = sanitize_html(
+            "[click](javascript:alert(1)) <img src=x onerror=alert(1)>\n\n&lt;script&gt;alert(1)&lt;/script&gt;",
+        );
+        let rendered = render_markdown_description(&sanitized);
+        assert!(!rendered.contains("javascript:"), "{rendered}");
+        assert!(!rendered.contains("<img"), "{rendered}");
+        assert!(!rendered.contains("<script"), "{rendered}");
+        assert!(!rendered.contains("onerror"), "{rendered}");
+    }
+
+    #[test]
+    fn short_html_is_not_truncated() {
+        assert_eq!(truncate_html("<p>A short blurb.</p>", 100), None);
+        assert_eq!(truncate_html("<p>Exactly ten</p>\n", 11), None);
+    }
+
+    #[test]
+    fn truncation_ends_at_a_word_and_closes_open_tags() {
+        let html = "<p>The <em>first volume</em> of a long saga.</p><p>More text.</p>";
+        assert_eq!(
+            truncate_html(html, 14).as_deref(),
+            Some("<p>The <em>first…</em></p>")
+        );
+        assert_eq!(
+            truncate_html(html, 32).as_deref(),
+            Some("<p>The <em>first volume</em> of a long saga.…</p>")
+        );
+    }
+
+    #[test]
+    fn truncation_never_splits_tags_or_entities() {
+        let html =
+            r#"<p>Tom&amp;Jerry <a href="https://example.com/?a=1&amp;b=>2">link text</a></p>"#;
+        assert_eq!(truncate_html(html, 4).as_deref(), Some("<p>Tom&amp;…</p>"));
+        assert_eq!(
+            truncate_html(html, 14).as_deref(),
+            Some(r#"<p>Tom&amp;Jerry <a href="https://example.com/?a=1&amp;b=>2">link…</a></p>"#)
+        );
+    }
+}
@@ -51,6 +51,15 @@ impl FileFingerprint {
     }
 }
 
+/// Unix timestamp (seconds) of when a file was added: its creation time where
+/// the platform records one, otherwise its modification time.
+pub fn file_added_unix(path: &Path) -> Option<i64> {
+    let metadata = fs::metadata(path).ok()?;
+    let time = metadata.created().or_else(|_| metadata.modified()).ok()?;
+    let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ItemFingerprints {
     pub book_file: FileFingerprint,
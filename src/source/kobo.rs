@@ -1,4 +1,4 @@
-use crate::source::sqlite_snapshot::copy_sqlite_snapshot;
+use crate::source::sqlite_snapshot::{SNAPSHOT_BUSY_TIMEOUT, copy_sqlite_snapshot};
 use anyhow::{Context, Result};
 use log::{debug, info, warn};
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
@@ -45,7 +45,8 @@ impl KoboDbParser {
 
         let url = format!("sqlite:{}?mode=ro", temp_db_path.display());
         let options = SqliteConnectOptions::from_str(&url)
-            .with_context(|| format!("Failed to parse Kobo DB URL for {:?}", temp_db_path))?;
+            .with_context(|| format!("Failed to parse Kobo DB URL for {:?}", temp_db_path))?
+            .busy_timeout(SNAPSHOT_BUSY_TIMEOUT);
 
         let pool = SqlitePoolOptions::new()
             .max_connections(1)
@@ -1,14 +1,56 @@
 use crate::source::koreader::types::{PageStat, StatBook, StatisticsData};
-use crate::source::sqlite_snapshot::copy_sqlite_snapshot;
+use crate::source::sqlite_snapshot::{SNAPSHOT_BUSY_TIMEOUT, copy_sqlite_snapshot};
 use anyhow::{Context, Result};
 use log::{debug, info, warn};
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 use sqlx::{Row, SqlitePool};
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use tempfile::TempDir;
 
+/// Newest KOReader statistics schema (`PRAGMA user_version`) KoShelf knows.
+const KNOWN_SCHEMA_VERSION: i64 = 20221111;
+
+/// `book` columns KoShelf reads, and the ones it knows to ignore.
+const BOOK_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "authors",
+    "notes",
+    "last_open",
+    "highlights",
+    "pages",
+    "md5",
+    "total_read_time",
+    "total_read_pages",
+];
+const BOOK_IGNORED_COLUMNS: &[&str] = &["series", "language"];
+
+/// Page stat columns KoShelf reads, and the ones it knows to ignore.
+const PAGE_STAT_COLUMNS: &[&str] = &["id_book", "page", "start_time", "duration"];
+const PAGE_STAT_IGNORED_COLUMNS: &[&str] = &["total_pages"];
+
+/// How page-level reading history is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageStatLayout {
+    /// Before schema 20201010: a plain `page_stat` table.
+    Legacy,
+    /// Schema 20201010+: raw rows in `page_stat_data`, read through the
+    /// rescaling `page_stat` view.
+    Current,
+}
+
+impl PageStatLayout {
+    fn table(self) -> &'static str {
+        match self {
+            Self::Legacy => "page_stat",
+            Self::Current => "page_stat_data",
+        }
+    }
+}
+
 /// Reads KOReader's `statistics.sqlite3` database to extract book metadata and page-level reading history.
 pub struct StatisticsParser;
 
@@ -66,7 +108,8 @@ impl StatisticsParser {
         let mode = if rest.is_empty() { "ro" } else { "rw" };
         let url = format!("sqlite:{}?mode={}", temp_db_path.display(), mode);
         let options = SqliteConnectOptions::from_str(&url)
-            .with_context(|| format!("Failed to parse statistics DB URL for {:?}", temp_db_path))?;
+            .with_context(|| format!("Failed to parse statistics DB URL for {:?}", temp_db_path))?
+            .busy_timeout(SNAPSHOT_BUSY_TIMEOUT);
 
         let pool = SqlitePoolOptions::new()
             .max_connections(1)
@@ -103,10 +146,21 @@ impl StatisticsParser {
             .context("Failed to recompute book totals after merging statistics databases")?;
         }
 
+        Self::check_schema(&pool, primary).await?;
+
         let mut books = Self::parse_books(&pool).await?;
         let mut page_stats = Self::parse_page_stats(&pool).await?;
         pool.close().await;
 
+        if !books.is_empty() && page_stats.is_empty() {
+            warn!(
+                "Statistics database {:?} has {} books but no readable page stats; \
+                 reading history will be empty",
+                primary,
+                books.len()
+            );
+        }
+
         Self::deduplicate_by_md5(&mut books, &mut page_stats);
 
         let mut stats_by_md5 = HashMap::new();
@@ -129,6 +183,97 @@ impl StatisticsParser {
         Ok(stats_data)
     }
 
+    /// Detect the schema version and page stat layout, and fail loudly when
+    /// columns KoShelf reads are missing instead of returning empty stats.
+    /// Unknown extra columns only produce a warning, since KOReader adds
+    /// columns KoShelf does not need.
+    async fn check_schema(pool: &SqlitePool, source: &Path) -> Result<()> {
+        let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(pool)
+            .await
+            .with_context(|| format!("Failed to read schema version of {:?}", source))?;
+        let layout = if Self::table_columns(pool, "page_stat_data")
+            .await?
+            .is_empty()
+        {
+            PageStatLayout::Legacy
+        } else {
+            PageStatLayout::Current
+        };
+        debug!(
+            "Statistics database {:?}: schema version {}, {:?} page stat layout",
+            source, version, layout
+        );
+
+        if version > KNOWN_SCHEMA_VERSION {
+            warn!(
+                "Statistics database {:?} uses KOReader schema version {}, newer than the \
+                 latest supported version {}; some statistics may be missing",
+                source, version, KNOWN_SCHEMA_VERSION
+            );
+        }
+
+        for (table, expected, ignored) in [
+            ("book", BOOK_COLUMNS, BOOK_IGNORED_COLUMNS),
+            (layout.table(), PAGE_STAT_COLUMNS, PAGE_STAT_IGNORED_COLUMNS),
+        ] {
+            let columns = Self::table_columns(pool, table).await?;
+            let missing: Vec<&str> = expected
+                .iter()
+                .copied()
+                .filter(|column| !columns.iter().any(|c| c == column))
+                .collect();
+            if !missing.is_empty() {
+                anyhow::bail!(
+                    "Statistics database {:?} has an unsupported KOReader schema \
+                     (version {}, {:?} layout): table `{}` is missing columns {}",
+                    source,
+                    version,
+                    layout,
+                    table,
+                    missing.join(", ")
+                );
+            }
+
+            let unknown: Vec<&str> = columns
+                .iter()
+                .map(String::as_str)
+                .filter(|column| !expected.contains(column) && !ignored.contains(column))
+                .collect();
+            if !unknown.is_empty() {
+                warn!(
+                    "Statistics database {:?} (schema version {}) has unknown columns in `{}`: {}; \
+                     KOReader may have changed its schema",
+                    source,
+                    version,
+                    table,
+                    unknown.join(", ")
+                );
+            }
+        }
+
+        if layout == PageStatLayout::Current
+            && Self::table_columns(pool, "page_stat").await?.is_empty()
+        {
+            anyhow::bail!(
+                "Statistics database {:?} (schema version {}) has `page_stat_data` but no `page_stat` view",
+                source,
+                version
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Column names of a table or view, empty when it does not exist.
+    async fn table_columns(pool: &SqlitePool, table: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar("SELECT name FROM pragma_table_info(?1)")
+            .bind(table)
+            .fetch_all(pool)
+            .await
+            .with_context(|| format!("Failed to inspect statistics table `{}`", table))
+    }
+
     /// Merging needs `page_stat_data` (raw rows with total_pages and the
     /// UNIQUE(id_book, page, start_time) constraint), present since KOReader
     /// schema 20201010 (release 2020.10).
@@ -294,6 +439,7 @@ impl StatisticsParser {
                 .try_get("total_read_pages")
                 .context("total_read_pages")?,
             completions: None,
+            removed: false,
         })
     }
 
@@ -334,13 +480,20 @@ impl StatisticsParser {
     /// metadata creates a second row for the same file. We pick the most
     /// recently opened entry as canonical, sum reading stats, and remap
     /// page_stats so downstream code sees a single book per md5.
-    fn deduplicate_by_md5(books: &mut Vec<StatBook>, page_stats: &mut [PageStat]) {
+    ///
+    /// The same happens when two devices registered the file under different
+    /// ids. Their pagination may differ, and the `page_stat` view scales each
+    /// row to its own book's page count, so remapped rows are rescaled to the
+    /// canonical page count. Otherwise completion detection would compare
+    /// pages from two scales.
+    fn deduplicate_by_md5(books: &mut Vec<StatBook>, page_stats: &mut Vec<PageStat>) {
         let mut md5_groups: HashMap<String, Vec<usize>> = HashMap::new();
         for (idx, book) in books.iter().enumerate() {
             md5_groups.entry(book.md5.clone()).or_default().push(idx);
         }
 
-        let mut id_remap: HashMap<i64, i64> = HashMap::new();
+        // duplicate id -> (canonical id, (duplicate pages, canonical pages))
+        let mut id_remap: HashMap<i64, (i64, Option<(i64, i64)>)> = HashMap::new();
         let mut indices_to_remove: HashSet<usize> = HashSet::new();
 
         for indices in md5_groups.values() {
@@ -374,7 +527,11 @@ impl StatisticsParser {
                     *canon = (*canon).max(h);
                 }
 
-                id_remap.insert(books[idx].id, books[canonical_idx].id);
+                let rescale = books[idx]
+                    .pages
+                    .zip(books[canonical_idx].pages)
+                    .filter(|&(from, to)| from > 0 && to > 0 && from != to);
+                id_remap.insert(books[idx].id, (books[canonical_idx].id, rescale));
                 indices_to_remove.insert(idx);
             }
         }
@@ -383,11 +540,19 @@ impl StatisticsParser {
             return;
         }
 
+        let mut rescaled = false;
         for stat in page_stats.iter_mut() {
-            if let Some(&new_id) = id_remap.get(&stat.id_book) {
+            if let Some(&(new_id, rescale)) = id_remap.get(&stat.id_book) {
                 stat.id_book = new_id;
+                if let Some((from, to)) = rescale {
+                    stat.page = ((stat.page - 1).max(0) * to / from + 1).min(to);
+                    rescaled = true;
+                }
             }
         }
+        if rescaled {
+            Self::collapse_page_stats(page_stats);
+        }
 
         let mut remove_sorted: Vec<usize> = indices_to_remove.into_iter().collect();
         remove_sorted.sort_unstable_by(|a, b| b.cmp(a));
@@ -395,6 +560,23 @@ impl StatisticsParser {
             books.remove(idx);
         }
     }
+
+    /// Fold rows that rescaling mapped onto the same page and start time into
+    /// one, summing their durations like the `page_stat` view splits them.
+    fn collapse_page_stats(page_stats: &mut Vec<PageStat>) {
+        let mut index_by_key: HashMap<(i64, i64, i64), usize> = HashMap::new();
+        let mut collapsed: Vec<PageStat> = Vec::with_capacity(page_stats.len());
+        for stat in page_stats.drain(..) {
+            match index_by_key.entry((stat.id_book, stat.page, stat.start_time)) {
+                Entry::Occupied(entry) => collapsed[*entry.get()].duration += stat.duration,
+                Entry::Vacant(entry) => {
+                    entry.insert(collapsed.len());
+                    collapsed.push(stat);
+                }
+            }
+        }
+        *page_stats = collapsed;
+    }
 }
 
 #[cfg(test)]
@@ -478,4 +660,184 @@ mod tests {
 
         pool.close().await;
     }
+
+    /// Open a WAL-mode database with the KOReader 2020.10+ tables KoShelf reads
+    /// and automatic checkpoints disabled, so writes stay in the `-wal` file.
+    async fn open_wal_statistics_db(path: &std::path::Path) -> sqlx::SqlitePool {
+        let url = format!("sqlite:{}?mode=rwc", path.display());
+        let options = SqliteConnectOptions::from_str(&url).expect("sqlite options");
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .expect("sqlite pool");
+
+        for statement in [
+            "PRAGMA journal_mode=WAL",
+            "PRAGMA wal_autocheckpoint=0",
+            "CREATE TABLE book (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                title TEXT,
+                authors TEXT,
+                notes INTEGER,
+                last_open INTEGER,
+                highlights INTEGER,
+                pages INTEGER,
+                md5 TEXT,
+                total_read_time INTEGER,
+                total_read_pages INTEGER
+            )",
+            "CREATE TABLE page_stat_data (
+                id_book INTEGER,
+                page INTEGER NOT NULL DEFAULT 0,
+                start_time INTEGER NOT NULL DEFAULT 0,
+                duration INTEGER NOT NULL DEFAULT 0,
+                total_pages INTEGER NOT NULL DEFAULT 0,
+                UNIQUE (id_book, page, start_time)
+            )",
+            "CREATE VIEW page_stat AS
+             SELECT id_book, page, start_time, duration FROM page_stat_data",
+            // Checkpoint the schema so only the rows below live in the WAL.
+            "PRAGMA wal_checkpoint(TRUNCATE)",
+        ] {
+            pool.execute(statement).await.expect("schema statement");
+        }
+        pool
+    }
+
+    #[tokio::test]
+    async fn parse_merged_reads_uncheckpointed_page_stat_data() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let paths = [
+            temp_dir.path().join("kindle.sqlite3"),
+            temp_dir.path().join("kobo.sqlite3"),
+        ];
+        let mut pools = Vec::new();
+        for (index, path) in paths.iter().enumerate() {
+            let pool = open_wal_statistics_db(path).await;
+            pool.execute(
+                "INSERT INTO book
+                 (title, authors, notes, last_open, highlights, pages, md5, total_read_time, total_read_pages)
+                 VALUES ('Shared', 'Author', 0, 10, 0, 100, 'md5-s', 0, 0)",
+            )
+            .await
+            .expect("book row");
+            // Each device read a different page after its last checkpoint.
+            sqlx::query(
+                "INSERT INTO page_stat_data (id_book, page, start_time, duration, total_pages)
+                 VALUES (1, ?1, ?2, 60, 100)",
+            )
+            .bind(index as i64 + 1)
+            .bind(1_000 + index as i64 * 1_000)
+            .execute(&pool)
+            .await
+            .expect("page_stat_data row");
+
+            let wal = path.with_file_name(format!(
+                "{}-wal",
+                path.file_name().unwrap().to_string_lossy()
+            ));
+            assert!(
+                std::fs::metadata(&wal).expect("wal file").len() > 0,
+                "test setup should leave rows in the WAL"
+            );
+            pools.push(pool);
+        }
+
+        let single = StatisticsParser::parse(&paths[0])
+            .await
+            .expect("parse stats db");
+        assert_eq!(single.page_stats.len(), 1);
+
+        let merged = StatisticsParser::parse_merged(&paths)
+            .await
+            .expect("merge stats dbs");
+        assert_eq!(merged.books.len(), 1);
+        let mut pages: Vec<i64> = merged.page_stats.iter().map(|stat| stat.page).collect();
+        pages.sort_unstable();
+        assert_eq!(pages, vec![1, 2]);
+        assert_eq!(merged.books[0].total_read_time, Some(120));
+
+        for pool in pools {
+            pool.close().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn parse_tolerates_unknown_columns() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let path = temp_dir.path().join("statistics.sqlite3");
+        let pool = open_wal_statistics_db(&path).await;
+        for statement in [
+            "PRAGMA user_version = 20221111",
+            "ALTER TABLE book ADD COLUMN rating INTEGER",
+            "INSERT INTO book (title, authors, pages, md5) VALUES ('Book', 'Author', 10, 'md5')",
+            "INSERT INTO page_stat_data (id_book, page, start_time, duration, total_pages)
+             VALUES (1, 1, 1000, 60, 10)",
+        ] {
+            pool.execute(statement).await.expect("setup statement");
+        }
+
+        let data = StatisticsParser::parse(&path)
+            .await
+            .expect("parse stats db");
+        assert_eq!(data.books.len(), 1);
+        assert_eq!(data.page_stats.len(), 1);
+        pool.close().await;
+    }
+
+    #[tokio::test]
+    async fn parse_rejects_schema_missing_read_columns() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let path = temp_dir.path().join("statistics.sqlite3");
+        let pool = open_wal_statistics_db(&path).await;
+        for statement in [
+            "PRAGMA user_version = 20990101",
+            "ALTER TABLE page_stat_data RENAME COLUMN duration TO period",
+            "DROP VIEW page_stat",
+            "CREATE VIEW page_stat AS
+             SELECT id_book, page, start_time, period FROM page_stat_data",
+        ] {
+            pool.execute(statement).await.expect("setup statement");
+        }
+
+        let err = StatisticsParser::parse(&path).await.unwrap_err();
+        let message = format!("{err:#}");
+        assert!(message.contains("version 20990101"), "{message}");
+        assert!(message.contains("`page_stat_data`"), "{message}");
+        assert!(message.contains("duration"), "{message}");
+        pool.close().await;
+    }
+
+    #[test]
+    fn dedup_rescales_pages_from_other_paginations() {
+        use crate::shelf::models::ContentType;
+        use crate::tests::fixtures;
+
+        // Device B paginates the same file into twice as many pages.
+        let mut device_a = fixtures::stat_book(1, "shared", 100, ContentType::Book);
+        device_a.last_open = Some(200);
+        let device_b = fixtures::stat_book(2, "shared", 200, ContentType::Book);
+        let mut books = vec![device_a, device_b];
+        let mut page_stats = vec![
+            fixtures::page_stat(1, 10, 5_000, 60),
+            // The view split one raw row over pages 3 and 4 of device B.
+            fixtures::page_stat(2, 3, 1_000, 20),
+            fixtures::page_stat(2, 4, 1_000, 20),
+            fixtures::page_stat(2, 200, 2_000, 30),
+        ];
+
+        StatisticsParser::deduplicate_by_md5(&mut books, &mut page_stats);
+
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[0].id, 1);
+        let rows: Vec<(i64, i64, i64, i64)> = page_stats
+            .iter()
+            .map(|s| (s.id_book, s.page, s.start_time, s.duration))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![(1, 10, 5_000, 60), (1, 2, 1_000, 40), (1, 100, 2_000, 30)]
+        );
+    }
 }
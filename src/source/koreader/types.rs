@@ -109,6 +109,10 @@ pub struct StatBook {
     #[serde(skip_serializing)]
     pub total_read_pages: Option<i64>,
     pub completions: Option<BookCompletions>,
+    /// The book's file is no longer in the library but its statistics were
+    /// kept via `--keep-ghost-stats`.
+    #[serde(skip)]
+    pub removed: bool,
 }
 
 /// Additional statistics calculated for a book from its reading sessions
@@ -119,6 +123,7 @@ pub struct BookSessionStats {
     pub longest_session_duration: Option<i64>, // in seconds
     pub last_read_date: Option<String>,
     pub reading_speed: Option<f64>, // pages per hour
+    pub pages_per_day: Option<f64>, // per day the book was read on
 }
 
 /// Data structure representing a page stat entry from the statistics database
@@ -1,9 +1,10 @@
-//! External data sources: KOReader integration, book format parsers, and filesystem scanning.
+//! External data sources: KOReader integration, book format parsers, filesystem scanning, and remote sources.
 
 pub mod fingerprints;
 pub mod kobo;
 pub mod koreader;
 pub mod parsers;
+pub mod remote;
 pub mod scanner;
 pub(crate) mod sqlite_snapshot;
 
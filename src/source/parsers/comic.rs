@@ -32,10 +32,8 @@ impl ComicParser {
     /// Parse a CBZ or CBR comic archive for metadata (ComicInfo.xml) and cover image.
     pub async fn parse(&self, comic_path: &Path) -> Result<BookInfo> {
         let path = comic_path.to_path_buf();
-        let is_cbr = path
-            .extension()
-            .map(|e| e.to_str().unwrap_or("").to_lowercase() == "cbr")
-            .unwrap_or(false);
+        // Go by the archive signature, a CBR is sometimes just a renamed CBZ.
+        let is_cbr = super::sniff::is_rar(&path);
 
         // Windows builds intentionally don't support CBR/RAR archives (unrar doesn't compile reliably).
         if is_cbr && cfg!(windows) {
@@ -45,6 +43,7 @@ impl ComicParser {
         }
 
         tokio::task::spawn_blocking(move || {
+            super::ensure_not_empty(&path, if is_cbr { "CBR" } else { "CBZ" })?;
             if is_cbr {
                 Self::parse_cbr_sync(&path)
             } else {
@@ -60,8 +59,12 @@ impl ComicParser {
         debug!("Opening CBZ: {:?}", cbz_path);
         let file = File::open(cbz_path)
             .with_context(|| format!("Failed to open CBZ file: {:?}", cbz_path))?;
-        let mut zip = ZipArchive::new(file)
-            .with_context(|| format!("Failed to read CBZ as zip: {:?}", cbz_path))?;
+        let mut zip = ZipArchive::new(file).with_context(|| {
+            format!(
+                "Failed to read CBZ as zip (truncated or corrupt?): {:?}",
+                cbz_path
+            )
+        })?;
 
         let image_count = zip
             .file_names()
@@ -158,9 +161,7 @@ impl ComicParser {
             book_info.pages = Some(image_files.len() as u32);
         }
 
-        image_files.sort();
-
-        if let Some(cover_filename) = image_files.first() {
+        if let Some(cover_filename) = Self::select_cover(image_files.iter().map(String::as_str)) {
             debug!("Selected cover image for CBR: {}", cover_filename);
 
             let archive = unrar::Archive::new(cbr_path)
@@ -175,7 +176,7 @@ impl ComicParser {
                     Ok(Some(header)) => {
                         let filename = header.entry().filename.to_string_lossy().to_string();
 
-                        if &filename == cover_filename {
+                        if filename == cover_filename {
                             // Use a safe fixed filename to prevent path traversal
                             // from malicious archive entry names containing "../".
                             let safe_name = Path::new(&filename)
@@ -324,7 +325,11 @@ impl ComicParser {
 
         Ok(BookInfo {
             title: final_title,
+            title_sort: None,
             authors,
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description: summary,
             language,
             publisher,
@@ -349,7 +354,11 @@ impl ComicParser {
 
         BookInfo {
             title,
+            title_sort: None,
             authors: Vec::new(),
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description: None,
             language: None,
             publisher: None,
@@ -368,18 +377,7 @@ impl ComicParser {
     fn extract_cover_from_cbz(
         zip: &mut ZipArchive<File>,
     ) -> Result<(Option<Vec<u8>>, Option<String>)> {
-        // Collect all image file names
-        // Find the first image file alphabetically (which is usually the cover page)
-        // using an iterator to avoid checking every file if not needed and avoiding allocations
-        let cover_file_name = zip
-            .file_names()
-            .filter(|name| {
-                let lower_name = name.to_lowercase();
-                IMAGE_EXTENSIONS.iter().any(|ext| lower_name.ends_with(ext))
-            })
-            // Lexicographical minimum is equivalent to sorting and taking the first
-            .min()
-            .map(|s| s.to_string());
+        let cover_file_name = Self::select_cover(zip.file_names()).map(|s| s.to_string());
 
         if let Some(first_image) = cover_file_name
             && let Ok(mut file) = zip.by_name(&first_image)
@@ -393,6 +391,50 @@ impl ComicParser {
         Ok((None, None))
     }
 
+    /// Name of the archive entry used as the cover, without extracting it.
+    pub fn cover_entry(comic_path: &Path) -> Result<Option<String>> {
+        if super::sniff::is_rar(comic_path) {
+            Self::cbr_cover_entry(comic_path)
+        } else {
+            let file = File::open(comic_path)
+                .with_context(|| format!("Failed to open CBZ file: {:?}", comic_path))?;
+            let zip = ZipArchive::new(file)
+                .with_context(|| format!("Failed to read CBZ as zip: {:?}", comic_path))?;
+            Ok(Self::select_cover(zip.file_names()).map(str::to_string))
+        }
+    }
+
+    #[cfg(not(windows))]
+    fn cbr_cover_entry(cbr_path: &Path) -> Result<Option<String>> {
+        let archive = unrar::Archive::new(cbr_path)
+            .open_for_listing()
+            .map_err(|e| anyhow!("Failed to open CBR file: {:?}", e))?;
+        let mut names = Vec::new();
+        for header in archive {
+            let header = header.map_err(|e| anyhow!("Failed to read RAR header: {:?}", e))?;
+            names.push(header.filename.to_string_lossy().to_string());
+        }
+        Ok(Self::select_cover(names.iter().map(String::as_str)).map(str::to_string))
+    }
+
+    #[cfg(windows)]
+    fn cbr_cover_entry(_cbr_path: &Path) -> Result<Option<String>> {
+        Err(anyhow!(
+            "CBR (.cbr) is not supported on Windows builds; please convert to CBZ (.cbz)."
+        ))
+    }
+
+    /// The cover is the first image entry in lexicographic order, which is
+    /// usually the cover page.
+    fn select_cover<'a>(names: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+        names
+            .filter(|name| {
+                let lower_name = name.to_lowercase();
+                IMAGE_EXTENSIONS.iter().any(|ext| lower_name.ends_with(ext))
+            })
+            .min()
+    }
+
     /// Get MIME type from file extension
     fn mime_type_from_extension(filename: &str) -> Option<String> {
         let lower = filename.to_lowercase();
@@ -1,5 +1,5 @@
 use crate::shelf::models::{BookInfo, ChapterEntry, Identifier};
-use crate::shelf::utils::sanitize_html;
+use crate::shelf::utils::{percent_decode, sanitize_html};
 use crate::source::parsers::xml::{decode_xml_text, xml_attr_value};
 use anyhow::{Context, Result, anyhow};
 use log::{debug, warn};
@@ -11,6 +11,17 @@ use std::io::Read;
 use std::path::{Component, Path, PathBuf};
 use zip::ZipArchive;
 
+/// Media type of the OPF package document in container.xml.
+const OPF_MEDIA_TYPE: &str = "application/oebps-package+xml";
+
+/// A `<dc:creator>` entry with its EPUB 2 `opf:role` and `opf:file-as`, if any.
+struct Creator {
+    id: Option<String>,
+    name: String,
+    role: Option<String>,
+    file_as: Option<String>,
+}
+
 /// Extracts metadata and cover images from EPUB files via OPF parsing.
 pub struct EpubParser;
 
@@ -56,10 +67,15 @@ impl EpubParser {
 
     fn parse_sync(epub_path: &PathBuf) -> Result<BookInfo> {
         debug!("Opening EPUB: {:?}", epub_path);
+        super::ensure_not_empty(epub_path, "EPUB")?;
         let file = File::open(epub_path)
             .with_context(|| format!("Failed to open EPUB file: {:?}", epub_path))?;
-        let mut zip = ZipArchive::new(file)
-            .with_context(|| format!("Failed to read EPUB as zip: {:?}", epub_path))?;
+        let mut zip = ZipArchive::new(file).with_context(|| {
+            format!(
+                "Failed to read EPUB as zip (truncated or corrupt?): {:?}",
+                epub_path
+            )
+        })?;
 
         let opf_path = {
             let mut container_xml = String::new();
@@ -67,7 +83,17 @@ impl EpubParser {
                 .by_name("META-INF/container.xml")
                 .with_context(|| "META-INF/container.xml not found in EPUB")?;
             container_file.read_to_string(&mut container_xml)?;
-            Self::normalize_zip_path(&Self::find_opf_path(&container_xml)?)
+            drop(container_file);
+
+            // `full-path` may be percent-encoded; keep the raw path for
+            // archives whose entry names contain a literal '%'.
+            let full_path = Self::find_opf_path(&container_xml)?;
+            let decoded = Self::normalize_zip_path(&percent_decode(&full_path));
+            if zip.index_for_name(&decoded).is_some() {
+                decoded
+            } else {
+                Self::normalize_zip_path(&full_path)
+            }
         };
         debug!("Found OPF file path: {}", opf_path);
 
@@ -137,21 +163,37 @@ impl EpubParser {
         })
     }
 
+    /// Pick the package document from container.xml.
+    ///
+    /// A container may list several rootfiles (e.g. alternate renditions or
+    /// non-OPF documents), so the one declared as
+    /// `application/oebps-package+xml` wins over the first one listed.
     fn find_opf_path(container_xml: &str) -> Result<String> {
         let mut reader = Reader::from_str(container_xml);
         reader.config_mut().trim_text(true);
         let mut buf = Vec::new();
+        let mut first_path = None;
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) => {
-                    if e.local_name().as_ref() == b"rootfile" {
-                        for attr in e.attributes().flatten() {
-                            let key = attr.key.as_ref();
-                            if key == b"full-path" {
-                                return Ok(xml_attr_value(&attr)?.into_owned());
-                            }
+                Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"rootfile" =>
+                {
+                    let mut full_path = None;
+                    let mut media_type = None;
+                    for attr in e.attributes().flatten() {
+                        match attr.key.as_ref() {
+                            b"full-path" => full_path = Some(xml_attr_value(&attr)?.into_owned()),
+                            b"media-type" => media_type = Some(xml_attr_value(&attr)?.into_owned()),
+                            _ => {}
                         }
                     }
+                    if let Some(full_path) = full_path {
+                        if media_type.is_some_and(|t| t.trim().eq_ignore_ascii_case(OPF_MEDIA_TYPE))
+                        {
+                            return Ok(full_path);
+                        }
+                        first_path.get_or_insert(full_path);
+                    }
                 }
                 Ok(Event::Eof) => break,
                 Err(e) => return Err(anyhow!("Error parsing container.xml: {}", e)),
@@ -159,7 +201,7 @@ impl EpubParser {
             }
             buf.clear();
         }
-        Err(anyhow!("No rootfile/full-path found in container.xml"))
+        first_path.ok_or_else(|| anyhow!("No rootfile/full-path found in container.xml"))
     }
 
     /// Parse OPF metadata - returns (BookInfo, cover_id, nav_path)
@@ -170,7 +212,9 @@ impl EpubParser {
         let mut in_metadata = false;
         let mut in_manifest = false;
         let mut title = None;
-        let mut authors = Vec::new();
+        let mut title_id = None;
+        let mut title_file_as = None;
+        let mut creators: Vec<Creator> = Vec::new();
         let mut description = None;
         let mut publisher = None;
         let mut language = None;
@@ -186,6 +230,9 @@ impl EpubParser {
         // EPUB3 collection tracking
         let mut epub3_collections: HashMap<String, String> = HashMap::new(); // id -> name
         let mut epub3_indices: HashMap<String, String> = HashMap::new(); // refines (#id) -> index
+        let mut epub3_roles: HashMap<String, String> = HashMap::new(); // refines (#id) -> role
+        let mut epub3_file_as: HashMap<String, String> = HashMap::new(); // refines (#id) -> file-as
+        let mut cal_title_sort: Option<String> = None;
 
         loop {
             match reader.read_event_into(&mut buf) {
@@ -198,13 +245,46 @@ impl EpubParser {
                     } else if in_metadata {
                         match local_name.as_ref() {
                             b"title" => {
+                                let mut id = None;
+                                let mut file_as = None;
+                                for attr in e.attributes().flatten() {
+                                    match attr.key.as_ref() {
+                                        b"id" => id = Some(xml_attr_value(&attr)?.into_owned()),
+                                        b"opf:file-as" | b"file-as" => {
+                                            file_as = Some(xml_attr_value(&attr)?.into_owned())
+                                        }
+                                        _ => {}
+                                    }
+                                }
                                 if let Ok(text) = reader.read_text(e.name()) {
                                     title = Some(decode_xml_text(&text));
+                                    title_id = id;
+                                    title_file_as = file_as;
                                 }
                             }
                             b"creator" => {
+                                let mut id = None;
+                                let mut role = None;
+                                let mut file_as = None;
+                                for attr in e.attributes().flatten() {
+                                    match attr.key.as_ref() {
+                                        b"id" => id = Some(xml_attr_value(&attr)?.into_owned()),
+                                        b"opf:role" | b"role" => {
+                                            role = Some(xml_attr_value(&attr)?.into_owned())
+                                        }
+                                        b"opf:file-as" | b"file-as" => {
+                                            file_as = Some(xml_attr_value(&attr)?.into_owned())
+                                        }
+                                        _ => {}
+                                    }
+                                }
                                 if let Ok(text_content) = reader.read_text(e.name()) {
-                                    authors.push(decode_xml_text(&text_content));
+                                    creators.push(Creator {
+                                        id,
+                                        name: decode_xml_text(&text_content),
+                                        role,
+                                        file_as,
+                                    });
                                 }
                             }
                             b"description" => match reader.read_text(e.name()) {
@@ -299,6 +379,9 @@ impl EpubParser {
                                     if n == "calibre:series_index" {
                                         cal_series_number = Some(c.clone());
                                     }
+                                    if n == "calibre:title_sort" {
+                                        cal_title_sort = Some(c.clone());
+                                    }
                                 }
 
                                 if let Some(prop) = property {
@@ -319,6 +402,24 @@ impl EpubParser {
                                                 decode_xml_text(&text_content),
                                             );
                                         }
+                                    } else if prop == "role" {
+                                        if let (Ok(text_content), Some(r)) =
+                                            (reader.read_text(e.name()), refines)
+                                        {
+                                            epub3_roles.insert(
+                                                r.trim_start_matches('#').to_string(),
+                                                decode_xml_text(&text_content),
+                                            );
+                                        }
+                                    } else if prop == "file-as" {
+                                        if let (Ok(text_content), Some(r)) =
+                                            (reader.read_text(e.name()), refines)
+                                        {
+                                            epub3_file_as.insert(
+                                                r.trim_start_matches('#').to_string(),
+                                                decode_xml_text(&text_content),
+                                            );
+                                        }
                                     } else if prop == "schema:numberOfPages"
                                         && let Ok(text_content) = reader.read_text(e.name())
                                         && let Ok(pages) =
@@ -354,6 +455,8 @@ impl EpubParser {
                                 cal_series = Some(c);
                             } else if n == "calibre:series_index" {
                                 cal_series_number = Some(c);
+                            } else if n == "calibre:title_sort" {
+                                cal_title_sort = Some(c);
                             }
                         }
                     } else if in_manifest && local_name.as_ref() == b"item" {
@@ -412,10 +515,44 @@ impl EpubParser {
             (cal_series, cal_series_number)
         };
 
+        // Creators without a role are authors (EPUB 2 books rarely set one);
+        // other roles than author, editor and translator are left out.
+        let mut authors = Vec::new();
+        let mut author_sort = None;
+        let mut editors = Vec::new();
+        let mut translators = Vec::new();
+        for creator in creators {
+            let role = creator
+                .role
+                .or_else(|| creator.id.as_ref().and_then(|id| epub3_roles.remove(id)));
+            match role.map(|r| r.trim().to_ascii_lowercase()).as_deref() {
+                None | Some("") | Some("aut") => {
+                    if authors.is_empty() {
+                        author_sort = creator
+                            .file_as
+                            .or_else(|| creator.id.and_then(|id| epub3_file_as.remove(&id)));
+                    }
+                    authors.push(creator.name);
+                }
+                Some("edt") => editors.push(creator.name),
+                Some("trl") => translators.push(creator.name),
+                Some(_) => {}
+            }
+        }
+
+        // An explicit `file-as` on the title wins over Calibre's title_sort.
+        let title_sort = title_file_as
+            .or_else(|| title_id.and_then(|id| epub3_file_as.remove(&id)))
+            .or(cal_title_sort);
+
         let cover_id = meta_cover_id;
         let info = BookInfo {
             title: title.unwrap_or_else(|| "Unknown Title".to_string()),
+            title_sort: title_sort.filter(|s| !s.trim().is_empty()),
             authors,
+            author_sort: author_sort.filter(|s| !s.trim().is_empty()),
+            editors,
+            translators,
             description,
             publisher,
             language,
@@ -441,42 +578,42 @@ impl EpubParser {
 
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) => {
-                    if e.local_name().as_ref() == b"item" {
-                        let mut id = None;
-                        let mut href = None;
-                        let mut media_type = None;
-                        let mut properties = None;
-
-                        for attr in e.attributes().flatten() {
-                            let key = attr.key.as_ref();
-                            if key == b"id" {
-                                id = Some(xml_attr_value(&attr)?.into_owned());
-                            } else if key == b"href" {
-                                href = Some(xml_attr_value(&attr)?.into_owned());
-                            } else if key == b"media-type" {
-                                media_type = Some(xml_attr_value(&attr)?.into_owned());
-                            } else if key == b"properties" {
-                                properties = Some(xml_attr_value(&attr)?.into_owned());
-                            }
+                Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"item" =>
+                {
+                    let mut id = None;
+                    let mut href = None;
+                    let mut media_type = None;
+                    let mut properties = None;
+
+                    for attr in e.attributes().flatten() {
+                        let key = attr.key.as_ref();
+                        if key == b"id" {
+                            id = Some(xml_attr_value(&attr)?.into_owned());
+                        } else if key == b"href" {
+                            href = Some(xml_attr_value(&attr)?.into_owned());
+                        } else if key == b"media-type" {
+                            media_type = Some(xml_attr_value(&attr)?.into_owned());
+                        } else if key == b"properties" {
+                            properties = Some(xml_attr_value(&attr)?.into_owned());
                         }
+                    }
 
-                        if let (Some(href), Some(media_type)) = (href, media_type)
-                            && media_type.starts_with("image/")
+                    if let (Some(href), Some(media_type)) = (href, media_type)
+                        && media_type.starts_with("image/")
+                    {
+                        // Check if this is the cover using EPUB 3.0 properties
+                        if let Some(props) = &properties
+                            && props.contains("cover-image")
                         {
-                            // Check if this is the cover using EPUB 3.0 properties
-                            if let Some(props) = &properties
-                                && props.contains("cover-image")
-                            {
-                                return Ok((Some(href), Some(media_type)));
-                            }
+                            return Ok((Some(href), Some(media_type)));
+                        }
 
-                            // Check if this matches the cover_id from meta tags (EPUB 2.0 style)
-                            if let (Some(cover_id), Some(id)) = (cover_id, &id)
-                                && id == cover_id
-                            {
-                                return Ok((Some(href), Some(media_type)));
-                            }
+                        // Check if this matches the cover_id from meta tags (EPUB 2.0 style)
+                        if let (Some(cover_id), Some(id)) = (cover_id, &id)
+                            && id == cover_id
+                        {
+                            return Ok((Some(href), Some(media_type)));
                         }
                     }
                 }
@@ -533,10 +670,8 @@ impl EpubParser {
                         page_count += 1;
                     }
                 }
-                Ok(Event::End(ref e)) => {
-                    if e.local_name().as_ref() == b"nav" {
-                        in_page_list = false;
-                    }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"nav" => {
+                    in_page_list = false;
                 }
                 Ok(Event::Eof) => break,
                 Err(_) => break,
@@ -892,3 +1027,117 @@ impl EpubParser {
         chapters
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    const TWO_ROOTFILES: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="META-INF/preview.xhtml" media-type="application/xhtml+xml"/>
+    <rootfile full-path="Book%20Files/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>"#;
+
+    #[test]
+    fn find_opf_path_prefers_the_package_rootfile() {
+        assert_eq!(
+            EpubParser::find_opf_path(TWO_ROOTFILES).unwrap(),
+            "Book%20Files/content.opf"
+        );
+
+        let untyped = r#"<container><rootfiles>
+            <rootfile full-path="a.opf"/><rootfile full-path="b.opf"/>
+        </rootfiles></container>"#;
+        assert_eq!(EpubParser::find_opf_path(untyped).unwrap(), "a.opf");
+    }
+
+    #[test]
+    fn creator_roles_separate_authors_from_editors_and_translators() {
+        let opf = r##"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" xmlns:opf="http://www.idpf.org/2007/opf">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title>Anthology</dc:title>
+    <dc:creator opf:role="aut">First Author</dc:creator>
+    <dc:creator>Second Author</dc:creator>
+    <dc:creator opf:role="edt">The Editor</dc:creator>
+    <dc:creator id="c4">The Translator</dc:creator>
+    <meta refines="#c4" property="role" scheme="marc:relators">trl</meta>
+    <dc:creator opf:role="ill">The Illustrator</dc:creator>
+  </metadata>
+  <manifest/>
+</package>"##;
+
+        let (info, _, _) = EpubParser::parse_opf_metadata(opf).unwrap();
+        assert_eq!(info.authors, vec!["First Author", "Second Author"]);
+        assert_eq!(info.editors, vec!["The Editor"]);
+        assert_eq!(info.translators, vec!["The Translator"]);
+    }
+
+    #[test]
+    fn sort_names_come_from_file_as_and_calibre_title_sort() {
+        let epub2 = r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" xmlns:opf="http://www.idpf.org/2007/opf">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title>The Hobbit</dc:title>
+    <dc:creator opf:role="aut" opf:file-as="Tolkien, J. R. R.">J. R. R. Tolkien</dc:creator>
+    <meta name="calibre:title_sort" content="Hobbit, The"/>
+  </metadata>
+  <manifest/>
+</package>"#;
+        let (info, _, _) = EpubParser::parse_opf_metadata(epub2).unwrap();
+        assert_eq!(info.title, "The Hobbit");
+        assert_eq!(info.title_sort.as_deref(), Some("Hobbit, The"));
+        assert_eq!(info.author_sort.as_deref(), Some("Tolkien, J. R. R."));
+
+        let epub3 = r##"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title id="t1">A Wizard of Earthsea</dc:title>
+    <meta refines="#t1" property="file-as">Wizard of Earthsea, A</meta>
+    <dc:creator id="c1">Ursula K. Le Guin</dc:creator>
+    <meta refines="#c1" property="file-as">Le Guin, Ursula K.</meta>
+    <dc:creator id="c2">Second Author</dc:creator>
+    <meta refines="#c2" property="file-as">Author, Second</meta>
+  </metadata>
+  <manifest/>
+</package>"##;
+        let (info, _, _) = EpubParser::parse_opf_metadata(epub3).unwrap();
+        assert_eq!(info.title_sort.as_deref(), Some("Wizard of Earthsea, A"));
+        assert_eq!(info.author_sort.as_deref(), Some("Le Guin, Ursula K."));
+    }
+
+    #[tokio::test]
+    async fn parse_reads_package_from_percent_encoded_rootfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("book.epub");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        let options = SimpleFileOptions::default();
+        zip.start_file("META-INF/container.xml", options).unwrap();
+        zip.write_all(TWO_ROOTFILES.as_bytes()).unwrap();
+        zip.start_file("META-INF/preview.xhtml", options).unwrap();
+        zip.write_all(b"<html><head><title>Preview</title></head></html>")
+            .unwrap();
+        zip.start_file("Book Files/content.opf", options).unwrap();
+        zip.write_all(
+            br#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title>The Right Package</dc:title>
+    <dc:creator>Jane Author</dc:creator>
+  </metadata>
+  <manifest/>
+</package>"#,
+        )
+        .unwrap();
+        zip.finish().unwrap();
+
+        let info = EpubParser::new().parse(&path).await.unwrap();
+        assert_eq!(info.title, "The Right Package");
+        assert_eq!(info.authors, vec!["Jane Author".to_string()]);
+    }
+}
@@ -36,6 +36,7 @@ impl Fb2Parser {
 
     fn parse_sync(fb2_path: &Path) -> Result<BookInfo> {
         debug!("Opening FB2: {:?}", fb2_path);
+        super::ensure_not_empty(fb2_path, "FB2")?;
 
         let xml_content = Self::read_fb2_content(fb2_path)?;
         let (fb2_info, cover_href) = Self::parse_fb2_metadata(&xml_content)?;
@@ -321,7 +322,11 @@ impl Fb2Parser {
 
         let info = BookInfo {
             title: title.unwrap_or_else(|| "Unknown Title".to_string()),
+            title_sort: None,
             authors,
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description,
             publisher,
             language,
@@ -481,39 +486,37 @@ impl Fb2Parser {
 
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Start(ref e)) => {
-                    if e.local_name().as_ref() == b"binary" {
-                        let mut found_id = false;
-                        let mut mime_type = None;
-
-                        for attr in e.attributes().flatten() {
-                            match attr.key.as_ref() {
-                                b"id" => {
-                                    if let Ok(id) = xml_attr_value(&attr)
-                                        && id.as_ref() == cover_href
-                                    {
-                                        found_id = true;
-                                    }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"binary" => {
+                    let mut found_id = false;
+                    let mut mime_type = None;
+
+                    for attr in e.attributes().flatten() {
+                        match attr.key.as_ref() {
+                            b"id" => {
+                                if let Ok(id) = xml_attr_value(&attr)
+                                    && id.as_ref() == cover_href
+                                {
+                                    found_id = true;
                                 }
-                                b"content-type" => {
-                                    if let Ok(ct) = xml_attr_value(&attr) {
-                                        mime_type = Some(ct.into_owned());
-                                    }
+                            }
+                            b"content-type" => {
+                                if let Ok(ct) = xml_attr_value(&attr) {
+                                    mime_type = Some(ct.into_owned());
                                 }
-                                _ => {}
                             }
+                            _ => {}
                         }
+                    }
 
-                        if found_id {
-                            // Read the base64 content
-                            if let Ok(text) = reader.read_text(e.name()) {
-                                let text = decode_xml_text(&text);
-                                let text_clean = text.trim().replace(['\n', '\r', ' '], "");
-                                match general_purpose::STANDARD.decode(&text_clean) {
-                                    Ok(data) => return Ok((Some(data), mime_type)),
-                                    Err(e) => {
-                                        warn!("Failed to decode base64 cover image: {}", e);
-                                    }
+                    if found_id {
+                        // Read the base64 content
+                        if let Ok(text) = reader.read_text(e.name()) {
+                            let text = decode_xml_text(&text);
+                            let text_clean = text.trim().replace(['\n', '\r', ' '], "");
+                            match general_purpose::STANDARD.decode(&text_clean) {
+                                Ok(data) => return Ok((Some(data), mime_type)),
+                                Err(e) => {
+                                    warn!("Failed to decode base64 cover image: {}", e);
                                 }
                             }
                         }
@@ -30,6 +30,7 @@ impl MobiParser {
 
     fn parse_sync(mobi_path: &PathBuf) -> Result<BookInfo> {
         debug!("Opening MOBI: {:?}", mobi_path);
+        super::ensure_not_empty(mobi_path, "MOBI")?;
         let data = fs::read(mobi_path)
             .with_context(|| format!("Failed to read MOBI file: {:?}", mobi_path))?;
 
@@ -211,7 +212,11 @@ impl MobiParser {
 
         Ok(BookInfo {
             title: final_title,
+            title_sort: None,
             authors,
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description,
             language,
             publisher,
@@ -229,7 +234,11 @@ impl MobiParser {
     fn book_info_from_filename(title: &str) -> BookInfo {
         BookInfo {
             title: title.to_string(),
+            title_sort: None,
             authors: Vec::new(),
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description: None,
             language: None,
             publisher: None,
@@ -4,9 +4,55 @@ pub mod comic;
 pub mod epub;
 pub mod fb2;
 pub mod mobi;
+mod sniff;
 mod xml;
 
 pub use comic::ComicParser;
 pub use epub::EpubParser;
 pub use fb2::Fb2Parser;
 pub use mobi::MobiParser;
+pub(crate) use sniff::sniff_format;
+
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+
+/// Reject empty files up front. They are usually left behind by an
+/// interrupted copy or sync and would otherwise fail deep inside a parser
+/// with an unhelpful error (or, for MOBI, parse as a title-only book).
+fn ensure_not_empty(path: &Path, kind: &str) -> Result<()> {
+    let len = std::fs::metadata(path)
+        .with_context(|| format!("Failed to read {kind} file metadata: {path:?}"))?
+        .len();
+    if len == 0 {
+        bail!("{kind} file is empty (0 bytes), possibly an incomplete copy: {path:?}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_files_are_rejected_by_every_parser() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, b"").unwrap();
+            path
+        };
+
+        let errors = [
+            EpubParser::new().parse(&path("a.epub")).await.unwrap_err(),
+            MobiParser::new().parse(&path("a.mobi")).await.unwrap_err(),
+            Fb2Parser::new().parse(&path("a.fb2")).await.unwrap_err(),
+            ComicParser::new().parse(&path("a.cbz")).await.unwrap_err(),
+        ];
+        for error in errors {
+            assert!(
+                error.to_string().contains("is empty (0 bytes)"),
+                "unexpected error: {error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Content sniffing: pick a parser by what a file contains rather than by
+//! its extension alone.
+
+use anyhow::{Context, Result, bail};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::shelf::models::LibraryItemFormat;
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const RAR_MAGIC: &[u8] = b"Rar!\x1a\x07";
+const PDF_MAGIC: &[u8] = b"%PDF";
+/// PDB type/creator pairs at offset 60 used by MOBI and AZW3 (KF8) books.
+const PDB_MOBI_TYPES: [&[u8]; 2] = [b"BOOKMOBI", b"TEXtREAd"];
+const IMAGE_EXTENSIONS: [&str; 5] = [".jpg", ".jpeg", ".png", ".webp", ".gif"];
+
+/// Bytes read from the start of a file for sniffing.
+const HEAD_LEN: usize = 1024;
+
+/// Detect the format a file's contents are in.
+///
+/// `hint` is the format implied by the extension and wins whenever the
+/// contents are ambiguous. Zip archives are told apart by their entries: an
+/// EPUB has `META-INF/container.xml` and no `ComicInfo.xml`, a zip of images
+/// is a comic, and a zip holding an `.fb2` file is an FB2 book.
+pub(crate) fn sniff_format(path: &Path, hint: LibraryItemFormat) -> Result<LibraryItemFormat> {
+    let mut head = Vec::with_capacity(HEAD_LEN);
+    File::open(path)
+        .with_context(|| format!("Failed to open file: {:?}", path))?
+        .take(HEAD_LEN as u64)
+        .read_to_end(&mut head)
+        .with_context(|| format!("Failed to read file: {:?}", path))?;
+
+    if head.starts_with(PDF_MAGIC) {
+        bail!("{:?} is a PDF, which is not supported", path);
+    }
+    if head.starts_with(ZIP_MAGIC) {
+        return Ok(sniff_zip(path, hint));
+    }
+    if head.starts_with(RAR_MAGIC) {
+        return Ok(LibraryItemFormat::Cbr);
+    }
+    if head
+        .get(60..68)
+        .is_some_and(|kind| PDB_MOBI_TYPES.contains(&kind))
+    {
+        return Ok(LibraryItemFormat::Mobi);
+    }
+    if contains(&head, b"<FictionBook") {
+        return Ok(LibraryItemFormat::Fb2);
+    }
+
+    Ok(hint)
+}
+
+fn sniff_zip(path: &Path, hint: LibraryItemFormat) -> LibraryItemFormat {
+    let Ok(zip) = File::open(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|file| ZipArchive::new(file).map_err(anyhow::Error::from))
+    else {
+        // Let the parser for the extension report the broken archive.
+        return hint;
+    };
+
+    let mut has_container = false;
+    let mut has_comic_info = false;
+    let mut has_fb2 = false;
+    let mut has_images = false;
+    for name in zip.file_names() {
+        let lower = name.to_lowercase();
+        has_container |= lower == "meta-inf/container.xml";
+        has_comic_info |= lower.rsplit('/').next() == Some("comicinfo.xml");
+        has_fb2 |= lower.ends_with(".fb2");
+        has_images |= IMAGE_EXTENSIONS.iter().any(|ext| lower.ends_with(ext));
+    }
+
+    if has_container && !has_comic_info {
+        LibraryItemFormat::Epub
+    } else if has_fb2 && !has_comic_info {
+        LibraryItemFormat::Fb2
+    } else if has_comic_info || has_images {
+        LibraryItemFormat::Cbz
+    } else {
+        hint
+    }
+}
+
+/// Whether `path` starts with the RAR signature.
+pub(crate) fn is_rar(path: &Path) -> bool {
+    let mut magic = [0u8; RAR_MAGIC.len()];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok_and(|()| magic == RAR_MAGIC)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    fn write_zip(path: &Path, entries: &[&str]) {
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        for name in entries {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(b"x").unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn zip_archives_are_classified_by_their_entries() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let epub = dir.path().join("book.cbz");
+        write_zip(
+            &epub,
+            &["mimetype", "META-INF/container.xml", "OEBPS/c1.xhtml"],
+        );
+        assert_eq!(
+            sniff_format(&epub, LibraryItemFormat::Cbz).unwrap(),
+            LibraryItemFormat::Epub
+        );
+
+        let comic = dir.path().join("comic.epub");
+        write_zip(&comic, &["001.jpg", "002.jpg"]);
+        assert_eq!(
+            sniff_format(&comic, LibraryItemFormat::Epub).unwrap(),
+            LibraryItemFormat::Cbz
+        );
+
+        let comic_with_info = dir.path().join("comic-info.cbz");
+        write_zip(
+            &comic_with_info,
+            &["ComicInfo.xml", "META-INF/container.xml", "001.png"],
+        );
+        assert_eq!(
+            sniff_format(&comic_with_info, LibraryItemFormat::Cbz).unwrap(),
+            LibraryItemFormat::Cbz
+        );
+
+        let fb2 = dir.path().join("book.epub");
+        write_zip(&fb2, &["book.fb2"]);
+        assert_eq!(
+            sniff_format(&fb2, LibraryItemFormat::Epub).unwrap(),
+            LibraryItemFormat::Fb2
+        );
+    }
+
+    #[test]
+    fn pdb_rar_and_pdf_signatures_are_recognized() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut pdb = vec![0u8; 78];
+        pdb[60..68].copy_from_slice(b"BOOKMOBI");
+        let azw3 = dir.path().join("book.epub");
+        std::fs::write(&azw3, &pdb).unwrap();
+        assert_eq!(
+            sniff_format(&azw3, LibraryItemFormat::Epub).unwrap(),
+            LibraryItemFormat::Mobi
+        );
+
+        let rar = dir.path().join("comic.cbz");
+        std::fs::write(&rar, b"Rar!\x1a\x07\x01\x00").unwrap();
+        assert!(is_rar(&rar));
+        assert_eq!(
+            sniff_format(&rar, LibraryItemFormat::Cbz).unwrap(),
+            LibraryItemFormat::Cbr
+        );
+
+        let pdf = dir.path().join("paper.mobi");
+        std::fs::write(&pdf, b"%PDF-1.7\n").unwrap();
+        assert!(sniff_format(&pdf, LibraryItemFormat::Mobi).is_err());
+
+        let unknown = dir.path().join("notes.fb2");
+        std::fs::write(&unknown, b"plain text").unwrap();
+        assert_eq!(
+            sniff_format(&unknown, LibraryItemFormat::Fb2).unwrap(),
+            LibraryItemFormat::Fb2
+        );
+    }
+}
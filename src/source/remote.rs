@@ -0,0 +1,795 @@
+//! Remote library sources: mirror KOReader data from a device over SFTP.
+//!
+//! A remote source is mirrored into a local cache, and the rest of the
+//! pipeline reads the cached copy like any other library path or statistics
+//! database. Each sync lists the remote and only downloads books and sidecar
+//! metadata that are new or whose size or modification time changed; files
+//! removed from the device are removed from the mirror. Downloads keep the
+//! remote modification times, so unchanged books are not ingested again.
+//! Transfers are retried a few times; if the remote stays unreachable, the
+//! mirror from the previous successful sync is reused.
+//!
+//! SFTP transfers use the system `sftp` client in batch mode, so host aliases,
+//! keys, and agents from the user's SSH configuration apply. Password prompts
+//! are not supported.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::shelf::models::LibraryItemFormat;
+
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+const CONNECT_TIMEOUT_SECS: u32 = 15;
+
+/// A file KoShelf reads from a remote source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteFile {
+    /// Path below the remote path, `/`-separated. Empty when the source is a
+    /// single file.
+    pub path: String,
+    pub size: u64,
+    /// Modification time as the remote lists it (to the minute).
+    pub modified: String,
+}
+
+/// A backend that can copy KOReader data to a local path.
+pub trait RemoteSource {
+    /// Human-readable location for log messages.
+    fn describe(&self) -> String;
+
+    /// List the files KoShelf reads: books and their sidecar metadata for a
+    /// library, the file itself for a statistics database.
+    fn list(&self) -> Result<Vec<RemoteFile>>;
+
+    /// Download `files` below `destination`, keeping their modification
+    /// times.
+    fn fetch(&self, files: &[RemoteFile], destination: &Path) -> Result<()>;
+}
+
+/// What a remote source provides, based on the remote path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteSourceKind {
+    /// A library directory with books and `.sdr` metadata folders.
+    Library,
+    /// A KOReader `statistics.sqlite3` file.
+    StatisticsDb,
+}
+
+/// A parsed `sftp://[user@]host[:port]/path` URL.
+///
+/// Paths are absolute; start them with `/~/` to make them relative to the
+/// remote user's home directory. Paths ending in `.sqlite3` or `.sqlite`
+/// are statistics databases, everything else is a library directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SftpSource {
+    pub user: Option<String>,
+    pub host: String,
+    pub port: Option<u16>,
+    pub path: String,
+}
+
+impl SftpSource {
+    pub fn parse(url: &str) -> Result<Self> {
+        let rest = url
+            .strip_prefix("sftp://")
+            .with_context(|| format!("Unsupported source '{}' (expected sftp://...)", url))?;
+        let (authority, path) = rest
+            .split_once('/')
+            .with_context(|| format!("Source '{}' is missing a remote path", url))?;
+
+        let (user, host_port) = match authority.rsplit_once('@') {
+            Some((user, host_port)) => (Some(user.to_string()), host_port),
+            None => (None, authority),
+        };
+        let (host, port) = match host_port.rsplit_once(':') {
+            Some((host, port)) => {
+                let port = port
+                    .parse::<u16>()
+                    .with_context(|| format!("Invalid port in source '{}'", url))?;
+                (host, Some(port))
+            }
+            None => (host_port, None),
+        };
+
+        if host.is_empty() || user.as_deref() == Some("") {
+            bail!("Source '{}' is missing a host or user name", url);
+        }
+        let path = path.trim_end_matches('/');
+        if path.is_empty() {
+            bail!("Source '{}' is missing a remote path", url);
+        }
+        let path = match path.strip_prefix("~/") {
+            Some(relative) => relative.to_string(),
+            None => format!("/{}", path),
+        };
+
+        Ok(Self {
+            user,
+            host: host.to_string(),
+            port,
+            path,
+        })
+    }
+
+    pub fn kind(&self) -> RemoteSourceKind {
+        if self.path.ends_with(".sqlite3") || self.path.ends_with(".sqlite") {
+            RemoteSourceKind::StatisticsDb
+        } else {
+            RemoteSourceKind::Library
+        }
+    }
+
+    /// Cache location for this source below `cache_root`. Stable across runs
+    /// so a previous copy can be reused when the device is offline.
+    pub fn cache_path(&self, cache_root: &Path) -> PathBuf {
+        let key = format!("{:x}", md5::compute(self.describe()));
+        let host: String = self
+            .host
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let dir = cache_root.join(format!("{}-{}", host, &key[..12]));
+        match self.kind() {
+            RemoteSourceKind::Library => dir.join("library"),
+            RemoteSourceKind::StatisticsDb => dir.join("statistics.sqlite3"),
+        }
+    }
+
+    fn destination(&self) -> String {
+        match &self.user {
+            Some(user) => format!("{}@{}", user, self.host),
+            None => self.host.clone(),
+        }
+    }
+}
+
+impl RemoteSource for SftpSource {
+    fn describe(&self) -> String {
+        match self.port {
+            Some(port) => format!("sftp://{}:{}{}", self.destination(), port, self.path),
+            None => format!("sftp://{}{}", self.destination(), self.path),
+        }
+    }
+
+    fn list(&self) -> Result<Vec<RemoteFile>> {
+        if self.kind() == RemoteSourceKind::StatisticsDb {
+            let output = self.run_batch(&format!("ls -ln {}\n", quote_sftp_remote(&self.path)))?;
+            let entry = output
+                .lines()
+                .filter_map(parse_ls_line)
+                .find(|entry| !entry.is_dir)
+                .with_context(|| format!("{} is not a file", self.path))?;
+            return Ok(vec![RemoteFile {
+                path: String::new(),
+                size: entry.size,
+                modified: entry.modified,
+            }]);
+        }
+
+        // One session per directory level, so deep libraries don't need a
+        // connection per folder.
+        let mut files = Vec::new();
+        let mut dirs = vec![String::new()];
+        while !dirs.is_empty() {
+            let batch: String = dirs
+                .iter()
+                .map(|dir| format!("ls -ln {}\n", quote_sftp_remote(&self.remote_path(dir))))
+                .collect();
+            let output = self.run_batch(&batch)?;
+
+            let mut subdirs = Vec::new();
+            for entry in output.lines().filter_map(parse_ls_line) {
+                let Some(relative) = entry
+                    .name
+                    .strip_prefix(&self.path)
+                    .and_then(|rest| rest.strip_prefix('/'))
+                else {
+                    continue;
+                };
+                if entry.is_dir {
+                    subdirs.push(relative.to_string());
+                } else if is_library_file(relative) {
+                    files.push(RemoteFile {
+                        path: relative.to_string(),
+                        size: entry.size,
+                        modified: entry.modified,
+                    });
+                }
+            }
+            dirs = subdirs;
+        }
+        Ok(files)
+    }
+
+    fn fetch(&self, files: &[RemoteFile], destination: &Path) -> Result<()> {
+        let mut batch = String::new();
+        for file in files {
+            let local = local_path(destination, file);
+            if let Some(parent) = local.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {:?}", parent))?;
+            }
+            batch.push_str(&format!(
+                "get -p {} {}\n",
+                quote_sftp_remote(&self.remote_path(&file.path)),
+                quote_sftp_arg(&local.to_string_lossy())
+            ));
+        }
+        self.run_batch(&batch).map(drop)
+    }
+}
+
+impl SftpSource {
+    fn remote_path(&self, relative: &str) -> String {
+        if relative.is_empty() {
+            self.path.clone()
+        } else {
+            format!("{}/{}", self.path, relative)
+        }
+    }
+
+    /// Run `batch` in one `sftp` session and return its output. The batch is
+    /// passed as a file so a long listing can't block on a full pipe.
+    fn run_batch(&self, batch: &str) -> Result<String> {
+        let mut batch_file =
+            tempfile::NamedTempFile::new().context("Failed to create sftp batch file")?;
+        batch_file
+            .write_all(batch.as_bytes())
+            .context("Failed to write sftp batch file")?;
+
+        let mut command = Command::new("sftp");
+        command
+            .arg("-b")
+            .arg(batch_file.path())
+            .args(["-o", "BatchMode=yes", "-o"])
+            .arg(format!("ConnectTimeout={}", CONNECT_TIMEOUT_SECS));
+        if let Some(port) = self.port {
+            command.arg("-P").arg(port.to_string());
+        }
+        let output = command
+            .arg(self.destination())
+            .output()
+            .context("Failed to run sftp (is the OpenSSH client installed?)")?;
+        if !output.status.success() {
+            bail!(
+                "sftp exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Books and the sidecar metadata next to them; everything else on the
+/// device is left out of the mirror.
+fn is_library_file(relative: &str) -> bool {
+    let path = Path::new(relative);
+    let in_sidecar = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|dir| dir.to_str())
+        .is_some_and(|dir| dir.ends_with(".sdr"));
+    LibraryItemFormat::from_path(path).is_some()
+        || (in_sidecar && LibraryItemFormat::is_metadata_path(path))
+}
+
+/// One entry of `sftp`'s `ls -ln` output.
+#[derive(Debug, PartialEq, Eq)]
+struct LsEntry {
+    is_dir: bool,
+    size: u64,
+    modified: String,
+    name: String,
+}
+
+/// Parse a line like
+/// `-rw-r--r--    1 0        0          123456 Mar  4 12:30 /mnt/us/a.epub`.
+/// Other lines, such as the echoed batch commands, yield `None`.
+fn parse_ls_line(line: &str) -> Option<LsEntry> {
+    let mut fields = Vec::with_capacity(8);
+    let mut rest = line;
+    for _ in 0..8 {
+        rest = rest.trim_start();
+        let end = rest.find(char::is_whitespace)?;
+        fields.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    let name = rest.strip_prefix(' ')?;
+    let kind = fields[0].chars().next()?;
+    if !matches!(kind, '-' | 'd') || fields[0].len() != 10 || name.is_empty() {
+        return None;
+    }
+
+    Some(LsEntry {
+        is_dir: kind == 'd',
+        size: fields[4].parse().ok()?,
+        modified: fields[5..8].join(" "),
+        name: unescape_octal(name),
+    })
+}
+
+/// `sftp` prints bytes it can't show in the current locale as `\ooo`.
+fn unescape_octal(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let octal = bytes.get(i + 1..i + 4).filter(|digits| {
+            bytes[i] == b'\\' && digits.iter().all(|digit| (b'0'..=b'7').contains(digit))
+        });
+        match octal.and_then(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 8).ok())
+        {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 4;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn quote_sftp_arg(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Quote a remote path, also escaping the characters `sftp` would expand as
+/// a glob.
+fn quote_sftp_remote(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '"' | '*' | '?' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    format!("\"{}\"", escaped)
+}
+
+/// Where `file` lives below a mirror at `root`.
+fn local_path(root: &Path, file: &RemoteFile) -> PathBuf {
+    if file.path.is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(&file.path)
+    }
+}
+
+/// Mirror `source` into `cache_path`, retrying failed transfers. Falls back
+/// to the existing mirror when every attempt fails.
+pub fn sync_to_cache(source: &dyn RemoteSource, cache_path: &Path) -> Result<PathBuf> {
+    sync_with_retry_delay(source, cache_path, RETRY_DELAY)
+}
+
+fn sync_with_retry_delay(
+    source: &dyn RemoteSource,
+    cache_path: &Path,
+    retry_delay: Duration,
+) -> Result<PathBuf> {
+    let parent = cache_path
+        .parent()
+        .context("Remote cache path has no parent directory")?;
+    fs::create_dir_all(parent)
+        .with_context(|| format!("Failed to create remote cache directory {:?}", parent))?;
+
+    let mut last_error = None;
+    for attempt in 1..=MAX_ATTEMPTS {
+        match mirror(source, cache_path) {
+            Ok(changes) => {
+                if changes.fetched > 0 || changes.removed > 0 {
+                    info!(
+                        "Synced {} to {:?} ({} files updated, {} removed)",
+                        source.describe(),
+                        cache_path,
+                        changes.fetched,
+                        changes.removed
+                    );
+                } else {
+                    debug!("{} is unchanged", source.describe());
+                }
+                return Ok(cache_path.to_path_buf());
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to sync {} (attempt {}/{}): {}",
+                    source.describe(),
+                    attempt,
+                    MAX_ATTEMPTS,
+                    e
+                );
+                last_error = Some(e);
+                if attempt < MAX_ATTEMPTS {
+                    std::thread::sleep(retry_delay * attempt);
+                }
+            }
+        }
+    }
+    remove_path(&cache_path.with_extension("partial"))?;
+
+    if cache_path.exists() {
+        warn!(
+            "{} is unreachable, using the copy from the last successful sync",
+            source.describe()
+        );
+        return Ok(cache_path.to_path_buf());
+    }
+
+    Err(last_error.unwrap_or_else(|| anyhow::anyhow!("no attempts made"))).with_context(|| {
+        format!(
+            "Failed to sync {} and no cached copy exists",
+            source.describe()
+        )
+    })
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct MirrorChanges {
+    fetched: usize,
+    removed: usize,
+}
+
+/// Bring the mirror at `cache_path` in line with the remote listing.
+///
+/// Files are downloaded into a staging directory first and moved into the
+/// mirror one by one, so a rebuild reading the mirror never sees a partial
+/// file. The listing is saved next to the mirror to tell which files changed
+/// on the next sync.
+fn mirror(source: &dyn RemoteSource, cache_path: &Path) -> Result<MirrorChanges> {
+    let listing = source.list()?;
+    let listing_path = cache_path.with_extension("listing.json");
+    let previous: BTreeMap<String, RemoteFile> = fs::read(&listing_path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<Vec<RemoteFile>>(&bytes).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|file| (file.path.clone(), file))
+        .collect();
+
+    let is_single_file = matches!(listing.as_slice(), [file] if file.path.is_empty());
+    if !is_single_file {
+        fs::create_dir_all(cache_path)
+            .with_context(|| format!("Failed to create remote mirror {:?}", cache_path))?;
+    }
+
+    let changed: Vec<RemoteFile> = listing
+        .iter()
+        .filter(|file| {
+            previous.get(&file.path) != Some(*file) || !local_path(cache_path, file).is_file()
+        })
+        .cloned()
+        .collect();
+
+    let mut changes = MirrorChanges::default();
+    if !changed.is_empty() {
+        let staging = cache_path.with_extension("partial");
+        remove_path(&staging)?;
+        source.fetch(&changed, &staging)?;
+        for file in &changed {
+            let from = local_path(&staging, file);
+            let to = local_path(cache_path, file);
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {:?}", parent))?;
+            }
+            fs::rename(&from, &to)
+                .with_context(|| format!("Failed to move {:?} into the remote mirror", from))?;
+        }
+        remove_path(&staging)?;
+        changes.fetched = changed.len();
+    }
+
+    if !is_single_file {
+        changes.removed = remove_unlisted(cache_path, &listing)?;
+    }
+
+    let json = serde_json::to_vec(&listing).context("Failed to encode remote listing")?;
+    fs::write(&listing_path, json)
+        .with_context(|| format!("Failed to write remote listing {:?}", listing_path))?;
+    Ok(changes)
+}
+
+/// Delete files the remote no longer lists, then the folders left empty.
+fn remove_unlisted(cache_path: &Path, listing: &[RemoteFile]) -> Result<usize> {
+    let listed: HashSet<PathBuf> = listing
+        .iter()
+        .map(|file| local_path(cache_path, file))
+        .collect();
+
+    let mut removed = 0;
+    for entry in walkdir::WalkDir::new(cache_path)
+        .contents_first(true)
+        .min_depth(1)
+    {
+        let entry = entry.with_context(|| format!("Failed to read {:?}", cache_path))?;
+        if entry.file_type().is_dir() {
+            // Only succeeds once the folder is empty.
+            let _ = fs::remove_dir(entry.path());
+        } else if !listed.contains(entry.path()) {
+            fs::remove_file(entry.path())
+                .with_context(|| format!("Failed to remove {:?}", entry.path()))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+fn remove_path(path: &Path) -> Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path).with_context(|| format!("Failed to remove {:?}", path))?;
+    } else if path.exists() {
+        fs::remove_file(path).with_context(|| format!("Failed to remove {:?}", path))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    /// Fails the first `failures` syncs, then lists and writes a marker file.
+    struct FlakySource {
+        failures: u32,
+        calls: Cell<u32>,
+    }
+
+    impl RemoteSource for FlakySource {
+        fn describe(&self) -> String {
+            "flaky".to_string()
+        }
+
+        fn list(&self) -> Result<Vec<RemoteFile>> {
+            self.calls.set(self.calls.get() + 1);
+            if self.calls.get() <= self.failures {
+                bail!("connection refused");
+            }
+            Ok(vec![RemoteFile {
+                path: "marker".to_string(),
+                size: 1,
+                modified: self.calls.get().to_string(),
+            }])
+        }
+
+        fn fetch(&self, files: &[RemoteFile], destination: &Path) -> Result<()> {
+            for file in files {
+                fs::create_dir_all(destination)?;
+                fs::write(local_path(destination, file), &file.modified)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Serves `files` (their contents are their modification times) and
+    /// records what was downloaded.
+    #[derive(Default)]
+    struct FakeSource {
+        files: RefCell<Vec<RemoteFile>>,
+        fetched: RefCell<Vec<String>>,
+    }
+
+    impl FakeSource {
+        fn set(&self, files: &[(&str, &str)]) {
+            *self.files.borrow_mut() = files
+                .iter()
+                .map(|(path, modified)| RemoteFile {
+                    path: path.to_string(),
+                    size: modified.len() as u64,
+                    modified: modified.to_string(),
+                })
+                .collect();
+        }
+    }
+
+    impl RemoteSource for FakeSource {
+        fn describe(&self) -> String {
+            "fake".to_string()
+        }
+
+        fn list(&self) -> Result<Vec<RemoteFile>> {
+            Ok(self.files.borrow().clone())
+        }
+
+        fn fetch(&self, files: &[RemoteFile], destination: &Path) -> Result<()> {
+            for file in files {
+                let path = local_path(destination, file);
+                fs::create_dir_all(path.parent().unwrap())?;
+                fs::write(path, &file.modified)?;
+                self.fetched.borrow_mut().push(file.path.clone());
+            }
+            Ok(())
+        }
+    }
+
+    fn flaky(failures: u32) -> FlakySource {
+        FlakySource {
+            failures,
+            calls: Cell::new(0),
+        }
+    }
+
+    #[test]
+    fn parses_sftp_urls() {
+        let source = SftpSource::parse("sftp://root@kindle:2222/mnt/us/documents/").unwrap();
+        assert_eq!(source.user.as_deref(), Some("root"));
+        assert_eq!(source.host, "kindle");
+        assert_eq!(source.port, Some(2222));
+        assert_eq!(source.path, "/mnt/us/documents");
+        assert_eq!(source.kind(), RemoteSourceKind::Library);
+        assert_eq!(
+            source.describe(),
+            "sftp://root@kindle:2222/mnt/us/documents"
+        );
+
+        let source = SftpSource::parse("sftp://kobo/~/koreader/statistics.sqlite3").unwrap();
+        assert_eq!(source.user, None);
+        assert_eq!(source.path, "koreader/statistics.sqlite3");
+        assert_eq!(source.kind(), RemoteSourceKind::StatisticsDb);
+    }
+
+    #[test]
+    fn rejects_invalid_urls() {
+        for url in [
+            "/local/path",
+            "ssh://host/path",
+            "sftp://host",
+            "sftp://host/",
+            "sftp://@host/path",
+            "sftp://host:port/path",
+        ] {
+            assert!(SftpSource::parse(url).is_err(), "{url} should be rejected");
+        }
+    }
+
+    #[test]
+    fn retries_until_transfer_succeeds() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("library");
+        let source = flaky(2);
+
+        let path = sync_with_retry_delay(&source, &cache_path, Duration::ZERO).unwrap();
+        assert_eq!(path, cache_path);
+        assert_eq!(source.calls.get(), 3);
+        assert_eq!(fs::read_to_string(cache_path.join("marker")).unwrap(), "3");
+        assert!(!cache_path.with_extension("partial").exists());
+    }
+
+    #[test]
+    fn reuses_cached_copy_when_remote_is_unreachable() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("library");
+        sync_with_retry_delay(&flaky(0), &cache_path, Duration::ZERO).unwrap();
+
+        let offline = flaky(u32::MAX);
+        let path = sync_with_retry_delay(&offline, &cache_path, Duration::ZERO).unwrap();
+        assert_eq!(path, cache_path);
+        assert_eq!(offline.calls.get(), MAX_ATTEMPTS);
+        assert_eq!(fs::read_to_string(cache_path.join("marker")).unwrap(), "1");
+    }
+
+    #[test]
+    fn fails_without_cached_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = sync_with_retry_delay(
+            &flaky(u32::MAX),
+            &dir.path().join("library"),
+            Duration::ZERO,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no cached copy"), "{err}");
+    }
+
+    #[test]
+    fn mirror_downloads_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("library");
+        let source = FakeSource::default();
+        source.set(&[
+            ("A.epub", "Mar  4 12:30"),
+            ("A.sdr/metadata.epub.lua", "Mar  4 12:30"),
+            ("sub/B.epub", "Mar  4 12:30"),
+        ]);
+        mirror(&source, &cache_path).unwrap();
+        assert_eq!(source.fetched.borrow().len(), 3);
+
+        source.fetched.borrow_mut().clear();
+        source.set(&[
+            ("A.epub", "Mar  4 12:30"),
+            ("A.sdr/metadata.epub.lua", "Mar  5 09:15"),
+        ]);
+        let changes = mirror(&source, &cache_path).unwrap();
+        assert_eq!(
+            changes,
+            MirrorChanges {
+                fetched: 1,
+                removed: 1
+            }
+        );
+        assert_eq!(*source.fetched.borrow(), ["A.sdr/metadata.epub.lua"]);
+        assert_eq!(
+            fs::read_to_string(cache_path.join("A.sdr/metadata.epub.lua")).unwrap(),
+            "Mar  5 09:15"
+        );
+        assert!(!cache_path.join("sub").exists());
+        assert!(!cache_path.with_extension("partial").exists());
+
+        // A file deleted from the mirror is downloaded again.
+        source.fetched.borrow_mut().clear();
+        fs::remove_file(cache_path.join("A.epub")).unwrap();
+        mirror(&source, &cache_path).unwrap();
+        assert_eq!(*source.fetched.borrow(), ["A.epub"]);
+    }
+
+    #[test]
+    fn mirror_writes_single_file_sources_to_the_cache_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("statistics.sqlite3");
+        let source = FakeSource::default();
+        source.set(&[("", "Mar  4 12:30")]);
+
+        mirror(&source, &cache_path).unwrap();
+        assert_eq!(fs::read_to_string(&cache_path).unwrap(), "Mar  4 12:30");
+        assert_eq!(
+            mirror(&source, &cache_path).unwrap(),
+            MirrorChanges::default()
+        );
+    }
+
+    #[test]
+    fn parses_sftp_long_listings() {
+        assert_eq!(
+            parse_ls_line(
+                "-rw-r--r--    1 0        0          123456 Mar  4 12:30 /mnt/us/My Book.epub"
+            ),
+            Some(LsEntry {
+                is_dir: false,
+                size: 123456,
+                modified: "Mar 4 12:30".to_string(),
+                name: "/mnt/us/My Book.epub".to_string(),
+            })
+        );
+        let dir = parse_ls_line(
+            "drwxr-xr-x    2 0        0            4096 Jan  1  2024 /mnt/us/Caf\\303\\251.sdr",
+        )
+        .unwrap();
+        assert!(dir.is_dir);
+        assert_eq!(dir.modified, "Jan 1 2024");
+        assert_eq!(dir.name, "/mnt/us/Café.sdr");
+
+        assert_eq!(parse_ls_line("sftp> ls -ln \"/mnt/us\""), None);
+        assert_eq!(
+            parse_ls_line("lrwxrwxrwx    1 0        0              11 Mar  4 12:30 /mnt/us/link"),
+            None
+        );
+    }
+
+    #[test]
+    fn mirrors_only_books_and_sidecars() {
+        assert!(is_library_file("sub/Book.epub"));
+        assert!(is_library_file("Book.fb2.zip"));
+        assert!(is_library_file("sub/Book.sdr/metadata.epub.lua"));
+        assert!(!is_library_file("Book.pdf"));
+        assert!(!is_library_file("Book.sdr/Book.epub.history.lua"));
+        assert!(!is_library_file("metadata.epub.lua"));
+    }
+
+    #[test]
+    fn quotes_remote_paths_against_globbing() {
+        assert_eq!(
+            quote_sftp_remote("/books/[2024] \"Best\"*.epub"),
+            "\"/books/\\[2024\\] \\\"Best\\\"\\*.epub\""
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! cover generation now live in `runtime::ingest::library`.
 
 use crate::shelf::models::LibraryItemFormat;
+use crate::shelf::utils::percent_decode;
 use crate::source::kobo::{KoboDbParser, KoboFileHints};
 use log::{info, warn};
 use std::collections::HashMap;
@@ -171,37 +172,6 @@ fn push_unique(values: &mut Vec<String>, value: String) {
     }
 }
 
-fn percent_decode(input: &str) -> String {
-    let bytes = input.as_bytes();
-    let mut output = Vec::with_capacity(bytes.len());
-    let mut i = 0;
-
-    while i < bytes.len() {
-        if bytes[i] == b'%'
-            && i + 2 < bytes.len()
-            && let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2]))
-        {
-            output.push(high * 16 + low);
-            i += 3;
-            continue;
-        }
-
-        output.push(bytes[i]);
-        i += 1;
-    }
-
-    String::from_utf8_lossy(&output).into_owned()
-}
-
-fn hex_value(byte: u8) -> Option<u8> {
-    match byte {
-        b'0'..=b'9' => Some(byte - b'0'),
-        b'a'..=b'f' => Some(byte - b'a' + 10),
-        b'A'..=b'F' => Some(byte - b'A' + 10),
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::{CollectionOptions, collect_paths};
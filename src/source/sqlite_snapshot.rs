@@ -1,6 +1,16 @@
 use anyhow::{Context, Result};
+use log::{debug, warn};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often a snapshot is retaken when the source changes mid-copy.
+const SNAPSHOT_ATTEMPTS: u32 = 5;
+const SNAPSHOT_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Busy timeout for connections to snapshot copies. Copies are private, so
+/// this only matters if SQLite needs to rebuild the WAL index on open.
+pub(crate) const SNAPSHOT_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Return the main SQLite database path and its WAL/SHM companion paths.
 pub(crate) fn sqlite_snapshot_paths(db_path: &Path) -> [PathBuf; 3] {
@@ -19,7 +29,36 @@ pub(crate) fn is_sqlite_db_or_companion(path: &Path, db_path: &Path) -> bool {
 }
 
 /// Copy a SQLite database and any present WAL/SHM companions to a temporary DB path.
+///
+/// The source may be open in a live writer (e.g. KOReader while reading), so
+/// the copy is retaken when the files change while being copied; otherwise the
+/// main file and the WAL could come from different transactions. If the source
+/// keeps changing, the last copy is used and SQLite recovers what it can.
 pub(crate) fn copy_sqlite_snapshot(source_db: &Path, temp_db: &Path) -> Result<()> {
+    for attempt in 1..=SNAPSHOT_ATTEMPTS {
+        let before = snapshot_fingerprint(source_db);
+        copy_snapshot_files(source_db, temp_db)?;
+        if snapshot_fingerprint(source_db) == before {
+            return Ok(());
+        }
+
+        if attempt < SNAPSHOT_ATTEMPTS {
+            debug!(
+                "SQLite database {:?} changed while copying, retrying (attempt {}/{})",
+                source_db, attempt, SNAPSHOT_ATTEMPTS
+            );
+            std::thread::sleep(SNAPSHOT_RETRY_DELAY);
+        }
+    }
+
+    warn!(
+        "SQLite database {:?} kept changing while copying; using the last copy",
+        source_db
+    );
+    Ok(())
+}
+
+fn copy_snapshot_files(source_db: &Path, temp_db: &Path) -> Result<()> {
     fs::copy(source_db, temp_db).with_context(|| {
         format!(
             "Failed to copy SQLite database from {:?} to {:?}",
@@ -29,11 +68,18 @@ pub(crate) fn copy_sqlite_snapshot(source_db: &Path, temp_db: &Path) -> Result<(
 
     for suffix in ["wal", "shm"] {
         let source = sqlite_companion_path(source_db, suffix);
+        let destination = sqlite_companion_path(temp_db, suffix);
         if !source.exists() {
+            // A companion left over from an earlier attempt (the writer has
+            // since checkpointed) would replay stale pages over the new copy.
+            if destination.exists() {
+                fs::remove_file(&destination).with_context(|| {
+                    format!("Failed to remove stale SQLite companion {:?}", destination)
+                })?;
+            }
             continue;
         }
 
-        let destination = sqlite_companion_path(temp_db, suffix);
         fs::copy(&source, &destination).with_context(|| {
             format!(
                 "Failed to copy SQLite companion from {:?} to {:?}",
@@ -45,6 +91,18 @@ pub(crate) fn copy_sqlite_snapshot(source_db: &Path, temp_db: &Path) -> Result<(
     Ok(())
 }
 
+/// Size and modification time of the database and its companions, used to
+/// detect writes that happened during a copy.
+fn snapshot_fingerprint(db_path: &Path) -> Vec<Option<(u64, SystemTime)>> {
+    sqlite_snapshot_paths(db_path)
+        .iter()
+        .map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            Some((metadata.len(), metadata.modified().ok()?))
+        })
+        .collect()
+}
+
 fn sqlite_companion_path(db_path: &Path, suffix: &str) -> PathBuf {
     let Some(filename) = db_path.file_name() else {
         return db_path.with_extension(suffix);
@@ -105,4 +163,26 @@ mod tests {
             b"shm"
         );
     }
+
+    #[test]
+    fn removes_companions_the_source_no_longer_has() {
+        let source_dir = tempfile::tempdir().expect("source temp dir");
+        let temp_dir = tempfile::tempdir().expect("destination temp dir");
+        let source_db = source_dir.path().join("statistics.sqlite3");
+        let source_wal = source_dir.path().join("statistics.sqlite3-wal");
+        let temp_db = temp_dir.path().join("statistics.db");
+
+        fs::write(&source_db, b"main").expect("source main");
+        fs::write(&source_wal, b"wal").expect("source wal");
+        copy_sqlite_snapshot(&source_db, &temp_db).expect("first copy");
+        assert!(temp_dir.path().join("statistics.db-wal").exists());
+
+        // The writer checkpointed and removed its WAL.
+        fs::write(&source_db, b"checkpointed").expect("source main");
+        fs::remove_file(&source_wal).expect("remove wal");
+        copy_sqlite_snapshot(&source_db, &temp_db).expect("second copy");
+
+        assert_eq!(fs::read(&temp_db).expect("temp main"), b"checkpointed");
+        assert!(!temp_dir.path().join("statistics.db-wal").exists());
+    }
 }
@@ -3,7 +3,8 @@
 //! Reading endpoints compute responses on demand from this data,
 //! applying scope, date-range, and timezone filters at request time.
 
-use crate::shelf::statistics::PageScaling;
+use crate::shelf::statistics::calendar::CalendarColorBy;
+use crate::shelf::statistics::{PageScaling, StatisticsConfig};
 use crate::shelf::time_config::TimeConfig;
 use crate::source::koreader::types::StatisticsData;
 use std::sync::{Arc, RwLock};
@@ -14,9 +15,14 @@ use std::sync::{Arc, RwLock};
 pub struct ReadingData {
     pub stats_data: StatisticsData,
     pub time_config: TimeConfig,
+    pub stats_config: StatisticsConfig,
     pub heatmap_scale_max: Option<u32>,
     /// Page scaling factors for synthetic page counts.
     pub page_scaling: PageScaling,
+    /// What decides the color of calendar events.
+    pub calendar_color_by: CalendarColorBy,
+    /// Whether recap month groups include months without completions.
+    pub recap_show_empty_months: bool,
 }
 
 /// Thread-safe store for [`ReadingData`], swapped atomically on statistics reload.
@@ -2,8 +2,8 @@
 
 use serde::Serialize;
 use std::sync::{
-    Arc,
-    atomic::{AtomicU64, Ordering},
+    Arc, RwLock,
+    atomic::{AtomicBool, AtomicU64, Ordering},
 };
 use tokio::sync::watch;
 
@@ -20,6 +20,8 @@ struct UpdateNotifierInner {
     revision_epoch: String,
     revision: AtomicU64,
     tx: watch::Sender<Update>,
+    rebuilding: AtomicBool,
+    last_build_at: RwLock<String>,
 }
 
 /// Publishes revision updates for SSE consumers.
@@ -31,10 +33,11 @@ pub struct UpdateNotifier {
 impl UpdateNotifier {
     pub fn new(revision_epoch: impl Into<String>, initial_generated_at: impl Into<String>) -> Self {
         let revision_epoch = revision_epoch.into();
+        let initial_generated_at = initial_generated_at.into();
         let initial_update = Update {
             revision_epoch: revision_epoch.clone(),
             revision: 0,
-            generated_at: initial_generated_at.into(),
+            generated_at: initial_generated_at.clone(),
         };
         let (tx, _rx) = watch::channel(initial_update);
 
@@ -43,6 +46,8 @@ impl UpdateNotifier {
                 revision_epoch,
                 revision: AtomicU64::new(0),
                 tx,
+                rebuilding: AtomicBool::new(false),
+                last_build_at: RwLock::new(initial_generated_at),
             }),
         }
     }
@@ -51,6 +56,11 @@ impl UpdateNotifier {
         self.inner.tx.subscribe()
     }
 
+    /// The most recently published update.
+    pub fn latest(&self) -> Update {
+        self.inner.tx.borrow().clone()
+    }
+
     pub fn publish(&self, generated_at: impl Into<String>) -> Update {
         let revision = self.inner.revision.fetch_add(1, Ordering::Relaxed) + 1;
 
@@ -64,6 +74,49 @@ impl UpdateNotifier {
         let _ = self.inner.tx.send(update.clone());
         update
     }
+
+    /// Mark a rebuild as in progress until the returned guard is dropped.
+    pub fn begin_rebuild(&self) -> RebuildGuard {
+        self.inner.rebuilding.store(true, Ordering::Relaxed);
+        RebuildGuard {
+            inner: self.inner.clone(),
+        }
+    }
+
+    pub fn is_rebuilding(&self) -> bool {
+        self.inner.rebuilding.load(Ordering::Relaxed)
+    }
+
+    /// Record the completion time of a successful build, whether or not it
+    /// changed any data.
+    pub fn record_build(&self, built_at: impl Into<String>) {
+        *self
+            .inner
+            .last_build_at
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = built_at.into();
+    }
+
+    pub fn last_build_at(&self) -> String {
+        self.inner
+            .last_build_at
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+/// Clears the rebuilding flag when dropped, so failed or panicking rebuilds
+/// do not leave it stuck.
+#[derive(Debug)]
+pub struct RebuildGuard {
+    inner: Arc<UpdateNotifierInner>,
+}
+
+impl Drop for RebuildGuard {
+    fn drop(&mut self) {
+        self.inner.rebuilding.store(false, Ordering::Relaxed);
+    }
 }
 
 #[cfg(test)]
@@ -93,4 +146,21 @@ mod tests {
         assert_eq!(initial.revision, 0);
         assert_eq!(initial.generated_at, "2026-03-11T11:00:00Z");
     }
+
+    #[test]
+    fn rebuild_guard_tracks_progress_and_builds_are_recorded() {
+        let notifier = UpdateNotifier::new("serve_epoch", "2026-03-11T11:00:00Z");
+        assert!(!notifier.is_rebuilding());
+        assert_eq!(notifier.last_build_at(), "2026-03-11T11:00:00Z");
+
+        let guard = notifier.begin_rebuild();
+        assert!(notifier.is_rebuilding());
+        notifier.record_build("2026-03-11T11:05:00Z");
+        drop(guard);
+
+        assert!(!notifier.is_rebuilding());
+        assert_eq!(notifier.last_build_at(), "2026-03-11T11:05:00Z");
+        // A build without data changes does not bump the revision.
+        assert_eq!(notifier.latest().revision, 0);
+    }
 }
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS kosync_progress (
+    username TEXT NOT NULL,
+    document TEXT NOT NULL,
+    progress TEXT NOT NULL,
+    percentage REAL NOT NULL,
+    device TEXT NOT NULL,
+    device_id TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (username, document)
+);
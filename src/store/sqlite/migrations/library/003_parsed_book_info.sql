@@ -0,0 +1,9 @@
+-- Parsed book file metadata, reused while the file's size and mtime and the
+-- KoShelf version that parsed it are unchanged.
+CREATE TABLE IF NOT EXISTS parsed_book_info (
+    book_path TEXT PRIMARY KEY,
+    book_size_bytes INTEGER NOT NULL CHECK (book_size_bytes >= 0),
+    book_modified_unix_ms INTEGER NOT NULL CHECK (book_modified_unix_ms >= 0),
+    parser_version TEXT NOT NULL,
+    book_info_json TEXT NOT NULL
+);
@@ -0,0 +1,6 @@
+-- Editors and translators, kept apart from authors.
+ALTER TABLE library_items ADD COLUMN contributors_json TEXT;
+
+-- Force a one-time re-parse so existing items pick up creator roles.
+DELETE FROM library_item_fingerprints;
+DELETE FROM parsed_book_info;
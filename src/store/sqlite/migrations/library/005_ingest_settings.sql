@@ -0,0 +1,6 @@
+-- Settings that change how parsed metadata is stored. When one differs from
+-- the stored value, every book is ingested again.
+CREATE TABLE IF NOT EXISTS ingest_settings (
+    name TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
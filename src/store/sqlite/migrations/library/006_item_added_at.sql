@@ -0,0 +1,6 @@
+-- When the book file was added (its creation time, or modification time where
+-- the filesystem has none). Clearing fingerprints re-ingests existing items
+-- once so the column is filled in.
+ALTER TABLE library_items ADD COLUMN added_at TEXT;
+
+DELETE FROM library_item_fingerprints;
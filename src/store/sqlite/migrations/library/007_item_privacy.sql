@@ -0,0 +1,6 @@
+-- Items tagged `koshelf:private` keep their annotations and review note off
+-- the site. Clearing fingerprints re-ingests existing items once so the flag
+-- is filled in.
+ALTER TABLE library_items ADD COLUMN is_private INTEGER NOT NULL DEFAULT 0;
+
+DELETE FROM library_item_fingerprints;
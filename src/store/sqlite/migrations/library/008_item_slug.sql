@@ -0,0 +1,5 @@
+-- Readable URL alias for an item (`--id-scheme slug`), NULL under the hash
+-- scheme. Assigned after every library sync.
+ALTER TABLE library_items ADD COLUMN slug TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_library_items_slug ON library_items (slug);
@@ -0,0 +1,11 @@
+-- Slugs an item used to have, so old links keep resolving after a title or
+-- author change. Rows go away with their item, so a removed book's URLs
+-- stop resolving instead of pointing at another book.
+CREATE TABLE IF NOT EXISTS library_item_slug_history (
+    slug TEXT PRIMARY KEY,
+    item_id TEXT NOT NULL,
+    FOREIGN KEY (item_id) REFERENCES library_items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_library_item_slug_history_item
+    ON library_item_slug_history (item_id);
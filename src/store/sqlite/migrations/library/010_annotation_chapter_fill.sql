@@ -0,0 +1,4 @@
+-- Annotations without a KOReader chapter now get one from the book's table
+-- of contents. Clearing fingerprints re-ingests existing items once so their
+-- stored annotations pick it up.
+DELETE FROM library_item_fingerprints;
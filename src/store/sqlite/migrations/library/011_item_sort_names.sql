@@ -0,0 +1,8 @@
+-- Sort names for titles and authors ("Hobbit, The", "Tolkien, J. R. R."),
+-- taken from the book file or derived from the title. Parsed book info and
+-- fingerprints are cleared so existing books are re-read for them.
+ALTER TABLE library_items ADD COLUMN title_sort TEXT;
+ALTER TABLE library_items ADD COLUMN author_sort TEXT;
+
+DELETE FROM parsed_book_info;
+DELETE FROM library_item_fingerprints;
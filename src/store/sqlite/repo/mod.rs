@@ -55,7 +55,10 @@ pub(crate) mod tests {
             format: "epub".to_string(),
             content_type: "book".to_string(),
             title: format!("Book {id}"),
+            title_sort: None,
             authors_json: r#"["Jane Doe"]"#.to_string(),
+            author_sort: None,
+            contributors_json: None,
             series_json: None,
             description: None,
             language: Some("en".to_string()),
@@ -80,6 +83,8 @@ pub(crate) mod tests {
             reader_presentation: None,
             chapters_json: "[]".to_string(),
             last_open_at: None,
+            added_at: None,
+            is_private: false,
             total_reading_time_sec: None,
             created_at: "2026-01-01T00:00:00Z".to_string(),
             updated_at: "2026-01-01T00:00:00Z".to_string(),
@@ -4,13 +4,15 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 use log::warn;
+use sqlx::types::Json;
 
 use crate::server::api::responses::library::{
-    LibraryAnnotation, LibraryDetailItem, LibraryListItem,
+    HighlightQuote, LibraryAnnotation, LibraryDetailItem, LibraryListItem,
 };
 use crate::shelf::library::queries::{ItemSort, LibraryListQuery, SortOrder};
 use crate::shelf::models::ChapterEntry;
-use crate::shelf::models::ContentType;
+use crate::shelf::models::{BookInfo, ContentType};
+use crate::source::FileFingerprint;
 
 use crate::store::sqlite::repo::LibraryRepository;
 use crate::store::sqlite::repo::rows::FingerprintRow;
@@ -35,11 +37,13 @@ impl LibraryRepository {
             "SELECT
                 i.id, i.title, i.authors_json, i.series_json, i.status,
                 i.progress_percentage, i.rating, i.cover_url, i.content_type, i.format,
-                i.language, i.publisher, i.description, i.review_note,
+                i.contributors_json, i.added_at, i.language, i.publisher, i.description,
+                CASE WHEN i.is_private THEN NULL ELSE i.review_note END AS review_note,
+                i.is_private,
                 COALESCE(i.pagemap_doc_pages, i.doc_pages, i.parser_pages) as pages,
                 i.search_base_path, i.subjects_json, i.identifiers_json,
                 (f.metadata_path IS NOT NULL) AS has_metadata,
-                i.partial_md5_checksum, i.reader_presentation
+                i.partial_md5_checksum, i.reader_presentation, i.slug
              FROM library_items i
              LEFT JOIN library_item_fingerprints f ON f.item_id = i.id
              WHERE i.id = ?1"
@@ -47,11 +51,13 @@ impl LibraryRepository {
             "SELECT
                 i.id, i.title, i.authors_json, i.series_json, i.status,
                 i.progress_percentage, i.rating, i.cover_url, i.content_type, i.format,
-                i.language, i.publisher, i.description, i.review_note,
+                i.contributors_json, i.added_at, i.language, i.publisher, i.description,
+                CASE WHEN i.is_private THEN NULL ELSE i.review_note END AS review_note,
+                i.is_private,
                 COALESCE(i.doc_pages, i.parser_pages) as pages,
                 i.search_base_path, i.subjects_json, i.identifiers_json,
                 (f.metadata_path IS NOT NULL) AS has_metadata,
-                i.partial_md5_checksum, i.reader_presentation
+                i.partial_md5_checksum, i.reader_presentation, i.slug
              FROM library_items i
              LEFT JOIN library_item_fingerprints f ON f.item_id = i.id
              WHERE i.id = ?1"
@@ -85,6 +91,38 @@ impl LibraryRepository {
         .context("Failed to get annotations")
     }
 
+    /// Load every highlight with non-empty text across the library, ordered
+    /// by item title and then reading position. Bookmarks are excluded.
+    pub async fn list_highlight_quotes(&self) -> Result<Vec<HighlightQuote>> {
+        sqlx::query_as::<_, HighlightQuote>(
+            "SELECT a.item_id, i.title AS item_title, i.content_type, a.text, a.chapter
+             FROM library_annotations a
+             JOIN library_items i ON i.id = a.item_id
+             WHERE a.annotation_kind = 'highlight' AND TRIM(COALESCE(a.text, '')) != ''
+               AND NOT i.is_private
+             ORDER BY LOWER(i.title) ASC, a.item_id ASC, a.lua_index ASC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to list highlight quotes")
+    }
+
+    /// Load the subject lists of all items matching the content type filter.
+    /// Items without subjects are skipped.
+    pub async fn list_item_subjects(&self, content_type: Option<&str>) -> Result<Vec<Vec<String>>> {
+        let rows: Vec<(Json<Vec<String>>,)> = sqlx::query_as(
+            "SELECT subjects_json
+             FROM library_items
+             WHERE (?1 IS NULL OR content_type = ?1) AND subjects_json != '[]'",
+        )
+        .bind(content_type)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to list item subjects")?;
+
+        Ok(rows.into_iter().map(|(subjects,)| subjects.0).collect())
+    }
+
     /// Load chapter entries (fractional positions) for an item.
     pub async fn get_item_chapters(&self, item_id: &str) -> Result<Vec<ChapterEntry>> {
         let row: Option<(String,)> =
@@ -139,6 +177,45 @@ impl LibraryRepository {
         .context("Failed to load fingerprints")
     }
 
+    /// Load the stored value of an ingest setting.
+    pub async fn load_ingest_setting(&self, name: &str) -> Result<Option<String>> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT value FROM ingest_settings WHERE name = ?1")
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await
+                .context("Failed to load ingest setting")?;
+        Ok(row.map(|(value,)| value))
+    }
+
+    /// Load the cached parse result for a book file if its size and
+    /// modification time still match `fp` and it was parsed by this version.
+    pub async fn load_parsed_book_info(&self, fp: &FileFingerprint) -> Result<Option<BookInfo>> {
+        let row: Option<(Json<BookInfo>,)> = sqlx::query_as(
+            "SELECT book_info_json
+             FROM parsed_book_info
+             WHERE book_path = ?1 AND book_size_bytes = ?2 AND book_modified_unix_ms = ?3
+               AND parser_version = ?4",
+        )
+        .bind(fp.path.to_string_lossy())
+        .bind(fp.size_bytes as i64)
+        .bind(fp.modified_unix_ms as i64)
+        .bind(env!("CARGO_PKG_VERSION"))
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to load parsed book info")?;
+        Ok(row.map(|(info,)| info.0))
+    }
+
+    /// Load the book paths that have a cached parse result.
+    pub async fn load_parsed_book_info_paths(&self) -> Result<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as("SELECT book_path FROM parsed_book_info")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to load parsed book info paths")?;
+        Ok(rows.into_iter().map(|(path,)| path).collect())
+    }
+
     /// Query whether the library contains books and/or comics.
     pub async fn query_content_type_flags(&self) -> Result<(bool, bool)> {
         let row: (i32, i32) = sqlx::query_as(
@@ -154,6 +231,20 @@ impl LibraryRepository {
         Ok((row.0 != 0, row.1 != 0))
     }
 
+    /// Count library items as `(books, comics)`.
+    pub async fn count_items_by_content_type(&self) -> Result<(i64, i64)> {
+        let row: (i64, i64) = sqlx::query_as(
+            "SELECT
+                COALESCE(SUM(content_type = 'book'), 0),
+                COALESCE(SUM(content_type = 'comic'), 0)
+             FROM library_items",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to count items by content type")?;
+        Ok(row)
+    }
+
     /// Find the fingerprint row for a given book file path.
     pub async fn find_fingerprint_by_book_path(
         &self,
@@ -187,6 +278,96 @@ impl LibraryRepository {
         Ok(row.map(|r| r.0))
     }
 
+    /// Resolve an item ID, URL slug or retired slug or ID to the item ID.
+    pub async fn resolve_item_key(&self, key: &str) -> Result<Option<String>> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT id FROM library_items WHERE id = ?1
+             UNION ALL
+             SELECT id FROM library_items WHERE slug = ?1
+             UNION ALL
+             SELECT item_id FROM library_item_slug_history WHERE slug = ?1
+             LIMIT 1",
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to resolve item key")?;
+        Ok(row.map(|r| r.0))
+    }
+
+    /// When `key` is a retired slug or ID, the key the item is reachable under
+    /// now (its current slug, or its ID once slugs are off).
+    pub async fn retired_slug_target(&self, key: &str) -> Result<Option<String>> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT COALESCE(i.slug, i.id)
+             FROM library_item_slug_history h
+             JOIN library_items i ON i.id = h.item_id
+             WHERE h.slug = ?1",
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to look up retired item slug")?;
+        Ok(row.map(|r| r.0))
+    }
+
+    /// Every key `id` is reachable under: the ID itself, its slug and the
+    /// keys it retired earlier.
+    pub async fn load_item_keys(&self, id: &str) -> Result<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT id FROM library_items WHERE id = ?1
+             UNION
+             SELECT slug FROM library_items WHERE id = ?1 AND slug IS NOT NULL
+             UNION
+             SELECT slug FROM library_item_slug_history WHERE item_id = ?1",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load item keys")?;
+        Ok(rows.into_iter().map(|r| r.0).collect())
+    }
+
+    /// Load `retired slug -> item ID` for every item that has been renamed.
+    pub async fn load_retired_slugs(&self) -> Result<HashMap<String, String>> {
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT slug, item_id FROM library_item_slug_history")
+                .fetch_all(&self.pool)
+                .await
+                .context("Failed to load retired item slugs")?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Load `(id, title, authors_json, slug)` for every item, for slug assignment.
+    pub async fn load_slug_inputs(
+        &self,
+    ) -> Result<Vec<(String, String, Json<Vec<String>>, Option<String>)>> {
+        sqlx::query_as("SELECT id, title, authors_json, slug FROM library_items")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to load slug inputs")
+    }
+
+    /// Load `(id, title, authors)` for every item, used to link statistics
+    /// recorded under an older MD5.
+    pub async fn load_titles_and_authors(
+        &self,
+    ) -> Result<Vec<(String, String, Json<Vec<String>>)>> {
+        sqlx::query_as("SELECT id, title, authors_json FROM library_items")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to load titles and authors")
+    }
+
+    /// Load `(id, partial_md5_checksum)` for every item, to look up reading
+    /// statistics for items from a list query.
+    pub async fn load_partial_md5s(&self) -> Result<Vec<(String, Option<String>)>> {
+        sqlx::query_as("SELECT id, partial_md5_checksum FROM library_items")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to load partial MD5 checksums")
+    }
+
     /// Load all item IDs (canonical MD5s, used as library_md5s for stats filtering).
     pub async fn load_all_item_ids(&self) -> Result<Vec<String>> {
         let rows: Vec<(String,)> = sqlx::query_as("SELECT id FROM library_items")
@@ -414,9 +595,10 @@ macro_rules! list_items_query {
     ($order_by:literal) => {
         concat!(
             "SELECT
-                id, title, authors_json, series_json, status,
+                id, title, title_sort, authors_json, series_json, status,
                 progress_percentage, rating, annotation_count,
-                cover_url, content_type
+                highlight_count, bookmark_count,
+                cover_url, content_type, added_at, slug
              FROM library_items
              WHERE (?1 IS NULL OR content_type = ?1)
              ORDER BY ",
@@ -428,14 +610,18 @@ macro_rules! list_items_query {
 
 fn list_items_sql(sort: ItemSort, direction: SortOrder) -> &'static str {
     match (sort, direction) {
-        (ItemSort::Title, SortOrder::Asc) => list_items_query!("LOWER(title) ASC"),
-        (ItemSort::Title, SortOrder::Desc) => list_items_query!("LOWER(title) DESC"),
-        (ItemSort::Author, SortOrder::Asc) => {
-            list_items_query!("LOWER(JSON_EXTRACT(authors_json, '$[0]')) ASC")
+        (ItemSort::Title, SortOrder::Asc) => {
+            list_items_query!("LOWER(COALESCE(title_sort, title)) ASC")
         }
-        (ItemSort::Author, SortOrder::Desc) => {
-            list_items_query!("LOWER(JSON_EXTRACT(authors_json, '$[0]')) DESC")
+        (ItemSort::Title, SortOrder::Desc) => {
+            list_items_query!("LOWER(COALESCE(title_sort, title)) DESC")
         }
+        (ItemSort::Author, SortOrder::Asc) => list_items_query!(
+            "LOWER(COALESCE(author_sort, JSON_EXTRACT(authors_json, '$[0]'))) ASC"
+        ),
+        (ItemSort::Author, SortOrder::Desc) => list_items_query!(
+            "LOWER(COALESCE(author_sort, JSON_EXTRACT(authors_json, '$[0]'))) DESC"
+        ),
         (ItemSort::Status, SortOrder::Asc) => list_items_query!("status ASC"),
         (ItemSort::Status, SortOrder::Desc) => list_items_query!("status DESC"),
         (ItemSort::Progress, SortOrder::Asc) => list_items_query!("progress_percentage ASC"),
@@ -446,6 +632,17 @@ fn list_items_sql(sort: ItemSort, direction: SortOrder) -> &'static str {
         (ItemSort::Annotations, SortOrder::Desc) => list_items_query!("annotation_count DESC"),
         (ItemSort::LastOpenAt, SortOrder::Asc) => list_items_query!("last_open_at ASC"),
         (ItemSort::LastOpenAt, SortOrder::Desc) => list_items_query!("last_open_at DESC"),
+        // Bulk-copied files often share a timestamp, so ties go by title.
+        (ItemSort::AddedAt, SortOrder::Asc) => {
+            list_items_query!(
+                "julianday(added_at) ASC NULLS LAST, LOWER(COALESCE(title_sort, title)) ASC"
+            )
+        }
+        (ItemSort::AddedAt, SortOrder::Desc) => {
+            list_items_query!(
+                "julianday(added_at) DESC NULLS LAST, LOWER(COALESCE(title_sort, title)) ASC"
+            )
+        }
     }
 }
 
@@ -462,6 +659,85 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn resolve_item_key_accepts_id_or_slug() {
+        let repo = test_repo().await;
+        repo.upsert_item(&sample_item("book1")).await.unwrap();
+        repo.replace_item_slugs(&[("book1".to_string(), "book-doe".to_string())].into())
+            .await
+            .unwrap();
+
+        // Slugs survive re-ingesting the item.
+        repo.upsert_item(&sample_item("book1")).await.unwrap();
+
+        let resolve = async |key| repo.resolve_item_key(key).await.unwrap();
+        assert_eq!(resolve("book1").await.as_deref(), Some("book1"));
+        assert_eq!(resolve("book-doe").await.as_deref(), Some("book1"));
+        assert_eq!(resolve("missing").await, None);
+        assert_eq!(repo.retired_slug_target("book-doe").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn retired_slugs_redirect_until_the_item_is_deleted() {
+        let repo = test_repo().await;
+        repo.upsert_item(&sample_item("book1")).await.unwrap();
+        let set_slug = |slug: &str| [("book1".to_string(), slug.to_string())].into();
+
+        repo.replace_item_slugs(&set_slug("old-title-doe"))
+            .await
+            .unwrap();
+        repo.replace_item_slugs(&set_slug("new-title-doe"))
+            .await
+            .unwrap();
+
+        let target = async |key| repo.retired_slug_target(key).await.unwrap();
+        assert_eq!(
+            target("old-title-doe").await.as_deref(),
+            Some("new-title-doe")
+        );
+        assert_eq!(target("new-title-doe").await, None);
+
+        // With slugs turned off, old links point at the item ID.
+        repo.replace_item_slugs(&Default::default()).await.unwrap();
+        assert_eq!(target("new-title-doe").await.as_deref(), Some("book1"));
+
+        // Reusing a retired slug drops it from the history.
+        repo.replace_item_slugs(&set_slug("old-title-doe"))
+            .await
+            .unwrap();
+        assert_eq!(target("old-title-doe").await, None);
+        assert_eq!(repo.load_retired_slugs().await.unwrap().len(), 1);
+
+        repo.delete_item("book1").await.unwrap();
+        assert!(repo.load_retired_slugs().await.unwrap().is_empty());
+        assert_eq!(repo.resolve_item_key("new-title-doe").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn retired_item_keys_redirect_to_the_successor() {
+        let repo = test_repo().await;
+        repo.upsert_item(&sample_item("old-id")).await.unwrap();
+        repo.replace_item_slugs(&[("old-id".to_string(), "book-doe".to_string())].into())
+            .await
+            .unwrap();
+
+        let keys = repo.load_item_keys("old-id").await.unwrap();
+        repo.delete_item("old-id").await.unwrap();
+        repo.upsert_item(&sample_item("new-id")).await.unwrap();
+        repo.retire_item_keys(&keys, "new-id").await.unwrap();
+
+        let target = async |key| repo.retired_slug_target(key).await.unwrap();
+        assert_eq!(target("old-id").await.as_deref(), Some("new-id"));
+        assert_eq!(target("book-doe").await.as_deref(), Some("new-id"));
+
+        // Once the successor takes the slug back, only the old ID redirects.
+        repo.replace_item_slugs(&[("new-id".to_string(), "book-doe".to_string())].into())
+            .await
+            .unwrap();
+        assert_eq!(target("old-id").await.as_deref(), Some("book-doe"));
+        assert_eq!(target("book-doe").await, None);
+    }
+
     #[tokio::test]
     async fn list_items_filters_by_content_type() {
         let repo = test_repo().await;
@@ -500,6 +776,21 @@ mod tests {
         assert_eq!(comics[0].id, "comic1");
     }
 
+    #[tokio::test]
+    async fn list_items_splits_annotation_count() {
+        let repo = test_repo().await;
+        let mut item = sample_item("book1");
+        item.annotation_count = 5;
+        item.highlight_count = 3;
+        item.bookmark_count = 2;
+        repo.upsert_item(&item).await.unwrap();
+
+        let items = repo.list_items(&LibraryListQuery::default()).await.unwrap();
+        assert_eq!(items[0].annotation_count, 5);
+        assert_eq!(items[0].highlight_count, 3);
+        assert_eq!(items[0].bookmark_count, 2);
+    }
+
     #[tokio::test]
     async fn list_items_sorts_by_title_asc_by_default() {
         let repo = test_repo().await;
@@ -519,6 +810,38 @@ mod tests {
         assert_eq!(items[1].title, "Zebra");
     }
 
+    #[tokio::test]
+    async fn list_items_sorts_by_sort_names_when_present() {
+        let repo = test_repo().await;
+
+        let mut hobbit = sample_item("hobbit");
+        hobbit.title = "The Hobbit".to_string();
+        hobbit.title_sort = Some("Hobbit, The".to_string());
+        hobbit.authors_json = r#"["J. R. R. Tolkien"]"#.to_string();
+        hobbit.author_sort = Some("Tolkien, J. R. R.".to_string());
+        repo.upsert_item(&hobbit).await.unwrap();
+
+        let mut moby = sample_item("moby");
+        moby.title = "Moby-Dick".to_string();
+        moby.authors_json = r#"["Herman Melville"]"#.to_string();
+        repo.upsert_item(&moby).await.unwrap();
+
+        let by_title = repo.list_items(&LibraryListQuery::default()).await.unwrap();
+        assert_eq!(by_title[0].title, "The Hobbit");
+        assert_eq!(by_title[0].title_sort.as_deref(), Some("Hobbit, The"));
+        assert_eq!(by_title[1].title, "Moby-Dick");
+
+        let by_author = repo
+            .list_items(&LibraryListQuery {
+                sort: ItemSort::Author,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_author[0].id, "moby");
+        assert_eq!(by_author[1].id, "hobbit");
+    }
+
     #[tokio::test]
     async fn list_items_sorts_by_rating_desc() {
         let repo = test_repo().await;
@@ -544,6 +867,46 @@ mod tests {
         assert_eq!(items[1].rating, Some(1));
     }
 
+    #[tokio::test]
+    async fn list_items_sorts_by_added_at_with_title_tie_break() {
+        let repo = test_repo().await;
+
+        for (id, title, added_at) in [
+            ("old", "Old", Some("2023-05-01T10:00:00+02:00")),
+            ("bulk-b", "Beta", Some("2024-01-01T00:00:00+00:00")),
+            ("bulk-a", "alpha", Some("2024-01-01T01:00:00+01:00")),
+            ("unknown", "Unknown", None),
+        ] {
+            let mut item = sample_item(id);
+            item.title = title.to_string();
+            item.file_path = format!("/books/{id}.epub");
+            item.added_at = added_at.map(str::to_string);
+            repo.upsert_item(&item).await.unwrap();
+        }
+
+        let ids = |items: Vec<crate::server::api::responses::library::LibraryListItem>| {
+            items.into_iter().map(|item| item.id).collect::<Vec<_>>()
+        };
+        let newest_first = repo
+            .list_items(&LibraryListQuery {
+                sort: ItemSort::AddedAt,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(ids(newest_first), ["bulk-a", "bulk-b", "old", "unknown"]);
+
+        let oldest_first = repo
+            .list_items(&LibraryListQuery {
+                sort: ItemSort::AddedAt,
+                order: Some(SortOrder::Asc),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(ids(oldest_first), ["old", "bulk-a", "bulk-b", "unknown"]);
+    }
+
     #[tokio::test]
     async fn list_items_supports_all_sort_and_order_combinations() {
         let repo = test_repo().await;
@@ -557,6 +920,7 @@ mod tests {
             ItemSort::Rating,
             ItemSort::Annotations,
             ItemSort::LastOpenAt,
+            ItemSort::AddedAt,
         ] {
             for order in [SortOrder::Asc, SortOrder::Desc] {
                 let items = repo
@@ -574,6 +938,48 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn list_highlight_quotes_skips_bookmarks_and_empty_text() {
+        let repo = test_repo().await;
+        repo.upsert_item(&sample_item("hhh")).await.unwrap();
+        let mut empty = sample_annotation("hhh", "highlight", 2);
+        empty.text = Some("   ".to_string());
+        repo.replace_annotations(
+            "hhh",
+            &[
+                sample_annotation("hhh", "highlight", 0),
+                sample_annotation("hhh", "bookmark", 1),
+                empty,
+            ],
+        )
+        .await
+        .unwrap();
+
+        let quotes = repo.list_highlight_quotes().await.unwrap();
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].item_id, "hhh");
+        assert_eq!(quotes[0].item_title, "Book hhh");
+        assert_eq!(quotes[0].text, "highlighted text");
+    }
+
+    #[tokio::test]
+    async fn private_items_hide_highlight_quotes_and_review_note() {
+        let repo = test_repo().await;
+        let mut item = sample_item("ppp");
+        item.is_private = true;
+        item.review_note = Some("personal".to_string());
+        repo.upsert_item(&item).await.unwrap();
+        repo.replace_annotations("ppp", &[sample_annotation("ppp", "highlight", 0)])
+            .await
+            .unwrap();
+
+        assert!(repo.list_highlight_quotes().await.unwrap().is_empty());
+        let detail = repo.get_item("ppp").await.unwrap().unwrap();
+        assert!(detail.is_private);
+        assert_eq!(detail.review_note, None);
+        assert_eq!(detail.title, "Book ppp");
+    }
+
     #[tokio::test]
     async fn get_annotations_filters_by_kind() {
         let repo = test_repo().await;
@@ -609,4 +1015,29 @@ mod tests {
         assert_eq!(repo.count_items().await.unwrap(), 1);
         assert!(repo.item_exists("hhh").await.unwrap());
     }
+
+    #[tokio::test]
+    async fn list_item_subjects_filters_by_content_type_and_skips_empty() {
+        let repo = test_repo().await;
+
+        let mut book = sample_item("book1");
+        book.subjects_json = r#"["Fantasy","Sci-Fi"]"#.to_string();
+        let mut comic = sample_item("comic1");
+        comic.content_type = "comic".to_string();
+        comic.subjects_json = r#"["Manga"]"#.to_string();
+        let untagged = sample_item("book2");
+
+        repo.upsert_item(&book).await.unwrap();
+        repo.upsert_item(&comic).await.unwrap();
+        repo.upsert_item(&untagged).await.unwrap();
+
+        let all = repo.list_item_subjects(None).await.unwrap();
+        assert_eq!(all.len(), 2);
+
+        let books = repo.list_item_subjects(Some("book")).await.unwrap();
+        assert_eq!(
+            books,
+            vec![vec!["Fantasy".to_string(), "Sci-Fi".to_string()]]
+        );
+    }
 }
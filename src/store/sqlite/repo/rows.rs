@@ -11,7 +11,10 @@ pub struct LibraryItemRow {
     pub format: String,
     pub content_type: String,
     pub title: String,
+    pub title_sort: Option<String>,
     pub authors_json: String,
+    pub author_sort: Option<String>,
+    pub contributors_json: Option<String>,
     pub series_json: Option<String>,
     pub description: Option<String>,
     pub language: Option<String>,
@@ -37,6 +40,8 @@ pub struct LibraryItemRow {
     pub chapters_json: String,
     pub last_open_at: Option<String>,
     pub total_reading_time_sec: Option<i64>,
+    pub added_at: Option<String>,
+    pub is_private: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -1,8 +1,14 @@
 //! Write operations for the library repository.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
+use sqlx::types::Json;
 use sqlx::{QueryBuilder, Sqlite};
 
+use crate::shelf::models::BookInfo;
+use crate::source::FileFingerprint;
+
 use crate::store::sqlite::repo::LibraryRepository;
 use crate::store::sqlite::repo::rows::{AnnotationRow, FingerprintRow, LibraryItemRow};
 
@@ -11,24 +17,26 @@ impl LibraryRepository {
         sqlx::query(
             "INSERT INTO library_items (
                 id, file_path, format, content_type, title,
-                authors_json, series_json,
+                authors_json, series_json, contributors_json,
                 description, language, publisher, subjects_json, identifiers_json,
                 status, progress_percentage, rating, review_note,
                 doc_pages, pagemap_doc_pages, has_synthetic_pagination, parser_pages,
                 cover_url, search_base_path, annotation_count, bookmark_count,
                 highlight_count, partial_md5_checksum, hidden_flow_pages,
                 reader_presentation, chapters_json,
-                last_open_at, total_reading_time_sec, created_at, updated_at
+                last_open_at, total_reading_time_sec, added_at, is_private, created_at, updated_at,
+                title_sort, author_sort
             ) VALUES (
                 ?1, ?2, ?3, ?4, ?5,
-                ?6, ?7,
+                ?6, ?7, ?34,
                 ?8, ?9, ?10, ?11, ?12,
                 ?13, ?14, ?15, ?16,
                 ?17, ?18, ?19, ?20,
                 ?21, ?22, ?23, ?24,
                 ?25, ?26, ?27, ?28,
                 ?29,
-                ?30, ?31, ?32, ?33
+                ?30, ?31, ?35, ?36, ?32, ?33,
+                ?37, ?38
             )
             ON CONFLICT(id) DO UPDATE SET
                 file_path = excluded.file_path,
@@ -37,6 +45,7 @@ impl LibraryRepository {
                 title = excluded.title,
                 authors_json = excluded.authors_json,
                 series_json = excluded.series_json,
+                contributors_json = excluded.contributors_json,
                 description = excluded.description,
                 language = excluded.language,
                 publisher = excluded.publisher,
@@ -61,7 +70,11 @@ impl LibraryRepository {
                 chapters_json = excluded.chapters_json,
                 last_open_at = excluded.last_open_at,
                 total_reading_time_sec = excluded.total_reading_time_sec,
-                updated_at = excluded.updated_at",
+                added_at = excluded.added_at,
+                is_private = excluded.is_private,
+                updated_at = excluded.updated_at,
+                title_sort = excluded.title_sort,
+                author_sort = excluded.author_sort",
         )
         .bind(&item.id)
         .bind(&item.file_path)
@@ -96,6 +109,11 @@ impl LibraryRepository {
         .bind(item.total_reading_time_sec)
         .bind(&item.created_at)
         .bind(&item.updated_at)
+        .bind(&item.contributors_json)
+        .bind(&item.added_at)
+        .bind(item.is_private)
+        .bind(&item.title_sort)
+        .bind(&item.author_sort)
         .execute(&self.pool)
         .await
         .context("Failed to upsert library item")?;
@@ -174,6 +192,28 @@ impl LibraryRepository {
         Ok(())
     }
 
+    /// Forget all fingerprints so the next sync ingests every book again.
+    pub async fn delete_all_fingerprints(&self) -> Result<()> {
+        sqlx::query("DELETE FROM library_item_fingerprints")
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete fingerprints")?;
+        Ok(())
+    }
+
+    pub async fn upsert_ingest_setting(&self, name: &str, value: &str) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO ingest_settings (name, value) VALUES (?1, ?2)
+             ON CONFLICT(name) DO UPDATE SET value = excluded.value",
+        )
+        .bind(name)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .context("Failed to upsert ingest setting")?;
+        Ok(())
+    }
+
     pub async fn delete_item(&self, id: &str) -> Result<()> {
         sqlx::query("DELETE FROM library_items WHERE id = ?1")
             .bind(id)
@@ -183,6 +223,124 @@ impl LibraryRepository {
         Ok(())
     }
 
+    /// Replace every item's slug with `slugs` (`id -> slug`); items missing
+    /// from the map have their slug cleared. Slugs that are dropped or
+    /// replaced are kept in the slug history so old links still resolve.
+    pub async fn replace_item_slugs(&self, slugs: &HashMap<String, String>) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let previous: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, slug FROM library_items WHERE slug IS NOT NULL")
+                .fetch_all(&mut *tx)
+                .await
+                .context("Failed to load current item slugs")?;
+        for (id, slug) in previous {
+            if slugs.get(&id) == Some(&slug) {
+                continue;
+            }
+            sqlx::query(
+                "INSERT INTO library_item_slug_history (slug, item_id) VALUES (?1, ?2)
+                 ON CONFLICT(slug) DO UPDATE SET item_id = excluded.item_id",
+            )
+            .bind(&slug)
+            .bind(&id)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to record retired item slug")?;
+        }
+
+        // Clear first so slugs can move between items without tripping the
+        // unique index mid-update.
+        sqlx::query("UPDATE library_items SET slug = NULL")
+            .execute(&mut *tx)
+            .await
+            .context("Failed to clear item slugs")?;
+        for (id, slug) in slugs {
+            sqlx::query("UPDATE library_items SET slug = ?2 WHERE id = ?1")
+                .bind(id)
+                .bind(slug)
+                .execute(&mut *tx)
+                .await
+                .context("Failed to set item slug")?;
+        }
+
+        // A slug in use again is no longer a redirect.
+        sqlx::query(
+            "DELETE FROM library_item_slug_history
+             WHERE slug IN (SELECT slug FROM library_items WHERE slug IS NOT NULL)",
+        )
+        .execute(&mut *tx)
+        .await
+        .context("Failed to prune item slug history")?;
+
+        tx.commit().await.context("Failed to commit item slugs")?;
+        Ok(())
+    }
+
+    /// Keep `keys` resolving to `item_id`: used when a book is read under a
+    /// new ID (e.g. its file changed under `--id-scheme hash`), so links to
+    /// the item it replaced redirect instead of breaking. Retired IDs share
+    /// the slug history, which already redirects.
+    pub async fn retire_item_keys(&self, keys: &[String], item_id: &str) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for key in keys.iter().filter(|key| key.as_str() != item_id) {
+            sqlx::query(
+                "INSERT INTO library_item_slug_history (slug, item_id) VALUES (?1, ?2)
+                 ON CONFLICT(slug) DO UPDATE SET item_id = excluded.item_id",
+            )
+            .bind(key)
+            .bind(item_id)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to record retired item key")?;
+        }
+        tx.commit()
+            .await
+            .context("Failed to commit retired item keys")?;
+        Ok(())
+    }
+
+    // ── Parsed book info cache ──────────────────────────────────────────
+
+    /// Cache the parse result for a book file. Callers take the cover bytes
+    /// out first; covers are cached as encoded files instead.
+    pub async fn upsert_parsed_book_info(
+        &self,
+        fp: &FileFingerprint,
+        book_info: &BookInfo,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO parsed_book_info (
+                book_path, book_size_bytes, book_modified_unix_ms, parser_version,
+                book_info_json
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(book_path) DO UPDATE SET
+                book_size_bytes = excluded.book_size_bytes,
+                book_modified_unix_ms = excluded.book_modified_unix_ms,
+                parser_version = excluded.parser_version,
+                book_info_json = excluded.book_info_json",
+        )
+        .bind(fp.path.to_string_lossy())
+        .bind(fp.size_bytes as i64)
+        .bind(fp.modified_unix_ms as i64)
+        .bind(env!("CARGO_PKG_VERSION"))
+        .bind(Json(book_info))
+        .execute(&self.pool)
+        .await
+        .context("Failed to upsert parsed book info")?;
+        Ok(())
+    }
+
+    /// Drop the cached parse result for a book file.
+    pub async fn delete_parsed_book_info(&self, book_path: &str) -> Result<()> {
+        sqlx::query("DELETE FROM parsed_book_info WHERE book_path = ?1")
+            .bind(book_path)
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete parsed book info")?;
+        Ok(())
+    }
+
     // ── Share image fingerprints ────────────────────────────────────────
 
     /// Upsert a share image fingerprint for a given year.
@@ -342,7 +500,11 @@ impl LibraryRepository {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use crate::server::api::responses::library::LibraryStatus;
+    use crate::shelf::models::BookInfo;
+    use crate::source::FileFingerprint;
     use crate::store::sqlite::repo::tests::{
         sample_annotation, sample_fingerprint, sample_item, test_repo,
     };
@@ -456,6 +618,54 @@ mod tests {
         assert!(all[0].text.is_some());
     }
 
+    #[tokio::test]
+    async fn parsed_book_info_is_reused_only_for_unchanged_files() {
+        let repo = test_repo().await;
+        let fp = FileFingerprint {
+            path: PathBuf::from("/books/a.epub"),
+            size_bytes: 1024,
+            modified_unix_ms: 1700000000000,
+        };
+        let info = BookInfo {
+            title: "Cached".to_string(),
+            title_sort: None,
+            authors: vec!["Author".to_string()],
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
+            description: None,
+            language: None,
+            publisher: None,
+            identifiers: Vec::new(),
+            subjects: Vec::new(),
+            series: None,
+            series_number: None,
+            pages: Some(42),
+            chapters: Vec::new(),
+            cover_data: None,
+            cover_mime_type: Some("image/jpeg".to_string()),
+        };
+        repo.upsert_parsed_book_info(&fp, &info).await.unwrap();
+
+        let cached = repo.load_parsed_book_info(&fp).await.unwrap().unwrap();
+        assert_eq!(cached.title, "Cached");
+        assert_eq!(cached.pages, Some(42));
+
+        let touched = FileFingerprint {
+            modified_unix_ms: 1700000001000,
+            ..fp.clone()
+        };
+        assert!(
+            repo.load_parsed_book_info(&touched)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        repo.delete_parsed_book_info("/books/a.epub").await.unwrap();
+        assert!(repo.load_parsed_book_info_paths().await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn upsert_and_load_fingerprints() {
         let repo = test_repo().await;
@@ -10,7 +10,11 @@ pub(crate) fn library_item(id: &str, metadata: Option<KoReaderMetadata>) -> Libr
         id: id.to_string(),
         book_info: BookInfo {
             title: "Item".to_string(),
+            title_sort: None,
             authors: vec!["Author".to_string()],
+            author_sort: None,
+            editors: Vec::new(),
+            translators: Vec::new(),
             description: None,
             language: None,
             publisher: None,
@@ -26,6 +30,7 @@ pub(crate) fn library_item(id: &str, metadata: Option<KoReaderMetadata>) -> Libr
         koreader_metadata: metadata,
         file_path: PathBuf::from("/tmp/item.epub"),
         format: LibraryItemFormat::Epub,
+        added_at: None,
     }
 }
 
@@ -70,6 +75,7 @@ pub(crate) fn stat_book(id: i64, md5: &str, pages: i64, content_type: ContentTyp
         total_read_time: Some(3600),
         total_read_pages: Some(10),
         completions: None,
+        removed: false,
     }
 }
 